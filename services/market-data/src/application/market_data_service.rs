@@ -6,39 +6,62 @@
 //! - 连接交易所
 //! - 获取行情事件
 //! - 发布到消息队列
-//! - 存储到 ClickHouse
+//! - 投递到缓冲存储写入器（不等待存储）
+//! - 交给质量服务检测异常
+//! - 录制标准化事件（用于确定性回放）
 //!
 //! ## 依赖规则
 //! - ✅ 只依赖 `domain::port` 中的 trait
 //! - ❌ 不直接依赖 infrastructure
 
+use std::sync::Arc;
 use std::time::Duration;
 
-use tracing::{debug, error, info, warn};
+use tracing::{error, info, warn};
 
-use crate::domain::port::{MarketExchangePort, MarketStoragePort, MessagePort};
+use crate::application::quality_service::QualityService;
+use crate::application::storage_writer::StorageWriterHandle;
+use crate::domain::port::{MarketExchangePort, MarketRecorderPort, MessagePort};
 
 /// 行情数据服务
-pub struct MarketDataService<E, M, S>
+pub struct MarketDataService<E, M>
 where
     E: MarketExchangePort,
     M: MessagePort,
-    S: MarketStoragePort,
 {
     exchange: E,
     message: M,
-    storage: Option<S>,
+    storage: Option<StorageWriterHandle>,
+    quality: Option<Arc<QualityService>>,
+    recorder: Option<Arc<dyn MarketRecorderPort>>,
 }
 
-impl<E, M, S> MarketDataService<E, M, S>
+impl<E, M> MarketDataService<E, M>
 where
     E: MarketExchangePort,
     M: MessagePort,
-    S: MarketStoragePort,
 {
     /// 创建服务实例（由 bootstrap 调用）
-    pub fn new(exchange: E, message: M, storage: Option<S>) -> Self {
-        Self { exchange, message, storage }
+    pub fn new(exchange: E, message: M, storage: Option<StorageWriterHandle>) -> Self {
+        Self {
+            exchange,
+            message,
+            storage,
+            quality: None,
+            recorder: None,
+        }
+    }
+
+    /// 接入行情质量服务
+    pub fn with_quality(mut self, quality: Arc<QualityService>) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    /// 接入行情录制器
+    pub fn with_recorder(mut self, recorder: Arc<dyn MarketRecorderPort>) -> Self {
+        self.recorder = Some(recorder);
+        self
     }
 
     /// 运行行情采集循环
@@ -54,19 +77,37 @@ where
         }
 
         // 3. 循环获取并发布行情
+        let mut session = self.exchange.session();
         loop {
             match self.exchange.next_event().await {
                 Ok(event) => {
-                    // 发布到 Kafka
-                    if let Err(e) = self.message.publish(event.clone()).await {
-                        warn!(error = %e, "Failed to publish event to Kafka");
+                    // 重连后的第一条事件：断线期间的成交不会补推，序号重新起算
+                    let current = self.exchange.session();
+                    if current != session {
+                        session = current;
+                        if let Some(ref quality) = self.quality {
+                            quality.reset_sequence(&event.exchange);
+                        }
                     }
 
-                    // 存储到 ClickHouse（如果启用）
+                    // 录制（非阻塞，先于其他处理以保持系统看到的顺序）
+                    if let Some(ref recorder) = self.recorder {
+                        recorder.record_event(&event);
+                    }
+
+                    // 质量检测（状态变化时发布质量事件）
+                    if let Some(ref quality) = self.quality {
+                        quality.observe(&event).await;
+                    }
+
+                    // 投递到存储缓冲区（非阻塞，满时丢弃并计数）
                     if let Some(ref storage) = self.storage {
-                        if let Err(e) = storage.save_event(&event).await {
-                            debug!(error = %e, "Failed to save event to storage");
-                        }
+                        storage.enqueue(event.clone());
+                    }
+
+                    // 发布到 Kafka
+                    if let Err(e) = self.message.publish(event).await {
+                        warn!(error = %e, "Failed to publish event to Kafka");
                     }
                 }
                 Err(e) => {
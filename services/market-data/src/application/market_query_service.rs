@@ -0,0 +1,152 @@
+//! # 行情查询服务 (Market Query Service)
+//!
+//! 应用层服务，负责编排历史行情查询（读侧）。
+//!
+//! ## 职责
+//! - 规范化查询参数（交易对大写、分页条数上限）
+//! - 调用 `MarketStoragePort` 读方法
+//! - 为大范围查询提供逐页拉取的流式结果
+//!
+//! ## 依赖规则
+//! - ✅ 只依赖 `domain::port` 中的 trait
+//! - ❌ 不直接依赖 infrastructure
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use futures_util::stream::{self, Stream};
+
+use crate::domain::model::{
+    DepthSnapshot, Kline, KlineInterval, KlineQuery, PriceSnapshot, TimeRange, TradeCursor,
+    TradePage, TradeQuery, TradeRecord,
+};
+use crate::domain::port::MarketStoragePort;
+
+/// 行情查询服务
+pub struct MarketQueryService {
+    storage: Arc<dyn MarketStoragePort>,
+    /// 单次查询最大条数
+    max_limit: usize,
+}
+
+impl MarketQueryService {
+    /// 默认分页条数
+    pub const DEFAULT_LIMIT: usize = 500;
+
+    /// 创建服务实例（由 bootstrap 调用）
+    pub fn new(storage: Arc<dyn MarketStoragePort>, max_limit: usize) -> Self {
+        Self {
+            storage,
+            max_limit: max_limit.max(1),
+        }
+    }
+
+    /// 分页查询成交
+    pub async fn trades(
+        &self,
+        symbol: &str,
+        range: TimeRange,
+        limit: Option<usize>,
+        cursor: Option<TradeCursor>,
+    ) -> Result<TradePage> {
+        let query = TradeQuery {
+            symbol: normalize_symbol(symbol),
+            range,
+            limit: self.clamp_limit(limit),
+            cursor,
+        };
+        self.storage.query_trades(&query).await
+    }
+
+    /// 流式查询成交
+    ///
+    /// 按最大分页条数逐页拉取，每次产出一页，直到范围内没有更多数据。
+    /// 任一页查询失败时产出错误并结束。
+    pub fn stream_trades(
+        self: Arc<Self>,
+        symbol: &str,
+        range: TimeRange,
+    ) -> impl Stream<Item = Result<Vec<TradeRecord>>> + Send + 'static {
+        let query = TradeQuery {
+            symbol: normalize_symbol(symbol),
+            range,
+            limit: self.max_limit,
+            cursor: None,
+        };
+
+        stream::unfold(Some(query), move |state| {
+            let service = Arc::clone(&self);
+            async move {
+                let mut query = state?;
+                match service.storage.query_trades(&query).await {
+                    Ok(page) => {
+                        let next = page.next_cursor.map(|cursor| {
+                            query.cursor = Some(cursor);
+                            query
+                        });
+                        Some((Ok(page.trades), next))
+                    }
+                    Err(e) => Some((Err(e), None)),
+                }
+            }
+        })
+    }
+
+    /// 查询 K 线（服务端重采样）
+    ///
+    /// 返回 K 线与下一页开始时间（毫秒），没有更多数据时为 None。
+    pub async fn klines(
+        &self,
+        symbol: &str,
+        interval: KlineInterval,
+        range: TimeRange,
+        limit: Option<usize>,
+    ) -> Result<(Vec<Kline>, Option<i64>)> {
+        let query = KlineQuery {
+            symbol: normalize_symbol(symbol),
+            interval,
+            range,
+            limit: self.clamp_limit(limit),
+        };
+        let klines = self.storage.query_klines(&query).await?;
+
+        let next_start = if klines.len() >= query.limit {
+            klines
+                .last()
+                .map(|k| k.close_time)
+                .filter(|t| *t < range.end.timestamp_millis())
+        } else {
+            None
+        };
+
+        Ok((klines, next_start))
+    }
+
+    /// 查询最新价格
+    pub async fn latest_price(&self, symbol: &str) -> Result<Option<PriceSnapshot>> {
+        self.storage.latest_price(&normalize_symbol(symbol)).await
+    }
+
+    /// 查询深度快照
+    pub async fn depth_snapshot(
+        &self,
+        symbol: &str,
+        at: Option<DateTime<Utc>>,
+    ) -> Result<Option<DepthSnapshot>> {
+        self.storage
+            .depth_snapshot(&normalize_symbol(symbol), at)
+            .await
+    }
+
+    fn clamp_limit(&self, limit: Option<usize>) -> usize {
+        limit
+            .unwrap_or(Self::DEFAULT_LIMIT)
+            .clamp(1, self.max_limit)
+    }
+}
+
+/// 交易对统一为大写（与采集端写入一致）
+fn normalize_symbol(symbol: &str) -> String {
+    symbol.trim().to_uppercase()
+}
@@ -1,7 +1,25 @@
 //! # 应用层 (Application Layer)
 //!
 //! market-data 服务的应用层，负责用例编排。
+//!
+//! ## 包含服务
+//! - `MarketDataService`: 行情采集（写侧）
+//! - `MarketQueryService`: 历史行情查询（读侧）
+//! - `BufferedStorageWriter`: 批量缓冲存储写入
+//! - `QualityService`: 行情数据质量监控与发布
+//! - `ReplayService`: 录制行情的确定性回放
 
 pub mod market_data_service;
+pub mod market_query_service;
+pub mod quality_service;
+pub mod replay_service;
+pub mod storage_writer;
 
 pub use market_data_service::MarketDataService;
+pub use market_query_service::MarketQueryService;
+pub use quality_service::QualityService;
+pub use replay_service::ReplayService;
+pub use storage_writer::{
+    BufferedStorageWriter, StorageWriterConfig, StorageWriterHandle, StorageWriterMetricsSnapshot,
+    StorageWriterTask,
+};
@@ -0,0 +1,121 @@
+//! # 行情质量服务 (Market Quality Service)
+//!
+//! 应用层服务，把 `QualityMonitor` 接入采集链路并发布质量状态。
+//!
+//! ## 职责
+//! - 采集链路每条事件调用 `observe`，状态变化时立即发布
+//! - 交易所重连后重置序号缺口检测
+//! - 后台周期检查断流与恢复流转
+//! - 周期发布全量心跳，保证后启动的消费者也能拿到状态
+//!
+//! ## 依赖规则
+//! - ✅ 只依赖 `domain::port` 中的 trait
+//! - ❌ 不直接依赖 infrastructure
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::Utc;
+use shared::event::market_event::MarketEvent;
+use shared::event::quality_event::DataQualityEvent;
+use shared::types::market::DataQuality;
+use tracing::{info, warn};
+
+use crate::domain::logic::{QualityMonitor, QualityMonitorConfig};
+use crate::domain::port::QualityMessagePort;
+
+/// 行情质量服务
+pub struct QualityService {
+    monitor: Mutex<QualityMonitor>,
+    publisher: Arc<dyn QualityMessagePort>,
+    /// 断流 / 恢复检查间隔
+    check_interval: Duration,
+    /// 全量心跳间隔
+    heartbeat_interval: Duration,
+}
+
+impl QualityService {
+    /// 创建服务实例（由 bootstrap 调用）
+    pub fn new(
+        config: QualityMonitorConfig,
+        publisher: Arc<dyn QualityMessagePort>,
+        check_interval: Duration,
+        heartbeat_interval: Duration,
+    ) -> Self {
+        Self {
+            monitor: Mutex::new(QualityMonitor::new(config)),
+            publisher,
+            check_interval,
+            heartbeat_interval,
+        }
+    }
+
+    /// 处理一条行情事件，状态变化时发布
+    pub async fn observe(&self, event: &MarketEvent) {
+        let changed = {
+            let mut monitor = self.monitor.lock().unwrap_or_else(|e| e.into_inner());
+            monitor.observe(event, Utc::now())
+        };
+
+        if let Some(change) = changed {
+            self.publish(&change).await;
+        }
+    }
+
+    /// 交易所连接重建：序号缺口检测重新起算
+    pub fn reset_sequence(&self, exchange: &str) {
+        let mut monitor = self.monitor.lock().unwrap_or_else(|e| e.into_inner());
+        monitor.reset_sequence(exchange);
+    }
+
+    /// 当前所有交易对的质量状态
+    pub fn snapshot(&self) -> Vec<DataQualityEvent> {
+        let monitor = self.monitor.lock().unwrap_or_else(|e| e.into_inner());
+        monitor.snapshot(Utc::now())
+    }
+
+    /// 运行后台检查循环
+    pub async fn run(&self) {
+        info!(
+            check_interval_ms = self.check_interval.as_millis() as u64,
+            heartbeat_interval_ms = self.heartbeat_interval.as_millis() as u64,
+            "QualityService started"
+        );
+
+        let mut check_tick = tokio::time::interval(self.check_interval);
+        let mut heartbeat_tick = tokio::time::interval(self.heartbeat_interval);
+
+        loop {
+            tokio::select! {
+                _ = check_tick.tick() => {
+                    let changes = {
+                        let mut monitor = self.monitor.lock().unwrap_or_else(|e| e.into_inner());
+                        monitor.check(Utc::now())
+                    };
+                    for change in &changes {
+                        self.publish(change).await;
+                    }
+                }
+                _ = heartbeat_tick.tick() => {
+                    for status in &self.snapshot() {
+                        if let Err(e) = self.publisher.publish_quality(status).await {
+                            warn!(error = %e, symbol = %status.symbol, "Failed to publish quality heartbeat");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn publish(&self, event: &DataQualityEvent) {
+        if event.quality == DataQuality::Suspect {
+            warn!(symbol = %event.symbol, issues = ?event.issues, "行情数据质量异常");
+        } else {
+            info!(symbol = %event.symbol, quality = %event.quality, "行情数据质量状态变化");
+        }
+
+        if let Err(e) = self.publisher.publish_quality(event).await {
+            warn!(error = %e, symbol = %event.symbol, "Failed to publish quality event");
+        }
+    }
+}
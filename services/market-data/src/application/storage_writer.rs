@@ -0,0 +1,567 @@
+//! # 缓冲存储写入器 (Buffered Storage Writer)
+//!
+//! 将行情事件批量写入 `MarketStoragePort`，与 Kafka 发布链路解耦。
+//!
+//! ## 职责
+//! - 按条数 / 时间两个条件攒批，调用 `save_events`
+//! - 写入失败时指数退避重试
+//! - 重试耗尽后溢写到 `SpillQueuePort`，存储恢复后按顺序回放
+//! - 统计积压、延迟、丢弃等指标
+//! - 停止时排空缓冲区并刷写剩余数据（`StorageWriterTask::shutdown`）
+//!
+//! ## 规则
+//! - ✅ `enqueue` 永不阻塞：缓冲区满时直接丢弃并计数
+//! - ✅ 存储不可用期间不再逐批重试，直接溢写，避免拖慢攒批
+//! - ❌ 不直接依赖 infrastructure
+
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use serde::Serialize;
+use shared::event::market_event::MarketEvent;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
+
+use crate::domain::port::{MarketStoragePort, SpillQueuePort};
+
+/// 写入器配置
+#[derive(Debug, Clone)]
+pub struct StorageWriterConfig {
+    /// 每批最大条数（达到即刷写）
+    pub batch_size: usize,
+    /// 最长刷写间隔
+    pub flush_interval: Duration,
+    /// 内存缓冲区容量（条）
+    pub buffer_capacity: usize,
+    /// 单批最大重试次数
+    pub max_retries: u32,
+    /// 首次重试等待
+    pub retry_base_delay: Duration,
+    /// 重试等待上限
+    pub retry_max_delay: Duration,
+    /// 溢写回放检查间隔
+    pub replay_interval: Duration,
+}
+
+impl Default for StorageWriterConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 1000,
+            flush_interval: Duration::from_secs(1),
+            buffer_capacity: 100_000,
+            max_retries: 3,
+            retry_base_delay: Duration::from_millis(200),
+            retry_max_delay: Duration::from_secs(5),
+            replay_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+/// 写入器指标（原子计数）
+#[derive(Debug, Default)]
+pub struct StorageWriterMetrics {
+    /// 进入缓冲区的事件数
+    received: AtomicU64,
+    /// 成功写入存储的事件数（含回放）
+    written: AtomicU64,
+    /// 丢弃的事件数（缓冲区满 / 溢写失败）
+    dropped: AtomicU64,
+    /// 溢写到本地队列的事件数
+    spilled: AtomicU64,
+    /// 从本地队列回放成功的事件数
+    replayed: AtomicU64,
+    /// 重试次数
+    retries: AtomicU64,
+    /// 最终失败的批次数
+    failed_batches: AtomicU64,
+    /// 最近一次成功写入实时批次时，批内最早事件距今的毫秒数（不含回放）
+    write_lag_ms: AtomicI64,
+    /// 待回放的溢写批次数
+    spill_segments: AtomicU64,
+    /// 存储是否可用
+    storage_healthy: AtomicBool,
+}
+
+/// 写入器指标快照
+#[derive(Debug, Clone, Serialize)]
+pub struct StorageWriterMetricsSnapshot {
+    pub received: u64,
+    pub written: u64,
+    pub dropped: u64,
+    pub spilled: u64,
+    pub replayed: u64,
+    pub retries: u64,
+    pub failed_batches: u64,
+    /// 内存缓冲区当前积压条数
+    pub buffered: usize,
+    pub write_lag_ms: i64,
+    pub spill_segments: u64,
+    pub storage_healthy: bool,
+}
+
+/// 写入器句柄
+///
+/// 由采集链路持有，`enqueue` 只做 `try_send`，不会等待存储。
+#[derive(Clone)]
+pub struct StorageWriterHandle {
+    tx: mpsc::Sender<MarketEvent>,
+    metrics: Arc<StorageWriterMetrics>,
+}
+
+impl StorageWriterHandle {
+    /// 投递事件到写入缓冲区
+    ///
+    /// 返回 false 表示缓冲区已满或写入器已停止，事件被丢弃。
+    pub fn enqueue(&self, event: MarketEvent) -> bool {
+        match self.tx.try_send(event) {
+            Ok(()) => {
+                self.metrics.received.fetch_add(1, Ordering::Relaxed);
+                true
+            }
+            Err(_) => {
+                self.metrics.dropped.fetch_add(1, Ordering::Relaxed);
+                false
+            }
+        }
+    }
+
+    /// 获取指标快照
+    pub fn metrics(&self) -> StorageWriterMetricsSnapshot {
+        let m = &self.metrics;
+        StorageWriterMetricsSnapshot {
+            received: m.received.load(Ordering::Relaxed),
+            written: m.written.load(Ordering::Relaxed),
+            dropped: m.dropped.load(Ordering::Relaxed),
+            spilled: m.spilled.load(Ordering::Relaxed),
+            replayed: m.replayed.load(Ordering::Relaxed),
+            retries: m.retries.load(Ordering::Relaxed),
+            failed_batches: m.failed_batches.load(Ordering::Relaxed),
+            buffered: self.tx.max_capacity() - self.tx.capacity(),
+            write_lag_ms: m.write_lag_ms.load(Ordering::Relaxed),
+            spill_segments: m.spill_segments.load(Ordering::Relaxed),
+            storage_healthy: m.storage_healthy.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// 写入器后台任务
+///
+/// 查询接口等处持有的 `StorageWriterHandle` 克隆会让通道一直保持打开，
+/// 因此退出时必须通过本对象显式停止，才能保证缓冲区内的事件落盘。
+pub struct StorageWriterTask {
+    stop: oneshot::Sender<()>,
+    join: JoinHandle<()>,
+}
+
+impl StorageWriterTask {
+    /// 停止写入器：不再接收新事件，刷写缓冲区内的剩余事件后等待任务退出
+    ///
+    /// 本对象被释放时写入器同样会排空并退出。
+    pub async fn shutdown(self) {
+        let _ = self.stop.send(());
+        if let Err(e) = self.join.await {
+            error!(error = %e, "BufferedStorageWriter task failed");
+        }
+    }
+}
+
+/// 缓冲存储写入器
+pub struct BufferedStorageWriter<S>
+where
+    S: MarketStoragePort + 'static,
+{
+    storage: S,
+    spill: Option<Arc<dyn SpillQueuePort>>,
+    config: StorageWriterConfig,
+    metrics: Arc<StorageWriterMetrics>,
+}
+
+impl<S> BufferedStorageWriter<S>
+where
+    S: MarketStoragePort + 'static,
+{
+    /// 启动写入器后台任务（由 bootstrap 调用）
+    ///
+    /// # 返回
+    /// - `StorageWriterHandle`: 投递事件 / 读取指标
+    /// - `StorageWriterTask`: 后台任务，退出前调用 `shutdown` 刷写剩余数据
+    pub fn start(
+        storage: S,
+        spill: Option<Arc<dyn SpillQueuePort>>,
+        config: StorageWriterConfig,
+    ) -> (StorageWriterHandle, StorageWriterTask) {
+        let (tx, rx) = mpsc::channel(config.buffer_capacity.max(1));
+        let (stop, stop_rx) = oneshot::channel();
+        let metrics = Arc::new(StorageWriterMetrics::default());
+        metrics.storage_healthy.store(true, Ordering::Relaxed);
+
+        let writer = Self {
+            storage,
+            spill,
+            config,
+            metrics: Arc::clone(&metrics),
+        };
+        let join = tokio::spawn(writer.run(rx, stop_rx));
+
+        (StorageWriterHandle { tx, metrics }, StorageWriterTask { stop, join })
+    }
+
+    async fn run(self, mut rx: mpsc::Receiver<MarketEvent>, mut stop: oneshot::Receiver<()>) {
+        info!(
+            batch_size = self.config.batch_size,
+            flush_interval_ms = self.config.flush_interval.as_millis() as u64,
+            buffer_capacity = self.config.buffer_capacity,
+            spill_enabled = self.spill.is_some(),
+            "BufferedStorageWriter started"
+        );
+
+        self.refresh_spill_segments().await;
+
+        let mut batch: Vec<MarketEvent> = Vec::with_capacity(self.config.batch_size);
+        let mut flush_tick = tokio::time::interval(self.config.flush_interval);
+        let mut replay_tick = tokio::time::interval(self.config.replay_interval);
+
+        loop {
+            tokio::select! {
+                received = rx.recv() => match received {
+                    Some(event) => {
+                        batch.push(event);
+                        if batch.len() >= self.config.batch_size {
+                            self.flush(&mut batch).await;
+                        }
+                    }
+                    None => {
+                        self.flush(&mut batch).await;
+                        info!("BufferedStorageWriter stopped");
+                        return;
+                    }
+                },
+                _ = &mut stop => {
+                    // 拒绝新事件，排空通道内已缓冲的事件
+                    rx.close();
+                    while let Some(event) = rx.recv().await {
+                        batch.push(event);
+                        if batch.len() >= self.config.batch_size {
+                            self.flush(&mut batch).await;
+                        }
+                    }
+                    self.flush(&mut batch).await;
+                    info!("BufferedStorageWriter stopped");
+                    return;
+                }
+                _ = flush_tick.tick() => {
+                    self.flush(&mut batch).await;
+                }
+                _ = replay_tick.tick() => {
+                    self.replay_one().await;
+                }
+            }
+        }
+    }
+
+    /// 刷写当前批次
+    async fn flush(&self, batch: &mut Vec<MarketEvent>) {
+        if batch.is_empty() {
+            return;
+        }
+        let events = std::mem::replace(batch, Vec::with_capacity(self.config.batch_size));
+
+        // 存储不可用时只尝试一次，失败直接溢写
+        let retries = if self.metrics.storage_healthy.load(Ordering::Relaxed) {
+            self.config.max_retries
+        } else {
+            0
+        };
+
+        if self.save_with_retry(&events, retries).await {
+            self.record_written(&events);
+            return;
+        }
+
+        self.metrics.failed_batches.fetch_add(1, Ordering::Relaxed);
+        self.spill_batch(&events).await;
+    }
+
+    /// 带指数退避的批量写入，返回是否成功
+    async fn save_with_retry(&self, events: &[MarketEvent], max_retries: u32) -> bool {
+        let mut delay = self.config.retry_base_delay;
+        let mut attempt = 0u32;
+
+        loop {
+            match self.storage.save_events(events).await {
+                Ok(()) => {
+                    self.metrics.storage_healthy.store(true, Ordering::Relaxed);
+                    return true;
+                }
+                Err(e) => {
+                    if attempt >= max_retries {
+                        if self.metrics.storage_healthy.swap(false, Ordering::Relaxed) {
+                            error!(error = %e, count = events.len(), "存储写入失败，进入溢写模式");
+                        } else {
+                            debug!(error = %e, count = events.len(), "存储仍不可用");
+                        }
+                        return false;
+                    }
+                    warn!(error = %e, attempt = attempt + 1, "存储写入失败，稍后重试");
+                    self.metrics.retries.fetch_add(1, Ordering::Relaxed);
+                    tokio::time::sleep(delay).await;
+                    delay = (delay * 2).min(self.config.retry_max_delay);
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// 溢写到本地队列，失败则计入丢弃
+    async fn spill_batch(&self, events: &[MarketEvent]) {
+        let count = events.len() as u64;
+        let Some(spill) = self.spill.as_ref() else {
+            self.metrics.dropped.fetch_add(count, Ordering::Relaxed);
+            warn!(count, "未配置溢写队列，丢弃批次");
+            return;
+        };
+
+        match spill.append(events).await {
+            Ok(()) => {
+                self.metrics.spilled.fetch_add(count, Ordering::Relaxed);
+                self.metrics.spill_segments.fetch_add(1, Ordering::Relaxed);
+                debug!(count, "批次已溢写到本地队列");
+            }
+            Err(e) => {
+                self.metrics.dropped.fetch_add(count, Ordering::Relaxed);
+                error!(error = %e, count, "溢写失败，丢弃批次");
+            }
+        }
+    }
+
+    /// 回放最早的一个溢写批次
+    ///
+    /// 回放成功后删除该批次；失败则保留，等待下次回放。
+    async fn replay_one(&self) {
+        let Some(spill) = self.spill.as_ref() else {
+            return;
+        };
+        if self.metrics.spill_segments.load(Ordering::Relaxed) == 0 {
+            return;
+        }
+
+        let segment = match spill.oldest().await {
+            Ok(Some(segment)) => segment,
+            Ok(None) => {
+                self.metrics.spill_segments.store(0, Ordering::Relaxed);
+                return;
+            }
+            Err(e) => {
+                warn!(error = %e, "读取溢写批次失败");
+                return;
+            }
+        };
+
+        if !segment.events.is_empty() && !self.save_with_retry(&segment.events, 0).await {
+            return;
+        }
+
+        if let Err(e) = spill.remove(&segment.id).await {
+            // 删除失败会导致下次重复回放，ClickHouse 侧可容忍少量重复
+            warn!(error = %e, segment = %segment.id, "删除溢写批次失败");
+        }
+
+        // 溢写事件本身就是旧数据，只计数，不更新写入延迟
+        let count = segment.events.len() as u64;
+        self.metrics.replayed.fetch_add(count, Ordering::Relaxed);
+        self.metrics.written.fetch_add(count, Ordering::Relaxed);
+        self.refresh_spill_segments().await;
+        info!(segment = %segment.id, count, "溢写批次回放完成");
+    }
+
+    async fn refresh_spill_segments(&self) {
+        if let Some(spill) = self.spill.as_ref() {
+            match spill.pending_segments().await {
+                Ok(n) => self.metrics.spill_segments.store(n as u64, Ordering::Relaxed),
+                Err(e) => warn!(error = %e, "统计溢写批次失败"),
+            }
+        }
+    }
+
+    fn record_written(&self, events: &[MarketEvent]) {
+        self.metrics
+            .written
+            .fetch_add(events.len() as u64, Ordering::Relaxed);
+        if let Some(oldest) = events.iter().map(|e| e.timestamp).min() {
+            let lag = (Utc::now() - oldest).num_milliseconds().max(0);
+            self.metrics.write_lag_ms.store(lag, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+    use std::sync::Mutex;
+
+    use anyhow::{bail, Result};
+    use async_trait::async_trait;
+    use chrono::{DateTime, TimeZone};
+    use rust_decimal::Decimal;
+    use shared::event::market_event::{MarketEventData, MarketEventType, TradeData};
+
+    use crate::domain::model::{
+        DepthSnapshot, Kline, KlineQuery, PriceSnapshot, TradePage, TradeQuery,
+    };
+    use crate::infrastructure::storage::FileSpillQueue;
+
+    /// 可控制失败的内存存储
+    #[derive(Default)]
+    struct FlakyStorage {
+        /// 接下来失败的次数
+        failures: AtomicU32,
+        /// 是否持续不可用
+        down: AtomicBool,
+        saved: Mutex<Vec<MarketEvent>>,
+    }
+
+    #[async_trait]
+    impl MarketStoragePort for FlakyStorage {
+        async fn save_event(&self, event: &MarketEvent) -> Result<()> {
+            self.save_events(std::slice::from_ref(event)).await
+        }
+
+        async fn save_events(&self, events: &[MarketEvent]) -> Result<()> {
+            if self.down.load(Ordering::SeqCst) {
+                bail!("storage down");
+            }
+            let failing = self
+                .failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1));
+            if failing.is_ok() {
+                bail!("transient failure");
+            }
+            self.saved.lock().unwrap().extend_from_slice(events);
+            Ok(())
+        }
+
+        async fn query_trades(&self, _query: &TradeQuery) -> Result<TradePage> {
+            bail!("not supported")
+        }
+
+        async fn query_klines(&self, _query: &KlineQuery) -> Result<Vec<Kline>> {
+            bail!("not supported")
+        }
+
+        async fn latest_price(&self, _symbol: &str) -> Result<Option<PriceSnapshot>> {
+            bail!("not supported")
+        }
+
+        async fn depth_snapshot(
+            &self,
+            _symbol: &str,
+            _at: Option<DateTime<Utc>>,
+        ) -> Result<Option<DepthSnapshot>> {
+            bail!("not supported")
+        }
+    }
+
+    fn trade(id: i64) -> MarketEvent {
+        MarketEvent {
+            event_type: MarketEventType::Trade,
+            exchange: "binance".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            timestamp: Utc.timestamp_millis_opt(id).unwrap(),
+            data: MarketEventData::Trade(TradeData {
+                trade_id: id.to_string(),
+                price: Decimal::from(50_000),
+                quantity: Decimal::ONE,
+                is_buyer_maker: false,
+            }),
+        }
+    }
+
+    fn config(batch_size: usize) -> StorageWriterConfig {
+        StorageWriterConfig {
+            batch_size,
+            flush_interval: Duration::from_secs(3600),
+            buffer_capacity: 100,
+            max_retries: 3,
+            retry_base_delay: Duration::from_millis(1),
+            retry_max_delay: Duration::from_millis(2),
+            replay_interval: Duration::from_millis(10),
+        }
+    }
+
+    fn saved_ids(storage: &FlakyStorage) -> Vec<i64> {
+        storage
+            .saved
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|e| e.timestamp.timestamp_millis())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_retry_then_drain_on_shutdown() {
+        let storage = Arc::new(FlakyStorage::default());
+        storage.failures.store(2, Ordering::SeqCst);
+        let (handle, task) = BufferedStorageWriter::start(Arc::clone(&storage), None, config(2));
+
+        // 满批触发写入，前两次失败后重试成功
+        assert!(handle.enqueue(trade(1)));
+        assert!(handle.enqueue(trade(2)));
+        // 未满批的事件在停止时刷写，即使还有句柄克隆存活
+        let _query_side = handle.clone();
+        assert!(handle.enqueue(trade(3)));
+        task.shutdown().await;
+
+        assert_eq!(saved_ids(&storage), vec![1, 2, 3]);
+        let metrics = handle.metrics();
+        assert_eq!(metrics.retries, 2);
+        assert_eq!(metrics.written, 3);
+        assert_eq!(metrics.spilled, 0);
+        assert!(!handle.enqueue(trade(4)));
+    }
+
+    #[tokio::test]
+    async fn test_spill_and_replay() {
+        let dir =
+            std::env::temp_dir().join(format!("market-data-spill-writer-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let spill: Arc<dyn SpillQueuePort> = Arc::new(FileSpillQueue::new(&dir, 1 << 20).unwrap());
+
+        let storage = Arc::new(FlakyStorage::default());
+        storage.down.store(true, Ordering::SeqCst);
+        let (handle, task) =
+            BufferedStorageWriter::start(Arc::clone(&storage), Some(Arc::clone(&spill)), config(2));
+
+        // 重试耗尽后溢写
+        handle.enqueue(trade(1));
+        handle.enqueue(trade(2));
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+        while handle.metrics().spilled < 2 {
+            assert!(tokio::time::Instant::now() < deadline, "batch was not spilled");
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(handle.metrics().failed_batches, 1);
+        assert_eq!(spill.pending_segments().await.unwrap(), 1);
+
+        // 存储恢复后按顺序回放并删除批次，回放不计入写入延迟
+        storage.down.store(false, Ordering::SeqCst);
+        while handle.metrics().replayed < 2 {
+            assert!(tokio::time::Instant::now() < deadline, "segment was not replayed");
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        task.shutdown().await;
+
+        assert_eq!(saved_ids(&storage), vec![1, 2]);
+        assert_eq!(spill.pending_segments().await.unwrap(), 0);
+        let metrics = handle.metrics();
+        assert_eq!(metrics.written, 2);
+        assert_eq!(metrics.write_lag_ms, 0);
+        assert!(metrics.storage_healthy);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -8,24 +8,142 @@
 //! - ❌ 不在 service 内 new adapter
 
 use std::sync::Arc;
+use std::time::Duration;
 
+use anyhow::{anyhow, Context};
+use chrono::{DateTime, Utc};
 use tracing::info;
 
-use crate::application::MarketDataService;
-use crate::infrastructure::exchange::BinanceWebSocket;
+use crate::application::{
+    BufferedStorageWriter, MarketDataService, MarketQueryService, QualityService, ReplayService,
+    StorageWriterConfig, StorageWriterTask,
+};
+use crate::domain::logic::QualityMonitorConfig;
+use crate::domain::model::{MarketExchange, ReplaySpeed, SymbolMap, TimeRange};
+use crate::domain::port::{
+    MarketExchangePort, MarketRecorderPort, MarketStoragePort, MessagePort, QualityMessagePort,
+    RecordingSourcePort, SpillQueuePort,
+};
+use crate::infrastructure::exchange::{
+    BinanceWebSocket, BybitWebSocket, CoinbaseWebSocket, OkxWebSocket,
+};
 use crate::infrastructure::messaging::KafkaProducer;
-use crate::infrastructure::storage::ClickHouseStorage;
-use crate::state::MarketDataConfig;
+use crate::infrastructure::recording::{FileRecorder, FileRecordingSource, RecorderTask};
+use crate::infrastructure::storage::{ClickHouseStorage, FileSpillQueue};
+use crate::state::{AppState, MarketDataConfig};
+
+/// 行情采集服务的具体类型（每个交易所一个实例）
+pub type IngestService = MarketDataService<Arc<dyn MarketExchangePort>, Arc<dyn MessagePort>>;
+
+/// 退出前需要收尾的后台任务
+///
+/// 采集任务停止后调用 `shutdown`，保证缓冲中的数据落盘。
+#[derive(Default)]
+pub struct BackgroundTasks {
+    storage_writer: Option<StorageWriterTask>,
+    recorder: Option<RecorderTask>,
+}
+
+impl BackgroundTasks {
+    /// 刷写并停止全部后台任务
+    pub async fn shutdown(self) {
+        if let Some(writer) = self.storage_writer {
+            writer.shutdown().await;
+            info!("存储写入器已刷写并停止");
+        }
+        if let Some(recorder) = self.recorder {
+            match tokio::task::spawn_blocking(move || recorder.shutdown()).await {
+                Ok(0) => info!("行情录制已写完并关闭"),
+                Ok(dropped) => tracing::warn!(dropped, "行情录制已关闭，运行期间因通道满丢弃部分数据"),
+                Err(e) => tracing::warn!("等待行情录制写线程失败: {}", e),
+            }
+        }
+    }
+}
 
 /// 构建行情数据服务
 ///
-/// 完成依赖注入，返回可运行的服务实例。
+/// 完成依赖注入，返回各交易所的行情采集服务、HTTP 查询状态与需要收尾的后台任务。
+/// 所有采集服务共享同一个存储写入器与质量服务（跨源校验依赖多交易所数据）。
+///
+/// 注意：存储启用时会启动缓冲写入器后台任务，必须在 tokio runtime 内调用。
+/// 质量服务的后台检查循环由调用方通过 `AppState::quality_service` 启动。
 pub fn build(
     config: MarketDataConfig,
-) -> anyhow::Result<MarketDataService<Arc<BinanceWebSocket>, Arc<KafkaProducer>, Arc<ClickHouseStorage>>> {
+) -> anyhow::Result<(Vec<IngestService>, AppState, BackgroundTasks)> {
     // 创建 Adapter（只在这里 new）
-    let exchange = Arc::new(BinanceWebSocket::new(config.ws_url, config.proxy_url));
-    let message = Arc::new(KafkaProducer::new(config.kafka_brokers, config.kafka_topic)?);
+    let symbol_map = Arc::new(
+        SymbolMap::parse(&config.symbol_map).context("解析 MARKET_DATA_SYMBOL_MAP 失败")?,
+    );
+    let shared_message: Option<Arc<dyn MessagePort>> = if config.kafka_topic_per_exchange {
+        None
+    } else {
+        Some(Arc::new(KafkaProducer::new(
+            config.kafka_brokers.clone(),
+            config.kafka_topic.clone(),
+        )?))
+    };
+
+    let mut background = BackgroundTasks::default();
+
+    // 行情录制（如果启用），写线程由 BackgroundTasks 在退出时关闭
+    let recorder: Option<Arc<dyn MarketRecorderPort>> = if config.recording_enabled {
+        let (recorder, task) = FileRecorder::start(
+            &config.recording_dir,
+            config.recording_buffer_capacity,
+            config.recording_raw,
+        )?;
+        background.recorder = Some(task);
+        Some(Arc::new(recorder))
+    } else {
+        None
+    };
+
+    let mut feeds: Vec<(Arc<dyn MarketExchangePort>, Arc<dyn MessagePort>)> = Vec::new();
+    for exchange in &config.exchanges {
+        let adapter = create_exchange(
+            *exchange,
+            &config,
+            Arc::clone(&symbol_map),
+            recorder.clone(),
+        );
+        let message = match shared_message {
+            Some(ref message) => Arc::clone(message),
+            None => {
+                let topic = format!("{}.{}", config.kafka_topic, exchange);
+                info!(%exchange, %topic, "使用独立行情 topic");
+                Arc::new(KafkaProducer::new(config.kafka_brokers.clone(), topic)?)
+                    as Arc<dyn MessagePort>
+            }
+        };
+        feeds.push((adapter, message));
+    }
+    info!(exchanges = ?config.exchanges, "行情交易所已配置");
+
+    // 行情质量监控（如果启用）
+    let quality_service = if config.quality_enabled {
+        let publisher: Arc<dyn QualityMessagePort> = Arc::new(KafkaProducer::new(
+            config.kafka_brokers.clone(),
+            config.quality_topic.clone(),
+        )?);
+        let monitor_config = QualityMonitorConfig {
+            stale_secs: config.quality_stale_secs,
+            spike_sigma: config.quality_spike_sigma,
+            max_cross_deviation_pct: config.quality_max_deviation_pct,
+            recovery_secs: config.quality_recovery_secs,
+            ..QualityMonitorConfig::default()
+        };
+        info!(topic = %config.quality_topic, "行情质量监控已启用");
+        Some(Arc::new(QualityService::new(
+            monitor_config,
+            publisher,
+            Duration::from_secs(1),
+            Duration::from_secs(config.quality_heartbeat_secs.max(1)),
+        )))
+    } else {
+        info!("行情质量监控已禁用");
+        None
+    };
 
     // 创建存储（如果启用）
     let storage = if config.storage_enabled {
@@ -48,6 +166,137 @@ pub fn build(
         None
     };
 
-    // 注入到 Service
-    Ok(MarketDataService::new(exchange, message, storage))
+    // 读侧查询服务（与采集共享存储）
+    let query_service = storage.as_ref().map(|s| {
+        let storage: Arc<dyn MarketStoragePort> = Arc::clone(s) as Arc<dyn MarketStoragePort>;
+        Arc::new(MarketQueryService::new(storage, config.query_max_limit))
+    });
+
+    // 写侧缓冲写入器（批量 + 重试 + 溢写）
+    let storage_writer = storage.map(|s| {
+        let spill = config.storage_spill_dir.as_ref().and_then(|dir| {
+            match FileSpillQueue::new(dir, config.storage_spill_max_bytes) {
+                Ok(q) => Some(Arc::new(q) as Arc<dyn SpillQueuePort>),
+                Err(e) => {
+                    tracing::warn!("溢写队列初始化失败，存储故障时将丢弃数据: {}", e);
+                    None
+                }
+            }
+        });
+        let writer_config = StorageWriterConfig {
+            batch_size: config.storage_batch_size.max(1),
+            flush_interval: Duration::from_millis(config.storage_flush_ms.max(1)),
+            buffer_capacity: config.storage_buffer_capacity,
+            max_retries: config.storage_max_retries,
+            ..StorageWriterConfig::default()
+        };
+        let (handle, task) = BufferedStorageWriter::start(s, spill, writer_config);
+        background.storage_writer = Some(task);
+        handle
+    });
+
+    let state = AppState {
+        query_service,
+        storage_writer: storage_writer.clone(),
+        quality_service: quality_service.clone(),
+    };
+
+    // 注入到 Service（每个交易所一个实例）
+    let services = feeds
+        .into_iter()
+        .map(|(exchange, message)| {
+            let mut service = MarketDataService::new(exchange, message, storage_writer.clone());
+            if let Some(ref quality) = quality_service {
+                service = service.with_quality(Arc::clone(quality));
+            }
+            if let Some(ref recorder) = recorder {
+                service = service.with_recorder(Arc::clone(recorder));
+            }
+            service
+        })
+        .collect();
+    Ok((services, state, background))
+}
+
+/// 构建行情回放服务（`MARKET_DATA_MODE=replay`）
+///
+/// # 返回
+/// - 回放服务、回放窗口与回放速度
+pub fn build_replay(
+    config: &MarketDataConfig,
+) -> anyhow::Result<(ReplayService, TimeRange, ReplaySpeed)> {
+    let start = parse_time(
+        config
+            .replay_start
+            .as_deref()
+            .ok_or_else(|| anyhow!("MARKET_DATA_REPLAY_START is required in replay mode"))?,
+    )
+    .context("解析 MARKET_DATA_REPLAY_START 失败")?;
+    let end = match config.replay_end.as_deref() {
+        Some(raw) => parse_time(raw).context("解析 MARKET_DATA_REPLAY_END 失败")?,
+        None => Utc::now(),
+    };
+    let range = TimeRange::new(start, end)?;
+    let speed = ReplaySpeed::parse(&config.replay_speed)?;
+
+    let source: Arc<dyn RecordingSourcePort> =
+        Arc::new(FileRecordingSource::new(&config.recording_dir));
+    let publisher: Arc<dyn MessagePort> = Arc::new(KafkaProducer::new(
+        config.kafka_brokers.clone(),
+        config.replay_topic.clone(),
+    )?);
+    info!(topic = %config.replay_topic, dir = %config.recording_dir, "行情回放已配置");
+
+    Ok((ReplayService::new(source, publisher), range, speed))
+}
+
+/// 解析时间：RFC3339 或毫秒时间戳
+fn parse_time(raw: &str) -> anyhow::Result<DateTime<Utc>> {
+    let raw = raw.trim();
+    if let Ok(ms) = raw.parse::<i64>() {
+        return crate::domain::model::market_query::millis_to_datetime(ms);
+    }
+    Ok(DateTime::parse_from_rfc3339(raw)?.with_timezone(&Utc))
+}
+
+/// 创建交易所 Adapter
+fn create_exchange(
+    exchange: MarketExchange,
+    config: &MarketDataConfig,
+    symbol_map: Arc<SymbolMap>,
+    recorder: Option<Arc<dyn MarketRecorderPort>>,
+) -> Arc<dyn MarketExchangePort> {
+    let proxy = config.proxy_url.clone();
+    match exchange {
+        MarketExchange::Binance => {
+            let adapter =
+                BinanceWebSocket::new(config.ws_url.clone(), proxy).with_symbol_map(symbol_map);
+            match recorder {
+                Some(r) => Arc::new(adapter.with_raw_recorder(r)),
+                None => Arc::new(adapter),
+            }
+        }
+        MarketExchange::Okx => {
+            let adapter = OkxWebSocket::with_url(config.okx_ws_url.clone(), proxy, symbol_map);
+            match recorder {
+                Some(r) => Arc::new(adapter.with_raw_recorder(r)),
+                None => Arc::new(adapter),
+            }
+        }
+        MarketExchange::Bybit => {
+            let adapter = BybitWebSocket::with_url(config.bybit_ws_url.clone(), proxy, symbol_map);
+            match recorder {
+                Some(r) => Arc::new(adapter.with_raw_recorder(r)),
+                None => Arc::new(adapter),
+            }
+        }
+        MarketExchange::Coinbase => {
+            let adapter =
+                CoinbaseWebSocket::with_url(config.coinbase_ws_url.clone(), proxy, symbol_map);
+            match recorder {
+                Some(r) => Arc::new(adapter.with_raw_recorder(r)),
+                None => Arc::new(adapter),
+            }
+        }
+    }
 }
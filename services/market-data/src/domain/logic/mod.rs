@@ -0,0 +1,8 @@
+//! # 领域逻辑 (Domain Logic)
+//!
+//! market-data 的纯业务规则，不依赖任何外部 IO。
+
+/// 行情质量监控 - 断流 / 异常价格 / 盘口交叉 / 序号缺口 / 跨源偏离
+pub mod quality_monitor;
+
+pub use quality_monitor::{QualityMonitor, QualityMonitorConfig};
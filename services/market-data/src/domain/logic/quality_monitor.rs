@@ -0,0 +1,614 @@
+//! # 行情质量监控 (Market Data Quality Monitor)
+//!
+//! 纯领域逻辑：根据行情事件流判定每个交易对的数据质量。
+//!
+//! ## 检测项
+//! - 断流: 某数据源超过 `stale_secs` 未更新
+//! - 异常价格: 相邻成交收益率超过 k·σ（滚动窗口）
+//! - 盘口交叉: 买一 >= 卖一
+//! - 序号缺口: 数字型成交 ID 不连续（仅限成交 ID 逐笔连续的交易所，重连后重新起算）
+//! - 跨源偏离: 与其他交易所最新价中位数偏离过大
+//!
+//! ## 状态流转
+//! ```text
+//! Unknown ──首个事件──▶ Normal ──异常──▶ Suspect
+//! Suspect ──异常消失──▶ Recovered ──持续 recovery_secs 无异常──▶ Normal
+//! ```
+//!
+//! ## 规则
+//! - ✅ 无 IO、无锁，调用方负责时间与并发
+//! - ❌ 不发布消息，只返回状态变化
+
+use std::collections::{HashMap, VecDeque};
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use shared::event::market_event::{MarketEvent, MarketEventData};
+use shared::event::quality_event::{DataQualityEvent, QualityIssue};
+use shared::types::market::DataQuality;
+
+/// 质量监控配置
+#[derive(Debug, Clone)]
+pub struct QualityMonitorConfig {
+    /// 断流阈值（秒）
+    pub stale_secs: i64,
+    /// 异常价格阈值 k（收益率超过 k·σ）
+    pub spike_sigma: f64,
+    /// 收益率滚动窗口长度
+    pub spike_window: usize,
+    /// 开始检测前需要的最少样本数
+    pub spike_min_samples: usize,
+    /// 异常价格的最小绝对收益率（避免平静行情下的误报）
+    pub spike_min_return: f64,
+    /// 跨源最大偏离（百分比）
+    pub max_cross_deviation_pct: f64,
+    /// 异常消失后恢复到 Normal 的观察期（秒）
+    pub recovery_secs: i64,
+    /// 成交 ID 逐笔连续、需要检测序号缺口的交易所
+    ///
+    /// OKX 推送聚合成交、Bybit 使用非数字 ID，这些交易所的 ID 天然不连续，不做检测。
+    pub sequence_gap_exchanges: Vec<String>,
+}
+
+impl Default for QualityMonitorConfig {
+    fn default() -> Self {
+        Self {
+            stale_secs: 30,
+            spike_sigma: 6.0,
+            spike_window: 200,
+            spike_min_samples: 30,
+            spike_min_return: 0.005,
+            max_cross_deviation_pct: 1.0,
+            recovery_secs: 60,
+            sequence_gap_exchanges: vec!["binance".to_string(), "coinbase".to_string()],
+        }
+    }
+}
+
+/// 单个数据源（交易所）状态
+#[derive(Debug, Default)]
+struct FeedState {
+    last_update: Option<DateTime<Utc>>,
+    last_price: Option<Decimal>,
+    last_trade_id: Option<u64>,
+    returns: VecDeque<f64>,
+}
+
+/// 单个交易对状态
+#[derive(Debug, Default)]
+struct SymbolState {
+    quality: DataQuality,
+    feeds: HashMap<String, FeedState>,
+    /// 瞬时异常及其发生时间（观察期后过期）
+    issues: Vec<(DateTime<Utc>, QualityIssue)>,
+    recovered_at: Option<DateTime<Utc>>,
+}
+
+impl SymbolState {
+    fn last_update(&self) -> Option<DateTime<Utc>> {
+        self.feeds.values().filter_map(|f| f.last_update).max()
+    }
+}
+
+/// 行情质量监控器
+#[derive(Debug)]
+pub struct QualityMonitor {
+    config: QualityMonitorConfig,
+    symbols: HashMap<String, SymbolState>,
+}
+
+impl QualityMonitor {
+    /// 创建监控器
+    pub fn new(config: QualityMonitorConfig) -> Self {
+        Self {
+            config,
+            symbols: HashMap::new(),
+        }
+    }
+
+    /// 处理一条行情事件
+    ///
+    /// # 返回
+    /// - `Some(event)`: 该交易对质量状态发生变化
+    /// - `None`: 状态不变
+    pub fn observe(&mut self, event: &MarketEvent, now: DateTime<Utc>) -> Option<DataQualityEvent> {
+        let issues = self.detect(event, now);
+
+        let state = self.symbols.entry(event.symbol.clone()).or_default();
+        state.issues.extend(issues.into_iter().map(|i| (now, i)));
+
+        self.reevaluate(&event.symbol, now)
+    }
+
+    /// 周期检查：断流检测与恢复流转
+    ///
+    /// 返回所有状态发生变化的交易对。
+    pub fn check(&mut self, now: DateTime<Utc>) -> Vec<DataQualityEvent> {
+        let symbols: Vec<String> = self.symbols.keys().cloned().collect();
+        symbols
+            .into_iter()
+            .filter_map(|symbol| self.reevaluate(&symbol, now))
+            .collect()
+    }
+
+    /// 所有交易对的当前状态（用于周期心跳）
+    pub fn snapshot(&self, now: DateTime<Utc>) -> Vec<DataQualityEvent> {
+        self.symbols
+            .iter()
+            .map(|(symbol, state)| self.build_event(symbol, state, now))
+            .collect()
+    }
+
+    /// 交易所连接重建：清空该交易所所有交易对的成交序号
+    ///
+    /// 断线期间的成交不会补推，重连后的第一笔成交重新作为序号起点。
+    pub fn reset_sequence(&mut self, exchange: &str) {
+        for state in self.symbols.values_mut() {
+            if let Some(feed) = state.feeds.get_mut(exchange) {
+                feed.last_trade_id = None;
+            }
+        }
+    }
+
+    /// 查询单个交易对的质量（测试用，对外以 `snapshot` 为准）
+    #[cfg(test)]
+    pub fn quality(&self, symbol: &str) -> DataQuality {
+        self.symbols
+            .get(symbol)
+            .map(|s| s.quality)
+            .unwrap_or_default()
+    }
+
+    /// 检测单条事件的异常，并更新数据源状态
+    fn detect(&mut self, event: &MarketEvent, now: DateTime<Utc>) -> Vec<QualityIssue> {
+        let mut issues = Vec::new();
+        let exchange = event.exchange.clone();
+
+        match &event.data {
+            MarketEventData::Trade(trade) => {
+                if let Some(issue) = self.check_cross_source(event, trade.price, now) {
+                    issues.push(issue);
+                }
+
+                let config = &self.config;
+                let check_sequence = config.sequence_gap_exchanges.contains(&exchange);
+                let feed = self
+                    .symbols
+                    .entry(event.symbol.clone())
+                    .or_default()
+                    .feeds
+                    .entry(exchange.clone())
+                    .or_default();
+
+                if let Some(id) = trade.trade_id.parse::<u64>().ok().filter(|_| check_sequence) {
+                    if let Some(last) = feed.last_trade_id {
+                        if id > last + 1 {
+                            issues.push(QualityIssue::SequenceGap {
+                                exchange: exchange.clone(),
+                                expected: last + 1,
+                                received: id,
+                            });
+                        }
+                    }
+                    feed.last_trade_id = Some(feed.last_trade_id.map_or(id, |last| last.max(id)));
+                }
+
+                if let Some(previous) = feed.last_price {
+                    if let Some(issue) = check_spike(config, feed, &exchange, previous, trade.price) {
+                        issues.push(issue);
+                    }
+                }
+                feed.last_price = Some(trade.price);
+                feed.last_update = Some(now);
+            }
+            MarketEventData::Depth(depth) => {
+                let best_bid = depth.bids.iter().map(|(p, _)| *p).max();
+                let best_ask = depth.asks.iter().map(|(p, _)| *p).min();
+                if let (Some(bid), Some(ask)) = (best_bid, best_ask) {
+                    if bid >= ask {
+                        issues.push(QualityIssue::CrossedBook {
+                            exchange: exchange.clone(),
+                            best_bid: bid,
+                            best_ask: ask,
+                        });
+                    }
+                }
+                self.touch(event, now);
+            }
+            MarketEventData::Tick(tick) => {
+                if tick.bid >= tick.ask && tick.ask > Decimal::ZERO {
+                    issues.push(QualityIssue::CrossedBook {
+                        exchange: exchange.clone(),
+                        best_bid: tick.bid,
+                        best_ask: tick.ask,
+                    });
+                }
+                self.touch(event, now);
+            }
+            MarketEventData::Kline(_) => {
+                self.touch(event, now);
+            }
+        }
+
+        issues
+    }
+
+    /// 更新数据源的最近更新时间
+    fn touch(&mut self, event: &MarketEvent, now: DateTime<Utc>) {
+        self.symbols
+            .entry(event.symbol.clone())
+            .or_default()
+            .feeds
+            .entry(event.exchange.clone())
+            .or_default()
+            .last_update = Some(now);
+    }
+
+    /// 跨源校验：与其他未断流数据源最新价的中位数比较
+    fn check_cross_source(
+        &self,
+        event: &MarketEvent,
+        price: Decimal,
+        now: DateTime<Utc>,
+    ) -> Option<QualityIssue> {
+        let state = self.symbols.get(&event.symbol)?;
+        let stale = Duration::seconds(self.config.stale_secs);
+
+        let mut others: Vec<Decimal> = state
+            .feeds
+            .iter()
+            .filter(|(exchange, _)| exchange.as_str() != event.exchange)
+            .filter(|(_, feed)| feed.last_update.is_some_and(|t| now - t <= stale))
+            .filter_map(|(_, feed)| feed.last_price)
+            .collect();
+        if others.is_empty() {
+            return None;
+        }
+        others.sort();
+        let reference = others[others.len() / 2];
+        if reference <= Decimal::ZERO {
+            return None;
+        }
+
+        let deviation_pct = ((price - reference).abs() / reference * Decimal::ONE_HUNDRED)
+            .to_f64()
+            .unwrap_or(0.0);
+        if deviation_pct > self.config.max_cross_deviation_pct {
+            Some(QualityIssue::CrossSourceDeviation {
+                exchange: event.exchange.clone(),
+                price,
+                reference,
+                deviation_pct,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// 重新计算交易对质量，状态变化时返回事件
+    fn reevaluate(&mut self, symbol: &str, now: DateTime<Utc>) -> Option<DataQualityEvent> {
+        let recovery = Duration::seconds(self.config.recovery_secs);
+        let stale = Duration::seconds(self.config.stale_secs);
+
+        let state = self.symbols.get_mut(symbol)?;
+        state.issues.retain(|(at, _)| now - *at < recovery);
+
+        let has_stale_feed = state
+            .feeds
+            .values()
+            .any(|f| f.last_update.is_some_and(|t| now - t > stale));
+
+        let previous = state.quality;
+        let next = if !state.issues.is_empty() || has_stale_feed {
+            DataQuality::Suspect
+        } else {
+            match previous {
+                DataQuality::Suspect => DataQuality::Recovered,
+                DataQuality::Recovered
+                    if state.recovered_at.is_some_and(|t| now - t < recovery) =>
+                {
+                    DataQuality::Recovered
+                }
+                _ => DataQuality::Normal,
+            }
+        };
+
+        if next == previous {
+            return None;
+        }
+        if next == DataQuality::Recovered {
+            state.recovered_at = Some(now);
+        }
+        state.quality = next;
+
+        let state = self.symbols.get(symbol)?;
+        Some(self.build_event(symbol, state, now))
+    }
+
+    fn build_event(&self, symbol: &str, state: &SymbolState, now: DateTime<Utc>) -> DataQualityEvent {
+        let stale = Duration::seconds(self.config.stale_secs);
+        let mut issues: Vec<QualityIssue> = state.issues.iter().map(|(_, i)| i.clone()).collect();
+        for (exchange, feed) in &state.feeds {
+            if let Some(t) = feed.last_update {
+                if now - t > stale {
+                    issues.push(QualityIssue::Stale {
+                        exchange: exchange.clone(),
+                        silent_secs: (now - t).num_seconds(),
+                    });
+                }
+            }
+        }
+
+        DataQualityEvent {
+            symbol: symbol.to_string(),
+            quality: state.quality,
+            issues,
+            last_update: state.last_update(),
+            timestamp: now,
+        }
+    }
+}
+
+/// 异常价格检测：收益率超过 k·σ，并维护收益率窗口
+fn check_spike(
+    config: &QualityMonitorConfig,
+    feed: &mut FeedState,
+    exchange: &str,
+    previous: Decimal,
+    price: Decimal,
+) -> Option<QualityIssue> {
+    let prev = previous.to_f64().filter(|p| *p > 0.0)?;
+    let ret = (price.to_f64()? - prev) / prev;
+
+    let mut issue = None;
+    if feed.returns.len() >= config.spike_min_samples {
+        let n = feed.returns.len() as f64;
+        let mean = feed.returns.iter().sum::<f64>() / n;
+        let variance = feed.returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / n;
+        let sigma = variance.sqrt();
+        if sigma > 0.0 && ret.abs() >= config.spike_min_return {
+            let multiple = (ret - mean).abs() / sigma;
+            if multiple > config.spike_sigma {
+                issue = Some(QualityIssue::PriceSpike {
+                    exchange: exchange.to_string(),
+                    price,
+                    previous,
+                    sigma_multiple: multiple,
+                });
+            }
+        }
+    }
+
+    feed.returns.push_back(ret);
+    while feed.returns.len() > config.spike_window {
+        feed.returns.pop_front();
+    }
+    issue
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::event::market_event::{DepthData, MarketEventType, TickData, TradeData};
+
+    fn d(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn t(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    fn trade(exchange: &str, trade_id: &str, price: Decimal) -> MarketEvent {
+        MarketEvent {
+            event_type: MarketEventType::Trade,
+            exchange: exchange.to_string(),
+            symbol: "BTCUSDT".to_string(),
+            timestamp: t(0),
+            data: MarketEventData::Trade(TradeData {
+                trade_id: trade_id.to_string(),
+                price,
+                quantity: d("1"),
+                is_buyer_maker: false,
+            }),
+        }
+    }
+
+    fn depth(bid: Decimal, ask: Decimal) -> MarketEvent {
+        MarketEvent {
+            event_type: MarketEventType::Depth,
+            exchange: "binance".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            timestamp: t(0),
+            data: MarketEventData::Depth(DepthData {
+                bids: vec![(bid, d("1"))],
+                asks: vec![(ask, d("1"))],
+            }),
+        }
+    }
+
+    fn tick(bid: Decimal, ask: Decimal) -> MarketEvent {
+        MarketEvent {
+            event_type: MarketEventType::Tick,
+            exchange: "binance".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            timestamp: t(0),
+            data: MarketEventData::Tick(TickData {
+                price: bid,
+                volume_24h: Decimal::ZERO,
+                change_24h: Decimal::ZERO,
+                bid,
+                ask,
+            }),
+        }
+    }
+
+    fn issues(monitor: &QualityMonitor, now: DateTime<Utc>) -> Vec<QualityIssue> {
+        monitor
+            .snapshot(now)
+            .into_iter()
+            .flat_map(|e| e.issues)
+            .collect()
+    }
+
+    #[test]
+    fn test_stale_feed_turns_suspect() {
+        let mut monitor = QualityMonitor::new(QualityMonitorConfig::default());
+        let first = monitor.observe(&trade("binance", "1", d("100")), t(0)).unwrap();
+        assert_eq!(first.quality, DataQuality::Normal);
+
+        assert!(monitor.check(t(30)).is_empty());
+        let changes = monitor.check(t(31));
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].quality, DataQuality::Suspect);
+        assert!(matches!(
+            changes[0].issues.as_slice(),
+            [QualityIssue::Stale { silent_secs: 31, .. }]
+        ));
+    }
+
+    #[test]
+    fn test_price_spike_detected_after_min_samples() {
+        let config = QualityMonitorConfig {
+            spike_min_samples: 10,
+            ..QualityMonitorConfig::default()
+        };
+        let mut monitor = QualityMonitor::new(config);
+        for i in 0..12 {
+            let price = if i % 2 == 0 { d("100") } else { d("100.1") };
+            monitor.observe(&trade("okx", "x", price), t(0));
+        }
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Normal);
+
+        let change = monitor.observe(&trade("okx", "x", d("110")), t(1)).unwrap();
+        assert_eq!(change.quality, DataQuality::Suspect);
+        assert!(matches!(
+            change.issues.as_slice(),
+            [QualityIssue::PriceSpike { previous, .. }] if *previous == d("100.1")
+        ));
+    }
+
+    #[test]
+    fn test_small_move_is_not_a_spike_in_calm_market() {
+        let config = QualityMonitorConfig {
+            spike_min_samples: 10,
+            ..QualityMonitorConfig::default()
+        };
+        let mut monitor = QualityMonitor::new(config);
+        for i in 0..12 {
+            let price = if i % 2 == 0 { d("100") } else { d("100.001") };
+            monitor.observe(&trade("okx", "x", price), t(0));
+        }
+        // 远超 k·σ 但低于最小绝对收益率
+        monitor.observe(&trade("okx", "x", d("100.3")), t(1));
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Normal);
+    }
+
+    #[test]
+    fn test_crossed_book_from_depth_and_tick() {
+        let mut monitor = QualityMonitor::new(QualityMonitorConfig::default());
+        monitor.observe(&depth(d("99"), d("100")), t(0));
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Normal);
+
+        let change = monitor.observe(&depth(d("100"), d("100")), t(1)).unwrap();
+        assert_eq!(change.quality, DataQuality::Suspect);
+        assert!(matches!(
+            change.issues.as_slice(),
+            [QualityIssue::CrossedBook { best_bid, best_ask, .. }]
+                if *best_bid == d("100") && *best_ask == d("100")
+        ));
+
+        let mut monitor = QualityMonitor::new(QualityMonitorConfig::default());
+        monitor.observe(&tick(d("101"), d("100")), t(0));
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Suspect);
+    }
+
+    #[test]
+    fn test_sequence_gap_only_for_contiguous_exchanges() {
+        let mut monitor = QualityMonitor::new(QualityMonitorConfig::default());
+        monitor.observe(&trade("binance", "100", d("100")), t(0));
+        monitor.observe(&trade("binance", "101", d("100")), t(0));
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Normal);
+
+        let change = monitor.observe(&trade("binance", "105", d("100")), t(0)).unwrap();
+        assert_eq!(
+            change.issues,
+            vec![QualityIssue::SequenceGap {
+                exchange: "binance".to_string(),
+                expected: 102,
+                received: 105,
+            }]
+        );
+
+        // OKX 聚合成交的 ID 天然跳号
+        let mut monitor = QualityMonitor::new(QualityMonitorConfig::default());
+        monitor.observe(&trade("okx", "100", d("100")), t(0));
+        monitor.observe(&trade("okx", "180", d("100")), t(0));
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Normal);
+    }
+
+    #[test]
+    fn test_sequence_restarts_after_reconnect() {
+        let mut monitor = QualityMonitor::new(QualityMonitorConfig::default());
+        monitor.observe(&trade("binance", "100", d("100")), t(0));
+
+        monitor.reset_sequence("binance");
+        monitor.observe(&trade("binance", "250", d("100")), t(6));
+        monitor.observe(&trade("binance", "251", d("100")), t(6));
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Normal);
+        assert!(issues(&monitor, t(6)).is_empty());
+    }
+
+    #[test]
+    fn test_cross_source_deviation() {
+        let mut monitor = QualityMonitor::new(QualityMonitorConfig::default());
+        monitor.observe(&trade("binance", "1", d("100")), t(0));
+        monitor.observe(&trade("bybit", "a", d("100.5")), t(0));
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Normal);
+
+        let change = monitor.observe(&trade("okx", "1", d("105")), t(0)).unwrap();
+        assert!(matches!(
+            change.issues.as_slice(),
+            [QualityIssue::CrossSourceDeviation { exchange, reference, .. }]
+                if exchange == "okx" && *reference == d("100.5")
+        ));
+    }
+
+    #[test]
+    fn test_cross_source_ignores_stale_feeds() {
+        let mut monitor = QualityMonitor::new(QualityMonitorConfig::default());
+        monitor.observe(&trade("binance", "1", d("100")), t(0));
+        // binance 已断流，不作为参考价
+        monitor.observe(&trade("okx", "1", d("105")), t(31));
+        assert!(!issues(&monitor, t(31))
+            .iter()
+            .any(|i| matches!(i, QualityIssue::CrossSourceDeviation { .. })));
+    }
+
+    #[test]
+    fn test_state_machine_suspect_recovered_normal() {
+        let mut monitor = QualityMonitor::new(QualityMonitorConfig::default());
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Unknown);
+
+        monitor.observe(&depth(d("99"), d("100")), t(0));
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Normal);
+
+        monitor.observe(&depth(d("101"), d("100")), t(1));
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Suspect);
+
+        // 异常在观察期内仍生效
+        monitor.observe(&depth(d("99"), d("100")), t(30));
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Suspect);
+
+        // 异常过期 → Recovered
+        monitor.observe(&depth(d("99"), d("100")), t(61));
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Recovered);
+
+        // 观察期内无异常 → 保持 Recovered，期满 → Normal
+        monitor.observe(&depth(d("99"), d("100")), t(90));
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Recovered);
+        monitor.observe(&depth(d("99"), d("100")), t(121));
+        assert_eq!(monitor.quality("BTCUSDT"), DataQuality::Normal);
+    }
+}
@@ -1,9 +1,11 @@
 //! # 领域层 (Domain Layer)
 //!
-//! market-data 服务的领域层，只包含端口定义。
+//! market-data 服务的领域层，包含端口定义、读侧查询模型与质量监控逻辑。
 //!
 //! ## 说明
-//! market-data 是行情采集器，不定义领域模型。
 //! 行情事件类型定义在 shared::event::market_event 中。
+//! `model` 只定义历史行情查询（Read Side）的条件与结果。
 
+pub mod logic;
+pub mod model;
 pub mod port;
@@ -0,0 +1,264 @@
+//! # 行情查询模型 (Market Query Models)
+//!
+//! 行情读侧（Read Side）使用的查询条件与结果对象。
+//!
+//! ## 规则
+//! - ✅ 纯数据结构，不依赖任何存储实现
+//! - ✅ 由 `MarketStoragePort` 的读方法使用
+//! - ❌ 不包含 SQL / ClickHouse 类型
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+/// 查询时间范围（左闭右开 `[start, end)`）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeRange {
+    /// 开始时间（包含）
+    pub start: DateTime<Utc>,
+    /// 结束时间（不包含）
+    pub end: DateTime<Utc>,
+}
+
+impl TimeRange {
+    /// 创建时间范围，要求 `start < end`
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Self> {
+        if start >= end {
+            bail!("start must be earlier than end");
+        }
+        Ok(Self { start, end })
+    }
+}
+
+/// 成交分页游标
+///
+/// 按 `(event_time, trade_id)` 严格递增排序，游标指向上一页最后一条记录。
+/// 对外编码为不透明字符串 `"{event_time_ms}:{trade_id}"`。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradeCursor {
+    /// 上一页最后一条的事件时间（毫秒）
+    pub event_time_ms: i64,
+    /// 上一页最后一条的成交 ID
+    pub trade_id: String,
+}
+
+impl TradeCursor {
+    /// 编码为字符串
+    pub fn encode(&self) -> String {
+        format!("{}:{}", self.event_time_ms, self.trade_id)
+    }
+
+    /// 从字符串解码
+    pub fn decode(raw: &str) -> Result<Self> {
+        let (time, trade_id) = raw
+            .split_once(':')
+            .ok_or_else(|| anyhow!("invalid cursor: {}", raw))?;
+        let event_time_ms = time
+            .parse::<i64>()
+            .map_err(|_| anyhow!("invalid cursor: {}", raw))?;
+        Ok(Self {
+            event_time_ms,
+            trade_id: trade_id.to_string(),
+        })
+    }
+}
+
+/// 成交查询条件
+#[derive(Debug, Clone)]
+pub struct TradeQuery {
+    /// 交易对（大写）
+    pub symbol: String,
+    /// 时间范围
+    pub range: TimeRange,
+    /// 单页条数
+    pub limit: usize,
+    /// 分页游标（None 表示第一页）
+    pub cursor: Option<TradeCursor>,
+}
+
+/// 成交记录
+#[derive(Debug, Clone, Serialize)]
+pub struct TradeRecord {
+    /// 交易所
+    pub exchange: String,
+    /// 交易对
+    pub symbol: String,
+    /// 成交 ID
+    pub trade_id: String,
+    /// 成交价格
+    pub price: Decimal,
+    /// 成交数量
+    pub quantity: Decimal,
+    /// 买方是否为 maker
+    pub is_buyer_maker: bool,
+    /// 事件时间（毫秒时间戳）
+    pub event_time: i64,
+}
+
+impl TradeRecord {
+    /// 当前记录对应的分页游标
+    pub fn cursor(&self) -> TradeCursor {
+        TradeCursor {
+            event_time_ms: self.event_time,
+            trade_id: self.trade_id.clone(),
+        }
+    }
+}
+
+/// 成交分页结果
+#[derive(Debug, Clone)]
+pub struct TradePage {
+    /// 本页成交
+    pub trades: Vec<TradeRecord>,
+    /// 下一页游标（None 表示没有更多数据）
+    pub next_cursor: Option<TradeCursor>,
+}
+
+/// K 线周期
+///
+/// 以秒为单位，从成交数据在服务端重采样。
+/// 周期按 Unix 纪元对齐（日线对齐 UTC 零点）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KlineInterval {
+    seconds: u32,
+}
+
+impl KlineInterval {
+    /// 最大周期（7 天）
+    const MAX_SECONDS: u32 = 7 * 24 * 3600;
+
+    /// 解析周期字符串，如 `1s` / `1m` / `15m` / `1h` / `4h` / `1d` / `1w`
+    pub fn parse(raw: &str) -> Result<Self> {
+        let raw = raw.trim();
+        if raw.len() < 2 {
+            bail!("invalid interval: {}", raw);
+        }
+        let (value, unit) = raw.split_at(raw.len() - 1);
+        let value: u32 = value
+            .parse()
+            .map_err(|_| anyhow!("invalid interval: {}", raw))?;
+        let unit_seconds = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 3600,
+            "d" => 24 * 3600,
+            "w" => 7 * 24 * 3600,
+            _ => bail!("invalid interval unit: {} (allowed: s, m, h, d, w)", raw),
+        };
+        let seconds = value
+            .checked_mul(unit_seconds)
+            .filter(|s| *s > 0 && *s <= Self::MAX_SECONDS)
+            .ok_or_else(|| anyhow!("interval out of range: {}", raw))?;
+        Ok(Self { seconds })
+    }
+
+    /// 周期秒数
+    pub fn seconds(&self) -> u32 {
+        self.seconds
+    }
+
+    /// 周期毫秒数
+    pub fn millis(&self) -> i64 {
+        i64::from(self.seconds) * 1000
+    }
+}
+
+/// K 线查询条件
+#[derive(Debug, Clone)]
+pub struct KlineQuery {
+    /// 交易对（大写）
+    pub symbol: String,
+    /// K 线周期
+    pub interval: KlineInterval,
+    /// 时间范围
+    pub range: TimeRange,
+    /// 最多返回的 K 线根数
+    pub limit: usize,
+}
+
+/// K 线（由成交重采样得到）
+#[derive(Debug, Clone, Serialize)]
+pub struct Kline {
+    /// 开盘时间（毫秒）
+    pub open_time: i64,
+    /// 收盘时间（毫秒，不包含）
+    pub close_time: i64,
+    /// 开盘价
+    pub open: Decimal,
+    /// 最高价
+    pub high: Decimal,
+    /// 最低价
+    pub low: Decimal,
+    /// 收盘价
+    pub close: Decimal,
+    /// 成交量
+    pub volume: Decimal,
+    /// 成交笔数
+    pub trade_count: u64,
+}
+
+/// 最新价格快照
+#[derive(Debug, Clone, Serialize)]
+pub struct PriceSnapshot {
+    /// 交易对
+    pub symbol: String,
+    /// 最新成交价
+    pub price: Decimal,
+    /// 成交时间（毫秒）
+    pub event_time: i64,
+}
+
+/// 深度快照
+#[derive(Debug, Clone, Serialize)]
+pub struct DepthSnapshot {
+    /// 交易所
+    pub exchange: String,
+    /// 交易对
+    pub symbol: String,
+    /// 买盘 (价格, 数量)，价格降序
+    pub bids: Vec<(Decimal, Decimal)>,
+    /// 卖盘 (价格, 数量)，价格升序
+    pub asks: Vec<(Decimal, Decimal)>,
+    /// 快照时间（毫秒）
+    pub event_time: i64,
+}
+
+/// 毫秒时间戳转换为 UTC 时间
+pub fn millis_to_datetime(ms: i64) -> Result<DateTime<Utc>> {
+    Utc.timestamp_millis_opt(ms)
+        .single()
+        .ok_or_else(|| anyhow!("invalid timestamp: {}", ms))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kline_interval_parse() {
+        assert_eq!(KlineInterval::parse("1s").unwrap().seconds(), 1);
+        assert_eq!(KlineInterval::parse("15m").unwrap().seconds(), 900);
+        assert_eq!(KlineInterval::parse(" 4h ").unwrap().seconds(), 14_400);
+        assert_eq!(KlineInterval::parse("1d").unwrap().millis(), 86_400_000);
+        assert_eq!(KlineInterval::parse("1w").unwrap().seconds(), 604_800);
+
+        for raw in ["", "m", "0m", "1x", "-1m", "1.5h", "8d", "99999999999w"] {
+            assert!(KlineInterval::parse(raw).is_err(), "{}", raw);
+        }
+    }
+
+    #[test]
+    fn test_trade_cursor_round_trip() {
+        let cursor = TradeCursor {
+            event_time_ms: 1_700_000_000_000,
+            trade_id: "abc:123".to_string(),
+        };
+        // trade_id 中可以包含分隔符，只按第一个 ':' 切分
+        assert_eq!(TradeCursor::decode(&cursor.encode()).unwrap(), cursor);
+
+        for raw in ["", "123", "abc:1", ":1"] {
+            assert!(TradeCursor::decode(raw).is_err(), "{}", raw);
+        }
+    }
+}
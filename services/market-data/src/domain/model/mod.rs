@@ -0,0 +1,15 @@
+//! # 领域模型 (Domain Models)
+//!
+//! market-data 读侧使用的查询模型、多交易所共用的交易对映射表，以及行情录制模型。
+//! 行情事件本身定义在 shared::event::market_event 中。
+
+pub mod market_query;
+pub mod recording;
+pub mod symbol_map;
+
+pub use market_query::{
+    DepthSnapshot, Kline, KlineInterval, KlineQuery, PriceSnapshot, TimeRange, TradeCursor,
+    TradePage, TradeQuery, TradeRecord,
+};
+pub use symbol_map::{MarketExchange, SymbolMap};
+pub use recording::{RecordedEvent, RecordedRaw, ReplaySpeed, ReplayStats};
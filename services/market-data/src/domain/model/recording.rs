@@ -0,0 +1,82 @@
+//! # 行情录制模型 (Market Recording Models)
+//!
+//! 行情录制与确定性回放使用的数据结构。
+//!
+//! ## 说明
+//! - 录制顺序即系统看到行情的顺序，由 `seq` 唯一确定
+//! - 回放按 `recorded_at` 控制节奏，事件本身（含原始 `timestamp`）原样发布
+//!
+//! ## 规则
+//! - ✅ 纯数据结构，不依赖任何文件格式实现
+//! - ❌ 不包含压缩 / IO 细节
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::event::market_event::MarketEvent;
+
+/// 录制的标准化行情事件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// 录制序号（进程内严格递增）
+    pub seq: u64,
+    /// 系统收到事件的时间
+    pub recorded_at: DateTime<Utc>,
+    /// 标准化行情事件
+    pub event: MarketEvent,
+}
+
+/// 录制的交易所原始消息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedRaw {
+    /// 录制序号（与标准化事件共用序列）
+    pub seq: u64,
+    /// 系统收到消息的时间
+    pub recorded_at: DateTime<Utc>,
+    /// 交易所标识
+    pub exchange: String,
+    /// 原始消息文本
+    pub raw: String,
+}
+
+/// 回放速度
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplaySpeed {
+    /// 按录制时间间隔的倍速回放（1.0 为原速）
+    Multiplier(f64),
+    /// 不等待，尽快回放
+    AsFastAsPossible,
+}
+
+impl ReplaySpeed {
+    /// 解析回放速度
+    ///
+    /// 支持 `1` / `10x` / `0.5` / `max`。
+    pub fn parse(raw: &str) -> Result<Self> {
+        let raw = raw.trim().to_lowercase();
+        if raw == "max" || raw == "fast" {
+            return Ok(ReplaySpeed::AsFastAsPossible);
+        }
+        let value: f64 = raw
+            .trim_end_matches('x')
+            .parse()
+            .map_err(|_| anyhow::anyhow!("invalid replay speed: {}", raw))?;
+        if !value.is_finite() || value <= 0.0 {
+            bail!("replay speed must be positive: {}", raw);
+        }
+        Ok(ReplaySpeed::Multiplier(value))
+    }
+}
+
+/// 回放结果统计
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReplayStats {
+    /// 已发布事件数
+    pub published: u64,
+    /// 发布失败事件数
+    pub failed: u64,
+    /// 第一条事件的录制时间
+    pub first_recorded_at: Option<DateTime<Utc>>,
+    /// 最后一条事件的录制时间
+    pub last_recorded_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,201 @@
+//! # 交易对映射表 (Symbol Mapping Table)
+//!
+//! 统一的交易对命名规则：系统内部使用标准交易对（大写、无分隔符，如 `BTCUSDT`），
+//! 各交易所适配器在订阅与解析时通过本表与交易所原生格式互转。
+//!
+//! ## 默认规则
+//! | 交易所 | 原生格式 | 示例 |
+//! |--------|----------|------|
+//! | binance | `BTCUSDT` | 与标准格式相同 |
+//! | bybit | `BTCUSDT` | 与标准格式相同 |
+//! | okx | `BTC-USDT` | `BASE-QUOTE` |
+//! | coinbase | `BTC-USDT` | `BASE-QUOTE` |
+//!
+//! 默认规则不适用时（如 Coinbase 只有 `BTC-USD`），通过覆盖项指定：
+//! `coinbase:BTCUSDT=BTC-USD,okx:XBTUSDT=BTC-USDT`
+//!
+//! ## 规则
+//! - ✅ 纯数据结构，不依赖任何交易所 SDK
+//! - ✅ 事件中的 `symbol` 一律为标准格式，跨交易所可直接比较
+
+use std::collections::HashMap;
+use std::fmt;
+
+use anyhow::{anyhow, bail, Result};
+
+/// 支持的行情交易所
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MarketExchange {
+    Binance,
+    Okx,
+    Bybit,
+    Coinbase,
+}
+
+impl MarketExchange {
+    /// 交易所标识（写入 `MarketEvent::exchange`）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarketExchange::Binance => "binance",
+            MarketExchange::Okx => "okx",
+            MarketExchange::Bybit => "bybit",
+            MarketExchange::Coinbase => "coinbase",
+        }
+    }
+
+    /// 从标识解析（大小写不敏感）
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_lowercase().as_str() {
+            "binance" => Some(MarketExchange::Binance),
+            "okx" => Some(MarketExchange::Okx),
+            "bybit" => Some(MarketExchange::Bybit),
+            "coinbase" => Some(MarketExchange::Coinbase),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MarketExchange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// 拆分标准交易对时识别的计价币（按长度优先匹配）
+const QUOTE_ASSETS: [&str; 8] = ["FDUSD", "USDT", "USDC", "BUSD", "USD", "EUR", "BTC", "ETH"];
+
+/// 交易对映射表
+#[derive(Debug, Clone, Default)]
+pub struct SymbolMap {
+    /// 覆盖项：(交易所, 标准交易对) → 原生交易对
+    overrides: HashMap<(MarketExchange, String), String>,
+}
+
+impl SymbolMap {
+    /// 创建只使用默认规则的映射表
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 从覆盖项字符串解析
+    ///
+    /// 格式: `exchange:CANONICAL=NATIVE`，多项以逗号分隔，空字符串表示无覆盖。
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut map = Self::new();
+        for item in spec.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let (exchange, pair) = item
+                .split_once(':')
+                .ok_or_else(|| anyhow!("invalid symbol mapping: {}", item))?;
+            let exchange = MarketExchange::parse(exchange)
+                .ok_or_else(|| anyhow!("unknown exchange in symbol mapping: {}", item))?;
+            let (canonical, native) = pair
+                .split_once('=')
+                .ok_or_else(|| anyhow!("invalid symbol mapping: {}", item))?;
+            if canonical.trim().is_empty() || native.trim().is_empty() {
+                bail!("invalid symbol mapping: {}", item);
+            }
+            map.insert(exchange, canonical, native.trim());
+        }
+        Ok(map)
+    }
+
+    /// 添加覆盖项
+    pub fn insert(&mut self, exchange: MarketExchange, canonical: &str, native: &str) {
+        self.overrides
+            .insert((exchange, canonical.trim().to_uppercase()), native.to_string());
+    }
+
+    /// 标准交易对 → 交易所原生交易对
+    pub fn to_exchange(&self, exchange: MarketExchange, canonical: &str) -> String {
+        let canonical = canonical.trim().to_uppercase();
+        if let Some(native) = self.overrides.get(&(exchange, canonical.clone())) {
+            return native.clone();
+        }
+
+        match exchange {
+            MarketExchange::Binance | MarketExchange::Bybit => canonical,
+            MarketExchange::Okx | MarketExchange::Coinbase => match split_symbol(&canonical) {
+                Some((base, quote)) => format!("{}-{}", base, quote),
+                None => canonical,
+            },
+        }
+    }
+
+    /// 构建订阅用的反向表：原生交易对 → 标准交易对
+    pub fn native_index(
+        &self,
+        exchange: MarketExchange,
+        canonical_symbols: &[String],
+    ) -> HashMap<String, String> {
+        canonical_symbols
+            .iter()
+            .map(|s| (self.to_exchange(exchange, s), s.trim().to_uppercase()))
+            .collect()
+    }
+}
+
+/// 拆分标准交易对为 (base, quote)
+fn split_symbol(canonical: &str) -> Option<(&str, &str)> {
+    QUOTE_ASSETS.iter().find_map(|quote| {
+        canonical
+            .strip_suffix(quote)
+            .filter(|base| !base.is_empty())
+            .map(|base| (base, *quote))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_symbol() {
+        assert_eq!(split_symbol("BTCUSDT"), Some(("BTC", "USDT")));
+        assert_eq!(split_symbol("BTCFDUSD"), Some(("BTC", "FDUSD")));
+        assert_eq!(split_symbol("ETHUSDC"), Some(("ETH", "USDC")));
+        assert_eq!(split_symbol("BTCUSD"), Some(("BTC", "USD")));
+        assert_eq!(split_symbol("ETHBTC"), Some(("ETH", "BTC")));
+        assert_eq!(split_symbol("USDT"), None);
+        assert_eq!(split_symbol("BTCXYZ"), None);
+    }
+
+    #[test]
+    fn test_default_rules() {
+        let map = SymbolMap::new();
+        assert_eq!(map.to_exchange(MarketExchange::Binance, "btcusdt"), "BTCUSDT");
+        assert_eq!(map.to_exchange(MarketExchange::Bybit, "BTCUSDT"), "BTCUSDT");
+        assert_eq!(map.to_exchange(MarketExchange::Okx, "BTCUSDT"), "BTC-USDT");
+        assert_eq!(map.to_exchange(MarketExchange::Coinbase, "ETHUSDC"), "ETH-USDC");
+        // 无法识别计价币时原样返回
+        assert_eq!(map.to_exchange(MarketExchange::Okx, "BTCXYZ"), "BTCXYZ");
+    }
+
+    #[test]
+    fn test_parse_overrides() {
+        let spec = " coinbase:BTCUSDT=BTC-USD, okx:xbtusdt=BTC-USDT,binance:PEPEUSDT=1000PEPEUSDT ";
+        let map = SymbolMap::parse(spec).unwrap();
+        assert_eq!(map.to_exchange(MarketExchange::Coinbase, "BTCUSDT"), "BTC-USD");
+        assert_eq!(map.to_exchange(MarketExchange::Okx, "XBTUSDT"), "BTC-USDT");
+        assert_eq!(map.to_exchange(MarketExchange::Binance, "PEPEUSDT"), "1000PEPEUSDT");
+        // 覆盖项只作用于指定交易所
+        assert_eq!(map.to_exchange(MarketExchange::Okx, "BTCUSDT"), "BTC-USDT");
+
+        assert!(SymbolMap::parse("").unwrap().overrides.is_empty());
+        assert!(SymbolMap::parse("kraken:BTCUSDT=XBT/USDT").is_err());
+        assert!(SymbolMap::parse("coinbase:BTCUSDT").is_err());
+        assert!(SymbolMap::parse("BTCUSDT=BTC-USD").is_err());
+        assert!(SymbolMap::parse("coinbase:=BTC-USD").is_err());
+    }
+
+    #[test]
+    fn test_native_index() {
+        let map = SymbolMap::parse("coinbase:BTCUSDT=BTC-USD").unwrap();
+        let index = map.native_index(
+            MarketExchange::Coinbase,
+            &["btcusdt".to_string(), "ETHUSDT".to_string()],
+        );
+        assert_eq!(index.len(), 2);
+        assert_eq!(index["BTC-USD"], "BTCUSDT");
+        assert_eq!(index["ETH-USDT"], "ETHUSDT");
+    }
+}
@@ -25,11 +25,19 @@ pub trait MarketExchangePort: Send + Sync {
 
     /// 获取下一个行情事件
     async fn next_event(&self) -> anyhow::Result<MarketEvent>;
+
+    /// 最近一次 `next_event` 返回的事件所属的连接会话序号
+    ///
+    /// 每次（重）连接成功后递增；序号变化说明中间可能丢失了行情。
+    /// 不区分连接的实现返回 0。
+    fn session(&self) -> u64 {
+        0
+    }
 }
 
-// Arc<T> 自动实现 MarketExchangePort
+// Arc<T> 自动实现 MarketExchangePort（含 Arc<dyn MarketExchangePort>）
 #[async_trait]
-impl<T: MarketExchangePort> MarketExchangePort for Arc<T> {
+impl<T: MarketExchangePort + ?Sized> MarketExchangePort for Arc<T> {
     async fn connect(&self) -> anyhow::Result<()> {
         (**self).connect().await
     }
@@ -45,4 +53,8 @@ impl<T: MarketExchangePort> MarketExchangePort for Arc<T> {
     async fn next_event(&self) -> anyhow::Result<MarketEvent> {
         (**self).next_event().await
     }
+
+    fn session(&self) -> u64 {
+        (**self).session()
+    }
 }
@@ -1,6 +1,6 @@
 //! # 消息推送端口 (Message Port)
 //!
-//! 定义发布行情事件的抽象接口。
+//! 定义发布行情事件与数据质量事件的抽象接口。
 //!
 //! ## 规则
 //! - 只定义 trait，不包含实现
@@ -10,6 +10,7 @@
 use std::sync::Arc;
 use async_trait::async_trait;
 use shared::event::market_event::MarketEvent;
+use shared::event::quality_event::DataQualityEvent;
 
 /// 消息推送端口
 #[async_trait]
@@ -18,10 +19,25 @@ pub trait MessagePort: Send + Sync {
     async fn publish(&self, event: MarketEvent) -> anyhow::Result<()>;
 }
 
-// Arc<T> 自动实现 MessagePort
+// Arc<T> 自动实现 MessagePort（含 Arc<dyn MessagePort>）
 #[async_trait]
-impl<T: MessagePort> MessagePort for Arc<T> {
+impl<T: MessagePort + ?Sized> MessagePort for Arc<T> {
     async fn publish(&self, event: MarketEvent) -> anyhow::Result<()> {
         (**self).publish(event).await
     }
 }
+
+/// 数据质量推送端口
+#[async_trait]
+pub trait QualityMessagePort: Send + Sync {
+    /// 发布交易对数据质量状态
+    async fn publish_quality(&self, event: &DataQualityEvent) -> anyhow::Result<()>;
+}
+
+// Arc<T> 自动实现 QualityMessagePort
+#[async_trait]
+impl<T: QualityMessagePort> QualityMessagePort for Arc<T> {
+    async fn publish_quality(&self, event: &DataQualityEvent) -> anyhow::Result<()> {
+        (**self).publish_quality(event).await
+    }
+}
@@ -5,12 +5,19 @@
 //! ## 包含端口
 //! - `MarketExchangePort`: 行情交易所端口
 //! - `MessagePort`: 消息推送端口
+//! - `QualityMessagePort`: 数据质量推送端口
 //! - `MarketStoragePort`: 行情存储端口
+//! - `SpillQueuePort`: 存储溢写队列端口
+//! - `MarketRecorderPort` / `RecordingSourcePort`: 行情录制与回放源端口
 
 pub mod market_exchange_port;
 pub mod message_port;
+pub mod recorder_port;
 pub mod storage_port;
+pub mod spill_queue_port;
 
 pub use market_exchange_port::MarketExchangePort;
-pub use message_port::MessagePort;
+pub use message_port::{MessagePort, QualityMessagePort};
+pub use recorder_port::{MarketRecorderPort, RecordedEventStream, RecordingSourcePort};
 pub use storage_port::MarketStoragePort;
+pub use spill_queue_port::{SpillQueuePort, SpillSegment};
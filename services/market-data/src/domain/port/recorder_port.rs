@@ -0,0 +1,63 @@
+//! # 行情录制端口 (Market Recorder Port)
+//!
+//! 定义行情录制（写）与录制读取（回放源）的抽象接口。
+//!
+//! ## 规则
+//! - 只定义 trait，不包含实现
+//! - 录制接口为同步、非阻塞，不能拖慢采集链路
+//! - 不暴露任何文件系统 / 压缩类型
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures_util::stream::BoxStream;
+use shared::event::market_event::MarketEvent;
+
+use crate::domain::model::{RecordedEvent, TimeRange};
+
+/// 行情录制端口
+pub trait MarketRecorderPort: Send + Sync {
+    /// 录制一条标准化行情事件
+    fn record_event(&self, event: &MarketEvent);
+
+    /// 录制一条交易所原始消息
+    fn record_raw(&self, exchange: &str, raw: &str);
+}
+
+// Arc<T> 自动实现 MarketRecorderPort
+impl<T: MarketRecorderPort + ?Sized> MarketRecorderPort for Arc<T> {
+    fn record_event(&self, event: &MarketEvent) {
+        (**self).record_event(event)
+    }
+
+    fn record_raw(&self, exchange: &str, raw: &str) {
+        (**self).record_raw(exchange, raw)
+    }
+}
+
+/// 录制事件流（按录制顺序）
+pub type RecordedEventStream = BoxStream<'static, RecordedEvent>;
+
+/// 录制读取端口（回放源）
+#[async_trait]
+pub trait RecordingSourcePort: Send + Sync {
+    /// 列出与时间范围相交的分区（按时间升序）
+    async fn partitions(&self, range: &TimeRange) -> Result<Vec<DateTime<Utc>>>;
+
+    /// 流式读取一个分区内的事件（按录制顺序，不整体载入内存）
+    async fn stream_partition(&self, partition: DateTime<Utc>) -> Result<RecordedEventStream>;
+}
+
+// Arc<T> 自动实现 RecordingSourcePort
+#[async_trait]
+impl<T: RecordingSourcePort + ?Sized> RecordingSourcePort for Arc<T> {
+    async fn partitions(&self, range: &TimeRange) -> Result<Vec<DateTime<Utc>>> {
+        (**self).partitions(range).await
+    }
+
+    async fn stream_partition(&self, partition: DateTime<Utc>) -> Result<RecordedEventStream> {
+        (**self).stream_partition(partition).await
+    }
+}
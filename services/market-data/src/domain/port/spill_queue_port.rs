@@ -0,0 +1,61 @@
+//! # 溢写队列端口 (Spill Queue Port)
+//!
+//! 存储不可用时，将待写入的行情批次暂存到本地，待存储恢复后回放。
+//!
+//! ## 规则
+//! - 只定义 trait，不包含实现
+//! - 以批次（segment）为单位写入与回放，回放成功后再删除
+//! - 不暴露任何文件系统类型
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use shared::event::market_event::MarketEvent;
+
+/// 溢写批次
+#[derive(Debug, Clone)]
+pub struct SpillSegment {
+    /// 批次 ID（按写入顺序递增）
+    pub id: String,
+    /// 批次内的行情事件
+    pub events: Vec<MarketEvent>,
+}
+
+/// 溢写队列端口
+#[async_trait]
+pub trait SpillQueuePort: Send + Sync {
+    /// 追加一个批次
+    ///
+    /// 超出容量上限时返回错误，调用方负责计入丢弃数。
+    async fn append(&self, events: &[MarketEvent]) -> Result<()>;
+
+    /// 读取最早的批次（不删除）
+    async fn oldest(&self) -> Result<Option<SpillSegment>>;
+
+    /// 删除已回放的批次
+    async fn remove(&self, id: &str) -> Result<()>;
+
+    /// 当前待回放的批次数
+    async fn pending_segments(&self) -> Result<usize>;
+}
+
+// Arc<T> 自动实现 SpillQueuePort
+#[async_trait]
+impl<T: SpillQueuePort> SpillQueuePort for Arc<T> {
+    async fn append(&self, events: &[MarketEvent]) -> Result<()> {
+        (**self).append(events).await
+    }
+
+    async fn oldest(&self) -> Result<Option<SpillSegment>> {
+        (**self).oldest().await
+    }
+
+    async fn remove(&self, id: &str) -> Result<()> {
+        (**self).remove(id).await
+    }
+
+    async fn pending_segments(&self) -> Result<usize> {
+        (**self).pending_segments().await
+    }
+}
@@ -2,13 +2,20 @@
 //!
 //! 定义行情数据存储的抽象接口。
 //! Infrastructure 层实现此 trait。
+//!
+//! ## 读写两侧
+//! - 写侧: `save_event` / `save_events`
+//! - 读侧: `query_trades` / `query_klines` / `latest_price` / `depth_snapshot`
 
 use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use shared::event::market_event::MarketEvent;
 
+use crate::domain::model::{DepthSnapshot, Kline, KlineQuery, PriceSnapshot, TradePage, TradeQuery};
+
 /// 行情存储端口
 ///
 /// 定义行情数据持久化的抽象接口。
@@ -20,6 +27,22 @@ pub trait MarketStoragePort: Send + Sync {
 
     /// 批量存储行情事件
     async fn save_events(&self, events: &[MarketEvent]) -> Result<()>;
+
+    /// 按时间范围分页查询成交（按 `(event_time, trade_id)` 升序）
+    async fn query_trades(&self, query: &TradeQuery) -> Result<TradePage>;
+
+    /// 从成交重采样 K 线（按开盘时间升序）
+    async fn query_klines(&self, query: &KlineQuery) -> Result<Vec<Kline>>;
+
+    /// 查询最新成交价
+    async fn latest_price(&self, symbol: &str) -> Result<Option<PriceSnapshot>>;
+
+    /// 查询不晚于 `at` 的最近一次深度快照（`at` 为 None 时取最新）
+    async fn depth_snapshot(
+        &self,
+        symbol: &str,
+        at: Option<DateTime<Utc>>,
+    ) -> Result<Option<DepthSnapshot>>;
 }
 
 /// 为 Arc<T> 实现 MarketStoragePort（blanket implementation）
@@ -32,4 +55,24 @@ impl<T: MarketStoragePort> MarketStoragePort for Arc<T> {
     async fn save_events(&self, events: &[MarketEvent]) -> Result<()> {
         (**self).save_events(events).await
     }
+
+    async fn query_trades(&self, query: &TradeQuery) -> Result<TradePage> {
+        (**self).query_trades(query).await
+    }
+
+    async fn query_klines(&self, query: &KlineQuery) -> Result<Vec<Kline>> {
+        (**self).query_klines(query).await
+    }
+
+    async fn latest_price(&self, symbol: &str) -> Result<Option<PriceSnapshot>> {
+        (**self).latest_price(symbol).await
+    }
+
+    async fn depth_snapshot(
+        &self,
+        symbol: &str,
+        at: Option<DateTime<Utc>>,
+    ) -> Result<Option<DepthSnapshot>> {
+        (**self).depth_snapshot(symbol, at).await
+    }
 }
@@ -8,28 +8,31 @@
 //! - 解析币安消息格式
 //! - 转换为标准 MarketEvent
 //! - 支持断线重连
+//! - 通过 `SymbolMap` 将原生交易对还原为标准交易对（支持 `binance:` 覆盖项）
+//! - 可选录制原始消息
 
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 use async_trait::async_trait;
 use chrono::Utc;
 use futures_util::{SinkExt, StreamExt};
 use rust_decimal::Decimal;
 use serde::Deserialize;
-use tokio::net::TcpStream;
 use tokio::sync::{mpsc, RwLock};
-use tokio_socks::tcp::Socks5Stream;
-use tokio_tungstenite::{
-    client_async_tls, connect_async, MaybeTlsStream, WebSocketStream,
-    tungstenite::{protocol::Message, Error as WsError},
-};
+use tokio_tungstenite::tungstenite::{protocol::Message, Error as WsError};
 use tracing::{debug, error, info, warn};
-use url::Url;
 
-use crate::domain::port::MarketExchangePort;
+use super::ws_connect::connect_ws;
+use crate::domain::model::{MarketExchange, SymbolMap};
+use crate::domain::port::{MarketExchangePort, MarketRecorderPort};
 use shared::event::market_event::{MarketEvent, MarketEventData, MarketEventType, TradeData};
 
+/// 附带所属连接会话序号的行情事件
+type SessionEvent = (u64, MarketEvent);
+
 /// 币安 Trade 消息格式
 #[derive(Debug, Deserialize)]
 struct BinanceTradeMsg {
@@ -108,12 +111,18 @@ pub struct BinanceWebSocket {
     ws_url: String,
     /// 代理地址（可选）
     proxy: Option<String>,
+    /// 交易对映射表
+    symbol_map: Arc<SymbolMap>,
     /// 事件接收通道
-    event_rx: Arc<RwLock<Option<mpsc::Receiver<MarketEvent>>>>,
+    event_rx: Arc<RwLock<Option<mpsc::Receiver<SessionEvent>>>>,
     /// 事件发送通道
-    event_tx: Arc<RwLock<Option<mpsc::Sender<MarketEvent>>>>,
+    event_tx: Arc<RwLock<Option<mpsc::Sender<SessionEvent>>>>,
+    /// 最近一次交付事件的连接会话序号
+    delivered_session: Arc<AtomicU64>,
     /// 状态
     state: Arc<RwLock<WsState>>,
+    /// 原始消息录制器（可选）
+    recorder: Option<Arc<dyn MarketRecorderPort>>,
 }
 
 impl BinanceWebSocket {
@@ -123,20 +132,35 @@ impl BinanceWebSocket {
     /// - `ws_url`: WebSocket URL，如 `wss://stream.binance.com:9443/ws`
     /// - `proxy`: 代理地址（可选），如 `http://127.0.0.1:4780`
     pub fn new(ws_url: String, proxy: Option<String>) -> Self {
-        let (tx, rx) = mpsc::channel::<MarketEvent>(10000);
+        let (tx, rx) = mpsc::channel::<SessionEvent>(10000);
         
         Self {
             ws_url,
             proxy,
+            symbol_map: Arc::new(SymbolMap::new()),
             event_rx: Arc::new(RwLock::new(Some(rx))),
             event_tx: Arc::new(RwLock::new(Some(tx))),
+            delivered_session: Arc::new(AtomicU64::new(0)),
             state: Arc::new(RwLock::new(WsState {
                 connected: false,
                 subscribed_symbols: Vec::new(),
             })),
+            recorder: None,
         }
     }
 
+    /// 使用交易对映射表（默认只使用默认规则）
+    pub fn with_symbol_map(mut self, symbol_map: Arc<SymbolMap>) -> Self {
+        self.symbol_map = symbol_map;
+        self
+    }
+
+    /// 录制原始消息
+    pub fn with_raw_recorder(mut self, recorder: Arc<dyn MarketRecorderPort>) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
     /// 从环境变量创建
     pub fn from_env() -> Self {
         let ws_url = std::env::var("BINANCE_WS_URL")
@@ -147,6 +171,9 @@ impl BinanceWebSocket {
     }
 
     /// 构建订阅 URL
+    ///
+    /// # 参数
+    /// - `symbols`: 交易所原生格式的交易对
     fn build_subscribe_url(&self, symbols: &[String]) -> String {
         if symbols.is_empty() {
             return self.ws_url.clone();
@@ -164,29 +191,37 @@ impl BinanceWebSocket {
     }
 
     /// 解析币安消息
-    fn parse_message(&self, text: &str) -> Option<MarketEvent> {
+    ///
+    /// # 参数
+    /// - `text`: 原始消息
+    /// - `symbols`: 原生交易对（大写）→ 标准交易对，未订阅的交易对被忽略
+    fn parse_message(&self, text: &str, symbols: &HashMap<String, String>) -> Option<MarketEvent> {
         // 尝试解析 combined stream 格式
         if let Ok(combined) = serde_json::from_str::<serde_json::Value>(text) {
             if let Some(data) = combined.get("data") {
-                return self.parse_trade_data(data);
+                return self.parse_trade_data(data, symbols);
             }
         }
 
         // 尝试直接解析 trade 消息
         if let Ok(value) = serde_json::from_str::<serde_json::Value>(text) {
-            return self.parse_trade_data(&value);
+            return self.parse_trade_data(&value, symbols);
         }
 
         None
     }
 
     /// 解析 trade 数据
-    fn parse_trade_data(&self, value: &serde_json::Value) -> Option<MarketEvent> {
+    fn parse_trade_data(
+        &self,
+        value: &serde_json::Value,
+        symbols: &HashMap<String, String>,
+    ) -> Option<MarketEvent> {
         let event_type = value.get("e")?.as_str()?;
 
         match event_type {
-            "trade" => self.parse_trade_msg(value),
-            "aggTrade" => self.parse_agg_trade_msg(value),
+            "trade" => self.parse_trade_msg(value, symbols),
+            "aggTrade" => self.parse_agg_trade_msg(value, symbols),
             _ => {
                 debug!("忽略未知事件类型: {}", event_type);
                 None
@@ -195,16 +230,21 @@ impl BinanceWebSocket {
     }
 
     /// 解析 Trade 消息
-    fn parse_trade_msg(&self, value: &serde_json::Value) -> Option<MarketEvent> {
+    fn parse_trade_msg(
+        &self,
+        value: &serde_json::Value,
+        symbols: &HashMap<String, String>,
+    ) -> Option<MarketEvent> {
         let msg: BinanceTradeMsg = serde_json::from_value(value.clone()).ok()?;
-        
+        let symbol = symbols.get(&msg.symbol.to_uppercase())?;
+
         let price = msg.price.parse::<Decimal>().ok()?;
         let quantity = msg.quantity.parse::<Decimal>().ok()?;
 
         Some(MarketEvent {
             event_type: MarketEventType::Trade,
             exchange: "binance".to_string(),
-            symbol: msg.symbol.to_uppercase(),
+            symbol: symbol.clone(),
             timestamp: Utc::now(),
             data: MarketEventData::Trade(TradeData {
                 trade_id: msg.trade_id.to_string(),
@@ -216,16 +256,21 @@ impl BinanceWebSocket {
     }
 
     /// 解析 AggTrade 消息
-    fn parse_agg_trade_msg(&self, value: &serde_json::Value) -> Option<MarketEvent> {
+    fn parse_agg_trade_msg(
+        &self,
+        value: &serde_json::Value,
+        symbols: &HashMap<String, String>,
+    ) -> Option<MarketEvent> {
         let msg: BinanceAggTradeMsg = serde_json::from_value(value.clone()).ok()?;
-        
+        let symbol = symbols.get(&msg.symbol.to_uppercase())?;
+
         let price = msg.price.parse::<Decimal>().ok()?;
         let quantity = msg.quantity.parse::<Decimal>().ok()?;
 
         Some(MarketEvent {
             event_type: MarketEventType::Trade,
             exchange: "binance".to_string(),
-            symbol: msg.symbol.to_uppercase(),
+            symbol: symbol.clone(),
             timestamp: Utc::now(),
             data: MarketEventData::Trade(TradeData {
                 trade_id: msg.agg_trade_id.to_string(),
@@ -236,111 +281,13 @@ impl BinanceWebSocket {
         })
     }
 
-    /// 创建 WebSocket 连接（支持代理）
-    async fn create_ws_connection(
-        &self,
-        url: &str,
-    ) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>> {
-        let parsed_url = Url::parse(url).context("解析 WebSocket URL 失败")?;
-        
-        match &self.proxy {
-            Some(proxy_url) if !proxy_url.is_empty() => {
-                info!("使用代理连接: {}", proxy_url);
-                self.connect_via_proxy(url, &parsed_url, proxy_url).await
-            }
-            _ => {
-                info!("直接连接（无代理）");
-                let (ws_stream, _) = connect_async(url)
-                    .await
-                    .context("连接 WebSocket 失败")?;
-                Ok(ws_stream)
-            }
-        }
-    }
-
-    /// 通过代理连接 WebSocket
-    async fn connect_via_proxy(
-        &self,
-        url: &str,
-        parsed_url: &Url,
-        proxy_url: &str,
-    ) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>> {
-        // 解析代理地址
-        let proxy_parsed = Url::parse(proxy_url).context("解析代理 URL 失败")?;
-        let proxy_host = proxy_parsed.host_str().context("代理缺少 host")?;
-        let proxy_port = proxy_parsed.port().unwrap_or(1080);
-        let proxy_addr = format!("{}:{}", proxy_host, proxy_port);
-
-        // 目标地址
-        let target_host = parsed_url.host_str().context("WebSocket URL 缺少 host")?;
-        let target_port = parsed_url.port().unwrap_or(if parsed_url.scheme() == "wss" { 443 } else { 80 });
-
-        info!(
-            "代理连接: {} -> {}:{}",
-            proxy_addr, target_host, target_port
-        );
-
-        // 根据代理类型选择连接方式
-        let scheme = proxy_parsed.scheme().to_lowercase();
-        
-        if scheme == "socks5" || scheme == "socks" {
-            // SOCKS5 代理
-            let stream = Socks5Stream::connect(
-                proxy_addr.as_str(),
-                (target_host, target_port),
-            )
-            .await
-            .context("SOCKS5 代理连接失败")?;
-
-            let tcp_stream = stream.into_inner();
-            let (ws_stream, _) = client_async_tls(url, tcp_stream)
-                .await
-                .context("WebSocket 握手失败")?;
-            Ok(ws_stream)
-        } else {
-            // HTTP 代理 - 使用 CONNECT 方法
-            let tcp_stream = TcpStream::connect(&proxy_addr)
-                .await
-                .context("连接 HTTP 代理失败")?;
-
-            // 发送 CONNECT 请求
-            let connect_req = format!(
-                "CONNECT {}:{} HTTP/1.1\r\nHost: {}:{}\r\n\r\n",
-                target_host, target_port, target_host, target_port
-            );
-
-            use tokio::io::{AsyncReadExt, AsyncWriteExt};
-            let mut tcp_stream = tcp_stream;
-            tcp_stream
-                .write_all(connect_req.as_bytes())
-                .await
-                .context("发送 CONNECT 请求失败")?;
-
-            // 读取响应
-            let mut buf = [0u8; 1024];
-            let n = tcp_stream
-                .read(&mut buf)
-                .await
-                .context("读取代理响应失败")?;
-            let response = String::from_utf8_lossy(&buf[..n]);
-
-            if !response.contains("200") {
-                return Err(anyhow::anyhow!("HTTP 代理连接失败: {}", response.trim()));
-            }
-
-            info!("HTTP 代理隧道建立成功");
-
-            // 升级到 WebSocket
-            let (ws_stream, _) = client_async_tls(url, tcp_stream)
-                .await
-                .context("WebSocket 握手失败")?;
-            Ok(ws_stream)
-        }
-    }
-
     /// 启动 WebSocket 连接循环（内部使用）
-    async fn run_ws_loop(&self, symbols: Vec<String>) -> Result<()> {
-        let url = self.build_subscribe_url(&symbols);
+    ///
+    /// # 参数
+    /// - `symbols`: 原生交易对（大写）→ 标准交易对
+    async fn run_ws_loop(&self, symbols: HashMap<String, String>) -> Result<()> {
+        let native: Vec<String> = symbols.keys().cloned().collect();
+        let url = self.build_subscribe_url(&native);
         info!("连接币安 WebSocket: {}", url);
 
         // 获取发送通道
@@ -356,8 +303,10 @@ impl BinanceWebSocket {
             }
         };
 
+        let mut session = 0;
         loop {
-            match self.connect_and_receive(&url, &tx).await {
+            session += 1;
+            match self.connect_and_receive(&url, &symbols, session, &tx).await {
                 Ok(_) => {
                     info!("WebSocket 连接正常关闭，5秒后重连...");
                 }
@@ -380,10 +329,12 @@ impl BinanceWebSocket {
     async fn connect_and_receive(
         &self,
         url: &str,
-        tx: &mpsc::Sender<MarketEvent>,
+        symbols: &HashMap<String, String>,
+        session: u64,
+        tx: &mpsc::Sender<SessionEvent>,
     ) -> Result<()> {
         // 根据是否有代理选择不同的连接方式
-        let ws_stream = self.create_ws_connection(url).await?;
+        let ws_stream = connect_ws(url, self.proxy.as_deref()).await?;
 
         info!("WebSocket 连接成功");
 
@@ -409,7 +360,10 @@ impl BinanceWebSocket {
         while let Some(msg_result) = read.next().await {
             match msg_result {
                 Ok(Message::Text(text)) => {
-                    if let Some(event) = self.parse_message(&text) {
+                    if let Some(ref recorder) = self.recorder {
+                        recorder.record_raw("binance", &text);
+                    }
+                    if let Some(event) = self.parse_message(&text, symbols) {
                         debug!(
                             "收到行情: {} @ {}",
                             event.symbol,
@@ -419,7 +373,7 @@ impl BinanceWebSocket {
                             }
                         );
                         
-                        if tx.send(event).await.is_err() {
+                        if tx.send((session, event)).await.is_err() {
                             warn!("事件通道已关闭");
                             break;
                         }
@@ -465,7 +419,13 @@ impl MarketExchangePort for BinanceWebSocket {
 
     /// 订阅现货行情
     async fn subscribe_spot(&self, symbols: Vec<String>) -> Result<()> {
-        info!("订阅现货行情: {:?}", symbols);
+        let index: HashMap<String, String> = self
+            .symbol_map
+            .native_index(MarketExchange::Binance, &symbols)
+            .into_iter()
+            .map(|(native, canonical)| (native.to_uppercase(), canonical))
+            .collect();
+        info!(mapping = ?index, "订阅现货行情");
         
         // 保存订阅的交易对
         {
@@ -477,13 +437,16 @@ impl MarketExchangePort for BinanceWebSocket {
         let self_clone = BinanceWebSocket {
             ws_url: self.ws_url.clone(),
             proxy: self.proxy.clone(),
+            symbol_map: Arc::clone(&self.symbol_map),
             event_rx: Arc::clone(&self.event_rx),
             event_tx: Arc::clone(&self.event_tx),
+            delivered_session: Arc::clone(&self.delivered_session),
             state: Arc::clone(&self.state),
+            recorder: self.recorder.clone(),
         };
 
         tokio::spawn(async move {
-            if let Err(e) = self_clone.run_ws_loop(symbols).await {
+            if let Err(e) = self_clone.run_ws_loop(index).await {
                 error!("WebSocket 循环异常退出: {}", e);
             }
         });
@@ -507,11 +470,56 @@ impl MarketExchangePort for BinanceWebSocket {
         
         if let Some(ref mut rx) = *rx_guard {
             match rx.recv().await {
-                Some(event) => Ok(event),
+                Some((session, event)) => {
+                    self.delivered_session.store(session, Ordering::Relaxed);
+                    Ok(event)
+                }
                 None => Err(anyhow::anyhow!("事件通道已关闭")),
             }
         } else {
             Err(anyhow::anyhow!("事件接收器未初始化"))
         }
     }
+
+    fn session(&self) -> u64 {
+        self.delivered_session.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMBINED_TRADE: &str = r#"{"stream":"1000pepeusdt@trade","data":{
+        "e":"trade","E":1672515782136,"s":"1000PEPEUSDT","t":12345,"p":"0.00123","q":"100",
+        "b":88,"a":50,"T":1672515782136,"m":true,"M":true
+    }}"#;
+
+    #[test]
+    fn test_symbol_map_override_applies() {
+        let map = SymbolMap::parse("binance:PEPEUSDT=1000pepeusdt").unwrap();
+        let index = map
+            .native_index(MarketExchange::Binance, &["PEPEUSDT".to_string()])
+            .into_iter()
+            .map(|(native, canonical)| (native.to_uppercase(), canonical))
+            .collect::<HashMap<_, _>>();
+        let ws = BinanceWebSocket::new("wss://stream.binance.com:9443/ws".to_string(), None)
+            .with_symbol_map(Arc::new(map));
+
+        assert_eq!(
+            ws.build_subscribe_url(&index.keys().cloned().collect::<Vec<_>>()),
+            "wss://stream.binance.com:9443/stream?streams=1000pepeusdt@trade"
+        );
+
+        let event = ws.parse_message(COMBINED_TRADE, &index).unwrap();
+        assert_eq!(event.symbol, "PEPEUSDT");
+        let MarketEventData::Trade(trade) = event.data else {
+            panic!("expected trade");
+        };
+        assert_eq!(trade.trade_id, "12345");
+        assert!(trade.is_buyer_maker);
+
+        // 未订阅的交易对被忽略
+        assert!(ws.parse_message(COMBINED_TRADE, &HashMap::new()).is_none());
+    }
 }
@@ -0,0 +1,193 @@
+//! # Bybit WebSocket 适配器 (Bybit WebSocket Adapter)
+//!
+//! 基于 `WsMarketFeed` 实现 MarketExchangePort，订阅 Bybit V5 现货公共成交频道。
+//!
+//! ## 协议
+//! - 地址: `wss://stream.bybit.com/v5/public/spot`
+//! - 订阅: `{"op":"subscribe","args":["publicTrade.BTCUSDT"]}`（单次最多 10 个）
+//! - 心跳: `{"op":"ping"}`，建议 20 秒一次
+//! - `S` 为主动成交方向，`Buy` 表示买方为 taker
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde_json::json;
+use shared::event::market_event::MarketEvent;
+use tokio_tungstenite::tungstenite::protocol::Message;
+use tracing::debug;
+
+use super::ws_feed::{millis_to_utc, trade_event, WsMarketFeed, WsProtocol};
+use crate::domain::model::{MarketExchange, SymbolMap};
+
+/// 单条订阅消息最多包含的 topic 数
+const MAX_ARGS_PER_SUBSCRIBE: usize = 10;
+
+/// Bybit 推送消息
+#[derive(Debug, Deserialize)]
+struct BybitPushMsg {
+    #[serde(default)]
+    data: Vec<BybitTradeMsg>,
+}
+
+/// Bybit 成交消息
+#[derive(Debug, Deserialize)]
+struct BybitTradeMsg {
+    /// 成交时间（毫秒）
+    #[serde(rename = "T")]
+    trade_time: i64,
+    /// 交易对
+    #[serde(rename = "s")]
+    symbol: String,
+    /// 主动成交方向（Buy / Sell）
+    #[serde(rename = "S")]
+    side: String,
+    /// 成交数量
+    #[serde(rename = "v")]
+    quantity: String,
+    /// 成交价格
+    #[serde(rename = "p")]
+    price: String,
+    /// 成交 ID
+    #[serde(rename = "i")]
+    trade_id: String,
+}
+
+/// Bybit 协议描述
+pub struct BybitProtocol {
+    ws_url: String,
+}
+
+impl WsProtocol for BybitProtocol {
+    fn exchange(&self) -> MarketExchange {
+        MarketExchange::Bybit
+    }
+
+    fn ws_url(&self) -> &str {
+        &self.ws_url
+    }
+
+    fn subscribe_messages(&self, native_symbols: &[String]) -> Vec<String> {
+        native_symbols
+            .chunks(MAX_ARGS_PER_SUBSCRIBE)
+            .map(|chunk| {
+                let args: Vec<String> = chunk.iter().map(|s| format!("publicTrade.{}", s)).collect();
+                json!({ "op": "subscribe", "args": args }).to_string()
+            })
+            .collect()
+    }
+
+    fn heartbeat_interval(&self) -> Duration {
+        Duration::from_secs(20)
+    }
+
+    fn heartbeat_message(&self) -> Message {
+        Message::Text(json!({ "op": "ping" }).to_string())
+    }
+
+    fn parse(&self, text: &str, symbols: &HashMap<String, String>) -> Vec<MarketEvent> {
+        // 心跳回复与订阅回执（op 字段）不含成交数据
+        let Ok(msg) = serde_json::from_str::<BybitPushMsg>(text) else {
+            debug!("忽略 Bybit 消息: {}", text);
+            return Vec::new();
+        };
+
+        msg.data
+            .into_iter()
+            .filter_map(|t| {
+                let symbol = symbols.get(&t.symbol)?;
+                let price = t.price.parse::<Decimal>().ok()?;
+                let quantity = t.quantity.parse::<Decimal>().ok()?;
+                Some(trade_event(
+                    MarketExchange::Bybit,
+                    symbol,
+                    millis_to_utc(t.trade_time),
+                    t.trade_id,
+                    price,
+                    quantity,
+                    t.side != "Buy",
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Bybit WebSocket 适配器
+pub type BybitWebSocket = WsMarketFeed<BybitProtocol>;
+
+impl BybitWebSocket {
+    /// 创建 Bybit WebSocket 客户端
+    ///
+    /// # 参数
+    /// - `ws_url`: WebSocket URL，如 `wss://stream.bybit.com/v5/public/spot`
+    /// - `proxy`: 代理地址（可选）
+    /// - `symbol_map`: 交易对映射表
+    pub fn with_url(ws_url: String, proxy: Option<String>, symbol_map: Arc<SymbolMap>) -> Self {
+        WsMarketFeed::new(BybitProtocol { ws_url }, proxy, symbol_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::event::market_event::MarketEventData;
+
+    const TRADE_PUSH: &str = r#"{
+        "topic":"publicTrade.BTCUSDT","ts":1672304486868,"type":"snapshot","data":[
+        {"i":"2290000000061666327","T":1672304486865,"p":"16578.50","v":"0.001",
+         "S":"Buy","s":"BTCUSDT","BT":false},
+        {"i":"2290000000061666328","T":1672304486866,"p":"16578.00","v":"0.002",
+         "S":"Sell","s":"BTCUSDT","BT":false}
+    ]}"#;
+
+    fn protocol() -> BybitProtocol {
+        BybitProtocol { ws_url: String::new() }
+    }
+
+    fn symbols() -> HashMap<String, String> {
+        HashMap::from([("BTCUSDT".to_string(), "BTCUSDT".to_string())])
+    }
+
+    #[test]
+    fn test_parse_public_trade() {
+        let events = protocol().parse(TRADE_PUSH, &symbols());
+        assert_eq!(events.len(), 2);
+
+        let first = &events[0];
+        assert_eq!(first.exchange, "bybit");
+        assert_eq!(first.symbol, "BTCUSDT");
+        assert_eq!(first.timestamp.timestamp_millis(), 1_672_304_486_865);
+        let MarketEventData::Trade(trade) = &first.data else {
+            panic!("expected trade");
+        };
+        assert_eq!(trade.trade_id, "2290000000061666327");
+        assert_eq!(trade.price, "16578.50".parse::<Decimal>().unwrap());
+        assert_eq!(trade.quantity, "0.001".parse::<Decimal>().unwrap());
+        assert!(!trade.is_buyer_maker);
+
+        let MarketEventData::Trade(second) = &events[1].data else {
+            panic!("expected trade");
+        };
+        assert!(second.is_buyer_maker);
+    }
+
+    #[test]
+    fn test_ignore_control_and_unsubscribed() {
+        let protocol = protocol();
+        let pong = r#"{"success":true,"ret_msg":"pong","conn_id":"0970e817","op":"ping"}"#;
+        assert!(protocol.parse(pong, &symbols()).is_empty());
+        assert!(protocol.parse(TRADE_PUSH, &HashMap::new()).is_empty());
+    }
+
+    #[test]
+    fn test_subscribe_messages_are_chunked() {
+        let symbols: Vec<String> = (0..12).map(|i| format!("SYM{}USDT", i)).collect();
+        let messages = protocol().subscribe_messages(&symbols);
+        assert_eq!(messages.len(), 2);
+        let first: serde_json::Value = serde_json::from_str(&messages[0]).unwrap();
+        assert_eq!(first["args"].as_array().unwrap().len(), MAX_ARGS_PER_SUBSCRIBE);
+        assert_eq!(first["args"][0], "publicTrade.SYM0USDT");
+    }
+}
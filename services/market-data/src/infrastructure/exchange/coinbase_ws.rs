@@ -0,0 +1,168 @@
+//! # Coinbase WebSocket 适配器 (Coinbase WebSocket Adapter)
+//!
+//! 基于 `WsMarketFeed` 实现 MarketExchangePort，订阅 Coinbase Exchange `matches` 频道。
+//!
+//! ## 协议
+//! - 地址: `wss://ws-feed.exchange.coinbase.com`
+//! - 订阅: `{"type":"subscribe","product_ids":["BTC-USD"],"channels":["matches"]}`
+//! - 心跳: WebSocket Ping 帧
+//! - `side` 为 maker 订单方向，`buy` 表示买方为 maker
+//! - Coinbase 多数交易对以 USD 计价，需要通过映射表覆盖（如 `coinbase:BTCUSDT=BTC-USD`）
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde_json::json;
+use shared::event::market_event::MarketEvent;
+use tracing::debug;
+
+use super::ws_feed::{trade_event, WsMarketFeed, WsProtocol};
+use crate::domain::model::{MarketExchange, SymbolMap};
+
+/// Coinbase 成交消息（`match` / `last_match`）
+#[derive(Debug, Deserialize)]
+struct CoinbaseMatchMsg {
+    /// 消息类型
+    #[serde(rename = "type")]
+    msg_type: String,
+    /// 成交 ID
+    trade_id: u64,
+    /// 产品 ID，如 BTC-USD
+    product_id: String,
+    /// 成交数量
+    size: String,
+    /// 成交价格
+    price: String,
+    /// maker 订单方向
+    side: String,
+    /// 成交时间
+    time: Option<DateTime<Utc>>,
+}
+
+/// Coinbase 协议描述
+pub struct CoinbaseProtocol {
+    ws_url: String,
+}
+
+impl WsProtocol for CoinbaseProtocol {
+    fn exchange(&self) -> MarketExchange {
+        MarketExchange::Coinbase
+    }
+
+    fn ws_url(&self) -> &str {
+        &self.ws_url
+    }
+
+    fn subscribe_messages(&self, native_symbols: &[String]) -> Vec<String> {
+        vec![json!({
+            "type": "subscribe",
+            "product_ids": native_symbols,
+            "channels": ["matches"],
+        })
+        .to_string()]
+    }
+
+    fn parse(&self, text: &str, symbols: &HashMap<String, String>) -> Vec<MarketEvent> {
+        // subscriptions / error 等消息缺少成交字段，解析失败即忽略
+        let Ok(msg) = serde_json::from_str::<CoinbaseMatchMsg>(text) else {
+            debug!("忽略 Coinbase 消息: {}", text);
+            return Vec::new();
+        };
+        if msg.msg_type != "match" && msg.msg_type != "last_match" {
+            return Vec::new();
+        }
+
+        let Some(symbol) = symbols.get(&msg.product_id) else {
+            return Vec::new();
+        };
+        let (Ok(price), Ok(quantity)) = (msg.price.parse::<Decimal>(), msg.size.parse::<Decimal>())
+        else {
+            return Vec::new();
+        };
+
+        vec![trade_event(
+            MarketExchange::Coinbase,
+            symbol,
+            msg.time,
+            msg.trade_id.to_string(),
+            price,
+            quantity,
+            msg.side == "buy",
+        )]
+    }
+}
+
+/// Coinbase WebSocket 适配器
+pub type CoinbaseWebSocket = WsMarketFeed<CoinbaseProtocol>;
+
+impl CoinbaseWebSocket {
+    /// 创建 Coinbase WebSocket 客户端
+    ///
+    /// # 参数
+    /// - `ws_url`: WebSocket URL，如 `wss://ws-feed.exchange.coinbase.com`
+    /// - `proxy`: 代理地址（可选）
+    /// - `symbol_map`: 交易对映射表
+    pub fn with_url(ws_url: String, proxy: Option<String>, symbol_map: Arc<SymbolMap>) -> Self {
+        WsMarketFeed::new(CoinbaseProtocol { ws_url }, proxy, symbol_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::event::market_event::MarketEventData;
+
+    const MATCH: &str = r#"{
+        "type":"match","trade_id":10,"sequence":50,
+        "maker_order_id":"ac928c66-ca53-498f-9c13-a110027a60e8",
+        "taker_order_id":"132fb6ae-456b-4654-b4e0-d681ac05cea1",
+        "time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD",
+        "size":"5.23512","price":"400.23","side":"sell"
+    }"#;
+
+    fn protocol() -> CoinbaseProtocol {
+        CoinbaseProtocol { ws_url: String::new() }
+    }
+
+    fn symbols() -> HashMap<String, String> {
+        HashMap::from([("BTC-USD".to_string(), "BTCUSDT".to_string())])
+    }
+
+    #[test]
+    fn test_parse_match() {
+        let events = protocol().parse(MATCH, &symbols());
+        assert_eq!(events.len(), 1);
+
+        let event = &events[0];
+        assert_eq!(event.exchange, "coinbase");
+        // 通过映射表还原为标准交易对
+        assert_eq!(event.symbol, "BTCUSDT");
+        assert_eq!(
+            event.timestamp,
+            "2014-11-07T08:19:27.028459Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        let MarketEventData::Trade(trade) = &event.data else {
+            panic!("expected trade");
+        };
+        assert_eq!(trade.trade_id, "10");
+        assert_eq!(trade.price, "400.23".parse::<Decimal>().unwrap());
+        assert_eq!(trade.quantity, "5.23512".parse::<Decimal>().unwrap());
+        // maker 为卖方：买方为 taker
+        assert!(!trade.is_buyer_maker);
+    }
+
+    #[test]
+    fn test_last_match_and_ignored_messages() {
+        let protocol = protocol();
+        let last_match = MATCH.replace(r#""type":"match""#, r#""type":"last_match""#);
+        assert_eq!(protocol.parse(&last_match, &symbols()).len(), 1);
+
+        let subscriptions =
+            r#"{"type":"subscriptions","channels":[{"name":"matches","product_ids":["BTC-USD"]}]}"#;
+        assert!(protocol.parse(subscriptions, &symbols()).is_empty());
+        assert!(protocol.parse(MATCH, &HashMap::new()).is_empty());
+    }
+}
@@ -1,7 +1,19 @@
 //! # 交易所适配器模块
 //!
 //! 提供各交易所的行情接入实现。
+//!
+//! ## 包含适配器
+//! - `BinanceWebSocket`: 币安（combined stream，URL 订阅）
+//! - `OkxWebSocket` / `BybitWebSocket` / `CoinbaseWebSocket`: 基于 `WsMarketFeed` 的订阅式交易所
 
 mod binance_ws;
+mod bybit_ws;
+mod coinbase_ws;
+mod okx_ws;
+mod ws_connect;
+mod ws_feed;
 
 pub use binance_ws::BinanceWebSocket;
+pub use bybit_ws::BybitWebSocket;
+pub use coinbase_ws::CoinbaseWebSocket;
+pub use okx_ws::OkxWebSocket;
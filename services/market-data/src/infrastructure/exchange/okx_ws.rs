@@ -0,0 +1,178 @@
+//! # OKX WebSocket 适配器 (OKX WebSocket Adapter)
+//!
+//! 基于 `WsMarketFeed` 实现 MarketExchangePort，订阅 OKX V5 公共成交频道。
+//!
+//! ## 协议
+//! - 地址: `wss://ws.okx.com:8443/ws/v5/public`
+//! - 订阅: `{"op":"subscribe","args":[{"channel":"trades","instId":"BTC-USDT"}]}`
+//! - 心跳: 文本 `ping`（30 秒内无消息会被断开），服务端回复 `pong`
+//! - `side` 为主动成交方向，`buy` 表示买方为 taker
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde_json::json;
+use shared::event::market_event::MarketEvent;
+use tokio_tungstenite::tungstenite::protocol::Message;
+use tracing::debug;
+
+use super::ws_feed::{millis_to_utc, trade_event, WsMarketFeed, WsProtocol};
+use crate::domain::model::{MarketExchange, SymbolMap};
+
+/// OKX 推送消息
+#[derive(Debug, Deserialize)]
+struct OkxPushMsg {
+    #[serde(default)]
+    data: Vec<OkxTradeMsg>,
+}
+
+/// OKX 成交消息
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OkxTradeMsg {
+    /// 产品 ID，如 BTC-USDT
+    inst_id: String,
+    /// 成交 ID
+    trade_id: String,
+    /// 成交价格
+    px: String,
+    /// 成交数量
+    sz: String,
+    /// 主动成交方向
+    side: String,
+    /// 成交时间（毫秒字符串）
+    ts: String,
+}
+
+/// OKX 协议描述
+pub struct OkxProtocol {
+    ws_url: String,
+}
+
+impl WsProtocol for OkxProtocol {
+    fn exchange(&self) -> MarketExchange {
+        MarketExchange::Okx
+    }
+
+    fn ws_url(&self) -> &str {
+        &self.ws_url
+    }
+
+    fn subscribe_messages(&self, native_symbols: &[String]) -> Vec<String> {
+        let args: Vec<_> = native_symbols
+            .iter()
+            .map(|s| json!({ "channel": "trades", "instId": s }))
+            .collect();
+        vec![json!({ "op": "subscribe", "args": args }).to_string()]
+    }
+
+    fn heartbeat_interval(&self) -> Duration {
+        Duration::from_secs(20)
+    }
+
+    fn heartbeat_message(&self) -> Message {
+        Message::Text("ping".to_string())
+    }
+
+    fn parse(&self, text: &str, symbols: &HashMap<String, String>) -> Vec<MarketEvent> {
+        // 心跳回复与订阅回执（event 字段）不含成交数据
+        let Ok(msg) = serde_json::from_str::<OkxPushMsg>(text) else {
+            debug!("忽略 OKX 消息: {}", text);
+            return Vec::new();
+        };
+
+        msg.data
+            .into_iter()
+            .filter_map(|t| {
+                let symbol = symbols.get(&t.inst_id)?;
+                let price = t.px.parse::<Decimal>().ok()?;
+                let quantity = t.sz.parse::<Decimal>().ok()?;
+                let timestamp = t.ts.parse::<i64>().ok().and_then(millis_to_utc);
+                Some(trade_event(
+                    MarketExchange::Okx,
+                    symbol,
+                    timestamp,
+                    t.trade_id,
+                    price,
+                    quantity,
+                    t.side != "buy",
+                ))
+            })
+            .collect()
+    }
+}
+
+/// OKX WebSocket 适配器
+pub type OkxWebSocket = WsMarketFeed<OkxProtocol>;
+
+impl OkxWebSocket {
+    /// 创建 OKX WebSocket 客户端
+    ///
+    /// # 参数
+    /// - `ws_url`: WebSocket URL，如 `wss://ws.okx.com:8443/ws/v5/public`
+    /// - `proxy`: 代理地址（可选）
+    /// - `symbol_map`: 交易对映射表
+    pub fn with_url(ws_url: String, proxy: Option<String>, symbol_map: Arc<SymbolMap>) -> Self {
+        WsMarketFeed::new(OkxProtocol { ws_url }, proxy, symbol_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::event::market_event::MarketEventData;
+
+    const TRADES_PUSH: &str = r#"{"arg":{"channel":"trades","instId":"BTC-USDT"},"data":[
+        {"instId":"BTC-USDT","tradeId":"130639474","px":"42219.9","sz":"0.12060306",
+         "side":"buy","ts":"1630048897897","count":"3"},
+        {"instId":"BTC-USDT","tradeId":"130639475","px":"42219.8","sz":"0.5",
+         "side":"sell","ts":"1630048897900","count":"1"},
+        {"instId":"ETH-USDT","tradeId":"9","px":"3000","sz":"1",
+         "side":"buy","ts":"1630048897900","count":"1"}
+    ]}"#;
+
+    fn protocol() -> OkxProtocol {
+        OkxProtocol { ws_url: String::new() }
+    }
+
+    fn symbols() -> HashMap<String, String> {
+        HashMap::from([("BTC-USDT".to_string(), "BTCUSDT".to_string())])
+    }
+
+    #[test]
+    fn test_parse_trades_push() {
+        let events = protocol().parse(TRADES_PUSH, &symbols());
+        // 未订阅的 ETH-USDT 被忽略
+        assert_eq!(events.len(), 2);
+
+        let first = &events[0];
+        assert_eq!(first.exchange, "okx");
+        assert_eq!(first.symbol, "BTCUSDT");
+        assert_eq!(first.timestamp.timestamp_millis(), 1_630_048_897_897);
+        let MarketEventData::Trade(trade) = &first.data else {
+            panic!("expected trade");
+        };
+        assert_eq!(trade.trade_id, "130639474");
+        assert_eq!(trade.price, "42219.9".parse::<Decimal>().unwrap());
+        assert_eq!(trade.quantity, "0.12060306".parse::<Decimal>().unwrap());
+        // 买方主动成交：买方为 taker
+        assert!(!trade.is_buyer_maker);
+
+        let MarketEventData::Trade(second) = &events[1].data else {
+            panic!("expected trade");
+        };
+        assert!(second.is_buyer_maker);
+    }
+
+    #[test]
+    fn test_ignore_control_messages() {
+        let protocol = protocol();
+        assert!(protocol.parse("pong", &symbols()).is_empty());
+        let ack = r#"{"event":"subscribe","arg":{"channel":"trades","instId":"BTC-USDT"},
+            "connId":"a4d3ae55"}"#;
+        assert!(protocol.parse(ack, &symbols()).is_empty());
+    }
+}
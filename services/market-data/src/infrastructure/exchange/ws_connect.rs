@@ -0,0 +1,114 @@
+//! # WebSocket 连接工具 (WebSocket Connector)
+//!
+//! 各交易所适配器共用的 WebSocket 建连逻辑。
+//!
+//! ## 功能
+//! - 直连
+//! - HTTP 代理（CONNECT 隧道）
+//! - SOCKS5 代理
+
+use anyhow::{Context, Result};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_socks::tcp::Socks5Stream;
+use tokio_tungstenite::{client_async_tls, connect_async, MaybeTlsStream, WebSocketStream};
+use tracing::info;
+use url::Url;
+
+/// WebSocket 连接类型
+pub(crate) type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// 创建 WebSocket 连接（支持代理）
+///
+/// # 参数
+/// - `url`: WebSocket URL
+/// - `proxy`: 代理地址（可选），支持 `http://` 与 `socks5://`
+pub(crate) async fn connect_ws(url: &str, proxy: Option<&str>) -> Result<WsStream> {
+    let parsed_url = Url::parse(url).context("解析 WebSocket URL 失败")?;
+
+    match proxy {
+        Some(proxy_url) if !proxy_url.is_empty() => {
+            info!("使用代理连接: {}", proxy_url);
+            connect_via_proxy(url, &parsed_url, proxy_url).await
+        }
+        _ => {
+            info!("直接连接（无代理）");
+            let (ws_stream, _) = connect_async(url)
+                .await
+                .context("连接 WebSocket 失败")?;
+            Ok(ws_stream)
+        }
+    }
+}
+
+/// 通过代理连接 WebSocket
+async fn connect_via_proxy(url: &str, parsed_url: &Url, proxy_url: &str) -> Result<WsStream> {
+    // 解析代理地址
+    let proxy_parsed = Url::parse(proxy_url).context("解析代理 URL 失败")?;
+    let proxy_host = proxy_parsed.host_str().context("代理缺少 host")?;
+    let proxy_port = proxy_parsed.port().unwrap_or(1080);
+    let proxy_addr = format!("{}:{}", proxy_host, proxy_port);
+
+    // 目标地址
+    let target_host = parsed_url.host_str().context("WebSocket URL 缺少 host")?;
+    let target_port = parsed_url.port().unwrap_or(if parsed_url.scheme() == "wss" { 443 } else { 80 });
+
+    info!(
+        "代理连接: {} -> {}:{}",
+        proxy_addr, target_host, target_port
+    );
+
+    // 根据代理类型选择连接方式
+    let scheme = proxy_parsed.scheme().to_lowercase();
+
+    if scheme == "socks5" || scheme == "socks" {
+        // SOCKS5 代理
+        let stream = Socks5Stream::connect(
+            proxy_addr.as_str(),
+            (target_host, target_port),
+        )
+        .await
+        .context("SOCKS5 代理连接失败")?;
+
+        let tcp_stream = stream.into_inner();
+        let (ws_stream, _) = client_async_tls(url, tcp_stream)
+            .await
+            .context("WebSocket 握手失败")?;
+        Ok(ws_stream)
+    } else {
+        // HTTP 代理 - 使用 CONNECT 方法
+        let mut tcp_stream = TcpStream::connect(&proxy_addr)
+            .await
+            .context("连接 HTTP 代理失败")?;
+
+        // 发送 CONNECT 请求
+        let connect_req = format!(
+            "CONNECT {}:{} HTTP/1.1\r\nHost: {}:{}\r\n\r\n",
+            target_host, target_port, target_host, target_port
+        );
+        tcp_stream
+            .write_all(connect_req.as_bytes())
+            .await
+            .context("发送 CONNECT 请求失败")?;
+
+        // 读取响应
+        let mut buf = [0u8; 1024];
+        let n = tcp_stream
+            .read(&mut buf)
+            .await
+            .context("读取代理响应失败")?;
+        let response = String::from_utf8_lossy(&buf[..n]);
+
+        if !response.contains("200") {
+            return Err(anyhow::anyhow!("HTTP 代理连接失败: {}", response.trim()));
+        }
+
+        info!("HTTP 代理隧道建立成功");
+
+        // 升级到 WebSocket
+        let (ws_stream, _) = client_async_tls(url, tcp_stream)
+            .await
+            .context("WebSocket 握手失败")?;
+        Ok(ws_stream)
+    }
+}
@@ -0,0 +1,296 @@
+//! # 通用 WebSocket 行情源 (Generic WebSocket Market Feed)
+//!
+//! 订阅式交易所（OKX / Bybit / Coinbase）共用的连接、订阅、心跳与重连逻辑。
+//! 各交易所只需实现 `WsProtocol`，描述订阅消息与消息解析。
+//!
+//! ## 功能
+//! - 连接 WebSocket（支持代理）
+//! - 连接建立后发送订阅消息
+//! - 按交易所要求发送心跳
+//! - 断线 5 秒后重连并重新订阅
+//! - 通过 `SymbolMap` 将原生交易对还原为标准交易对
+//! - 可选录制交易所原始消息
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use futures_util::{SinkExt, StreamExt};
+use rust_decimal::Decimal;
+use shared::event::market_event::{MarketEvent, MarketEventData, MarketEventType, TradeData};
+use tokio::sync::{mpsc, Mutex};
+use tokio_tungstenite::tungstenite::{protocol::Message, Error as WsError};
+use tracing::{debug, error, info, warn};
+
+use super::ws_connect::connect_ws;
+use crate::domain::model::{MarketExchange, SymbolMap};
+use crate::domain::port::{MarketExchangePort, MarketRecorderPort};
+
+/// 交易所 WebSocket 协议描述
+pub trait WsProtocol: Send + Sync + 'static {
+    /// 交易所标识
+    fn exchange(&self) -> MarketExchange;
+
+    /// WebSocket 地址
+    fn ws_url(&self) -> &str;
+
+    /// 连接建立后发送的订阅消息
+    ///
+    /// # 参数
+    /// - `native_symbols`: 交易所原生格式的交易对
+    fn subscribe_messages(&self, native_symbols: &[String]) -> Vec<String>;
+
+    /// 心跳间隔
+    fn heartbeat_interval(&self) -> Duration {
+        Duration::from_secs(30)
+    }
+
+    /// 心跳消息（默认发送 WebSocket Ping 帧）
+    fn heartbeat_message(&self) -> Message {
+        Message::Ping(vec![])
+    }
+
+    /// 解析一条文本消息
+    ///
+    /// # 参数
+    /// - `text`: 原始消息
+    /// - `symbols`: 原生交易对 → 标准交易对
+    ///
+    /// # 返回
+    /// 消息中包含的行情事件（订阅回执、心跳等返回空）
+    fn parse(&self, text: &str, symbols: &HashMap<String, String>) -> Vec<MarketEvent>;
+}
+
+/// 构建标准成交事件
+///
+/// # 参数
+/// - `symbol`: 标准交易对
+/// - `timestamp`: 交易所成交时间（缺失时使用本地时间）
+pub(crate) fn trade_event(
+    exchange: MarketExchange,
+    symbol: &str,
+    timestamp: Option<DateTime<Utc>>,
+    trade_id: String,
+    price: Decimal,
+    quantity: Decimal,
+    is_buyer_maker: bool,
+) -> MarketEvent {
+    MarketEvent {
+        event_type: MarketEventType::Trade,
+        exchange: exchange.as_str().to_string(),
+        symbol: symbol.to_string(),
+        timestamp: timestamp.unwrap_or_else(Utc::now),
+        data: MarketEventData::Trade(TradeData {
+            trade_id,
+            price,
+            quantity,
+            is_buyer_maker,
+        }),
+    }
+}
+
+/// 毫秒时间戳转换为 UTC 时间
+pub(crate) fn millis_to_utc(ms: i64) -> Option<DateTime<Utc>> {
+    Utc.timestamp_millis_opt(ms).single()
+}
+
+/// 通用 WebSocket 行情源
+pub struct WsMarketFeed<P: WsProtocol> {
+    protocol: Arc<P>,
+    /// 代理地址（可选）
+    proxy: Option<String>,
+    /// 交易对映射表
+    symbol_map: Arc<SymbolMap>,
+    /// 原始消息录制器（可选）
+    recorder: Option<Arc<dyn MarketRecorderPort>>,
+    /// 事件接收通道（事件附带所属连接会话序号）
+    event_rx: Mutex<mpsc::Receiver<(u64, MarketEvent)>>,
+    /// 事件发送通道
+    event_tx: mpsc::Sender<(u64, MarketEvent)>,
+    /// 最近一次交付事件的连接会话序号
+    delivered_session: AtomicU64,
+}
+
+impl<P: WsProtocol> WsMarketFeed<P> {
+    /// 创建行情源
+    pub fn new(protocol: P, proxy: Option<String>, symbol_map: Arc<SymbolMap>) -> Self {
+        let (tx, rx) = mpsc::channel::<(u64, MarketEvent)>(10000);
+
+        Self {
+            protocol: Arc::new(protocol),
+            proxy,
+            symbol_map,
+            recorder: None,
+            event_rx: Mutex::new(rx),
+            event_tx: tx,
+            delivered_session: AtomicU64::new(0),
+        }
+    }
+
+    /// 录制交易所原始消息
+    pub fn with_raw_recorder(mut self, recorder: Arc<dyn MarketRecorderPort>) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// 启动 WebSocket 连接循环（后台任务）
+    fn spawn_ws_loop(&self, symbols: HashMap<String, String>) {
+        let protocol = Arc::clone(&self.protocol);
+        let proxy = self.proxy.clone();
+        let recorder = self.recorder.clone();
+        let tx = self.event_tx.clone();
+
+        tokio::spawn(async move {
+            let exchange = protocol.exchange();
+            let mut session = 0;
+            loop {
+                session += 1;
+                let result = connect_and_receive(
+                    protocol.as_ref(),
+                    proxy.as_deref(),
+                    recorder.as_deref(),
+                    &symbols,
+                    session,
+                    &tx,
+                )
+                .await;
+                match result {
+                    Ok(()) => {
+                        info!(%exchange, "WebSocket 连接正常关闭，5秒后重连...");
+                    }
+                    Err(e) => {
+                        error!(%exchange, "WebSocket 错误: {}, 5秒后重连...", e);
+                    }
+                }
+
+                if tx.is_closed() {
+                    warn!(%exchange, "事件通道已关闭，停止重连");
+                    break;
+                }
+
+                tokio::time::sleep(Duration::from_secs(5)).await;
+            }
+        });
+    }
+}
+
+/// 连接、订阅并接收消息
+async fn connect_and_receive<P: WsProtocol>(
+    protocol: &P,
+    proxy: Option<&str>,
+    recorder: Option<&dyn MarketRecorderPort>,
+    symbols: &HashMap<String, String>,
+    session: u64,
+    tx: &mpsc::Sender<(u64, MarketEvent)>,
+) -> Result<()> {
+    let exchange = protocol.exchange();
+    let ws_stream = connect_ws(protocol.ws_url(), proxy).await?;
+    info!(%exchange, "WebSocket 连接成功");
+
+    let (mut write, mut read) = ws_stream.split();
+
+    // 发送订阅消息
+    let native: Vec<String> = symbols.keys().cloned().collect();
+    for msg in protocol.subscribe_messages(&native) {
+        write.send(Message::Text(msg)).await?;
+    }
+    info!(%exchange, symbols = ?native, "已发送订阅请求");
+
+    // 心跳保持连接
+    let interval = protocol.heartbeat_interval();
+    let heartbeat = protocol.heartbeat_message();
+    let ping_handle = tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(interval).await;
+            if write.send(heartbeat.clone()).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    // 接收消息
+    while let Some(msg_result) = read.next().await {
+        match msg_result {
+            Ok(Message::Text(text)) => {
+                if let Some(recorder) = recorder {
+                    recorder.record_raw(exchange.as_str(), &text);
+                }
+                for event in protocol.parse(&text, symbols) {
+                    if tx.send((session, event)).await.is_err() {
+                        warn!(%exchange, "事件通道已关闭");
+                        ping_handle.abort();
+                        return Ok(());
+                    }
+                }
+            }
+            Ok(Message::Ping(_)) => {
+                debug!(%exchange, "收到 Ping");
+            }
+            Ok(Message::Pong(_)) => {
+                debug!(%exchange, "收到 Pong");
+            }
+            Ok(Message::Close(_)) => {
+                info!(%exchange, "收到关闭消息");
+                break;
+            }
+            Ok(_) => {}
+            Err(WsError::ConnectionClosed) => {
+                info!(%exchange, "连接已关闭");
+                break;
+            }
+            Err(e) => {
+                error!(%exchange, "WebSocket 错误: {}", e);
+                break;
+            }
+        }
+    }
+
+    ping_handle.abort();
+    Ok(())
+}
+
+#[async_trait]
+impl<P: WsProtocol> MarketExchangePort for WsMarketFeed<P> {
+    /// 连接交易所
+    async fn connect(&self) -> Result<()> {
+        // 实际连接在 subscribe 时进行
+        info!(exchange = %self.protocol.exchange(), "connect() 调用");
+        Ok(())
+    }
+
+    /// 订阅现货行情
+    async fn subscribe_spot(&self, symbols: Vec<String>) -> Result<()> {
+        let exchange = self.protocol.exchange();
+        let index = self.symbol_map.native_index(exchange, &symbols);
+        info!(%exchange, mapping = ?index, "订阅现货行情");
+
+        self.spawn_ws_loop(index);
+        Ok(())
+    }
+
+    /// 订阅合约行情
+    async fn subscribe_futures(&self, symbols: Vec<String>) -> Result<()> {
+        // 合约使用不同的地址与交易对格式，暂时用现货实现
+        info!(exchange = %self.protocol.exchange(), "订阅合约行情: {:?}", symbols);
+        self.subscribe_spot(symbols).await
+    }
+
+    /// 获取下一个行情事件
+    async fn next_event(&self) -> Result<MarketEvent> {
+        let mut rx = self.event_rx.lock().await;
+        let (session, event) = rx
+            .recv()
+            .await
+            .ok_or_else(|| anyhow::anyhow!("事件通道已关闭"))?;
+        self.delivered_session.store(session, Ordering::Relaxed);
+        Ok(event)
+    }
+
+    fn session(&self) -> u64 {
+        self.delivered_session.load(Ordering::Relaxed)
+    }
+}
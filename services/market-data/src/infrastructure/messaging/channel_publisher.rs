@@ -0,0 +1,37 @@
+//! # 进程内通道发布器 (Channel Publisher)
+//!
+//! 实现 MessagePort，将行情事件发送到进程内 tokio 通道。
+//! 用于离线回放时直接驱动进程内的策略 / 交易组件，无需 Kafka。
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use shared::event::market_event::MarketEvent;
+use tokio::sync::mpsc;
+
+use crate::domain::port::MessagePort;
+
+/// 进程内通道发布器
+pub struct ChannelPublisher {
+    tx: mpsc::Sender<MarketEvent>,
+}
+
+impl ChannelPublisher {
+    /// 创建发布器与对应的接收端
+    ///
+    /// # 参数
+    /// - `capacity`: 通道容量；接收端消费慢时发布会等待（保证不丢事件）
+    pub fn channel(capacity: usize) -> (Self, mpsc::Receiver<MarketEvent>) {
+        let (tx, rx) = mpsc::channel(capacity.max(1));
+        (Self { tx }, rx)
+    }
+}
+
+#[async_trait]
+impl MessagePort for ChannelPublisher {
+    async fn publish(&self, event: MarketEvent) -> anyhow::Result<()> {
+        self.tx
+            .send(event)
+            .await
+            .map_err(|_| anyhow!("replay channel closed"))
+    }
+}
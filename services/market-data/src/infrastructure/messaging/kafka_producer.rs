@@ -1,6 +1,11 @@
 //! # Kafka 生产者适配器 (Kafka Producer Adapter)
 //!
-//! 实现 MessagePort trait。
+//! 实现 MessagePort / QualityMessagePort trait。
+//! 每个实例只写一个 topic，行情与质量事件使用不同实例。
+//!
+//! ## 消息 Key
+//! - 行情事件: `{exchange}:{symbol}`，同一交易所同一交易对保序
+//! - 质量事件: `{symbol}`
 
 use std::time::Duration;
 
@@ -9,8 +14,9 @@ use async_trait::async_trait;
 use rdkafka::config::ClientConfig;
 use rdkafka::producer::{FutureProducer, FutureRecord};
 use shared::event::market_event::MarketEvent;
+use shared::event::quality_event::DataQualityEvent;
 
-use crate::domain::port::MessagePort;
+use crate::domain::port::{MessagePort, QualityMessagePort};
 
 /// Kafka 生产者适配器
 pub struct KafkaProducer {
@@ -37,16 +43,12 @@ impl KafkaProducer {
     }
 }
 
-#[async_trait]
-impl MessagePort for KafkaProducer {
-    async fn publish(&self, event: MarketEvent) -> anyhow::Result<()> {
-        let payload = serde_json::to_string(&event)
-            .context("serialize market event")?;
-        let key = event.symbol.clone();
-
+impl KafkaProducer {
+    /// 按指定 key 发送 JSON 消息
+    async fn send_json(&self, key: &str, payload: &str) -> anyhow::Result<()> {
         let record = FutureRecord::to(&self.topic)
-            .payload(&payload)
-            .key(&key);
+            .payload(payload)
+            .key(key);
 
         let delivery = self.producer.send(record, Duration::from_secs(5)).await;
         match delivery {
@@ -55,3 +57,22 @@ impl MessagePort for KafkaProducer {
         }
     }
 }
+
+#[async_trait]
+impl MessagePort for KafkaProducer {
+    async fn publish(&self, event: MarketEvent) -> anyhow::Result<()> {
+        let payload = serde_json::to_string(&event)
+            .context("serialize market event")?;
+        let key = format!("{}:{}", event.exchange, event.symbol);
+        self.send_json(&key, &payload).await
+    }
+}
+
+#[async_trait]
+impl QualityMessagePort for KafkaProducer {
+    async fn publish_quality(&self, event: &DataQualityEvent) -> anyhow::Result<()> {
+        let payload = serde_json::to_string(event)
+            .context("serialize data quality event")?;
+        self.send_json(&event.symbol, &payload).await
+    }
+}
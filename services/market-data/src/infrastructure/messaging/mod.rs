@@ -1,7 +1,14 @@
 //! # 消息适配器模块
 //!
 //! 提供消息队列的发布实现。
+//!
+//! - `KafkaProducer`: 发布到 Kafka
+//! - `ChannelPublisher`: 发布到进程内通道（仅测试使用）
 
+#[cfg(test)]
+pub mod channel_publisher;
 pub mod kafka_producer;
 
+#[cfg(test)]
+pub use channel_publisher::ChannelPublisher;
 pub use kafka_producer::KafkaProducer;
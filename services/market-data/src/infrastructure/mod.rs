@@ -6,7 +6,9 @@
 //! - `exchange`: 交易所适配器
 //! - `messaging`: 消息队列适配器
 //! - `storage`: 数据存储适配器
+//! - `recording`: 行情录制与回放源
 
 pub mod exchange;
 pub mod messaging;
+pub mod recording;
 pub mod storage;
@@ -0,0 +1,440 @@
+//! # 本地文件录制器 (File Recorder)
+//!
+//! 实现 MarketRecorderPort，将行情写入按小时分区的 gzip JSON Lines 文件。
+//!
+//! ## 设计
+//! - 采集链路只做 `try_send`，通道满时丢弃并计数，不阻塞行情
+//! - 独立写线程负责序列化、压缩与分区切换
+//! - 每个进程（session）写自己的分区文件，不追加到其他 session 的文件：
+//!   进程崩溃留下的截断 gzip member 只影响该文件的尾部
+//! - 每秒 flush 一次（按时间，持续有数据时同样生效）；进程崩溃最多丢失最近 1 秒，
+//!   读取端容忍截断的尾部
+//! - 正常退出时通过 `RecorderTask::shutdown` 写完通道内剩余数据并结束 gzip member
+
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, SyncSender, TrySendError};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use shared::event::market_event::MarketEvent;
+use tracing::{error, info, warn};
+
+use super::{new_session, partition_of, partition_path, EVENTS_DIR, RAW_DIR};
+use crate::domain::model::{RecordedEvent, RecordedRaw};
+use crate::domain::port::MarketRecorderPort;
+
+/// 写线程 flush 间隔
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 待写入的录制行
+enum RecordLine {
+    Event(RecordedEvent),
+    Raw(RecordedRaw),
+}
+
+impl RecordLine {
+    fn kind(&self) -> &'static str {
+        match self {
+            RecordLine::Event(_) => EVENTS_DIR,
+            RecordLine::Raw(_) => RAW_DIR,
+        }
+    }
+
+    fn recorded_at(&self) -> DateTime<Utc> {
+        match self {
+            RecordLine::Event(e) => e.recorded_at,
+            RecordLine::Raw(r) => r.recorded_at,
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Result<String> {
+        match self {
+            RecordLine::Event(e) => serde_json::to_string(e),
+            RecordLine::Raw(r) => serde_json::to_string(r),
+        }
+    }
+}
+
+/// 录制写线程句柄
+///
+/// 录制器被采集链路共享，写线程不会因发送端释放而及时退出，
+/// 退出前必须调用 `shutdown`，否则最后一个 gzip member 不完整。
+pub struct RecorderTask {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+    /// 与录制器共享的丢弃计数
+    dropped: Arc<AtomicU64>,
+}
+
+impl RecorderTask {
+    /// 通知写线程退出并等待：写完通道内剩余数据、结束所有分区文件
+    ///
+    /// 阻塞调用，异步上下文中需放入 `spawn_blocking`。返回运行期间因通道满被丢弃的条数。
+    pub fn shutdown(self) -> u64 {
+        self.stop.store(true, Ordering::Release);
+        if self.handle.join().is_err() {
+            error!("行情录制写线程异常退出");
+        }
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+/// 本地文件录制器
+pub struct FileRecorder {
+    tx: SyncSender<RecordLine>,
+    /// 录制序号
+    seq: AtomicU64,
+    /// 因通道满被丢弃的条数
+    dropped: Arc<AtomicU64>,
+    /// 是否录制原始消息
+    record_raw: bool,
+}
+
+impl FileRecorder {
+    /// 启动录制器与写线程
+    ///
+    /// # 参数
+    /// - `dir`: 录制根目录
+    /// - `capacity`: 内存通道容量（条）
+    /// - `record_raw`: 是否录制交易所原始消息
+    pub fn start(
+        dir: impl Into<PathBuf>,
+        capacity: usize,
+        record_raw: bool,
+    ) -> Result<(Self, RecorderTask)> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("创建录制目录失败: {}", dir.display()))?;
+
+        let (tx, rx) = mpsc::sync_channel::<RecordLine>(capacity.max(1));
+        let stop = Arc::new(AtomicBool::new(false));
+        let session = new_session();
+        let writer = SessionWriter {
+            dir: dir.clone(),
+            session: session.clone(),
+            partitions: HashMap::new(),
+        };
+        let writer_stop = Arc::clone(&stop);
+        let handle = std::thread::Builder::new()
+            .name("market-recorder".to_string())
+            .spawn(move || run_writer(writer, rx, writer_stop))
+            .context("启动录制写线程失败")?;
+
+        info!(dir = %dir.display(), session = %session, record_raw, "行情录制已启用");
+
+        let dropped = Arc::new(AtomicU64::new(0));
+        Ok((
+            Self {
+                tx,
+                seq: AtomicU64::new(0),
+                dropped: Arc::clone(&dropped),
+                record_raw,
+            },
+            RecorderTask { stop, handle, dropped },
+        ))
+    }
+
+    fn send(&self, line: RecordLine) {
+        match self.tx.try_send(line) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+                if dropped.is_power_of_two() {
+                    warn!(dropped, "录制通道已满，丢弃录制数据");
+                }
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn next_seq(&self) -> u64 {
+        self.seq.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+impl MarketRecorderPort for FileRecorder {
+    fn record_event(&self, event: &MarketEvent) {
+        self.send(RecordLine::Event(RecordedEvent {
+            seq: self.next_seq(),
+            recorded_at: Utc::now(),
+            event: event.clone(),
+        }));
+    }
+
+    fn record_raw(&self, exchange: &str, raw: &str) {
+        if !self.record_raw {
+            return;
+        }
+        self.send(RecordLine::Raw(RecordedRaw {
+            seq: self.next_seq(),
+            recorded_at: Utc::now(),
+            exchange: exchange.to_string(),
+            raw: raw.to_string(),
+        }));
+    }
+}
+
+/// 当前打开的分区文件
+struct PartitionWriter {
+    partition: DateTime<Utc>,
+    encoder: GzEncoder<BufWriter<File>>,
+}
+
+/// 本 session 的分区文件
+struct SessionWriter {
+    dir: PathBuf,
+    session: String,
+    /// 类型 -> 当前分区
+    partitions: HashMap<&'static str, PartitionWriter>,
+}
+
+impl SessionWriter {
+    /// 写入一行，必要时切换分区文件
+    fn write_line(&mut self, line: &RecordLine) -> Result<()> {
+        let kind = line.kind();
+        let partition = partition_of(line.recorded_at());
+
+        let rotate = self.partitions.get(kind).is_none_or(|w| w.partition != partition);
+        if rotate {
+            if let Some(old) = self.partitions.remove(kind) {
+                old.encoder
+                    .finish()
+                    .and_then(|mut w| w.flush())
+                    .context("关闭录制分区失败")?;
+            }
+
+            let path = partition_path(&self.dir, kind, partition, &self.session);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .with_context(|| format!("创建录制分区目录失败: {}", parent.display()))?;
+            }
+            // 追加模式：时钟回拨回到已关闭的分区时写入新的 gzip member
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .with_context(|| format!("打开录制文件失败: {}", path.display()))?;
+            self.partitions.insert(
+                kind,
+                PartitionWriter {
+                    partition,
+                    encoder: GzEncoder::new(BufWriter::new(file), Compression::fast()),
+                },
+            );
+        }
+
+        let writer = self.partitions.get_mut(kind).context("录制分区未打开")?;
+        let json = line.to_json().context("序列化录制数据失败")?;
+        writer.encoder.write_all(json.as_bytes())?;
+        writer.encoder.write_all(b"\n")?;
+        Ok(())
+    }
+
+    fn write(&mut self, line: &RecordLine) {
+        if let Err(e) = self.write_line(line) {
+            error!(error = %e, "写入录制文件失败");
+        }
+    }
+
+    fn flush(&mut self) {
+        for writer in self.partitions.values_mut() {
+            if let Err(e) = writer.encoder.flush() {
+                warn!(error = %e, "flush 录制文件失败");
+            }
+        }
+    }
+
+    /// 结束所有分区文件的 gzip member
+    fn finish(&mut self) {
+        for (_, writer) in self.partitions.drain() {
+            if let Err(e) = writer.encoder.finish().and_then(|mut w| w.flush()) {
+                warn!(error = %e, "关闭录制文件失败");
+            }
+        }
+    }
+}
+
+/// 写线程主循环
+///
+/// 每个 `FLUSH_INTERVAL` 按时间 flush 一次；收到停止信号或发送端全部释放后，
+/// 写完通道内剩余数据再退出。
+fn run_writer(mut writer: SessionWriter, rx: mpsc::Receiver<RecordLine>, stop: Arc<AtomicBool>) {
+    let mut last_flush = Instant::now();
+
+    loop {
+        if stop.load(Ordering::Acquire) {
+            for line in rx.try_iter() {
+                writer.write(&line);
+            }
+            break;
+        }
+
+        let wait = FLUSH_INTERVAL.saturating_sub(last_flush.elapsed());
+        match rx.recv_timeout(wait) {
+            Ok(line) => writer.write(&line),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+
+        if last_flush.elapsed() >= FLUSH_INTERVAL {
+            writer.flush();
+            last_flush = Instant::now();
+        }
+    }
+
+    writer.finish();
+    info!(session = %writer.session, "行情录制写线程已退出");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+    use futures_util::StreamExt;
+    use rust_decimal::Decimal;
+    use shared::event::market_event::{MarketEventData, MarketEventType, TradeData};
+
+    use crate::application::ReplayService;
+    use crate::domain::model::{ReplaySpeed, TimeRange};
+    use crate::domain::port::RecordingSourcePort;
+    use crate::infrastructure::messaging::ChannelPublisher;
+    use crate::infrastructure::recording::{partition_files, FileRecordingSource};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn trade(trade_id: u64) -> MarketEvent {
+        MarketEvent {
+            event_type: MarketEventType::Trade,
+            exchange: "binance".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            timestamp: Utc::now(),
+            data: MarketEventData::Trade(TradeData {
+                trade_id: trade_id.to_string(),
+                price: Decimal::from(50_000 + trade_id),
+                quantity: Decimal::ONE,
+                is_buyer_maker: false,
+            }),
+        }
+    }
+
+    fn trade_id(event: &MarketEvent) -> String {
+        match &event.data {
+            MarketEventData::Trade(t) => t.trade_id.clone(),
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    fn around_now() -> TimeRange {
+        TimeRange {
+            start: Utc::now() - TimeDelta::hours(1),
+            end: Utc::now() + TimeDelta::hours(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay_round_trip() {
+        let dir = temp_dir("file-recorder-round-trip");
+        let (recorder, task) = FileRecorder::start(&dir, 100, true).unwrap();
+        for id in 1..=5 {
+            recorder.record_raw("binance", "{\"e\":\"trade\"}");
+            recorder.record_event(&trade(id));
+        }
+        // 录制器仍被持有时关闭：剩余数据写完且 gzip member 结束
+        let dropped = tokio::task::spawn_blocking(move || task.shutdown()).await.unwrap();
+        assert_eq!(dropped, 0);
+
+        let source: Arc<dyn RecordingSourcePort> = Arc::new(FileRecordingSource::new(&dir));
+        let (publisher, mut rx) = ChannelPublisher::channel(100);
+        let replay = ReplayService::new(source, Arc::new(publisher));
+        let stats = replay
+            .replay(around_now(), ReplaySpeed::AsFastAsPossible)
+            .await
+            .unwrap();
+        assert_eq!(stats.published, 5);
+        assert_eq!(stats.failed, 0);
+
+        let mut replayed = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            replayed.push(trade_id(&event));
+        }
+        assert_eq!(replayed, vec!["1", "2", "3", "4", "5"]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_flushes_while_data_keeps_arriving() {
+        let dir = temp_dir("file-recorder-flush");
+        let (recorder, task) = FileRecorder::start(&dir, 1000, false).unwrap();
+
+        // 持续写入（间隔远小于 flush 间隔），不关闭录制器
+        let deadline = Instant::now() + FLUSH_INTERVAL + Duration::from_millis(500);
+        let mut id = 0;
+        while Instant::now() < deadline {
+            id += 1;
+            recorder.record_event(&trade(id));
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        let source = FileRecordingSource::new(&dir);
+        let mut readable = 0;
+        for partition in source.partitions(&around_now()).await.unwrap() {
+            readable += source.stream_partition(partition).await.unwrap().count().await;
+        }
+        assert!(readable > 0, "no events flushed while data kept arriving");
+
+        tokio::task::spawn_blocking(move || task.shutdown()).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_crashed_session_does_not_hide_later_sessions() {
+        let dir = temp_dir("file-recorder-crash");
+
+        // 第一个 session 正常写完后模拟崩溃：截掉 gzip 尾部
+        let (recorder, task) = FileRecorder::start(&dir, 100, false).unwrap();
+        for id in 1..=3 {
+            recorder.record_event(&trade(id));
+        }
+        tokio::task::spawn_blocking(move || task.shutdown()).await.unwrap();
+        let crashed = partition_files(&dir, EVENTS_DIR, partition_of(Utc::now())).unwrap();
+        assert_eq!(crashed.len(), 1);
+        let bytes = std::fs::read(&crashed[0]).unwrap();
+        std::fs::write(&crashed[0], &bytes[..bytes.len() - 8]).unwrap();
+
+        // 重启后的 session 写入自己的文件
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        let (recorder, task) = FileRecorder::start(&dir, 100, false).unwrap();
+        for id in 4..=5 {
+            recorder.record_event(&trade(id));
+        }
+        tokio::task::spawn_blocking(move || task.shutdown()).await.unwrap();
+
+        let source = FileRecordingSource::new(&dir);
+        let mut replayed = Vec::new();
+        for partition in source.partitions(&around_now()).await.unwrap() {
+            let mut events = source.stream_partition(partition).await.unwrap();
+            while let Some(recorded) = events.next().await {
+                replayed.push(trade_id(&recorded.event));
+            }
+        }
+        assert_eq!(replayed, vec!["1", "2", "3", "4", "5"]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,121 @@
+//! # 本地文件回放源 (File Recording Source)
+//!
+//! 实现 RecordingSourcePort，读取 `FileRecorder` 写入的标准化事件分区。
+//!
+//! ## 读取
+//! - 一个分区由多个 session 文件组成，按文件名顺序依次读取
+//! - 读线程逐行解码，经有界通道流式交给回放，不整体载入内存
+//!
+//! ## 容错
+//! - 文件尾部被截断（进程崩溃）时，保留截断前已完整读取的事件，继续读取下一个文件
+//! - 单行解析失败时跳过该行并告警
+
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use flate2::read::MultiGzDecoder;
+use futures_util::stream::{self, StreamExt};
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use super::{partition_files, partition_of, EVENTS_DIR};
+use crate::domain::model::{RecordedEvent, TimeRange};
+use crate::domain::port::{RecordedEventStream, RecordingSourcePort};
+
+/// 读线程与回放之间的通道容量（条）
+const STREAM_CAPACITY: usize = 1024;
+
+/// 本地文件回放源
+pub struct FileRecordingSource {
+    dir: PathBuf,
+}
+
+impl FileRecordingSource {
+    /// 创建回放源
+    ///
+    /// # 参数
+    /// - `dir`: 录制根目录（与 `FileRecorder` 相同）
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// 分区内的事件文件
+    async fn files(&self, partition: DateTime<Utc>) -> Result<Vec<PathBuf>> {
+        let dir = self.dir.clone();
+        tokio::task::spawn_blocking(move || partition_files(&dir, EVENTS_DIR, partition))
+            .await
+            .context("列出录制分区任务失败")?
+            .with_context(|| format!("列出录制分区失败: {}", partition))
+    }
+}
+
+#[async_trait]
+impl RecordingSourcePort for FileRecordingSource {
+    async fn partitions(&self, range: &TimeRange) -> Result<Vec<DateTime<Utc>>> {
+        let mut partitions = Vec::new();
+        let mut partition = partition_of(range.start);
+        while partition < range.end {
+            if !self.files(partition).await?.is_empty() {
+                partitions.push(partition);
+            }
+            partition += TimeDelta::hours(1);
+        }
+        Ok(partitions)
+    }
+
+    async fn stream_partition(&self, partition: DateTime<Utc>) -> Result<RecordedEventStream> {
+        let files = self.files(partition).await?;
+        let (tx, rx) = mpsc::channel(STREAM_CAPACITY);
+        tokio::task::spawn_blocking(move || {
+            for path in files {
+                if !read_file(&path, &tx) {
+                    break;
+                }
+            }
+        });
+
+        let events = stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|event| (event, rx))
+        });
+        Ok(events.boxed())
+    }
+}
+
+/// 读取一个 session 文件，返回接收端是否仍然存在
+fn read_file(path: &Path, tx: &mpsc::Sender<RecordedEvent>) -> bool {
+    let file = match std::fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) => {
+            warn!(path = %path.display(), error = %e, "打开录制文件失败，跳过");
+            return true;
+        }
+    };
+    let reader = BufReader::new(MultiGzDecoder::new(BufReader::new(file)));
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                warn!(path = %path.display(), error = %e, "录制文件尾部不完整，停止读取该文件");
+                break;
+            }
+        };
+        if line.is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<RecordedEvent>(&line) {
+            Ok(event) => {
+                if tx.blocking_send(event).is_err() {
+                    return false;
+                }
+            }
+            Err(e) => {
+                warn!(path = %path.display(), line = index + 1, error = %e, "跳过无法解析的录制行");
+            }
+        }
+    }
+    true
+}
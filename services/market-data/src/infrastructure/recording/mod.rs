@@ -0,0 +1,83 @@
+//! # 录制模块 (Recording Module)
+//!
+//! 行情录制与回放源的本地文件实现。
+//!
+//! ## 文件布局
+//! - `{dir}/events/{YYYYMMDD}/{HH}-{session}.jsonl.gz`: 标准化事件（`RecordedEvent`）
+//! - `{dir}/raw/{YYYYMMDD}/{HH}-{session}.jsonl.gz`: 交易所原始消息（`RecordedRaw`）
+//!
+//! 按录制时间（UTC）小时分区；每个录制进程（session）写自己的文件，`session` 以启动时间开头，
+//! 按文件名排序即录制顺序。进程崩溃留下的截断文件不影响之后的 session，可直接用 `zcat` 查看。
+//! 旧版本追加写入的 `{HH}.jsonl.gz` 仍可读取，排在同一小时的 session 文件之前。
+
+pub mod file_recorder;
+pub mod file_recording_source;
+
+pub use file_recorder::{FileRecorder, RecorderTask};
+pub use file_recording_source::FileRecordingSource;
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
+
+/// 标准化事件子目录
+pub(crate) const EVENTS_DIR: &str = "events";
+/// 原始消息子目录
+pub(crate) const RAW_DIR: &str = "raw";
+
+/// 时间所在的小时分区起点
+pub(crate) fn partition_of(time: DateTime<Utc>) -> DateTime<Utc> {
+    time.duration_trunc(TimeDelta::hours(1)).unwrap_or(time)
+}
+
+/// 录制文件扩展名
+const FILE_SUFFIX: &str = ".jsonl.gz";
+
+/// 分区所在的日期目录
+fn partition_dir(dir: &Path, kind: &str, partition: DateTime<Utc>) -> PathBuf {
+    dir.join(kind).join(partition.format("%Y%m%d").to_string())
+}
+
+/// 新的录制 session 标识（启动时间 + 进程号，按字典序即启动顺序）
+pub(crate) fn new_session() -> String {
+    format!("{}-{}", Utc::now().format("%Y%m%dT%H%M%S%3f"), std::process::id())
+}
+
+/// 某个 session 的分区文件路径
+pub(crate) fn partition_path(
+    dir: &Path,
+    kind: &str,
+    partition: DateTime<Utc>,
+    session: &str,
+) -> PathBuf {
+    let name = format!("{}-{}{}", partition.format("%H"), session, FILE_SUFFIX);
+    partition_dir(dir, kind, partition).join(name)
+}
+
+/// 分区内的全部文件（按录制顺序：旧版单文件在前，session 文件按文件名升序）
+pub(crate) fn partition_files(
+    dir: &Path,
+    kind: &str,
+    partition: DateTime<Utc>,
+) -> std::io::Result<Vec<PathBuf>> {
+    let hour = partition.format("%H").to_string();
+    let legacy = format!("{}{}", hour, FILE_SUFFIX);
+    let session_prefix = format!("{}-", hour);
+
+    let entries = match std::fs::read_dir(partition_dir(dir, kind, partition)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut names = Vec::new();
+    for entry in entries {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if name == legacy || (name.starts_with(&session_prefix) && name.ends_with(FILE_SUFFIX)) {
+            names.push(name);
+        }
+    }
+    names.sort_by_key(|name| (*name != legacy, name.clone()));
+
+    let base = partition_dir(dir, kind, partition);
+    Ok(names.into_iter().map(|name| base.join(name)).collect())
+}
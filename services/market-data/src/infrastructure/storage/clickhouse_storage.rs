@@ -1,13 +1,25 @@
 //! # ClickHouse 存储适配器 (ClickHouse Storage Adapter)
 //!
 //! 实现 MarketStoragePort，将行情数据存储到 ClickHouse。
+//!
+//! ## 表结构
+//! - `{table}`: 成交明细（Trade 事件）
+//! - `{table}_depth`: 深度快照（Depth 事件，买卖盘以 JSON 存储）
+//!
+//! ## 读侧
+//! K 线不单独落表，查询时由成交明细按周期在服务端聚合（重采样）。
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use clickhouse::Client;
-use serde::Serialize;
-use tracing::{debug, error, info};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
 
+use crate::domain::model::{
+    DepthSnapshot, Kline, KlineQuery, PriceSnapshot, TradePage, TradeQuery, TradeRecord,
+};
 use crate::domain::port::MarketStoragePort;
 use shared::event::market_event::{MarketEvent, MarketEventData};
 
@@ -32,10 +44,73 @@ struct TradeRow {
     insert_time: i64,
 }
 
+/// ClickHouse 深度快照记录
+#[derive(Debug, Clone, Serialize, clickhouse::Row)]
+struct DepthRow {
+    /// 交易所
+    exchange: String,
+    /// 交易对
+    symbol: String,
+    /// 买盘 JSON: [[price, qty], ...]
+    bids: String,
+    /// 卖盘 JSON: [[price, qty], ...]
+    asks: String,
+    /// 事件时间（毫秒时间戳）
+    event_time: i64,
+    /// 插入时间
+    insert_time: i64,
+}
+
+/// 成交查询结果行
+#[derive(Debug, Deserialize, clickhouse::Row)]
+struct TradeReadRow {
+    exchange: String,
+    symbol: String,
+    trade_id: String,
+    price: f64,
+    quantity: f64,
+    is_buyer_maker: bool,
+    /// 事件时间（毫秒时间戳）；别名避免遮蔽 DateTime64 列
+    event_time_ms: i64,
+}
+
+/// K 线聚合结果行
+#[derive(Debug, Deserialize, clickhouse::Row)]
+struct KlineReadRow {
+    open_time: i64,
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+    trade_count: u64,
+}
+
+/// 最新价格结果行
+#[derive(Debug, Deserialize, clickhouse::Row)]
+struct PriceReadRow {
+    symbol: String,
+    price: f64,
+    /// 事件时间（毫秒时间戳）；别名避免遮蔽 DateTime64 列
+    event_time_ms: i64,
+}
+
+/// 深度快照结果行
+#[derive(Debug, Deserialize, clickhouse::Row)]
+struct DepthReadRow {
+    exchange: String,
+    symbol: String,
+    bids: String,
+    asks: String,
+    /// 事件时间（毫秒时间戳）；别名避免遮蔽 DateTime64 列
+    event_time_ms: i64,
+}
+
 /// ClickHouse 存储适配器
 pub struct ClickHouseStorage {
     client: Client,
     table_name: String,
+    depth_table_name: String,
 }
 
 impl ClickHouseStorage {
@@ -58,6 +133,7 @@ impl ClickHouseStorage {
         Ok(Self {
             client,
             table_name: table_name.to_string(),
+            depth_table_name: format!("{}_depth", table_name),
         })
     }
 
@@ -99,7 +175,32 @@ impl ClickHouseStorage {
             .await
             .context("创建 ClickHouse 表失败")?;
 
-        info!("ClickHouse 表 {} 初始化完成", self.table_name);
+        let create_depth_sql = format!(
+            r#"
+            CREATE TABLE IF NOT EXISTS {} (
+                exchange String,
+                symbol String,
+                bids String,
+                asks String,
+                event_time DateTime64(3),
+                insert_time DateTime64(3)
+            ) ENGINE = MergeTree()
+            PARTITION BY toYYYYMMDD(event_time)
+            ORDER BY (symbol, event_time)
+            "#,
+            self.depth_table_name
+        );
+
+        self.client
+            .query(&create_depth_sql)
+            .execute()
+            .await
+            .context("创建 ClickHouse 深度表失败")?;
+
+        info!(
+            "ClickHouse 表 {} / {} 初始化完成",
+            self.table_name, self.depth_table_name
+        );
         Ok(())
     }
 
@@ -124,16 +225,59 @@ impl ClickHouseStorage {
             _ => None,
         }
     }
+
+    /// 将 MarketEvent 转换为 DepthRow
+    fn event_to_depth_row(&self, event: &MarketEvent) -> Option<DepthRow> {
+        match &event.data {
+            MarketEventData::Depth(depth) => Some(DepthRow {
+                exchange: event.exchange.clone(),
+                symbol: event.symbol.clone(),
+                bids: serde_json::to_string(&depth.bids).ok()?,
+                asks: serde_json::to_string(&depth.asks).ok()?,
+                event_time: event.timestamp.timestamp_millis(),
+                insert_time: chrono::Utc::now().timestamp_millis(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// 批量写入深度快照
+    async fn insert_depth_rows(&self, rows: &[DepthRow]) -> Result<()> {
+        let mut insert = self.client.insert(&self.depth_table_name)?;
+        for row in rows {
+            insert.write(row).await.context("写入深度数据失败")?;
+        }
+        insert.end().await.context("提交深度插入失败")?;
+        Ok(())
+    }
+}
+
+/// f64 转换为 Decimal（存储层以 Float64 落盘）
+///
+/// NaN / 无穷大等无法表示的值返回错误，不以 0 代替。
+fn to_decimal(value: f64, field: &str) -> Result<Decimal> {
+    Decimal::try_from(value).with_context(|| format!("{} 无法转换为 Decimal: {}", field, value))
+}
+
+/// 解析 JSON 存储的盘口档位
+fn parse_levels(raw: &str) -> Result<Vec<(Decimal, Decimal)>> {
+    serde_json::from_str(raw).context("解析深度档位失败")
 }
 
 #[async_trait]
 impl MarketStoragePort for ClickHouseStorage {
     /// 存储单条行情事件
     async fn save_event(&self, event: &MarketEvent) -> Result<()> {
+        if let Some(depth) = self.event_to_depth_row(event) {
+            self.insert_depth_rows(std::slice::from_ref(&depth)).await?;
+            debug!("存储深度快照: {}", depth.symbol);
+            return Ok(());
+        }
+
         let row = match self.event_to_row(event) {
             Some(r) => r,
             None => {
-                debug!("跳过非 Trade 事件");
+                debug!("跳过非 Trade / Depth 事件");
                 return Ok(());
             }
         };
@@ -152,6 +296,15 @@ impl MarketStoragePort for ClickHouseStorage {
             return Ok(());
         }
 
+        let depth_rows: Vec<DepthRow> = events
+            .iter()
+            .filter_map(|e| self.event_to_depth_row(e))
+            .collect();
+        if !depth_rows.is_empty() {
+            self.insert_depth_rows(&depth_rows).await?;
+            debug!("批量存储 {} 条深度快照", depth_rows.len());
+        }
+
         let rows: Vec<TradeRow> = events
             .iter()
             .filter_map(|e| self.event_to_row(e))
@@ -170,4 +323,230 @@ impl MarketStoragePort for ClickHouseStorage {
         debug!("批量存储 {} 条行情", rows.len());
         Ok(())
     }
+
+    /// 按时间范围分页查询成交
+    async fn query_trades(&self, query: &TradeQuery) -> Result<TradePage> {
+        // 多取一条用于判断是否还有下一页
+        let fetch_limit = query.limit as u64 + 1;
+        let start_ms = query.range.start.timestamp_millis();
+        let end_ms = query.range.end.timestamp_millis();
+
+        let rows: Vec<TradeReadRow> = match &query.cursor {
+            Some(cursor) => {
+                let sql = format!(
+                    r#"
+                    SELECT exchange, symbol, trade_id, price, quantity, is_buyer_maker,
+                           toUnixTimestamp64Milli(event_time) AS event_time_ms
+                    FROM {}
+                    WHERE symbol = ?
+                      AND event_time >= fromUnixTimestamp64Milli(toInt64(?))
+                      AND event_time < fromUnixTimestamp64Milli(toInt64(?))
+                      AND (event_time > fromUnixTimestamp64Milli(toInt64(?))
+                           OR (event_time = fromUnixTimestamp64Milli(toInt64(?)) AND trade_id > ?))
+                    ORDER BY event_time, trade_id
+                    LIMIT ?
+                    "#,
+                    self.table_name
+                );
+                self.client
+                    .query(&sql)
+                    .bind(&query.symbol)
+                    .bind(start_ms)
+                    .bind(end_ms)
+                    .bind(cursor.event_time_ms)
+                    .bind(cursor.event_time_ms)
+                    .bind(&cursor.trade_id)
+                    .bind(fetch_limit)
+                    .fetch_all()
+                    .await
+                    .context("查询成交失败")?
+            }
+            None => {
+                let sql = format!(
+                    r#"
+                    SELECT exchange, symbol, trade_id, price, quantity, is_buyer_maker,
+                           toUnixTimestamp64Milli(event_time) AS event_time_ms
+                    FROM {}
+                    WHERE symbol = ?
+                      AND event_time >= fromUnixTimestamp64Milli(toInt64(?))
+                      AND event_time < fromUnixTimestamp64Milli(toInt64(?))
+                    ORDER BY event_time, trade_id
+                    LIMIT ?
+                    "#,
+                    self.table_name
+                );
+                self.client
+                    .query(&sql)
+                    .bind(&query.symbol)
+                    .bind(start_ms)
+                    .bind(end_ms)
+                    .bind(fetch_limit)
+                    .fetch_all()
+                    .await
+                    .context("查询成交失败")?
+            }
+        };
+
+        let has_more = rows.len() > query.limit;
+        let trades: Vec<TradeRecord> = rows
+            .into_iter()
+            .take(query.limit)
+            .map(|row| {
+                Ok(TradeRecord {
+                    price: to_decimal(row.price, "price")?,
+                    quantity: to_decimal(row.quantity, "quantity")?,
+                    exchange: row.exchange,
+                    symbol: row.symbol,
+                    trade_id: row.trade_id,
+                    is_buyer_maker: row.is_buyer_maker,
+                    event_time: row.event_time_ms,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        let next_cursor = if has_more {
+            trades.last().map(TradeRecord::cursor)
+        } else {
+            None
+        };
+
+        Ok(TradePage { trades, next_cursor })
+    }
+
+    /// 从成交明细重采样 K 线
+    async fn query_klines(&self, query: &KlineQuery) -> Result<Vec<Kline>> {
+        let sql = format!(
+            r#"
+            SELECT toInt64(toUnixTimestamp(toStartOfInterval(toDateTime(event_time), INTERVAL {} SECOND))) * 1000 AS open_time,
+                   argMin(price, event_time) AS open,
+                   max(price) AS high,
+                   min(price) AS low,
+                   argMax(price, event_time) AS close,
+                   sum(quantity) AS volume,
+                   count() AS trade_count
+            FROM {}
+            WHERE symbol = ?
+              AND event_time >= fromUnixTimestamp64Milli(toInt64(?))
+              AND event_time < fromUnixTimestamp64Milli(toInt64(?))
+            GROUP BY open_time
+            ORDER BY open_time
+            LIMIT ?
+            "#,
+            query.interval.seconds(),
+            self.table_name
+        );
+
+        let rows: Vec<KlineReadRow> = self
+            .client
+            .query(&sql)
+            .bind(&query.symbol)
+            .bind(query.range.start.timestamp_millis())
+            .bind(query.range.end.timestamp_millis())
+            .bind(query.limit as u64)
+            .fetch_all()
+            .await
+            .context("查询 K 线失败")?;
+
+        let interval_ms = query.interval.millis();
+        rows.into_iter()
+            .map(|row| {
+                Ok(Kline {
+                    open_time: row.open_time,
+                    close_time: row.open_time + interval_ms,
+                    open: to_decimal(row.open, "open")?,
+                    high: to_decimal(row.high, "high")?,
+                    low: to_decimal(row.low, "low")?,
+                    close: to_decimal(row.close, "close")?,
+                    volume: to_decimal(row.volume, "volume")?,
+                    trade_count: row.trade_count,
+                })
+            })
+            .collect()
+    }
+
+    /// 查询最新成交价
+    async fn latest_price(&self, symbol: &str) -> Result<Option<PriceSnapshot>> {
+        let sql = format!(
+            r#"
+            SELECT symbol, price, toUnixTimestamp64Milli(event_time) AS event_time_ms
+            FROM {}
+            WHERE symbol = ?
+            ORDER BY event_time DESC
+            LIMIT 1
+            "#,
+            self.table_name
+        );
+
+        let rows: Vec<PriceReadRow> = self
+            .client
+            .query(&sql)
+            .bind(symbol)
+            .fetch_all()
+            .await
+            .context("查询最新价格失败")?;
+
+        rows.into_iter()
+            .next()
+            .map(|row| {
+                Ok(PriceSnapshot {
+                    price: to_decimal(row.price, "price")?,
+                    symbol: row.symbol,
+                    event_time: row.event_time_ms,
+                })
+            })
+            .transpose()
+    }
+
+    /// 查询深度快照
+    async fn depth_snapshot(
+        &self,
+        symbol: &str,
+        at: Option<DateTime<Utc>>,
+    ) -> Result<Option<DepthSnapshot>> {
+        let at_ms = at.unwrap_or_else(Utc::now).timestamp_millis();
+        let sql = format!(
+            r#"
+            SELECT exchange, symbol, bids, asks, toUnixTimestamp64Milli(event_time) AS event_time_ms
+            FROM {}
+            WHERE symbol = ?
+              AND event_time <= fromUnixTimestamp64Milli(toInt64(?))
+            ORDER BY event_time DESC
+            LIMIT 1
+            "#,
+            self.depth_table_name
+        );
+
+        let rows: Vec<DepthReadRow> = self
+            .client
+            .query(&sql)
+            .bind(symbol)
+            .bind(at_ms)
+            .fetch_all()
+            .await
+            .context("查询深度快照失败")?;
+
+        let Some(row) = rows.into_iter().next() else {
+            return Ok(None);
+        };
+
+        Ok(Some(DepthSnapshot {
+            exchange: row.exchange,
+            symbol: row.symbol,
+            bids: parse_levels(&row.bids)?,
+            asks: parse_levels(&row.asks)?,
+            event_time: row.event_time_ms,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_decimal_rejects_unrepresentable_values() {
+        assert_eq!(to_decimal(42000.5, "price").unwrap(), Decimal::new(420005, 1));
+        assert!(to_decimal(f64::NAN, "price").is_err());
+        assert!(to_decimal(f64::INFINITY, "price").is_err());
+    }
 }
@@ -0,0 +1,246 @@
+//! # 本地文件溢写队列 (File Spill Queue)
+//!
+//! 实现 SpillQueuePort，每个批次写为一个 JSON Lines 文件。
+//!
+//! ## 文件布局
+//! - `{dir}/{seq:020}.jsonl`: 一个批次，每行一个 MarketEvent
+//! - 先写 `.tmp` 再 rename，避免进程崩溃留下半个批次
+//! - 序号在启动时从已有文件恢复，重启后继续按顺序回放
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use shared::event::market_event::MarketEvent;
+use tokio::io::AsyncWriteExt;
+use tracing::{info, warn};
+
+use crate::domain::port::{SpillQueuePort, SpillSegment};
+
+/// 批次文件扩展名
+const SEGMENT_EXT: &str = "jsonl";
+
+/// 本地文件溢写队列
+pub struct FileSpillQueue {
+    dir: PathBuf,
+    /// 磁盘占用上限（字节）
+    max_bytes: u64,
+    /// 当前磁盘占用（字节）
+    used_bytes: AtomicU64,
+    /// 下一个批次序号
+    next_seq: AtomicU64,
+}
+
+impl FileSpillQueue {
+    /// 创建溢写队列，目录不存在时自动创建
+    ///
+    /// # 参数
+    /// - `dir`: 溢写目录
+    /// - `max_bytes`: 磁盘占用上限
+    pub fn new(dir: impl Into<PathBuf>, max_bytes: u64) -> Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("创建溢写目录失败: {}", dir.display()))?;
+
+        let mut used_bytes = 0u64;
+        let mut max_seq = 0u64;
+        for (seq, path) in list_segments(&dir)? {
+            max_seq = max_seq.max(seq);
+            used_bytes += std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        }
+
+        info!(
+            "溢写队列初始化: dir={}, used_bytes={}, max_bytes={}",
+            dir.display(),
+            used_bytes,
+            max_bytes
+        );
+
+        Ok(Self {
+            dir,
+            max_bytes,
+            used_bytes: AtomicU64::new(used_bytes),
+            next_seq: AtomicU64::new(max_seq + 1),
+        })
+    }
+
+    fn segment_path(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{}.{}", id, SEGMENT_EXT))
+    }
+}
+
+/// 列出目录下所有批次文件（按序号升序）
+fn list_segments(dir: &Path) -> Result<Vec<(u64, PathBuf)>> {
+    let mut segments = Vec::new();
+    let entries =
+        std::fs::read_dir(dir).with_context(|| format!("读取溢写目录失败: {}", dir.display()))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some(SEGMENT_EXT) {
+            continue;
+        }
+        let seq = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .and_then(|s| s.parse::<u64>().ok());
+        if let Some(seq) = seq {
+            segments.push((seq, path));
+        }
+    }
+    segments.sort_by_key(|(seq, _)| *seq);
+    Ok(segments)
+}
+
+#[async_trait]
+impl SpillQueuePort for FileSpillQueue {
+    async fn append(&self, events: &[MarketEvent]) -> Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let mut payload = String::new();
+        for event in events {
+            payload.push_str(&serde_json::to_string(event).context("序列化行情事件失败")?);
+            payload.push('\n');
+        }
+
+        let size = payload.len() as u64;
+        if self.used_bytes.load(Ordering::Relaxed) + size > self.max_bytes {
+            bail!("溢写队列已满: max_bytes={}", self.max_bytes);
+        }
+
+        let id = format!("{:020}", self.next_seq.fetch_add(1, Ordering::Relaxed));
+        let path = self.segment_path(&id);
+        let tmp_path = path.with_extension("tmp");
+
+        let mut file = tokio::fs::File::create(&tmp_path)
+            .await
+            .with_context(|| format!("创建溢写文件失败: {}", tmp_path.display()))?;
+        file.write_all(payload.as_bytes())
+            .await
+            .context("写入溢写文件失败")?;
+        file.sync_all().await.context("刷盘溢写文件失败")?;
+        drop(file);
+
+        tokio::fs::rename(&tmp_path, &path)
+            .await
+            .context("提交溢写文件失败")?;
+
+        self.used_bytes.fetch_add(size, Ordering::Relaxed);
+        Ok(())
+    }
+
+    async fn oldest(&self) -> Result<Option<SpillSegment>> {
+        let dir = self.dir.clone();
+        let segments = tokio::task::spawn_blocking(move || list_segments(&dir))
+            .await
+            .context("列出溢写文件失败")??;
+
+        let Some((seq, path)) = segments.into_iter().next() else {
+            return Ok(None);
+        };
+
+        let content = tokio::fs::read_to_string(&path)
+            .await
+            .with_context(|| format!("读取溢写文件失败: {}", path.display()))?;
+
+        let events = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match serde_json::from_str::<MarketEvent>(line) {
+                Ok(event) => Some(event),
+                Err(e) => {
+                    warn!(error = %e, file = %path.display(), "跳过无法解析的溢写记录");
+                    None
+                }
+            })
+            .collect();
+
+        Ok(Some(SpillSegment {
+            id: format!("{:020}", seq),
+            events,
+        }))
+    }
+
+    async fn remove(&self, id: &str) -> Result<()> {
+        let path = self.segment_path(id);
+        let size = tokio::fs::metadata(&path).await.map(|m| m.len()).unwrap_or(0);
+        tokio::fs::remove_file(&path)
+            .await
+            .with_context(|| format!("删除溢写文件失败: {}", path.display()))?;
+        // 饱和减法，避免与启动时统计的误差导致下溢
+        let _ = self
+            .used_bytes
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |v| {
+                Some(v.saturating_sub(size))
+            });
+        Ok(())
+    }
+
+    async fn pending_segments(&self) -> Result<usize> {
+        let dir = self.dir.clone();
+        let segments = tokio::task::spawn_blocking(move || list_segments(&dir))
+            .await
+            .context("列出溢写文件失败")??;
+        Ok(segments.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use rust_decimal::Decimal;
+    use shared::event::market_event::{MarketEventData, MarketEventType, TradeData};
+
+    fn trade(id: i64) -> MarketEvent {
+        MarketEvent {
+            event_type: MarketEventType::Trade,
+            exchange: "binance".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            timestamp: Utc.timestamp_millis_opt(id).unwrap(),
+            data: MarketEventData::Trade(TradeData {
+                trade_id: id.to_string(),
+                price: Decimal::from(50_000),
+                quantity: Decimal::ONE,
+                is_buyer_maker: false,
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_segments_are_fifo_and_survive_restart() {
+        let dir =
+            std::env::temp_dir().join(format!("market-data-spill-queue-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let queue = FileSpillQueue::new(&dir, 1 << 20).unwrap();
+        assert!(queue.oldest().await.unwrap().is_none());
+        queue.append(&[trade(1), trade(2)]).await.unwrap();
+        queue.append(&[trade(3)]).await.unwrap();
+        // 未提交的临时文件不算批次
+        std::fs::write(dir.join("00000000000000000099.tmp"), "partial").unwrap();
+        assert_eq!(queue.pending_segments().await.unwrap(), 2);
+
+        // 重启后从已有文件恢复序号，新批次排在最后
+        drop(queue);
+        let queue = FileSpillQueue::new(&dir, 1 << 20).unwrap();
+        queue.append(&[trade(4)]).await.unwrap();
+
+        let mut replayed = Vec::new();
+        while let Some(segment) = queue.oldest().await.unwrap() {
+            replayed.extend(segment.events.iter().map(|e| e.timestamp.timestamp_millis()));
+            queue.remove(&segment.id).await.unwrap();
+        }
+        assert_eq!(replayed, vec![1, 2, 3, 4]);
+        assert_eq!(queue.used_bytes.load(Ordering::Relaxed), 0);
+
+        // 超出容量上限时拒绝写入
+        let small = FileSpillQueue::new(&dir, 10).unwrap();
+        assert!(small.append(&[trade(5)]).await.is_err());
+        assert_eq!(small.pending_segments().await.unwrap(), 0);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -3,5 +3,7 @@
 //! 行情数据持久化实现。
 
 pub mod clickhouse_storage;
+pub mod file_spill_queue;
 
 pub use clickhouse_storage::ClickHouseStorage;
+pub use file_spill_queue::FileSpillQueue;
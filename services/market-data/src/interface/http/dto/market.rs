@@ -0,0 +1,88 @@
+//! # 行情查询 DTO
+//!
+//! 时间参数统一为毫秒时间戳；省略 `end` 时取当前时间。
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::model::{Kline, TradeRecord};
+
+/// GET /api/v1/market/trades 查询参数
+#[derive(Debug, Clone, Deserialize)]
+pub struct TradesQueryParams {
+    /// 交易对
+    pub symbol: String,
+    /// 开始时间（毫秒，默认 end 前 1 小时）
+    pub start: Option<i64>,
+    /// 结束时间（毫秒，不包含）
+    pub end: Option<i64>,
+    /// 单页条数
+    pub limit: Option<usize>,
+    /// 分页游标（上一页返回的 next_cursor）
+    pub cursor: Option<String>,
+}
+
+/// GET /api/v1/market/trades/stream 查询参数
+#[derive(Debug, Clone, Deserialize)]
+pub struct TradeStreamParams {
+    /// 交易对
+    pub symbol: String,
+    /// 开始时间（毫秒，默认 end 前 1 小时）
+    pub start: Option<i64>,
+    /// 结束时间（毫秒，不包含）
+    pub end: Option<i64>,
+}
+
+/// 成交分页响应
+#[derive(Debug, Clone, Serialize)]
+pub struct TradePageResponse {
+    /// 交易对
+    pub symbol: String,
+    /// 本页成交
+    pub trades: Vec<TradeRecord>,
+    /// 下一页游标（None 表示没有更多数据）
+    pub next_cursor: Option<String>,
+}
+
+/// GET /api/v1/market/klines 查询参数
+#[derive(Debug, Clone, Deserialize)]
+pub struct KlinesQueryParams {
+    /// 交易对
+    pub symbol: String,
+    /// 周期，如 1m / 5m / 1h / 1d
+    pub interval: String,
+    /// 开始时间（毫秒，默认 end 前 24 小时）
+    pub start: Option<i64>,
+    /// 结束时间（毫秒，不包含）
+    pub end: Option<i64>,
+    /// 最多返回根数
+    pub limit: Option<usize>,
+}
+
+/// K 线响应
+#[derive(Debug, Clone, Serialize)]
+pub struct KlinesResponse {
+    /// 交易对
+    pub symbol: String,
+    /// 周期
+    pub interval: String,
+    /// K 线
+    pub klines: Vec<Kline>,
+    /// 下一页开始时间（毫秒，None 表示没有更多数据）
+    pub next_start: Option<i64>,
+}
+
+/// GET /api/v1/market/price 查询参数
+#[derive(Debug, Clone, Deserialize)]
+pub struct PriceQueryParams {
+    /// 交易对
+    pub symbol: String,
+}
+
+/// GET /api/v1/market/depth 查询参数
+#[derive(Debug, Clone, Deserialize)]
+pub struct DepthQueryParams {
+    /// 交易对
+    pub symbol: String,
+    /// 快照时间点（毫秒，默认最新）
+    pub at: Option<i64>,
+}
@@ -0,0 +1,9 @@
+//! # HTTP DTO 模块
+//!
+//! 定义 HTTP 请求/响应的数据传输对象。
+
+pub mod common;
+pub mod market;
+
+pub use common::*;
+pub use market::*;
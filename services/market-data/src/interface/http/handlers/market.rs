@@ -0,0 +1,252 @@
+//! # 行情查询处理器 (Market Query Handlers)
+
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::extract::{Query, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use chrono::{Duration, Utc};
+use futures_util::StreamExt;
+use shared::event::quality_event::DataQualityEvent;
+
+use crate::application::StorageWriterMetricsSnapshot;
+use crate::domain::model::market_query::millis_to_datetime;
+use crate::domain::model::{
+    DepthSnapshot, KlineInterval, PriceSnapshot, TimeRange, TradeCursor, TradeRecord,
+};
+use crate::interface::http::dto::{
+    ApiResponse, DepthQueryParams, KlinesQueryParams, KlinesResponse, PriceQueryParams,
+    TradePageResponse, TradeStreamParams, TradesQueryParams,
+};
+use crate::state::AppState;
+
+/// GET /api/v1/market/trades
+pub async fn list_trades(
+    State(state): State<AppState>,
+    Query(params): Query<TradesQueryParams>,
+) -> Json<ApiResponse<TradePageResponse>> {
+    let Some(service) = state.query_service.as_ref() else {
+        return Json(ApiResponse::err("market storage is not enabled"));
+    };
+
+    let range = match resolve_range(params.start, params.end, Duration::hours(1)) {
+        Ok(v) => v,
+        Err(e) => return Json(ApiResponse::err(e.to_string())),
+    };
+
+    let cursor = match params.cursor.as_deref().map(TradeCursor::decode).transpose() {
+        Ok(v) => v,
+        Err(e) => return Json(ApiResponse::err(e.to_string())),
+    };
+
+    match service.trades(&params.symbol, range, params.limit, cursor).await {
+        Ok(page) => Json(ApiResponse::ok(TradePageResponse {
+            symbol: params.symbol.trim().to_uppercase(),
+            trades: page.trades,
+            next_cursor: page.next_cursor.map(|c| c.encode()),
+        })),
+        Err(e) => {
+            tracing::warn!(error = %e, symbol = %params.symbol, "query trades failed");
+            Json(ApiResponse::err(format!("query trades failed: {}", e)))
+        }
+    }
+}
+
+/// GET /api/v1/market/trades/stream
+///
+/// 以 NDJSON（每行一条 TradeRecord）流式返回整个时间范围内的成交。
+/// 查询中途失败时，最后一行为 `{"error": "..."}`。
+pub async fn stream_trades(
+    State(state): State<AppState>,
+    Query(params): Query<TradeStreamParams>,
+) -> Response {
+    let Some(service) = state.query_service.as_ref() else {
+        return Json(ApiResponse::<()>::err("market storage is not enabled")).into_response();
+    };
+
+    let range = match resolve_range(params.start, params.end, Duration::hours(1)) {
+        Ok(v) => v,
+        Err(e) => return Json(ApiResponse::<()>::err(e.to_string())).into_response(),
+    };
+
+    let body = Arc::clone(service)
+        .stream_trades(&params.symbol, range)
+        .map(|page| Ok::<_, Infallible>(encode_ndjson_page(page)));
+
+    (
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        Body::from_stream(body),
+    )
+        .into_response()
+}
+
+/// GET /api/v1/market/klines
+pub async fn list_klines(
+    State(state): State<AppState>,
+    Query(params): Query<KlinesQueryParams>,
+) -> Json<ApiResponse<KlinesResponse>> {
+    let Some(service) = state.query_service.as_ref() else {
+        return Json(ApiResponse::err("market storage is not enabled"));
+    };
+
+    let interval = match KlineInterval::parse(&params.interval) {
+        Ok(v) => v,
+        Err(e) => return Json(ApiResponse::err(e.to_string())),
+    };
+
+    let range = match resolve_range(params.start, params.end, Duration::hours(24)) {
+        Ok(v) => v,
+        Err(e) => return Json(ApiResponse::err(e.to_string())),
+    };
+
+    match service
+        .klines(&params.symbol, interval, range, params.limit)
+        .await
+    {
+        Ok((klines, next_start)) => Json(ApiResponse::ok(KlinesResponse {
+            symbol: params.symbol.trim().to_uppercase(),
+            interval: params.interval.trim().to_string(),
+            klines,
+            next_start,
+        })),
+        Err(e) => {
+            tracing::warn!(error = %e, symbol = %params.symbol, "query klines failed");
+            Json(ApiResponse::err(format!("query klines failed: {}", e)))
+        }
+    }
+}
+
+/// GET /api/v1/market/price
+pub async fn latest_price(
+    State(state): State<AppState>,
+    Query(params): Query<PriceQueryParams>,
+) -> Json<ApiResponse<PriceSnapshot>> {
+    let Some(service) = state.query_service.as_ref() else {
+        return Json(ApiResponse::err("market storage is not enabled"));
+    };
+
+    match service.latest_price(&params.symbol).await {
+        Ok(Some(snapshot)) => Json(ApiResponse::ok(snapshot)),
+        Ok(None) => Json(ApiResponse::err(format!("no trades for {}", params.symbol))),
+        Err(e) => {
+            tracing::warn!(error = %e, symbol = %params.symbol, "query latest price failed");
+            Json(ApiResponse::err(format!("query latest price failed: {}", e)))
+        }
+    }
+}
+
+/// GET /api/v1/market/depth
+pub async fn depth_snapshot(
+    State(state): State<AppState>,
+    Query(params): Query<DepthQueryParams>,
+) -> Json<ApiResponse<DepthSnapshot>> {
+    let Some(service) = state.query_service.as_ref() else {
+        return Json(ApiResponse::err("market storage is not enabled"));
+    };
+
+    let at = match params.at.map(millis_to_datetime).transpose() {
+        Ok(v) => v,
+        Err(e) => return Json(ApiResponse::err(e.to_string())),
+    };
+
+    match service.depth_snapshot(&params.symbol, at).await {
+        Ok(Some(snapshot)) => Json(ApiResponse::ok(snapshot)),
+        Ok(None) => Json(ApiResponse::err(format!(
+            "no depth snapshot for {}",
+            params.symbol
+        ))),
+        Err(e) => {
+            tracing::warn!(error = %e, symbol = %params.symbol, "query depth snapshot failed");
+            Json(ApiResponse::err(format!("query depth snapshot failed: {}", e)))
+        }
+    }
+}
+
+/// GET /api/v1/market/storage/metrics
+pub async fn storage_metrics(
+    State(state): State<AppState>,
+) -> Json<ApiResponse<StorageWriterMetricsSnapshot>> {
+    match state.storage_writer.as_ref() {
+        Some(writer) => Json(ApiResponse::ok(writer.metrics())),
+        None => Json(ApiResponse::err("market storage is not enabled")),
+    }
+}
+
+/// GET /api/v1/market/quality
+pub async fn quality_status(
+    State(state): State<AppState>,
+) -> Json<ApiResponse<Vec<DataQualityEvent>>> {
+    match state.quality_service.as_ref() {
+        Some(quality) => {
+            let mut statuses = quality.snapshot();
+            statuses.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+            Json(ApiResponse::ok(statuses))
+        }
+        None => Json(ApiResponse::err("market quality monitor is not enabled")),
+    }
+}
+
+/// 解析时间范围：缺省 end 为当前时间，缺省 start 为 end 前 `default_span`
+fn resolve_range(
+    start: Option<i64>,
+    end: Option<i64>,
+    default_span: Duration,
+) -> anyhow::Result<TimeRange> {
+    let end = match end {
+        Some(ms) => millis_to_datetime(ms)?,
+        None => Utc::now(),
+    };
+    let start = match start {
+        Some(ms) => millis_to_datetime(ms)?,
+        None => end - default_span,
+    };
+    TimeRange::new(start, end)
+}
+
+/// 将一页成交编码为 NDJSON 文本块
+fn encode_ndjson_page(page: anyhow::Result<Vec<TradeRecord>>) -> String {
+    match page {
+        Ok(trades) => {
+            let mut chunk = String::new();
+            for trade in &trades {
+                if let Ok(line) = serde_json::to_string(trade) {
+                    chunk.push_str(&line);
+                    chunk.push('\n');
+                }
+            }
+            chunk
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "stream trades failed");
+            let line = serde_json::json!({ "error": e.to_string() });
+            format!("{}\n", line)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_range() {
+        let range = resolve_range(Some(1_000), Some(2_000), Duration::hours(1)).unwrap();
+        assert_eq!(range.start.timestamp_millis(), 1_000);
+        assert_eq!(range.end.timestamp_millis(), 2_000);
+
+        // 缺省 start 为 end 前 default_span
+        let range = resolve_range(None, Some(7_200_000), Duration::hours(1)).unwrap();
+        assert_eq!(range.start.timestamp_millis(), 3_600_000);
+
+        // 缺省 end 为当前时间
+        let range = resolve_range(None, None, Duration::hours(24)).unwrap();
+        assert_eq!(range.end - range.start, Duration::hours(24));
+
+        assert!(resolve_range(Some(2_000), Some(2_000), Duration::hours(1)).is_err());
+        assert!(resolve_range(Some(3_000), Some(2_000), Duration::hours(1)).is_err());
+        assert!(resolve_range(Some(i64::MAX), None, Duration::hours(1)).is_err());
+    }
+}
@@ -0,0 +1,7 @@
+//! # HTTP 处理器 (HTTP Handlers)
+
+/// 健康检查处理器
+pub mod health;
+
+/// 行情查询处理器
+pub mod market;
@@ -0,0 +1,12 @@
+//! # HTTP 接口 (HTTP Interface)
+//!
+//! 提供历史行情查询的 HTTP REST API。
+
+/// 请求处理器
+pub mod handlers;
+
+/// 路由配置
+pub mod routes;
+
+/// 数据传输对象
+pub mod dto;
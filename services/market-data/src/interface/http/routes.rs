@@ -0,0 +1,36 @@
+//! # 路由配置 (Route Configuration)
+//!
+//! 定义行情数据服务的所有 HTTP 路由。
+//!
+//! ## API 端点
+//! - `GET /health`: 健康检查
+//! - `GET /api/v1/market/trades`: 成交明细（游标分页）
+//! - `GET /api/v1/market/trades/stream`: 成交明细（NDJSON 流式，适合大范围导出）
+//! - `GET /api/v1/market/klines`: K 线（服务端按周期重采样）
+//! - `GET /api/v1/market/price`: 最新成交价
+//! - `GET /api/v1/market/depth`: 深度快照
+//! - `GET /api/v1/market/storage/metrics`: 存储写入指标（积压 / 延迟 / 丢弃）
+//! - `GET /api/v1/market/quality`: 各交易对数据质量状态
+
+use axum::{routing::get, Router};
+
+use super::handlers;
+use crate::state::AppState;
+
+/// 创建 HTTP 路由器
+pub fn create_router(state: AppState) -> Router {
+    Router::new()
+        // 健康检查
+        .route("/health", get(handlers::health::health_check))
+        // 历史行情查询
+        .route("/api/v1/market/trades", get(handlers::market::list_trades))
+        .route("/api/v1/market/trades/stream", get(handlers::market::stream_trades))
+        .route("/api/v1/market/klines", get(handlers::market::list_klines))
+        .route("/api/v1/market/price", get(handlers::market::latest_price))
+        .route("/api/v1/market/depth", get(handlers::market::depth_snapshot))
+        // 存储写入指标
+        .route("/api/v1/market/storage/metrics", get(handlers::market::storage_metrics))
+        // 数据质量
+        .route("/api/v1/market/quality", get(handlers::market::quality_status))
+        .with_state(state)
+}
@@ -4,9 +4,11 @@
 //! - `application`: 应用层（用例编排）
 //! - `domain`: 领域层（端口定义）
 //! - `infrastructure`: 基础设施层（适配器实现）
+//! - `interface`: 接口层（历史行情查询 HTTP API）
 
 pub mod state;
 pub mod application;
 pub mod domain;
 pub mod infrastructure;
+pub mod interface;
 pub mod bootstrap;
@@ -1,26 +1,33 @@
 //! # 行情数据服务 (Market Data Service)
 //!
-//! 端口: 8082 (未使用 - 无 HTTP API)
+//! 端口: 8082 (历史行情查询 HTTP API)
 //!
 //! ## 服务职责
-//! - 连接交易所 WebSocket 获取实时行情
-//! - 标准化行情数据格式
+//! - 连接交易所 WebSocket 获取实时行情（Binance / OKX / Bybit / Coinbase，可并发）
+//! - 标准化行情数据格式（统一交易对映射表）
 //! - 发布到 Kafka 供其他服务消费
+//! - 存储到 ClickHouse，并提供只读查询 API
+//! - 监控行情数据质量，按交易对发布 DataQuality 到 Kafka
+//! - 录制行情到本地压缩文件；`MARKET_DATA_MODE=replay` 时回放录制窗口后退出
 //!
 //! ## 架构说明
-//! market-data 是纯粹的行情采集器（Market Ingestor）
-//! ❌ 不提供 HTTP API
-//! ❌ 不存储数据
+//! market-data 是行情采集器（Market Ingestor）+ 历史行情读侧
+//! ✅ HTTP API 只读（成交 / K 线 / 最新价 / 深度快照）
 //! ❌ 不做业务判断
 
 mod state;
 mod application;
 mod domain;
 mod infrastructure;
+mod interface;
 mod bootstrap;
 
-use anyhow::Result;
-use tracing::info;
+use std::future::IntoFuture;
+use std::net::SocketAddr;
+
+use anyhow::{anyhow, Result};
+use tokio::task::JoinSet;
+use tracing::{error, info};
 use tracing_subscriber::EnvFilter;
 use crate::state::MarketDataConfig;
 
@@ -42,9 +49,76 @@ async fn main() -> Result<()> {
     // 加载配置
     let config = MarketDataConfig::from_env();
 
+    // 回放模式：回放录制窗口到 Kafka 后退出
+    if config.replay_mode {
+        let (replay, range, speed) = bootstrap::build_replay(&config)?;
+        let stats = replay.replay(range, speed).await?;
+        info!(
+            published = stats.published,
+            failed = stats.failed,
+            "Market Data replay finished"
+        );
+        return Ok(());
+    }
+
     // 构建服务（依赖注入在 bootstrap 中完成）
-    let service = bootstrap::build(config.clone())?;
+    let (services, state, background) = bootstrap::build(config.clone())?;
+
+    // 运行行情采集循环（每个交易所一个任务）
+    let mut ingest_tasks = JoinSet::new();
+    for service in services {
+        let symbols = config.symbols.clone();
+        ingest_tasks.spawn(async move { service.run(symbols).await });
+    }
+
+    // 运行行情质量检查循环
+    let quality_handle = state.quality_service.clone().map(|quality| {
+        tokio::spawn(async move {
+            quality.run().await;
+        })
+    });
+
+    // 启动 HTTP 查询接口
+    let app = interface::http::routes::create_router(state);
+    let addr = SocketAddr::from(([0, 0, 0, 0], config.http_port));
+    info!("Market Data query API listening on {}", addr);
+
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    let server = axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .into_future();
+
+    // 任一交易所采集退出时整个进程退出，避免查询接口继续提供陈旧数据
+    let result = tokio::select! {
+        served = server => served.map_err(anyhow::Error::from),
+        Some(joined) = ingest_tasks.join_next() => {
+            let result = match joined {
+                Ok(result) => result,
+                Err(e) => Err(anyhow!("market data ingest task failed: {}", e)),
+            };
+            if let Err(e) = &result {
+                error!(error = %e, "market data ingest stopped, shutting down");
+            }
+            result
+        }
+    };
+
+    // 先停止采集，再刷写缓冲中的数据
+    ingest_tasks.abort_all();
+    while ingest_tasks.join_next().await.is_some() {}
+    if let Some(handle) = quality_handle {
+        handle.abort();
+    }
+    background.shutdown().await;
+    info!("Market Data Service 已关闭");
+    result
+}
+
+async fn shutdown_signal() {
+    if let Err(err) = tokio::signal::ctrl_c().await {
+        error!(error = %err, "监听 Ctrl+C 失败");
+        return;
+    }
 
-    // 运行行情采集循环
-    service.run(config.symbols).await
+    info!("收到 Ctrl+C，开始优雅关闭");
 }
@@ -1,17 +1,48 @@
 //! # 应用配置 (Application Config)
 //!
 //! market-data 服务的配置管理。
-//! 注意：market-data 不需要 AppState，只需要配置。
+//!
+//! ## 说明
+//! - `MarketDataConfig`: 行情采集与查询配置
+//! - `AppState`: HTTP 查询接口共享状态（只持有读侧服务）
+
+use std::sync::Arc;
+
+use crate::application::{MarketQueryService, QualityService, StorageWriterHandle};
+use crate::domain::model::MarketExchange;
+
+/// HTTP 接口共享状态
+#[derive(Clone)]
+pub struct AppState {
+    /// 行情查询服务（存储未启用时为 None）
+    pub query_service: Option<Arc<MarketQueryService>>,
+    /// 存储写入器句柄（用于读取写入指标，存储未启用时为 None）
+    pub storage_writer: Option<StorageWriterHandle>,
+    /// 行情质量服务（未启用时为 None）
+    pub quality_service: Option<Arc<QualityService>>,
+}
 
 /// 行情服务配置
 #[derive(Debug, Clone)]
 pub struct MarketDataConfig {
-    /// WebSocket 连接地址
+    /// 启用的交易所（并发采集）
+    pub exchanges: Vec<MarketExchange>,
+    /// 币安 WebSocket 连接地址
     pub ws_url: String,
+    /// OKX WebSocket 连接地址
+    pub okx_ws_url: String,
+    /// Bybit WebSocket 连接地址
+    pub bybit_ws_url: String,
+    /// Coinbase WebSocket 连接地址
+    pub coinbase_ws_url: String,
+    /// 交易对映射覆盖项（`exchange:CANONICAL=NATIVE`，逗号分隔）
+    pub symbol_map: String,
     /// Kafka broker 地址
     pub kafka_brokers: String,
     /// Kafka topic 名称
     pub kafka_topic: String,
+    /// 是否按交易所拆分 topic（`{kafka_topic}.{exchange}`）
+    pub kafka_topic_per_exchange: bool,
     /// 订阅的交易对（小写）
     pub symbols: Vec<String>,
     /// 代理地址（可选）
@@ -24,6 +55,54 @@ pub struct MarketDataConfig {
     pub clickhouse_table: String,
     /// 是否启用存储
     pub storage_enabled: bool,
+    /// 存储批量写入条数
+    pub storage_batch_size: usize,
+    /// 存储刷写间隔（毫秒）
+    pub storage_flush_ms: u64,
+    /// 存储内存缓冲区容量（条）
+    pub storage_buffer_capacity: usize,
+    /// 存储单批最大重试次数
+    pub storage_max_retries: u32,
+    /// 溢写目录（为空时禁用溢写）
+    pub storage_spill_dir: Option<String>,
+    /// 溢写磁盘占用上限（字节）
+    pub storage_spill_max_bytes: u64,
+    /// 是否启用行情质量监控
+    pub quality_enabled: bool,
+    /// 数据质量 Kafka topic
+    pub quality_topic: String,
+    /// 断流阈值（秒）
+    pub quality_stale_secs: i64,
+    /// 异常价格阈值 k（k·σ）
+    pub quality_spike_sigma: f64,
+    /// 跨源最大偏离（百分比）
+    pub quality_max_deviation_pct: f64,
+    /// 恢复观察期（秒）
+    pub quality_recovery_secs: i64,
+    /// 质量心跳间隔（秒）
+    pub quality_heartbeat_secs: u64,
+    /// 是否启用行情录制
+    pub recording_enabled: bool,
+    /// 录制根目录（回放也从这里读取）
+    pub recording_dir: String,
+    /// 是否同时录制交易所原始消息
+    pub recording_raw: bool,
+    /// 录制内存通道容量（条）
+    pub recording_buffer_capacity: usize,
+    /// 是否以回放模式运行（`MARKET_DATA_MODE=replay`）
+    pub replay_mode: bool,
+    /// 回放开始时间（RFC3339 或毫秒时间戳）
+    pub replay_start: Option<String>,
+    /// 回放结束时间（RFC3339 或毫秒时间戳）
+    pub replay_end: Option<String>,
+    /// 回放速度（`1` / `10x` / `max`）
+    pub replay_speed: String,
+    /// 回放发布的 Kafka topic
+    pub replay_topic: String,
+    /// HTTP 查询接口端口
+    pub http_port: u16,
+    /// 查询接口单次最大条数
+    pub query_max_limit: usize,
 }
 
 impl MarketDataConfig {
@@ -41,12 +120,25 @@ impl MarketDataConfig {
         }
 
         Self {
+            exchanges: parse_exchanges(
+                &std::env::var("MARKET_DATA_EXCHANGES").unwrap_or_else(|_| "binance".to_string()),
+            ),
             ws_url: std::env::var("BINANCE_WS_URL")
                 .unwrap_or_else(|_| "wss://stream.binance.com:9443/ws".to_string()),
+            okx_ws_url: std::env::var("OKX_WS_URL")
+                .unwrap_or_else(|_| "wss://ws.okx.com:8443/ws/v5/public".to_string()),
+            bybit_ws_url: std::env::var("BYBIT_WS_URL")
+                .unwrap_or_else(|_| "wss://stream.bybit.com/v5/public/spot".to_string()),
+            coinbase_ws_url: std::env::var("COINBASE_WS_URL")
+                .unwrap_or_else(|_| "wss://ws-feed.exchange.coinbase.com".to_string()),
+            symbol_map: std::env::var("MARKET_DATA_SYMBOL_MAP").unwrap_or_default(),
             kafka_brokers: std::env::var("KAFKA_BROKERS")
                 .unwrap_or_else(|_| "localhost:9092".to_string()),
             kafka_topic: std::env::var("KAFKA_MARKET_TOPIC")
                 .unwrap_or_else(|_| "market-events".to_string()),
+            kafka_topic_per_exchange: std::env::var("KAFKA_MARKET_TOPIC_PER_EXCHANGE")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
             symbols,
             proxy_url: std::env::var("MARKET_DATA_PROXY")
                 .ok()
@@ -61,6 +153,79 @@ impl MarketDataConfig {
             storage_enabled: std::env::var("MARKET_DATA_STORAGE_ENABLED")
                 .map(|v| v == "true" || v == "1")
                 .unwrap_or(true),
+            storage_batch_size: read_env("MARKET_DATA_STORAGE_BATCH_SIZE", 1000),
+            storage_flush_ms: read_env("MARKET_DATA_STORAGE_FLUSH_MS", 1000),
+            storage_buffer_capacity: read_env("MARKET_DATA_STORAGE_BUFFER_CAPACITY", 100_000),
+            storage_max_retries: read_env("MARKET_DATA_STORAGE_MAX_RETRIES", 3),
+            storage_spill_dir: match std::env::var("MARKET_DATA_STORAGE_SPILL_DIR") {
+                Ok(v) if v.trim().is_empty() => None,
+                Ok(v) => Some(v),
+                Err(_) => Some("./data/market-spill".to_string()),
+            },
+            storage_spill_max_bytes: read_env(
+                "MARKET_DATA_STORAGE_SPILL_MAX_BYTES",
+                1024 * 1024 * 1024,
+            ),
+            quality_enabled: std::env::var("MARKET_DATA_QUALITY_ENABLED")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(true),
+            quality_topic: std::env::var("KAFKA_MARKET_QUALITY_TOPIC")
+                .unwrap_or_else(|_| "market-quality".to_string()),
+            quality_stale_secs: read_env("MARKET_DATA_QUALITY_STALE_SECS", 30),
+            quality_spike_sigma: read_env("MARKET_DATA_QUALITY_SPIKE_SIGMA", 6.0),
+            quality_max_deviation_pct: read_env("MARKET_DATA_QUALITY_MAX_DEVIATION_PCT", 1.0),
+            quality_recovery_secs: read_env("MARKET_DATA_QUALITY_RECOVERY_SECS", 60),
+            quality_heartbeat_secs: read_env("MARKET_DATA_QUALITY_HEARTBEAT_SECS", 30),
+            recording_enabled: std::env::var("MARKET_DATA_RECORDING_ENABLED")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            recording_dir: std::env::var("MARKET_DATA_RECORDING_DIR")
+                .unwrap_or_else(|_| "./data/market-recording".to_string()),
+            recording_raw: std::env::var("MARKET_DATA_RECORDING_RAW")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(true),
+            recording_buffer_capacity: read_env("MARKET_DATA_RECORDING_BUFFER_CAPACITY", 100_000),
+            replay_mode: std::env::var("MARKET_DATA_MODE")
+                .map(|v| v.eq_ignore_ascii_case("replay"))
+                .unwrap_or(false),
+            replay_start: std::env::var("MARKET_DATA_REPLAY_START").ok(),
+            replay_end: std::env::var("MARKET_DATA_REPLAY_END").ok(),
+            replay_speed: std::env::var("MARKET_DATA_REPLAY_SPEED")
+                .unwrap_or_else(|_| "1".to_string()),
+            replay_topic: std::env::var("KAFKA_MARKET_REPLAY_TOPIC")
+                .unwrap_or_else(|_| "market-events.replay".to_string()),
+            http_port: std::env::var("MARKET_DATA_PORT")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(8082),
+            query_max_limit: std::env::var("MARKET_DATA_QUERY_MAX_LIMIT")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5000),
+        }
+    }
+}
+
+/// 解析交易所列表，忽略未知项；结果为空时回退到币安
+fn parse_exchanges(raw: &str) -> Vec<MarketExchange> {
+    let mut exchanges = Vec::new();
+    for name in raw.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        match MarketExchange::parse(name) {
+            Some(exchange) if !exchanges.contains(&exchange) => exchanges.push(exchange),
+            Some(_) => {}
+            None => tracing::warn!("忽略未知交易所: {}", name),
         }
     }
+    if exchanges.is_empty() {
+        exchanges.push(MarketExchange::Binance);
+    }
+    exchanges
+}
+
+/// 读取可解析的环境变量，缺失或非法时使用默认值
+fn read_env<T: std::str::FromStr>(key: &str, default: T) -> T {
+    std::env::var(key)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(default)
 }
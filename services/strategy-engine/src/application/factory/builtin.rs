@@ -0,0 +1,1578 @@
+//! # 内置策略注册 (Builtin Strategies)
+//!
+//! 所有 `Strategy` 实现的注册项：参数描述、参数校验与构造函数。
+//!
+//! ## 规则
+//! - ✅ 参数描述中的默认值必须与配置结构的 `Default` 一致（`test_schema_matches_default_config` 检查）
+//! - ✅ 新增策略时在 `descriptors()` 中追加一项即可
+
+use anyhow::Result;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::domain::logic::ai::ml_signal::{FeatureSpec, MlSignalConfig};
+use crate::domain::logic::ai::MlSignalStrategy;
+use crate::domain::logic::ensemble::{EnsembleConfig, EnsembleMethod, EnsembleStrategy};
+use crate::domain::logic::futures::bollinger::FuturesBollingerConfig;
+use crate::domain::logic::futures::breakout::BreakoutConfig;
+use crate::domain::logic::futures::calendar_spread::CalendarSpreadConfig;
+use crate::domain::logic::futures::funding_arb::FundingArbConfig;
+use crate::domain::logic::futures::grid::FuturesGridConfig;
+use crate::domain::logic::futures::macd::FuturesMacdConfig;
+use crate::domain::logic::futures::mean::FuturesMeanReversionConfig;
+use crate::domain::logic::futures::pairs_trading::PairsTradingConfig;
+use crate::domain::logic::futures::reversal::ReversalConfig;
+use crate::domain::logic::futures::rsi::FuturesRsiConfig;
+use crate::domain::logic::futures::trend_following::TrendFollowingConfig;
+use crate::domain::logic::futures::{
+    BreakoutStrategy, CalendarSpreadStrategy, FundingArbStrategy, FuturesBollingerStrategy,
+    FuturesGridStrategy, FuturesMacdStrategy, FuturesMeanReversionStrategy, FuturesRsiStrategy,
+    PairsTradingStrategy, ReversalStrategy, TrendFollowingStrategy,
+};
+use crate::domain::logic::hft::market_making::MarketMakingConfig;
+use crate::domain::logic::hft::order_flow::OrderFlowConfig;
+use crate::domain::logic::hft::{MarketMakingStrategy, OrderFlowStrategy};
+use crate::domain::logic::script::{SandboxLimits, ScriptConfig, ScriptStrategy};
+use crate::domain::logic::spot::bollinger::SpotBollingerConfig;
+use crate::domain::logic::spot::dca::SpotDcaConfig;
+use crate::domain::logic::spot::grid::SpotGridConfig;
+use crate::domain::logic::spot::macd::SpotMacdConfig;
+use crate::domain::logic::spot::mean::SpotMeanReversionConfig;
+use crate::domain::logic::spot::rebalance::SpotRebalanceConfig;
+use crate::domain::logic::spot::rsi::SpotRsiConfig;
+use crate::domain::logic::spot::{
+    SpotBollingerStrategy, SpotDcaStrategy, SpotGridStrategy, SpotMacdStrategy,
+    SpotMeanReversionStrategy, SpotRebalanceStrategy, SpotRsiStrategy,
+};
+use crate::domain::model::market_type::{LeverageConfig, MarketType};
+use crate::domain::model::timer::TimerSchedule;
+use crate::infrastructure::model::load_model;
+
+use super::param_schema::{ParamField, ParamSchema};
+use super::strategy_factory::{
+    futures_executor, loaded_executor, spot_executor, ParamErrors, StrategyDescriptor,
+    StrategyParams,
+};
+
+/// 现货市场
+const SPOT: &[MarketType] = &[MarketType::Spot];
+/// 合约市场
+const FUTURES: &[MarketType] = &[MarketType::UsdtFutures, MarketType::CoinFutures];
+/// 全部市场
+const ANY_MARKET: &[MarketType] = &[
+    MarketType::Spot,
+    MarketType::UsdtFutures,
+    MarketType::CoinFutures,
+];
+
+/// 交易所允许的最大杠杆
+const MAX_LEVERAGE: u32 = 125;
+
+/// 全部内置策略
+pub(crate) fn descriptors() -> Vec<StrategyDescriptor> {
+    vec![
+        // ==================== 现货 ====================
+        StrategyDescriptor {
+            strategy_type: "spot_grid",
+            description: "现货网格：在价格区间内等分网格，下穿买入、上穿卖出",
+            market_types: SPOT,
+            params: grid_schema(false),
+            constructor: |c| spot_executor(c, SpotGridStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "spot_mean_reversion",
+            description: "现货均值回归：价格偏离移动平均超过阈值时反向交易",
+            market_types: SPOT,
+            params: mean_reversion_schema(false),
+            constructor: |c| spot_executor(c, SpotMeanReversionStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "spot_macd",
+            description: "现货 MACD：MACD 线与信号线交叉时交易",
+            market_types: SPOT,
+            params: macd_schema(false),
+            constructor: |c| spot_executor(c, SpotMacdStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "spot_bollinger",
+            description: "现货布林带：触及下轨买入、触及上轨卖出",
+            market_types: SPOT,
+            params: bollinger_schema(false),
+            constructor: |c| spot_executor(c, SpotBollingerStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "spot_rsi",
+            description: "现货 RSI：超卖买入、超买卖出",
+            market_types: SPOT,
+            params: rsi_schema(false),
+            constructor: |c| spot_executor(c, SpotRsiStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "spot_dca",
+            description: "现货定投：按定时计划以固定金额买入，价格较近期高点回撤时按档位加码",
+            market_types: SPOT,
+            params: ParamSchema::new()
+                .field(
+                    "amount",
+                    ParamField::decimal("每次定投金额（计价资产）")
+                        .default_value("100")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "schedule",
+                    schedule_field(serde_json::json!({
+                        "type": "interval",
+                        "every_secs": 86400,
+                        "offset_secs": 0
+                    })),
+                )
+                .field(
+                    "dip_lookback",
+                    ParamField::integer("近期高点回看周期（价格更新次数）")
+                        .default_value(1000)
+                        .minimum(1),
+                )
+                .field(
+                    "dip_levels",
+                    ParamField::array("逢跌加码档位：[{\"drawdown\": 0.1, \"multiplier\": 2}, ...]")
+                        .default_value(serde_json::json!([])),
+                )
+                .field(
+                    "max_total_amount",
+                    ParamField::decimal("累计投入上限（0 不限）")
+                        .default_value("0")
+                        .minimum(0),
+                ),
+            constructor: |c| spot_executor(c, SpotDcaStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "spot_rebalance",
+            description: "现货组合再平衡：按目标权重持有多个现货资产，权重偏离超过阈值或定期调回目标",
+            market_types: SPOT,
+            params: ParamSchema::new()
+                .field(
+                    "targets",
+                    ParamField::map("目标权重（交易对 -> 权重，之和不超过 1，剩余为现金）")
+                        .default_value(serde_json::json!({ "BTCUSDT": "0.5", "ETHUSDT": "0.3" })),
+                )
+                .field(
+                    "capital",
+                    ParamField::decimal("初始投入资金（计价资产）")
+                        .default_value("10000")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "mode",
+                    ParamField::enumeration("触发方式", &["threshold", "calendar"])
+                        .default_value("threshold"),
+                )
+                .field(
+                    "drift_threshold",
+                    ParamField::decimal("权重偏离阈值（阈值模式，0.05 表示 5 个百分点）")
+                        .default_value("0.05")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "schedule",
+                    schedule_field(serde_json::json!({ "type": "cron", "expression": "0 0 * * 1" })),
+                )
+                .field(
+                    "min_trade_value",
+                    ParamField::decimal("最小调整金额（计价资产）")
+                        .default_value("10")
+                        .minimum(0),
+                ),
+            constructor: |c| spot_executor(c, SpotRebalanceStrategy::new),
+        },
+        // ==================== 合约 ====================
+        StrategyDescriptor {
+            strategy_type: "futures_grid",
+            description: "合约网格：支持杠杆与持仓方向的网格交易",
+            market_types: FUTURES,
+            params: grid_schema(true),
+            constructor: |c| futures_executor(c, FuturesGridStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "futures_mean_reversion",
+            description: "合约均值回归：价格偏离移动平均超过阈值时反向开仓",
+            market_types: FUTURES,
+            params: mean_reversion_schema(true),
+            constructor: |c| futures_executor(c, FuturesMeanReversionStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "funding_arb",
+            description: "资金费率套利：资金费率绝对值超过阈值时反向持仓收取费率",
+            market_types: FUTURES,
+            params: ParamSchema::new()
+                .field(
+                    "funding_rate_threshold",
+                    ParamField::decimal("资金费率阈值（绝对值，0.001 表示 0.1%）")
+                        .default_value("0.001")
+                        .exclusive_minimum(0),
+                )
+                .field("quantity", quantity_field())
+                .field("leverage", leverage_field(3))
+                .field(
+                    "max_hold_hours",
+                    ParamField::integer("最大持仓时间（小时）")
+                        .default_value(8)
+                        .minimum(1),
+                )
+                .field(
+                    "funding_interval_hours",
+                    ParamField::integer("资金费结算间隔（小时）")
+                        .default_value(8)
+                        .minimum(1),
+                )
+                .field(
+                    "entry_lead_minutes",
+                    ParamField::integer("结算前定时入场的提前分钟数（0 为行情到达即入场）")
+                        .default_value(0)
+                        .minimum(0),
+                ),
+            constructor: |c| futures_executor(c, FundingArbStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "futures_macd",
+            description: "合约 MACD：MACD 线与信号线交叉时开平仓",
+            market_types: FUTURES,
+            params: macd_schema(true),
+            constructor: |c| futures_executor(c, FuturesMacdStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "futures_bollinger",
+            description: "合约布林带：触及上下轨时反向开仓",
+            market_types: FUTURES,
+            params: bollinger_schema(true),
+            constructor: |c| futures_executor(c, FuturesBollingerStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "futures_rsi",
+            description: "合约 RSI：超卖做多、超买做空",
+            market_types: FUTURES,
+            params: rsi_schema(true),
+            constructor: |c| futures_executor(c, FuturesRsiStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "trend_following",
+            description: "趋势跟踪：快慢均线交叉顺势开仓，可选止损",
+            market_types: FUTURES,
+            params: ParamSchema::new()
+                .field(
+                    "fast_period",
+                    ParamField::integer("快速均线周期")
+                        .default_value(10)
+                        .minimum(1),
+                )
+                .field(
+                    "slow_period",
+                    ParamField::integer("慢速均线周期（须大于快速周期）")
+                        .default_value(30)
+                        .minimum(2),
+                )
+                .field("quantity", quantity_field())
+                .field("leverage", leverage_field(10))
+                .field(
+                    "stop_loss_percent",
+                    ParamField::decimal("止损百分比（0.02 表示 2%），null 表示不止损")
+                        .default_value("0.02")
+                        .exclusive_minimum(0)
+                        .maximum(1),
+                ),
+            constructor: |c| futures_executor(c, TrendFollowingStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "breakout",
+            description: "突破：价格突破回溯区间高低点时顺势开仓",
+            market_types: FUTURES,
+            params: ParamSchema::new()
+                .field(
+                    "lookback_period",
+                    ParamField::integer("回溯周期（计算历史高低点）")
+                        .default_value(20)
+                        .minimum(1),
+                )
+                .field(
+                    "breakout_threshold_percent",
+                    ParamField::decimal("突破确认百分比（0.005 表示 0.5%）")
+                        .default_value("0.005")
+                        .exclusive_minimum(0),
+                )
+                .field("quantity", quantity_field())
+                .field("leverage", leverage_field(10))
+                .field(
+                    "stop_loss_percent",
+                    ParamField::decimal("止损百分比（0.02 表示 2%）")
+                        .default_value("0.02")
+                        .exclusive_minimum(0)
+                        .maximum(1),
+                ),
+            constructor: |c| futures_executor(c, BreakoutStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "reversal",
+            description: "反转：动量连续反向变化超过阈值时反向开仓",
+            market_types: FUTURES,
+            params: ParamSchema::new()
+                .field(
+                    "momentum_period",
+                    ParamField::integer("动量周期")
+                        .default_value(10)
+                        .minimum(1),
+                )
+                .field(
+                    "reversal_threshold_percent",
+                    ParamField::decimal("反转阈值（动量变化百分比）")
+                        .default_value("0.15")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "confirmation_periods",
+                    ParamField::integer("确认周期（连续 N 个周期确认反转）")
+                        .default_value(2)
+                        .minimum(1),
+                )
+                .field("quantity", quantity_field())
+                .field("leverage", leverage_field(10)),
+            constructor: |c| futures_executor(c, ReversalStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "calendar_spread",
+            description: "跨期套利：近远月价差偏离均值超过阈值时建立价差头寸",
+            market_types: FUTURES,
+            params: ParamSchema::new()
+                .field(
+                    "near_contract",
+                    ParamField::string("近月合约").default_value("BTCUSDT_PERP"),
+                )
+                .field(
+                    "far_contract",
+                    ParamField::string("远月合约").default_value("BTCUSDT_240329"),
+                )
+                .field(
+                    "spread_period",
+                    ParamField::integer("价差均值计算周期")
+                        .default_value(20)
+                        .minimum(2),
+                )
+                .field(
+                    "spread_std_multiplier",
+                    ParamField::decimal("价差标准差倍数（触发阈值）")
+                        .default_value("2")
+                        .exclusive_minimum(0),
+                )
+                .field("quantity", quantity_field())
+                .field("leverage", leverage_field(5)),
+            constructor: |c| futures_executor(c, CalendarSpreadStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "pairs_trading",
+            description: "配对交易：滚动估计两品种对冲比率，价差 z 分数偏离时建立价差头寸，回归、超时或结构破裂时平仓",
+            market_types: FUTURES,
+            params: ParamSchema::new()
+                .field(
+                    "symbol_a",
+                    ParamField::string("A 腿合约（因变量）").default_value("BTCUSDT"),
+                )
+                .field(
+                    "symbol_b",
+                    ParamField::string("B 腿合约（对冲腿）").default_value("ETHUSDT"),
+                )
+                .field(
+                    "hedge_method",
+                    ParamField::enumeration("对冲比率估计方法", &["ols", "kalman"])
+                        .default_value("ols"),
+                )
+                .field(
+                    "lookback",
+                    ParamField::integer("回看周期（价格更新次数）")
+                        .default_value(100)
+                        .minimum(2),
+                )
+                .field(
+                    "kalman_delta",
+                    ParamField::decimal("卡尔曼滤波过程噪声比例（0~1）")
+                        .default_value("0.0001")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "kalman_observation_variance",
+                    ParamField::decimal("卡尔曼滤波观测噪声方差")
+                        .default_value("0.001")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "entry_z",
+                    ParamField::decimal("入场 z 分数")
+                        .default_value("2")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "exit_z",
+                    ParamField::decimal("离场 z 分数")
+                        .default_value("0.5")
+                        .minimum(0),
+                )
+                .field(
+                    "stop_z",
+                    ParamField::decimal("结构破裂止损 z 分数")
+                        .default_value("4")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "half_life_multiple",
+                    ParamField::decimal("最长持仓（半衰期倍数）")
+                        .default_value("3")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "max_half_life",
+                    ParamField::decimal("半衰期上限（价格更新次数）")
+                        .default_value("100")
+                        .exclusive_minimum(0),
+                )
+                .field("quantity", quantity_field())
+                .field("leverage", leverage_field(3)),
+            constructor: |c| futures_executor(c, PairsTradingStrategy::new),
+        },
+        // ==================== 高频 ====================
+        StrategyDescriptor {
+            strategy_type: "market_making",
+            description: "做市：围绕公允价格双边挂单，按库存偏移报价、按波动率调整数量，价格偏离时撤旧挂新",
+            market_types: ANY_MARKET,
+            params: ParamSchema::new()
+                .field(
+                    "fair_value",
+                    ParamField::enumeration("公允价格来源", &["mid", "microprice"])
+                        .default_value("microprice"),
+                )
+                .field(
+                    "half_spread_bps",
+                    ParamField::decimal("报价半价差（基点）")
+                        .default_value("10")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "base_quantity",
+                    ParamField::decimal("基础挂单数量")
+                        .default_value("0.001")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "max_inventory",
+                    ParamField::decimal("最大库存（绝对值）")
+                        .default_value("0.01")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "inventory_skew_bps",
+                    ParamField::decimal("满库存时的报价偏移（基点）")
+                        .default_value("10")
+                        .minimum(0),
+                )
+                .field(
+                    "volatility_period",
+                    ParamField::integer("波动率统计周期（深度更新次数）")
+                        .default_value(20)
+                        .minimum(2),
+                )
+                .field(
+                    "target_volatility_bps",
+                    ParamField::decimal("目标波动率（基点），超过时缩小挂单数量")
+                        .default_value("5")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "min_size_ratio",
+                    ParamField::decimal("挂单数量下限（相对基础数量的比例）")
+                        .default_value("0.2")
+                        .exclusive_minimum(0)
+                        .maximum(1),
+                )
+                .field(
+                    "requote_threshold_bps",
+                    ParamField::decimal("重新报价阈值（公允价格偏离基点数）")
+                        .default_value("5")
+                        .minimum(0),
+                )
+                .field(
+                    "max_market_spread_bps",
+                    ParamField::decimal("盘口价差上限（基点），超过时全部撤单")
+                        .default_value("50")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "tick_size",
+                    ParamField::decimal("价格最小变动单位")
+                        .default_value("0.01")
+                        .exclusive_minimum(0),
+                ),
+            constructor: |c| futures_executor(c, MarketMakingStrategy::new),
+        },
+        StrategyDescriptor {
+            strategy_type: "order_flow",
+            description: "订单流：综合成交失衡、盘口失衡、CVD 与大单判断短期方向，VPIN 过高时不开仓",
+            market_types: ANY_MARKET,
+            params: ParamSchema::new()
+                .field(
+                    "imbalance_period",
+                    ParamField::integer("成交失衡度周期（成交笔数）")
+                        .default_value(50)
+                        .minimum(2),
+                )
+                .field(
+                    "cvd_period",
+                    ParamField::integer("CVD 变化量周期（成交笔数）")
+                        .default_value(200)
+                        .minimum(2),
+                )
+                .field(
+                    "book_levels",
+                    ParamField::integer("盘口失衡度统计档数")
+                        .default_value(5)
+                        .minimum(1),
+                )
+                .field(
+                    "vpin_bucket_volume",
+                    ParamField::decimal("VPIN 桶容量（成交量）")
+                        .default_value("1")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "vpin_buckets",
+                    ParamField::integer("VPIN 统计桶数")
+                        .default_value(20)
+                        .minimum(2),
+                )
+                .field(
+                    "max_vpin",
+                    ParamField::decimal("VPIN 上限，超过时不开新仓")
+                        .default_value("0.6")
+                        .exclusive_minimum(0)
+                        .maximum(1),
+                )
+                .field(
+                    "large_trade_period",
+                    ParamField::integer("大单检测周期（成交笔数）")
+                        .default_value(100)
+                        .minimum(2),
+                )
+                .field(
+                    "large_trade_std",
+                    ParamField::decimal("大单阈值（标准差倍数）")
+                        .default_value("3")
+                        .exclusive_minimum(0),
+                )
+                .field(
+                    "trade_weight",
+                    ParamField::decimal("成交失衡度权重")
+                        .default_value("0.4")
+                        .minimum(0),
+                )
+                .field(
+                    "book_weight",
+                    ParamField::decimal("盘口失衡度权重")
+                        .default_value("0.4")
+                        .minimum(0),
+                )
+                .field(
+                    "cvd_weight",
+                    ParamField::decimal("CVD 变化率权重")
+                        .default_value("0.2")
+                        .minimum(0),
+                )
+                .field(
+                    "large_trade_boost",
+                    ParamField::decimal("大单加分")
+                        .default_value("0.2")
+                        .minimum(0),
+                )
+                .field(
+                    "entry_threshold",
+                    ParamField::decimal("入场阈值（方向分数绝对值）")
+                        .default_value("0.5")
+                        .exclusive_minimum(0)
+                        .maximum(1),
+                )
+                .field(
+                    "exit_threshold",
+                    ParamField::decimal("离场阈值（反向分数绝对值）")
+                        .default_value("0.2")
+                        .minimum(0)
+                        .maximum(1),
+                )
+                .field("quantity", quantity_field()),
+            constructor: |c| futures_executor(c, OrderFlowStrategy::new),
+        },
+        // ==================== AI ====================
+        StrategyDescriptor {
+            strategy_type: "ml_signal",
+            description: "机器学习信号：用离线训练的模型（ONNX / JSON 线性或树模型）对指标特征推理，按方向概率开平仓",
+            market_types: ANY_MARKET,
+            params: ParamSchema::new()
+                .field(
+                    "model_path",
+                    ParamField::string("模型文件路径（.onnx / .json）").required(),
+                )
+                .field(
+                    "features",
+                    ParamField::array("特征列表（顺序与训练时一致）").default_value(
+                        serde_json::to_value(FeatureSpec::default_set()).unwrap_or_default(),
+                    ),
+                )
+                .field(
+                    "inference_interval",
+                    ParamField::integer("推理间隔（成交笔数）")
+                        .default_value(1)
+                        .minimum(1),
+                )
+                .field(
+                    "entry_probability",
+                    ParamField::decimal("入场概率")
+                        .default_value("0.6")
+                        .exclusive_minimum(0)
+                        .maximum(1),
+                )
+                .field(
+                    "exit_probability",
+                    ParamField::decimal("离场概率（反方向）")
+                        .default_value("0.5")
+                        .exclusive_minimum(0)
+                        .maximum(1),
+                )
+                .field("quantity", quantity_field()),
+            constructor: |c| loaded_executor(c, ml_signal),
+        },
+        // ==================== 用户脚本 ====================
+        StrategyDescriptor {
+            strategy_type: "script",
+            description: "用户脚本：上传脚本实现 on_market_event / on_fill / reset，在沙箱中按事件限额执行，无需重新编译",
+            market_types: ANY_MARKET,
+            params: ParamSchema::new()
+                .field("source", ParamField::string("脚本源码（ABI v1）").required())
+                .field(
+                    "params",
+                    ParamField::map("脚本参数（脚本中通过 params / param() 读取）")
+                        .default_value(serde_json::json!({})),
+                )
+                .field("quantity", quantity_field())
+                .field("limits", sandbox_limits_field()),
+            constructor: |c| loaded_executor(c, ScriptStrategy::new),
+        },
+        // ==================== 组合 ====================
+        StrategyDescriptor {
+            strategy_type: "ensemble",
+            description: "组合策略：订阅成员实例的信号，按置信度加权投票 / 一致同意 / 元模型综合为一个意图，附带成员归因",
+            market_types: ANY_MARKET,
+            params: ParamSchema::new()
+                .field(
+                    "members",
+                    ParamField::array("成员实例 ID（须订阅同一交易对，顺序即元模型特征顺序）").required(),
+                )
+                .field(
+                    "method",
+                    ParamField::map(
+                        "组合方法：{\"type\": \"weighted_vote\", \"weights\": {实例 ID: 权重}} / {\"type\": \"unanimous\"} / {\"type\": \"meta_model\", \"model_path\": ...}",
+                    )
+                    .default_value(serde_json::json!({ "type": "weighted_vote", "weights": {} })),
+                )
+                .field(
+                    "entry_threshold",
+                    ParamField::decimal("开仓阈值（综合分值绝对值）")
+                        .default_value("0.5")
+                        .exclusive_minimum(0)
+                        .maximum(1),
+                )
+                .field(
+                    "exit_threshold",
+                    ParamField::decimal("平仓阈值（持仓方向上的支持度低于此值时平仓）")
+                        .default_value("0")
+                        .minimum(0)
+                        .maximum(1),
+                )
+                .field(
+                    "signal_ttl_secs",
+                    ParamField::integer("成员信号有效期（秒）")
+                        .default_value(300)
+                        .minimum(1),
+                )
+                .field(
+                    "history_size",
+                    ParamField::integer("保留的决策归因条数")
+                        .default_value(100)
+                        .minimum(1),
+                )
+                .field("quantity", quantity_field()),
+            constructor: |c| loaded_executor(c, ensemble),
+        },
+    ]
+}
+
+/// 加载模型并创建机器学习信号策略
+fn ml_signal(
+    instance_id: Uuid,
+    symbol: String,
+    config: MlSignalConfig,
+    market_type: MarketType,
+) -> Result<MlSignalStrategy> {
+    let model = load_model(&config.model_path, config.features.len())?;
+    MlSignalStrategy::new(instance_id, symbol, config, market_type, model)
+}
+
+/// 加载元模型（如有）并创建组合策略
+fn ensemble(
+    instance_id: Uuid,
+    symbol: String,
+    config: EnsembleConfig,
+    market_type: MarketType,
+) -> Result<EnsembleStrategy> {
+    let model = match &config.method {
+        EnsembleMethod::MetaModel { model_path } => {
+            Some(load_model(model_path, config.members.len())?)
+        }
+        _ => None,
+    };
+    EnsembleStrategy::new(instance_id, symbol, config, market_type, model)
+}
+
+// ============================================================================
+// 参数描述
+// ============================================================================
+
+/// 交易数量（默认 0.001）
+fn quantity_field() -> ParamField {
+    ParamField::decimal("每次交易数量")
+        .default_value("0.001")
+        .exclusive_minimum(0)
+}
+
+/// 沙箱资源限制（不能超过宿主上限）
+fn sandbox_limits_field() -> ParamField {
+    let defaults = SandboxLimits::default();
+    let ceiling = SandboxLimits::CEILING;
+    let schema = ParamSchema::new()
+        .field(
+            "max_operations",
+            ParamField::integer("每个事件的最大指令数")
+                .default_value(defaults.max_operations)
+                .minimum(1)
+                .maximum(ceiling.max_operations),
+        )
+        .field(
+            "max_memory_bytes",
+            ParamField::integer("每个事件的最大内存分配（字节）")
+                .default_value(defaults.max_memory_bytes)
+                .minimum(1)
+                .maximum(ceiling.max_memory_bytes),
+        )
+        .field(
+            "max_state_bytes",
+            ParamField::integer("持久状态上限（字节）")
+                .default_value(defaults.max_state_bytes)
+                .minimum(1)
+                .maximum(ceiling.max_state_bytes),
+        )
+        .field(
+            "max_time_ms",
+            ParamField::integer("每个事件的最长执行时间（毫秒）")
+                .default_value(defaults.max_time_ms)
+                .minimum(1)
+                .maximum(ceiling.max_time_ms),
+        )
+        .field(
+            "max_call_depth",
+            ParamField::integer("最大函数调用深度")
+                .default_value(defaults.max_call_depth)
+                .minimum(1)
+                .maximum(ceiling.max_call_depth),
+        );
+    ParamField::object("沙箱资源限制", schema)
+}
+
+/// 定时计划
+fn schedule_field(default: serde_json::Value) -> ParamField {
+    ParamField::map(
+        "定时计划：{\"type\": \"interval\", \"every_secs\": 秒, \"offset_secs\": 秒} / {\"type\": \"cron\", \"expression\": \"分 时 日 月 周\"}（UTC）",
+    )
+    .default_value(default)
+}
+
+/// 杠杆配置
+fn leverage_field(default_leverage: u32) -> ParamField {
+    let schema = ParamSchema::new()
+        .field(
+            "leverage",
+            ParamField::integer("杠杆倍数")
+                .minimum(1)
+                .maximum(MAX_LEVERAGE)
+                .required(),
+        )
+        .field(
+            "margin_type",
+            ParamField::enumeration("保证金模式", &["Cross", "Isolated"]).default_value("Cross"),
+        );
+    ParamField::object("杠杆配置", schema)
+        .default_value(serde_json::json!({ "leverage": default_leverage, "margin_type": "Cross" }))
+}
+
+/// 持仓方向
+fn position_side_field() -> ParamField {
+    ParamField::enumeration("持仓方向", &["Long", "Short", "Both"]).default_value("Both")
+}
+
+fn grid_schema(futures: bool) -> ParamSchema {
+    let schema = ParamSchema::new()
+        .field(
+            "upper_price",
+            ParamField::decimal("价格上界")
+                .exclusive_minimum(0)
+                .required(),
+        )
+        .field(
+            "lower_price",
+            ParamField::decimal("价格下界（须小于上界）")
+                .exclusive_minimum(0)
+                .required(),
+        )
+        .field(
+            "grid_count",
+            ParamField::integer("网格数量").minimum(1).required(),
+        )
+        .field(
+            "quantity_per_grid",
+            ParamField::decimal("每格交易数量")
+                .exclusive_minimum(0)
+                .required(),
+        );
+    if futures {
+        schema
+            .field("leverage", leverage_field(10))
+            .field("position_side", position_side_field())
+    } else {
+        schema
+    }
+}
+
+fn mean_reversion_schema(futures: bool) -> ParamSchema {
+    let schema = ParamSchema::new()
+        .field(
+            "window_size",
+            ParamField::integer("移动平均窗口大小")
+                .default_value(20)
+                .minimum(2),
+        )
+        .field(
+            "threshold_percent",
+            ParamField::decimal("偏离阈值百分比（0.02 表示 2%）")
+                .default_value("0.02")
+                .exclusive_minimum(0),
+        )
+        .field("quantity", quantity_field());
+    if futures {
+        schema
+            .field("leverage", leverage_field(10))
+            .field("position_side", position_side_field())
+    } else {
+        schema
+    }
+}
+
+fn macd_schema(futures: bool) -> ParamSchema {
+    let schema = ParamSchema::new()
+        .field(
+            "fast_period",
+            ParamField::integer("快线周期").default_value(12).minimum(1),
+        )
+        .field(
+            "slow_period",
+            ParamField::integer("慢线周期（须大于快线周期）")
+                .default_value(26)
+                .minimum(2),
+        )
+        .field(
+            "signal_period",
+            ParamField::integer("信号线周期").default_value(9).minimum(1),
+        )
+        .field("quantity", quantity_field());
+    with_leverage(schema, futures, 10)
+}
+
+fn bollinger_schema(futures: bool) -> ParamSchema {
+    let schema = ParamSchema::new()
+        .field(
+            "period",
+            ParamField::integer("周期").default_value(20).minimum(2),
+        )
+        .field(
+            "std_dev_multiplier",
+            ParamField::decimal("标准差倍数")
+                .default_value("2")
+                .exclusive_minimum(0),
+        )
+        .field("quantity", quantity_field());
+    with_leverage(schema, futures, 10)
+}
+
+fn rsi_schema(futures: bool) -> ParamSchema {
+    let schema = ParamSchema::new()
+        .field(
+            "period",
+            ParamField::integer("RSI 周期").default_value(14).minimum(1),
+        )
+        .field(
+            "oversold_threshold",
+            ParamField::decimal("超卖阈值")
+                .default_value("30")
+                .exclusive_minimum(0),
+        )
+        .field(
+            "overbought_threshold",
+            ParamField::decimal("超买阈值（须大于超卖阈值）")
+                .default_value("70")
+                .maximum(100),
+        )
+        .field("quantity", quantity_field());
+    with_leverage(schema, futures, 10)
+}
+
+fn with_leverage(schema: ParamSchema, futures: bool, default_leverage: u32) -> ParamSchema {
+    if futures {
+        schema.field("leverage", leverage_field(default_leverage))
+    } else {
+        schema
+    }
+}
+
+// ============================================================================
+// 参数校验
+// ============================================================================
+
+fn check_schedule(errors: &mut ParamErrors, schedule: &TimerSchedule) {
+    if let Err(e) = schedule.validate() {
+        errors.check(false, format!("schedule is invalid: {}", e));
+    }
+}
+
+fn check_leverage(errors: &mut ParamErrors, leverage: &LeverageConfig) {
+    errors.check(
+        (1..=MAX_LEVERAGE).contains(&leverage.leverage),
+        format!(
+            "leverage.leverage must be between 1 and {}, got {}",
+            MAX_LEVERAGE, leverage.leverage
+        ),
+    );
+}
+
+fn check_grid(
+    errors: &mut ParamErrors,
+    upper_price: Decimal,
+    lower_price: Decimal,
+    grid_count: u32,
+    quantity_per_grid: Decimal,
+) {
+    errors.positive("lower_price", lower_price);
+    errors.check(
+        upper_price > lower_price,
+        format!(
+            "upper_price must be greater than lower_price, got {} <= {}",
+            upper_price, lower_price
+        ),
+    );
+    errors.positive("grid_count", grid_count);
+    errors.positive("quantity_per_grid", quantity_per_grid);
+}
+
+fn check_macd(errors: &mut ParamErrors, fast: usize, slow: usize, signal: usize) {
+    errors.positive("fast_period", fast);
+    errors.positive("signal_period", signal);
+    errors.check(
+        fast < slow,
+        format!(
+            "fast_period must be less than slow_period, got {} >= {}",
+            fast, slow
+        ),
+    );
+}
+
+fn check_rsi(errors: &mut ParamErrors, period: usize, oversold: Decimal, overbought: Decimal) {
+    errors.positive("period", period);
+    errors.positive("oversold_threshold", oversold);
+    errors.check(
+        oversold < overbought && overbought <= Decimal::ONE_HUNDRED,
+        format!(
+            "thresholds must satisfy 0 < oversold_threshold < overbought_threshold <= 100, got {} / {}",
+            oversold, overbought
+        ),
+    );
+}
+
+fn check_window(errors: &mut ParamErrors, name: &str, value: usize) {
+    errors.check(
+        value >= 2,
+        format!("{} must be at least 2, got {}", name, value),
+    );
+}
+
+impl StrategyParams for SpotGridConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        check_grid(
+            errors,
+            self.upper_price,
+            self.lower_price,
+            self.grid_count,
+            self.quantity_per_grid,
+        );
+    }
+}
+
+impl StrategyParams for FuturesGridConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        check_grid(
+            errors,
+            self.upper_price,
+            self.lower_price,
+            self.grid_count,
+            self.quantity_per_grid,
+        );
+        check_leverage(errors, &self.leverage);
+    }
+}
+
+impl StrategyParams for SpotMeanReversionConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        check_window(errors, "window_size", self.window_size);
+        errors.positive("threshold_percent", self.threshold_percent);
+        errors.positive("quantity", self.quantity);
+    }
+}
+
+impl StrategyParams for FuturesMeanReversionConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        check_window(errors, "window_size", self.window_size);
+        errors.positive("threshold_percent", self.threshold_percent);
+        errors.positive("quantity", self.quantity);
+        check_leverage(errors, &self.leverage);
+    }
+}
+
+impl StrategyParams for SpotMacdConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        check_macd(
+            errors,
+            self.fast_period,
+            self.slow_period,
+            self.signal_period,
+        );
+        errors.positive("quantity", self.quantity);
+    }
+}
+
+impl StrategyParams for FuturesMacdConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        check_macd(
+            errors,
+            self.fast_period,
+            self.slow_period,
+            self.signal_period,
+        );
+        errors.positive("quantity", self.quantity);
+        check_leverage(errors, &self.leverage);
+    }
+}
+
+impl StrategyParams for SpotBollingerConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        check_window(errors, "period", self.period);
+        errors.positive("std_dev_multiplier", self.std_dev_multiplier);
+        errors.positive("quantity", self.quantity);
+    }
+}
+
+impl StrategyParams for FuturesBollingerConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        check_window(errors, "period", self.period);
+        errors.positive("std_dev_multiplier", self.std_dev_multiplier);
+        errors.positive("quantity", self.quantity);
+        check_leverage(errors, &self.leverage);
+    }
+}
+
+impl StrategyParams for SpotRsiConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        check_rsi(
+            errors,
+            self.period,
+            self.oversold_threshold,
+            self.overbought_threshold,
+        );
+        errors.positive("quantity", self.quantity);
+    }
+}
+
+impl StrategyParams for SpotDcaConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        errors.positive("amount", self.amount);
+        check_schedule(errors, &self.schedule);
+        errors.positive("dip_lookback", self.dip_lookback);
+        for level in &self.dip_levels {
+            errors.check(
+                Decimal::ZERO < level.drawdown && level.drawdown < Decimal::ONE,
+                format!("dip_levels.drawdown must be in (0, 1), got {}", level.drawdown),
+            );
+            errors.positive("dip_levels.multiplier", level.multiplier);
+        }
+        errors.check(
+            self.max_total_amount >= Decimal::ZERO,
+            format!("max_total_amount must be >= 0, got {}", self.max_total_amount),
+        );
+    }
+}
+
+impl StrategyParams for SpotRebalanceConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        errors.check(!self.targets.is_empty(), "targets cannot be empty");
+        for (symbol, weight) in &self.targets {
+            errors.positive(&format!("targets.{}", symbol), *weight);
+        }
+        let total: Decimal = self.targets.values().sum();
+        errors.check(
+            total <= Decimal::ONE,
+            format!("targets weights must sum to at most 1, got {}", total),
+        );
+        errors.positive("capital", self.capital);
+        errors.positive("drift_threshold", self.drift_threshold);
+        check_schedule(errors, &self.schedule);
+        errors.check(
+            self.min_trade_value >= Decimal::ZERO,
+            format!("min_trade_value must be >= 0, got {}", self.min_trade_value),
+        );
+    }
+}
+
+impl StrategyParams for FuturesRsiConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        check_rsi(
+            errors,
+            self.period,
+            self.oversold_threshold,
+            self.overbought_threshold,
+        );
+        errors.positive("quantity", self.quantity);
+        check_leverage(errors, &self.leverage);
+    }
+}
+
+impl StrategyParams for FundingArbConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        errors.positive("funding_rate_threshold", self.funding_rate_threshold);
+        errors.positive("quantity", self.quantity);
+        errors.positive("max_hold_hours", self.max_hold_hours);
+        errors.positive("funding_interval_hours", self.funding_interval_hours);
+        let interval_minutes = self.funding_interval_hours.saturating_mul(60);
+        errors.check(
+            self.entry_lead_minutes < interval_minutes,
+            format!(
+                "entry_lead_minutes must be less than the funding interval, got {} >= {}",
+                self.entry_lead_minutes, interval_minutes
+            ),
+        );
+        check_leverage(errors, &self.leverage);
+    }
+}
+
+impl StrategyParams for TrendFollowingConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        errors.positive("fast_period", self.fast_period);
+        errors.check(
+            self.fast_period < self.slow_period,
+            format!(
+                "fast_period must be less than slow_period, got {} >= {}",
+                self.fast_period, self.slow_period
+            ),
+        );
+        errors.positive("quantity", self.quantity);
+        if let Some(stop_loss) = self.stop_loss_percent {
+            errors.check(
+                stop_loss > Decimal::ZERO && stop_loss <= Decimal::ONE,
+                format!("stop_loss_percent must be in (0, 1], got {}", stop_loss),
+            );
+        }
+        check_leverage(errors, &self.leverage);
+    }
+}
+
+impl StrategyParams for BreakoutConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        errors.positive("lookback_period", self.lookback_period);
+        errors.positive(
+            "breakout_threshold_percent",
+            self.breakout_threshold_percent,
+        );
+        errors.positive("quantity", self.quantity);
+        errors.check(
+            self.stop_loss_percent > Decimal::ZERO && self.stop_loss_percent <= Decimal::ONE,
+            format!(
+                "stop_loss_percent must be in (0, 1], got {}",
+                self.stop_loss_percent
+            ),
+        );
+        check_leverage(errors, &self.leverage);
+    }
+}
+
+impl StrategyParams for ReversalConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        errors.positive("momentum_period", self.momentum_period);
+        errors.positive(
+            "reversal_threshold_percent",
+            self.reversal_threshold_percent,
+        );
+        errors.positive("confirmation_periods", self.confirmation_periods);
+        errors.positive("quantity", self.quantity);
+        check_leverage(errors, &self.leverage);
+    }
+}
+
+impl StrategyParams for CalendarSpreadConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        errors.check(
+            !self.near_contract.trim().is_empty() && !self.far_contract.trim().is_empty(),
+            "near_contract and far_contract cannot be empty",
+        );
+        errors.check(
+            self.near_contract != self.far_contract,
+            format!(
+                "near_contract and far_contract must differ, got {}",
+                self.near_contract
+            ),
+        );
+        check_window(errors, "spread_period", self.spread_period);
+        errors.positive("spread_std_multiplier", self.spread_std_multiplier);
+        errors.positive("quantity", self.quantity);
+        check_leverage(errors, &self.leverage);
+    }
+}
+
+impl StrategyParams for PairsTradingConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        errors.check(
+            !self.symbol_a.trim().is_empty() && !self.symbol_b.trim().is_empty(),
+            "symbol_a and symbol_b cannot be empty",
+        );
+        errors.check(
+            self.symbol_a != self.symbol_b,
+            format!("symbol_a and symbol_b must differ, got {}", self.symbol_a),
+        );
+        check_window(errors, "lookback", self.lookback);
+        errors.check(
+            self.kalman_delta > Decimal::ZERO && self.kalman_delta < Decimal::ONE,
+            format!("kalman_delta must be in (0, 1), got {}", self.kalman_delta),
+        );
+        errors.positive("kalman_observation_variance", self.kalman_observation_variance);
+        errors.check(
+            Decimal::ZERO <= self.exit_z && self.exit_z < self.entry_z && self.entry_z < self.stop_z,
+            format!(
+                "thresholds must satisfy 0 <= exit_z < entry_z < stop_z, got {} / {} / {}",
+                self.exit_z, self.entry_z, self.stop_z
+            ),
+        );
+        errors.positive("half_life_multiple", self.half_life_multiple);
+        errors.positive("max_half_life", self.max_half_life);
+        errors.positive("quantity", self.quantity);
+        check_leverage(errors, &self.leverage);
+    }
+}
+
+impl StrategyParams for MlSignalConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        errors.check(
+            !self.model_path.trim().is_empty(),
+            "model_path cannot be empty",
+        );
+        errors.check(!self.features.is_empty(), "features cannot be empty");
+        for spec in &self.features {
+            for (name, period) in spec.periods() {
+                errors.positive(name, period);
+            }
+            if let FeatureSpec::MacdHistogram { fast, slow, .. } = *spec {
+                errors.check(
+                    fast < slow,
+                    format!("macd_histogram.fast must be less than slow, got {} / {}", fast, slow),
+                );
+            }
+            if let FeatureSpec::BollingerPercent { multiplier, .. } = *spec {
+                errors.positive("bollinger_percent.multiplier", multiplier);
+            }
+        }
+        errors.positive("inference_interval", self.inference_interval);
+        errors.check(
+            Decimal::ZERO < self.exit_probability
+                && self.exit_probability <= Decimal::ONE
+                && Decimal::ZERO < self.entry_probability
+                && self.entry_probability <= Decimal::ONE,
+            format!(
+                "probabilities must be in (0, 1], got entry {} / exit {}",
+                self.entry_probability, self.exit_probability
+            ),
+        );
+        errors.positive("quantity", self.quantity);
+    }
+}
+
+impl StrategyParams for ScriptConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        errors.check(!self.source.trim().is_empty(), "source cannot be empty");
+        for (name, value, max) in self.limits.exceeded() {
+            errors.check(
+                false,
+                format!("limits.{} must be in [1, {}], got {}", name, max, value),
+            );
+        }
+        errors.positive("quantity", self.quantity);
+        if !self.source.trim().is_empty() && self.limits.exceeded().is_empty() {
+            if let Err(e) = self.compile() {
+                errors.check(false, format!("source: {:#}", e));
+            }
+        }
+    }
+}
+
+impl StrategyParams for EnsembleConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        errors.check(!self.members.is_empty(), "members cannot be empty");
+        for (i, member) in self.members.iter().enumerate() {
+            errors.check(
+                !self.members[..i].contains(member),
+                format!("members contains duplicate instance {}", member),
+            );
+        }
+        match &self.method {
+            EnsembleMethod::WeightedVote { weights } => {
+                for (id, weight) in weights {
+                    errors.check(
+                        self.members.contains(id),
+                        format!("method.weights references non-member instance {}", id),
+                    );
+                    errors.check(
+                        weight.is_finite() && *weight >= 0.0,
+                        format!("method.weights of {} cannot be negative, got {}", id, weight),
+                    );
+                }
+            }
+            EnsembleMethod::MetaModel { model_path } => {
+                errors.check(
+                    !model_path.trim().is_empty(),
+                    "method.model_path cannot be empty",
+                );
+            }
+            EnsembleMethod::Unanimous => {}
+        }
+        errors.check(
+            self.entry_threshold > Decimal::ZERO && self.entry_threshold <= Decimal::ONE,
+            format!("entry_threshold must be in (0, 1], got {}", self.entry_threshold),
+        );
+        errors.check(
+            self.exit_threshold >= Decimal::ZERO && self.exit_threshold <= Decimal::ONE,
+            format!("exit_threshold must be in [0, 1], got {}", self.exit_threshold),
+        );
+        errors.positive("signal_ttl_secs", self.signal_ttl_secs);
+        errors.positive("history_size", self.history_size);
+        errors.positive("quantity", self.quantity);
+    }
+}
+
+impl StrategyParams for MarketMakingConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        errors.positive("half_spread_bps", self.half_spread_bps);
+        errors.positive("base_quantity", self.base_quantity);
+        errors.positive("max_inventory", self.max_inventory);
+        errors.check(
+            self.inventory_skew_bps >= Decimal::ZERO,
+            format!("inventory_skew_bps cannot be negative, got {}", self.inventory_skew_bps),
+        );
+        check_window(errors, "volatility_period", self.volatility_period);
+        errors.positive("target_volatility_bps", self.target_volatility_bps);
+        errors.check(
+            self.min_size_ratio > Decimal::ZERO && self.min_size_ratio <= Decimal::ONE,
+            format!("min_size_ratio must be in (0, 1], got {}", self.min_size_ratio),
+        );
+        errors.check(
+            self.requote_threshold_bps >= Decimal::ZERO,
+            format!(
+                "requote_threshold_bps cannot be negative, got {}",
+                self.requote_threshold_bps
+            ),
+        );
+        errors.positive("max_market_spread_bps", self.max_market_spread_bps);
+        errors.positive("tick_size", self.tick_size);
+    }
+}
+
+impl StrategyParams for OrderFlowConfig {
+    fn validate(&self, errors: &mut ParamErrors) {
+        check_window(errors, "imbalance_period", self.imbalance_period);
+        check_window(errors, "cvd_period", self.cvd_period);
+        errors.positive("book_levels", self.book_levels);
+        errors.positive("vpin_bucket_volume", self.vpin_bucket_volume);
+        check_window(errors, "vpin_buckets", self.vpin_buckets);
+        errors.check(
+            self.max_vpin > Decimal::ZERO && self.max_vpin <= Decimal::ONE,
+            format!("max_vpin must be in (0, 1], got {}", self.max_vpin),
+        );
+        check_window(errors, "large_trade_period", self.large_trade_period);
+        errors.positive("large_trade_std", self.large_trade_std);
+        for (name, weight) in [
+            ("trade_weight", self.trade_weight),
+            ("book_weight", self.book_weight),
+            ("cvd_weight", self.cvd_weight),
+            ("large_trade_boost", self.large_trade_boost),
+        ] {
+            errors.check(
+                weight >= Decimal::ZERO,
+                format!("{} cannot be negative, got {}", name, weight),
+            );
+        }
+        errors.check(
+            self.trade_weight > Decimal::ZERO,
+            format!("trade_weight must be positive, got {}", self.trade_weight),
+        );
+        errors.check(
+            self.entry_threshold > Decimal::ZERO && self.entry_threshold <= Decimal::ONE,
+            format!("entry_threshold must be in (0, 1], got {}", self.entry_threshold),
+        );
+        errors.check(
+            self.exit_threshold >= Decimal::ZERO && self.exit_threshold <= Decimal::ONE,
+            format!("exit_threshold must be in [0, 1], got {}", self.exit_threshold),
+        );
+        errors.positive("quantity", self.quantity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::factory::strategy_factory::parse_params;
+    use serde::Serialize;
+    use serde_json::{json, Value};
+
+    fn to_value<P: Serialize>(config: P) -> Value {
+        serde_json::to_value(config).unwrap()
+    }
+
+    /// 各策略参数结构体的默认配置（序列化后）
+    ///
+    /// 网格参数没有 `Default`（价格区间必填）：用必填参数构造，其余字段取反序列化默认值。
+    fn default_config(strategy_type: &str) -> Value {
+        let grid = json!({
+            "upper_price": "50000",
+            "lower_price": "40000",
+            "grid_count": 10,
+            "quantity_per_grid": "0.001",
+        });
+        match strategy_type {
+            "spot_grid" => to_value(parse_params::<SpotGridConfig>(strategy_type, &grid).unwrap()),
+            "futures_grid" => {
+                to_value(parse_params::<FuturesGridConfig>(strategy_type, &grid).unwrap())
+            }
+            "spot_mean_reversion" => to_value(SpotMeanReversionConfig::default()),
+            "spot_macd" => to_value(SpotMacdConfig::default()),
+            "spot_bollinger" => to_value(SpotBollingerConfig::default()),
+            "spot_rsi" => to_value(SpotRsiConfig::default()),
+            "spot_dca" => to_value(SpotDcaConfig::default()),
+            "spot_rebalance" => to_value(SpotRebalanceConfig::default()),
+            "futures_mean_reversion" => to_value(FuturesMeanReversionConfig::default()),
+            "funding_arb" => to_value(FundingArbConfig::default()),
+            "futures_macd" => to_value(FuturesMacdConfig::default()),
+            "futures_bollinger" => to_value(FuturesBollingerConfig::default()),
+            "futures_rsi" => to_value(FuturesRsiConfig::default()),
+            "trend_following" => to_value(TrendFollowingConfig::default()),
+            "breakout" => to_value(BreakoutConfig::default()),
+            "reversal" => to_value(ReversalConfig::default()),
+            "calendar_spread" => to_value(CalendarSpreadConfig::default()),
+            "pairs_trading" => to_value(PairsTradingConfig::default()),
+            "market_making" => to_value(MarketMakingConfig::default()),
+            "order_flow" => to_value(OrderFlowConfig::default()),
+            "ml_signal" => to_value(MlSignalConfig::default()),
+            "script" => to_value(ScriptConfig::default()),
+            "ensemble" => to_value(EnsembleConfig::default()),
+            other => panic!("no default config for {}, add it to default_config()", other),
+        }
+    }
+
+    /// 数值按大小比较（"2" 与 "2.0" 相同），对象与数组逐项比较
+    fn same_value(schema: &Value, config: &Value) -> bool {
+        let decimal = |value: &Value| match value {
+            Value::String(s) => s.parse::<Decimal>().ok(),
+            Value::Number(n) => n.to_string().parse::<Decimal>().ok(),
+            _ => None,
+        };
+        match (schema, config) {
+            (Value::Object(a), Value::Object(b)) => {
+                a.len() == b.len()
+                    && a.iter().all(|(k, v)| b.get(k).is_some_and(|other| same_value(v, other)))
+            }
+            (Value::Array(a), Value::Array(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(x, y)| same_value(x, y))
+            }
+            _ => match (decimal(schema), decimal(config)) {
+                (Some(a), Some(b)) => a == b,
+                _ => schema == config,
+            },
+        }
+    }
+
+    /// 比较 schema 的参数名与默认值和配置对象，返回不一致之处
+    fn diff(path: &str, properties: &Value, config: &Value, problems: &mut Vec<String>) {
+        let properties = properties.as_object().unwrap();
+        let Some(config) = config.as_object() else {
+            problems.push(format!("{}: default config is not an object: {}", path, config));
+            return;
+        };
+
+        let mut schema_keys: Vec<_> = properties.keys().collect();
+        let mut config_keys: Vec<_> = config.keys().collect();
+        schema_keys.sort();
+        config_keys.sort();
+        if schema_keys != config_keys {
+            problems.push(format!(
+                "{}: schema properties {:?} != config fields {:?}",
+                path, schema_keys, config_keys
+            ));
+        }
+
+        for (name, property) in properties {
+            let Some(value) = config.get(name) else {
+                continue;
+            };
+            let field = format!("{}.{}", path, name);
+            if let Some(default) = property.get("default") {
+                if !same_value(default, value) {
+                    problems.push(format!(
+                        "{}: schema default {} != Default {}",
+                        field, default, value
+                    ));
+                }
+            }
+            if let Some(nested) = property.get("properties") {
+                diff(&field, nested, value, problems);
+            }
+        }
+    }
+
+    #[test]
+    fn test_schema_matches_default_config() {
+        let mut problems = Vec::new();
+        for descriptor in descriptors() {
+            let doc = descriptor
+                .params
+                .to_json(descriptor.strategy_type, descriptor.description);
+            let config = default_config(descriptor.strategy_type);
+            diff(descriptor.strategy_type, &doc["properties"], &config, &mut problems);
+        }
+        assert!(
+            problems.is_empty(),
+            "schema out of sync with Default:\n{}",
+            problems.join("\n")
+        );
+    }
+}
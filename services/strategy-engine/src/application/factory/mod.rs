@@ -0,0 +1,14 @@
+//! # 策略工厂模块 (Strategy Factory Module)
+//!
+//! 按 `strategy_type` 创建策略实例，并描述各策略的参数。
+
+pub mod param_schema;
+pub mod strategy_factory;
+
+mod builtin;
+
+pub use param_schema::{ParamField, ParamSchema};
+pub use strategy_factory::{
+    parse_params, ParamErrors, StrategyConstructor, StrategyDescriptor, StrategyFactory,
+    StrategyParams, StrategyTypeInfo,
+};
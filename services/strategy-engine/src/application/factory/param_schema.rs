@@ -0,0 +1,160 @@
+//! # 策略参数描述 (Strategy Parameter Schema)
+//!
+//! 以 JSON Schema（draft 2020-12）描述策略参数，供前端动态渲染配置表单。
+//!
+//! ## 约定
+//! - 金额 / 价格 / 比例类参数统一为 `decimal`：接受字符串或数字，推荐字符串以免精度丢失
+//! - 未声明的参数一律拒绝（`additionalProperties: false`），与反序列化规则一致
+//! - 有默认值的参数可省略；`required` 只列出无默认值的参数
+
+use serde_json::{json, Map, Value};
+
+/// JSON Schema 版本标识
+const SCHEMA_DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// 单个参数描述
+#[derive(Debug, Clone)]
+pub struct ParamField {
+    schema: Map<String, Value>,
+    required: bool,
+}
+
+impl ParamField {
+    fn typed(kind: Value, description: &str) -> Self {
+        let mut schema = Map::new();
+        schema.insert("type".to_string(), kind);
+        schema.insert("description".to_string(), json!(description));
+        Self {
+            schema,
+            required: false,
+        }
+    }
+
+    /// 整数参数
+    pub fn integer(description: &str) -> Self {
+        Self::typed(json!("integer"), description)
+    }
+
+    /// 十进制数参数（字符串或数字）
+    pub fn decimal(description: &str) -> Self {
+        let mut field = Self::typed(json!(["string", "number"]), description);
+        field.schema.insert("format".to_string(), json!("decimal"));
+        field
+    }
+
+    /// 字符串参数
+    pub fn string(description: &str) -> Self {
+        Self::typed(json!("string"), description)
+    }
+
+    /// 枚举参数
+    pub fn enumeration(description: &str, values: &[&str]) -> Self {
+        let mut field = Self::typed(json!("string"), description);
+        field.schema.insert("enum".to_string(), json!(values));
+        field
+    }
+
+    /// 数组参数
+    pub fn array(description: &str) -> Self {
+        Self::typed(json!("array"), description)
+    }
+
+    /// 键值对象参数（键不限，如传给用户脚本的参数）
+    pub fn map(description: &str) -> Self {
+        Self::typed(json!("object"), description)
+    }
+
+    /// 嵌套对象参数
+    pub fn object(description: &str, schema: ParamSchema) -> Self {
+        let mut field = Self::typed(json!("object"), description);
+        field.schema.extend(schema.body());
+        field
+    }
+
+    /// 默认值
+    pub fn default_value(mut self, value: impl Into<Value>) -> Self {
+        self.schema.insert("default".to_string(), value.into());
+        self
+    }
+
+    /// 最小值（含）
+    pub fn minimum(mut self, value: impl Into<Value>) -> Self {
+        self.schema.insert("minimum".to_string(), value.into());
+        self
+    }
+
+    /// 最小值（不含）
+    pub fn exclusive_minimum(mut self, value: impl Into<Value>) -> Self {
+        self.schema
+            .insert("exclusiveMinimum".to_string(), value.into());
+        self
+    }
+
+    /// 最大值（含）
+    pub fn maximum(mut self, value: impl Into<Value>) -> Self {
+        self.schema.insert("maximum".to_string(), value.into());
+        self
+    }
+
+    /// 标记为必填
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+}
+
+/// 策略参数描述（一个 JSON 对象）
+#[derive(Debug, Clone, Default)]
+pub struct ParamSchema {
+    properties: Map<String, Value>,
+    required: Vec<String>,
+}
+
+impl ParamSchema {
+    /// 创建空描述
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加参数
+    pub fn field(mut self, name: &str, field: ParamField) -> Self {
+        if field.required {
+            self.required.push(name.to_string());
+        }
+        self.properties
+            .insert(name.to_string(), Value::Object(field.schema));
+        self
+    }
+
+    /// 参数名列表
+    pub fn field_names(&self) -> impl Iterator<Item = &str> {
+        self.properties.keys().map(String::as_str)
+    }
+
+    /// 必填参数列表
+    pub fn required_fields(&self) -> &[String] {
+        &self.required
+    }
+
+    /// 生成完整 JSON Schema 文档
+    pub fn to_json(&self, title: &str, description: &str) -> Value {
+        let mut doc = Map::new();
+        doc.insert("$schema".to_string(), json!(SCHEMA_DRAFT));
+        doc.insert("title".to_string(), json!(title));
+        doc.insert("description".to_string(), json!(description));
+        doc.insert("type".to_string(), json!("object"));
+        doc.extend(self.clone().body());
+        Value::Object(doc)
+    }
+
+    /// 对象主体：properties / required / additionalProperties
+    fn body(self) -> Map<String, Value> {
+        let mut body = Map::new();
+        body.insert("properties".to_string(), Value::Object(self.properties));
+        if !self.required.is_empty() {
+            body.insert("required".to_string(), json!(self.required));
+        }
+        body.insert("additionalProperties".to_string(), json!(false));
+        body
+    }
+}
@@ -0,0 +1,509 @@
+//! # 策略工厂 (Strategy Factory)
+//!
+//! `strategy_type` → 策略构造函数的注册表。
+//!
+//! ## 职责
+//! - 按名称查找策略并校验市场类型
+//! - 将 `StrategyConfig::params` 反序列化为强类型参数并做业务校验
+//! - 对外提供每种策略的参数描述（JSON Schema）
+//!
+//! ## 规则
+//! - ✅ 参数错误一次性返回全部问题，信息中包含策略类型与参数名
+//! - ✅ 新策略只需注册一个 `StrategyDescriptor`，加载器与 HTTP 接口无需改动
+//! - ❌ 不负责注册表 / 生命周期管理（由 `StrategyLoader` 处理）
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::builtin;
+use super::param_schema::ParamSchema;
+use crate::application::scheduler::StrategyConfig;
+use crate::domain::logic::strategy_trait::Strategy;
+use crate::domain::model::market_type::MarketType;
+use crate::domain::port::strategy_executor_port::StrategyExecutorPort;
+use crate::infrastructure::strategy::StrategyExecutorAdapter;
+
+/// 策略构造函数
+pub type StrategyConstructor = fn(&StrategyConfig) -> Result<Arc<dyn StrategyExecutorPort>>;
+
+/// 强类型策略参数
+///
+/// 参数结构通过 serde 从 JSON 反序列化，`validate` 补充类型系统表达不了的约束。
+pub trait StrategyParams: DeserializeOwned {
+    /// 业务校验，将问题写入 `errors`
+    fn validate(&self, errors: &mut ParamErrors);
+}
+
+/// 参数校验错误收集器
+#[derive(Debug, Default)]
+pub struct ParamErrors {
+    messages: Vec<String>,
+}
+
+impl ParamErrors {
+    /// 条件不满足时记录错误
+    pub fn check(&mut self, ok: bool, message: impl Into<String>) {
+        if !ok {
+            self.messages.push(message.into());
+        }
+    }
+
+    /// 要求数值大于 0
+    pub fn positive<T>(&mut self, name: &str, value: T)
+    where
+        T: PartialOrd + Default + std::fmt::Display,
+    {
+        if value <= T::default() {
+            self.messages
+                .push(format!("{} must be greater than 0, got {}", name, value));
+        }
+    }
+
+    /// 是否无错误
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// 错误列表
+    pub fn messages(&self) -> &[String] {
+        &self.messages
+    }
+}
+
+/// 解析并校验策略参数
+///
+/// `null` 视为空对象，所有参数取默认值。
+pub fn parse_params<P: StrategyParams>(strategy_type: &str, params: &Value) -> Result<P> {
+    let params = match params {
+        Value::Null => Value::Object(Default::default()),
+        other => other.clone(),
+    };
+
+    let parsed: P = serde_json::from_value(params)
+        .map_err(|e| anyhow!("invalid params for {}: {}", strategy_type, e))?;
+
+    let mut errors = ParamErrors::default();
+    parsed.validate(&mut errors);
+    if !errors.is_empty() {
+        bail!(
+            "invalid params for {}: {}",
+            strategy_type,
+            errors.messages().join("; ")
+        );
+    }
+
+    Ok(parsed)
+}
+
+/// 构造现货策略执行器
+///
+/// 启停由 `StrategyHandle` 的生命周期控制，策略自身创建后即激活
+pub(crate) fn spot_executor<P, S>(
+    config: &StrategyConfig,
+    new: fn(Uuid, String, P) -> S,
+) -> Result<Arc<dyn StrategyExecutorPort>>
+where
+    P: StrategyParams,
+    S: Strategy + 'static,
+{
+    let params = parse_params::<P>(&config.strategy_type, &config.params)?;
+    let mut strategy = new(config.instance_id, config.symbol.clone(), params);
+    strategy.activate();
+    Ok(Arc::new(StrategyExecutorAdapter::new(strategy)))
+}
+
+/// 构造合约策略执行器
+pub(crate) fn futures_executor<P, S>(
+    config: &StrategyConfig,
+    new: fn(Uuid, String, P, MarketType) -> S,
+) -> Result<Arc<dyn StrategyExecutorPort>>
+where
+    P: StrategyParams,
+    S: Strategy + 'static,
+{
+    let params = parse_params::<P>(&config.strategy_type, &config.params)?;
+    let mut strategy = new(
+        config.instance_id,
+        config.symbol.clone(),
+        params,
+        config.market_type,
+    );
+    strategy.activate();
+    Ok(Arc::new(StrategyExecutorAdapter::new(strategy)))
+}
+
+/// 构造需要加载外部资源（如模型文件）的策略执行器
+///
+/// 资源加载失败时创建失败，参数热更新时同样重新加载
+pub(crate) fn loaded_executor<P, S>(
+    config: &StrategyConfig,
+    load: fn(Uuid, String, P, MarketType) -> Result<S>,
+) -> Result<Arc<dyn StrategyExecutorPort>>
+where
+    P: StrategyParams,
+    S: Strategy + 'static,
+{
+    let params = parse_params::<P>(&config.strategy_type, &config.params)?;
+    let mut strategy = load(
+        config.instance_id,
+        config.symbol.clone(),
+        params,
+        config.market_type,
+    )
+    .with_context(|| format!("failed to create {}", config.strategy_type))?;
+    strategy.activate();
+    Ok(Arc::new(StrategyExecutorAdapter::new(strategy)))
+}
+
+/// 策略描述（注册项）
+pub struct StrategyDescriptor {
+    /// 策略类型名称（唯一）
+    pub strategy_type: &'static str,
+    /// 策略说明
+    pub description: &'static str,
+    /// 支持的市场类型
+    pub market_types: &'static [MarketType],
+    /// 参数描述
+    pub params: ParamSchema,
+    /// 构造函数
+    pub constructor: StrategyConstructor,
+}
+
+impl StrategyDescriptor {
+    /// 是否支持指定市场
+    pub fn supports(&self, market_type: MarketType) -> bool {
+        self.market_types.contains(&market_type)
+    }
+
+    /// 对外展示的描述信息
+    pub fn info(&self) -> StrategyTypeInfo {
+        StrategyTypeInfo {
+            strategy_type: self.strategy_type.to_string(),
+            description: self.description.to_string(),
+            market_types: self
+                .market_types
+                .iter()
+                .map(|m| m.as_str().to_string())
+                .collect(),
+            params_schema: self.params.to_json(self.strategy_type, self.description),
+        }
+    }
+}
+
+/// 策略类型信息（供前端渲染）
+#[derive(Debug, Clone, Serialize)]
+pub struct StrategyTypeInfo {
+    /// 策略类型名称
+    pub strategy_type: String,
+    /// 策略说明
+    pub description: String,
+    /// 支持的市场类型
+    pub market_types: Vec<String>,
+    /// 参数 JSON Schema
+    pub params_schema: Value,
+}
+
+/// 策略工厂
+#[derive(Default)]
+pub struct StrategyFactory {
+    /// strategy_type → 描述（有序，便于稳定输出）
+    descriptors: BTreeMap<&'static str, StrategyDescriptor>,
+}
+
+impl StrategyFactory {
+    /// 创建空工厂
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 创建注册了全部内置策略的工厂
+    pub fn with_builtin() -> Self {
+        let mut factory = Self::new();
+        for descriptor in builtin::descriptors() {
+            factory
+                .register(descriptor)
+                .expect("builtin strategy types are unique");
+        }
+        factory
+    }
+
+    /// 注册策略
+    pub fn register(&mut self, descriptor: StrategyDescriptor) -> Result<()> {
+        if self.descriptors.contains_key(descriptor.strategy_type) {
+            bail!(
+                "strategy type already registered: {}",
+                descriptor.strategy_type
+            );
+        }
+        self.descriptors
+            .insert(descriptor.strategy_type, descriptor);
+        Ok(())
+    }
+
+    /// 查找策略描述
+    pub fn descriptor(&self, strategy_type: &str) -> Option<&StrategyDescriptor> {
+        self.descriptors.get(strategy_type)
+    }
+
+    /// 全部策略描述（按名称排序）
+    pub fn descriptors(&self) -> impl Iterator<Item = &StrategyDescriptor> {
+        self.descriptors.values()
+    }
+
+    /// 已注册的策略类型
+    pub fn strategy_types(&self) -> Vec<&'static str> {
+        self.descriptors.keys().copied().collect()
+    }
+
+    /// 创建策略执行器
+    pub fn create(&self, config: &StrategyConfig) -> Result<Arc<dyn StrategyExecutorPort>> {
+        let descriptor = self.descriptor(&config.strategy_type).ok_or_else(|| {
+            anyhow!(
+                "unsupported strategy type: {}, available: {}",
+                config.strategy_type,
+                self.strategy_types().join(", ")
+            )
+        })?;
+
+        if !descriptor.supports(config.market_type) {
+            bail!(
+                "strategy type {} does not support market type {}, supported: {}",
+                descriptor.strategy_type,
+                config.market_type,
+                descriptor
+                    .market_types
+                    .iter()
+                    .map(|m| m.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+
+        (descriptor.constructor)(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn config(strategy_type: &str, market_type: MarketType, params: Value) -> StrategyConfig {
+        StrategyConfig {
+            instance_id: Uuid::new_v4(),
+            strategy_type: strategy_type.to_string(),
+            market_type,
+            symbol: "BTCUSDT".to_string(),
+            owner_id: Uuid::new_v4(),
+            name: "test".to_string(),
+            params,
+            auto_start: false,
+            restart_policy: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_builtin_registers_every_strategy() {
+        let factory = StrategyFactory::with_builtin();
+        assert_eq!(factory.strategy_types().len(), 23);
+        assert!(factory.descriptor("spot_grid").is_some());
+        assert!(factory.descriptor("spot_dca").is_some());
+        assert!(factory.descriptor("spot_rebalance").is_some());
+        assert!(factory.descriptor("calendar_spread").is_some());
+        assert!(factory.descriptor("pairs_trading").is_some());
+        assert!(factory.descriptor("market_making").is_some());
+        assert!(factory.descriptor("order_flow").is_some());
+        assert!(factory.descriptor("ml_signal").is_some());
+        assert!(factory.descriptor("script").is_some());
+        assert!(factory.descriptor("ensemble").is_some());
+    }
+
+    #[test]
+    fn test_create_with_defaults() {
+        let factory = StrategyFactory::with_builtin();
+        for descriptor in factory.descriptors() {
+            if !descriptor.params.required_fields().is_empty() {
+                continue;
+            }
+            let market = descriptor.market_types[0];
+            let result = factory.create(&config(descriptor.strategy_type, market, Value::Null));
+            assert!(result.is_ok(), "{}: {:?}", descriptor.strategy_type, result.err());
+        }
+    }
+
+    /// 按 schema 生成参数：有默认值的取默认值，必填参数取示例值
+    fn params_from_schema(schema: &ParamSchema, samples: &Value) -> Value {
+        let doc = schema.to_json("", "");
+        let mut params = serde_json::Map::new();
+        for (name, property) in doc["properties"].as_object().unwrap() {
+            if let Some(default) = property.get("default") {
+                params.insert(name.clone(), default.clone());
+            }
+        }
+        for name in schema.required_fields() {
+            let sample = samples
+                .get(name)
+                .unwrap_or_else(|| panic!("no sample value for required field {}", name));
+            params.insert(name.clone(), sample.clone());
+        }
+        Value::Object(params)
+    }
+
+    #[test]
+    fn test_create_every_type_from_schema_defaults() {
+        use crate::domain::logic::ai::ml_signal::FeatureSpec;
+
+        // schema 与参数结构体分开维护：默认值必须能通过 deny_unknown_fields 反序列化与校验
+        let dir = std::env::temp_dir().join(format!("schema-defaults-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let model_path = dir.join("model.json");
+        let weights = vec![0.0; FeatureSpec::default_set().len()];
+        std::fs::write(&model_path, json!({ "type": "logistic", "weights": weights }).to_string())
+            .unwrap();
+
+        let samples = json!({
+            "upper_price": "50000",
+            "lower_price": "40000",
+            "grid_count": 10,
+            "quantity_per_grid": "0.001",
+            "model_path": model_path.to_str().unwrap(),
+            "source": "fn on_market_event(event) { return 0; }",
+            "members": [Uuid::new_v4(), Uuid::new_v4()],
+        });
+
+        let factory = StrategyFactory::with_builtin();
+        for descriptor in factory.descriptors() {
+            let params = params_from_schema(&descriptor.params, &samples);
+            for market in descriptor.market_types {
+                let result =
+                    factory.create(&config(descriptor.strategy_type, *market, params.clone()));
+                assert!(
+                    result.is_ok(),
+                    "{} ({}) from {}: {:?}",
+                    descriptor.strategy_type,
+                    market.as_str(),
+                    params,
+                    result.err()
+                );
+            }
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_accepts_string_and_number_decimals() {
+        let factory = StrategyFactory::with_builtin();
+        let params = json!({
+            "upper_price": "50000",
+            "lower_price": 40000,
+            "grid_count": 10,
+            "quantity_per_grid": 0.001
+        });
+        assert!(factory
+            .create(&config("spot_grid", MarketType::Spot, params))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_rejects_unknown_and_invalid_params() {
+        let factory = StrategyFactory::with_builtin();
+
+        let err = factory
+            .create(&config("spot_rsi", MarketType::Spot, json!({ "perod": 14 })))
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("unknown field `perod`"), "{}", err);
+
+        let err = factory
+            .create(&config(
+                "spot_grid",
+                MarketType::Spot,
+                json!({
+                    "upper_price": "40000",
+                    "lower_price": "50000",
+                    "grid_count": 0,
+                    "quantity_per_grid": "0.001"
+                }),
+            ))
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("upper_price must be greater than lower_price"), "{}", err);
+        assert!(err.contains("grid_count"), "{}", err);
+
+        let err = factory
+            .create(&config(
+                "script",
+                MarketType::Spot,
+                json!({
+                    "source": "fn on_market_event(event) { return 1 +; }",
+                    "limits": { "max_time_ms": 5000 }
+                }),
+            ))
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("limits.max_time_ms"), "{}", err);
+
+        let source = "fn on_market_event(event) { return open_long(param(\"size\")); }";
+        assert!(factory
+            .create(&config(
+                "script",
+                MarketType::Spot,
+                json!({ "source": source, "params": { "size": 0.5 } }),
+            ))
+            .is_ok());
+        let err = factory
+            .create(&config("script", MarketType::Spot, json!({ "source": "fn on_market_event() {}" })))
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("source:"), "{}", err);
+    }
+
+    #[test]
+    fn test_rejects_unsupported_type_and_market() {
+        let factory = StrategyFactory::with_builtin();
+
+        let err = factory
+            .create(&config("martingale", MarketType::Spot, Value::Null))
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("unsupported strategy type"), "{}", err);
+
+        let err = factory
+            .create(&config("futures_macd", MarketType::Spot, Value::Null))
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("does not support market type spot"), "{}", err);
+    }
+
+    #[test]
+    fn test_schema_matches_params() {
+        let factory = StrategyFactory::with_builtin();
+        let info = factory.descriptor("futures_grid").unwrap().info();
+        let schema = &info.params_schema;
+
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["additionalProperties"], false);
+        assert_eq!(schema["properties"]["leverage"]["type"], "object");
+        let required: Vec<&str> = schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|v| v.as_str())
+            .collect();
+        assert!(required.contains(&"upper_price"));
+        assert!(!required.contains(&"leverage"));
+    }
+}
@@ -7,3 +7,12 @@ pub mod service;
 
 /// 策略调度器
 pub mod scheduler;
+
+/// 策略工厂
+pub mod factory;
+
+/// 参数优化
+pub mod optimizer;
+
+/// 组合资金分配
+pub mod portfolio;
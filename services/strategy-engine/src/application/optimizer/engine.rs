@@ -0,0 +1,419 @@
+//! # 参数优化引擎 (Optimization Engine)
+//!
+//! 在参数空间上反复回测，按优化目标给参数组合排序。
+//!
+//! ## 职责
+//! - 按搜索方式生成参数组合，与基础参数合并后交给 `BacktestService`
+//! - 将同一批试验分发到多个 CPU 核心并行回测
+//! - 汇总结果：成功的试验按分数降序，失败的试验（参数校验不通过等）排在最后
+//!
+//! ## 规则
+//! - ✅ 回测是纯 CPU 计算，调用方应在阻塞线程池中执行（如 `spawn_blocking`）
+//! - ✅ 单次试验失败只记录错误，不中断整个优化
+//! - ❌ 不持久化结果，不修改运行中的策略
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use anyhow::{bail, Result};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::info;
+
+use super::objective::Objective;
+use super::param_space::{merge_params, ParamSet, ParamSpace};
+use super::search::{rng, Observation, SearchMethod, TpeSampler};
+use crate::application::service::backtest_service::backtest_config;
+use crate::application::service::{BacktestReport, BacktestService, BacktestSettings};
+use crate::domain::model::market_type::MarketType;
+
+/// 单次优化的试验次数上限
+pub const MAX_TRIALS: usize = 10_000;
+
+/// 贝叶斯搜索中重复建议的重试次数，超过后改为随机采样
+const SUGGEST_RETRIES: usize = 8;
+
+/// 优化任务描述
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptimizationSpec {
+    /// 策略类型
+    pub strategy_type: String,
+    /// 市场类型
+    pub market_type: MarketType,
+    /// 交易对
+    pub symbol: String,
+    /// 基础参数（不参与优化的参数）
+    #[serde(default)]
+    pub base_params: Value,
+    /// 参数空间
+    pub space: ParamSpace,
+    /// 搜索方式
+    #[serde(default)]
+    pub search: SearchMethod,
+    /// 优化目标
+    #[serde(default)]
+    pub objective: Objective,
+    /// 回测设置
+    #[serde(default)]
+    pub settings: BacktestSettings,
+    /// 每次回测的预热行情笔数
+    #[serde(default)]
+    pub warmup: usize,
+}
+
+/// 单次试验结果
+#[derive(Debug, Clone, Serialize)]
+pub struct TrialResult {
+    /// 本次试验的参数取值（仅参数空间内的参数）
+    pub params: ParamSet,
+    /// 目标分数（失败时为 None）
+    pub score: Option<f64>,
+    /// 回测报告
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report: Option<BacktestReport>,
+    /// 错误信息
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 优化结果
+#[derive(Debug, Clone, Serialize)]
+pub struct OptimizationReport {
+    /// 搜索方式
+    pub method: &'static str,
+    /// 优化目标
+    pub objective: Objective,
+    /// 试验次数
+    pub evaluated: usize,
+    /// 失败次数
+    pub failed: usize,
+    /// 耗时（毫秒）
+    pub elapsed_ms: u64,
+    /// 全部试验（按分数降序）
+    pub trials: Vec<TrialResult>,
+}
+
+impl OptimizationReport {
+    /// 最优试验
+    pub fn best(&self) -> Option<&TrialResult> {
+        self.trials.first().filter(|t| t.score.is_some())
+    }
+}
+
+/// 参数优化器
+pub struct Optimizer {
+    /// 回测服务
+    backtest: Arc<BacktestService>,
+    /// 并行度
+    parallelism: usize,
+}
+
+impl Optimizer {
+    /// 创建优化器（并行度默认为 CPU 核心数）
+    pub fn new(backtest: Arc<BacktestService>) -> Self {
+        let parallelism = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        Self {
+            backtest,
+            parallelism,
+        }
+    }
+
+    /// 设置并行度
+    pub fn with_parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism.max(1);
+        self
+    }
+
+    /// 回测服务
+    pub fn backtest(&self) -> &Arc<BacktestService> {
+        &self.backtest
+    }
+
+    /// 运行参数优化
+    pub fn optimize(
+        &self,
+        spec: &OptimizationSpec,
+        prices: &[Decimal],
+    ) -> Result<OptimizationReport> {
+        spec.space.validate()?;
+        let started = Instant::now();
+
+        let mut trials = match &spec.search {
+            SearchMethod::Grid => {
+                let size = spec.space.grid_size().unwrap_or(0);
+                if size > MAX_TRIALS {
+                    bail!(
+                        "grid has {} combinations, exceeds limit {}; narrow the space or use random search",
+                        size,
+                        MAX_TRIALS
+                    );
+                }
+                self.evaluate_batch(spec, prices, spec.space.grid()?)
+            }
+            SearchMethod::Random { trials, seed } => {
+                let mut rng = rng(*seed);
+                let sets = (0..check_trials(*trials)?)
+                    .map(|_| spec.space.decode(&spec.space.sample_point(&mut rng)))
+                    .collect();
+                self.evaluate_batch(spec, prices, sets)
+            }
+            SearchMethod::Bayesian {
+                trials,
+                initial_trials,
+                seed,
+            } => {
+                let trials = check_trials(*trials)?;
+                let initial = initial_trials.unwrap_or((trials / 5).max(5)).min(trials);
+                self.bayesian(spec, prices, trials, initial, *seed)
+            }
+        };
+
+        trials.sort_by(|a, b| match (a.score, b.score) {
+            (Some(a), Some(b)) => b.total_cmp(&a),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        });
+
+        let failed = trials.iter().filter(|t| t.score.is_none()).count();
+        let report = OptimizationReport {
+            method: spec.search.name(),
+            objective: spec.objective,
+            evaluated: trials.len(),
+            failed,
+            elapsed_ms: started.elapsed().as_millis() as u64,
+            trials,
+        };
+
+        info!(
+            strategy_type = %spec.strategy_type,
+            method = report.method,
+            evaluated = report.evaluated,
+            failed = report.failed,
+            best_score = ?report.best().and_then(|t| t.score),
+            elapsed_ms = report.elapsed_ms,
+            "Parameter optimization finished"
+        );
+
+        Ok(report)
+    }
+
+    /// 贝叶斯搜索：随机预热后按批次（每批 = 并行度）向 TPE 请求新点
+    fn bayesian(
+        &self,
+        spec: &OptimizationSpec,
+        prices: &[Decimal],
+        trials: usize,
+        initial: usize,
+        seed: Option<u64>,
+    ) -> Vec<TrialResult> {
+        let mut rng = rng(seed);
+        let sampler = TpeSampler::new(&spec.space);
+        let mut seen = HashSet::new();
+        let mut history = Vec::new();
+        let mut results = Vec::new();
+
+        while results.len() < trials {
+            let batch_size = if results.len() < initial {
+                initial - results.len()
+            } else {
+                self.parallelism.min(trials - results.len())
+            };
+
+            let mut points = Vec::with_capacity(batch_size);
+            for _ in 0..batch_size {
+                let mut point = Vec::new();
+                for attempt in 0..=SUGGEST_RETRIES {
+                    point = if results.len() < initial || attempt == SUGGEST_RETRIES {
+                        spec.space.sample_point(&mut rng)
+                    } else {
+                        sampler.suggest(&history, &mut rng)
+                    };
+                    let key = serde_json::to_string(&spec.space.decode(&point)).unwrap_or_default();
+                    if seen.insert(key) {
+                        break;
+                    }
+                }
+                points.push(point);
+            }
+
+            let sets = points.iter().map(|p| spec.space.decode(p)).collect();
+            let batch = self.evaluate_batch(spec, prices, sets);
+            for (point, trial) in points.into_iter().zip(&batch) {
+                if let Some(score) = trial.score {
+                    history.push(Observation { point, score });
+                }
+            }
+            results.extend(batch);
+        }
+
+        results
+    }
+
+    /// 并行回测一批参数组合（结果顺序与输入一致）
+    pub(crate) fn evaluate_batch(
+        &self,
+        spec: &OptimizationSpec,
+        prices: &[Decimal],
+        sets: Vec<ParamSet>,
+    ) -> Vec<TrialResult> {
+        let next = AtomicUsize::new(0);
+        let workers = self.parallelism.min(sets.len()).max(1);
+
+        let mut indexed: Vec<(usize, TrialResult)> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut done = Vec::new();
+                        loop {
+                            let index = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(set) = sets.get(index) else {
+                                break;
+                            };
+                            done.push((index, self.evaluate(spec, prices, set)));
+                        }
+                        done
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap_or_default())
+                .collect()
+        });
+
+        indexed.sort_by_key(|(index, _)| *index);
+        indexed.into_iter().map(|(_, trial)| trial).collect()
+    }
+
+    /// 回测单组参数
+    fn evaluate(&self, spec: &OptimizationSpec, prices: &[Decimal], set: &ParamSet) -> TrialResult {
+        let config = backtest_config(
+            &spec.strategy_type,
+            spec.market_type,
+            &spec.symbol,
+            merge_params(&spec.base_params, set),
+        );
+
+        match self
+            .backtest
+            .run(&config, prices, spec.warmup, &spec.settings)
+        {
+            Ok(report) => TrialResult {
+                params: set.clone(),
+                score: Some(spec.objective.score(&report)).filter(|s| s.is_finite()),
+                report: Some(report),
+                error: None,
+            },
+            Err(e) => TrialResult {
+                params: set.clone(),
+                score: None,
+                report: None,
+                error: Some(format!("{:#}", e)),
+            },
+        }
+    }
+}
+
+fn check_trials(trials: usize) -> Result<usize> {
+    if trials == 0 || trials > MAX_TRIALS {
+        bail!("trials must be between 1 and {}, got {}", MAX_TRIALS, trials);
+    }
+    Ok(trials)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::application::factory::StrategyFactory;
+    use serde_json::json;
+
+    pub(crate) fn optimizer() -> Optimizer {
+        let backtest = BacktestService::new(Arc::new(StrategyFactory::with_builtin()));
+        Optimizer::new(Arc::new(backtest)).with_parallelism(4)
+    }
+
+    /// 在 90 ~ 110 之间来回震荡的价格
+    pub(crate) fn oscillating_prices(len: usize) -> Vec<Decimal> {
+        (0..len)
+            .map(|i| {
+                let phase = (i % 20) as i64;
+                let offset = if phase < 10 { phase } else { 20 - phase };
+                Decimal::from(90 + offset * 2)
+            })
+            .collect()
+    }
+
+    pub(crate) fn grid_spec(search: SearchMethod) -> OptimizationSpec {
+        serde_json::from_value(json!({
+            "strategy_type": "spot_grid",
+            "market_type": "Spot",
+            "symbol": "BTCUSDT",
+            "base_params": { "upper_price": "110", "lower_price": "90", "quantity_per_grid": "1" },
+            "space": { "grid_count": { "type": "int", "min": 2, "max": 20, "step": 2 } },
+            "objective": "total_return"
+        }))
+        .map(|spec: OptimizationSpec| OptimizationSpec { search, ..spec })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_grid_search_ranks_by_objective() {
+        let report = optimizer()
+            .optimize(&grid_spec(SearchMethod::Grid), &oscillating_prices(200))
+            .unwrap();
+
+        assert_eq!(report.evaluated, 10);
+        assert_eq!(report.failed, 0);
+        let scores: Vec<f64> = report.trials.iter().filter_map(|t| t.score).collect();
+        assert!(scores.windows(2).all(|w| w[0] >= w[1]));
+        assert!(report.best().is_some());
+    }
+
+    #[test]
+    fn test_failed_trials_are_reported_last() {
+        let mut spec = grid_spec(SearchMethod::Grid);
+        spec.space = serde_json::from_value(json!({
+            "grid_count": { "type": "int", "min": 0, "max": 4, "step": 2 }
+        }))
+        .unwrap();
+
+        let report = optimizer().optimize(&spec, &oscillating_prices(100)).unwrap();
+        assert_eq!(report.evaluated, 3);
+        assert_eq!(report.failed, 1);
+        let last = report.trials.last().unwrap();
+        assert_eq!(last.params["grid_count"], 0);
+        assert!(last.error.as_deref().unwrap().contains("grid_count"));
+    }
+
+    #[test]
+    fn test_seeded_searches_are_reproducible() {
+        let prices = oscillating_prices(120);
+        for search in [
+            SearchMethod::Random {
+                trials: 6,
+                seed: Some(3),
+            },
+            SearchMethod::Bayesian {
+                trials: 8,
+                initial_trials: Some(4),
+                seed: Some(3),
+            },
+        ] {
+            let spec = grid_spec(search);
+            let a = optimizer().optimize(&spec, &prices).unwrap();
+            let b = optimizer().optimize(&spec, &prices).unwrap();
+            assert_eq!(a.evaluated, b.evaluated);
+            let params = |r: &OptimizationReport| {
+                r.trials.iter().map(|t| t.params.clone()).collect::<Vec<_>>()
+            };
+            assert_eq!(params(&a), params(&b));
+        }
+    }
+}
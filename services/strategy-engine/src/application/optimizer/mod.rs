@@ -0,0 +1,15 @@
+//! # 参数优化模块 (Parameter Optimization Module)
+//!
+//! 基于回测的策略参数搜索（网格 / 随机 / 贝叶斯）与滚动前推分析。
+
+pub mod engine;
+pub mod objective;
+pub mod param_space;
+pub mod search;
+pub mod walk_forward;
+
+pub use engine::{OptimizationReport, OptimizationSpec, Optimizer, TrialResult, MAX_TRIALS};
+pub use objective::Objective;
+pub use param_space::{ParamRange, ParamSet, ParamSpace};
+pub use search::SearchMethod;
+pub use walk_forward::{WalkForwardConfig, WalkForwardFold, WalkForwardReport};
@@ -0,0 +1,47 @@
+//! # 优化目标 (Optimization Objective)
+//!
+//! 从回测报告中取出用于排序的分数，分数越大越好。
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::service::BacktestReport;
+
+/// 计算收益回撤比时回撤的下限，避免无回撤时除以 0
+const MIN_DRAWDOWN: f64 = 1e-4;
+
+/// 优化目标
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Objective {
+    /// 夏普比率
+    #[default]
+    Sharpe,
+    /// 收益 / 最大回撤
+    ReturnOverDrawdown,
+    /// 总收益率
+    TotalReturn,
+}
+
+impl Objective {
+    /// 计算分数
+    pub fn score(&self, report: &BacktestReport) -> f64 {
+        match self {
+            Self::Sharpe => report.sharpe_ratio,
+            Self::ReturnOverDrawdown => {
+                report.total_return / report.max_drawdown.max(MIN_DRAWDOWN)
+            }
+            Self::TotalReturn => report.total_return,
+        }
+    }
+
+    /// 按行情笔数归一化的分数
+    ///
+    /// 收益类目标随区间长度累积，样本内外长度不同时需归一化后才可比较；
+    /// 夏普比率本身按笔计算，不做处理。
+    pub fn normalized(&self, score: f64, ticks: usize) -> f64 {
+        match self {
+            Self::Sharpe => score,
+            Self::ReturnOverDrawdown | Self::TotalReturn => score / ticks.max(1) as f64,
+        }
+    }
+}
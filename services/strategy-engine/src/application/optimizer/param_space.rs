@@ -0,0 +1,329 @@
+//! # 参数空间 (Parameter Space)
+//!
+//! 描述待优化参数的取值范围：整数区间、小数区间（可选步长）与枚举。
+//!
+//! ## 约定
+//! - 参数名与 `StrategyConfig::params` 中的字段一致，采样结果覆盖基础参数
+//! - 小数一律输出为字符串，避免精度丢失（与参数描述的 `decimal` 约定一致）
+//! - 搜索算法在归一化坐标上工作：数值维度取 [0, 1]，枚举维度取下标
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use rand::Rng;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// 一组参数取值（参数名 → JSON 值）
+pub type ParamSet = BTreeMap<String, Value>;
+
+/// 连续小数的保留位数
+const CONTINUOUS_DP: u32 = 8;
+
+fn default_step() -> i64 {
+    1
+}
+
+/// 单个参数的取值范围
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ParamRange {
+    /// 整数区间 [min, max]，按步长取值
+    Int {
+        min: i64,
+        max: i64,
+        #[serde(default = "default_step")]
+        step: i64,
+    },
+    /// 小数区间 [min, max]
+    ///
+    /// 不指定步长时为连续取值，只能用于随机 / 贝叶斯搜索
+    Decimal {
+        min: Decimal,
+        max: Decimal,
+        #[serde(default)]
+        step: Option<Decimal>,
+    },
+    /// 枚举取值
+    Choice { values: Vec<Value> },
+}
+
+impl ParamRange {
+    /// 离散取值个数（连续小数为 None）
+    pub fn levels(&self) -> Option<usize> {
+        match self {
+            Self::Int { min, max, step } => {
+                if *step <= 0 || min > max {
+                    return Some(0);
+                }
+                Some(((max - min) / step) as usize + 1)
+            }
+            Self::Decimal {
+                min,
+                max,
+                step: Some(step),
+            } => {
+                if *step <= Decimal::ZERO || min > max {
+                    return Some(0);
+                }
+                ((*max - *min) / *step).floor().to_usize().map(|n| n + 1)
+            }
+            Self::Decimal { step: None, .. } => None,
+            Self::Choice { values } => Some(values.len()),
+        }
+    }
+
+    /// 是否为枚举维度
+    pub fn is_categorical(&self) -> bool {
+        matches!(self, Self::Choice { .. })
+    }
+
+    /// 第 `index` 个离散取值
+    fn level(&self, index: usize) -> Value {
+        match self {
+            Self::Int { min, step, .. } => Value::from(min + step * index as i64),
+            Self::Decimal { min, step, .. } => {
+                let step = step.unwrap_or(Decimal::ZERO);
+                decimal_value(*min + step * Decimal::from(index))
+            }
+            Self::Choice { values } => values[index].clone(),
+        }
+    }
+
+    /// 由归一化坐标得到取值
+    ///
+    /// 数值维度 `coord` 取 [0, 1]，吸附到最近的离散取值；枚举维度 `coord` 为下标
+    fn decode(&self, coord: f64) -> Value {
+        match (self, self.levels()) {
+            (Self::Choice { .. }, Some(n)) => self.level((coord as usize).min(n - 1)),
+            (Self::Decimal { min, max, .. }, None) => {
+                let fraction = Decimal::from_f64(coord.clamp(0.0, 1.0)).unwrap_or_default();
+                decimal_value((*min + (*max - *min) * fraction).round_dp(CONTINUOUS_DP))
+            }
+            (_, Some(n)) => {
+                let index = (coord.clamp(0.0, 1.0) * (n - 1) as f64).round() as usize;
+                self.level(index)
+            }
+            (_, None) => Value::Null,
+        }
+    }
+
+    /// 随机坐标
+    fn sample_coord<R: Rng>(&self, rng: &mut R) -> f64 {
+        match (self, self.levels()) {
+            (Self::Choice { .. }, Some(n)) => rng.gen_range(0..n) as f64,
+            _ => rng.gen::<f64>(),
+        }
+    }
+
+    /// 校验取值范围
+    fn validate(&self, name: &str, errors: &mut Vec<String>) {
+        match self {
+            Self::Int { min, max, step } => {
+                if min > max {
+                    errors.push(format!("{}: min must not exceed max", name));
+                }
+                if *step <= 0 {
+                    errors.push(format!("{}: step must be greater than 0", name));
+                }
+            }
+            Self::Decimal { min, max, step } => {
+                if min > max {
+                    errors.push(format!("{}: min must not exceed max", name));
+                }
+                if step.is_some_and(|s| s <= Decimal::ZERO) {
+                    errors.push(format!("{}: step must be greater than 0", name));
+                }
+            }
+            Self::Choice { values } => {
+                if values.is_empty() {
+                    errors.push(format!("{}: values cannot be empty", name));
+                }
+            }
+        }
+    }
+}
+
+/// 参数空间
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ParamSpace {
+    /// 参数名 → 取值范围（有序，保证网格枚举顺序稳定）
+    params: BTreeMap<String, ParamRange>,
+}
+
+impl ParamSpace {
+    /// 创建空参数空间
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加参数
+    pub fn with_param(mut self, name: impl Into<String>, range: ParamRange) -> Self {
+        self.params.insert(name.into(), range);
+        self
+    }
+
+    /// 维度数
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// 各维度（按参数名排序）
+    pub fn ranges(&self) -> impl Iterator<Item = &ParamRange> {
+        self.params.values()
+    }
+
+    /// 校验参数空间，一次性返回全部问题
+    pub fn validate(&self) -> Result<()> {
+        if self.params.is_empty() {
+            bail!("parameter space cannot be empty");
+        }
+
+        let mut errors = Vec::new();
+        for (name, range) in &self.params {
+            range.validate(name, &mut errors);
+        }
+        if !errors.is_empty() {
+            bail!("invalid parameter space: {}", errors.join("; "));
+        }
+        Ok(())
+    }
+
+    /// 网格大小（存在连续维度时为 None，溢出时为 usize::MAX）
+    pub fn grid_size(&self) -> Option<usize> {
+        self.params.values().try_fold(1usize, |acc, range| {
+            range.levels().map(|n| acc.saturating_mul(n))
+        })
+    }
+
+    /// 枚举全部网格点
+    pub fn grid(&self) -> Result<Vec<ParamSet>> {
+        if self.grid_size().is_none() {
+            bail!("grid search requires a step for every decimal parameter");
+        }
+
+        let mut sets = vec![ParamSet::new()];
+        for (name, range) in &self.params {
+            let levels = range.levels().unwrap_or(0);
+            sets = sets
+                .into_iter()
+                .flat_map(|set| {
+                    (0..levels).map(move |index| {
+                        let mut next = set.clone();
+                        next.insert(name.clone(), range.level(index));
+                        next
+                    })
+                })
+                .collect();
+        }
+        Ok(sets)
+    }
+
+    /// 随机采样一个坐标点
+    pub fn sample_point<R: Rng>(&self, rng: &mut R) -> Vec<f64> {
+        self.params.values().map(|r| r.sample_coord(rng)).collect()
+    }
+
+    /// 坐标点 → 参数取值
+    pub fn decode(&self, point: &[f64]) -> ParamSet {
+        self.params
+            .iter()
+            .zip(point)
+            .map(|((name, range), coord)| (name.clone(), range.decode(*coord)))
+            .collect()
+    }
+}
+
+/// 将采样结果覆盖到基础参数上
+pub fn merge_params(base: &Value, set: &ParamSet) -> Value {
+    let mut merged = match base {
+        Value::Object(map) => map.clone(),
+        _ => Map::new(),
+    };
+    for (name, value) in set {
+        merged.insert(name.clone(), value.clone());
+    }
+    Value::Object(merged)
+}
+
+fn decimal_value(value: Decimal) -> Value {
+    Value::String(value.normalize().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use serde_json::json;
+
+    fn space() -> ParamSpace {
+        serde_json::from_value(json!({
+            "period": { "type": "int", "min": 10, "max": 20, "step": 5 },
+            "threshold": { "type": "decimal", "min": "0.01", "max": "0.02", "step": "0.005" },
+            "mode": { "type": "choice", "values": ["fast", "slow"] }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_grid_enumerates_cartesian_product() {
+        let space = space();
+        space.validate().unwrap();
+        assert_eq!(space.grid_size(), Some(18));
+
+        let grid = space.grid().unwrap();
+        assert_eq!(grid.len(), 18);
+        assert_eq!(grid[0]["mode"], "fast");
+        assert_eq!(grid[0]["period"], 10);
+        assert_eq!(grid[0]["threshold"], "0.01");
+        assert_eq!(grid[17]["mode"], "slow");
+        assert_eq!(grid[17]["period"], 20);
+        assert_eq!(grid[17]["threshold"], "0.02");
+    }
+
+    #[test]
+    fn test_continuous_decimal_rejects_grid_but_samples() {
+        let space = ParamSpace::new().with_param(
+            "threshold",
+            ParamRange::Decimal {
+                min: Decimal::new(1, 2),
+                max: Decimal::new(5, 2),
+                step: None,
+            },
+        );
+        assert!(space.grid().is_err());
+
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..20 {
+            let set = space.decode(&space.sample_point(&mut rng));
+            let value: Decimal = set["threshold"].as_str().unwrap().parse().unwrap();
+            assert!(value >= Decimal::new(1, 2) && value <= Decimal::new(5, 2));
+        }
+    }
+
+    #[test]
+    fn test_validate_and_merge() {
+        let invalid: ParamSpace = serde_json::from_value(json!({
+            "period": { "type": "int", "min": 20, "max": 10 },
+            "mode": { "type": "choice", "values": [] }
+        }))
+        .unwrap();
+        let err = invalid.validate().unwrap_err().to_string();
+        assert!(err.contains("period: min must not exceed max"), "{}", err);
+        assert!(err.contains("mode: values cannot be empty"), "{}", err);
+
+        let set = space().grid().unwrap().remove(0);
+        let merged = merge_params(&json!({ "quantity": "1", "period": 99 }), &set);
+        assert_eq!(merged["quantity"], "1");
+        assert_eq!(merged["period"], 10);
+    }
+}
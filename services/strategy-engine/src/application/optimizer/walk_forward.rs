@@ -0,0 +1,246 @@
+//! # 滚动前推分析 (Walk-Forward Analysis)
+//!
+//! 把价格序列切成若干折：每折先在样本内（in-sample）区间优化参数，
+//! 再用最优参数回测紧随其后的样本外（out-of-sample）区间。
+//!
+//! ## 过拟合判断
+//! - 前推效率 = 样本外平均分数 / 样本内平均分数（按笔数归一化后比较）
+//! - 效率低于阈值，或样本内为正而样本外不为正，视为过拟合
+//!
+//! ## 规则
+//! - ✅ 样本外回测用样本内末尾的行情预热，预热期不成交
+//! - ✅ `anchored` 为 true 时样本内起点固定（扩张窗口），否则随样本外窗口滚动
+//! - ❌ 样本内分数为非正数时不计算效率
+
+use anyhow::{bail, Context, Result};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use super::engine::{OptimizationSpec, Optimizer};
+use super::param_space::{merge_params, ParamSet};
+use crate::application::service::backtest_service::backtest_config;
+use crate::application::service::BacktestReport;
+
+fn default_overfit_threshold() -> f64 {
+    0.5
+}
+
+/// 滚动前推配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WalkForwardConfig {
+    /// 样本内行情笔数
+    pub in_sample: usize,
+    /// 样本外行情笔数（也是每折的滚动步长）
+    pub out_of_sample: usize,
+    /// 是否固定样本内起点
+    #[serde(default)]
+    pub anchored: bool,
+    /// 前推效率阈值，低于该值视为过拟合
+    #[serde(default = "default_overfit_threshold")]
+    pub overfit_threshold: f64,
+}
+
+/// 单折结果
+#[derive(Debug, Clone, Serialize)]
+pub struct WalkForwardFold {
+    /// 折序号（从 0 开始）
+    pub index: usize,
+    /// 样本内区间 [start, end)
+    pub in_sample: (usize, usize),
+    /// 样本外区间 [start, end)
+    pub out_of_sample: (usize, usize),
+    /// 样本内最优参数
+    pub best_params: ParamSet,
+    /// 样本内分数
+    pub in_sample_score: f64,
+    /// 样本外分数
+    pub out_of_sample_score: f64,
+    /// 样本外回测报告
+    pub out_of_sample_report: BacktestReport,
+}
+
+/// 滚动前推结果
+#[derive(Debug, Clone, Serialize)]
+pub struct WalkForwardReport {
+    /// 各折结果
+    pub folds: Vec<WalkForwardFold>,
+    /// 样本内平均分数（按笔归一化）
+    pub mean_in_sample_score: f64,
+    /// 样本外平均分数（按笔归一化）
+    pub mean_out_of_sample_score: f64,
+    /// 前推效率（样本内分数非正时为 None）
+    pub efficiency: Option<f64>,
+    /// 是否判定为过拟合
+    pub overfit: bool,
+}
+
+impl WalkForwardConfig {
+    /// 切分各折：返回 (样本内起点, 样本内终点, 样本外终点)
+    pub fn folds(&self, len: usize) -> Result<Vec<(usize, usize, usize)>> {
+        if self.in_sample == 0 || self.out_of_sample == 0 {
+            bail!("in_sample and out_of_sample must be greater than 0");
+        }
+
+        let mut folds = Vec::new();
+        let mut offset = 0;
+        while offset + self.in_sample + self.out_of_sample <= len {
+            let start = if self.anchored { 0 } else { offset };
+            let split = offset + self.in_sample;
+            folds.push((start, split, split + self.out_of_sample));
+            offset += self.out_of_sample;
+        }
+
+        if folds.is_empty() {
+            bail!(
+                "walk-forward needs at least {} prices, got {}",
+                self.in_sample + self.out_of_sample,
+                len
+            );
+        }
+        Ok(folds)
+    }
+}
+
+impl Optimizer {
+    /// 运行滚动前推分析
+    pub fn walk_forward(
+        &self,
+        spec: &OptimizationSpec,
+        prices: &[Decimal],
+        config: &WalkForwardConfig,
+    ) -> Result<WalkForwardReport> {
+        if spec.warmup >= config.in_sample {
+            bail!(
+                "warmup ({}) must be smaller than in_sample ({})",
+                spec.warmup,
+                config.in_sample
+            );
+        }
+
+        let mut folds = Vec::new();
+        for (index, (start, split, end)) in config.folds(prices.len())?.into_iter().enumerate() {
+            let optimization = self
+                .optimize(spec, &prices[start..split])
+                .with_context(|| format!("fold {} in-sample optimization failed", index))?;
+            let Some(best) = optimization.best() else {
+                bail!("fold {}: every in-sample trial failed", index);
+            };
+            let in_sample_score = best.score.unwrap_or_default();
+
+            // 样本外：用样本内末尾 warmup 笔行情预热
+            let oos_config = backtest_config(
+                &spec.strategy_type,
+                spec.market_type,
+                &spec.symbol,
+                merge_params(&spec.base_params, &best.params),
+            );
+            let report = self
+                .backtest()
+                .run(&oos_config, &prices[split - spec.warmup..end], spec.warmup, &spec.settings)
+                .with_context(|| format!("fold {} out-of-sample backtest failed", index))?;
+
+            folds.push(WalkForwardFold {
+                index,
+                in_sample: (start, split),
+                out_of_sample: (split, end),
+                best_params: best.params.clone(),
+                in_sample_score,
+                out_of_sample_score: spec.objective.score(&report),
+                out_of_sample_report: report,
+            });
+        }
+
+        let mean = |scores: Vec<f64>| scores.iter().sum::<f64>() / scores.len() as f64;
+        let mean_in_sample_score = mean(
+            folds
+                .iter()
+                .map(|f| {
+                    let ticks = f.in_sample.1 - f.in_sample.0 - spec.warmup;
+                    spec.objective.normalized(f.in_sample_score, ticks)
+                })
+                .collect(),
+        );
+        let mean_out_of_sample_score = mean(
+            folds
+                .iter()
+                .map(|f| {
+                    let ticks = f.out_of_sample.1 - f.out_of_sample.0;
+                    spec.objective.normalized(f.out_of_sample_score, ticks)
+                })
+                .collect(),
+        );
+
+        let efficiency =
+            (mean_in_sample_score > 0.0).then(|| mean_out_of_sample_score / mean_in_sample_score);
+        let overfit = efficiency.is_some_and(|e| e < config.overfit_threshold);
+
+        info!(
+            strategy_type = %spec.strategy_type,
+            folds = folds.len(),
+            mean_in_sample_score,
+            mean_out_of_sample_score,
+            efficiency = ?efficiency,
+            overfit,
+            "Walk-forward analysis finished"
+        );
+
+        Ok(WalkForwardReport {
+            folds,
+            mean_in_sample_score,
+            mean_out_of_sample_score,
+            efficiency,
+            overfit,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::optimizer::engine::tests::{grid_spec, optimizer, oscillating_prices};
+    use crate::application::optimizer::SearchMethod;
+
+    fn config(anchored: bool) -> WalkForwardConfig {
+        WalkForwardConfig {
+            in_sample: 100,
+            out_of_sample: 40,
+            anchored,
+            overfit_threshold: 0.5,
+        }
+    }
+
+    #[test]
+    fn test_fold_split() {
+        assert_eq!(
+            config(false).folds(220).unwrap(),
+            vec![(0, 100, 140), (40, 140, 180), (80, 180, 220)]
+        );
+        assert_eq!(
+            config(true).folds(200).unwrap(),
+            vec![(0, 100, 140), (0, 140, 180)]
+        );
+        assert!(config(false).folds(139).is_err());
+    }
+
+    #[test]
+    fn test_walk_forward_runs_each_fold() {
+        let mut spec = grid_spec(SearchMethod::Grid);
+        spec.warmup = 5;
+
+        let report = optimizer()
+            .walk_forward(&spec, &oscillating_prices(220), &config(false))
+            .unwrap();
+
+        assert_eq!(report.folds.len(), 3);
+        for fold in &report.folds {
+            assert!(fold.best_params.contains_key("grid_count"));
+            assert_eq!(fold.out_of_sample_report.total_ticks, 40);
+        }
+        assert_eq!(
+            report.overfit,
+            report.efficiency.is_some_and(|e| e < 0.5)
+        );
+    }
+}
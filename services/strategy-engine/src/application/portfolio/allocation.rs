@@ -0,0 +1,264 @@
+//! # 资金分配方法 (Allocation Methods)
+//!
+//! 按各策略的历史表现计算资金权重（占组合权益的比例，合计不超过 1）：
+//! - 固定权重：按配置分配，未配置的策略平分剩余部分
+//! - 风险平价：权重与波动率成反比（逆波动率加权），合计为 1
+//! - 波动率目标：每个策略 1/n 的基础份额按 `目标波动率 / 实际波动率` 缩放（不加杠杆）
+//! - 凯利比例：`fraction × f*`，`f* = p - (1 - p) / b`（p 为胜率，b 为盈亏比），合计超过 1 时等比缩小
+//!
+//! 波动率与胜率来自已平仓交易的收益率；样本不足时：
+//! 风险平价取其余策略的平均逆波动率，波动率目标取基础份额，凯利取 `fraction / n`。
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+fn default_kelly_fraction() -> f64 {
+    0.5
+}
+
+/// 资金分配方法
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case", deny_unknown_fields)]
+pub enum AllocationMethod {
+    /// 固定权重
+    FixedWeights {
+        /// 策略实例 ID → 权重
+        #[serde(default)]
+        weights: HashMap<Uuid, f64>,
+    },
+    /// 风险平价（逆波动率加权）
+    RiskParity,
+    /// 波动率目标
+    VolatilityTarget {
+        /// 目标波动率（单笔交易收益率的标准差）
+        target_volatility: f64,
+    },
+    /// 凯利比例
+    Kelly {
+        /// 凯利系数（1 为全凯利）
+        #[serde(default = "default_kelly_fraction")]
+        fraction: f64,
+    },
+}
+
+impl Default for AllocationMethod {
+    fn default() -> Self {
+        Self::FixedWeights {
+            weights: HashMap::new(),
+        }
+    }
+}
+
+impl AllocationMethod {
+    /// 方法名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FixedWeights { .. } => "fixed_weights",
+            Self::RiskParity => "risk_parity",
+            Self::VolatilityTarget { .. } => "volatility_target",
+            Self::Kelly { .. } => "kelly",
+        }
+    }
+
+    /// 解析配置：JSON 对象，或只写方法名（如 `risk_parity`）
+    pub fn parse(value: &str) -> Result<Self, serde_json::Error> {
+        let value = value.trim();
+        if value.starts_with('{') {
+            serde_json::from_str(value)
+        } else {
+            serde_json::from_value(serde_json::json!({ "method": value }))
+        }
+    }
+
+    /// 计算各策略权重
+    pub fn weights(&self, strategies: &[(Uuid, StrategyStats)]) -> HashMap<Uuid, f64> {
+        if strategies.is_empty() {
+            return HashMap::new();
+        }
+        let n = strategies.len() as f64;
+
+        let weights: Vec<(Uuid, f64)> = match self {
+            Self::FixedWeights { weights } => {
+                let configured: f64 = strategies
+                    .iter()
+                    .filter_map(|(id, _)| weights.get(id))
+                    .map(|w| w.max(0.0))
+                    .sum();
+                let unconfigured = strategies.iter().filter(|(id, _)| !weights.contains_key(id)).count();
+                let share = if unconfigured > 0 {
+                    (1.0 - configured).max(0.0) / unconfigured as f64
+                } else {
+                    0.0
+                };
+                strategies
+                    .iter()
+                    .map(|(id, _)| (*id, weights.get(id).map_or(share, |w| w.max(0.0))))
+                    .collect()
+            }
+            Self::RiskParity => {
+                let inverse: Vec<Option<f64>> = strategies
+                    .iter()
+                    .map(|(_, stats)| stats.volatility.filter(|v| *v > 0.0).map(|v| 1.0 / v))
+                    .collect();
+                let known: Vec<f64> = inverse.iter().flatten().copied().collect();
+                let fallback = if known.is_empty() {
+                    1.0
+                } else {
+                    known.iter().sum::<f64>() / known.len() as f64
+                };
+                strategies
+                    .iter()
+                    .zip(inverse)
+                    .map(|((id, _), inverse)| (*id, inverse.unwrap_or(fallback)))
+                    .collect()
+            }
+            Self::VolatilityTarget { target_volatility } => strategies
+                .iter()
+                .map(|(id, stats)| {
+                    let scale = match stats.volatility {
+                        Some(v) if v > 0.0 => (target_volatility / v).clamp(0.0, 1.0),
+                        _ => 1.0,
+                    };
+                    (*id, scale / n)
+                })
+                .collect(),
+            Self::Kelly { fraction } => strategies
+                .iter()
+                .map(|(id, stats)| {
+                    let weight = match stats.kelly {
+                        Some(kelly) => fraction * kelly.max(0.0),
+                        None => fraction / n,
+                    };
+                    (*id, weight.clamp(0.0, 1.0))
+                })
+                .collect(),
+        };
+
+        normalize(weights, matches!(self, Self::RiskParity))
+    }
+}
+
+/// 归一化：`always` 时合计缩放为 1，否则只在合计超过 1 时等比缩小
+fn normalize(weights: Vec<(Uuid, f64)>, always: bool) -> HashMap<Uuid, f64> {
+    let total: f64 = weights.iter().map(|(_, w)| w).sum();
+    let scale = if total > 0.0 && (always || total > 1.0) {
+        1.0 / total
+    } else {
+        1.0
+    };
+    weights.into_iter().map(|(id, w)| (id, w * scale)).collect()
+}
+
+/// 策略表现统计
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StrategyStats {
+    /// 单笔交易收益率的标准差（样本不足时为 None）
+    pub volatility: Option<f64>,
+    /// 凯利最优比例 f*（样本不足或没有亏损交易时为 None）
+    pub kelly: Option<f64>,
+}
+
+impl StrategyStats {
+    /// 由已平仓交易收益率计算（样本数少于 `min_trades` 时不给出统计）
+    pub fn from_returns(returns: &[f64], min_trades: usize) -> Self {
+        if returns.len() < min_trades.max(2) {
+            return Self::default();
+        }
+        let n = returns.len() as f64;
+        let mean = returns.iter().sum::<f64>() / n;
+        let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0);
+
+        let wins: Vec<f64> = returns.iter().copied().filter(|r| *r > 0.0).collect();
+        let losses: Vec<f64> = returns.iter().copied().filter(|r| *r < 0.0).collect();
+        let kelly = if wins.is_empty() {
+            Some(0.0)
+        } else if losses.is_empty() {
+            None
+        } else {
+            let p = wins.len() as f64 / n;
+            let average_win = wins.iter().sum::<f64>() / wins.len() as f64;
+            let average_loss = -losses.iter().sum::<f64>() / losses.len() as f64;
+            Some(p - (1.0 - p) / (average_win / average_loss))
+        };
+
+        Self {
+            volatility: Some(variance.sqrt()),
+            kelly,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(n: usize) -> Vec<Uuid> {
+        (0..n).map(|_| Uuid::new_v4()).collect()
+    }
+
+    #[test]
+    fn test_weights_by_method() {
+        let id = ids(3);
+        let stats = |volatility: Option<f64>, kelly: Option<f64>| StrategyStats { volatility, kelly };
+        let strategies = vec![
+            (id[0], stats(Some(0.01), Some(0.4))),
+            (id[1], stats(Some(0.02), Some(-0.1))),
+            (id[2], stats(None, None)),
+        ];
+
+        // 固定权重：未配置的平分剩余
+        let fixed = AllocationMethod::FixedWeights {
+            weights: HashMap::from([(id[0], 0.5)]),
+        }
+        .weights(&strategies);
+        assert!((fixed[&id[0]] - 0.5).abs() < 1e-12);
+        assert!((fixed[&id[1]] - 0.25).abs() < 1e-12);
+
+        // 风险平价：逆波动率 100 / 50 / 均值 75，合计 225
+        let parity = AllocationMethod::RiskParity.weights(&strategies);
+        assert!((parity[&id[0]] - 100.0 / 225.0).abs() < 1e-12);
+        assert!((parity[&id[2]] - 75.0 / 225.0).abs() < 1e-12);
+        assert!((parity.values().sum::<f64>() - 1.0).abs() < 1e-12);
+
+        // 波动率目标：0.01 的目标下，第二个策略减半
+        let target = AllocationMethod::VolatilityTarget {
+            target_volatility: 0.01,
+        }
+        .weights(&strategies);
+        assert!((target[&id[0]] - 1.0 / 3.0).abs() < 1e-12);
+        assert!((target[&id[1]] - 1.0 / 6.0).abs() < 1e-12);
+
+        // 凯利：负期望不分配，样本不足取 fraction / n
+        let kelly = AllocationMethod::parse(r#"{"method": "kelly", "fraction": 0.5}"#)
+            .unwrap()
+            .weights(&strategies);
+        assert!((kelly[&id[0]] - 0.2).abs() < 1e-12);
+        assert_eq!(kelly[&id[1]], 0.0);
+        assert!((kelly[&id[2]] - 0.5 / 3.0).abs() < 1e-12);
+
+        // 超配时等比缩小
+        let over = AllocationMethod::FixedWeights {
+            weights: HashMap::from([(id[0], 1.5), (id[1], 0.5)]),
+        }
+        .weights(&strategies);
+        assert!((over.values().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!(matches!(AllocationMethod::parse("risk_parity"), Ok(AllocationMethod::RiskParity)));
+        assert!(AllocationMethod::parse("martingale").is_err());
+    }
+
+    #[test]
+    fn test_stats_from_returns() {
+        assert_eq!(StrategyStats::from_returns(&[0.01], 5), StrategyStats::default());
+
+        // 胜率 0.6，盈亏比 2：f* = 0.6 - 0.4 / 2 = 0.4
+        let returns = [0.02, 0.02, 0.02, -0.01, -0.01];
+        let stats = StrategyStats::from_returns(&returns, 5);
+        assert!((stats.kelly.unwrap() - 0.4).abs() < 1e-12);
+        assert!(stats.volatility.unwrap() > 0.0);
+
+        assert_eq!(StrategyStats::from_returns(&[-0.01, -0.02], 2).kelly, Some(0.0));
+        assert_eq!(StrategyStats::from_returns(&[0.01, 0.02], 2).kelly, None);
+    }
+}
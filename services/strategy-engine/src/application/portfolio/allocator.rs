@@ -0,0 +1,558 @@
+//! # 组合资金分配器 (Portfolio Allocator)
+//!
+//! 位于策略信号与 trading-engine 之间，按共享资金重新确定每个意图的数量：
+//! 1. 权益 = 基础资金 + 各策略已实现盈亏 + 未实现盈亏（按执行回报与最新价跟踪）
+//! 2. 按分配方法计算运行中策略的权重，策略预算 = 权益 × 权重
+//! 3. 开仓（含反手）：名义价值 = min(预算 × 单次开仓比例, 预算 - 已占用)，数量 = 名义价值 / 价格
+//! 4. 平仓：数量取账簿中的持仓；减仓：不超过账簿中的持仓
+//! 5. 对冲：同一行情批次中，同一交易对与市场、不同策略的反向意图在价格相容时内部对敲，
+//!    只把剩余数量发往交易所（见 `IntentNetting`）；对冲部分作为内部成交记入双方账簿，
+//!    调度器同时把同一笔成交回报交给双方策略
+//!
+//! ## 规则
+//! - ✅ 只处理单腿意图
+//! - ❌ 多腿意图组不缩放、不对冲（腿间数量比例由策略决定）
+//! - ❌ 做市报价不经过分配器（库存由做市策略自行控制）
+//! - ❌ 对冲默认关闭：trading-engine 的策略持仓只由交易所成交驱动，不含内部成交，
+//!   其后发布的持仓快照会覆盖内部成交；trading-engine 记账内部对冲之前不要开启
+
+use std::collections::HashMap;
+
+use parking_lot::Mutex;
+use rust_decimal::prelude::*;
+use rust_decimal::Decimal;
+use shared::event::execution_feedback_event::ExecutionFeedback;
+use tracing::debug;
+use uuid::Uuid;
+
+use super::allocation::{AllocationMethod, StrategyStats};
+use super::book::StrategyBook;
+use crate::domain::model::strategy_metadata::MarketType;
+use crate::domain::model::strategy_runtime::{
+    ExecutionResult, IntentNetting, OrderSide, OrderType, TradeIntent,
+};
+use shared::types::order::PositionAction;
+
+/// 组合配置
+#[derive(Debug, Clone)]
+pub struct PortfolioConfig {
+    /// 基础资金（计价货币）
+    pub base_equity: Decimal,
+    /// 分配方法
+    pub method: AllocationMethod,
+    /// 单次开仓占策略预算的比例
+    pub order_fraction: Decimal,
+    /// 数量保留的小数位（向下取整）
+    pub quantity_scale: u32,
+    /// 统计用的已平仓交易数
+    pub return_window: usize,
+    /// 给出统计所需的最少交易数
+    pub min_trades: usize,
+    /// 是否对冲不同策略的反向意图（默认关闭，见模块文档）
+    pub netting: bool,
+}
+
+impl Default for PortfolioConfig {
+    fn default() -> Self {
+        Self {
+            base_equity: Decimal::new(10_000, 0),
+            method: AllocationMethod::default(),
+            order_fraction: Decimal::ONE,
+            quantity_scale: 6,
+            return_window: 100,
+            min_trades: 10,
+            netting: false,
+        }
+    }
+}
+
+impl PortfolioConfig {
+    /// 校验配置
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.base_equity <= Decimal::ZERO {
+            anyhow::bail!("base_equity must be positive, got {}", self.base_equity);
+        }
+        if self.order_fraction <= Decimal::ZERO || self.order_fraction > Decimal::ONE {
+            anyhow::bail!("order_fraction must be in (0, 1], got {}", self.order_fraction);
+        }
+        match &self.method {
+            AllocationMethod::FixedWeights { weights } => {
+                if let Some((id, weight)) = weights.iter().find(|(_, w)| !(0.0..=1.0).contains(*w)) {
+                    anyhow::bail!("weight of {} must be in [0, 1], got {}", id, weight);
+                }
+            }
+            AllocationMethod::VolatilityTarget { target_volatility } if *target_volatility <= 0.0 => {
+                anyhow::bail!("target_volatility must be positive, got {}", target_volatility);
+            }
+            AllocationMethod::Kelly { fraction } if *fraction <= 0.0 || *fraction > 1.0 => {
+                anyhow::bail!("kelly fraction must be in (0, 1], got {}", fraction);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// 待分配的执行结果
+#[derive(Debug, Clone)]
+pub struct StrategyOutput {
+    /// 策略市场类型（不同市场的同名交易对不对冲）
+    pub market_type: MarketType,
+    /// 执行结果
+    pub result: ExecutionResult,
+}
+
+/// 组合账簿
+#[derive(Debug, Default)]
+struct PortfolioBooks {
+    books: HashMap<Uuid, StrategyBook>,
+    /// 最新价
+    prices: HashMap<String, Decimal>,
+}
+
+/// 组合资金分配器
+pub struct PortfolioAllocator {
+    config: PortfolioConfig,
+    books: Mutex<PortfolioBooks>,
+}
+
+impl PortfolioAllocator {
+    pub fn new(config: PortfolioConfig) -> Self {
+        Self {
+            config,
+            books: Mutex::new(PortfolioBooks::default()),
+        }
+    }
+
+    /// 配置
+    pub fn config(&self) -> &PortfolioConfig {
+        &self.config
+    }
+
+    /// 记录最新价
+    pub fn observe_price(&self, symbol: &str, price: Decimal) {
+        if price > Decimal::ZERO {
+            self.books.lock().prices.insert(symbol.to_string(), price);
+        }
+    }
+
+    /// 记录执行回报
+    pub fn on_feedback(&self, strategy_id: Uuid, symbol: &str, feedback: &ExecutionFeedback) {
+        let mut books = self.books.lock();
+        let window = self.config.return_window;
+        let book = books
+            .books
+            .entry(strategy_id)
+            .or_insert_with(|| StrategyBook::new(window));
+        match feedback {
+            ExecutionFeedback::Fill(fill) => book.apply_fill(symbol, fill),
+            ExecutionFeedback::PositionSnapshot(snapshot) => book.apply_snapshot(symbol, snapshot),
+            ExecutionFeedback::OrderRejected(_) => {}
+        }
+    }
+
+    /// 当前权益
+    pub fn equity(&self) -> Decimal {
+        let books = self.books.lock();
+        self.equity_of(&books)
+    }
+
+    fn equity_of(&self, books: &PortfolioBooks) -> Decimal {
+        self.config.base_equity
+            + books
+                .books
+                .values()
+                .map(|book| book.realized_pnl() + book.unrealized_pnl(&books.prices))
+                .sum::<Decimal>()
+    }
+
+    fn weights_of(&self, books: &PortfolioBooks, strategies: &[Uuid]) -> HashMap<Uuid, f64> {
+        let stats: Vec<(Uuid, StrategyStats)> = strategies
+            .iter()
+            .map(|id| {
+                let stats = books
+                    .books
+                    .get(id)
+                    .map(|book| book.stats(self.config.min_trades))
+                    .unwrap_or_default();
+                (*id, stats)
+            })
+            .collect();
+        self.config.method.weights(&stats)
+    }
+
+    /// 缩放并对冲一个行情批次的执行结果
+    ///
+    /// - `strategies`: 参与分配的策略（运行中的实例）
+    /// - `market_price`: 本批次行情价格（市价意图的参考价与对冲价）
+    ///
+    /// 不再包含任何意图的结果被丢弃。对冲部分在返回前已记入双方账簿，
+    /// 调度器只需把 `TradeIntent::internal_fill` 交给策略。
+    pub fn allocate(
+        &self,
+        strategies: &[Uuid],
+        market_price: Decimal,
+        outputs: Vec<StrategyOutput>,
+    ) -> Vec<ExecutionResult> {
+        let mut outputs = {
+            let books = self.books.lock();
+            let equity = self.equity_of(&books);
+            let weights = self.weights_of(&books, strategies);
+            outputs
+                .into_iter()
+                .map(|mut output| {
+                    output.result.intent = output.result.intent.take().and_then(|intent| {
+                        let weight = weights.get(&intent.strategy_id).copied().unwrap_or(0.0);
+                        self.size(&books, equity, weight, market_price, intent)
+                    });
+                    output
+                })
+                .collect::<Vec<_>>()
+        };
+
+        if self.config.netting {
+            net(&mut outputs, market_price);
+            for intent in outputs.iter().filter_map(|output| output.result.intent.as_ref()) {
+                if let Some(fill) = intent.internal_fill() {
+                    let feedback = ExecutionFeedback::Fill(fill);
+                    self.on_feedback(intent.strategy_id, &intent.symbol, &feedback);
+                }
+            }
+        }
+
+        outputs
+            .into_iter()
+            .filter_map(|output| {
+                let mut result = output.result;
+                result.has_intent = result.intent.is_some() || result.group.is_some() || result.quotes.is_some();
+                result.has_intent.then_some(result)
+            })
+            .collect()
+    }
+
+    /// 按预算确定意图数量（预算用尽时返回 None）
+    fn size(
+        &self,
+        books: &PortfolioBooks,
+        equity: Decimal,
+        weight: f64,
+        market_price: Decimal,
+        mut intent: TradeIntent,
+    ) -> Option<TradeIntent> {
+        let held = books
+            .books
+            .get(&intent.strategy_id)
+            .map(|book| book.position(&intent.symbol).quantity.abs())
+            .unwrap_or_default();
+
+        match intent.instruction.action {
+            // 平仓以账簿持仓为准；账簿无记录时保持原数量
+            PositionAction::Close if !held.is_zero() => intent.quantity = held,
+            PositionAction::Reduce if !held.is_zero() => intent.quantity = intent.quantity.min(held),
+            PositionAction::Close | PositionAction::Reduce => {}
+            PositionAction::Open | PositionAction::Flip => {
+                let price = intent.price.unwrap_or(market_price);
+                if price <= Decimal::ZERO {
+                    return None;
+                }
+                let budget = equity.max(Decimal::ZERO) * Decimal::from_f64(weight).unwrap_or_default();
+                let used = books.books.get(&intent.strategy_id).map_or(Decimal::ZERO, |book| {
+                    let exposure = book.exposure(&books.prices);
+                    // 反手时本交易对的原有仓位会被平掉，不占用预算
+                    if intent.instruction.action == PositionAction::Flip {
+                        let own = books.prices.get(&intent.symbol).copied().unwrap_or(price);
+                        exposure - held * own
+                    } else {
+                        exposure
+                    }
+                });
+                let notional = (budget * self.config.order_fraction).min(budget - used);
+                let quantity = (notional / price)
+                    .round_dp_with_strategy(self.config.quantity_scale, RoundingStrategy::ToZero);
+                if quantity <= Decimal::ZERO {
+                    debug!(
+                        strategy_id = %intent.strategy_id,
+                        symbol = %intent.symbol,
+                        budget = %budget,
+                        used = %used,
+                        "Strategy budget exhausted, intent dropped"
+                    );
+                    return None;
+                }
+                intent.quantity = match intent.instruction.action {
+                    PositionAction::Flip => quantity + held,
+                    _ => quantity,
+                };
+            }
+        }
+        Some(intent)
+    }
+}
+
+/// 对冲不同策略的反向意图
+fn net(outputs: &mut [StrategyOutput], market_price: Decimal) {
+    // (索引, 剩余数量)
+    let mut remaining: Vec<Option<Decimal>> = outputs
+        .iter()
+        .map(|output| output.result.intent.as_ref().map(|intent| intent.quantity))
+        .collect();
+    let mut crosses: Vec<(usize, usize, Decimal, Decimal)> = Vec::new();
+
+    for buy in 0..outputs.len() {
+        for sell in 0..outputs.len() {
+            let (Some(b), Some(s)) = (&outputs[buy].result.intent, &outputs[sell].result.intent) else {
+                continue;
+            };
+            if b.side != OrderSide::Buy
+                || s.side != OrderSide::Sell
+                || b.strategy_id == s.strategy_id
+                || b.symbol != s.symbol
+                || outputs[buy].market_type != outputs[sell].market_type
+            {
+                continue;
+            }
+            let (Some(buy_left), Some(sell_left)) = (remaining[buy], remaining[sell]) else {
+                continue;
+            };
+            let quantity = buy_left.min(sell_left);
+            let Some(price) = cross_price(b, s, market_price) else {
+                continue;
+            };
+            if quantity <= Decimal::ZERO {
+                continue;
+            }
+            remaining[buy] = Some(buy_left - quantity);
+            remaining[sell] = Some(sell_left - quantity);
+            crosses.push((buy, sell, quantity, price));
+        }
+    }
+
+    for (buy, sell, quantity, price) in crosses {
+        let buyer = outputs[buy].result.intent.as_ref().map(|i| i.strategy_id);
+        let seller = outputs[sell].result.intent.as_ref().map(|i| i.strategy_id);
+        for (index, counterparty) in [(buy, seller), (sell, buyer)] {
+            if let (Some(intent), Some(counterparty)) = (outputs[index].result.intent.as_mut(), counterparty) {
+                record_cross(intent, quantity, price, counterparty);
+            }
+        }
+    }
+}
+
+/// 对冲价：参考价落在双方限价区间内（买价 ≥ 卖价）时成交，不相容时返回 None
+fn cross_price(buy: &TradeIntent, sell: &TradeIntent, market_price: Decimal) -> Option<Decimal> {
+    let limit = |intent: &TradeIntent| match intent.order_type {
+        OrderType::Market => None,
+        _ => intent.price,
+    };
+    let (buy_limit, sell_limit) = (limit(buy), limit(sell));
+    if let (Some(buy_limit), Some(sell_limit)) = (buy_limit, sell_limit) {
+        if buy_limit < sell_limit {
+            return None;
+        }
+    }
+    let mut price = market_price;
+    if let Some(buy_limit) = buy_limit {
+        price = price.min(buy_limit);
+    }
+    if let Some(sell_limit) = sell_limit {
+        price = price.max(sell_limit);
+    }
+    (price > Decimal::ZERO).then_some(price)
+}
+
+/// 累加一笔对冲（价格按数量加权）
+fn record_cross(intent: &mut TradeIntent, quantity: Decimal, price: Decimal, counterparty: Uuid) {
+    let netting = intent.netting.get_or_insert(IntentNetting {
+        quantity: Decimal::ZERO,
+        price: Decimal::ZERO,
+        counterparties: Vec::new(),
+    });
+    let total = netting.quantity + quantity;
+    netting.price = (netting.price * netting.quantity + price * quantity) / total;
+    netting.quantity = total;
+    if !netting.counterparties.contains(&counterparty) {
+        netting.counterparties.push(counterparty);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::event::execution_feedback_event::PositionSnapshot;
+    use shared::types::order::OrderInstruction;
+
+    fn output(intent: TradeIntent, market_type: MarketType) -> StrategyOutput {
+        StrategyOutput {
+            market_type,
+            result: ExecutionResult {
+                request_id: Uuid::new_v4(),
+                has_intent: true,
+                intent: Some(intent),
+                group: None,
+                quotes: None,
+                execution_time_us: 0,
+                error: None,
+            },
+        }
+    }
+
+    fn allocator(weights: HashMap<Uuid, f64>) -> PortfolioAllocator {
+        PortfolioAllocator::new(PortfolioConfig {
+            method: AllocationMethod::FixedWeights { weights },
+            order_fraction: Decimal::new(5, 1),
+            netting: true,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_sizes_intents_from_equity_and_budget() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let portfolio = allocator(HashMap::from([(a, 0.2)]));
+        let price = Decimal::from(100);
+
+        // 权益 10000：A 预算 2000，单次开仓 1000 → 10；B 平分剩余 8000，单次 4000 → 40
+        let results = portfolio.allocate(
+            &[a, b],
+            price,
+            vec![
+                output(TradeIntent::market_buy(a, "BTCUSDT", Decimal::ONE, 1.0), MarketType::Spot),
+                output(TradeIntent::limit_buy(b, "ETHUSDT", Decimal::ONE, price, 1.0), MarketType::Spot),
+            ],
+        );
+        assert_eq!(results[0].intent.as_ref().unwrap().quantity, Decimal::from(10));
+        assert_eq!(results[1].intent.as_ref().unwrap().quantity, Decimal::from(40));
+
+        // A 已持有 15（占用 1500），剩余预算 500 → 5
+        portfolio.on_feedback(
+            a,
+            "BTCUSDT",
+            &ExecutionFeedback::PositionSnapshot(PositionSnapshot {
+                quantity: Decimal::from(15),
+                average_price: price,
+            }),
+        );
+        portfolio.observe_price("BTCUSDT", price);
+        let results = portfolio.allocate(
+            &[a, b],
+            price,
+            vec![output(TradeIntent::market_buy(a, "BTCUSDT", Decimal::ONE, 1.0), MarketType::Spot)],
+        );
+        assert_eq!(results[0].intent.as_ref().unwrap().quantity, Decimal::from(5));
+
+        // 平仓取账簿持仓；预算用尽的开仓被丢弃
+        portfolio.on_feedback(
+            a,
+            "BTCUSDT",
+            &ExecutionFeedback::PositionSnapshot(PositionSnapshot {
+                quantity: Decimal::from(20),
+                average_price: price,
+            }),
+        );
+        let close = TradeIntent::market_sell(a, "BTCUSDT", Decimal::ONE, 1.0)
+            .with_instruction(OrderInstruction::close());
+        let results = portfolio.allocate(
+            &[a, b],
+            price,
+            vec![
+                output(close, MarketType::Spot),
+                output(TradeIntent::market_buy(a, "BTCUSDT", Decimal::ONE, 1.0), MarketType::Spot),
+            ],
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].intent.as_ref().unwrap().quantity, Decimal::from(20));
+
+        // 权益随盈亏变化
+        portfolio.observe_price("BTCUSDT", Decimal::from(110));
+        assert_eq!(portfolio.equity(), Decimal::from(10_200));
+    }
+
+    #[test]
+    fn test_nets_opposing_intents_across_strategies() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let portfolio = allocator(HashMap::from([(a, 0.2), (b, 0.1), (c, 0.1)]));
+        let price = Decimal::from(100);
+
+        // A 买 10，B 卖 5（限价 100 与参考价相容），C 是合约市场不参与
+        let results = portfolio.allocate(
+            &[a, b, c],
+            price,
+            vec![
+                output(TradeIntent::market_buy(a, "BTCUSDT", Decimal::ONE, 1.0), MarketType::Spot),
+                output(
+                    TradeIntent::limit_sell(b, "BTCUSDT", Decimal::ONE, price, 1.0),
+                    MarketType::Spot,
+                ),
+                output(TradeIntent::market_sell(c, "BTCUSDT", Decimal::ONE, 1.0), MarketType::UsdtFutures),
+            ],
+        );
+        let buy = results[0].intent.as_ref().unwrap();
+        let sell = results[1].intent.as_ref().unwrap();
+        assert_eq!(buy.quantity, Decimal::from(10));
+        assert_eq!(buy.exchange_quantity(), Decimal::from(5));
+        assert_eq!(sell.exchange_quantity(), Decimal::ZERO);
+        assert_eq!(sell.netting.as_ref().unwrap().price, price);
+        assert_eq!(sell.netting.as_ref().unwrap().counterparties, vec![a]);
+        assert!(results[2].intent.as_ref().unwrap().netting.is_none());
+
+        // 限价不相容（买 95 < 卖 105）不对冲
+        let results = portfolio.allocate(
+            &[a, b, c],
+            price,
+            vec![
+                output(TradeIntent::limit_buy(a, "BTCUSDT", Decimal::ONE, Decimal::from(95), 1.0), MarketType::Spot),
+                output(TradeIntent::limit_sell(b, "BTCUSDT", Decimal::ONE, Decimal::from(105), 1.0), MarketType::Spot),
+            ],
+        );
+        assert!(results.iter().all(|r| r.intent.as_ref().unwrap().netting.is_none()));
+    }
+
+    #[test]
+    fn test_full_cross_books_both_counterparties() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let portfolio = allocator(HashMap::from([(a, 0.1), (b, 0.1)]));
+        let price = Decimal::from(100);
+
+        // A 买 5，B 卖 5：全部内部对冲，不发往交易所
+        let results = portfolio.allocate(
+            &[a, b],
+            price,
+            vec![
+                output(TradeIntent::market_buy(a, "BTCUSDT", Decimal::ONE, 1.0), MarketType::Spot),
+                output(TradeIntent::market_sell(b, "BTCUSDT", Decimal::ONE, 1.0), MarketType::Spot),
+            ],
+        );
+        let fills: Vec<_> = results
+            .iter()
+            .map(|r| r.intent.as_ref().unwrap().internal_fill().unwrap())
+            .collect();
+        assert!(results.iter().all(|r| r.intent.as_ref().unwrap().exchange_quantity().is_zero()));
+        assert!(fills
+            .iter()
+            .all(|f| f.is_final && f.quantity == Decimal::from(5) && f.price == price));
+
+        // 双方账簿都记录了内部成交
+        let books = portfolio.books.lock();
+        let position = |id: &Uuid| books.books[id].position("BTCUSDT");
+        assert_eq!(position(&a).quantity, Decimal::from(5));
+        assert_eq!(position(&b).quantity, Decimal::from(-5));
+        assert_eq!(position(&a).average_price, price);
+        assert_eq!(position(&b).average_price, price);
+    }
+
+    #[test]
+    fn test_netting_disabled_by_default() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let portfolio = PortfolioAllocator::new(PortfolioConfig::default());
+        let results = portfolio.allocate(
+            &[a, b],
+            Decimal::from(100),
+            vec![
+                output(TradeIntent::market_buy(a, "BTCUSDT", Decimal::ONE, 1.0), MarketType::Spot),
+                output(TradeIntent::market_sell(b, "BTCUSDT", Decimal::ONE, 1.0), MarketType::Spot),
+            ],
+        );
+        assert!(results.iter().all(|r| r.intent.as_ref().unwrap().netting.is_none()));
+        assert!(portfolio.books.lock().books.is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+//! # 策略账簿 (Strategy Book)
+//!
+//! 按执行回报跟踪每个策略的持仓、已实现盈亏与已平仓交易收益率：
+//! - 成交：同向加仓更新均价；反向成交先平仓（记一笔交易收益率），剩余部分反向开仓
+//! - 持仓快照：以 trading-engine 为准覆盖数量与均价
+//!
+//! 手续费资产不一定是计价资产，盈亏不扣手续费。
+
+use std::collections::{HashMap, VecDeque};
+
+use rust_decimal::prelude::*;
+use rust_decimal::Decimal;
+use shared::event::execution_feedback_event::{PositionSnapshot, StrategyFill};
+use shared::types::order::OrderSide;
+
+use super::allocation::StrategyStats;
+
+/// 单个交易对的持仓
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BookPosition {
+    /// 净持仓（正数为多头，负数为空头）
+    pub quantity: Decimal,
+    /// 持仓均价
+    pub average_price: Decimal,
+}
+
+/// 策略账簿
+#[derive(Debug, Clone)]
+pub struct StrategyBook {
+    positions: HashMap<String, BookPosition>,
+    realized_pnl: Decimal,
+    /// 最近的已平仓交易收益率
+    trade_returns: VecDeque<f64>,
+    return_window: usize,
+}
+
+impl StrategyBook {
+    pub fn new(return_window: usize) -> Self {
+        Self {
+            positions: HashMap::new(),
+            realized_pnl: Decimal::ZERO,
+            trade_returns: VecDeque::with_capacity(return_window),
+            return_window: return_window.max(1),
+        }
+    }
+
+    /// 交易对持仓
+    pub fn position(&self, symbol: &str) -> BookPosition {
+        self.positions.get(symbol).copied().unwrap_or_default()
+    }
+
+    /// 已实现盈亏
+    pub fn realized_pnl(&self) -> Decimal {
+        self.realized_pnl
+    }
+
+    /// 记录成交
+    pub fn apply_fill(&mut self, symbol: &str, fill: &StrategyFill) {
+        let signed = match fill.side {
+            OrderSide::Buy => fill.quantity,
+            OrderSide::Sell => -fill.quantity,
+        };
+        let position = self.positions.entry(symbol.to_string()).or_default();
+
+        // 同向（或空仓）加仓
+        if position.quantity.is_zero() || position.quantity.is_sign_positive() == signed.is_sign_positive() {
+            let total = position.quantity.abs() + fill.quantity;
+            if !total.is_zero() {
+                position.average_price =
+                    (position.quantity.abs() * position.average_price + fill.quantity * fill.price) / total;
+            }
+            position.quantity += signed;
+            return;
+        }
+
+        // 反向成交：先平仓
+        let closed = position.quantity.abs().min(fill.quantity);
+        let direction = if position.quantity.is_sign_positive() {
+            Decimal::ONE
+        } else {
+            -Decimal::ONE
+        };
+        let pnl = closed * (fill.price - position.average_price) * direction;
+        let cost = closed * position.average_price;
+        self.realized_pnl += pnl;
+        if let Some(trade_return) = (!cost.is_zero()).then(|| pnl / cost).and_then(|r| r.to_f64()) {
+            if self.trade_returns.len() == self.return_window {
+                self.trade_returns.pop_front();
+            }
+            self.trade_returns.push_back(trade_return);
+        }
+
+        position.quantity += signed;
+        if position.quantity.is_zero() {
+            position.average_price = Decimal::ZERO;
+        } else if fill.quantity > closed {
+            // 反手：剩余部分按成交价开仓
+            position.average_price = fill.price;
+        }
+    }
+
+    /// 以持仓快照覆盖
+    pub fn apply_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {
+        self.positions.insert(
+            symbol.to_string(),
+            BookPosition {
+                quantity: snapshot.quantity,
+                average_price: snapshot.average_price,
+            },
+        );
+    }
+
+    /// 持仓名义价值（无最新价时按均价计）
+    pub fn exposure(&self, prices: &HashMap<String, Decimal>) -> Decimal {
+        self.positions
+            .iter()
+            .map(|(symbol, position)| {
+                let price = prices.get(symbol).copied().unwrap_or(position.average_price);
+                position.quantity.abs() * price
+            })
+            .sum()
+    }
+
+    /// 未实现盈亏
+    pub fn unrealized_pnl(&self, prices: &HashMap<String, Decimal>) -> Decimal {
+        self.positions
+            .iter()
+            .filter_map(|(symbol, position)| {
+                let price = prices.get(symbol)?;
+                Some(position.quantity * (*price - position.average_price))
+            })
+            .sum()
+    }
+
+    /// 表现统计
+    pub fn stats(&self, min_trades: usize) -> StrategyStats {
+        let returns: Vec<f64> = self.trade_returns.iter().copied().collect();
+        StrategyStats::from_returns(&returns, min_trades)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn fill(side: OrderSide, quantity: i64, price: i64) -> StrategyFill {
+        StrategyFill {
+            order_id: "1".to_string(),
+            trade_id: "1".to_string(),
+            side,
+            quantity: Decimal::from(quantity),
+            price: Decimal::from(price),
+            cumulative_quantity: Decimal::from(quantity),
+            original_quantity: Decimal::from(quantity),
+            is_final: true,
+            commission: Decimal::ZERO,
+            commission_asset: "USDT".to_string(),
+            fill_time: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_book_tracks_positions_and_trade_returns() {
+        let mut book = StrategyBook::new(10);
+        book.apply_fill("BTCUSDT", &fill(OrderSide::Buy, 1, 100));
+        book.apply_fill("BTCUSDT", &fill(OrderSide::Buy, 1, 110));
+        assert_eq!(book.position("BTCUSDT").average_price, Decimal::from(105));
+
+        // 反手：平 2 赚 2 × 5，剩余 1 空头按 110 开仓
+        book.apply_fill("BTCUSDT", &fill(OrderSide::Sell, 3, 110));
+        assert_eq!(book.realized_pnl(), Decimal::from(10));
+        assert_eq!(
+            book.position("BTCUSDT"),
+            BookPosition {
+                quantity: Decimal::from(-1),
+                average_price: Decimal::from(110),
+            }
+        );
+
+        let prices = HashMap::from([("BTCUSDT".to_string(), Decimal::from(100))]);
+        assert_eq!(book.exposure(&prices), Decimal::from(100));
+        assert_eq!(book.unrealized_pnl(&prices), Decimal::from(10));
+
+        book.apply_fill("BTCUSDT", &fill(OrderSide::Buy, 1, 121));
+        assert!(book.position("BTCUSDT").quantity.is_zero());
+        let returns: Vec<f64> = book.trade_returns.iter().copied().collect();
+        assert_eq!(returns.len(), 2);
+        assert!((returns[1] + 0.1).abs() < 1e-12);
+
+        book.apply_snapshot(
+            "ETHUSDT",
+            &PositionSnapshot {
+                quantity: Decimal::from(2),
+                average_price: Decimal::from(50),
+            },
+        );
+        assert_eq!(book.exposure(&prices), Decimal::from(100));
+    }
+}
@@ -0,0 +1,12 @@
+//! # 组合资金分配模块 (Portfolio Allocation Module)
+//!
+//! 多个策略共享一份资金：按分配方法给每个策略预算，按当前权益重新确定意图数量，
+//! 并在发往交易所前对冲不同策略在同一交易对上的反向意图。
+
+pub mod allocation;
+pub mod allocator;
+pub mod book;
+
+pub use allocation::{AllocationMethod, StrategyStats};
+pub use allocator::{PortfolioAllocator, PortfolioConfig, StrategyOutput};
+pub use book::{BookPosition, StrategyBook};
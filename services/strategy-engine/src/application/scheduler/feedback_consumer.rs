@@ -0,0 +1,305 @@
+//! # 执行回报消费者 (Execution Feedback Consumer)
+//!
+//! 负责：
+//! 1. 从Kafka消费 trading-engine 发布的执行回报
+//! 2. 按 `strategy_id` 路由到对应的策略实例
+//! 3. 启用组合资金分配时同步更新组合账簿
+//! 4. 热重启时重放检查点之后的回报
+//!
+//! 回报消息以 `strategy_id` 为 key，同一实例的成交 / 拒单 / 快照按序到达。
+//!
+//! ## 规则
+//! - ✅ 关闭自动提交，位点只在回报路由完成后提交（无法解析的消息同样提交）
+//! - ✅ 启动时把位点回退到最早的检查点保存时间；回退区间（启动前已提交的位点之前）的回报
+//!   只交给检查点早于该回报的实例，检查点已包含的回报不重复应用
+//! - ❌ 冷启动的实例不重放历史回报（以之后的持仓快照为准）
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+use rdkafka::{ClientConfig, Message, Offset, TopicPartitionList};
+use shared::event::execution_feedback_event::ExecutionFeedbackEvent;
+use tokio::time::sleep;
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use crate::application::portfolio::PortfolioAllocator;
+use crate::domain::service::strategy_registry::StrategyRegistry;
+
+/// Kafka 元数据 / 位点查询超时
+const KAFKA_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 重放区间
+///
+/// 分区内位点小于 `until` 的消息为重放消息，只交给检查点早于该回报的实例。
+#[derive(Debug, Default)]
+struct ReplayWindow {
+    /// 实例 ID -> 检查点保存时间
+    checkpoints: HashMap<Uuid, DateTime<Utc>>,
+    /// 分区 -> 启动前已提交的位点
+    until: HashMap<i32, i64>,
+}
+
+impl ReplayWindow {
+    /// 该回报是否需要应用
+    fn accepts(&self, partition: i32, offset: i64, event: &ExecutionFeedbackEvent) -> bool {
+        let replaying = self.until.get(&partition).is_some_and(|until| offset < *until);
+        if !replaying {
+            return true;
+        }
+        self.checkpoints
+            .get(&event.strategy_id)
+            .is_some_and(|saved_at| event.timestamp > *saved_at)
+    }
+}
+
+/// 执行回报消费者
+pub struct ExecutionFeedbackConsumer {
+    /// 策略注册表
+    registry: Arc<StrategyRegistry>,
+    /// Kafka消费者
+    consumer: StreamConsumer,
+    /// 回报主题
+    topic: String,
+    /// 组合资金分配器
+    portfolio: Option<Arc<PortfolioAllocator>>,
+    /// 重放区间
+    replay: Mutex<ReplayWindow>,
+}
+
+impl ExecutionFeedbackConsumer {
+    /// 创建消费者
+    pub fn new(
+        registry: Arc<StrategyRegistry>,
+        kafka_brokers: &str,
+        topic: &str,
+        consumer_group: &str,
+    ) -> Result<Self> {
+        let consumer: StreamConsumer = ClientConfig::new()
+            .set("group.id", consumer_group)
+            .set("bootstrap.servers", kafka_brokers)
+            .set("enable.auto.commit", "false")
+            .set("auto.offset.reset", "latest")
+            .create()
+            .context("Failed to create Kafka feedback consumer")?;
+
+        consumer
+            .subscribe(&[topic])
+            .context("Failed to subscribe to feedback topic")?;
+
+        info!("ExecutionFeedbackConsumer created: topic={}", topic);
+
+        Ok(Self {
+            registry,
+            consumer,
+            topic: topic.to_string(),
+            portfolio: None,
+            replay: Mutex::new(ReplayWindow::default()),
+        })
+    }
+
+    /// 同步组合账簿
+    pub fn with_portfolio(mut self, portfolio: Arc<PortfolioAllocator>) -> Self {
+        self.portfolio = Some(portfolio);
+        self
+    }
+
+    /// 重放检查点之后的回报（实例 ID -> 检查点保存时间）
+    pub fn with_checkpoints(self, checkpoints: HashMap<Uuid, DateTime<Utc>>) -> Self {
+        self.replay.lock().checkpoints = checkpoints;
+        self
+    }
+
+    /// 运行消费循环
+    pub async fn run(&self) -> Result<()> {
+        info!("ExecutionFeedbackConsumer starting...");
+
+        if let Err(e) = self.rewind() {
+            warn!(error = %e, "Failed to rewind feedback offsets, replay skipped");
+        }
+
+        loop {
+            match self.consumer.recv().await {
+                Ok(message) => {
+                    if let Some(payload) = message.payload() {
+                        match serde_json::from_slice::<ExecutionFeedbackEvent>(payload) {
+                            Ok(event) => {
+                                let accepted = self.replay.lock().accepts(
+                                    message.partition(),
+                                    message.offset(),
+                                    &event,
+                                );
+                                if accepted {
+                                    self.dispatch(&event);
+                                }
+                            }
+                            Err(e) => warn!(error = %e, "Failed to parse execution feedback"),
+                        }
+                    }
+                    if let Err(e) = self.consumer.commit_message(&message, CommitMode::Async) {
+                        warn!(error = %e, "Failed to commit feedback offset");
+                    }
+                }
+                Err(e) => {
+                    error!(error = %e, "Kafka feedback consumer error");
+                    sleep(Duration::from_secs(1)).await;
+                }
+            }
+        }
+    }
+
+    /// 把各分区的位点回退到最早的检查点保存时间，并记录重放区间
+    ///
+    /// 在第一次 `recv` 之前执行：分区分配时从回退后的位点开始消费。
+    fn rewind(&self) -> Result<()> {
+        let Some(earliest) = self.replay.lock().checkpoints.values().min().copied() else {
+            return Ok(());
+        };
+
+        let metadata = self
+            .consumer
+            .fetch_metadata(Some(&self.topic), KAFKA_TIMEOUT)
+            .context("Failed to fetch feedback topic metadata")?;
+        let partitions: Vec<i32> = metadata
+            .topics()
+            .iter()
+            .filter(|topic| topic.name() == self.topic)
+            .flat_map(|topic| topic.partitions().iter().map(|p| p.id()))
+            .collect();
+
+        let since = Offset::Offset(earliest.timestamp_millis());
+        let mut timestamps = TopicPartitionList::new();
+        let mut assigned = TopicPartitionList::new();
+        for &partition in &partitions {
+            timestamps
+                .add_partition_offset(&self.topic, partition, since)
+                .map_err(|e| anyhow!("invalid feedback partition: {}", e))?;
+            assigned.add_partition(&self.topic, partition);
+        }
+        let rewound = self
+            .consumer
+            .offsets_for_times(timestamps, KAFKA_TIMEOUT)
+            .context("Failed to look up feedback offsets by checkpoint time")?;
+        let committed = self
+            .consumer
+            .committed_offsets(assigned, KAFKA_TIMEOUT)
+            .context("Failed to fetch committed feedback offsets")?;
+
+        let mut offsets = TopicPartitionList::new();
+        let mut until = HashMap::new();
+        let offset_of = |list: &TopicPartitionList, partition: i32| {
+            list.find_partition(&self.topic, partition).map(|p| p.offset())
+        };
+        for &partition in &partitions {
+            // 检查点之后没有回报的分区无需回退
+            let Some(Offset::Offset(from)) = offset_of(&rewound, partition) else {
+                continue;
+            };
+            let boundary = match offset_of(&committed, partition) {
+                Some(Offset::Offset(offset)) => offset,
+                // 该消费组从未提交过：启动前已有的消息全部视为重放
+                _ => {
+                    self.consumer
+                        .fetch_watermarks(&self.topic, partition, KAFKA_TIMEOUT)
+                        .context("Failed to fetch feedback watermarks")?
+                        .1
+                }
+            };
+            if from < boundary {
+                offsets
+                    .add_partition_offset(&self.topic, partition, Offset::Offset(from))
+                    .map_err(|e| anyhow!("invalid feedback offset: {}", e))?;
+                until.insert(partition, boundary);
+            }
+        }
+
+        if until.is_empty() {
+            return Ok(());
+        }
+        self.consumer
+            .commit(&offsets, CommitMode::Sync)
+            .context("Failed to commit rewound feedback offsets")?;
+        info!(
+            since = %earliest,
+            partitions = until.len(),
+            "Replaying execution feedback after checkpoints"
+        );
+        self.replay.lock().until = until;
+        Ok(())
+    }
+
+    /// 路由单条回报
+    fn dispatch(&self, event: &ExecutionFeedbackEvent) {
+        if !self.registry.contains(event.strategy_id) {
+            debug!(
+                strategy_id = %event.strategy_id,
+                "Feedback for unknown strategy instance, skipping"
+            );
+            return;
+        }
+
+        if let Some(portfolio) = &self.portfolio {
+            portfolio.on_feedback(event.strategy_id, &event.symbol, &event.feedback);
+        }
+
+        if let Err(e) =
+            self.registry
+                .dispatch_feedback(event.strategy_id, &event.symbol, &event.feedback)
+        {
+            warn!(
+                strategy_id = %event.strategy_id,
+                symbol = %event.symbol,
+                error = %e,
+                "Failed to dispatch execution feedback"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration as ChronoDuration;
+    use rust_decimal::Decimal;
+    use shared::event::execution_feedback_event::{ExecutionFeedback, PositionSnapshot};
+
+    fn event(strategy_id: Uuid, timestamp: DateTime<Utc>) -> ExecutionFeedbackEvent {
+        let mut event = ExecutionFeedbackEvent::new(
+            strategy_id,
+            "BTCUSDT",
+            ExecutionFeedback::PositionSnapshot(PositionSnapshot {
+                quantity: Decimal::ONE,
+                average_price: Decimal::ONE,
+            }),
+        );
+        event.timestamp = timestamp;
+        event
+    }
+
+    #[test]
+    fn test_replay_only_applies_feedback_after_checkpoint() {
+        let (restored, cold) = (Uuid::new_v4(), Uuid::new_v4());
+        let saved_at = Utc::now();
+        let window = ReplayWindow {
+            checkpoints: HashMap::from([(restored, saved_at)]),
+            until: HashMap::from([(0, 100)]),
+        };
+        let before = saved_at - ChronoDuration::seconds(1);
+        let after = saved_at + ChronoDuration::seconds(1);
+
+        // 重放区间：只应用检查点之后的回报，冷启动实例不重放
+        assert!(!window.accepts(0, 50, &event(restored, before)));
+        assert!(window.accepts(0, 50, &event(restored, after)));
+        assert!(!window.accepts(0, 50, &event(cold, after)));
+
+        // 已提交位点之后与未回退的分区照常应用
+        assert!(window.accepts(0, 100, &event(restored, before)));
+        assert!(window.accepts(0, 100, &event(cold, before)));
+        assert!(window.accepts(1, 0, &event(cold, before)));
+    }
+}
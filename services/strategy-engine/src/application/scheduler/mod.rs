@@ -1,9 +1,16 @@
 //! # 策略调度器模块 (Strategy Scheduler Module)
 //!
-//! 负责策略的加载、调度和执行。
+//! 负责策略的加载、调度和执行，执行回报的回送，状态检查点的保存与恢复，
+//! 以及故障实例的监督与自动重启。
 
+pub mod feedback_consumer;
+pub mod state_checkpointer;
 pub mod strategy_loader;
 pub mod strategy_scheduler;
+pub mod strategy_supervisor;
 
-pub use strategy_loader::{StrategyConfig, StrategyLoader};
-pub use strategy_scheduler::{SchedulerConfig, StrategyScheduler};
+pub use feedback_consumer::ExecutionFeedbackConsumer;
+pub use state_checkpointer::StateCheckpointer;
+pub use strategy_loader::{ParamUpdate, StrategyConfig, StrategyLoader};
+pub use strategy_scheduler::{SchedulerConfig, SignalEventConfig, StrategyScheduler};
+pub use strategy_supervisor::{StrategySupervisor, SupervisionReport};
@@ -0,0 +1,142 @@
+//! # 策略状态检查点 (State Checkpointer)
+//!
+//! 负责：
+//! 1. 启动时在消费行情之前，从 Redis 恢复各实例的检查点（热重启）
+//! 2. 运行中按固定间隔写入检查点
+//! 3. 关闭时写入最后一次检查点
+//!
+//! 单个实例恢复或保存失败只记录日志，不影响其它实例；恢复失败的实例冷启动。
+//! 恢复结果（各实例检查点的保存时间）交给执行回报消费者，重放检查点之后的回报。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::domain::model::strategy_handle::StrategyHandle;
+use crate::domain::port::StrategyStatePort;
+use crate::domain::service::strategy_registry::{StrategyQuery, StrategyRegistry};
+
+/// 策略状态检查点
+pub struct StateCheckpointer {
+    /// 策略注册表
+    registry: Arc<StrategyRegistry>,
+    /// 状态存储
+    store: Arc<dyn StrategyStatePort>,
+    /// 检查点间隔
+    interval: Duration,
+}
+
+impl StateCheckpointer {
+    /// 创建检查点
+    pub fn new(
+        registry: Arc<StrategyRegistry>,
+        store: Arc<dyn StrategyStatePort>,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            registry,
+            store,
+            interval,
+        }
+    }
+
+    /// 恢复全部已注册实例的状态，返回成功恢复的实例及其检查点保存时间
+    pub async fn restore_all(&self) -> HashMap<Uuid, DateTime<Utc>> {
+        let mut restored = HashMap::new();
+
+        for handle in self.registry.query(&StrategyQuery::all()) {
+            if let Some(saved_at) = self.restore_one(&handle).await {
+                restored.insert(handle.instance_id(), saved_at);
+            }
+        }
+
+        info!(restored = restored.len(), "Strategy checkpoints restored");
+        restored
+    }
+
+    /// 恢复单个实例，返回检查点保存时间
+    async fn restore_one(&self, handle: &StrategyHandle) -> Option<DateTime<Utc>> {
+        let instance_id = handle.instance_id();
+
+        let checkpoint = match self.store.load_checkpoint(&instance_id.to_string()).await {
+            Ok(Some(checkpoint)) => checkpoint,
+            Ok(None) => {
+                debug!(instance_id = %instance_id, "No checkpoint found, cold start");
+                return None;
+            }
+            Err(e) => {
+                warn!(instance_id = %instance_id, error = %e, "Failed to load checkpoint");
+                return None;
+            }
+        };
+
+        let schema_version = checkpoint.schema_version;
+        let saved_at = checkpoint.saved_at;
+        match handle.restore(checkpoint) {
+            Ok(()) => {
+                info!(
+                    instance_id = %instance_id,
+                    schema_version,
+                    saved_at = %saved_at,
+                    "Strategy state restored"
+                );
+                Some(saved_at)
+            }
+            Err(e) => {
+                warn!(
+                    instance_id = %instance_id,
+                    schema_version,
+                    error = %e,
+                    "Failed to restore checkpoint, cold start"
+                );
+                None
+            }
+        }
+    }
+
+    /// 保存全部已注册实例的检查点，返回写入的检查点数
+    pub async fn checkpoint_all(&self) -> usize {
+        let mut saved = 0;
+
+        for handle in self.registry.query(&StrategyQuery::all()) {
+            let instance_id = handle.instance_id();
+
+            let checkpoint = match handle.checkpoint() {
+                Ok(Some(checkpoint)) => checkpoint,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!(instance_id = %instance_id, error = %e, "Failed to export strategy state");
+                    continue;
+                }
+            };
+
+            match self.store.save_checkpoint(&checkpoint).await {
+                Ok(()) => saved += 1,
+                Err(e) => {
+                    warn!(instance_id = %instance_id, error = %e, "Failed to save checkpoint");
+                }
+            }
+        }
+
+        debug!(saved, "Strategy checkpoints saved");
+        saved
+    }
+
+    /// 按间隔循环写入检查点
+    pub async fn run(&self) {
+        info!(interval_secs = self.interval.as_secs(), "StateCheckpointer starting...");
+
+        let mut ticker = tokio::time::interval(self.interval);
+        // 第一个 tick 立即触发，跳过（刚恢复完无需立即保存）
+        ticker.tick().await;
+
+        loop {
+            ticker.tick().await;
+            self.checkpoint_all().await;
+        }
+    }
+}
@@ -2,27 +2,22 @@
 //!
 //! 负责：
 //! 1. 从配置文件或数据库加载策略配置
-//! 2. 创建策略实例
+//! 2. 通过 `StrategyFactory` 创建策略实例（参数解析与校验）
 //! 3. 注册到策略注册表
+//! 4. 参数热更新：以新参数重建执行器并替换到已注册的句柄
 
 use std::sync::Arc;
 
-use anyhow::{Context, Result};
-use rust_decimal::prelude::FromPrimitive;
-use rust_decimal::Decimal;
+use anyhow::{anyhow, bail, Result};
 use tracing::{info, warn};
 use uuid::Uuid;
 
-use crate::domain::logic::spot::{
-    SpotGridStrategy, SpotMeanReversionStrategy,
-};
-use crate::domain::logic::spot::grid::SpotGridConfig;
-use crate::domain::logic::spot::mean::SpotMeanReversionConfig;
+use crate::application::factory::StrategyFactory;
+use crate::domain::model::market_type::MarketType;
+use crate::domain::model::restart_policy::RestartPolicy;
 use crate::domain::model::strategy_handle::StrategyHandle;
-use crate::domain::model::strategy_metadata::StrategyMetadata;
-use crate::domain::port::strategy_executor_port::StrategyExecutorPort;
+use crate::domain::model::strategy_metadata::{self, StrategyKind, StrategyMetadata};
 use crate::domain::service::strategy_registry::StrategyRegistry;
-use crate::infrastructure::strategy::StrategyExecutorAdapter;
 
 /// 策略配置
 #[derive(Debug, Clone)]
@@ -31,6 +26,8 @@ pub struct StrategyConfig {
     pub instance_id: Uuid,
     /// 策略类型
     pub strategy_type: String,
+    /// 市场类型
+    pub market_type: MarketType,
     /// 交易对
     pub symbol: String,
     /// 用户ID
@@ -41,18 +38,53 @@ pub struct StrategyConfig {
     pub params: serde_json::Value,
     /// 是否自动启动
     pub auto_start: bool,
+    /// 故障后的自动重启策略
+    pub restart_policy: RestartPolicy,
 }
 
+/// 参数热更新结果
+#[derive(Debug, Clone)]
+pub struct ParamUpdate {
+    /// 更新后的元数据版本号
+    pub version: u32,
+    /// 生效的完整参数
+    pub params: serde_json::Value,
+    /// 观察期事件数（0 表示不观察）
+    pub probation_events: u32,
+}
+
+/// 默认参数更新观察期（事件数）
+const DEFAULT_PROBATION_EVENTS: u32 = 20;
+
 /// 策略加载器
 pub struct StrategyLoader {
     /// 策略注册表
     registry: Arc<StrategyRegistry>,
+    /// 策略工厂
+    factory: Arc<StrategyFactory>,
+    /// 参数更新观察期（事件数）
+    probation_events: u32,
 }
 
 impl StrategyLoader {
     /// 创建加载器
-    pub fn new(registry: Arc<StrategyRegistry>) -> Self {
-        Self { registry }
+    pub fn new(registry: Arc<StrategyRegistry>, factory: Arc<StrategyFactory>) -> Self {
+        Self {
+            registry,
+            factory,
+            probation_events: DEFAULT_PROBATION_EVENTS,
+        }
+    }
+
+    /// 设置参数更新观察期（事件数）
+    pub fn with_probation_events(mut self, events: u32) -> Self {
+        self.probation_events = events;
+        self
+    }
+
+    /// 策略工厂
+    pub fn factory(&self) -> &Arc<StrategyFactory> {
+        &self.factory
     }
 
     /// 从配置列表加载策略
@@ -85,22 +117,29 @@ impl StrategyLoader {
     }
 
     /// 加载单个策略
-    async fn load_strategy(&self, config: &StrategyConfig) -> Result<Uuid> {
+    ///
+    /// 参数不合法时返回包含具体参数名的错误。
+    pub async fn load_strategy(&self, config: &StrategyConfig) -> Result<Uuid> {
         // 创建策略实例
-        let executor = self.create_strategy_executor(config)?;
+        let executor = self.factory.create(config)?;
 
         // 创建元数据
         let metadata = StrategyMetadata::new(
-            crate::domain::model::strategy_metadata::StrategyKind::Custom(config.strategy_type.clone()),
-            crate::domain::model::strategy_metadata::MarketType::Spot,
+            StrategyKind::Custom(config.strategy_type.clone()),
+            metadata_market_type(config.market_type),
             &config.symbol,
             config.owner_id,
             config.name.clone(),
         )
-        .with_id(config.instance_id);
+        .with_id(config.instance_id)
+        .with_model_version(executor.model_version());
 
         // 创建策略句柄
-        let handle = Arc::new(StrategyHandle::new(metadata, executor));
+        let handle = Arc::new(
+            StrategyHandle::new(metadata, executor)
+                .with_params(config.params.clone())
+                .with_restart_policy(config.restart_policy.clone()),
+        );
 
         // 注册到注册表
         let instance_id = self.registry.register(handle.clone())?;
@@ -114,116 +153,62 @@ impl StrategyLoader {
         Ok(instance_id)
     }
 
-    /// 创建策略执行器
-    fn create_strategy_executor(
+    /// 热更新策略参数
+    ///
+    /// `replace` 为 false 时新参数按键合并到当前参数，否则整体替换。
+    /// 合并后的参数经工厂校验并创建新执行器，由句柄在两次事件之间替换。
+    pub fn update_params(
         &self,
-        config: &StrategyConfig,
-    ) -> Result<Arc<dyn StrategyExecutorPort>> {
-        match config.strategy_type.as_str() {
-            "spot_grid" => {
-                let params = self.parse_grid_config(&config.params)?;
-                let strategy = SpotGridStrategy::new(
-                    config.instance_id,
-                    config.symbol.clone(),
-                    params,
-                );
-                Ok(Arc::new(StrategyExecutorAdapter::new(strategy)))
-            }
-            "spot_mean_reversion" => {
-                let params = self.parse_mean_reversion_config(&config.params)?;
-                let strategy = SpotMeanReversionStrategy::new(
-                    config.instance_id,
-                    config.symbol.clone(),
-                    params,
-                );
-                Ok(Arc::new(StrategyExecutorAdapter::new(strategy)))
-            }
-            _ => Err(anyhow::anyhow!(
-                "Unsupported strategy type: {}",
-                config.strategy_type
-            )),
-        }
-    }
+        instance_id: Uuid,
+        params: serde_json::Value,
+        replace: bool,
+    ) -> Result<ParamUpdate> {
+        let handle = self
+            .registry
+            .get(instance_id)
+            .ok_or_else(|| anyhow!("策略实例 {} 不存在", instance_id))?;
+        let metadata = handle.metadata();
+        let StrategyKind::Custom(strategy_type) = metadata.kind else {
+            bail!("策略实例 {} 不是由策略工厂创建，不支持参数热更新", instance_id);
+        };
 
-    /// 解析网格策略配置
-    fn parse_grid_config(&self, params: &serde_json::Value) -> Result<SpotGridConfig> {
-        let upper_price = params
-            .get("upper_price")
-            .and_then(|v| v.as_str())
-            .and_then(|s| s.parse::<Decimal>().ok())
-            .context("Missing or invalid upper_price")?;
-
-        let lower_price = params
-            .get("lower_price")
-            .and_then(|v| v.as_str())
-            .and_then(|s| s.parse::<Decimal>().ok())
-            .context("Missing or invalid lower_price")?;
-
-        let grid_count = params
-            .get("grid_count")
-            .and_then(|v| v.as_u64())
-            .map(|v| v as u32)
-            .context("Missing or invalid grid_count")?;
-
-        let quantity_per_grid = params
-            .get("quantity_per_grid")
-            .and_then(|v| v.as_str())
-            .and_then(|s| s.parse::<Decimal>().ok())
-            .context("Missing or invalid quantity_per_grid")?;
-
-        Ok(SpotGridConfig {
-            upper_price,
-            lower_price,
-            grid_count,
-            quantity_per_grid,
-        })
-    }
+        let params = if replace {
+            params
+        } else {
+            merge_params(handle.params(), params)
+        };
 
-    /// 解析均值回归策略配置
-    fn parse_mean_reversion_config(
-        &self,
-        params: &serde_json::Value,
-    ) -> Result<SpotMeanReversionConfig> {
-        let window_size = params
-            .get("window_size")
-            .or_else(|| params.get("period"))
-            .and_then(|v| v.as_u64())
-            .map(|v| v as usize)
-            .unwrap_or(20);
-
-        let threshold_percent = params
-            .get("threshold_percent")
-            .or_else(|| params.get("std_dev_multiplier"))
-            .and_then(|v| {
-                v.as_str()
-                    .and_then(|s| s.parse::<Decimal>().ok())
-                    .or_else(|| v.as_f64().and_then(Decimal::from_f64))
-            })
-            .unwrap_or(Decimal::new(2, 2));
-
-        let quantity = params
-            .get("quantity")
-            .and_then(|v| {
-                v.as_str()
-                    .and_then(|s| s.parse::<Decimal>().ok())
-                    .or_else(|| v.as_f64().and_then(Decimal::from_f64))
-            })
-            .unwrap_or(Decimal::new(1, 3));
-
-        Ok(SpotMeanReversionConfig {
-            window_size,
-            threshold_percent,
-            quantity,
+        let config = StrategyConfig {
+            instance_id,
+            strategy_type,
+            market_type: config_market_type(metadata.market_type),
+            symbol: metadata.symbol,
+            owner_id: metadata.owner_id,
+            name: metadata.name,
+            params: params.clone(),
+            auto_start: false,
+            restart_policy: handle.restart_policy().clone(),
+        };
+        let executor = self.factory.create(&config)?;
+        let version = handle.update_params(params.clone(), executor, self.probation_events)?;
+
+        Ok(ParamUpdate {
+            version,
+            params,
+            probation_events: self.probation_events,
         })
     }
 
     /// 从环境变量加载示例策略
+    ///
+    /// 实例 ID 固定，重启后可按 ID 恢复状态检查点。
     pub fn load_example_strategies() -> Vec<StrategyConfig> {
         vec![
             // 示例：BTC网格策略
             StrategyConfig {
-                instance_id: Uuid::new_v4(),
+                instance_id: Uuid::from_u128(0x0000_0001_0000_4000_8000_0000_0000_0001),
                 strategy_type: "spot_grid".to_string(),
+                market_type: MarketType::Spot,
                 symbol: "BTCUSDT".to_string(),
                 owner_id: Uuid::new_v4(),
                 name: "BTC Grid Strategy".to_string(),
@@ -234,11 +219,13 @@ impl StrategyLoader {
                     "quantity_per_grid": "0.001"
                 }),
                 auto_start: true,
+                restart_policy: RestartPolicy::default(),
             },
             // 示例：ETH均值回归策略
             StrategyConfig {
-                instance_id: Uuid::new_v4(),
+                instance_id: Uuid::from_u128(0x0000_0001_0000_4000_8000_0000_0000_0002),
                 strategy_type: "spot_mean_reversion".to_string(),
+                market_type: MarketType::Spot,
                 symbol: "ETHUSDT".to_string(),
                 owner_id: Uuid::new_v4(),
                 name: "ETH Mean Reversion Strategy".to_string(),
@@ -248,11 +235,41 @@ impl StrategyLoader {
                     "quantity": "0.01"
                 }),
                 auto_start: true,
+                restart_policy: RestartPolicy::default(),
             },
         ]
     }
 }
 
+/// 策略市场类型 → 元数据市场类型
+fn metadata_market_type(market_type: MarketType) -> strategy_metadata::MarketType {
+    match market_type {
+        MarketType::Spot => strategy_metadata::MarketType::Spot,
+        MarketType::UsdtFutures => strategy_metadata::MarketType::UsdtFutures,
+        MarketType::CoinFutures => strategy_metadata::MarketType::CoinFutures,
+    }
+}
+
+/// 元数据市场类型 → 策略市场类型
+fn config_market_type(market_type: strategy_metadata::MarketType) -> MarketType {
+    match market_type {
+        strategy_metadata::MarketType::Spot => MarketType::Spot,
+        strategy_metadata::MarketType::UsdtFutures => MarketType::UsdtFutures,
+        strategy_metadata::MarketType::CoinFutures => MarketType::CoinFutures,
+    }
+}
+
+/// 按键合并参数：两者都是对象时逐键覆盖，否则以新参数为准
+fn merge_params(current: serde_json::Value, patch: serde_json::Value) -> serde_json::Value {
+    match (current, patch) {
+        (serde_json::Value::Object(mut current), serde_json::Value::Object(patch)) => {
+            current.extend(patch);
+            serde_json::Value::Object(current)
+        }
+        (_, patch) => patch,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -264,4 +281,61 @@ mod tests {
         assert_eq!(configs[0].strategy_type, "spot_grid");
         assert_eq!(configs[1].strategy_type, "spot_mean_reversion");
     }
+
+    /// 参数热更新后的均线窗口：周期取新参数，已累积的价格回放到新窗口
+    #[tokio::test]
+    async fn test_update_window_size_rebuilds_indicator_on_live_instance() {
+        use crate::domain::model::strategy_runtime::ExecutionRequest;
+        use rust_decimal::Decimal;
+
+        let registry = Arc::new(StrategyRegistry::new());
+        let factory = Arc::new(StrategyFactory::with_builtin());
+        let loader = StrategyLoader::new(registry.clone(), factory);
+        let mut config = StrategyLoader::load_example_strategies().remove(1);
+        config.params = serde_json::json!({ "window_size": 20 });
+        let instance_id = loader.load_strategy(&config).await.unwrap();
+        let handle = registry.get(instance_id).unwrap();
+
+        let feed = |count: i64| {
+            for i in 0..count {
+                let price = Decimal::new(3000 + i, 0);
+                handle.execute(&ExecutionRequest::new("ETHUSDT", price, Decimal::ONE)).unwrap();
+            }
+        };
+        let sma_window = || {
+            let checkpoint = handle.checkpoint().unwrap().unwrap();
+            let window = checkpoint.state["sma"]["window"].clone();
+            (window["capacity"].as_u64().unwrap(), window["values"].as_array().unwrap().len())
+        };
+
+        feed(25);
+        assert_eq!(sma_window(), (20, 20));
+
+        let update = loader
+            .update_params(instance_id, serde_json::json!({ "window_size": 30 }), false)
+            .unwrap();
+        assert_eq!(update.version, 2);
+        assert_eq!(handle.params()["window_size"], 30);
+        assert_eq!(sma_window(), (30, 20));
+
+        feed(15);
+        assert_eq!(sma_window(), (30, 30));
+
+        loader
+            .update_params(instance_id, serde_json::json!({ "window_size": 10 }), false)
+            .unwrap();
+        assert_eq!(sma_window(), (10, 10));
+    }
+
+    #[test]
+    fn test_example_strategies_pass_validation() {
+        let factory = StrategyFactory::with_builtin();
+        for config in StrategyLoader::load_example_strategies() {
+            assert!(
+                factory.create(&config).is_ok(),
+                "{} rejected",
+                config.strategy_type
+            );
+        }
+    }
 }
@@ -1,24 +1,45 @@
 //! # 策略调度器 (Strategy Scheduler)
 //!
 //! 负责：
-//! 1. 从Kafka消费行情数据
-//! 2. 路由到对应的策略实例
-//! 3. 执行策略计算
+//! 1. 从Kafka消费行情数据（成交与深度）
+//! 2. 按交易对路由到订阅了该交易对的策略实例（一个实例可订阅多个交易对）
+//! 3. 执行策略计算（先以行情时间触发到期的定时器，再处理行情）
 //! 4. 聚合信号
-//! 5. 发布信号到Kafka
+//! 5. 发布信号到Kafka（多腿意图组发布到独立主题，整组一条消息）
+//! 6. 发布做市报价指令到报价主题（按交易对分区，保证同一交易对的撤挂顺序）
+//!
+//! 报价执行不在本服务范围内：trading-engine 目前没有报价主题的消费者，
+//! 报价指令发布后不会下单。按指令撤挂单需要单独的执行端消费者（PostOnly 限价单、
+//! 按实例与交易对跟踪挂单、风控），接入前做市策略只产出指令，可用于回放与研究。
+//!
+//! 组合策略的成员意图不单独发布，交给组合策略综合为一个意图。
+//! 启用信号事件时，意图同时以版本化 `SignalEvent` 发布到信号事件主题（trading-engine 异步消费），
+//! 发布前按信号 ID 去重并丢弃已超过最大时延的信号。
+//! 启用组合资金分配时，同一行情批次的结果先经 `PortfolioAllocator` 缩放与对冲再发布。
+//! 内部对冲的部分作为成交回报直接交给双方策略，不等待 trading-engine。
 
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Context, Result};
+use chrono::Utc;
+use parking_lot::Mutex;
 use rdkafka::consumer::{Consumer, StreamConsumer};
 use rdkafka::producer::{FutureProducer, FutureRecord};
 use rdkafka::{ClientConfig, Message};
+use rust_decimal::Decimal;
+use shared::event::execution_feedback_event::ExecutionFeedback;
+use shared::event::signal_event::{SignalEvent, SignalGate};
 use tokio::time::sleep;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
-use crate::domain::model::strategy_runtime::{ExecutionRequest, ExecutionResult};
+use crate::application::portfolio::{PortfolioAllocator, StrategyOutput};
+use crate::domain::model::quote::QuoteInstruction;
+use crate::domain::model::strategy_handle::StrategyHandle;
+use crate::domain::model::strategy_runtime::{
+    ExecutionRequest, ExecutionResult, TradeIntent, TradeIntentGroup,
+};
 use crate::domain::service::strategy_registry::StrategyRegistry;
 
 /// 调度器配置
@@ -30,8 +51,35 @@ pub struct SchedulerConfig {
     pub market_topic: String,
     /// 信号主题
     pub signal_topic: String,
+    /// 多腿意图组主题
+    pub signal_group_topic: String,
+    /// 做市报价主题
+    pub quote_topic: String,
     /// 消费者组ID
     pub consumer_group: String,
+    /// 版本化信号事件（None 表示不发布）
+    pub signal_events: Option<SignalEventConfig>,
+}
+
+/// 信号事件发布配置
+#[derive(Debug, Clone)]
+pub struct SignalEventConfig {
+    /// 信号事件主题
+    pub topic: String,
+    /// 最大时延（毫秒，信号创建到发布，0 不限）
+    pub max_age_ms: u64,
+    /// 去重窗口（记住的最近信号 ID 数）
+    pub dedupe_window: usize,
+}
+
+impl Default for SignalEventConfig {
+    fn default() -> Self {
+        Self {
+            topic: "trading.signal-events".to_string(),
+            max_age_ms: 5_000,
+            dedupe_window: 10_000,
+        }
+    }
 }
 
 impl Default for SchedulerConfig {
@@ -40,7 +88,10 @@ impl Default for SchedulerConfig {
             kafka_brokers: "localhost:9092".to_string(),
             market_topic: "market-events".to_string(),
             signal_topic: "strategy-signals".to_string(),
+            signal_group_topic: "strategy-signal-groups".to_string(),
+            quote_topic: "strategy-quotes".to_string(),
             consumer_group: "strategy-scheduler".to_string(),
+            signal_events: None,
         }
     }
 }
@@ -55,6 +106,10 @@ pub struct StrategyScheduler {
     producer: FutureProducer,
     /// 配置
     config: SchedulerConfig,
+    /// 组合资金分配器（未启用时按策略原始数量发布）
+    portfolio: Option<Arc<PortfolioAllocator>>,
+    /// 信号事件去重与过期闸门（仅启用信号事件时使用）
+    signal_gate: Mutex<SignalGate>,
 }
 
 impl StrategyScheduler {
@@ -82,18 +137,42 @@ impl StrategyScheduler {
             .context("Failed to create Kafka producer")?;
 
         info!(
-            "StrategyScheduler created: market_topic={}, signal_topic={}",
-            config.market_topic, config.signal_topic
+            "StrategyScheduler created: market_topic={}, signal_topic={}, signal_group_topic={}, quote_topic={}",
+            config.market_topic, config.signal_topic, config.signal_group_topic, config.quote_topic
         );
 
+        let signal_gate = match &config.signal_events {
+            Some(events) => {
+                info!(
+                    topic = %events.topic,
+                    max_age_ms = events.max_age_ms,
+                    dedupe_window = events.dedupe_window,
+                    "Signal events enabled"
+                );
+                SignalGate::new(
+                    chrono::Duration::milliseconds(events.max_age_ms as i64),
+                    events.dedupe_window,
+                )
+            }
+            None => SignalGate::new(chrono::Duration::zero(), 1),
+        };
+
         Ok(Self {
             registry,
             consumer,
             producer,
             config,
+            portfolio: None,
+            signal_gate: Mutex::new(signal_gate),
         })
     }
 
+    /// 启用组合资金分配
+    pub fn with_portfolio(mut self, portfolio: Arc<PortfolioAllocator>) -> Self {
+        self.portfolio = Some(portfolio);
+        self
+    }
+
     /// 运行调度器
     pub async fn run(&self) -> Result<()> {
         info!("StrategyScheduler starting...");
@@ -133,22 +212,37 @@ impl StrategyScheduler {
         // 转换为执行请求
         let request = self.market_event_to_request(&market_event)?;
 
-        // 获取所有运行中的策略
-        let running_strategies = self.registry.running_instances();
+        // 获取订阅了该交易对的运行中策略
+        let running_strategies = self.registry.running_for_symbol(&market_event.symbol);
 
         if running_strategies.is_empty() {
-            debug!("No running strategies, skipping");
+            debug!(symbol = %market_event.symbol, "No running strategies subscribed, skipping");
             return Ok(());
         }
 
+        if let Some(portfolio) = &self.portfolio {
+            portfolio.observe_price(&request.symbol, request.price);
+        }
+
         // 执行所有策略
-        let mut results = Vec::new();
+        let mut strategy_ids: Vec<Uuid> = running_strategies.iter().map(|h| h.instance_id()).collect();
+        let mut outputs = Vec::new();
         for handle in running_strategies {
             let instance_id = handle.instance_id();
 
-            // 检查策略是否订阅了这个交易对
-            if handle.metadata().symbol != market_event.symbol {
-                continue;
+            // 定时器先于同一时刻的行情触发，结果与行情结果一同综合、分配与发布
+            match self.registry.fire_timers(instance_id, request.timestamp) {
+                Ok(results) => outputs.extend(results.into_iter().map(|result| StrategyOutput {
+                    market_type: handle.metadata().market_type,
+                    result,
+                })),
+                Err(e) => {
+                    warn!(
+                        instance_id = %instance_id,
+                        error = %e,
+                        "Strategy timer failed"
+                    );
+                }
             }
 
             match self.registry.execute(instance_id, &request) {
@@ -159,7 +253,10 @@ impl StrategyScheduler {
                             has_intent = result.has_intent,
                             "Strategy executed"
                         );
-                        results.push(result);
+                        outputs.push(StrategyOutput {
+                            market_type: handle.metadata().market_type,
+                            result,
+                        });
                     }
                 }
                 Err(e) => {
@@ -172,9 +269,37 @@ impl StrategyScheduler {
             }
         }
 
+        // 成员意图交给组合策略综合（成员不单独下单，也不分配预算）
+        let ensembles = self.registry.ensembles_for_symbol(&request.symbol);
+        let outputs = self.combine_ensembles(&request, &ensembles, outputs);
+        strategy_ids.retain(|id| !ensembles.iter().any(|(_, members)| members.contains(id)));
+
+        // 组合层缩放与对冲
+        let results: Vec<ExecutionResult> = match &self.portfolio {
+            Some(portfolio) => portfolio.allocate(&strategy_ids, request.price, outputs),
+            None => outputs.into_iter().map(|output| output.result).collect(),
+        };
+
+        // 内部对冲成交直接回报给策略（组合账簿已在分配时记录）
+        for intent in results.iter().filter_map(|result| result.intent.as_ref()) {
+            if let Some(fill) = intent.internal_fill() {
+                let feedback = ExecutionFeedback::Fill(fill);
+                if let Err(e) =
+                    self.registry
+                        .dispatch_feedback(intent.strategy_id, &intent.symbol, &feedback)
+                {
+                    warn!(
+                        strategy_id = %intent.strategy_id,
+                        error = %e,
+                        "Failed to dispatch internal fill"
+                    );
+                }
+            }
+        }
+
         // 发布信号
         for result in results {
-            if let Err(e) = self.publish_signal(&result).await {
+            if let Err(e) = self.publish_signal(&request, &result).await {
                 error!(error = %e, "Failed to publish signal");
             }
         }
@@ -182,6 +307,72 @@ impl StrategyScheduler {
         Ok(())
     }
 
+    /// 组合策略：用成员意图换取综合意图
+    ///
+    /// 成员的单腿意图一律不单独发布（组合策略暂停时同样丢弃）；
+    /// 多腿意图组与做市报价照常发布。
+    fn combine_ensembles(
+        &self,
+        request: &ExecutionRequest,
+        ensembles: &[(Arc<StrategyHandle>, Vec<Uuid>)],
+        mut outputs: Vec<StrategyOutput>,
+    ) -> Vec<StrategyOutput> {
+        if ensembles.is_empty() {
+            return outputs;
+        }
+
+        let mut combined = Vec::new();
+        for (handle, members) in ensembles {
+            let member_intents: Vec<TradeIntent> = outputs
+                .iter()
+                .filter_map(|output| output.result.intent.as_ref())
+                .filter(|intent| members.contains(&intent.strategy_id))
+                .cloned()
+                .collect();
+            if member_intents.is_empty() || !handle.can_execute() {
+                continue;
+            }
+
+            let instance_id = handle.instance_id();
+            match self.registry.execute_ensemble(instance_id, request, &member_intents) {
+                Ok(result) if result.has_intent => {
+                    debug!(
+                        instance_id = %instance_id,
+                        members = member_intents.len(),
+                        "Ensemble combined member intents"
+                    );
+                    combined.push(StrategyOutput {
+                        market_type: handle.metadata().market_type,
+                        result,
+                    });
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    warn!(
+                        instance_id = %instance_id,
+                        error = %e,
+                        "Ensemble execution failed"
+                    );
+                }
+            }
+        }
+
+        for output in &mut outputs {
+            let is_member = output.result.intent.as_ref().is_some_and(|intent| {
+                ensembles
+                    .iter()
+                    .any(|(_, members)| members.contains(&intent.strategy_id))
+            });
+            if is_member {
+                output.result.intent = None;
+                output.result.has_intent = output.result.group.is_some() || output.result.quotes.is_some();
+            }
+        }
+        outputs.retain(|output| output.result.has_intent);
+        outputs.extend(combined);
+        outputs
+    }
+
     /// 将行情事件转换为执行请求
     fn market_event_to_request(
         &self,
@@ -193,6 +384,10 @@ impl StrategyScheduler {
             MarketEventData::Trade(trade) => {
                 (trade.price, trade.quantity, trade.is_buyer_maker)
             }
+            MarketEventData::Depth(depth) => {
+                return ExecutionRequest::from_depth(&event.symbol, depth.clone(), event.timestamp)
+                    .context("Depth event has an empty side");
+            }
             _ => {
                 return Err(anyhow::anyhow!("Unsupported market event type"));
             }
@@ -205,11 +400,23 @@ impl StrategyScheduler {
             quantity,
             timestamp: event.timestamp,
             is_buyer_maker,
+            depth: None,
         })
     }
 
     /// 发布信号到Kafka
-    async fn publish_signal(&self, result: &ExecutionResult) -> Result<()> {
+    async fn publish_signal(&self, request: &ExecutionRequest, result: &ExecutionResult) -> Result<()> {
+        if let Some(ref group) = result.group {
+            self.publish_signal_group(group).await?;
+            if let Some(event) = group.to_signal_event(request.price) {
+                self.publish_signal_event(event).await?;
+            }
+        }
+
+        if let Some(ref quotes) = result.quotes {
+            self.publish_quotes(quotes).await?;
+        }
+
         if let Some(ref intent) = result.intent {
             let signal_json = serde_json::to_string(intent)
                 .context("Failed to serialize signal")?;
@@ -230,8 +437,104 @@ impl StrategyScheduler {
                 side = ?intent.side,
                 "Signal published"
             );
+
+            // 全部内部对冲的意图无需交易所下单
+            if intent.exchange_quantity() > Decimal::ZERO {
+                self.publish_signal_event(intent.to_signal_event(request.price)).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 发布版本化信号事件（未启用时忽略；重复或已过期的信号直接丢弃）
+    async fn publish_signal_event(&self, event: SignalEvent) -> Result<()> {
+        let Some(events) = &self.config.signal_events else {
+            return Ok(());
+        };
+
+        if let Err(reason) = self.signal_gate.lock().admit(&event, Utc::now()) {
+            warn!(
+                signal_id = %event.id,
+                strategy_id = %event.strategy_id,
+                symbol = %event.symbol,
+                reason = %reason,
+                "Signal event dropped"
+            );
+            return Ok(());
         }
 
+        let event_json = serde_json::to_string(&event)
+            .context("Failed to serialize signal event")?;
+
+        let record = FutureRecord::to(&events.topic)
+            .payload(&event_json)
+            .key(&event.symbol);
+
+        self.producer
+            .send(record, Duration::from_secs(0))
+            .await
+            .map_err(|(e, _)| anyhow::anyhow!("Failed to send signal event: {}", e))?;
+
+        debug!(
+            signal_id = %event.id,
+            strategy_id = %event.strategy_id,
+            symbol = %event.symbol,
+            schema_version = event.schema_version,
+            leg_count = event.legs.len(),
+            "Signal event published"
+        );
+
+        Ok(())
+    }
+
+    /// 发布多腿意图组（整组一条消息，保证下游拿到完整的腿）
+    async fn publish_signal_group(&self, group: &TradeIntentGroup) -> Result<()> {
+        let group_json = serde_json::to_string(group)
+            .context("Failed to serialize signal group")?;
+        let key = group.strategy_id.to_string();
+
+        let record = FutureRecord::to(&self.config.signal_group_topic)
+            .payload(&group_json)
+            .key(&key);
+
+        self.producer
+            .send(record, Duration::from_secs(0))
+            .await
+            .map_err(|(e, _)| anyhow::anyhow!("Failed to send signal group: {}", e))?;
+
+        info!(
+            group_id = %group.id,
+            strategy_id = %group.strategy_id,
+            leg_count = group.legs.len(),
+            "Signal group published"
+        );
+
+        Ok(())
+    }
+
+    /// 发布做市报价指令
+    async fn publish_quotes(&self, quotes: &QuoteInstruction) -> Result<()> {
+        let quotes_json = serde_json::to_string(quotes)
+            .context("Failed to serialize quote instruction")?;
+
+        let record = FutureRecord::to(&self.config.quote_topic)
+            .payload(&quotes_json)
+            .key(&quotes.symbol);
+
+        self.producer
+            .send(record, Duration::from_secs(0))
+            .await
+            .map_err(|(e, _)| anyhow::anyhow!("Failed to send quote instruction: {}", e))?;
+
+        debug!(
+            quote_id = %quotes.id,
+            strategy_id = %quotes.strategy_id,
+            symbol = %quotes.symbol,
+            action = ?quotes.action,
+            "Quote instruction published"
+        );
+
         Ok(())
     }
 }
@@ -246,5 +549,7 @@ mod tests {
         assert_eq!(config.kafka_brokers, "localhost:9092");
         assert_eq!(config.market_topic, "market-events");
         assert_eq!(config.signal_topic, "strategy-signals");
+        assert_eq!(config.signal_group_topic, "strategy-signal-groups");
+        assert_eq!(config.quote_topic, "strategy-quotes");
     }
 }
@@ -0,0 +1,303 @@
+//! # 策略监督器 (Strategy Supervisor)
+//!
+//! 负责：
+//! 1. 定时巡检故障（Faulted）实例
+//! 2. 按实例的重启策略（`RestartPolicy`）在退避时间到达后自动重启
+//! 3. 策略为 `never` 或窗口内重启次数用尽时放弃，发出永久故障通知
+//!
+//! 执行器 panic 已由 `StrategyHandle` 隔离并转为故障状态，监督器只处理状态，
+//! 不直接调用执行器。
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::domain::model::lifecycle_state::LifecycleState;
+use crate::domain::model::strategy_handle::StrategyHandle;
+use crate::domain::port::fault_notifier_port::{FaultNotification, FaultNotifierPort};
+use crate::domain::service::strategy_registry::{StrategyQuery, StrategyRegistry};
+
+/// 单个实例的监督记录
+#[derive(Debug, Default)]
+struct SupervisionRecord {
+    /// 统计窗口内的重启时间
+    restarts: VecDeque<DateTime<Utc>>,
+    /// 是否已放弃（永久故障）
+    gave_up: bool,
+}
+
+/// 一次巡检的结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SupervisionReport {
+    /// 本次重启的实例
+    pub restarted: Vec<Uuid>,
+    /// 本次判定为永久故障的实例
+    pub gave_up: Vec<Uuid>,
+}
+
+/// 策略监督器
+pub struct StrategySupervisor {
+    /// 策略注册表
+    registry: Arc<StrategyRegistry>,
+    /// 永久故障通知
+    notifier: Arc<dyn FaultNotifierPort>,
+    /// 巡检间隔
+    interval: Duration,
+    /// 各实例的监督记录
+    records: Mutex<HashMap<Uuid, SupervisionRecord>>,
+}
+
+impl StrategySupervisor {
+    /// 创建监督器
+    pub fn new(
+        registry: Arc<StrategyRegistry>,
+        notifier: Arc<dyn FaultNotifierPort>,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            registry,
+            notifier,
+            interval,
+            records: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 按固定间隔巡检
+    pub async fn run(&self) {
+        info!(interval_ms = self.interval.as_millis() as u64, "Strategy supervisor started");
+
+        let mut ticker = tokio::time::interval(self.interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            ticker.tick().await;
+            self.supervise(Utc::now());
+        }
+    }
+
+    /// 巡检一次
+    pub fn supervise(&self, now: DateTime<Utc>) -> SupervisionReport {
+        let mut report = SupervisionReport::default();
+        let mut records = self.records.lock();
+
+        // 已注销的实例不再跟踪
+        records.retain(|id, _| self.registry.contains(*id));
+
+        for handle in self.registry.query(&StrategyQuery::all()) {
+            let instance_id = handle.instance_id();
+
+            if handle.lifecycle_state() != LifecycleState::Faulted {
+                // 手动恢复后重新纳入监督
+                if let Some(record) = records.get_mut(&instance_id) {
+                    record.gave_up = false;
+                }
+                continue;
+            }
+
+            let record = records.entry(instance_id).or_default();
+            if record.gave_up {
+                continue;
+            }
+
+            let policy = handle.restart_policy();
+            let window_start = now - policy.window();
+            while record.restarts.front().is_some_and(|t| *t <= window_start) {
+                record.restarts.pop_front();
+            }
+            let restarts = record.restarts.len() as u32;
+
+            if !policy.allows_restart(restarts) {
+                record.gave_up = true;
+                let reason = match policy.backoff(restarts) {
+                    None => "重启策略为 never".to_string(),
+                    Some(_) => format!("统计窗口内已重启 {} 次，达到上限", restarts),
+                };
+                self.give_up(&handle, &reason, restarts, now);
+                report.gave_up.push(instance_id);
+                continue;
+            }
+
+            // 退避时间从进入故障状态起算
+            let faulted_at = handle.faulted_at().unwrap_or(now);
+            let backoff = policy.backoff(restarts).unwrap_or_default();
+            if now < faulted_at + backoff {
+                continue;
+            }
+
+            match handle.restart() {
+                Ok(()) => {
+                    record.restarts.push_back(now);
+                    info!(
+                        instance_id = %instance_id,
+                        attempt = restarts + 1,
+                        backoff_ms = backoff.num_milliseconds(),
+                        "Faulted strategy restarted by supervisor"
+                    );
+                    report.restarted.push(instance_id);
+                }
+                Err(e) => {
+                    warn!(instance_id = %instance_id, error = %e, "Supervisor restart failed");
+                }
+            }
+        }
+
+        report
+    }
+
+    /// 放弃重启并发出永久故障通知
+    fn give_up(&self, handle: &StrategyHandle, reason: &str, restarts: u32, now: DateTime<Utc>) {
+        let faulted_at = handle.faulted_at();
+        handle.mark_faulted(format!("永久故障: {}", reason));
+
+        let metadata = handle.metadata();
+        let notification = FaultNotification {
+            instance_id: metadata.instance_id,
+            owner_id: metadata.owner_id,
+            name: metadata.name,
+            strategy_type: metadata.kind.to_string(),
+            reason: reason.to_string(),
+            restarts_in_window: restarts,
+            last_failure: handle.failure_history().last().cloned(),
+            faulted_at,
+            notified_at: now,
+        };
+
+        if !self.notifier.notify(&notification) {
+            warn!(instance_id = %metadata.instance_id, "Failed to deliver fault notification");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::{bail, Result};
+    use chrono::Duration as ChronoDuration;
+
+    use crate::domain::model::restart_policy::RestartPolicy;
+    use crate::domain::model::strategy_metadata::{MarketType, StrategyKind, StrategyMetadata};
+    use crate::domain::model::strategy_runtime::{ExecutionRequest, ExecutionResult};
+    use crate::domain::port::strategy_executor_port::StrategyExecutorPort;
+
+    /// 测试用的总是失败的执行器
+    struct FailingExecutor;
+
+    impl StrategyExecutorPort for FailingExecutor {
+        fn execute(&self, _request: &ExecutionRequest) -> Result<ExecutionResult> {
+            bail!("下游不可用")
+        }
+
+        fn reset(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn state_snapshot(&self) -> Result<serde_json::Value> {
+            Ok(serde_json::json!({}))
+        }
+    }
+
+    /// 测试用的记录通知
+    #[derive(Default)]
+    struct RecordingNotifier {
+        notifications: Mutex<Vec<FaultNotification>>,
+    }
+
+    impl FaultNotifierPort for RecordingNotifier {
+        fn notify(&self, notification: &FaultNotification) -> bool {
+            self.notifications.lock().push(notification.clone());
+            true
+        }
+    }
+
+    fn setup(policy: RestartPolicy) -> (StrategySupervisor, Arc<StrategyHandle>, Arc<RecordingNotifier>) {
+        let registry = Arc::new(StrategyRegistry::new());
+        let metadata =
+            StrategyMetadata::new(StrategyKind::Grid, MarketType::Spot, "BTCUSDT", Uuid::new_v4(), "监督测试");
+        let handle = Arc::new(
+            StrategyHandle::new(metadata, Arc::new(FailingExecutor)).with_restart_policy(policy),
+        );
+        registry.register(Arc::clone(&handle)).unwrap();
+        handle.start().unwrap();
+
+        let notifier = Arc::new(RecordingNotifier::default());
+        let supervisor = StrategySupervisor::new(
+            registry,
+            Arc::clone(&notifier) as Arc<dyn FaultNotifierPort>,
+            Duration::from_secs(1),
+        );
+        (supervisor, handle, notifier)
+    }
+
+    fn fault(handle: &StrategyHandle) {
+        let request = ExecutionRequest::new("BTCUSDT", rust_decimal::Decimal::ONE, rust_decimal::Decimal::ONE);
+        while handle.lifecycle_state() == LifecycleState::Running {
+            let _ = handle.execute(&request);
+        }
+    }
+
+    #[test]
+    fn test_on_failure_restarts_after_backoff_until_limit() {
+        let policy = RestartPolicy::OnFailure {
+            initial_backoff_ms: 1_000,
+            max_backoff_ms: 10_000,
+            multiplier: 2.0,
+            max_restarts: Some(2),
+            window_secs: 600,
+        };
+        let (supervisor, handle, notifier) = setup(policy);
+        let id = handle.instance_id();
+
+        fault(&handle);
+        let faulted_at = handle.faulted_at().unwrap();
+
+        // 退避未到
+        assert_eq!(supervisor.supervise(faulted_at), SupervisionReport::default());
+        // 第 1 次重启：退避 1s
+        let now = faulted_at + ChronoDuration::seconds(1);
+        assert_eq!(supervisor.supervise(now).restarted, vec![id]);
+        assert_eq!(handle.lifecycle_state(), LifecycleState::Running);
+
+        // 第 2 次重启：退避翻倍为 2s（从再次故障时起算）
+        fault(&handle);
+        let faulted_at = handle.faulted_at().unwrap();
+        assert!(supervisor.supervise(faulted_at + ChronoDuration::seconds(1)).restarted.is_empty());
+        assert_eq!(
+            supervisor.supervise(faulted_at + ChronoDuration::seconds(2)).restarted,
+            vec![id]
+        );
+
+        // 窗口内已重启 2 次：放弃并通知一次
+        fault(&handle);
+        let now = handle.faulted_at().unwrap() + ChronoDuration::seconds(60);
+        assert_eq!(supervisor.supervise(now).gave_up, vec![id]);
+        assert_eq!(supervisor.supervise(now), SupervisionReport::default());
+        assert_eq!(handle.lifecycle_state(), LifecycleState::Faulted);
+
+        let notifications = notifier.notifications.lock();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].instance_id, id);
+        assert_eq!(notifications[0].restarts_in_window, 2);
+        assert!(notifications[0].last_failure.is_some());
+    }
+
+    #[test]
+    fn test_never_policy_gives_up_immediately() {
+        let (supervisor, handle, notifier) = setup(RestartPolicy::Never);
+        fault(&handle);
+
+        let report = supervisor.supervise(Utc::now());
+        assert_eq!(report.gave_up, vec![handle.instance_id()]);
+        assert_eq!(notifier.notifications.lock().len(), 1);
+
+        // 手动恢复后重新纳入监督
+        handle.restart().unwrap();
+        supervisor.supervise(Utc::now());
+        fault(&handle);
+        assert_eq!(supervisor.supervise(Utc::now()).gave_up.len(), 1);
+        assert_eq!(notifier.notifications.lock().len(), 2);
+    }
+}
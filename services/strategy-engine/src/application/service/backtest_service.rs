@@ -1,24 +1,403 @@
 //! # 回测服务 (Backtest Service)
-//! 
-//! 策略回测的用例编排。
+//!
+//! 策略回测的用例编排：通过 `StrategyFactory` 创建策略，逐笔回放价格序列，
+//! 按信号价格模拟成交并计算绩效指标。
+//!
+//! ## 规则
+//! - ✅ 与实盘走同一条构造路径（参数解析 / 校验与 `StrategyLoader` 一致）
+//! - ✅ 模拟成交以成交回报形式投递回策略，保持策略内部持仓一致
+//! - ✅ 预热区间（warmup）内的信号直接丢弃，只用于指标预热
+//! - ✅ 定时器按模拟时间触发（每笔行情前检查，与实盘一致），成交价取当前价格
+//! - ❌ 不模拟滑点与部分成交；市价单按当前价成交，限价单按信号价成交
 
-use anyhow::Result;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::{ExecutionFeedback, StrategyFill};
+use shared::types::order::OrderSide;
+use uuid::Uuid;
+
+use crate::application::factory::StrategyFactory;
+use crate::application::scheduler::StrategyConfig;
+use crate::domain::model::restart_policy::RestartPolicy;
+use crate::domain::model::strategy_runtime::{ExecutionRequest, TradeIntent};
+
+/// 回测设置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BacktestSettings {
+    /// 初始资金
+    pub initial_capital: Decimal,
+    /// 每笔行情的成交量（传给策略）
+    pub tick_quantity: Decimal,
+    /// 手续费率（按成交额计）
+    pub fee_rate: Decimal,
+    /// 模拟起始时间（第一笔行情的时间）
+    pub start_time: DateTime<Utc>,
+    /// 相邻两笔行情的间隔（秒）
+    pub tick_interval_secs: u64,
+}
+
+impl Default for BacktestSettings {
+    fn default() -> Self {
+        Self {
+            initial_capital: Decimal::new(10_000, 0),
+            tick_quantity: Decimal::new(1, 3),
+            fee_rate: Decimal::ZERO,
+            start_time: DateTime::UNIX_EPOCH,
+            tick_interval_secs: 1,
+        }
+    }
+}
+
+/// 回测报告
+#[derive(Debug, Clone, Serialize)]
+pub struct BacktestReport {
+    /// 计入绩效的行情笔数（不含预热）
+    pub total_ticks: usize,
+    /// 买入成交次数
+    pub buy_signals: usize,
+    /// 卖出成交次数
+    pub sell_signals: usize,
+    /// 主交易对最终持仓
+    pub final_position: Decimal,
+    /// 最终现金
+    pub final_cash: Decimal,
+    /// 最终权益
+    pub final_equity: Decimal,
+    /// 累计手续费
+    pub total_fees: Decimal,
+    /// 总收益率
+    pub total_return: f64,
+    /// 最大回撤（0 ~ 1）
+    pub max_drawdown: f64,
+    /// 夏普比率（逐笔收益，无风险利率为 0，未年化）
+    pub sharpe_ratio: f64,
+}
 
 /// 回测服务
-#[allow(dead_code)]
-pub struct BacktestService;
+pub struct BacktestService {
+    /// 策略工厂
+    factory: Arc<StrategyFactory>,
+}
 
 impl BacktestService {
     /// 创建回测服务实例
-    #[allow(dead_code)]
-    pub fn new() -> Self {
-        Self
+    pub fn new(factory: Arc<StrategyFactory>) -> Self {
+        Self { factory }
+    }
+
+    /// 策略工厂
+    pub fn factory(&self) -> &Arc<StrategyFactory> {
+        &self.factory
     }
-    
+
     /// 运行回测
-    #[allow(dead_code)]
-    pub async fn run_backtest(&self) -> Result<()> {
-        // TODO: 实现回测逻辑
-        Ok(())
+    ///
+    /// 前 `warmup` 个价格只用于预热，不成交、不计入绩效。
+    pub fn run(
+        &self,
+        config: &StrategyConfig,
+        prices: &[Decimal],
+        warmup: usize,
+        settings: &BacktestSettings,
+    ) -> Result<BacktestReport> {
+        if prices.len() <= warmup {
+            bail!(
+                "backtest needs more prices than warmup: {} <= {}",
+                prices.len(),
+                warmup
+            );
+        }
+        if let Some(price) = prices.iter().find(|p| **p <= Decimal::ZERO) {
+            bail!("all prices must be positive, got {}", price);
+        }
+
+        let executor = self
+            .factory
+            .create(config)
+            .with_context(|| format!("创建回测策略失败: {}", config.strategy_type))?;
+
+        let interval = Duration::seconds(settings.tick_interval_secs.max(1) as i64);
+        let mut account = SimAccount::new(settings);
+        let mut equity_curve = Vec::with_capacity(prices.len() - warmup + 1);
+        equity_curve.push(settings.initial_capital);
+
+        for (idx, price) in prices.iter().copied().enumerate() {
+            let timestamp = settings.start_time + interval * idx as i32;
+            let mut request =
+                ExecutionRequest::new(config.symbol.clone(), price, settings.tick_quantity);
+            request.timestamp = timestamp;
+            account.mark(&config.symbol, price);
+
+            let mut results = executor.fire_timers(timestamp)?;
+            results.push(executor.execute(&request)?);
+            if idx < warmup {
+                continue;
+            }
+
+            let intents = results.into_iter().flat_map(|result| {
+                result
+                    .intent
+                    .into_iter()
+                    .chain(result.group.into_iter().flat_map(|group| group.legs))
+            });
+            for intent in intents {
+                let fill = account.fill(&intent, price, idx, timestamp);
+                executor.on_feedback(&intent.symbol, &ExecutionFeedback::Fill(fill))?;
+            }
+
+            equity_curve.push(account.equity());
+        }
+
+        Ok(account.report(&config.symbol, &equity_curve, settings))
+    }
+}
+
+/// 模拟账户
+struct SimAccount {
+    /// 现金
+    cash: Decimal,
+    /// 各交易对净持仓
+    positions: HashMap<String, Decimal>,
+    /// 各交易对最新价格
+    marks: HashMap<String, Decimal>,
+    /// 手续费率
+    fee_rate: Decimal,
+    /// 累计手续费
+    fees: Decimal,
+    /// 买入成交次数
+    buys: usize,
+    /// 卖出成交次数
+    sells: usize,
+}
+
+impl SimAccount {
+    fn new(settings: &BacktestSettings) -> Self {
+        Self {
+            cash: settings.initial_capital,
+            positions: HashMap::new(),
+            marks: HashMap::new(),
+            fee_rate: settings.fee_rate,
+            fees: Decimal::ZERO,
+            buys: 0,
+            sells: 0,
+        }
+    }
+
+    /// 更新标记价格
+    fn mark(&mut self, symbol: &str, price: Decimal) {
+        self.marks.insert(symbol.to_string(), price);
+    }
+
+    /// 按意图成交：市价单取当前价，限价单取信号价
+    fn fill(
+        &mut self,
+        intent: &TradeIntent,
+        market_price: Decimal,
+        tick: usize,
+        fill_time: DateTime<Utc>,
+    ) -> StrategyFill {
+        let price = intent.price.unwrap_or(market_price);
+        let notional = price * intent.quantity;
+        let fee = notional * self.fee_rate;
+        let position = self.positions.entry(intent.symbol.clone()).or_default();
+
+        match intent.side {
+            OrderSide::Buy => {
+                self.buys += 1;
+                self.cash -= notional;
+                *position += intent.quantity;
+            }
+            OrderSide::Sell => {
+                self.sells += 1;
+                self.cash += notional;
+                *position -= intent.quantity;
+            }
+        }
+        self.cash -= fee;
+        self.fees += fee;
+        // 其它腿没有独立行情，以成交价作为标记价格
+        self.marks.entry(intent.symbol.clone()).or_insert(price);
+
+        StrategyFill {
+            order_id: intent.id.to_string(),
+            trade_id: format!("backtest-{}", tick),
+            side: intent.side,
+            quantity: intent.quantity,
+            price,
+            cumulative_quantity: intent.quantity,
+            original_quantity: intent.quantity,
+            is_final: true,
+            commission: fee,
+            commission_asset: "USDT".to_string(),
+            fill_time,
+        }
+    }
+
+    /// 当前权益（现金 + 持仓市值）
+    fn equity(&self) -> Decimal {
+        self.positions
+            .iter()
+            .map(|(symbol, qty)| *qty * self.marks.get(symbol).copied().unwrap_or_default())
+            .fold(self.cash, |acc, value| acc + value)
+    }
+
+    fn report(
+        &self,
+        symbol: &str,
+        equity_curve: &[Decimal],
+        settings: &BacktestSettings,
+    ) -> BacktestReport {
+        let final_equity = self.equity();
+        let total_return = if settings.initial_capital.is_zero() {
+            0.0
+        } else {
+            ((final_equity - settings.initial_capital) / settings.initial_capital)
+                .to_f64()
+                .unwrap_or(0.0)
+        };
+
+        let curve: Vec<f64> = equity_curve
+            .iter()
+            .map(|e| e.to_f64().unwrap_or(0.0))
+            .collect();
+
+        BacktestReport {
+            total_ticks: equity_curve.len() - 1,
+            buy_signals: self.buys,
+            sell_signals: self.sells,
+            final_position: self.positions.get(symbol).copied().unwrap_or_default(),
+            final_cash: self.cash,
+            final_equity,
+            total_fees: self.fees,
+            total_return,
+            max_drawdown: max_drawdown(&curve),
+            sharpe_ratio: sharpe_ratio(&curve),
+        }
+    }
+}
+
+/// 最大回撤：权益从历史高点回落的最大比例
+fn max_drawdown(curve: &[f64]) -> f64 {
+    let mut peak = f64::MIN;
+    let mut worst = 0.0_f64;
+    for &equity in curve {
+        peak = peak.max(equity);
+        if peak > 0.0 {
+            worst = worst.max((peak - equity) / peak);
+        }
+    }
+    worst
+}
+
+/// 夏普比率：逐笔收益均值 / 标准差（样本数不足或无波动时为 0）
+fn sharpe_ratio(curve: &[f64]) -> f64 {
+    let returns: Vec<f64> = curve
+        .windows(2)
+        .filter(|w| w[0] > 0.0)
+        .map(|w| w[1] / w[0] - 1.0)
+        .collect();
+    if returns.len() < 2 {
+        return 0.0;
+    }
+
+    let n = returns.len() as f64;
+    let mean = returns.iter().sum::<f64>() / n;
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    let std_dev = variance.sqrt();
+    if std_dev <= f64::EPSILON {
+        0.0
+    } else {
+        mean / std_dev
+    }
+}
+
+/// 回测用策略配置（实例 ID 每次随机，不进入注册表）
+pub fn backtest_config(
+    strategy_type: &str,
+    market_type: crate::domain::model::market_type::MarketType,
+    symbol: &str,
+    params: serde_json::Value,
+) -> StrategyConfig {
+    StrategyConfig {
+        instance_id: Uuid::new_v4(),
+        strategy_type: strategy_type.to_string(),
+        market_type,
+        symbol: symbol.to_string(),
+        owner_id: Uuid::nil(),
+        name: format!("backtest-{}", strategy_type),
+        params,
+        auto_start: false,
+        restart_policy: RestartPolicy::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::market_type::MarketType;
+    use serde_json::json;
+
+    fn service() -> BacktestService {
+        BacktestService::new(Arc::new(StrategyFactory::with_builtin()))
+    }
+
+    fn grid_config() -> StrategyConfig {
+        backtest_config(
+            "spot_grid",
+            MarketType::Spot,
+            "BTCUSDT",
+            json!({
+                "upper_price": "110",
+                "lower_price": "90",
+                "grid_count": 10,
+                "quantity_per_grid": "1"
+            }),
+        )
+    }
+
+    #[test]
+    fn test_metrics() {
+        assert_eq!(max_drawdown(&[100.0, 120.0, 90.0, 130.0]), 0.25);
+        assert_eq!(sharpe_ratio(&[100.0, 100.0, 100.0]), 0.0);
+        assert!(sharpe_ratio(&[100.0, 101.0, 103.0, 104.0]) > 0.0);
+    }
+
+    #[test]
+    fn test_grid_backtest_trades_and_accounts() {
+        let prices: Vec<Decimal> = [100, 97, 95, 98, 102, 105, 101, 96, 100]
+            .iter()
+            .map(|p| Decimal::from(*p))
+            .collect();
+
+        let report = service()
+            .run(&grid_config(), &prices, 0, &BacktestSettings::default())
+            .unwrap();
+
+        assert_eq!(report.total_ticks, prices.len());
+        assert!(report.buy_signals + report.sell_signals > 0);
+        assert_eq!(
+            report.final_equity,
+            report.final_cash + report.final_position * prices[prices.len() - 1]
+        );
+    }
+
+    #[test]
+    fn test_warmup_discards_signals() {
+        let prices: Vec<Decimal> = [100, 95, 105, 100].iter().map(|p| Decimal::from(*p)).collect();
+        let report = service()
+            .run(&grid_config(), &prices, 3, &BacktestSettings::default())
+            .unwrap();
+        assert_eq!(report.total_ticks, 1);
+
+        assert!(service()
+            .run(&grid_config(), &prices, 4, &BacktestSettings::default())
+            .is_err());
     }
 }
@@ -12,6 +12,7 @@ pub mod market_event_consumer_service;
 /// 风控服务 - 风控检查用例
 pub mod risk_service;
 
+pub use backtest_service::{BacktestReport, BacktestService, BacktestSettings};
 pub use market_event_consumer_service::MarketEventConsumerService;
 pub use risk_service::RiskService;
 
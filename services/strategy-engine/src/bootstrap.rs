@@ -2,19 +2,31 @@
 //!
 //! 负责创建并组装适配器与服务。
 
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
 
-use crate::application::scheduler::{SchedulerConfig, StrategyLoader, StrategyScheduler};
+use crate::application::factory::StrategyFactory;
+use crate::application::portfolio::{PortfolioAllocator, PortfolioConfig};
+use crate::application::scheduler::{
+    ExecutionFeedbackConsumer, SchedulerConfig, SignalEventConfig, StateCheckpointer,
+    StrategyLoader, StrategyScheduler, StrategySupervisor,
+};
 use crate::application::service::market_event_consumer_service::MarketEventConsumerService;
 use crate::application::service::risk_service::RiskService;
 use crate::application::service::strategy_service::StrategyService;
 use crate::domain::logic::grid::GridConfig;
 use crate::domain::logic::mean::MeanReversionConfig;
 use crate::domain::model::strategy_config::StrategyType;
+use crate::domain::port::StrategyStatePort;
 use crate::domain::service::strategy_registry::StrategyRegistry;
-use crate::infrastructure::messaging::{KafkaConsumer, KafkaProducer, MockConsumer};
+use crate::infrastructure::messaging::{
+    KafkaConsumer, KafkaFaultNotifier, KafkaProducer, MockConsumer,
+};
 use crate::infrastructure::repository::strategy_repository::StrategyRepository;
 use crate::infrastructure::risk::noop_risk::NoopRisk;
 use crate::infrastructure::strategy::noop_strategy::NoopStrategy;
@@ -65,11 +77,16 @@ pub fn create_risk_service() -> RiskService<Arc<NoopRisk>> {
 /// 创建策略调度器（新版本）
 ///
 /// 使用 StrategyRegistry + StrategyScheduler 架构
+#[allow(clippy::too_many_arguments)]
 pub async fn create_strategy_scheduler(
     kafka_brokers: String,
     market_topic: String,
     signal_topic: String,
+    signal_group_topic: String,
+    quote_topic: String,
     consumer_group: String,
+    portfolio: Option<Arc<PortfolioAllocator>>,
+    signal_events: Option<SignalEventConfig>,
 ) -> Result<(Arc<StrategyRegistry>, Arc<StrategyScheduler>, StrategyLoader)> {
     // 创建策略注册表
     let registry = Arc::new(StrategyRegistry::new());
@@ -79,17 +96,83 @@ pub async fn create_strategy_scheduler(
         kafka_brokers,
         market_topic,
         signal_topic,
+        signal_group_topic,
+        quote_topic,
         consumer_group,
+        signal_events,
     };
 
-    // 创建调度器
-    let scheduler = Arc::new(StrategyScheduler::new(
-        Arc::clone(&registry),
-        scheduler_config,
-    )?);
+    // 创建调度器（启用组合资金分配时，发布前按共享资金缩放与对冲）
+    let mut scheduler = StrategyScheduler::new(Arc::clone(&registry), scheduler_config)?;
+    if let Some(portfolio) = portfolio {
+        scheduler = scheduler.with_portfolio(portfolio);
+    }
+    let scheduler = Arc::new(scheduler);
 
-    // 创建策略加载器
-    let loader = StrategyLoader::new(Arc::clone(&registry));
+    // 创建策略加载器（注册全部内置策略）
+    let factory = Arc::new(StrategyFactory::with_builtin());
+    let loader = StrategyLoader::new(Arc::clone(&registry), factory);
 
     Ok((registry, scheduler, loader))
 }
+
+/// 创建组合资金分配器
+///
+/// 调度器与执行回报消费者共享同一个实例（回报更新账簿，调度器据此缩放意图）。
+pub fn create_portfolio_allocator(config: Option<PortfolioConfig>) -> Option<Arc<PortfolioAllocator>> {
+    config.map(|config| Arc::new(PortfolioAllocator::new(config)))
+}
+
+/// 创建执行回报消费者
+///
+/// 消费 trading-engine 回送的成交 / 拒单 / 持仓快照，按实例 ID 路由到注册表；
+/// `checkpoints` 为已恢复实例的检查点保存时间，启动时重放其后的回报。
+pub fn create_feedback_consumer(
+    registry: Arc<StrategyRegistry>,
+    kafka_brokers: &str,
+    feedback_topic: &str,
+    consumer_group: &str,
+    portfolio: Option<Arc<PortfolioAllocator>>,
+    checkpoints: HashMap<Uuid, DateTime<Utc>>,
+) -> Result<Arc<ExecutionFeedbackConsumer>> {
+    let group_id = format!("{}-feedback", consumer_group);
+    let mut consumer =
+        ExecutionFeedbackConsumer::new(registry, kafka_brokers, feedback_topic, &group_id)?
+            .with_checkpoints(checkpoints);
+    if let Some(portfolio) = portfolio {
+        consumer = consumer.with_portfolio(portfolio);
+    }
+    Ok(Arc::new(consumer))
+}
+
+/// 创建策略状态检查点
+///
+/// 启动时恢复、运行中定时保存、关闭时最后保存一次。
+pub fn create_state_checkpointer(
+    registry: Arc<StrategyRegistry>,
+    store: Arc<dyn StrategyStatePort>,
+    interval_secs: u64,
+) -> Arc<StateCheckpointer> {
+    Arc::new(StateCheckpointer::new(
+        registry,
+        store,
+        Duration::from_secs(interval_secs),
+    ))
+}
+
+/// 创建策略监督器
+///
+/// 按各实例的重启策略自动重启故障实例，放弃时向告警主题发送永久故障通知。
+pub fn create_strategy_supervisor(
+    registry: Arc<StrategyRegistry>,
+    kafka_brokers: &str,
+    alert_topic: &str,
+    interval_secs: u64,
+) -> Result<Arc<StrategySupervisor>> {
+    let notifier = Arc::new(KafkaFaultNotifier::new(kafka_brokers, alert_topic)?);
+    Ok(Arc::new(StrategySupervisor::new(
+        registry,
+        notifier,
+        Duration::from_secs(interval_secs),
+    )))
+}
@@ -0,0 +1,263 @@
+//! # 特征向量 (Feature Vector)
+//!
+//! 用指标库从逐笔成交流计算模型输入。特征顺序即配置顺序，必须与训练时一致。
+//!
+//! ## 特征
+//! - `return`：周期对数收益率 ln(p_t / p_{t−n})
+//! - `volatility`：逐笔对数收益率的滚动标准差
+//! - `rsi`：RSI / 100
+//! - `macd_histogram`：MACD 柱 / 价格
+//! - `bollinger_percent`：布林带 %B = (价格 − 下轨) / (上轨 − 下轨)
+//! - `volume_ratio`：成交量 / 成交量均值
+//!
+//! 全部特征预热完成后才输出向量。
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::logic::indicator::{
+    Bollinger, Indicator, Macd, Rsi, RollingWindow, Sma, StdDev,
+};
+
+/// 特征定义
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum FeatureSpec {
+    /// 周期对数收益率
+    Return { period: usize },
+    /// 对数收益率标准差
+    Volatility { period: usize },
+    /// RSI（缩放到 0~1）
+    Rsi { period: usize },
+    /// MACD 柱（相对价格）
+    MacdHistogram {
+        fast: usize,
+        slow: usize,
+        signal: usize,
+    },
+    /// 布林带 %B
+    BollingerPercent { period: usize, multiplier: f64 },
+    /// 成交量 / 成交量均值
+    VolumeRatio { period: usize },
+}
+
+impl FeatureSpec {
+    /// 周期参数（用于校验）
+    pub fn periods(&self) -> Vec<(&'static str, usize)> {
+        match *self {
+            FeatureSpec::Return { period } => vec![("return.period", period)],
+            FeatureSpec::Volatility { period } => vec![("volatility.period", period)],
+            FeatureSpec::Rsi { period } => vec![("rsi.period", period)],
+            FeatureSpec::MacdHistogram { fast, slow, signal } => vec![
+                ("macd_histogram.fast", fast),
+                ("macd_histogram.slow", slow),
+                ("macd_histogram.signal", signal),
+            ],
+            FeatureSpec::BollingerPercent { period, .. } => {
+                vec![("bollinger_percent.period", period)]
+            }
+            FeatureSpec::VolumeRatio { period } => vec![("volume_ratio.period", period)],
+        }
+    }
+
+    /// 默认特征集
+    pub fn default_set() -> Vec<FeatureSpec> {
+        vec![
+            FeatureSpec::Return { period: 10 },
+            FeatureSpec::Return { period: 50 },
+            FeatureSpec::Volatility { period: 50 },
+            FeatureSpec::Rsi { period: 14 },
+            FeatureSpec::MacdHistogram {
+                fast: 12,
+                slow: 26,
+                signal: 9,
+            },
+            FeatureSpec::BollingerPercent {
+                period: 20,
+                multiplier: 2.0,
+            },
+            FeatureSpec::VolumeRatio { period: 50 },
+        ]
+    }
+}
+
+/// 单个特征的滚动状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Feature {
+    Return {
+        prices: RollingWindow<f64>,
+    },
+    Volatility {
+        last_price: Option<f64>,
+        returns: StdDev<f64>,
+    },
+    Rsi {
+        rsi: Rsi<f64>,
+    },
+    MacdHistogram {
+        macd: Macd<f64>,
+    },
+    BollingerPercent {
+        bands: Bollinger<f64>,
+    },
+    VolumeRatio {
+        average: Sma<f64>,
+    },
+}
+
+impl Feature {
+    /// 按定义创建
+    pub fn new(spec: &FeatureSpec) -> Self {
+        match *spec {
+            FeatureSpec::Return { period } => Feature::Return {
+                prices: RollingWindow::new(period + 1),
+            },
+            FeatureSpec::Volatility { period } => Feature::Volatility {
+                last_price: None,
+                returns: StdDev::new(period),
+            },
+            FeatureSpec::Rsi { period } => Feature::Rsi {
+                rsi: Rsi::new(period),
+            },
+            FeatureSpec::MacdHistogram { fast, slow, signal } => Feature::MacdHistogram {
+                macd: Macd::new(fast, slow, signal),
+            },
+            FeatureSpec::BollingerPercent { period, multiplier } => Feature::BollingerPercent {
+                bands: Bollinger::new(period, multiplier),
+            },
+            FeatureSpec::VolumeRatio { period } => Feature::VolumeRatio {
+                average: Sma::new(period),
+            },
+        }
+    }
+
+    /// 输入一笔成交，返回特征值（预热期为 None）
+    pub fn update(&mut self, price: f64, volume: f64) -> Option<f64> {
+        match self {
+            Feature::Return { prices } => {
+                prices.push(price);
+                if !prices.is_full() {
+                    return None;
+                }
+                log_return(prices.oldest()?, price)
+            }
+            Feature::Volatility {
+                last_price,
+                returns,
+            } => {
+                let previous = last_price.replace(price)?;
+                returns.update(log_return(previous, price)?)
+            }
+            Feature::Rsi { rsi } => rsi.update(price).map(|v| v / 100.0),
+            Feature::MacdHistogram { macd } => {
+                let output = macd.update(price)?;
+                (price > 0.0).then(|| output.histogram / price)
+            }
+            Feature::BollingerPercent { bands } => {
+                let output = bands.update(price)?;
+                let width = output.upper - output.lower;
+                Some(if width > 0.0 {
+                    (price - output.lower) / width
+                } else {
+                    0.5
+                })
+            }
+            Feature::VolumeRatio { average } => {
+                let mean = average.update(volume)?;
+                Some(if mean > 0.0 { volume / mean } else { 1.0 })
+            }
+        }
+    }
+}
+
+/// 特征向量
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeatureVector {
+    /// 特征定义（参数热更新时判断能否沿用）
+    specs: Vec<FeatureSpec>,
+    /// 各特征的滚动状态
+    features: Vec<Feature>,
+    /// 各特征的最新值
+    values: Vec<Option<f64>>,
+}
+
+impl FeatureVector {
+    /// 按定义创建
+    pub fn new(specs: &[FeatureSpec]) -> Self {
+        Self {
+            specs: specs.to_vec(),
+            features: specs.iter().map(Feature::new).collect(),
+            values: vec![None; specs.len()],
+        }
+    }
+
+    /// 特征定义
+    pub fn specs(&self) -> &[FeatureSpec] {
+        &self.specs
+    }
+
+    /// 特征维度
+    pub fn len(&self) -> usize {
+        self.specs.len()
+    }
+
+    /// 是否没有特征
+    pub fn is_empty(&self) -> bool {
+        self.specs.is_empty()
+    }
+
+    /// 输入一笔成交，全部特征就绪时返回向量
+    pub fn update(&mut self, price: f64, volume: f64) -> Option<Vec<f64>> {
+        // 所有特征都要更新，不能短路
+        for (feature, value) in self.features.iter_mut().zip(self.values.iter_mut()) {
+            *value = feature.update(price, volume);
+        }
+        self.values.iter().copied().collect()
+    }
+}
+
+fn log_return(from: f64, to: f64) -> Option<f64> {
+    (from > 0.0 && to > 0.0).then(|| (to / from).ln())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_vector_warms_up_then_outputs_in_order() {
+        let specs = vec![
+            FeatureSpec::Return { period: 2 },
+            FeatureSpec::Rsi { period: 3 },
+            FeatureSpec::VolumeRatio { period: 2 },
+        ];
+        let mut vector = FeatureVector::new(&specs);
+        assert_eq!(vector.len(), 3);
+
+        let prices = [100.0, 101.0, 102.0, 103.0];
+        let mut outputs = Vec::new();
+        for (i, price) in prices.iter().enumerate() {
+            outputs.push(vector.update(*price, (i + 1) as f64));
+        }
+
+        // RSI(3) 需要 4 个价格，前 3 笔没有向量
+        assert!(outputs[..3].iter().all(Option::is_none));
+        let features = outputs[3].clone().unwrap();
+        assert!((features[0] - (103.0f64 / 101.0).ln()).abs() < 1e-12);
+        // 单边上涨 RSI = 100
+        assert!((features[1] - 1.0).abs() < 1e-12);
+        // 成交量 4 / 均值 3.5
+        assert!((features[2] - 4.0 / 3.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_feature_spec_serde() {
+        let specs: Vec<FeatureSpec> = serde_json::from_str(
+            r#"[{ "kind": "macd_histogram", "fast": 12, "slow": 26, "signal": 9 },
+                { "kind": "bollinger_percent", "period": 20, "multiplier": 2 }]"#,
+        )
+        .unwrap();
+        assert_eq!(specs, FeatureSpec::default_set()[4..6].to_vec());
+        assert!(serde_json::from_str::<FeatureSpec>(r#"{ "kind": "rsi", "period": 14, "x": 1 }"#).is_err());
+    }
+}
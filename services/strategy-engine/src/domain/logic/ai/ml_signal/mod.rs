@@ -0,0 +1,268 @@
+//! # 机器学习信号策略 (ML Signal Strategy)
+//!
+//! 加载离线训练的模型（ONNX 或 JSON 描述的线性 / 树模型），在进程内 CPU 推理：
+//!
+//! - 特征：用指标库从逐笔成交流计算（见 `features`），顺序与训练时一致
+//! - 推理：每 `inference_interval` 笔成交推理一次，输出方向概率（见 `model`）
+//! - 开仓：上涨概率 ≥ 入场概率做多，下跌概率 ≥ 入场概率做空（现货只做多）
+//! - 平仓：反方向概率 ≥ 离场概率
+//! - 信号置信度即对应方向的概率
+//!
+//! 模型由创建方加载后注入，模型版本通过 `Strategy::model_version` 写入策略元数据。
+
+pub mod features;
+pub mod model;
+
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+use rust_decimal::prelude::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::PositionSnapshot;
+use shared::event::market_event::{MarketEvent, MarketEventData};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
+use crate::domain::model::market_type::MarketType;
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalType};
+
+pub use features::{FeatureSpec, FeatureVector};
+pub use model::{InferenceModel, JsonInferenceModel, JsonModel, JsonModelFile, Prediction};
+
+/// 机器学习信号策略配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MlSignalConfig {
+    /// 模型文件路径（`.onnx` 或 `.json`）
+    pub model_path: String,
+    /// 特征列表（顺序与训练时一致）
+    pub features: Vec<FeatureSpec>,
+    /// 推理间隔（成交笔数）
+    pub inference_interval: u32,
+    /// 入场概率
+    pub entry_probability: Decimal,
+    /// 离场概率（反方向）
+    pub exit_probability: Decimal,
+    /// 交易数量
+    pub quantity: Decimal,
+}
+
+impl Default for MlSignalConfig {
+    fn default() -> Self {
+        Self {
+            model_path: String::new(),
+            features: FeatureSpec::default_set(),
+            inference_interval: 1,
+            entry_probability: Decimal::new(6, 1), // 0.6
+            exit_probability: Decimal::new(5, 1),  // 0.5
+            quantity: Decimal::new(1, 3),          // 0.001
+        }
+    }
+}
+
+/// 机器学习信号策略状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MlSignalState {
+    /// 特征向量
+    pub features: FeatureVector,
+    /// 距上次推理的成交笔数
+    pub since_inference: u32,
+    /// 当前持仓方向（None 为空仓）
+    pub position: Option<SignalType>,
+}
+
+impl MlSignalState {
+    pub fn new(config: &MlSignalConfig) -> Self {
+        Self {
+            features: FeatureVector::new(&config.features),
+            since_inference: 0,
+            position: None,
+        }
+    }
+}
+
+/// 机器学习信号策略
+pub struct MlSignalStrategy {
+    meta: StrategyMeta,
+    config: MlSignalConfig,
+    state: MlSignalState,
+    model: Arc<dyn InferenceModel>,
+}
+
+impl MlSignalStrategy {
+    /// 创建机器学习信号策略实例
+    ///
+    /// 模型须按 `config.features` 的维度加载。
+    pub fn new(
+        instance_id: Uuid,
+        symbol: String,
+        config: MlSignalConfig,
+        market_type: MarketType,
+        model: Arc<dyn InferenceModel>,
+    ) -> Result<Self> {
+        if config.features.is_empty() {
+            bail!("机器学习信号策略至少需要一个特征");
+        }
+
+        Ok(Self {
+            meta: StrategyMeta {
+                instance_id,
+                strategy_type: "ml_signal".to_string(),
+                market_type,
+                symbol,
+                is_active: false,
+            },
+            state: MlSignalState::new(&config),
+            config,
+            model,
+        })
+    }
+
+    /// 推理方向概率（失败时记录并跳过本次推理）
+    fn predict(&self, features: &[f64]) -> Option<Prediction> {
+        let result = self
+            .model
+            .predict(features)
+            .and_then(|probabilities| Prediction::from_probabilities(&probabilities));
+        match result {
+            Ok(prediction) => Some(prediction),
+            Err(e) => {
+                warn!(
+                    instance_id = %self.meta.instance_id,
+                    model_version = self.model.version(),
+                    error = %e,
+                    "模型推理失败"
+                );
+                None
+            }
+        }
+    }
+
+    /// 构造信号
+    fn signal(
+        &self,
+        event: &MarketEvent,
+        signal_type: SignalType,
+        price: Decimal,
+        probability: f64,
+        instruction: OrderInstruction,
+    ) -> Signal {
+        Signal {
+            id: Uuid::new_v4(),
+            strategy_id: self.meta.instance_id,
+            symbol: event.symbol.clone(),
+            signal_type,
+            price,
+            quantity: self.config.quantity,
+            confidence: probability,
+            created_at: event.timestamp,
+            instruction,
+        }
+    }
+
+    /// 计算模型信号
+    fn calculate_signal(&mut self, event: &MarketEvent) -> Option<Signal> {
+        if event.symbol != self.meta.symbol {
+            return None;
+        }
+        let trade = match &event.data {
+            MarketEventData::Trade(trade) => trade,
+            _ => return None,
+        };
+
+        // 特征每笔都要更新，推理按间隔进行
+        let features = self
+            .state
+            .features
+            .update(trade.price.to_f64()?, trade.quantity.to_f64()?);
+        self.state.since_inference += 1;
+        let features = features?;
+        if self.state.since_inference < self.config.inference_interval {
+            return None;
+        }
+        self.state.since_inference = 0;
+
+        let prediction = self.predict(&features)?;
+        let entry = self.config.entry_probability.to_f64()?;
+        let exit = self.config.exit_probability.to_f64()?;
+
+        // 有持仓：反方向概率足够高时平仓
+        match self.state.position {
+            Some(SignalType::Buy) if prediction.down >= exit => {
+                self.state.position = None;
+                return Some(self.signal(event, SignalType::Sell, trade.price, prediction.down, OrderInstruction::close()));
+            }
+            Some(SignalType::Sell) if prediction.up >= exit => {
+                self.state.position = None;
+                return Some(self.signal(event, SignalType::Buy, trade.price, prediction.up, OrderInstruction::close()));
+            }
+            Some(_) => return None,
+            None => {}
+        }
+
+        let (signal_type, probability) = if prediction.up >= entry {
+            (SignalType::Buy, prediction.up)
+        } else if prediction.down >= entry && self.meta.market_type.is_futures() {
+            (SignalType::Sell, prediction.down)
+        } else {
+            return None;
+        };
+
+        self.state.position = Some(signal_type);
+        Some(self.signal(event, signal_type, trade.price, probability, OrderInstruction::open()))
+    }
+}
+
+impl Strategy for MlSignalStrategy {
+    fn meta(&self) -> &StrategyMeta {
+        &self.meta
+    }
+
+    fn meta_mut(&mut self) -> &mut StrategyMeta {
+        &mut self.meta
+    }
+
+    fn on_market_event(&mut self, event: &MarketEvent) -> Option<Signal> {
+        if !self.is_active() {
+            return None;
+        }
+        self.calculate_signal(event)
+    }
+
+    fn model_version(&self) -> Option<String> {
+        Some(self.model.version().to_string())
+    }
+
+    fn on_position_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {
+        if symbol != self.meta.symbol {
+            return;
+        }
+        self.state.position = if snapshot.is_long() {
+            Some(SignalType::Buy)
+        } else if snapshot.is_short() {
+            Some(SignalType::Sell)
+        } else {
+            None
+        };
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let mut state: MlSignalState = serde_json::from_value(state)?;
+        // 参数热更新后特征定义变化的窗口无法沿用，重新预热
+        if state.features.specs() != self.config.features.as_slice() {
+            state.features = FeatureVector::new(&self.config.features);
+        }
+        self.state = state;
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.state = MlSignalState::new(&self.config);
+    }
+}
@@ -0,0 +1,398 @@
+//! # 推理模型 (Inference Models)
+//!
+//! 离线训练、导出后在进程内 CPU 推理的模型。
+//!
+//! ## 规则
+//! - ✅ 输入为特征向量（顺序与训练时一致），输出为各类别概率
+//! - ✅ 加载时校验输入维度与结构，推理期间不再出现结构性错误
+//! - ✅ 每个模型带版本号，写入策略元数据便于追踪
+//! - ❌ 不在推理时做 IO，不访问网络
+//!
+//! ## JSON 模型格式
+//! ```json
+//! { "type": "logistic", "version": "2024-06-01", "weights": [0.8, -1.2], "intercept": 0.1 }
+//! ```
+//! - `logistic`：二分类逻辑回归，输出 [P(涨)]
+//! - `softmax`：多分类线性模型，每类一组权重，输出各类概率
+//! - `tree_ensemble`：树集成（梯度提升 / 随机森林），节点按数组存储，子节点下标必须大于父节点
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+/// 推理模型
+///
+/// 实现必须是纯 CPU 计算，可在多个策略实例间共享。
+pub trait InferenceModel: Send + Sync {
+    /// 模型版本
+    fn version(&self) -> &str;
+
+    /// 推理：特征向量 → 类别概率
+    fn predict(&self, features: &[f64]) -> Result<Vec<f64>>;
+}
+
+/// 方向概率
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Prediction {
+    /// 上涨概率
+    pub up: f64,
+    /// 下跌概率
+    pub down: f64,
+}
+
+impl Prediction {
+    /// 由模型输出解释方向概率
+    ///
+    /// - 1 个输出：[P(涨)]，P(跌) = 1 − P(涨)
+    /// - 2 个输出：[P(跌), P(涨)]
+    /// - 3 个输出：[P(跌), P(平), P(涨)]
+    pub fn from_probabilities(probabilities: &[f64]) -> Result<Self> {
+        let (up, down) = match *probabilities {
+            [up] => (up, 1.0 - up),
+            [down, up] | [down, _, up] => (up, down),
+            _ => bail!(
+                "模型输出 {} 个值，只支持 1 / 2 / 3 个类别概率",
+                probabilities.len()
+            ),
+        };
+        if !(0.0..=1.0).contains(&up) || !(0.0..=1.0).contains(&down) {
+            bail!("模型输出不是概率: 涨 {} / 跌 {}", up, down);
+        }
+        Ok(Self { up, down })
+    }
+}
+
+/// JSON 模型文件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonModelFile {
+    /// 模型版本（缺省时由加载方按文件内容生成）
+    #[serde(default)]
+    pub version: Option<String>,
+    /// 模型本体
+    #[serde(flatten)]
+    pub model: JsonModel,
+}
+
+/// JSON 描述的模型
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JsonModel {
+    /// 二分类逻辑回归
+    Logistic {
+        /// 特征权重
+        weights: Vec<f64>,
+        /// 截距
+        #[serde(default)]
+        intercept: f64,
+    },
+    /// 多分类线性模型（softmax）
+    Softmax {
+        /// 每个类别一组特征权重
+        weights: Vec<Vec<f64>>,
+        /// 每个类别的截距
+        intercepts: Vec<f64>,
+    },
+    /// 树集成
+    TreeEnsemble {
+        /// 决策树
+        trees: Vec<Tree>,
+        /// 基础分
+        #[serde(default)]
+        base_score: f64,
+        /// 各树输出的聚合方式
+        #[serde(default)]
+        aggregation: Aggregation,
+        /// 聚合结果到概率的变换
+        #[serde(default)]
+        link: Link,
+    },
+}
+
+/// 树输出聚合方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Aggregation {
+    /// 求和（梯度提升）
+    #[default]
+    Sum,
+    /// 平均（随机森林）
+    Mean,
+}
+
+/// 概率变换
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Link {
+    /// sigmoid（叶子值为对数几率）
+    #[default]
+    Logistic,
+    /// 不变换（叶子值即概率）
+    Identity,
+}
+
+/// 决策树
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tree {
+    /// 节点数组，0 号为根节点
+    pub nodes: Vec<TreeNode>,
+}
+
+/// 树节点
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TreeNode {
+    /// 分裂节点：x[feature] < threshold 走左子树
+    Split {
+        feature: usize,
+        threshold: f64,
+        left: usize,
+        right: usize,
+    },
+    /// 叶子节点
+    Leaf { value: f64 },
+}
+
+impl Tree {
+    /// 校验结构：子节点下标大于父节点（无环）且不越界，特征下标在输入维度内
+    fn validate(&self, input_size: usize) -> Result<()> {
+        if self.nodes.is_empty() {
+            bail!("决策树没有节点");
+        }
+        for (index, node) in self.nodes.iter().enumerate() {
+            if let TreeNode::Split {
+                feature,
+                left,
+                right,
+                ..
+            } = *node
+            {
+                if feature >= input_size {
+                    bail!("节点 {} 使用特征 {}，超出输入维度 {}", index, feature, input_size);
+                }
+                for child in [left, right] {
+                    if child <= index || child >= self.nodes.len() {
+                        bail!("节点 {} 的子节点下标 {} 不合法", index, child);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 从根节点走到叶子
+    fn evaluate(&self, features: &[f64]) -> f64 {
+        let mut index = 0;
+        loop {
+            match self.nodes[index] {
+                TreeNode::Leaf { value } => return value,
+                TreeNode::Split {
+                    feature,
+                    threshold,
+                    left,
+                    right,
+                } => {
+                    index = if features[feature] < threshold {
+                        left
+                    } else {
+                        right
+                    };
+                }
+            }
+        }
+    }
+}
+
+impl JsonModel {
+    /// 校验模型与输入维度一致
+    pub fn validate(&self, input_size: usize) -> Result<()> {
+        match self {
+            JsonModel::Logistic { weights, .. } => check_weights(weights, input_size),
+            JsonModel::Softmax {
+                weights,
+                intercepts,
+            } => {
+                if weights.len() < 2 || weights.len() != intercepts.len() {
+                    bail!(
+                        "softmax 至少需要 2 个类别，且权重组数（{}）与截距数（{}）一致",
+                        weights.len(),
+                        intercepts.len()
+                    );
+                }
+                weights.iter().try_for_each(|w| check_weights(w, input_size))
+            }
+            JsonModel::TreeEnsemble { trees, .. } => {
+                if trees.is_empty() {
+                    bail!("树集成没有决策树");
+                }
+                trees.iter().try_for_each(|tree| tree.validate(input_size))
+            }
+        }
+    }
+
+    /// 推理（调用前须已通过 `validate`）
+    pub fn predict(&self, features: &[f64]) -> Vec<f64> {
+        match self {
+            JsonModel::Logistic { weights, intercept } => {
+                vec![sigmoid(dot(weights, features) + intercept)]
+            }
+            JsonModel::Softmax {
+                weights,
+                intercepts,
+            } => {
+                let logits: Vec<f64> = weights
+                    .iter()
+                    .zip(intercepts)
+                    .map(|(w, b)| dot(w, features) + b)
+                    .collect();
+                softmax(&logits)
+            }
+            JsonModel::TreeEnsemble {
+                trees,
+                base_score,
+                aggregation,
+                link,
+            } => {
+                let sum: f64 = trees.iter().map(|tree| tree.evaluate(features)).sum();
+                let score = base_score
+                    + match aggregation {
+                        Aggregation::Sum => sum,
+                        Aggregation::Mean => sum / trees.len() as f64,
+                    };
+                vec![match link {
+                    Link::Logistic => sigmoid(score),
+                    Link::Identity => score.clamp(0.0, 1.0),
+                }]
+            }
+        }
+    }
+}
+
+/// 已加载的 JSON 模型
+#[derive(Debug, Clone)]
+pub struct JsonInferenceModel {
+    model: JsonModel,
+    version: String,
+}
+
+impl JsonInferenceModel {
+    /// 创建（校验输入维度）
+    pub fn new(model: JsonModel, version: String, input_size: usize) -> Result<Self> {
+        model.validate(input_size)?;
+        Ok(Self { model, version })
+    }
+}
+
+impl InferenceModel for JsonInferenceModel {
+    fn version(&self) -> &str {
+        &self.version
+    }
+
+    fn predict(&self, features: &[f64]) -> Result<Vec<f64>> {
+        Ok(self.model.predict(features))
+    }
+}
+
+fn check_weights(weights: &[f64], input_size: usize) -> Result<()> {
+    if weights.len() != input_size {
+        bail!("模型权重数 {} 与特征数 {} 不一致", weights.len(), input_size);
+    }
+    Ok(())
+}
+
+fn dot(weights: &[f64], features: &[f64]) -> f64 {
+    weights.iter().zip(features).map(|(w, x)| w * x).sum()
+}
+
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+fn softmax(logits: &[f64]) -> Vec<f64> {
+    // 减去最大值避免溢出
+    let max = logits.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let exps: Vec<f64> = logits.iter().map(|l| (l - max).exp()).collect();
+    let total: f64 = exps.iter().sum();
+    exps.into_iter().map(|e| e / total).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_models_predict_probabilities() {
+        let logistic: JsonModelFile = serde_json::from_str(
+            r#"{ "type": "logistic", "version": "v1", "weights": [2.0, -1.0], "intercept": 0.5 }"#,
+        )
+        .unwrap();
+        assert_eq!(logistic.version.as_deref(), Some("v1"));
+        let model = JsonInferenceModel::new(logistic.model, "v1".to_string(), 2).unwrap();
+        let up = model.predict(&[1.0, 0.5]).unwrap();
+        assert!((up[0] - sigmoid(2.0)).abs() < 1e-12);
+        let prediction = Prediction::from_probabilities(&up).unwrap();
+        assert!((prediction.up + prediction.down - 1.0).abs() < 1e-12);
+
+        let softmax: JsonModelFile = serde_json::from_str(
+            r#"{ "type": "softmax", "weights": [[1, 0], [0, 0], [0, 1]], "intercepts": [0, 0, 0] }"#,
+        )
+        .unwrap();
+        let probabilities = softmax.model.predict(&[0.0, 3.0]);
+        assert_eq!(probabilities.len(), 3);
+        assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        let prediction = Prediction::from_probabilities(&probabilities).unwrap();
+        assert!(prediction.up > 0.9 && prediction.down < 0.05);
+
+        // 维度不一致在加载时拒绝
+        let mismatch = JsonModel::Logistic {
+            weights: vec![1.0],
+            intercept: 0.0,
+        };
+        assert!(JsonInferenceModel::new(mismatch, "v1".to_string(), 2).is_err());
+    }
+
+    #[test]
+    fn test_tree_ensemble_evaluation_and_validation() {
+        let file: JsonModelFile = serde_json::from_str(
+            r#"{
+                "type": "tree_ensemble",
+                "aggregation": "mean",
+                "link": "identity",
+                "trees": [
+                    { "nodes": [
+                        { "feature": 0, "threshold": 0.0, "left": 1, "right": 2 },
+                        { "value": 0.2 },
+                        { "value": 0.8 }
+                    ] },
+                    { "nodes": [
+                        { "feature": 1, "threshold": 50.0, "left": 1, "right": 2 },
+                        { "value": 0.6 },
+                        { "value": 0.4 }
+                    ] }
+                ]
+            }"#,
+        )
+        .unwrap();
+        file.model.validate(2).unwrap();
+        assert!((file.model.predict(&[1.0, 30.0])[0] - 0.7).abs() < 1e-12);
+        assert!((file.model.predict(&[-1.0, 70.0])[0] - 0.3).abs() < 1e-12);
+
+        // 特征越界、子节点指向自身（成环）均拒绝
+        assert!(file.model.validate(1).is_err());
+        let cyclic = JsonModel::TreeEnsemble {
+            trees: vec![Tree {
+                nodes: vec![TreeNode::Split {
+                    feature: 0,
+                    threshold: 0.0,
+                    left: 0,
+                    right: 0,
+                }],
+            }],
+            base_score: 0.0,
+            aggregation: Aggregation::Sum,
+            link: Link::Logistic,
+        };
+        assert!(cyclic.validate(1).is_err());
+
+        assert!(Prediction::from_probabilities(&[0.1, 0.2, 0.3, 0.4]).is_err());
+        assert!(Prediction::from_probabilities(&[1.5]).is_err());
+    }
+}
@@ -3,8 +3,11 @@
 //! 基于机器学习和 AI 模型的策略实现。
 //! 与 ai-service 服务配合使用。
 
+pub mod ml_signal;           // 机器学习信号策略
+
 // TODO: AI 策略待实现
-// pub mod ml_signal;        // 机器学习信号策略
 // pub mod sentiment;        // 情绪分析策略
 // pub mod pattern;          // 模式识别策略
 // pub mod reinforcement;    // 强化学习策略
+
+pub use ml_signal::MlSignalStrategy;
@@ -0,0 +1,308 @@
+//! # 组合策略 (Ensemble Strategy)
+//!
+//! 订阅成员实例（其他已注册的策略实例）的信号，综合后只产出一个意图：
+//!
+//! - 成员信号：调度器把成员在同一行情事件上产出的单腿意图转交给组合策略（见 `MemberSignal`）
+//! - 信号有效期：每个成员只保留最新一条信号，超过 `signal_ttl_secs`（按行情时间）视为未投票
+//! - 计票：加权投票 / 一致同意 / 元模型（见 `vote`），得到 -1 到 1 的综合分值
+//! - 开仓：`|分值| ≥ entry_threshold` 且方向与持仓不同（合约反手，现货只做多）
+//! - 平仓：持仓方向上的支持度 `分值 × 持仓方向` 低于 `exit_threshold`
+//! - 归因：综合意图附带各成员的投票与贡献，并保留最近 `history_size` 次决策写入检查点
+//!
+//! ## 规则
+//! - ✅ 只综合与组合策略同一交易对的成员信号
+//! - ❌ 成员不再单独下单（单腿意图全部交给组合策略）
+//! - ❌ 不综合多腿意图组与做市报价
+
+pub mod vote;
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+use chrono::Duration;
+use rust_decimal::prelude::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::PositionSnapshot;
+use shared::event::market_event::{MarketEvent, MarketEventData};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::domain::logic::ai::ml_signal::InferenceModel;
+use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
+use crate::domain::model::ensemble::{EnsembleAttribution, EnsembleDecision, MemberSignal};
+use crate::domain::model::market_type::MarketType;
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalType};
+
+pub use vote::{tally, EnsembleMethod, Tally};
+
+/// 组合策略配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EnsembleConfig {
+    /// 成员实例 ID（顺序即元模型的特征顺序）
+    pub members: Vec<Uuid>,
+    /// 组合方法
+    pub method: EnsembleMethod,
+    /// 开仓阈值（综合分值绝对值）
+    pub entry_threshold: Decimal,
+    /// 平仓阈值（持仓方向上的支持度低于此值时平仓）
+    pub exit_threshold: Decimal,
+    /// 成员信号有效期（秒）
+    pub signal_ttl_secs: u64,
+    /// 保留的决策归因条数
+    pub history_size: usize,
+    /// 交易数量
+    pub quantity: Decimal,
+}
+
+impl Default for EnsembleConfig {
+    fn default() -> Self {
+        Self {
+            members: Vec::new(),
+            method: EnsembleMethod::default(),
+            entry_threshold: Decimal::new(5, 1), // 0.5
+            exit_threshold: Decimal::ZERO,
+            signal_ttl_secs: 300,
+            history_size: 100,
+            quantity: Decimal::new(1, 3), // 0.001
+        }
+    }
+}
+
+/// 组合策略状态
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EnsembleState {
+    /// 各成员最新信号
+    pub signals: HashMap<Uuid, MemberSignal>,
+    /// 当前持仓方向（None 为空仓）
+    pub position: Option<SignalType>,
+    /// 最近的决策归因（最新在后）
+    pub history: VecDeque<EnsembleAttribution>,
+}
+
+/// 组合策略
+pub struct EnsembleStrategy {
+    meta: StrategyMeta,
+    config: EnsembleConfig,
+    state: EnsembleState,
+    /// 元模型（仅 `meta_model` 方法）
+    model: Option<Arc<dyn InferenceModel>>,
+}
+
+impl EnsembleStrategy {
+    /// 创建组合策略实例
+    ///
+    /// `meta_model` 方法须注入按成员数加载的模型。
+    pub fn new(
+        instance_id: Uuid,
+        symbol: String,
+        config: EnsembleConfig,
+        market_type: MarketType,
+        model: Option<Arc<dyn InferenceModel>>,
+    ) -> Result<Self> {
+        if config.members.is_empty() {
+            bail!("组合策略至少需要一个成员");
+        }
+        if config.members.contains(&instance_id) {
+            bail!("组合策略不能以自身为成员");
+        }
+        if matches!(config.method, EnsembleMethod::MetaModel { .. }) && model.is_none() {
+            bail!("meta_model 方法需要加载元模型");
+        }
+
+        Ok(Self {
+            meta: StrategyMeta {
+                instance_id,
+                strategy_type: "ensemble".to_string(),
+                market_type,
+                symbol,
+                is_active: false,
+            },
+            config,
+            state: EnsembleState::default(),
+            model,
+        })
+    }
+
+    /// 构造信号
+    fn signal(
+        &self,
+        event: &MarketEvent,
+        signal_type: SignalType,
+        price: Decimal,
+        score: f64,
+        instruction: OrderInstruction,
+    ) -> Signal {
+        Signal {
+            id: Uuid::new_v4(),
+            strategy_id: self.meta.instance_id,
+            symbol: event.symbol.clone(),
+            signal_type,
+            price,
+            quantity: self.config.quantity,
+            confidence: score.abs(),
+            created_at: event.timestamp,
+            instruction,
+        }
+    }
+
+    /// 按综合分值决定动作
+    fn decide(&self, score: f64) -> Option<(SignalType, OrderInstruction)> {
+        let entry = self.config.entry_threshold.to_f64()?;
+        let exit = self.config.exit_threshold.to_f64()?;
+        let target = if score >= entry {
+            Some(SignalType::Buy)
+        } else if score <= -entry {
+            Some(SignalType::Sell)
+        } else {
+            None
+        };
+        let futures = self.meta.market_type.is_futures();
+
+        match (self.state.position, target) {
+            (None, Some(SignalType::Buy)) => Some((SignalType::Buy, OrderInstruction::open())),
+            (None, Some(SignalType::Sell)) if futures => Some((SignalType::Sell, OrderInstruction::open())),
+            (Some(position), Some(target)) if position != target => {
+                if futures {
+                    Some((target, OrderInstruction::flip()))
+                } else {
+                    Some((SignalType::Sell, OrderInstruction::close()))
+                }
+            }
+            (Some(SignalType::Buy), _) if score < exit => Some((SignalType::Sell, OrderInstruction::close())),
+            (Some(SignalType::Sell), _) if -score < exit => Some((SignalType::Buy, OrderInstruction::close())),
+            _ => None,
+        }
+    }
+
+    /// 记录决策归因
+    fn record(&mut self, attribution: EnsembleAttribution) {
+        if self.state.history.len() >= self.config.history_size.max(1) {
+            self.state.history.pop_front();
+        }
+        self.state.history.push_back(attribution);
+    }
+}
+
+/// 行情价格（成交价，深度取买一卖一中间价）
+fn event_price(event: &MarketEvent) -> Option<Decimal> {
+    match &event.data {
+        MarketEventData::Trade(trade) => Some(trade.price),
+        MarketEventData::Depth(depth) => {
+            let (bid, _) = depth.bids.first()?;
+            let (ask, _) = depth.asks.first()?;
+            Some((*bid + *ask) / Decimal::TWO)
+        }
+        _ => None,
+    }
+}
+
+impl Strategy for EnsembleStrategy {
+    fn meta(&self) -> &StrategyMeta {
+        &self.meta
+    }
+
+    fn meta_mut(&mut self) -> &mut StrategyMeta {
+        &mut self.meta
+    }
+
+    /// 组合策略不直接响应行情，只在成员信号到达时决策
+    fn on_market_event(&mut self, _event: &MarketEvent) -> Option<Signal> {
+        None
+    }
+
+    fn ensemble_members(&self) -> Vec<Uuid> {
+        self.config.members.clone()
+    }
+
+    fn on_member_signals(
+        &mut self,
+        event: &MarketEvent,
+        signals: &[MemberSignal],
+    ) -> Option<EnsembleDecision> {
+        if !self.is_active() || event.symbol != self.meta.symbol {
+            return None;
+        }
+
+        let mut updated = false;
+        for signal in signals {
+            if signal.symbol == self.meta.symbol && self.config.members.contains(&signal.instance_id) {
+                self.state.signals.insert(signal.instance_id, signal.clone());
+                updated = true;
+            }
+        }
+        let cutoff = event.timestamp - Duration::seconds(self.config.signal_ttl_secs as i64);
+        self.state.signals.retain(|_, signal| signal.created_at >= cutoff);
+        if !updated {
+            return None;
+        }
+
+        let result = tally(
+            &self.config.method,
+            &self.config.members,
+            &self.state.signals,
+            self.model.as_deref(),
+        );
+        let Tally { score, votes } = match result {
+            Ok(tally) => tally,
+            Err(e) => {
+                warn!(instance_id = %self.meta.instance_id, error = %e, "组合计票失败");
+                return None;
+            }
+        };
+
+        let (signal_type, instruction) = self.decide(score)?;
+        let price = event_price(event)?;
+        self.state.position = match instruction.action {
+            action if action.is_reduce_only() => None,
+            _ => Some(signal_type),
+        };
+
+        let attribution = EnsembleAttribution {
+            method: self.config.method.name().to_string(),
+            score,
+            model_version: self.model.as_ref().map(|m| m.version().to_string()),
+            members: votes,
+        };
+        self.record(attribution.clone());
+        Some(EnsembleDecision {
+            signal: self.signal(event, signal_type, price, score, instruction),
+            attribution,
+        })
+    }
+
+    fn model_version(&self) -> Option<String> {
+        self.model.as_ref().map(|m| m.version().to_string())
+    }
+
+    fn on_position_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {
+        if symbol != self.meta.symbol {
+            return;
+        }
+        self.state.position = if snapshot.is_long() {
+            Some(SignalType::Buy)
+        } else if snapshot.is_short() {
+            Some(SignalType::Sell)
+        } else {
+            None
+        };
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> Result<()> {
+        let mut state: EnsembleState = serde_json::from_value(state)?;
+        // 参数热更新后被移除的成员不再计票
+        state.signals.retain(|id, _| self.config.members.contains(id));
+        self.state = state;
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.state = EnsembleState::default();
+    }
+}
@@ -0,0 +1,203 @@
+//! # 组合投票 (Ensemble Voting)
+//!
+//! 把成员的最新信号合成一个综合分值（-1 到 1，正数看多）：
+//! - 加权投票：`Σ 权重 × 置信度 × 方向 / Σ 权重`，未投票或离场的成员方向为 0
+//! - 一致同意：全部成员同向时为 `方向 × 平均置信度`，否则为 0
+//! - 元模型：以各成员 `置信度 × 方向` 为特征推理方向概率，分值为 `P(涨) - P(跌)`
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::logic::ai::ml_signal::{InferenceModel, Prediction};
+use crate::domain::model::ensemble::{MemberSignal, MemberVote};
+
+/// 组合方法
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum EnsembleMethod {
+    /// 置信度加权投票
+    WeightedVote {
+        /// 成员实例 ID → 权重（未配置的成员权重为 1）
+        #[serde(default)]
+        weights: HashMap<Uuid, f64>,
+    },
+    /// 一致同意
+    Unanimous,
+    /// 元模型
+    MetaModel {
+        /// 模型文件路径（输入维度 = 成员数，顺序与 `members` 一致）
+        model_path: String,
+    },
+}
+
+impl Default for EnsembleMethod {
+    fn default() -> Self {
+        Self::WeightedVote {
+            weights: HashMap::new(),
+        }
+    }
+}
+
+impl EnsembleMethod {
+    /// 方法名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::WeightedVote { .. } => "weighted_vote",
+            Self::Unanimous => "unanimous",
+            Self::MetaModel { .. } => "meta_model",
+        }
+    }
+}
+
+/// 计票结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tally {
+    /// 综合分值
+    pub score: f64,
+    /// 各成员投票（按成员顺序）
+    pub votes: Vec<MemberVote>,
+}
+
+/// 计票
+///
+/// `signals` 只包含未过期的成员信号；元模型方法必须提供 `model`。
+pub fn tally(
+    method: &EnsembleMethod,
+    members: &[Uuid],
+    signals: &HashMap<Uuid, MemberSignal>,
+    model: Option<&dyn InferenceModel>,
+) -> Result<Tally> {
+    let weight = |id: &Uuid| match method {
+        EnsembleMethod::WeightedVote { weights } => weights.get(id).copied().unwrap_or(1.0).max(0.0),
+        _ => 1.0,
+    };
+    // 置信度 × 方向
+    let signed = |id: &Uuid| signals.get(id).map_or(0.0, |s| s.confidence * s.direction());
+
+    let contributions: Vec<Option<f64>> = match method {
+        EnsembleMethod::WeightedVote { .. } => {
+            let total: f64 = members.iter().map(weight).sum();
+            members
+                .iter()
+                .map(|id| Some(if total > 0.0 { weight(id) * signed(id) / total } else { 0.0 }))
+                .collect()
+        }
+        EnsembleMethod::Unanimous => {
+            let first = members.first().and_then(|id| signals.get(id)).and_then(|s| s.side);
+            let agreed = first.is_some()
+                && members
+                    .iter()
+                    .all(|id| signals.get(id).and_then(|s| s.side) == first);
+            let n = members.len() as f64;
+            members
+                .iter()
+                .map(|id| Some(if agreed { signed(id) / n } else { 0.0 }))
+                .collect()
+        }
+        EnsembleMethod::MetaModel { .. } => vec![None; members.len()],
+    };
+
+    let score = match method {
+        EnsembleMethod::MetaModel { .. } => {
+            let model = model.context("元模型未加载")?;
+            let features: Vec<f64> = members.iter().map(signed).collect();
+            let prediction = model
+                .predict(&features)
+                .and_then(|probabilities| Prediction::from_probabilities(&probabilities))
+                .with_context(|| format!("元模型 {} 推理失败", model.version()))?;
+            prediction.up - prediction.down
+        }
+        _ => contributions.iter().flatten().sum(),
+    };
+
+    let votes = members
+        .iter()
+        .zip(contributions)
+        .map(|(id, contribution)| {
+            let signal = signals.get(id);
+            MemberVote {
+                instance_id: *id,
+                side: signal.and_then(|s| s.side),
+                confidence: signal.map_or(0.0, |s| s.confidence),
+                weight: weight(id),
+                contribution,
+                signal_at: signal.map(|s| s.created_at),
+            }
+        })
+        .collect();
+
+    Ok(Tally {
+        score: score.clamp(-1.0, 1.0),
+        votes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::logic::ai::ml_signal::{JsonInferenceModel, JsonModel};
+    use crate::domain::model::strategy_runtime::OrderSide;
+    use chrono::Utc;
+
+    fn signal(instance_id: Uuid, side: Option<OrderSide>, confidence: f64) -> (Uuid, MemberSignal) {
+        let signal = MemberSignal {
+            instance_id,
+            symbol: "BTCUSDT".to_string(),
+            side,
+            confidence,
+            created_at: Utc::now(),
+        };
+        (instance_id, signal)
+    }
+
+    #[test]
+    fn test_tally_by_method() {
+        let members: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        let signals = HashMap::from([
+            signal(members[0], Some(OrderSide::Buy), 0.9),
+            signal(members[1], Some(OrderSide::Buy), 0.6),
+            signal(members[2], Some(OrderSide::Sell), 0.3),
+        ]);
+
+        // 加权投票：(2 × 0.9 + 0.6 - 0.3) / 4
+        let weighted = EnsembleMethod::WeightedVote {
+            weights: HashMap::from([(members[0], 2.0)]),
+        };
+        let result = tally(&weighted, &members, &signals, None).unwrap();
+        assert!((result.score - 2.1 / 4.0).abs() < 1e-12);
+        assert!((result.votes[2].contribution.unwrap() + 0.3 / 4.0).abs() < 1e-12);
+        assert_eq!(result.votes[0].weight, 2.0);
+
+        // 一致同意：有成员反对为 0；全部同向为平均置信度
+        let result = tally(&EnsembleMethod::Unanimous, &members, &signals, None).unwrap();
+        assert_eq!(result.score, 0.0);
+        let agreed = HashMap::from([
+            signal(members[0], Some(OrderSide::Sell), 0.9),
+            signal(members[1], Some(OrderSide::Sell), 0.6),
+            signal(members[2], Some(OrderSide::Sell), 0.3),
+        ]);
+        let result = tally(&EnsembleMethod::Unanimous, &members, &agreed, None).unwrap();
+        assert!((result.score + 0.6).abs() < 1e-12);
+
+        // 元模型：按成员信号推理，缺少模型时报错
+        let meta = EnsembleMethod::MetaModel {
+            model_path: "meta.json".to_string(),
+        };
+        let model = JsonInferenceModel::new(
+            JsonModel::Logistic {
+                weights: vec![4.0, 4.0, 4.0],
+                intercept: 0.0,
+            },
+            "v1".to_string(),
+            3,
+        )
+        .unwrap();
+        let result = tally(&meta, &members, &signals, Some(&model)).unwrap();
+        assert!(result.score > 0.9);
+        assert!(result.votes.iter().all(|v| v.contribution.is_none()));
+        assert!(tally(&meta, &members, &signals, None).is_err());
+    }
+}
@@ -3,19 +3,20 @@
 //! 基于布林带指标的合约突破策略。
 //! 支持杠杆、双向持仓。
 
-use std::collections::VecDeque;
-
 use rust_decimal::prelude::*;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use shared::event::market_event::{MarketEvent, MarketEventData};
 use uuid::Uuid;
 
+use crate::domain::logic::indicator::{Bollinger, Indicator};
 use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
 use crate::domain::model::market_type::{LeverageConfig, MarketType};
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalType};
 
 /// 合约布林带策略配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct FuturesBollingerConfig {
     /// 周期
     pub period: usize,
@@ -42,29 +43,23 @@ impl Default for FuturesBollingerConfig {
 }
 
 /// 合约布林带策略状态
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FuturesBollingerState {
-    /// 价格历史
-    pub price_history: VecDeque<Decimal>,
+    /// 布林带指标
+    pub bands: Bollinger<Decimal>,
     /// 上次信号
     pub last_signal: Option<SignalType>,
 }
 
 impl FuturesBollingerState {
-    pub fn new() -> Self {
+    pub fn new(config: &FuturesBollingerConfig) -> Self {
         Self {
-            price_history: VecDeque::new(),
+            bands: Bollinger::new(config.period, config.std_dev_multiplier),
             last_signal: None,
         }
     }
 }
 
-impl Default for FuturesBollingerState {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 /// 合约布林带策略
 pub struct FuturesBollingerStrategy {
     meta: StrategyMeta,
@@ -94,38 +89,11 @@ impl FuturesBollingerStrategy {
                 symbol,
                 is_active: false,
             },
+            state: FuturesBollingerState::new(&config),
             config,
-            state: FuturesBollingerState::new(),
         }
     }
 
-    /// 计算标准差
-    fn calculate_std_dev(&self, prices: &VecDeque<Decimal>, mean: Decimal) -> Option<Decimal> {
-        if prices.is_empty() {
-            return None;
-        }
-
-        let variance: Decimal = prices
-            .iter()
-            .map(|p| {
-                let diff = *p - mean;
-                diff * diff
-            })
-            .sum::<Decimal>()
-            / Decimal::from(prices.len());
-
-        // 简化的平方根计算（使用迭代法）
-        let mut x = variance;
-        for _ in 0..10 {
-            if x == Decimal::ZERO {
-                break;
-            }
-            x = (x + variance / x) / Decimal::from(2);
-        }
-
-        Some(x)
-    }
-
     /// 计算布林带信号
     fn calculate_signal(&mut self, event: &MarketEvent) -> Option<Signal> {
         let trade = match &event.data {
@@ -135,27 +103,10 @@ impl FuturesBollingerStrategy {
 
         let price = trade.price;
 
-        // 更新价格历史
-        self.state.price_history.push_back(price);
-        if self.state.price_history.len() > self.config.period {
-            self.state.price_history.pop_front();
-        }
-
-        // 需要足够的数据
-        if self.state.price_history.len() < self.config.period {
-            return None;
-        }
-
-        // 计算中轨（移动平均）
-        let middle_band: Decimal = self.state.price_history.iter().sum::<Decimal>()
-            / Decimal::from(self.state.price_history.len());
-
-        // 计算标准差
-        let std_dev = self.calculate_std_dev(&self.state.price_history, middle_band)?;
-
-        // 计算上轨和下轨
-        let upper_band = middle_band + std_dev * self.config.std_dev_multiplier;
-        let lower_band = middle_band - std_dev * self.config.std_dev_multiplier;
+        // 更新布林带（预热期无信号）
+        let bands = self.state.bands.update(price)?;
+        let upper_band = bands.upper;
+        let lower_band = bands.lower;
 
         // 判断信号（合约支持做空）
         let signal_type = if price < lower_band && self.state.last_signal != Some(SignalType::Buy)
@@ -184,6 +135,7 @@ impl FuturesBollingerStrategy {
                 quantity: leveraged_quantity,
                 confidence: 0.75,
                 created_at: event.timestamp,
+                instruction: OrderInstruction::default(),
             });
         }
 
@@ -207,7 +159,25 @@ impl Strategy for FuturesBollingerStrategy {
         self.calculate_signal(event)
     }
 
+    fn state_version(&self) -> u32 {
+        2
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let mut state: FuturesBollingerState = serde_json::from_value(state)?;
+        // 指标按当前配置重建，只回放检查点中的值（周期变化后截取最新部分）
+        let mut fresh = FuturesBollingerState::new(&self.config);
+        fresh.bands.replay(&state.bands);
+        state.bands = fresh.bands;
+        self.state = state;
+        Ok(())
+    }
+
     fn reset(&mut self) {
-        self.state = FuturesBollingerState::new();
+        self.state = FuturesBollingerState::new(&self.config);
     }
 }
@@ -4,19 +4,21 @@
 //! 当价格突破N周期内的最高价时做多，突破最低价时做空。
 //! 支持杠杆、双向持仓。
 
-use std::collections::VecDeque;
-
 use rust_decimal::prelude::*;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::PositionSnapshot;
 use shared::event::market_event::{MarketEvent, MarketEventData};
 use uuid::Uuid;
 
+use crate::domain::logic::indicator::RollingExtremum;
 use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
 use crate::domain::model::market_type::{LeverageConfig, MarketType};
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, OrderType, Signal, SignalType};
 
 /// 突破策略配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct BreakoutConfig {
     /// 回溯周期（用于计算历史高低点）
     pub lookback_period: usize,
@@ -46,13 +48,15 @@ impl Default for BreakoutConfig {
 }
 
 /// 突破策略状态
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BreakoutState {
-    /// 价格历史
-    pub price_history: VecDeque<Decimal>,
-    /// 历史最高价
+    /// 滚动最高价
+    pub highs: RollingExtremum<Decimal>,
+    /// 滚动最低价
+    pub lows: RollingExtremum<Decimal>,
+    /// 通道上沿（此前 N 个价格的最高价）
     pub highest_high: Option<Decimal>,
-    /// 历史最低价
+    /// 通道下沿（此前 N 个价格的最低价）
     pub lowest_low: Option<Decimal>,
     /// 当前持仓方向
     pub current_position: Option<SignalType>,
@@ -63,9 +67,10 @@ pub struct BreakoutState {
 }
 
 impl BreakoutState {
-    pub fn new() -> Self {
+    pub fn new(config: &BreakoutConfig) -> Self {
         Self {
-            price_history: VecDeque::new(),
+            highs: RollingExtremum::max(config.lookback_period),
+            lows: RollingExtremum::min(config.lookback_period),
             highest_high: None,
             lowest_low: None,
             current_position: None,
@@ -75,12 +80,6 @@ impl BreakoutState {
     }
 }
 
-impl Default for BreakoutState {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 /// 突破策略
 pub struct BreakoutStrategy {
     meta: StrategyMeta,
@@ -110,21 +109,11 @@ impl BreakoutStrategy {
                 symbol,
                 is_active: false,
             },
+            state: BreakoutState::new(&config),
             config,
-            state: BreakoutState::new(),
         }
     }
 
-    /// 计算历史高低点
-    fn calculate_high_low(&mut self) {
-        if self.state.price_history.is_empty() {
-            return;
-        }
-
-        self.state.highest_high = self.state.price_history.iter().max().copied();
-        self.state.lowest_low = self.state.price_history.iter().min().copied();
-    }
-
     /// 检查止损
     fn check_stop_loss(&self, current_price: Decimal) -> bool {
         if let (Some(entry_price), Some(position)) =
@@ -181,23 +170,22 @@ impl BreakoutStrategy {
                 quantity: self.config.quantity * leverage_multiplier,
                 confidence: 0.5, // 止损信号置信度较低
                 created_at: event.timestamp,
+                instruction: OrderInstruction::close().with_order_type(OrderType::Market),
             });
         }
 
-        // 更新价格历史
-        self.state.price_history.push_back(price);
-        if self.state.price_history.len() > self.config.lookback_period {
-            self.state.price_history.pop_front();
-        }
+        // 通道取此前 N 个价格的高低点（不含当前价格），再纳入当前价格
+        let channel_ready = self.state.highs.is_full();
+        self.state.highest_high = self.state.highs.value();
+        self.state.lowest_low = self.state.lows.value();
+        self.state.highs.push(price);
+        self.state.lows.push(price);
 
         // 需要足够的历史数据
-        if self.state.price_history.len() < self.config.lookback_period {
+        if !channel_ready {
             return None;
         }
 
-        // 计算历史高低点
-        self.calculate_high_low();
-
         let highest_high = self.state.highest_high?;
         let lowest_low = self.state.lowest_low?;
 
@@ -233,6 +221,7 @@ impl BreakoutStrategy {
                 quantity: self.config.quantity * leverage_multiplier,
                 confidence: 0.75,
                 created_at: event.timestamp,
+                instruction: OrderInstruction::default(),
             });
         }
 
@@ -256,7 +245,47 @@ impl Strategy for BreakoutStrategy {
         self.calculate_signal(event)
     }
 
+    fn on_position_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {
+        if symbol != self.meta.symbol {
+            return;
+        }
+        self.state.current_position = if snapshot.is_long() {
+            Some(SignalType::Buy)
+        } else if snapshot.is_short() {
+            Some(SignalType::Sell)
+        } else {
+            None
+        };
+        self.state.entry_price = (!snapshot.is_flat()).then_some(snapshot.average_price);
+        // 无持仓时允许同方向再次突破入场
+        if snapshot.is_flat() {
+            self.state.last_signal = None;
+        }
+    }
+
+    fn state_version(&self) -> u32 {
+        2
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let mut state: BreakoutState = serde_json::from_value(state)?;
+        // 参数热更新后周期变化的指标窗口无法沿用，重新预热
+        let fresh = BreakoutState::new(&self.config);
+        if state.highs.period() != fresh.highs.period() {
+            state.highs = fresh.highs;
+            state.lows = fresh.lows;
+            state.highest_high = None;
+            state.lowest_low = None;
+        }
+        self.state = state;
+        Ok(())
+    }
+
     fn reset(&mut self) {
-        self.state = BreakoutState::new();
+        self.state = BreakoutState::new(&self.config);
     }
 }
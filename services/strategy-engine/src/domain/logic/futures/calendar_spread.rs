@@ -3,20 +3,25 @@
 //! 利用不同到期日合约之间的价差进行套利。
 //! 当近月合约和远月合约的价差偏离正常范围时进行套利交易。
 //! 支持杠杆。
-
-use std::collections::VecDeque;
+//!
+//! 同时订阅近月和远月合约，开平仓均以两腿信号组输出（远月在前），
+//! 由 trading-engine 作为关联组整体执行。
 
 use rust_decimal::prelude::*;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::PositionSnapshot;
 use shared::event::market_event::{MarketEvent, MarketEventData};
 use uuid::Uuid;
 
+use crate::domain::logic::indicator::{Bollinger, Indicator};
 use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
 use crate::domain::model::market_type::{LeverageConfig, MarketType};
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalGroup, SignalType};
 
 /// 跨期套利策略配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct CalendarSpreadConfig {
     /// 近月合约symbol（例如：BTCUSDT_PERP）
     pub near_contract: String,
@@ -49,14 +54,14 @@ impl Default for CalendarSpreadConfig {
 }
 
 /// 跨期套利策略状态
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CalendarSpreadState {
     /// 近月合约价格
     pub near_price: Option<Decimal>,
     /// 远月合约价格
     pub far_price: Option<Decimal>,
-    /// 价差历史（远月 - 近月）
-    pub spread_history: VecDeque<Decimal>,
+    /// 价差布林带（远月 - 近月，均值 ± k·σ）
+    pub spread_bands: Bollinger<Decimal>,
     /// 是否有持仓
     pub has_position: bool,
     /// 持仓方向（true=做多价差，false=做空价差）
@@ -64,23 +69,17 @@ pub struct CalendarSpreadState {
 }
 
 impl CalendarSpreadState {
-    pub fn new() -> Self {
+    pub fn new(config: &CalendarSpreadConfig) -> Self {
         Self {
             near_price: None,
             far_price: None,
-            spread_history: VecDeque::new(),
+            spread_bands: Bollinger::new(config.spread_period, config.spread_std_multiplier),
             has_position: false,
             is_long_spread: false,
         }
     }
 }
 
-impl Default for CalendarSpreadState {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 /// 跨期套利策略
 pub struct CalendarSpreadStrategy {
     meta: StrategyMeta,
@@ -110,58 +109,61 @@ impl CalendarSpreadStrategy {
                 symbol,
                 is_active: false,
             },
+            state: CalendarSpreadState::new(&config),
             config,
-            state: CalendarSpreadState::new(),
         }
     }
 
-    /// 更新合约价格
-    ///
-    /// 外部需要调用此方法更新近月和远月合约的价格
-    pub fn update_near_price(&mut self, price: Decimal) {
-        self.state.near_price = Some(price);
-    }
-
-    pub fn update_far_price(&mut self, price: Decimal) {
-        self.state.far_price = Some(price);
-    }
+    /// 构造单腿信号
+    fn leg(
+        &self,
+        symbol: &str,
+        signal_type: SignalType,
+        price: Decimal,
+        confidence: f64,
+        event: &MarketEvent,
+        instruction: OrderInstruction,
+    ) -> Signal {
+        let leverage_multiplier =
+            Decimal::from_u32(self.config.leverage.leverage).unwrap_or(Decimal::ONE);
 
-    /// 计算价差的均值和标准差
-    fn calculate_spread_stats(&self) -> Option<(Decimal, Decimal)> {
-        if self.state.spread_history.len() < self.config.spread_period {
-            return None;
+        Signal {
+            id: Uuid::new_v4(),
+            strategy_id: self.meta.instance_id,
+            symbol: symbol.to_string(),
+            signal_type,
+            price,
+            quantity: self.config.quantity * leverage_multiplier,
+            confidence,
+            created_at: event.timestamp,
+            instruction,
         }
+    }
 
-        // 计算均值
-        let sum: Decimal = self.state.spread_history.iter().sum();
-        let mean = sum / Decimal::from(self.state.spread_history.len());
-
-        // 计算标准差
-        let variance: Decimal = self
-            .state
-            .spread_history
-            .iter()
-            .map(|s| {
-                let diff = *s - mean;
-                diff * diff
-            })
-            .sum::<Decimal>()
-            / Decimal::from(self.state.spread_history.len());
-
-        // 简化的平方根计算
-        let mut std_dev = variance;
-        for _ in 0..10 {
-            if std_dev == Decimal::ZERO {
-                break;
-            }
-            std_dev = (std_dev + variance / std_dev) / Decimal::from(2);
-        }
+    /// 构造两腿信号组（远月在前，近月反向）
+    fn spread_legs(
+        &self,
+        far_type: SignalType,
+        far_price: Decimal,
+        near_price: Decimal,
+        confidence: f64,
+        event: &MarketEvent,
+        instruction: OrderInstruction,
+    ) -> SignalGroup {
+        let near_type = match far_type {
+            SignalType::Buy => SignalType::Sell,
+            _ => SignalType::Buy,
+        };
 
-        Some((mean, std_dev))
+        let legs = vec![
+            self.leg(&self.config.far_contract, far_type, far_price, confidence, event, instruction.clone()),
+            self.leg(&self.config.near_contract, near_type, near_price, confidence, event, instruction),
+        ];
+        SignalGroup::new(self.meta.instance_id, legs, event.timestamp)
     }
 
-    /// 计算套利信号
-    fn calculate_signal(&mut self, event: &MarketEvent) -> Option<Signal> {
+    /// 计算套利信号组
+    fn calculate_legs(&mut self, event: &MarketEvent) -> Option<SignalGroup> {
         let trade = match &event.data {
             MarketEventData::Trade(trade) => trade,
             _ => return None,
@@ -172,6 +174,8 @@ impl CalendarSpreadStrategy {
             self.state.near_price = Some(trade.price);
         } else if event.symbol == self.config.far_contract {
             self.state.far_price = Some(trade.price);
+        } else {
+            return None;
         }
 
         // 需要两个合约的价格
@@ -181,26 +185,11 @@ impl CalendarSpreadStrategy {
         // 计算价差（远月 - 近月）
         let spread = far_price - near_price;
 
-        // 更新价差历史
-        self.state.spread_history.push_back(spread);
-        if self.state.spread_history.len() > self.config.spread_period {
-            self.state.spread_history.pop_front();
-        }
-
-        // 需要足够的历史数据
-        if self.state.spread_history.len() < self.config.spread_period {
-            return None;
-        }
-
-        // 计算价差统计
-        let (mean_spread, std_spread) = self.calculate_spread_stats()?;
-
-        // 计算上下界
-        let upper_bound = mean_spread + std_spread * self.config.spread_std_multiplier;
-        let lower_bound = mean_spread - std_spread * self.config.spread_std_multiplier;
-
-        let leverage_multiplier =
-            Decimal::from_u32(self.config.leverage.leverage).unwrap_or(Decimal::ONE);
+        // 更新价差布林带（预热期无信号）
+        let bands = self.state.spread_bands.update(spread)?;
+        let mean_spread = bands.middle;
+        let upper_bound = bands.upper;
+        let lower_bound = bands.lower;
 
         // 无持仓时，判断开仓信号
         if !self.state.has_position {
@@ -209,31 +198,27 @@ impl CalendarSpreadStrategy {
                 self.state.has_position = true;
                 self.state.is_long_spread = false;
 
-                return Some(Signal {
-                    id: Uuid::new_v4(),
-                    strategy_id: self.meta.instance_id,
-                    symbol: self.config.far_contract.clone(),
-                    signal_type: SignalType::Sell, // 卖远月
-                    price: far_price,
-                    quantity: self.config.quantity * leverage_multiplier,
-                    confidence: 0.8,
-                    created_at: event.timestamp,
-                });
+                return Some(self.spread_legs(
+                    SignalType::Sell,
+                    far_price,
+                    near_price,
+                    0.8,
+                    event,
+                    OrderInstruction::default(),
+                ));
             } else if spread < lower_bound {
                 // 价差过小，做多价差（买远月，卖近月）
                 self.state.has_position = true;
                 self.state.is_long_spread = true;
 
-                return Some(Signal {
-                    id: Uuid::new_v4(),
-                    strategy_id: self.meta.instance_id,
-                    symbol: self.config.far_contract.clone(),
-                    signal_type: SignalType::Buy, // 买远月
-                    price: far_price,
-                    quantity: self.config.quantity * leverage_multiplier,
-                    confidence: 0.8,
-                    created_at: event.timestamp,
-                });
+                return Some(self.spread_legs(
+                    SignalType::Buy,
+                    far_price,
+                    near_price,
+                    0.8,
+                    event,
+                    OrderInstruction::default(),
+                ));
             }
         } else {
             // 有持仓时，判断平仓信号（价差回归均值）
@@ -248,22 +233,20 @@ impl CalendarSpreadStrategy {
             if should_close {
                 self.state.has_position = false;
 
-                let signal_type = if self.state.is_long_spread {
-                    SignalType::Sell // 平多仓
+                let far_type = if self.state.is_long_spread {
+                    SignalType::Sell // 平远月多仓
                 } else {
-                    SignalType::Buy // 平空仓
+                    SignalType::Buy // 平远月空仓
                 };
 
-                return Some(Signal {
-                    id: Uuid::new_v4(),
-                    strategy_id: self.meta.instance_id,
-                    symbol: self.config.far_contract.clone(),
-                    signal_type,
-                    price: far_price,
-                    quantity: self.config.quantity * leverage_multiplier,
-                    confidence: 0.85,
-                    created_at: event.timestamp,
-                });
+                return Some(self.spread_legs(
+                    far_type,
+                    far_price,
+                    near_price,
+                    0.85,
+                    event,
+                    OrderInstruction::close(),
+                ));
             }
         }
 
@@ -280,14 +263,56 @@ impl Strategy for CalendarSpreadStrategy {
         &mut self.meta
     }
 
+    /// 单信号接口只返回远月腿（近月腿需通过 `on_market_event_legs` 获取）
     fn on_market_event(&mut self, event: &MarketEvent) -> Option<Signal> {
+        self.on_market_event_legs(event)
+            .and_then(|group| group.legs.into_iter().next())
+    }
+
+    fn on_market_event_legs(&mut self, event: &MarketEvent) -> Option<SignalGroup> {
         if !self.is_active() {
             return None;
         }
-        self.calculate_signal(event)
+        self.calculate_legs(event)
+    }
+
+    fn subscriptions(&self) -> Vec<String> {
+        vec![
+            self.config.near_contract.clone(),
+            self.config.far_contract.clone(),
+        ]
+    }
+
+    fn on_position_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {
+        // 远月腿方向即价差方向，以远月持仓为准
+        if symbol != self.config.far_contract {
+            return;
+        }
+        self.state.has_position = !snapshot.is_flat();
+        if !snapshot.is_flat() {
+            self.state.is_long_spread = snapshot.is_long();
+        }
+    }
+
+    fn state_version(&self) -> u32 {
+        2
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let mut state: CalendarSpreadState = serde_json::from_value(state)?;
+        // 指标按当前配置重建，只回放检查点中的值（周期变化后截取最新部分）
+        let mut fresh = CalendarSpreadState::new(&self.config);
+        fresh.spread_bands.replay(&state.spread_bands);
+        state.spread_bands = fresh.spread_bands;
+        self.state = state;
+        Ok(())
     }
 
     fn reset(&mut self) {
-        self.state = CalendarSpreadState::new();
+        self.state = CalendarSpreadState::new(&self.config);
     }
 }
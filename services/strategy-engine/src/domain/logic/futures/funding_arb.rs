@@ -2,18 +2,29 @@
 //!
 //! 合约专属策略：利用资金费率进行套利。
 //! 当资金费率为正时做空，为负时做多。
+//!
+//! `entry_lead_minutes` > 0 时改为定时入场：在每个资金费结算前 N 分钟检查费率并开仓，
+//! 行情事件只负责平仓判断。
 
 use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::{PositionSnapshot, StrategyFill};
 use shared::event::market_event::{MarketEvent, MarketEventData};
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
 use crate::domain::model::market_type::{LeverageConfig, MarketType};
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalGroup, SignalType};
+use crate::domain::model::timer::{TimerEvent, TimerSchedule, TimerSpec};
+
+/// 定时入场的定时器名称
+const ENTRY_TIMER: &str = "funding_entry";
 
 /// 资金费率套利策略配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct FundingArbConfig {
     /// 资金费率阈值（绝对值，如 0.001 表示 0.1%）
     pub funding_rate_threshold: Decimal,
@@ -23,10 +34,30 @@ pub struct FundingArbConfig {
     pub leverage: LeverageConfig,
     /// 最大持仓时间（小时）
     pub max_hold_hours: u32,
+    /// 资金费结算间隔（小时，按 UTC 整点对齐）
+    pub funding_interval_hours: u32,
+    /// 结算前多少分钟定时入场（0 表示行情到达即评估入场）
+    pub entry_lead_minutes: u32,
+}
+
+impl Default for FundingArbConfig {
+    fn default() -> Self {
+        Self {
+            funding_rate_threshold: Decimal::new(1, 3), // 0.1%
+            quantity: Decimal::new(1, 3),               // 0.001
+            leverage: LeverageConfig {
+                leverage: 3,
+                margin_type: crate::domain::model::market_type::MarginType::Cross,
+            },
+            max_hold_hours: 8,
+            funding_interval_hours: 8,
+            entry_lead_minutes: 0,
+        }
+    }
 }
 
 /// 资金费率套利策略状态
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FundingArbState {
     /// 当前资金费率
     pub current_funding_rate: Option<Decimal>,
@@ -38,6 +69,9 @@ pub struct FundingArbState {
     pub is_long: bool,
     /// 入场时间戳
     pub entry_timestamp: Option<i64>,
+    /// 最新成交价（定时入场使用）
+    #[serde(default)]
+    pub last_price: Option<Decimal>,
 }
 
 impl FundingArbState {
@@ -49,6 +83,7 @@ impl FundingArbState {
             has_position: false,
             is_long: false,
             entry_timestamp: None,
+            last_price: None,
         }
     }
 }
@@ -107,6 +142,7 @@ impl FundingArbStrategy {
             MarketEventData::Trade(trade) => trade,
             _ => return None,
         };
+        self.state.last_price = Some(trade.price);
 
         // 需要资金费率数据
         let funding_rate = self.state.current_funding_rate?;
@@ -146,43 +182,18 @@ impl FundingArbStrategy {
                         quantity: self.config.quantity * leverage_multiplier,
                         confidence: 0.8, // 超时平仓置信度较低
                         created_at: event.timestamp,
+                        instruction: OrderInstruction::close(),
                     });
                 }
             }
         }
 
-        // 无持仓时，根据资金费率开仓
+        // 无持仓时，根据资金费率开仓（定时入场模式下由 on_timer 负责）
         if !self.state.has_position {
-            let signal_type = if funding_rate > threshold {
-                // 资金费率为正且超过阈值，做空（收取资金费）
-                self.state.is_long = false;
-                Some(SignalType::Sell)
-            } else if funding_rate < -threshold {
-                // 资金费率为负且超过阈值，做多（收取资金费）
-                self.state.is_long = true;
-                Some(SignalType::Buy)
-            } else {
-                None
-            };
-
-            if signal_type.is_some() {
-                self.state.has_position = true;
-                self.state.entry_timestamp = Some(current_ts);
+            if self.config.entry_lead_minutes > 0 {
+                return None;
             }
-
-            let leverage_multiplier = Decimal::from_u32(self.config.leverage.leverage)
-                .unwrap_or(Decimal::ONE);
-
-            return signal_type.map(|st| Signal {
-                id: Uuid::new_v4(),
-                strategy_id: self.meta.instance_id,
-                symbol: event.symbol.clone(),
-                signal_type: st,
-                price: trade.price,
-                quantity: self.config.quantity * leverage_multiplier,
-                confidence: 1.0,
-                created_at: event.timestamp,
-            });
+            return self.entry_signal(&event.symbol, trade.price, event.timestamp);
         }
 
         // 有持仓时，检查是否需要反向平仓
@@ -217,12 +228,57 @@ impl FundingArbStrategy {
                     quantity: self.config.quantity * leverage_multiplier,
                     confidence: 0.9,
                     created_at: event.timestamp,
+                    instruction: OrderInstruction::close(),
                 });
             }
         }
 
         None
     }
+
+    /// 按资金费率生成开仓信号
+    fn entry_signal(
+        &mut self,
+        symbol: &str,
+        price: Decimal,
+        timestamp: DateTime<Utc>,
+    ) -> Option<Signal> {
+        let funding_rate = self.state.current_funding_rate?;
+        if self.config.quantity <= Decimal::ZERO {
+            return None;
+        }
+        let threshold = self.config.funding_rate_threshold;
+
+        let signal_type = if funding_rate > threshold {
+            // 资金费率为正且超过阈值，做空（收取资金费）
+            self.state.is_long = false;
+            SignalType::Sell
+        } else if funding_rate < -threshold {
+            // 资金费率为负且超过阈值，做多（收取资金费）
+            self.state.is_long = true;
+            SignalType::Buy
+        } else {
+            return None;
+        };
+
+        self.state.has_position = true;
+        self.state.entry_timestamp = Some(timestamp.timestamp());
+
+        let leverage_multiplier =
+            Decimal::from_u32(self.config.leverage.leverage).unwrap_or(Decimal::ONE);
+
+        Some(Signal {
+            id: Uuid::new_v4(),
+            strategy_id: self.meta.instance_id,
+            symbol: symbol.to_string(),
+            signal_type,
+            price,
+            quantity: self.config.quantity * leverage_multiplier,
+            confidence: 1.0,
+            created_at: timestamp,
+            instruction: OrderInstruction::default(),
+        })
+    }
 }
 
 impl Strategy for FundingArbStrategy {
@@ -241,6 +297,59 @@ impl Strategy for FundingArbStrategy {
         self.calculate_signal(event)
     }
 
+    fn timers(&self) -> Vec<TimerSpec> {
+        if self.config.entry_lead_minutes == 0 || self.config.funding_interval_hours == 0 {
+            return Vec::new();
+        }
+        let every_secs = u64::from(self.config.funding_interval_hours) * 3600;
+        let lead_secs = i64::from(self.config.entry_lead_minutes) * 60;
+        vec![TimerSpec::new(
+            ENTRY_TIMER,
+            TimerSchedule::every_with_offset(every_secs, -lead_secs),
+        )]
+    }
+
+    fn on_timer(&mut self, timer: &TimerEvent) -> Option<SignalGroup> {
+        if !self.is_active() || timer.name != ENTRY_TIMER || self.state.has_position {
+            return None;
+        }
+        let price = self.state.last_price?;
+        let symbol = self.meta.symbol.clone();
+        self.entry_signal(&symbol, price, timer.fired_at)
+            .map(SignalGroup::single)
+    }
+
+    fn on_fill(&mut self, symbol: &str, fill: &StrategyFill) {
+        if symbol != self.meta.symbol {
+            return;
+        }
+        // 持仓时间从首笔真实成交开始计算
+        if self.state.entry_timestamp.is_none() {
+            self.state.entry_timestamp = Some(fill.fill_time.timestamp());
+        }
+    }
+
+    fn on_position_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {
+        if symbol != self.meta.symbol {
+            return;
+        }
+        self.state.has_position = !snapshot.is_flat();
+        if snapshot.is_flat() {
+            self.state.entry_timestamp = None;
+        } else {
+            self.state.is_long = snapshot.is_long();
+        }
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        self.state = serde_json::from_value(state)?;
+        Ok(())
+    }
+
     fn reset(&mut self) {
         self.state = FundingArbState::new();
     }
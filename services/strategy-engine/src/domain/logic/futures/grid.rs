@@ -5,15 +5,18 @@
 
 use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::PositionSnapshot;
 use shared::event::market_event::{MarketEvent, MarketEventData};
 use uuid::Uuid;
 
 use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
 use crate::domain::model::market_type::{LeverageConfig, MarketType, PositionSide};
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalType};
 
 /// 合约网格策略配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct FuturesGridConfig {
     /// 价格上界
     pub upper_price: Decimal,
@@ -24,13 +27,15 @@ pub struct FuturesGridConfig {
     /// 每格交易数量
     pub quantity_per_grid: Decimal,
     /// 杠杆配置
+    #[serde(default)]
     pub leverage: LeverageConfig,
     /// 持仓方向（单向/双向）
+    #[serde(default)]
     pub position_side: PositionSide,
 }
 
 /// 合约网格策略状态
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FuturesGridState {
     /// 上一次价格所在网格索引
     pub last_grid_index: Option<i32>,
@@ -171,6 +176,7 @@ impl FuturesGridStrategy {
             quantity: leveraged_quantity,
             confidence: 1.0,
             created_at: event.timestamp,
+            instruction: OrderInstruction::default(),
         })
     }
 }
@@ -191,6 +197,23 @@ impl Strategy for FuturesGridStrategy {
         self.calculate_signal(event)
     }
 
+    fn on_position_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {
+        if symbol != self.meta.symbol {
+            return;
+        }
+        self.state.long_position = snapshot.quantity.max(Decimal::ZERO);
+        self.state.short_position = (-snapshot.quantity).max(Decimal::ZERO);
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        self.state = serde_json::from_value(state)?;
+        Ok(())
+    }
+
     fn reset(&mut self) {
         self.state = FuturesGridState::new();
     }
@@ -5,15 +5,18 @@
 
 use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use shared::event::market_event::{MarketEvent, MarketEventData};
 use uuid::Uuid;
 
+use crate::domain::logic::indicator::{Indicator, Macd};
 use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
 use crate::domain::model::market_type::{LeverageConfig, MarketType};
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalType};
 
 /// 合约 MACD 配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct FuturesMacdConfig {
     /// 快线周期
     pub fast_period: usize,
@@ -43,41 +46,24 @@ impl Default for FuturesMacdConfig {
 }
 
 /// 合约 MACD 状态
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FuturesMacdState {
-    /// 价格历史
-    pub price_history: Vec<Decimal>,
-    /// MACD 历史
-    pub macd_history: Vec<Decimal>,
-    /// 快线 EMA
-    pub fast_ema: Option<Decimal>,
-    /// 慢线 EMA
-    pub slow_ema: Option<Decimal>,
-    /// 信号线
-    pub signal_line: Option<Decimal>,
-    /// 上一次柱状图值
+    /// MACD 指标
+    pub macd: Macd<Decimal>,
+    /// 上一次柱状图
     pub last_histogram: Option<Decimal>,
 }
 
 impl FuturesMacdState {
-    pub fn new() -> Self {
+    /// 创建初始状态 (Create Initial State)
+    pub fn new(config: &FuturesMacdConfig) -> Self {
         Self {
-            price_history: Vec::new(),
-            macd_history: Vec::new(),
-            fast_ema: None,
-            slow_ema: None,
-            signal_line: None,
+            macd: Macd::new(config.fast_period, config.slow_period, config.signal_period),
             last_histogram: None,
         }
     }
 }
 
-impl Default for FuturesMacdState {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 /// 合约 MACD 策略
 pub struct FuturesMacdStrategy {
     meta: StrategyMeta,
@@ -107,8 +93,8 @@ impl FuturesMacdStrategy {
                 symbol,
                 is_active: false,
             },
+            state: FuturesMacdState::new(&config),
             config,
-            state: FuturesMacdState::new(),
         }
     }
 
@@ -131,64 +117,9 @@ impl FuturesMacdStrategy {
         }
 
         let price = trade.price;
-        let max_period = self.config.fast_period.max(self.config.slow_period);
-
-        // 更新价格历史
-        self.state.price_history.push(price);
-        if self.state.price_history.len() > max_period {
-            let overflow = self.state.price_history.len() - max_period;
-            self.state.price_history.drain(0..overflow);
-        }
-
-        // 计算快线 EMA
-        let fast_ema = match self.state.fast_ema {
-            Some(prev) => update_ema(price, prev, self.config.fast_period)?,
-            None => {
-                if self.state.price_history.len() < self.config.fast_period {
-                    return None;
-                }
-                let start = self.state.price_history.len() - self.config.fast_period;
-                average(&self.state.price_history[start..])?
-            }
-        };
-
-        // 计算慢线 EMA
-        let slow_ema = match self.state.slow_ema {
-            Some(prev) => update_ema(price, prev, self.config.slow_period)?,
-            None => {
-                if self.state.price_history.len() < self.config.slow_period {
-                    return None;
-                }
-                let start = self.state.price_history.len() - self.config.slow_period;
-                average(&self.state.price_history[start..])?
-            }
-        };
 
-        self.state.fast_ema = Some(fast_ema);
-        self.state.slow_ema = Some(slow_ema);
-
-        // 计算 MACD 线
-        let macd_line = fast_ema - slow_ema;
-        self.state.macd_history.push(macd_line);
-        if self.state.macd_history.len() > self.config.signal_period {
-            let overflow = self.state.macd_history.len() - self.config.signal_period;
-            self.state.macd_history.drain(0..overflow);
-        }
-
-        // 计算信号线
-        let signal_line = match self.state.signal_line {
-            Some(prev) => update_ema(macd_line, prev, self.config.signal_period)?,
-            None => {
-                if self.state.macd_history.len() < self.config.signal_period {
-                    return None;
-                }
-                average(&self.state.macd_history)?
-            }
-        };
-        self.state.signal_line = Some(signal_line);
-
-        // 计算柱状图（MACD - Signal）
-        let histogram = macd_line - signal_line;
+        // 更新 MACD（预热期无信号）
+        let histogram = self.state.macd.update(price)?.histogram;
         let last_histogram = self.state.last_histogram;
         self.state.last_histogram = Some(histogram);
 
@@ -220,6 +151,7 @@ impl FuturesMacdStrategy {
             quantity: leveraged_quantity,
             confidence: 0.85,
             created_at: event.timestamp,
+            instruction: OrderInstruction::default(),
         })
     }
 }
@@ -240,30 +172,27 @@ impl Strategy for FuturesMacdStrategy {
         self.calculate_signal(event)
     }
 
-    fn reset(&mut self) {
-        self.state = FuturesMacdState::new();
+    fn state_version(&self) -> u32 {
+        2
     }
-}
 
-/// 计算平均值
-fn average(values: &[Decimal]) -> Option<Decimal> {
-    if values.is_empty() {
-        return None;
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
     }
-    let sum = values.iter().fold(Decimal::ZERO, |acc, value| acc + *value);
-    let count = Decimal::from_usize(values.len())?;
-    if count == Decimal::ZERO {
-        return None;
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let mut state: FuturesMacdState = serde_json::from_value(state)?;
+        // 参数热更新后周期变化的指标窗口无法沿用，重新预热
+        let fresh = FuturesMacdState::new(&self.config);
+        if state.macd.periods() != fresh.macd.periods() {
+            state.macd = fresh.macd;
+            state.last_histogram = None;
+        }
+        self.state = state;
+        Ok(())
     }
-    Some(sum / count)
-}
 
-/// 更新 EMA
-fn update_ema(current: Decimal, previous: Decimal, period: usize) -> Option<Decimal> {
-    if period == 0 {
-        return None;
+    fn reset(&mut self) {
+        self.state = FuturesMacdState::new(&self.config);
     }
-    let period_decimal = Decimal::from_usize(period)?;
-    let multiplier = Decimal::from_u32(2)? / (period_decimal + Decimal::ONE);
-    Some((current - previous) * multiplier + previous)
 }
@@ -5,15 +5,19 @@
 
 use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::PositionSnapshot;
 use shared::event::market_event::{MarketEvent, MarketEventData};
 use uuid::Uuid;
 
+use crate::domain::logic::indicator::{Indicator, Sma};
 use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
 use crate::domain::model::market_type::{LeverageConfig, MarketType, PositionSide};
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalType};
 
 /// 合约均值回归策略配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct FuturesMeanReversionConfig {
     /// 移动平均窗口大小
     pub window_size: usize,
@@ -27,11 +31,23 @@ pub struct FuturesMeanReversionConfig {
     pub position_side: PositionSide,
 }
 
+impl Default for FuturesMeanReversionConfig {
+    fn default() -> Self {
+        Self {
+            window_size: 20,
+            threshold_percent: Decimal::new(2, 2), // 2%
+            quantity: Decimal::new(1, 3),          // 0.001
+            leverage: LeverageConfig::default(),
+            position_side: PositionSide::Both,
+        }
+    }
+}
+
 /// 合约均值回归策略状态
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FuturesMeanReversionState {
-    /// 历史价格队列
-    pub price_history: Vec<Decimal>,
+    /// 移动平均指标
+    pub sma: Sma<Decimal>,
     /// 当前持仓方向
     pub current_side: Option<PositionSide>,
     /// 当前持仓数量
@@ -40,21 +56,15 @@ pub struct FuturesMeanReversionState {
 
 impl FuturesMeanReversionState {
     /// 创建初始状态
-    pub fn new() -> Self {
+    pub fn new(config: &FuturesMeanReversionConfig) -> Self {
         Self {
-            price_history: Vec::new(),
+            sma: Sma::new(config.window_size),
             current_side: None,
             current_position: Decimal::ZERO,
         }
     }
 }
 
-impl Default for FuturesMeanReversionState {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 /// 合约均值回归策略
 pub struct FuturesMeanReversionStrategy {
     meta: StrategyMeta,
@@ -84,8 +94,8 @@ impl FuturesMeanReversionStrategy {
                 symbol,
                 is_active: false,
             },
+            state: FuturesMeanReversionState::new(&config),
             config,
-            state: FuturesMeanReversionState::new(),
         }
     }
 
@@ -104,30 +114,8 @@ impl FuturesMeanReversionStrategy {
             return None;
         }
 
-        // 更新价格历史
-        self.state.price_history.push(trade.price);
-        if self.state.price_history.len() > self.config.window_size {
-            let overflow = self.state.price_history.len() - self.config.window_size;
-            self.state.price_history.drain(0..overflow);
-        }
-
-        // 窗口未满，不产生信号
-        if self.state.price_history.len() < self.config.window_size {
-            return None;
-        }
-
-        // 计算移动平均
-        let sum = self
-            .state
-            .price_history
-            .iter()
-            .fold(Decimal::ZERO, |acc, price| acc + *price);
-        let window_size = Decimal::from_usize(self.config.window_size)?;
-        if window_size == Decimal::ZERO {
-            return None;
-        }
-
-        let moving_average = sum / window_size;
+        // 更新移动平均（窗口未满不产生信号）
+        let moving_average = self.state.sma.update(trade.price)?;
         if moving_average == Decimal::ZERO {
             return None;
         }
@@ -161,6 +149,7 @@ impl FuturesMeanReversionStrategy {
             quantity: leveraged_quantity,
             confidence: 1.0,
             created_at: event.timestamp,
+            instruction: OrderInstruction::default(),
         })
     }
 }
@@ -181,7 +170,39 @@ impl Strategy for FuturesMeanReversionStrategy {
         self.calculate_signal(event)
     }
 
+    fn on_position_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {
+        if symbol != self.meta.symbol {
+            return;
+        }
+        self.state.current_position = snapshot.quantity.abs();
+        self.state.current_side = if snapshot.is_long() {
+            Some(PositionSide::Long)
+        } else if snapshot.is_short() {
+            Some(PositionSide::Short)
+        } else {
+            None
+        };
+    }
+
+    fn state_version(&self) -> u32 {
+        2
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let mut state: FuturesMeanReversionState = serde_json::from_value(state)?;
+        // 指标按当前配置重建，只回放检查点中的价格（周期变化后截取最新部分）
+        let mut fresh = FuturesMeanReversionState::new(&self.config);
+        fresh.sma.replay(&state.sma);
+        state.sma = fresh.sma;
+        self.state = state;
+        Ok(())
+    }
+
     fn reset(&mut self) {
-        self.state = FuturesMeanReversionState::new();
+        self.state = FuturesMeanReversionState::new(&self.config);
     }
 }
@@ -13,6 +13,7 @@ pub mod trend_following;
 pub mod breakout;
 pub mod reversal;
 pub mod calendar_spread;
+pub mod pairs_trading;
 
 pub use grid::FuturesGridStrategy;
 pub use mean::FuturesMeanReversionStrategy;
@@ -24,3 +25,4 @@ pub use trend_following::TrendFollowingStrategy;
 pub use breakout::BreakoutStrategy;
 pub use reversal::ReversalStrategy;
 pub use calendar_spread::CalendarSpreadStrategy;
+pub use pairs_trading::PairsTradingStrategy;
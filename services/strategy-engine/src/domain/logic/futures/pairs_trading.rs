@@ -0,0 +1,524 @@
+//! # 配对交易策略 (Pairs Trading Strategy)
+//!
+//! 基于协整关系的跨品种统计套利（与跨期套利不同，两腿可以是不同标的）。
+//!
+//! - 对冲比率：A = α + β·B，用滚动最小二乘或卡尔曼滤波估计 β
+//! - 价差：A − (α + β·B)，按滚动均值 / 标准差计算 z 分数
+//! - 开仓：|z| 超过入场阈值且价差均值回归（半衰期有效）时建立价差头寸
+//! - 平仓：|z| 回到离场阈值以内；持仓超过 半衰期 × 倍数 时按时间止损平仓
+//! - 结构破裂：|z| 超过止损阈值时平仓，并在 |z| 回到离场阈值以内之前不再开仓
+//!
+//! 同时订阅两个合约，开平仓均以两腿信号组输出（A 腿在前），
+//! 由 trading-engine 作为关联组整体执行。B 腿数量 = A 腿数量 × β。
+//! 支持杠杆。
+
+use rust_decimal::prelude::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::PositionSnapshot;
+use shared::event::market_event::{MarketEvent, MarketEventData};
+use uuid::Uuid;
+
+use crate::domain::logic::indicator::{
+    Indicator, KalmanRegression, RegressionOutput, RollingOls, RollingWindow,
+};
+use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
+use crate::domain::model::market_type::{LeverageConfig, MarketType};
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalGroup, SignalType};
+
+/// 对冲比率估计方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HedgeRatioMethod {
+    /// 滚动最小二乘
+    Ols,
+    /// 卡尔曼滤波
+    Kalman,
+}
+
+/// 配对交易策略配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PairsTradingConfig {
+    /// A 腿合约（因变量）
+    pub symbol_a: String,
+    /// B 腿合约（自变量，对冲腿）
+    pub symbol_b: String,
+    /// 对冲比率估计方法
+    pub hedge_method: HedgeRatioMethod,
+    /// 回看周期（最小二乘窗口、价差 z 分数与半衰期的统计窗口，单位为价格更新次数）
+    pub lookback: usize,
+    /// 卡尔曼滤波过程噪声比例 δ
+    pub kalman_delta: Decimal,
+    /// 卡尔曼滤波观测噪声方差
+    pub kalman_observation_variance: Decimal,
+    /// 入场 z 分数
+    pub entry_z: Decimal,
+    /// 离场 z 分数
+    pub exit_z: Decimal,
+    /// 结构破裂止损 z 分数
+    pub stop_z: Decimal,
+    /// 最长持仓 = 半衰期 × 该倍数
+    pub half_life_multiple: Decimal,
+    /// 半衰期上限（价格更新次数），超过时视为不均值回归
+    pub max_half_life: Decimal,
+    /// A 腿交易数量
+    pub quantity: Decimal,
+    /// 杠杆配置
+    pub leverage: LeverageConfig,
+}
+
+impl Default for PairsTradingConfig {
+    fn default() -> Self {
+        Self {
+            symbol_a: "BTCUSDT".to_string(),
+            symbol_b: "ETHUSDT".to_string(),
+            hedge_method: HedgeRatioMethod::Ols,
+            lookback: 100,
+            kalman_delta: Decimal::new(1, 4), // 0.0001
+            kalman_observation_variance: Decimal::new(1, 3), // 0.001
+            entry_z: Decimal::from(2),
+            exit_z: Decimal::new(5, 1), // 0.5
+            stop_z: Decimal::from(4),
+            half_life_multiple: Decimal::from(3),
+            max_half_life: Decimal::from(100),
+            quantity: Decimal::new(1, 3), // 0.001
+            leverage: LeverageConfig {
+                leverage: 3,
+                margin_type: crate::domain::model::market_type::MarginType::Cross,
+            },
+        }
+    }
+}
+
+/// 对冲比率估计器
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum HedgeEstimator {
+    /// 滚动最小二乘
+    Ols(RollingOls<f64>),
+    /// 卡尔曼滤波
+    Kalman(KalmanRegression<f64>),
+}
+
+impl HedgeEstimator {
+    fn new(config: &PairsTradingConfig) -> Self {
+        match config.hedge_method {
+            HedgeRatioMethod::Ols => HedgeEstimator::Ols(RollingOls::new(config.lookback)),
+            HedgeRatioMethod::Kalman => HedgeEstimator::Kalman(KalmanRegression::new(
+                config.kalman_delta.to_f64().unwrap_or(0.0),
+                config.kalman_observation_variance.to_f64().unwrap_or(0.0),
+                config.lookback,
+            )),
+        }
+    }
+
+    /// 输入 (B 价格, A 价格)，返回最新的对冲关系
+    fn update(&mut self, b: f64, a: f64) -> Option<RegressionOutput<f64>> {
+        match self {
+            HedgeEstimator::Ols(ols) => ols.update((b, a)),
+            HedgeEstimator::Kalman(kalman) => kalman.update((b, a)).map(|o| o.regression),
+        }
+    }
+
+    /// 按当前配置重建（参数热更新 / 热重启后沿用已累积的样本）
+    ///
+    /// OLS 回放窗口内的样本；卡尔曼滤波参数一致时沿用已收敛的状态。
+    /// 估计方法或卡尔曼参数变化时返回 `None`，需要整体重新预热。
+    fn rebuild(self, config: &PairsTradingConfig) -> Option<Self> {
+        match (self, config.hedge_method) {
+            (HedgeEstimator::Ols(stored), HedgeRatioMethod::Ols) => {
+                let mut ols = RollingOls::new(config.lookback);
+                ols.replay(&stored);
+                Some(HedgeEstimator::Ols(ols))
+            }
+            (HedgeEstimator::Kalman(kalman), HedgeRatioMethod::Kalman)
+                if Some(kalman.delta()) == config.kalman_delta.to_f64()
+                    && Some(kalman.observation_variance())
+                        == config.kalman_observation_variance.to_f64() =>
+            {
+                Some(HedgeEstimator::Kalman(kalman.with_warmup(config.lookback)))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// 价差头寸
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PairPosition {
+    /// true = 做多价差（买 A 卖 B），false = 做空价差
+    pub long_spread: bool,
+    /// A 腿持仓数量
+    pub quantity_a: Decimal,
+    /// B 腿持仓数量
+    pub quantity_b: Decimal,
+    /// 已持有的价格更新次数
+    pub held: u32,
+    /// 最长持有次数（半衰期 × 倍数）
+    pub max_hold: Option<u32>,
+}
+
+/// 配对交易策略状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PairsTradingState {
+    /// A 腿最新价格
+    pub price_a: Option<Decimal>,
+    /// B 腿最新价格
+    pub price_b: Option<Decimal>,
+    /// 对冲比率估计器
+    pub hedge: HedgeEstimator,
+    /// 价差窗口（z 分数）
+    pub spreads: RollingWindow<f64>,
+    /// 价差 AR(1) 回归：Δs_t = λ·s_{t−1} + c，用于估计半衰期
+    pub mean_reversion: RollingOls<f64>,
+    /// 上一次的价差
+    pub last_spread: Option<f64>,
+    /// 当前价差头寸
+    pub position: Option<PairPosition>,
+    /// 结构破裂止损后等待价差回归
+    pub cooling_down: bool,
+}
+
+impl PairsTradingState {
+    pub fn new(config: &PairsTradingConfig) -> Self {
+        Self {
+            price_a: None,
+            price_b: None,
+            hedge: HedgeEstimator::new(config),
+            spreads: RollingWindow::new(config.lookback),
+            mean_reversion: RollingOls::new(config.lookback),
+            last_spread: None,
+            position: None,
+            cooling_down: false,
+        }
+    }
+}
+
+/// 配对交易策略
+pub struct PairsTradingStrategy {
+    meta: StrategyMeta,
+    config: PairsTradingConfig,
+    state: PairsTradingState,
+}
+
+impl PairsTradingStrategy {
+    /// 创建配对交易策略实例
+    pub fn new(
+        instance_id: Uuid,
+        symbol: String,
+        config: PairsTradingConfig,
+        market_type: MarketType,
+    ) -> Self {
+        let market = if market_type.is_futures() {
+            market_type
+        } else {
+            MarketType::UsdtFutures
+        };
+
+        Self {
+            meta: StrategyMeta {
+                instance_id,
+                strategy_type: "pairs_trading".to_string(),
+                market_type: market,
+                symbol,
+                is_active: false,
+            },
+            state: PairsTradingState::new(&config),
+            config,
+        }
+    }
+
+    /// 半衰期（价格更新次数），价差不均值回归时为 None
+    fn half_life(&self) -> Option<f64> {
+        let lambda = self.state.mean_reversion.value()?.slope;
+        if lambda >= 0.0 {
+            return None;
+        }
+        let half_life = -std::f64::consts::LN_2 / lambda;
+        let max = self.config.max_half_life.to_f64().unwrap_or(f64::MAX);
+        (half_life.is_finite() && half_life <= max).then_some(half_life)
+    }
+
+    /// 构造单腿信号
+    #[allow(clippy::too_many_arguments)]
+    fn leg(
+        &self,
+        symbol: &str,
+        signal_type: SignalType,
+        price: Decimal,
+        quantity: Decimal,
+        confidence: f64,
+        event: &MarketEvent,
+        instruction: OrderInstruction,
+    ) -> Signal {
+        Signal {
+            id: Uuid::new_v4(),
+            strategy_id: self.meta.instance_id,
+            symbol: symbol.to_string(),
+            signal_type,
+            price,
+            quantity,
+            confidence,
+            created_at: event.timestamp,
+            instruction,
+        }
+    }
+
+    /// 构造两腿信号组（A 腿在前，B 腿反向；数量为 0 的腿省略）
+    #[allow(clippy::too_many_arguments)]
+    fn pair_legs(
+        &self,
+        a_type: SignalType,
+        quantity_a: Decimal,
+        quantity_b: Decimal,
+        price_a: Decimal,
+        price_b: Decimal,
+        confidence: f64,
+        event: &MarketEvent,
+        instruction: OrderInstruction,
+    ) -> SignalGroup {
+        let b_type = match a_type {
+            SignalType::Buy => SignalType::Sell,
+            _ => SignalType::Buy,
+        };
+
+        let mut legs = vec![self.leg(
+            &self.config.symbol_a,
+            a_type,
+            price_a,
+            quantity_a,
+            confidence,
+            event,
+            instruction.clone(),
+        )];
+        if quantity_b > Decimal::ZERO {
+            legs.push(self.leg(
+                &self.config.symbol_b,
+                b_type,
+                price_b,
+                quantity_b,
+                confidence,
+                event,
+                instruction,
+            ));
+        }
+        SignalGroup::new(self.meta.instance_id, legs, event.timestamp)
+    }
+
+    /// 平掉当前价差头寸
+    fn close(
+        &mut self,
+        price_a: Decimal,
+        price_b: Decimal,
+        confidence: f64,
+        event: &MarketEvent,
+    ) -> Option<SignalGroup> {
+        let position = self.state.position.take()?;
+        let a_type = if position.long_spread {
+            SignalType::Sell
+        } else {
+            SignalType::Buy
+        };
+        Some(self.pair_legs(
+            a_type,
+            position.quantity_a,
+            position.quantity_b,
+            price_a,
+            price_b,
+            confidence,
+            event,
+            OrderInstruction::close(),
+        ))
+    }
+
+    /// 计算配对信号组
+    fn calculate_legs(&mut self, event: &MarketEvent) -> Option<SignalGroup> {
+        let trade = match &event.data {
+            MarketEventData::Trade(trade) => trade,
+            _ => return None,
+        };
+
+        if event.symbol == self.config.symbol_a {
+            self.state.price_a = Some(trade.price);
+        } else if event.symbol == self.config.symbol_b {
+            self.state.price_b = Some(trade.price);
+        } else {
+            return None;
+        }
+
+        let price_a = self.state.price_a?;
+        let price_b = self.state.price_b?;
+        let (a, b) = (price_a.to_f64()?, price_b.to_f64()?);
+
+        // 更新对冲关系与价差统计（预热期无信号）
+        let hedge = self.state.hedge.update(b, a)?;
+        let spread = hedge.residual(b, a);
+        if let Some(last) = self.state.last_spread.replace(spread) {
+            self.state.mean_reversion.update((last, spread - last));
+        }
+        self.state.spreads.push(spread);
+        if !self.state.spreads.is_full() {
+            return None;
+        }
+        let mean = self.state.spreads.mean()?;
+        let std_dev = self.state.spreads.std_dev()?;
+        if std_dev <= 0.0 {
+            return None;
+        }
+        let z = Decimal::from_f64((spread - mean) / std_dev)?;
+        let abs_z = z.abs();
+
+        if let Some(position) = self.state.position.as_mut() {
+            position.held += 1;
+            let expired = position.max_hold.is_some_and(|max| position.held >= max);
+            let reverted = if position.long_spread {
+                z >= -self.config.exit_z
+            } else {
+                z <= self.config.exit_z
+            };
+
+            if abs_z >= self.config.stop_z {
+                // 价差继续发散，协整关系可能已破裂
+                self.state.cooling_down = true;
+                return self.close(price_a, price_b, 0.5, event);
+            }
+            if reverted {
+                return self.close(price_a, price_b, 0.85, event);
+            }
+            if expired {
+                return self.close(price_a, price_b, 0.6, event);
+            }
+            return None;
+        }
+
+        if self.state.cooling_down {
+            if abs_z <= self.config.exit_z {
+                self.state.cooling_down = false;
+            }
+            return None;
+        }
+
+        // 入场：偏离足够大、尚未破裂、价差均值回归且对冲方向为正
+        if abs_z < self.config.entry_z || abs_z >= self.config.stop_z || hedge.slope <= 0.0 {
+            return None;
+        }
+        let half_life = self.half_life()?;
+
+        let leverage_multiplier =
+            Decimal::from_u32(self.config.leverage.leverage).unwrap_or(Decimal::ONE);
+        let quantity_a = self.config.quantity * leverage_multiplier;
+        let quantity_b = (quantity_a * Decimal::from_f64(hedge.slope)?).round_dp(8);
+        let max_hold = (half_life * self.config.half_life_multiple.to_f64().unwrap_or(0.0)).ceil();
+
+        // z > 0：A 相对 B 偏贵，做空价差；z < 0 做多价差
+        let long_spread = z < Decimal::ZERO;
+        self.state.position = Some(PairPosition {
+            long_spread,
+            quantity_a,
+            quantity_b,
+            held: 0,
+            max_hold: (max_hold >= 1.0).then_some(max_hold.min(u32::MAX as f64) as u32),
+        });
+
+        let a_type = if long_spread {
+            SignalType::Buy
+        } else {
+            SignalType::Sell
+        };
+        Some(self.pair_legs(
+            a_type,
+            quantity_a,
+            quantity_b,
+            price_a,
+            price_b,
+            0.8,
+            event,
+            OrderInstruction::default(),
+        ))
+    }
+}
+
+impl Strategy for PairsTradingStrategy {
+    fn meta(&self) -> &StrategyMeta {
+        &self.meta
+    }
+
+    fn meta_mut(&mut self) -> &mut StrategyMeta {
+        &mut self.meta
+    }
+
+    /// 单信号接口只返回 A 腿（B 腿需通过 `on_market_event_legs` 获取）
+    fn on_market_event(&mut self, event: &MarketEvent) -> Option<Signal> {
+        self.on_market_event_legs(event)
+            .and_then(|group| group.legs.into_iter().next())
+    }
+
+    fn on_market_event_legs(&mut self, event: &MarketEvent) -> Option<SignalGroup> {
+        if !self.is_active() {
+            return None;
+        }
+        self.calculate_legs(event)
+    }
+
+    fn subscriptions(&self) -> Vec<String> {
+        vec![self.config.symbol_a.clone(), self.config.symbol_b.clone()]
+    }
+
+    fn on_position_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {
+        let quantity = snapshot.quantity.abs();
+        if symbol == self.config.symbol_a {
+            // A 腿方向即价差方向，A 腿平掉即视为价差头寸已平
+            if snapshot.is_flat() {
+                self.state.position = None;
+                return;
+            }
+            match self.state.position.as_mut() {
+                Some(position) => position.quantity_a = quantity,
+                None => {
+                    self.state.position = Some(PairPosition {
+                        long_spread: snapshot.is_long(),
+                        quantity_a: quantity,
+                        quantity_b: Decimal::ZERO,
+                        held: 0,
+                        max_hold: None,
+                    })
+                }
+            }
+        } else if symbol == self.config.symbol_b {
+            if let Some(position) = self.state.position.as_mut() {
+                position.quantity_b = quantity;
+            }
+        }
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let mut state: PairsTradingState = serde_json::from_value(state)?;
+        // 窗口按当前配置重建，只回放检查点中的样本（周期变化后截取最新部分）
+        let mut fresh = PairsTradingState::new(&self.config);
+        match state.hedge.rebuild(&self.config) {
+            Some(hedge) => {
+                fresh.spreads.replay(&state.spreads);
+                fresh.mean_reversion.replay(&state.mean_reversion);
+                state.hedge = hedge;
+            }
+            // 对冲关系变化后旧价差失去意义，重新预热
+            None => {
+                state.hedge = fresh.hedge;
+                state.last_spread = None;
+            }
+        }
+        state.spreads = fresh.spreads;
+        state.mean_reversion = fresh.mean_reversion;
+        self.state = state;
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.state = PairsTradingState::new(&self.config);
+    }
+}
@@ -8,15 +8,17 @@ use std::collections::VecDeque;
 
 use rust_decimal::prelude::*;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use shared::event::market_event::{MarketEvent, MarketEventData};
 use uuid::Uuid;
 
 use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
 use crate::domain::model::market_type::{LeverageConfig, MarketType};
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalType};
 
 /// 反转策略配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct ReversalConfig {
     /// 动量周期
     pub momentum_period: usize,
@@ -46,7 +48,7 @@ impl Default for ReversalConfig {
 }
 
 /// 反转策略状态
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReversalState {
     /// 价格历史
     pub price_history: VecDeque<Decimal>,
@@ -226,6 +228,7 @@ impl ReversalStrategy {
                 quantity: self.config.quantity * leverage_multiplier,
                 confidence: 0.7,
                 created_at: event.timestamp,
+                instruction: OrderInstruction::default(),
             });
         }
 
@@ -249,6 +252,15 @@ impl Strategy for ReversalStrategy {
         self.calculate_signal(event)
     }
 
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        self.state = serde_json::from_value(state)?;
+        Ok(())
+    }
+
     fn reset(&mut self) {
         self.state = ReversalState::new();
     }
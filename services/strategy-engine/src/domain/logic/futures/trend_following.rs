@@ -4,19 +4,21 @@
 //! 使用快速均线和慢速均线的交叉来判断趋势方向。
 //! 支持杠杆、双向持仓。
 
-use std::collections::VecDeque;
-
 use rust_decimal::prelude::*;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::PositionSnapshot;
 use shared::event::market_event::{MarketEvent, MarketEventData};
 use uuid::Uuid;
 
+use crate::domain::logic::indicator::{Indicator, Sma};
 use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
 use crate::domain::model::market_type::{LeverageConfig, MarketType};
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, OrderType, Signal, SignalType};
 
 /// 趋势跟踪策略配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct TrendFollowingConfig {
     /// 快速均线周期
     pub fast_period: usize,
@@ -46,13 +48,15 @@ impl Default for TrendFollowingConfig {
 }
 
 /// 趋势跟踪策略状态
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrendFollowingState {
-    /// 价格历史
-    pub price_history: VecDeque<Decimal>,
-    /// 快速均线
+    /// 快速均线指标
+    pub fast: Sma<Decimal>,
+    /// 慢速均线指标
+    pub slow: Sma<Decimal>,
+    /// 上一次快速均线
     pub fast_ma: Option<Decimal>,
-    /// 慢速均线
+    /// 上一次慢速均线
     pub slow_ma: Option<Decimal>,
     /// 当前持仓方向
     pub current_position: Option<SignalType>,
@@ -61,9 +65,10 @@ pub struct TrendFollowingState {
 }
 
 impl TrendFollowingState {
-    pub fn new() -> Self {
+    pub fn new(config: &TrendFollowingConfig) -> Self {
         Self {
-            price_history: VecDeque::new(),
+            fast: Sma::new(config.fast_period),
+            slow: Sma::new(config.slow_period),
             fast_ma: None,
             slow_ma: None,
             current_position: None,
@@ -72,12 +77,6 @@ impl TrendFollowingState {
     }
 }
 
-impl Default for TrendFollowingState {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 /// 趋势跟踪策略
 pub struct TrendFollowingStrategy {
     meta: StrategyMeta,
@@ -107,22 +106,11 @@ impl TrendFollowingStrategy {
                 symbol,
                 is_active: false,
             },
+            state: TrendFollowingState::new(&config),
             config,
-            state: TrendFollowingState::new(),
         }
     }
 
-    /// 计算移动平均
-    fn calculate_ma(&self, period: usize) -> Option<Decimal> {
-        if self.state.price_history.len() < period {
-            return None;
-        }
-
-        let start = self.state.price_history.len() - period;
-        let sum: Decimal = self.state.price_history.iter().skip(start).sum();
-        Some(sum / Decimal::from(period))
-    }
-
     /// 检查止损
     fn check_stop_loss(&self, current_price: Decimal) -> bool {
         if let (Some(entry_price), Some(position), Some(stop_loss_percent)) = (
@@ -157,12 +145,9 @@ impl TrendFollowingStrategy {
 
         let price = trade.price;
 
-        // 更新价格历史
-        self.state.price_history.push_back(price);
-        let max_period = self.config.fast_period.max(self.config.slow_period);
-        if self.state.price_history.len() > max_period {
-            self.state.price_history.pop_front();
-        }
+        // 更新均线（止损检查不依赖均线预热）
+        let fast_value = self.state.fast.update(price);
+        let slow_value = self.state.slow.update(price);
 
         // 检查止损
         if self.check_stop_loss(price) {
@@ -187,12 +172,13 @@ impl TrendFollowingStrategy {
                 quantity: self.config.quantity * leverage_multiplier,
                 confidence: 0.6, // 止损信号置信度较低
                 created_at: event.timestamp,
+                instruction: OrderInstruction::close().with_order_type(OrderType::Market),
             });
         }
 
         // 计算快速和慢速均线
-        let fast_ma = self.calculate_ma(self.config.fast_period)?;
-        let slow_ma = self.calculate_ma(self.config.slow_period)?;
+        let fast_ma = fast_value?;
+        let slow_ma = slow_value?;
 
         let prev_fast_ma = self.state.fast_ma;
         let prev_slow_ma = self.state.slow_ma;
@@ -239,6 +225,7 @@ impl TrendFollowingStrategy {
                 quantity: self.config.quantity * leverage_multiplier,
                 confidence: 0.8,
                 created_at: event.timestamp,
+                instruction: OrderInstruction::default(),
             });
         }
 
@@ -262,7 +249,44 @@ impl Strategy for TrendFollowingStrategy {
         self.calculate_signal(event)
     }
 
+    fn on_position_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {
+        if symbol != self.meta.symbol {
+            return;
+        }
+        // 以真实持仓为准，拒单或部分成交后不再误判方向
+        self.state.current_position = if snapshot.is_long() {
+            Some(SignalType::Buy)
+        } else if snapshot.is_short() {
+            Some(SignalType::Sell)
+        } else {
+            None
+        };
+        self.state.entry_price = (!snapshot.is_flat()).then_some(snapshot.average_price);
+    }
+
+    fn state_version(&self) -> u32 {
+        2
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let mut state: TrendFollowingState = serde_json::from_value(state)?;
+        // 均线按当前配置重建，只回放检查点中的价格（周期变化后截取最新部分）
+        let mut fresh = TrendFollowingState::new(&self.config);
+        fresh.fast.replay(&state.fast);
+        fresh.slow.replay(&state.slow);
+        state.fast_ma = fresh.fast.value();
+        state.slow_ma = fresh.slow.value();
+        state.fast = fresh.fast;
+        state.slow = fresh.slow;
+        self.state = state;
+        Ok(())
+    }
+
     fn reset(&mut self) {
-        self.state = TrendFollowingState::new();
+        self.state = TrendFollowingState::new(&self.config);
     }
 }
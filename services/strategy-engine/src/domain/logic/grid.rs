@@ -7,7 +7,7 @@ use rust_decimal::Decimal;
 use shared::event::market_event::{MarketEvent, MarketEventData};
 use uuid::Uuid;
 
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalType};
 
 /// 网格策略配置 (Grid Strategy Configuration)
 #[derive(Debug, Clone)]
@@ -96,5 +96,6 @@ pub fn calculate_grid_signal(
         quantity: config.quantity_per_grid,
         confidence: 1.0,
         created_at: event.timestamp,
+        instruction: OrderInstruction::default(),
     })
 }
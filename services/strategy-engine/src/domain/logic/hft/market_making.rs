@@ -0,0 +1,482 @@
+//! # 做市策略 (Market Making Strategy)
+//!
+//! 围绕公允价格在买卖两侧持续挂单，赚取买卖价差。
+//!
+//! - 公允价格：买一卖一中间价（mid）或按盘口数量加权的微观价格（microprice）
+//! - 库存偏移：持有多头时整体下移报价（更容易卖出、更难买入），空头反之
+//! - 波动率定量：中间价收益率的滚动标准差超过目标值时按比例缩小挂单数量
+//! - 撤旧挂新：公允价格偏离上次报价超过阈值、或库存变化时重新报价
+//! - 盘口价差过宽（流动性异常）时全部撤单，恢复后重新报价
+//!
+//! 只消费深度行情，输出报价指令（`on_market_event_quotes`），不产生普通信号。
+//! 报价指令目前不会被执行（trading-engine 尚无报价消费者），实盘运行时不会挂单。
+//! 现货不做空：卖单数量不超过当前持仓。
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::PositionSnapshot;
+use shared::event::market_event::{DepthData, MarketEvent, MarketEventData};
+use uuid::Uuid;
+
+use crate::domain::logic::indicator::{Indicator, StdDev};
+use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
+use crate::domain::model::market_type::MarketType;
+use crate::domain::model::quote::{QuoteInstruction, QuoteLevel};
+use crate::domain::model::signal::Signal;
+
+/// 基点换算
+const BPS: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// 公允价格来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FairValueSource {
+    /// 买一卖一中间价
+    Mid,
+    /// 微观价格：(买一价 × 卖一量 + 卖一价 × 买一量) / (买一量 + 卖一量)
+    Microprice,
+}
+
+/// 做市策略配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MarketMakingConfig {
+    /// 公允价格来源
+    pub fair_value: FairValueSource,
+    /// 报价半价差（基点，相对公允价格）
+    pub half_spread_bps: Decimal,
+    /// 基础挂单数量
+    pub base_quantity: Decimal,
+    /// 最大库存（绝对值）
+    pub max_inventory: Decimal,
+    /// 满库存时的报价偏移（基点）
+    pub inventory_skew_bps: Decimal,
+    /// 波动率统计周期（深度更新次数）
+    pub volatility_period: usize,
+    /// 目标波动率（基点，每次深度更新的中间价收益率标准差）
+    pub target_volatility_bps: Decimal,
+    /// 挂单数量下限（相对基础数量的比例）
+    pub min_size_ratio: Decimal,
+    /// 重新报价阈值（公允价格偏离上次报价的基点数）
+    pub requote_threshold_bps: Decimal,
+    /// 盘口价差上限（基点），超过时全部撤单
+    pub max_market_spread_bps: Decimal,
+    /// 价格最小变动单位
+    pub tick_size: Decimal,
+}
+
+impl Default for MarketMakingConfig {
+    fn default() -> Self {
+        Self {
+            fair_value: FairValueSource::Microprice,
+            half_spread_bps: Decimal::from(10),
+            base_quantity: Decimal::new(1, 3),  // 0.001
+            max_inventory: Decimal::new(1, 2),  // 0.01
+            inventory_skew_bps: Decimal::from(10),
+            volatility_period: 20,
+            target_volatility_bps: Decimal::from(5),
+            min_size_ratio: Decimal::new(2, 1), // 0.2
+            requote_threshold_bps: Decimal::from(5),
+            max_market_spread_bps: Decimal::from(50),
+            tick_size: Decimal::new(1, 2),      // 0.01
+        }
+    }
+}
+
+/// 当前挂出的报价
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveQuote {
+    /// 报价时的公允价格
+    pub fair_value: Decimal,
+    /// 报价时的库存
+    pub inventory: Decimal,
+    /// 买单
+    pub bid: Option<QuoteLevel>,
+    /// 卖单
+    pub ask: Option<QuoteLevel>,
+}
+
+/// 做市策略状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketMakingState {
+    /// 上一次的中间价
+    pub last_mid: Option<Decimal>,
+    /// 中间价收益率（基点）的滚动标准差
+    pub volatility: StdDev<Decimal>,
+    /// 当前库存（多头为正，空头为负）
+    pub inventory: Decimal,
+    /// 当前挂出的报价（None 表示没有挂单）
+    pub live_quote: Option<LiveQuote>,
+}
+
+impl MarketMakingState {
+    pub fn new(config: &MarketMakingConfig) -> Self {
+        Self {
+            last_mid: None,
+            volatility: StdDev::new(config.volatility_period),
+            inventory: Decimal::ZERO,
+            live_quote: None,
+        }
+    }
+}
+
+/// 做市策略
+pub struct MarketMakingStrategy {
+    meta: StrategyMeta,
+    config: MarketMakingConfig,
+    state: MarketMakingState,
+}
+
+impl MarketMakingStrategy {
+    /// 创建做市策略实例
+    pub fn new(
+        instance_id: Uuid,
+        symbol: String,
+        config: MarketMakingConfig,
+        market_type: MarketType,
+    ) -> Self {
+        Self {
+            meta: StrategyMeta {
+                instance_id,
+                strategy_type: "market_making".to_string(),
+                market_type,
+                symbol,
+                is_active: false,
+            },
+            state: MarketMakingState::new(&config),
+            config,
+        }
+    }
+
+    /// 公允价格
+    fn fair_value(
+        &self,
+        (bid, bid_qty): (Decimal, Decimal),
+        (ask, ask_qty): (Decimal, Decimal),
+    ) -> Decimal {
+        let total = bid_qty + ask_qty;
+        match self.config.fair_value {
+            FairValueSource::Microprice if total > Decimal::ZERO => {
+                (bid * ask_qty + ask * bid_qty) / total
+            }
+            _ => (bid + ask) / Decimal::TWO,
+        }
+    }
+
+    /// 按波动率缩放后的挂单数量
+    fn quote_size(&self, volatility_bps: Decimal) -> Decimal {
+        let ratio = if volatility_bps > self.config.target_volatility_bps {
+            (self.config.target_volatility_bps / volatility_bps).max(self.config.min_size_ratio)
+        } else {
+            Decimal::ONE
+        };
+        self.config.base_quantity * ratio
+    }
+
+    /// 买价向下取整到最小变动单位
+    fn floor_tick(&self, price: Decimal) -> Decimal {
+        if self.config.tick_size <= Decimal::ZERO {
+            return price;
+        }
+        (price / self.config.tick_size).floor() * self.config.tick_size
+    }
+
+    /// 卖价向上取整到最小变动单位
+    fn ceil_tick(&self, price: Decimal) -> Decimal {
+        if self.config.tick_size <= Decimal::ZERO {
+            return price;
+        }
+        (price / self.config.tick_size).ceil() * self.config.tick_size
+    }
+
+    /// 撤掉全部报价（没有挂单时无需撤单）
+    fn cancel(&mut self, fair_value: Decimal, event: &MarketEvent) -> Option<QuoteInstruction> {
+        self.state.live_quote.take()?;
+        Some(QuoteInstruction::cancel_all(
+            self.meta.instance_id,
+            &self.meta.symbol,
+            fair_value,
+            event.timestamp,
+        ))
+    }
+
+    /// 计算报价指令
+    fn calculate_quotes(&mut self, event: &MarketEvent, depth: &DepthData) -> Option<QuoteInstruction> {
+        let best_bid = *depth.bids.first()?;
+        let best_ask = *depth.asks.first()?;
+        if best_bid.0 <= Decimal::ZERO || best_bid.0 >= best_ask.0 {
+            return None;
+        }
+
+        let mid = (best_bid.0 + best_ask.0) / Decimal::TWO;
+        let volatility = match self.state.last_mid.replace(mid) {
+            Some(last_mid) => self.state.volatility.update((mid - last_mid) / last_mid * BPS),
+            None => None,
+        };
+        let fair_value = self.fair_value(best_bid, best_ask);
+
+        // 盘口价差过宽，流动性异常，撤单观望
+        let market_spread_bps = (best_ask.0 - best_bid.0) / mid * BPS;
+        if market_spread_bps > self.config.max_market_spread_bps {
+            return self.cancel(fair_value, event);
+        }
+
+        // 波动率预热期不报价
+        let volatility = volatility?;
+
+        // 报价未过期：公允价格偏离不大且库存未变
+        let inventory = self.state.inventory;
+        if let Some(live) = &self.state.live_quote {
+            let drift_bps = ((fair_value - live.fair_value) / live.fair_value * BPS).abs();
+            if drift_bps <= self.config.requote_threshold_bps && live.inventory == inventory {
+                return None;
+            }
+        }
+
+        // 库存偏移：库存占比 ∈ [-1, 1]，多头下移、空头上移
+        let inventory_ratio = if self.config.max_inventory > Decimal::ZERO {
+            (inventory / self.config.max_inventory).clamp(-Decimal::ONE, Decimal::ONE)
+        } else {
+            Decimal::ZERO
+        };
+        let reservation =
+            fair_value * (Decimal::ONE - inventory_ratio * self.config.inventory_skew_bps / BPS);
+        let half_spread = reservation * self.config.half_spread_bps / BPS;
+
+        // 只做 Maker：买价不高于卖一减一跳，卖价不低于买一加一跳
+        let bid_price = self
+            .floor_tick(reservation - half_spread)
+            .min(best_ask.0 - self.config.tick_size);
+        let ask_price = self
+            .ceil_tick(reservation + half_spread)
+            .max(best_bid.0 + self.config.tick_size);
+
+        // 数量受库存上限约束，现货卖单不超过持仓
+        let size = self.quote_size(volatility);
+        let bid_quantity = size.min(self.config.max_inventory - inventory);
+        let ask_quantity = if self.meta.market_type.is_futures() {
+            size.min(self.config.max_inventory + inventory)
+        } else {
+            size.min(inventory)
+        };
+
+        let bid = (bid_quantity > Decimal::ZERO && bid_price > Decimal::ZERO).then_some(QuoteLevel {
+            price: bid_price,
+            quantity: bid_quantity,
+        });
+        let ask = (ask_quantity > Decimal::ZERO).then_some(QuoteLevel {
+            price: ask_price,
+            quantity: ask_quantity,
+        });
+        if bid.is_none() && ask.is_none() {
+            return self.cancel(fair_value, event);
+        }
+
+        self.state.live_quote = Some(LiveQuote {
+            fair_value,
+            inventory,
+            bid,
+            ask,
+        });
+        Some(QuoteInstruction::replace(
+            self.meta.instance_id,
+            &self.meta.symbol,
+            bid,
+            ask,
+            fair_value,
+            event.timestamp,
+        ))
+    }
+}
+
+impl Strategy for MarketMakingStrategy {
+    fn meta(&self) -> &StrategyMeta {
+        &self.meta
+    }
+
+    fn meta_mut(&mut self) -> &mut StrategyMeta {
+        &mut self.meta
+    }
+
+    /// 做市策略只输出报价指令
+    fn on_market_event(&mut self, _event: &MarketEvent) -> Option<Signal> {
+        None
+    }
+
+    fn on_market_event_quotes(&mut self, event: &MarketEvent) -> Option<QuoteInstruction> {
+        if !self.is_active() || event.symbol != self.meta.symbol {
+            return None;
+        }
+        match &event.data {
+            MarketEventData::Depth(depth) => self.calculate_quotes(event, depth),
+            _ => None,
+        }
+    }
+
+    fn on_position_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {
+        if symbol == self.meta.symbol {
+            self.state.inventory = snapshot.quantity;
+        }
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let mut state: MarketMakingState = serde_json::from_value(state)?;
+        // 波动率窗口按当前配置重建，只回放检查点中的值（周期变化后截取最新部分）
+        let mut volatility = StdDev::new(self.config.volatility_period);
+        volatility.replay(&state.volatility);
+        state.volatility = volatility;
+        self.state = state;
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.state = MarketMakingState::new(&self.config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use shared::event::market_event::MarketEventType;
+
+    use crate::domain::model::quote::QuoteAction;
+
+    /// 录制的 BTCUSDT 深度快照（market-data 输出格式，每行一个 `MarketEvent`）
+    ///
+    /// 第 30 条起中间价上移约 15 bps，第 45 条盘口价差短暂放大到约 80 bps。
+    const RECORDED_DEPTH: &str = include_str!("testdata/btcusdt_depth.jsonl");
+
+    fn recorded_events() -> Vec<MarketEvent> {
+        RECORDED_DEPTH
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).expect("recorded depth event"))
+            .collect()
+    }
+
+    fn strategy(market_type: MarketType) -> MarketMakingStrategy {
+        let config = MarketMakingConfig {
+            volatility_period: 10,
+            ..MarketMakingConfig::default()
+        };
+        let mut strategy =
+            MarketMakingStrategy::new(Uuid::new_v4(), "BTCUSDT".to_string(), config, market_type);
+        strategy.activate();
+        strategy
+    }
+
+    fn depth_event(bid: Decimal, ask: Decimal, bid_qty: Decimal, ask_qty: Decimal) -> MarketEvent {
+        MarketEvent {
+            event_type: MarketEventType::Depth,
+            exchange: "binance".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            timestamp: Utc::now(),
+            data: MarketEventData::Depth(DepthData {
+                bids: vec![(bid, bid_qty)],
+                asks: vec![(ask, ask_qty)],
+            }),
+        }
+    }
+
+    #[test]
+    fn test_replay_recorded_depth() {
+        let events = recorded_events();
+        let mut strategy = strategy(MarketType::UsdtFutures);
+        let tick = strategy.config.tick_size;
+
+        let mut replaced = 0;
+        let mut cancelled = Vec::new();
+        for (index, event) in events.iter().enumerate() {
+            let Some(quotes) = strategy.on_market_event_quotes(event) else {
+                continue;
+            };
+            let MarketEventData::Depth(depth) = &event.data else {
+                unreachable!()
+            };
+            match quotes.action {
+                QuoteAction::Replace { bid, ask } => {
+                    replaced += 1;
+                    let (bid, ask) = (bid.unwrap(), ask.unwrap());
+                    assert!(bid.price < ask.price);
+                    assert!(bid.price < depth.asks[0].0 && ask.price > depth.bids[0].0);
+                    assert_eq!(bid.price % tick, Decimal::ZERO);
+                    assert_eq!(ask.price % tick, Decimal::ZERO);
+                }
+                QuoteAction::CancelAll => cancelled.push(index),
+            }
+        }
+
+        // 预热后首次报价、价格上移后重新报价、价差恢复后重新报价；其余更新不撤挂
+        assert!(replaced >= 3, "replaced {} times", replaced);
+        assert!(replaced < events.len() / 4, "too much churn: {}", replaced);
+        assert_eq!(cancelled, vec![45]);
+    }
+
+    #[test]
+    fn test_inventory_skews_quotes_and_caps_side() {
+        let (bid, ask) = (Decimal::from(50_000), Decimal::new(5_000_010, 2));
+        let qty = Decimal::ONE;
+
+        let mut flat = strategy(MarketType::UsdtFutures);
+        let mut long = strategy(MarketType::UsdtFutures);
+        long.on_position_snapshot(
+            "BTCUSDT",
+            &PositionSnapshot {
+                quantity: long.config.max_inventory,
+                average_price: bid,
+            },
+        );
+
+        let mut last = (None, None);
+        for _ in 0..12 {
+            let event = depth_event(bid, ask, qty, qty);
+            last = (
+                flat.on_market_event_quotes(&event).or(last.0),
+                long.on_market_event_quotes(&event).or(last.1),
+            );
+        }
+        let (flat, long) = (last.0.unwrap(), last.1.unwrap());
+
+        // 满仓多头：报价整体下移，不再挂买单
+        assert!(long.ask().unwrap().price < flat.ask().unwrap().price);
+        assert!(long.bid().is_none());
+        assert!(flat.bid().is_some());
+    }
+
+    #[test]
+    fn test_volatility_shrinks_size_and_spot_never_shorts() {
+        let qty = Decimal::ONE;
+        let mut calm = strategy(MarketType::UsdtFutures);
+        let mut volatile = strategy(MarketType::UsdtFutures);
+        let mut spot = strategy(MarketType::Spot);
+
+        let (mut calm_quote, mut volatile_quote, mut spot_quote) = (None, None, None);
+        for i in 0..12 {
+            let calm_bid = Decimal::from(50_000);
+            // 每次来回跳动 ±20 bps
+            let volatile_bid = if i % 2 == 0 { Decimal::from(50_000) } else { Decimal::from(50_100) };
+
+            let event = depth_event(calm_bid, calm_bid + Decimal::ONE, qty, qty);
+            calm_quote = calm.on_market_event_quotes(&event).or(calm_quote);
+            spot_quote = spot.on_market_event_quotes(&event).or(spot_quote);
+            let event = depth_event(volatile_bid, volatile_bid + Decimal::ONE, qty, qty);
+            volatile_quote = volatile.on_market_event_quotes(&event).or(volatile_quote);
+        }
+
+        let calm_size = calm_quote.unwrap().bid().unwrap().quantity;
+        let volatile_size = volatile_quote.unwrap().bid().unwrap().quantity;
+        assert_eq!(calm_size, calm.config.base_quantity);
+        assert!(volatile_size < calm_size);
+        assert!(volatile_size >= calm.config.base_quantity * calm.config.min_size_ratio);
+
+        // 现货无持仓时只挂买单
+        let spot_quote = spot_quote.unwrap();
+        assert!(spot_quote.bid().is_some());
+        assert!(spot_quote.ask().is_none());
+    }
+}
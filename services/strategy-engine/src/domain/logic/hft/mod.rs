@@ -3,8 +3,12 @@
 //! 高频交易策略实现。
 //! 特点：微秒级响应、低延迟、高吞吐。
 
+pub mod market_making;       // 做市策略
+pub mod order_flow;          // 订单流策略
+
 // TODO: 高频策略待实现
-// pub mod market_making;    // 做市策略
 // pub mod scalping;         // 剥头皮策略
 // pub mod latency_arb;      // 延迟套利
-// pub mod order_flow;       // 订单流策略
+
+pub use market_making::MarketMakingStrategy;
+pub use order_flow::OrderFlowStrategy;
@@ -0,0 +1,516 @@
+//! # 订单流特征 (Order-Flow Features)
+//!
+//! 逐笔成交与盘口深度上的微观结构特征，可单独用于其他策略或研究。
+//!
+//! - `TradeImbalance`：滚动成交失衡度，(主动买量 − 主动卖量) / 总成交量 ∈ [-1, 1]
+//! - `CumulativeVolumeDelta`：累计成交量差（CVD）及窗口内的变化量
+//! - `BookImbalance`：前 N 档挂单失衡度，(买盘量 − 卖盘量) / 总挂单量 ∈ [-1, 1]
+//! - `Vpin`：按成交量分桶的知情交易概率（VPIN），衡量订单流毒性 ∈ [0, 1]
+//! - `LargeTradeDetector`：成交量超过近期均值 + k·σ 的大单
+//!
+//! ## 规则
+//! - ✅ 主动方向取自 `TradeData::is_buyer_maker`：买方为 maker 即主动卖
+//! - ✅ 每次更新为 O(1)（VPIN 单笔跨越多个桶时按桶数计）
+//! - ✅ 可序列化，随策略检查点保存
+//! - ✅ 预热期返回 `None`
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::event::market_event::{DepthData, TradeData};
+use shared::types::order::OrderSide;
+
+use crate::domain::logic::indicator::{Indicator, RollingWindow};
+
+/// 逐笔成交
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TradePrint {
+    /// 成交价格
+    pub price: Decimal,
+    /// 成交数量
+    pub quantity: Decimal,
+    /// 买方是否为 maker（是则为主动卖）
+    pub is_buyer_maker: bool,
+}
+
+impl TradePrint {
+    /// 由成交数据构造
+    pub fn from_trade(trade: &TradeData) -> Self {
+        Self {
+            price: trade.price,
+            quantity: trade.quantity,
+            is_buyer_maker: trade.is_buyer_maker,
+        }
+    }
+
+    /// 主动方向
+    pub fn aggressor(&self) -> OrderSide {
+        if self.is_buyer_maker {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        }
+    }
+
+    /// 带符号成交量（主动买为正、主动卖为负）
+    pub fn signed_quantity(&self) -> Decimal {
+        if self.is_buyer_maker {
+            -self.quantity
+        } else {
+            self.quantity
+        }
+    }
+}
+
+/// 带符号成交量与总成交量之比（总量为 0 时为 0）
+fn ratio(signed: Decimal, total: Decimal) -> Decimal {
+    if total > Decimal::ZERO {
+        signed / total
+    } else {
+        Decimal::ZERO
+    }
+}
+
+// ============================================================================
+// 成交失衡度
+// ============================================================================
+
+/// 滚动成交失衡度（最近 N 笔成交）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeImbalance {
+    /// 带符号成交量
+    signed: RollingWindow<Decimal>,
+    /// 成交量
+    volume: RollingWindow<Decimal>,
+}
+
+impl TradeImbalance {
+    /// 创建成交失衡度
+    pub fn new(period: usize) -> Self {
+        Self {
+            signed: RollingWindow::new(period),
+            volume: RollingWindow::new(period),
+        }
+    }
+
+    /// 周期（成交笔数）
+    pub fn period(&self) -> usize {
+        self.signed.capacity()
+    }
+}
+
+impl Indicator for TradeImbalance {
+    type Input = TradePrint;
+    type Output = Decimal;
+
+    fn update(&mut self, trade: TradePrint) -> Option<Decimal> {
+        self.signed.push(trade.signed_quantity());
+        self.volume.push(trade.quantity);
+        self.value()
+    }
+
+    fn value(&self) -> Option<Decimal> {
+        self.signed
+            .is_full()
+            .then(|| ratio(self.signed.sum(), self.volume.sum()))
+    }
+
+    fn reset(&mut self) {
+        self.signed.clear();
+        self.volume.clear();
+    }
+}
+
+// ============================================================================
+// 累计成交量差
+// ============================================================================
+
+/// 累计成交量差输出
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CvdOutput {
+    /// 自启动以来的累计成交量差
+    pub cvd: Decimal,
+    /// 最近 N 笔成交内的变化量
+    pub delta: Decimal,
+    /// 变化量 / 窗口成交量 ∈ [-1, 1]
+    pub normalized: Decimal,
+}
+
+/// 累计成交量差（CVD）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CumulativeVolumeDelta {
+    /// 累计成交量差
+    cvd: Decimal,
+    /// 窗口内的带符号成交量
+    signed: RollingWindow<Decimal>,
+    /// 窗口内的成交量
+    volume: RollingWindow<Decimal>,
+}
+
+impl CumulativeVolumeDelta {
+    /// 创建累计成交量差（`period` 为变化量的统计窗口）
+    pub fn new(period: usize) -> Self {
+        Self {
+            cvd: Decimal::ZERO,
+            signed: RollingWindow::new(period),
+            volume: RollingWindow::new(period),
+        }
+    }
+
+    /// 周期（成交笔数）
+    pub fn period(&self) -> usize {
+        self.signed.capacity()
+    }
+
+    /// 当前累计值（不需要预热）
+    pub fn cvd(&self) -> Decimal {
+        self.cvd
+    }
+}
+
+impl Indicator for CumulativeVolumeDelta {
+    type Input = TradePrint;
+    type Output = CvdOutput;
+
+    fn update(&mut self, trade: TradePrint) -> Option<CvdOutput> {
+        let signed = trade.signed_quantity();
+        self.cvd += signed;
+        self.signed.push(signed);
+        self.volume.push(trade.quantity);
+        self.value()
+    }
+
+    fn value(&self) -> Option<CvdOutput> {
+        self.signed.is_full().then(|| CvdOutput {
+            cvd: self.cvd,
+            delta: self.signed.sum(),
+            normalized: ratio(self.signed.sum(), self.volume.sum()),
+        })
+    }
+
+    fn reset(&mut self) {
+        self.cvd = Decimal::ZERO;
+        self.signed.clear();
+        self.volume.clear();
+    }
+}
+
+// ============================================================================
+// 盘口失衡度
+// ============================================================================
+
+/// 盘口失衡度（前 N 档）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookImbalance {
+    /// 统计档数
+    levels: usize,
+    /// 最新值
+    last: Option<Decimal>,
+}
+
+impl BookImbalance {
+    /// 创建盘口失衡度（档数至少为 1）
+    pub fn new(levels: usize) -> Self {
+        Self {
+            levels: levels.max(1),
+            last: None,
+        }
+    }
+
+    /// 统计档数
+    pub fn levels(&self) -> usize {
+        self.levels
+    }
+
+    /// 输入一个深度快照（任一侧为空时保持上一个值）
+    pub fn update(&mut self, depth: &DepthData) -> Option<Decimal> {
+        if depth.bids.is_empty() || depth.asks.is_empty() {
+            return self.last;
+        }
+
+        let bid_qty: Decimal = depth.bids.iter().take(self.levels).map(|(_, q)| *q).sum();
+        let ask_qty: Decimal = depth.asks.iter().take(self.levels).map(|(_, q)| *q).sum();
+        self.last = Some(ratio(bid_qty - ask_qty, bid_qty + ask_qty));
+        self.last
+    }
+
+    /// 最新值
+    pub fn value(&self) -> Option<Decimal> {
+        self.last
+    }
+
+    /// 清空状态
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+// ============================================================================
+// VPIN
+// ============================================================================
+
+/// VPIN（成交量同步的知情交易概率）
+///
+/// 成交按固定成交量装桶，每桶的失衡度为 |主动买量 − 主动卖量| / 桶容量，
+/// VPIN 为最近 N 个桶失衡度的均值。值越高说明单边知情交易越多，做市 / 逆势风险越大。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Vpin {
+    /// 桶容量（成交量）
+    bucket_volume: Decimal,
+    /// 当前桶的主动买量
+    buy_volume: Decimal,
+    /// 当前桶的主动卖量
+    sell_volume: Decimal,
+    /// 已完成桶的失衡度
+    buckets: RollingWindow<Decimal>,
+}
+
+impl Vpin {
+    /// 创建 VPIN（`bucket_volume` 必须为正，否则始终处于预热期）
+    pub fn new(bucket_volume: Decimal, buckets: usize) -> Self {
+        Self {
+            bucket_volume,
+            buy_volume: Decimal::ZERO,
+            sell_volume: Decimal::ZERO,
+            buckets: RollingWindow::new(buckets),
+        }
+    }
+
+    /// 桶容量
+    pub fn bucket_volume(&self) -> Decimal {
+        self.bucket_volume
+    }
+
+    /// 统计桶数
+    pub fn bucket_count(&self) -> usize {
+        self.buckets.capacity()
+    }
+
+    /// 向当前桶加入成交量
+    fn fill(&mut self, side: OrderSide, quantity: Decimal) {
+        match side {
+            OrderSide::Buy => self.buy_volume += quantity,
+            OrderSide::Sell => self.sell_volume += quantity,
+        }
+    }
+
+    /// 当前桶装满时结算
+    fn close_bucket_if_full(&mut self) {
+        if self.buy_volume + self.sell_volume >= self.bucket_volume {
+            let imbalance = (self.buy_volume - self.sell_volume).abs() / self.bucket_volume;
+            self.buckets.push(imbalance.min(Decimal::ONE));
+            self.buy_volume = Decimal::ZERO;
+            self.sell_volume = Decimal::ZERO;
+        }
+    }
+}
+
+impl Indicator for Vpin {
+    type Input = TradePrint;
+    type Output = Decimal;
+
+    fn update(&mut self, trade: TradePrint) -> Option<Decimal> {
+        if self.bucket_volume <= Decimal::ZERO || trade.quantity <= Decimal::ZERO {
+            return self.value();
+        }
+        let side = trade.aggressor();
+
+        // 先填满当前桶
+        let room = self.bucket_volume - self.buy_volume - self.sell_volume;
+        let take = trade.quantity.min(room);
+        self.fill(side, take);
+        self.close_bucket_if_full();
+
+        // 单笔成交跨越的整桶全部是单边成交，失衡度为 1（最多记满窗口）
+        let mut remaining = trade.quantity - take;
+        let whole = (remaining / self.bucket_volume).floor();
+        let recorded = whole.min(Decimal::from(self.buckets.capacity()));
+        let mut n = Decimal::ZERO;
+        while n < recorded {
+            self.buckets.push(Decimal::ONE);
+            n += Decimal::ONE;
+        }
+        remaining -= whole * self.bucket_volume;
+
+        // 剩余部分进入新桶
+        self.fill(side, remaining);
+        self.close_bucket_if_full();
+
+        self.value()
+    }
+
+    fn value(&self) -> Option<Decimal> {
+        if self.buckets.is_full() {
+            self.buckets.mean()
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.buy_volume = Decimal::ZERO;
+        self.sell_volume = Decimal::ZERO;
+        self.buckets.clear();
+    }
+}
+
+// ============================================================================
+// 大单检测
+// ============================================================================
+
+/// 大单
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LargeTrade {
+    /// 主动方向
+    pub side: OrderSide,
+    /// 成交数量
+    pub quantity: Decimal,
+    /// 相对近期成交量的标准分
+    pub z_score: Decimal,
+}
+
+/// 大单检测
+///
+/// 与此前 N 笔成交（不含当前成交）的均值和标准差比较，避免大单拉高自身阈值。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LargeTradeDetector {
+    /// 近期成交量
+    sizes: RollingWindow<Decimal>,
+    /// 标准差倍数
+    threshold: Decimal,
+}
+
+impl LargeTradeDetector {
+    /// 创建大单检测（`threshold` 为标准差倍数）
+    pub fn new(period: usize, threshold: Decimal) -> Self {
+        Self {
+            sizes: RollingWindow::new(period),
+            threshold,
+        }
+    }
+
+    /// 周期（成交笔数）
+    pub fn period(&self) -> usize {
+        self.sizes.capacity()
+    }
+
+    /// 标准差倍数
+    pub fn threshold(&self) -> Decimal {
+        self.threshold
+    }
+
+    /// 输入一笔成交，是大单时返回（预热期不判定）
+    pub fn update(&mut self, trade: TradePrint) -> Option<LargeTrade> {
+        let detected = if self.sizes.is_full() {
+            let mean = self.sizes.mean()?;
+            let std_dev = self.sizes.std_dev()?;
+            let excess = trade.quantity - mean;
+            let z_score = if std_dev > Decimal::ZERO {
+                excess / std_dev
+            } else {
+                Decimal::ZERO
+            };
+            let is_large = if std_dev > Decimal::ZERO {
+                z_score > self.threshold
+            } else {
+                // 近期成交量完全相同时，超过均值即视为大单
+                excess > Decimal::ZERO
+            };
+            is_large.then_some(LargeTrade {
+                side: trade.aggressor(),
+                quantity: trade.quantity,
+                z_score,
+            })
+        } else {
+            None
+        };
+
+        self.sizes.push(trade.quantity);
+        detected
+    }
+
+    /// 清空状态
+    pub fn reset(&mut self) {
+        self.sizes.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buy(quantity: i64) -> TradePrint {
+        TradePrint {
+            price: Decimal::from(100),
+            quantity: Decimal::from(quantity),
+            is_buyer_maker: false,
+        }
+    }
+
+    fn sell(quantity: i64) -> TradePrint {
+        TradePrint {
+            is_buyer_maker: true,
+            ..buy(quantity)
+        }
+    }
+
+    #[test]
+    fn test_trade_imbalance_and_cvd() {
+        let mut imbalance = TradeImbalance::new(3);
+        let mut cvd = CumulativeVolumeDelta::new(3);
+        let trades = [buy(3), sell(1), buy(2), sell(4)];
+
+        let mut outputs = Vec::new();
+        for trade in trades {
+            outputs.push((imbalance.update(trade), cvd.update(trade)));
+        }
+
+        assert_eq!(outputs[1].0, None);
+        // (3 - 1 + 2) / 6
+        assert_eq!(outputs[2].0, Some(Decimal::from(4) / Decimal::from(6)));
+        // (-1 + 2 - 4) / 7
+        assert_eq!(outputs[3].0, Some(Decimal::from(-3) / Decimal::from(7)));
+
+        let last = outputs[3].1.unwrap();
+        assert_eq!(last.cvd, Decimal::ZERO);
+        assert_eq!(last.delta, Decimal::from(-3));
+    }
+
+    #[test]
+    fn test_book_imbalance_uses_top_levels() {
+        let depth = DepthData {
+            bids: vec![(Decimal::from(99), Decimal::from(3)), (Decimal::from(98), Decimal::from(5))],
+            asks: vec![(Decimal::from(101), Decimal::ONE), (Decimal::from(102), Decimal::from(100))],
+        };
+        let mut one = BookImbalance::new(1);
+        let mut two = BookImbalance::new(2);
+        assert_eq!(one.update(&depth), Some(Decimal::new(5, 1)));
+        assert!(two.update(&depth).unwrap() < Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_vpin_buckets_split_large_trades() {
+        let mut vpin = Vpin::new(Decimal::from(10), 3);
+
+        // 桶 1：买 5 卖 5 → 0
+        assert_eq!(vpin.update(buy(5)), None);
+        assert_eq!(vpin.update(sell(5)), None);
+        // 一笔卖 25：桶 2、桶 3 全卖 → 1，余 5 进入桶 4
+        assert_eq!(vpin.update(sell(25)), Some(Decimal::from(2) / Decimal::from(3)));
+        // 桶 4：卖 5 买 5 → 0，窗口为 [1, 1, 0]
+        assert_eq!(vpin.update(buy(5)), Some(Decimal::from(2) / Decimal::from(3)));
+        assert_eq!(vpin.update(buy(10)), Some(Decimal::from(2) / Decimal::from(3)));
+    }
+
+    #[test]
+    fn test_large_trade_detector() {
+        let mut detector = LargeTradeDetector::new(4, Decimal::from(2));
+        for quantity in [1, 2, 1, 2] {
+            assert_eq!(detector.update(buy(quantity)), None);
+        }
+        assert_eq!(detector.update(sell(2)), None);
+
+        let large = detector.update(sell(10)).unwrap();
+        assert_eq!(large.side, OrderSide::Sell);
+        assert!(large.z_score > Decimal::from(2));
+    }
+}
@@ -0,0 +1,319 @@
+//! # 订单流策略 (Order-Flow Strategy)
+//!
+//! 综合逐笔成交与盘口深度上的微观结构特征（见 `features`）判断短期方向：
+//!
+//! - 方向分数 = 成交失衡度、盘口失衡度、CVD 变化率的加权平均（未就绪的特征不参与）
+//! - 同向大单额外加分，反向大单减分
+//! - VPIN 超过上限时订单流毒性过高，不开新仓
+//! - 分数超过入场阈值开仓，反向超过离场阈值平仓（市价单）
+//!
+//! 成交事件更新成交类特征并判断信号，深度事件只更新盘口失衡度。
+//! 现货只做多。
+
+pub mod features;
+
+use rust_decimal::prelude::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::PositionSnapshot;
+use shared::event::market_event::{MarketEvent, MarketEventData};
+use shared::types::order::OrderSide;
+use uuid::Uuid;
+
+use crate::domain::logic::indicator::Indicator;
+use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
+use crate::domain::model::market_type::MarketType;
+use crate::domain::model::signal::{OrderInstruction, OrderType, Signal, SignalType};
+
+pub use features::{
+    BookImbalance, CumulativeVolumeDelta, CvdOutput, LargeTrade, LargeTradeDetector,
+    TradeImbalance, TradePrint, Vpin,
+};
+
+/// 订单流策略配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OrderFlowConfig {
+    /// 成交失衡度周期（成交笔数）
+    pub imbalance_period: usize,
+    /// CVD 变化量周期（成交笔数）
+    pub cvd_period: usize,
+    /// 盘口失衡度统计档数
+    pub book_levels: usize,
+    /// VPIN 桶容量（成交量）
+    pub vpin_bucket_volume: Decimal,
+    /// VPIN 统计桶数
+    pub vpin_buckets: usize,
+    /// VPIN 上限，超过时不开新仓
+    pub max_vpin: Decimal,
+    /// 大单检测周期（成交笔数）
+    pub large_trade_period: usize,
+    /// 大单阈值（标准差倍数）
+    pub large_trade_std: Decimal,
+    /// 成交失衡度权重
+    pub trade_weight: Decimal,
+    /// 盘口失衡度权重
+    pub book_weight: Decimal,
+    /// CVD 变化率权重
+    pub cvd_weight: Decimal,
+    /// 大单加分
+    pub large_trade_boost: Decimal,
+    /// 入场阈值（方向分数绝对值）
+    pub entry_threshold: Decimal,
+    /// 离场阈值（反向分数绝对值）
+    pub exit_threshold: Decimal,
+    /// 交易数量
+    pub quantity: Decimal,
+}
+
+impl Default for OrderFlowConfig {
+    fn default() -> Self {
+        Self {
+            imbalance_period: 50,
+            cvd_period: 200,
+            book_levels: 5,
+            vpin_bucket_volume: Decimal::ONE,
+            vpin_buckets: 20,
+            max_vpin: Decimal::new(6, 1),           // 0.6
+            large_trade_period: 100,
+            large_trade_std: Decimal::from(3),
+            trade_weight: Decimal::new(4, 1),       // 0.4
+            book_weight: Decimal::new(4, 1),        // 0.4
+            cvd_weight: Decimal::new(2, 1),         // 0.2
+            large_trade_boost: Decimal::new(2, 1),  // 0.2
+            entry_threshold: Decimal::new(5, 1),    // 0.5
+            exit_threshold: Decimal::new(2, 1),     // 0.2
+            quantity: Decimal::new(1, 3),           // 0.001
+        }
+    }
+}
+
+/// 订单流策略状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderFlowState {
+    /// 成交失衡度
+    pub trade_imbalance: TradeImbalance,
+    /// 累计成交量差
+    pub cvd: CumulativeVolumeDelta,
+    /// 盘口失衡度
+    pub book_imbalance: BookImbalance,
+    /// VPIN
+    pub vpin: Vpin,
+    /// 大单检测
+    pub large_trades: LargeTradeDetector,
+    /// 当前持仓方向（None 为空仓）
+    pub position: Option<SignalType>,
+}
+
+impl OrderFlowState {
+    pub fn new(config: &OrderFlowConfig) -> Self {
+        Self {
+            trade_imbalance: TradeImbalance::new(config.imbalance_period),
+            cvd: CumulativeVolumeDelta::new(config.cvd_period),
+            book_imbalance: BookImbalance::new(config.book_levels),
+            vpin: Vpin::new(config.vpin_bucket_volume, config.vpin_buckets),
+            large_trades: LargeTradeDetector::new(config.large_trade_period, config.large_trade_std),
+            position: None,
+        }
+    }
+}
+
+/// 订单流策略
+pub struct OrderFlowStrategy {
+    meta: StrategyMeta,
+    config: OrderFlowConfig,
+    state: OrderFlowState,
+}
+
+impl OrderFlowStrategy {
+    /// 创建订单流策略实例
+    pub fn new(
+        instance_id: Uuid,
+        symbol: String,
+        config: OrderFlowConfig,
+        market_type: MarketType,
+    ) -> Self {
+        Self {
+            meta: StrategyMeta {
+                instance_id,
+                strategy_type: "order_flow".to_string(),
+                market_type,
+                symbol,
+                is_active: false,
+            },
+            state: OrderFlowState::new(&config),
+            config,
+        }
+    }
+
+    /// 方向分数：已就绪特征的加权平均，再叠加大单加分，截断到 [-1, 1]
+    fn score(
+        &self,
+        trade_imbalance: Decimal,
+        cvd: Option<CvdOutput>,
+        large_trade: Option<LargeTrade>,
+    ) -> Decimal {
+        let components = [
+            (Some(trade_imbalance), self.config.trade_weight),
+            (self.state.book_imbalance.value(), self.config.book_weight),
+            (cvd.map(|c| c.normalized), self.config.cvd_weight),
+        ];
+
+        let (weighted, total_weight) = components
+            .iter()
+            .filter_map(|(value, weight)| value.map(|v| (v * weight, *weight)))
+            .fold((Decimal::ZERO, Decimal::ZERO), |(sum, total), (v, w)| (sum + v, total + w));
+        let mut score = if total_weight > Decimal::ZERO {
+            weighted / total_weight
+        } else {
+            Decimal::ZERO
+        };
+
+        if let Some(large) = large_trade {
+            score += match large.side {
+                OrderSide::Buy => self.config.large_trade_boost,
+                OrderSide::Sell => -self.config.large_trade_boost,
+            };
+        }
+        score.clamp(-Decimal::ONE, Decimal::ONE)
+    }
+
+    /// 构造市价信号
+    fn signal(
+        &self,
+        event: &MarketEvent,
+        signal_type: SignalType,
+        price: Decimal,
+        score: Decimal,
+        instruction: OrderInstruction,
+    ) -> Signal {
+        Signal {
+            id: Uuid::new_v4(),
+            strategy_id: self.meta.instance_id,
+            symbol: event.symbol.clone(),
+            signal_type,
+            price,
+            quantity: self.config.quantity,
+            confidence: score.abs().to_f64().unwrap_or(0.0),
+            created_at: event.timestamp,
+            instruction: instruction.with_order_type(OrderType::Market),
+        }
+    }
+
+    /// 计算订单流信号
+    fn calculate_signal(&mut self, event: &MarketEvent) -> Option<Signal> {
+        if event.symbol != self.meta.symbol {
+            return None;
+        }
+
+        let trade = match &event.data {
+            MarketEventData::Trade(trade) => TradePrint::from_trade(trade),
+            MarketEventData::Depth(depth) => {
+                self.state.book_imbalance.update(depth);
+                return None;
+            }
+            _ => return None,
+        };
+
+        // 所有成交特征都要更新，不能短路
+        let trade_imbalance = self.state.trade_imbalance.update(trade);
+        let cvd = self.state.cvd.update(trade);
+        let vpin = self.state.vpin.update(trade);
+        let large_trade = self.state.large_trades.update(trade);
+
+        let score = self.score(trade_imbalance?, cvd, large_trade);
+
+        // 有持仓：反向分数超过离场阈值时平仓
+        match self.state.position {
+            Some(SignalType::Buy) if score <= -self.config.exit_threshold => {
+                self.state.position = None;
+                return Some(self.signal(event, SignalType::Sell, trade.price, score, OrderInstruction::close()));
+            }
+            Some(SignalType::Sell) if score >= self.config.exit_threshold => {
+                self.state.position = None;
+                return Some(self.signal(event, SignalType::Buy, trade.price, score, OrderInstruction::close()));
+            }
+            Some(_) => return None,
+            None => {}
+        }
+
+        // 订单流毒性过高（知情交易占比大）时不开新仓
+        if vpin.is_some_and(|v| v > self.config.max_vpin) {
+            return None;
+        }
+
+        let signal_type = if score >= self.config.entry_threshold {
+            SignalType::Buy
+        } else if score <= -self.config.entry_threshold && self.meta.market_type.is_futures() {
+            SignalType::Sell
+        } else {
+            return None;
+        };
+
+        self.state.position = Some(signal_type);
+        Some(self.signal(event, signal_type, trade.price, score, OrderInstruction::open()))
+    }
+}
+
+impl Strategy for OrderFlowStrategy {
+    fn meta(&self) -> &StrategyMeta {
+        &self.meta
+    }
+
+    fn meta_mut(&mut self) -> &mut StrategyMeta {
+        &mut self.meta
+    }
+
+    fn on_market_event(&mut self, event: &MarketEvent) -> Option<Signal> {
+        if !self.is_active() {
+            return None;
+        }
+        self.calculate_signal(event)
+    }
+
+    fn on_position_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {
+        if symbol != self.meta.symbol {
+            return;
+        }
+        self.state.position = if snapshot.is_long() {
+            Some(SignalType::Buy)
+        } else if snapshot.is_short() {
+            Some(SignalType::Sell)
+        } else {
+            None
+        };
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let mut state: OrderFlowState = serde_json::from_value(state)?;
+        // 参数热更新后周期变化的特征窗口无法沿用，重新预热
+        let fresh = OrderFlowState::new(&self.config);
+        if state.trade_imbalance.period() != fresh.trade_imbalance.period() {
+            state.trade_imbalance = fresh.trade_imbalance;
+        }
+        if state.cvd.period() != fresh.cvd.period() {
+            state.cvd = fresh.cvd;
+        }
+        if state.book_imbalance.levels() != fresh.book_imbalance.levels() {
+            state.book_imbalance = fresh.book_imbalance;
+        }
+        if state.vpin.bucket_volume() != fresh.vpin.bucket_volume()
+            || state.vpin.bucket_count() != fresh.vpin.bucket_count() {
+            state.vpin = fresh.vpin;
+        }
+        if state.large_trades.period() != fresh.large_trades.period()
+            || state.large_trades.threshold() != fresh.large_trades.threshold() {
+            state.large_trades = fresh.large_trades;
+        }
+        self.state = state;
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.state = OrderFlowState::new(&self.config);
+    }
+}
@@ -0,0 +1,171 @@
+//! # 平均趋向指标 (ADX)
+//!
+//! Wilder 定义：
+//! - +DM = 高 - 前高（大于 0 且大于 -DM 时），-DM = 前低 - 低（同理）
+//! - TR / +DM / -DM 前 n 个求和作为初值，之后 S = S - S/n + x
+//! - +DI = 100·(+DM)/TR，-DI = 100·(-DM)/TR，DX = 100·|+DI - -DI| / (+DI + -DI)
+//! - ADX：前 n 个 DX 的简单平均作为初值，之后 Wilder 平滑
+//!
+//! 需要 2n 根 K 线完成预热。
+
+use serde::{Deserialize, Serialize};
+
+use super::{Bar, Indicator, Numeric};
+
+/// ADX 输出
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AdxOutput<T> {
+    /// 趋势强度
+    pub adx: T,
+    /// 正向指标 +DI
+    pub plus_di: T,
+    /// 负向指标 -DI
+    pub minus_di: T,
+}
+
+/// Wilder 平滑后的 TR / +DM / -DM
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Smoothed<T> {
+    tr: T,
+    plus_dm: T,
+    minus_dm: T,
+}
+
+/// 平均趋向指标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Adx<T> {
+    /// 周期
+    period: usize,
+    /// 上一根 K 线
+    prev: Option<Bar<T>>,
+    /// 平滑值（预热完成后）
+    smoothed: Option<Smoothed<T>>,
+    /// 平滑预热期累计
+    seed: Smoothed<T>,
+    /// 平滑预热期已累计个数
+    seed_count: usize,
+    /// ADX 预热期 DX 累计
+    dx_sum: T,
+    /// ADX 预热期已累计个数
+    dx_count: usize,
+    /// 最新输出
+    last: Option<AdxOutput<T>>,
+}
+
+impl<T: Numeric> Adx<T> {
+    /// 创建 ADX
+    pub fn new(period: usize) -> Self {
+        let zero = T::zero_value();
+        Self {
+            period: period.max(1),
+            prev: None,
+            smoothed: None,
+            seed: Smoothed {
+                tr: zero,
+                plus_dm: zero,
+                minus_dm: zero,
+            },
+            seed_count: 0,
+            dx_sum: zero,
+            dx_count: 0,
+            last: None,
+        }
+    }
+}
+
+impl<T: Numeric> Indicator for Adx<T> {
+    type Input = Bar<T>;
+    type Output = AdxOutput<T>;
+
+    fn update(&mut self, input: Bar<T>) -> Option<AdxOutput<T>> {
+        let prev = self.prev.replace(input)?;
+        let zero = T::zero_value();
+        let n = T::from_count(self.period);
+
+        let up = input.high - prev.high;
+        let down = prev.low - input.low;
+        let plus_dm = if up > down && up > zero { up } else { zero };
+        let minus_dm = if down > up && down > zero { down } else { zero };
+        let true_range = input.true_range(Some(prev.close));
+
+        let smoothed = match self.smoothed {
+            Some(s) => Smoothed {
+                tr: s.tr - s.tr / n + true_range,
+                plus_dm: s.plus_dm - s.plus_dm / n + plus_dm,
+                minus_dm: s.minus_dm - s.minus_dm / n + minus_dm,
+            },
+            None => {
+                self.seed.tr = self.seed.tr + true_range;
+                self.seed.plus_dm = self.seed.plus_dm + plus_dm;
+                self.seed.minus_dm = self.seed.minus_dm + minus_dm;
+                self.seed_count += 1;
+                if self.seed_count < self.period {
+                    return None;
+                }
+                self.seed
+            }
+        };
+        self.smoothed = Some(smoothed);
+
+        // 无波动时方向指标无意义
+        if smoothed.tr.is_zero_value() {
+            return self.last;
+        }
+
+        let hundred = T::hundred();
+        let plus_di = hundred * smoothed.plus_dm / smoothed.tr;
+        let minus_di = hundred * smoothed.minus_dm / smoothed.tr;
+        let di_sum = plus_di + minus_di;
+        let dx = if di_sum.is_zero_value() {
+            zero
+        } else {
+            hundred * (plus_di - minus_di).absolute() / di_sum
+        };
+
+        let adx = match self.last {
+            Some(last) => (last.adx * T::from_count(self.period - 1) + dx) / n,
+            None => {
+                self.dx_sum = self.dx_sum + dx;
+                self.dx_count += 1;
+                if self.dx_count < self.period {
+                    return None;
+                }
+                self.dx_sum / n
+            }
+        };
+
+        self.last = Some(AdxOutput {
+            adx,
+            plus_di,
+            minus_di,
+        });
+        self.last
+    }
+
+    fn value(&self) -> Option<AdxOutput<T>> {
+        self.last
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new(self.period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::logic::indicator::test_data::{assert_close, bars};
+
+    #[test]
+    fn test_adx_reference() {
+        let mut adx = Adx::new(5);
+        let values: Vec<_> = bars().into_iter().map(|bar| adx.update(bar)).collect();
+        assert!(values[8].is_none());
+        assert!(values[9].is_some());
+
+        let last = values[29].unwrap();
+        assert_close(last.adx, 32.83899627267578);
+        assert_close(last.plus_di, 20.623114879601882);
+        assert_close(last.minus_di, 30.93613956658075);
+    }
+}
@@ -0,0 +1,92 @@
+//! # 平均真实波幅 (ATR)
+//!
+//! 真实波幅 TR = max(高 - 低, |高 - 前收|, |低 - 前收|)，首根 K 线为高 - 低。
+//! 前 n 个 TR 取简单平均作为初值，之后 Wilder 平滑 atr = (atr·(n-1) + tr) / n。
+
+use serde::{Deserialize, Serialize};
+
+use super::{Bar, Indicator, Numeric};
+
+/// 平均真实波幅
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Atr<T> {
+    /// 周期
+    period: usize,
+    /// 前收盘价
+    prev_close: Option<T>,
+    /// 当前值（预热完成后）
+    current: Option<T>,
+    /// 预热期 TR 累计
+    seed_sum: T,
+    /// 预热期已累计个数
+    seed_count: usize,
+}
+
+impl<T: Numeric> Atr<T> {
+    /// 创建 ATR
+    pub fn new(period: usize) -> Self {
+        Self {
+            period: period.max(1),
+            prev_close: None,
+            current: None,
+            seed_sum: T::zero_value(),
+            seed_count: 0,
+        }
+    }
+}
+
+impl<T: Numeric> Indicator for Atr<T> {
+    type Input = Bar<T>;
+    type Output = T;
+
+    fn update(&mut self, input: Bar<T>) -> Option<T> {
+        let true_range = input.true_range(self.prev_close);
+        self.prev_close = Some(input.close);
+        let n = T::from_count(self.period);
+
+        match self.current {
+            Some(prev) => {
+                self.current = Some((prev * T::from_count(self.period - 1) + true_range) / n);
+            }
+            None => {
+                self.seed_sum = self.seed_sum + true_range;
+                self.seed_count += 1;
+                if self.seed_count >= self.period {
+                    self.current = Some(self.seed_sum / n);
+                }
+            }
+        }
+        self.current
+    }
+
+    fn value(&self) -> Option<T> {
+        self.current
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new(self.period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::logic::indicator::test_data::{assert_close, bars};
+
+    #[test]
+    fn test_atr_reference() {
+        let mut atr14 = Atr::new(14);
+        let mut atr5 = Atr::new(5);
+        let mut last14 = None;
+        let mut last5 = None;
+        for (i, bar) in bars().into_iter().enumerate() {
+            last14 = atr14.update(bar);
+            last5 = atr5.update(bar);
+            if i == 12 {
+                assert!(last14.is_none());
+            }
+        }
+        assert_close(last14.unwrap(), 0.8197701400365126);
+        assert_close(last5.unwrap(), 0.8436320253661709);
+    }
+}
@@ -0,0 +1,173 @@
+//! # 标准差与布林带 (Standard Deviation / Bollinger Bands)
+//!
+//! 中轨 = SMA(n)，上下轨 = 中轨 ± k·σ，σ 为窗口总体标准差（除以 n）。
+//! 滚动和 + 滚动平方和，每次更新 O(1)。
+
+use serde::{Deserialize, Serialize};
+
+use super::window::RollingWindow;
+use super::{Indicator, Numeric};
+
+/// 滚动总体标准差
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StdDev<T> {
+    window: RollingWindow<T>,
+}
+
+impl<T: Numeric> StdDev<T> {
+    /// 创建标准差
+    pub fn new(period: usize) -> Self {
+        Self {
+            window: RollingWindow::new(period),
+        }
+    }
+
+    /// 周期
+    pub fn period(&self) -> usize {
+        self.window.capacity()
+    }
+
+    /// 回放另一个标准差窗口内的值（按本实例的周期截取最新部分）
+    pub fn replay(&mut self, other: &StdDev<T>) {
+        self.window.replay(&other.window);
+    }
+
+    /// 窗口均值（窗口填满后）
+    pub fn mean(&self) -> Option<T> {
+        if self.window.is_full() {
+            self.window.mean()
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Numeric> Indicator for StdDev<T> {
+    type Input = T;
+    type Output = T;
+
+    fn update(&mut self, input: T) -> Option<T> {
+        self.window.push(input);
+        self.value()
+    }
+
+    fn value(&self) -> Option<T> {
+        if self.window.is_full() {
+            self.window.std_dev()
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+/// 布林带输出
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BollingerOutput<T> {
+    /// 上轨
+    pub upper: T,
+    /// 中轨
+    pub middle: T,
+    /// 下轨
+    pub lower: T,
+    /// 标准差
+    pub std_dev: T,
+}
+
+/// 布林带
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bollinger<T> {
+    window: RollingWindow<T>,
+    /// 标准差倍数
+    multiplier: T,
+}
+
+impl<T: Numeric> Bollinger<T> {
+    /// 创建布林带
+    pub fn new(period: usize, multiplier: T) -> Self {
+        Self {
+            window: RollingWindow::new(period),
+            multiplier,
+        }
+    }
+
+    /// 周期
+    pub fn period(&self) -> usize {
+        self.window.capacity()
+    }
+
+    /// 标准差倍数
+    pub fn multiplier(&self) -> T {
+        self.multiplier
+    }
+
+    /// 回放另一条布林带窗口内的值，周期与倍数沿用本实例
+    pub fn replay(&mut self, other: &Bollinger<T>) {
+        self.window.replay(&other.window);
+    }
+}
+
+impl<T: Numeric> Indicator for Bollinger<T> {
+    type Input = T;
+    type Output = BollingerOutput<T>;
+
+    fn update(&mut self, input: T) -> Option<BollingerOutput<T>> {
+        self.window.push(input);
+        self.value()
+    }
+
+    fn value(&self) -> Option<BollingerOutput<T>> {
+        if !self.window.is_full() {
+            return None;
+        }
+        let middle = self.window.mean()?;
+        let std_dev = self.window.std_dev()?;
+        let width = std_dev * self.multiplier;
+
+        Some(BollingerOutput {
+            upper: middle + width,
+            middle,
+            lower: middle - width,
+            std_dev,
+        })
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::logic::indicator::test_data::{assert_close, CLOSES};
+    use rust_decimal::prelude::ToPrimitive;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_bollinger_reference() {
+        let mut bands = Bollinger::new(20, 2.0);
+        let values: Vec<_> = CLOSES.iter().map(|&c| bands.update(c)).collect();
+        assert!(values[18].is_none());
+
+        let last = values[29].unwrap();
+        assert_close(last.middle, 45.657);
+        assert_close(last.std_dev, 0.7611379638409848);
+        assert_close(last.upper, 47.179275927681964);
+        assert_close(last.lower, 44.13472407231803);
+    }
+
+    #[test]
+    fn test_std_dev_decimal() {
+        let mut std_dev = StdDev::new(20);
+        for c in CLOSES {
+            std_dev.update(c.to_string().parse::<Decimal>().unwrap());
+        }
+        assert_eq!(std_dev.mean(), Some(Decimal::new(45657, 3)));
+        let value = std_dev.value().unwrap().to_f64().unwrap();
+        assert!((value - 0.7611379638409848).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,83 @@
+//! # 肯特纳通道 (Keltner Channel)
+//!
+//! 中轨 = EMA(收盘价, n)，上下轨 = 中轨 ± k·ATR(m)。
+
+use serde::{Deserialize, Serialize};
+
+use super::atr::Atr;
+use super::moving_average::Ema;
+use super::{Bar, Indicator, Numeric};
+
+/// 肯特纳通道输出
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct KeltnerOutput<T> {
+    /// 上轨
+    pub upper: T,
+    /// 中轨
+    pub middle: T,
+    /// 下轨
+    pub lower: T,
+}
+
+/// 肯特纳通道
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Keltner<T> {
+    ema: Ema<T>,
+    atr: Atr<T>,
+    /// ATR 倍数
+    multiplier: T,
+}
+
+impl<T: Numeric> Keltner<T> {
+    /// 创建肯特纳通道
+    pub fn new(ema_period: usize, atr_period: usize, multiplier: T) -> Self {
+        Self {
+            ema: Ema::new(ema_period),
+            atr: Atr::new(atr_period),
+            multiplier,
+        }
+    }
+}
+
+impl<T: Numeric> Indicator for Keltner<T> {
+    type Input = Bar<T>;
+    type Output = KeltnerOutput<T>;
+
+    fn update(&mut self, input: Bar<T>) -> Option<KeltnerOutput<T>> {
+        self.ema.update(input.close);
+        self.atr.update(input);
+        self.value()
+    }
+
+    fn value(&self) -> Option<KeltnerOutput<T>> {
+        let middle = self.ema.value()?;
+        let width = self.atr.value()? * self.multiplier;
+        Some(KeltnerOutput {
+            upper: middle + width,
+            middle,
+            lower: middle - width,
+        })
+    }
+
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.atr.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::logic::indicator::test_data::{assert_close, bars};
+
+    #[test]
+    fn test_keltner_reference() {
+        let mut keltner = Keltner::new(10, 10, 2.0);
+        let last = bars().into_iter().map(|bar| keltner.update(bar)).last().flatten();
+
+        let last = last.unwrap();
+        assert_close(last.upper, 46.666570902663565);
+        assert_close(last.middle, 44.99946089061762);
+        assert_close(last.lower, 43.332350878571674);
+    }
+}
@@ -0,0 +1,96 @@
+//! # MACD
+//!
+//! MACD 线 = EMA(fast) - EMA(slow)，信号线 = EMA(MACD 线, signal)，柱 = MACD - 信号线。
+//! 三条 EMA 均以 SMA 作为初值，信号线从 MACD 线可用后开始预热。
+
+use serde::{Deserialize, Serialize};
+
+use super::moving_average::Ema;
+use super::{Indicator, Numeric};
+
+/// MACD 输出
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MacdOutput<T> {
+    /// MACD 线
+    pub macd: T,
+    /// 信号线
+    pub signal: T,
+    /// 柱状图（MACD - 信号线）
+    pub histogram: T,
+}
+
+/// MACD
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Macd<T> {
+    fast: Ema<T>,
+    slow: Ema<T>,
+    signal: Ema<T>,
+    /// 最新输出
+    last: Option<MacdOutput<T>>,
+}
+
+impl<T: Numeric> Macd<T> {
+    /// 创建 MACD
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Self {
+        Self {
+            fast: Ema::new(fast_period),
+            slow: Ema::new(slow_period),
+            signal: Ema::new(signal_period),
+            last: None,
+        }
+    }
+
+    /// 周期 (fast, slow, signal)
+    pub fn periods(&self) -> (usize, usize, usize) {
+        (self.fast.period(), self.slow.period(), self.signal.period())
+    }
+}
+
+impl<T: Numeric> Indicator for Macd<T> {
+    type Input = T;
+    type Output = MacdOutput<T>;
+
+    fn update(&mut self, input: T) -> Option<MacdOutput<T>> {
+        let fast = self.fast.update(input);
+        let slow = self.slow.update(input);
+        let macd = fast? - slow?;
+        let signal = self.signal.update(macd)?;
+
+        self.last = Some(MacdOutput {
+            macd,
+            signal,
+            histogram: macd - signal,
+        });
+        self.last
+    }
+
+    fn value(&self) -> Option<MacdOutput<T>> {
+        self.last
+    }
+
+    fn reset(&mut self) {
+        self.fast.reset();
+        self.slow.reset();
+        self.signal.reset();
+        self.last = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::logic::indicator::test_data::{assert_close, CLOSES};
+
+    #[test]
+    fn test_macd_reference() {
+        let mut macd = Macd::new(12, 26, 3);
+        let values: Vec<_> = CLOSES.iter().map(|&c| macd.update(c)).collect();
+        assert!(values[26].is_none());
+        assert!(values[27].is_some());
+
+        let last = values[29].unwrap();
+        assert_close(last.macd, -0.11590361200609323);
+        assert_close(last.signal, -0.037446006950360086);
+        assert_close(last.histogram, -0.07845760505573314);
+    }
+}
@@ -0,0 +1,262 @@
+//! # 技术指标库 (Technical Indicators)
+//!
+//! 策略共用的流式技术指标。每个指标持有自己的滚动状态，逐笔 / 逐根 K 线更新。
+//!
+//! ## 规则
+//! - ✅ 每次 `update` 为 O(1)（滚动和、Wilder 平滑、单调队列）
+//! - ✅ 数值类型泛化：`Decimal`（与订单价格一致）和 `f64`（回测 / 研究）
+//! - ✅ 可序列化，策略状态检查点直接包含指标状态
+//! - ✅ 预热期返回 `None`，不输出未收敛的值
+//! - ❌ 不持有交易逻辑，不产生信号
+//!
+//! ## 指标列表
+//! - 均线：`Sma` / `Ema` / `Wma`
+//! - 动量：`Rsi` / `Macd` / `Stochastic` / `Adx`
+//! - 波动：`StdDev` / `Bollinger` / `Atr` / `Keltner`
+//! - 量价：`Vwap` / `Obv`
+//! - 回归：`RollingOls` / `KalmanRegression`
+
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use rust_decimal::{Decimal, MathematicalOps};
+use serde::{Deserialize, Serialize};
+
+/// 滚动窗口与滚动极值
+pub mod window;
+
+/// 均线（SMA / EMA / WMA）
+pub mod moving_average;
+
+/// 相对强弱指标（RSI）
+pub mod rsi;
+
+/// 指数平滑异同移动平均线（MACD）
+pub mod macd;
+
+/// 标准差与布林带（Bollinger Bands）
+pub mod bollinger;
+
+/// 平均真实波幅（ATR）
+pub mod atr;
+
+/// 平均趋向指标（ADX）
+pub mod adx;
+
+/// 随机指标（Stochastic）
+pub mod stochastic;
+
+/// 量价指标（VWAP / OBV）
+pub mod volume;
+
+/// 肯特纳通道（Keltner Channel）
+pub mod keltner;
+
+/// 线性回归（滚动最小二乘 / 卡尔曼滤波）
+pub mod regression;
+
+pub use adx::{Adx, AdxOutput};
+pub use atr::Atr;
+pub use bollinger::{Bollinger, BollingerOutput, StdDev};
+pub use keltner::{Keltner, KeltnerOutput};
+pub use macd::{Macd, MacdOutput};
+pub use moving_average::{Ema, Sma, Wma};
+pub use regression::{KalmanOutput, KalmanRegression, RegressionOutput, RollingOls};
+pub use rsi::Rsi;
+pub use stochastic::{Stochastic, StochasticOutput};
+pub use volume::{Obv, Vwap};
+pub use window::{RollingExtremum, RollingWindow};
+
+/// 指标数值类型
+///
+/// 为 `Decimal` 和 `f64` 实现。方法名避开 `rust_decimal::prelude` 中的同名方法，
+/// 策略文件同时导入两者时不会产生歧义。
+pub trait Numeric:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// 0
+    fn zero_value() -> Self;
+
+    /// 由计数转换（周期、权重）
+    fn from_count(count: usize) -> Self;
+
+    /// 平方根（负数返回 0）
+    fn square_root(self) -> Self;
+
+    /// 1
+    fn one_value() -> Self {
+        Self::from_count(1)
+    }
+
+    /// 100（百分比类指标）
+    fn hundred() -> Self {
+        Self::from_count(100)
+    }
+
+    /// 绝对值
+    fn absolute(self) -> Self {
+        if self < Self::zero_value() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// 较大值
+    fn larger(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// 是否为 0（除法前检查，`Decimal` 除以 0 会 panic）
+    fn is_zero_value(self) -> bool {
+        self == Self::zero_value()
+    }
+}
+
+impl Numeric for Decimal {
+    fn zero_value() -> Self {
+        Decimal::ZERO
+    }
+
+    fn from_count(count: usize) -> Self {
+        Decimal::from(count)
+    }
+
+    fn square_root(self) -> Self {
+        self.sqrt().unwrap_or(Decimal::ZERO)
+    }
+}
+
+impl Numeric for f64 {
+    fn zero_value() -> Self {
+        0.0
+    }
+
+    fn from_count(count: usize) -> Self {
+        count as f64
+    }
+
+    fn square_root(self) -> Self {
+        if self > 0.0 {
+            self.sqrt()
+        } else {
+            0.0
+        }
+    }
+}
+
+/// K 线（指标输入）
+///
+/// 只有成交价的逐笔数据用 `Bar::from_price` 构造（高 = 低 = 收）。
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Bar<T> {
+    /// 最高价
+    pub high: T,
+    /// 最低价
+    pub low: T,
+    /// 收盘价
+    pub close: T,
+    /// 成交量
+    pub volume: T,
+}
+
+impl<T: Numeric> Bar<T> {
+    /// 创建 K 线
+    pub fn new(high: T, low: T, close: T, volume: T) -> Self {
+        Self {
+            high,
+            low,
+            close,
+            volume,
+        }
+    }
+
+    /// 由单笔成交构造
+    pub fn from_price(price: T, volume: T) -> Self {
+        Self::new(price, price, price, volume)
+    }
+
+    /// 典型价格 (high + low + close) / 3
+    pub fn typical_price(&self) -> T {
+        (self.high + self.low + self.close) / T::from_count(3)
+    }
+
+    /// 真实波幅（无前收盘价时为高低差）
+    pub fn true_range(&self, prev_close: Option<T>) -> T {
+        let range = self.high - self.low;
+        match prev_close {
+            Some(prev) => range
+                .larger((self.high - prev).absolute())
+                .larger((self.low - prev).absolute()),
+            None => range,
+        }
+    }
+}
+
+/// 流式指标
+pub trait Indicator {
+    /// 输入（价格或 K 线）
+    type Input;
+    /// 输出
+    type Output;
+
+    /// 输入一个新值，返回最新指标值（预热期返回 None）
+    fn update(&mut self, input: Self::Input) -> Option<Self::Output>;
+
+    /// 最新指标值（不推进状态）
+    fn value(&self) -> Option<Self::Output>;
+
+    /// 清空状态，重新预热
+    fn reset(&mut self);
+}
+
+/// 测试数据
+///
+/// 收盘价为 StockCharts RSI 教程中的 30 个样本，高低价与成交量按固定规则生成。
+/// 各指标测试的参考值由教科书定义（非流式、逐窗口重算）独立计算得到。
+#[cfg(test)]
+pub(crate) mod test_data {
+    use super::Bar;
+
+    pub const CLOSES: [f64; 30] = [
+        44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42, 45.84, 46.08, 45.89, 46.03,
+        45.61, 46.28, 46.28, 46.00, 46.03, 46.41, 46.22, 45.64, 46.21, 46.25, 45.71, 46.45,
+        45.78, 45.35, 44.03, 44.18, 44.22, 44.57,
+    ];
+
+    pub fn bars() -> Vec<Bar<f64>> {
+        CLOSES
+            .iter()
+            .enumerate()
+            .map(|(i, &close)| {
+                Bar::new(
+                    close + 0.3 + 0.05 * (i % 3) as f64,
+                    close - 0.25 - 0.04 * (i % 4) as f64,
+                    close,
+                    (1000 + 37 * (i % 7) + 11 * i) as f64,
+                )
+            })
+            .collect()
+    }
+
+    pub fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+}
@@ -0,0 +1,225 @@
+//! # 均线 (Moving Averages)
+//!
+//! - `Sma`：简单移动平均，滚动和
+//! - `Ema`：指数移动平均，α = 2 / (n + 1)，以前 n 个值的 SMA 作为初值
+//! - `Wma`：线性加权移动平均（最新权重为 n），滚动和 + 滚动加权和
+
+use serde::{Deserialize, Serialize};
+
+use super::window::RollingWindow;
+use super::{Indicator, Numeric};
+
+/// 简单移动平均
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sma<T> {
+    window: RollingWindow<T>,
+}
+
+impl<T: Numeric> Sma<T> {
+    /// 创建 SMA
+    pub fn new(period: usize) -> Self {
+        Self {
+            window: RollingWindow::new(period),
+        }
+    }
+
+    /// 周期
+    pub fn period(&self) -> usize {
+        self.window.capacity()
+    }
+
+    /// 回放另一个 SMA 窗口内的值（按本实例的周期截取最新部分）
+    pub fn replay(&mut self, other: &Sma<T>) {
+        self.window.replay(&other.window);
+    }
+}
+
+impl<T: Numeric> Indicator for Sma<T> {
+    type Input = T;
+    type Output = T;
+
+    fn update(&mut self, input: T) -> Option<T> {
+        self.window.push(input);
+        self.value()
+    }
+
+    fn value(&self) -> Option<T> {
+        if self.window.is_full() {
+            self.window.mean()
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+/// 指数移动平均
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ema<T> {
+    /// 周期
+    period: usize,
+    /// 当前值（预热完成后）
+    current: Option<T>,
+    /// 预热期累计和
+    seed_sum: T,
+    /// 预热期已输入个数
+    seed_count: usize,
+}
+
+impl<T: Numeric> Ema<T> {
+    /// 创建 EMA
+    pub fn new(period: usize) -> Self {
+        Self {
+            period: period.max(1),
+            current: None,
+            seed_sum: T::zero_value(),
+            seed_count: 0,
+        }
+    }
+
+    /// 周期
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// 平滑系数 α = 2 / (n + 1)
+    fn alpha(&self) -> T {
+        T::from_count(2) / T::from_count(self.period + 1)
+    }
+}
+
+impl<T: Numeric> Indicator for Ema<T> {
+    type Input = T;
+    type Output = T;
+
+    fn update(&mut self, input: T) -> Option<T> {
+        match self.current {
+            Some(prev) => {
+                self.current = Some(prev + self.alpha() * (input - prev));
+            }
+            None => {
+                self.seed_sum = self.seed_sum + input;
+                self.seed_count += 1;
+                if self.seed_count >= self.period {
+                    self.current = Some(self.seed_sum / T::from_count(self.period));
+                }
+            }
+        }
+        self.current
+    }
+
+    fn value(&self) -> Option<T> {
+        self.current
+    }
+
+    fn reset(&mut self) {
+        self.current = None;
+        self.seed_sum = T::zero_value();
+        self.seed_count = 0;
+    }
+}
+
+/// 线性加权移动平均
+///
+/// 窗口满后输入 x：加权和 W' = W - S + n·x（每个旧值权重减 1，最旧值权重归 0），
+/// 滚动和 S' = S - x_oldest + x。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Wma<T> {
+    window: RollingWindow<T>,
+    /// 加权和
+    weighted_sum: T,
+}
+
+impl<T: Numeric> Wma<T> {
+    /// 创建 WMA
+    pub fn new(period: usize) -> Self {
+        Self {
+            window: RollingWindow::new(period),
+            weighted_sum: T::zero_value(),
+        }
+    }
+
+    /// 权重和 n(n + 1) / 2
+    fn weight_total(&self) -> T {
+        let n = self.window.capacity();
+        T::from_count(n * (n + 1) / 2)
+    }
+}
+
+impl<T: Numeric> Indicator for Wma<T> {
+    type Input = T;
+    type Output = T;
+
+    fn update(&mut self, input: T) -> Option<T> {
+        if self.window.is_full() {
+            let sum_before = self.window.sum();
+            self.weighted_sum = self.weighted_sum - sum_before
+                + T::from_count(self.window.capacity()) * input;
+        } else {
+            self.weighted_sum = self.weighted_sum + T::from_count(self.window.len() + 1) * input;
+        }
+        self.window.push(input);
+        self.value()
+    }
+
+    fn value(&self) -> Option<T> {
+        if self.window.is_full() {
+            Some(self.weighted_sum / self.weight_total())
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.weighted_sum = T::zero_value();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::logic::indicator::test_data::{assert_close, CLOSES};
+    use rust_decimal::prelude::ToPrimitive;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_sma_reference() {
+        let mut sma = Sma::new(5);
+        let values: Vec<_> = CLOSES.iter().map(|&c| sma.update(c)).collect();
+        assert!(values[3].is_none());
+        assert_close(values[29].unwrap(), 44.47);
+    }
+
+    #[test]
+    fn test_ema_reference() {
+        let mut ema = Ema::new(10);
+        let values: Vec<_> = CLOSES.iter().map(|&c| ema.update(c)).collect();
+        assert!(values[8].is_none());
+        assert_close(values[9].unwrap(), 44.779);
+        assert_close(values[29].unwrap(), 44.99946089061762);
+    }
+
+    #[test]
+    fn test_wma_reference() {
+        let mut wma = Wma::new(5);
+        let last = CLOSES.iter().map(|&c| wma.update(c)).last().flatten();
+        assert_close(last.unwrap(), 44.37866666666666);
+    }
+
+    #[test]
+    fn test_decimal_matches_f64() {
+        let mut sma = Sma::new(5);
+        let mut wma = Wma::new(5);
+        for c in CLOSES {
+            let d: Decimal = c.to_string().parse().unwrap();
+            sma.update(d);
+            wma.update(d);
+        }
+        assert_eq!(sma.value(), Some(Decimal::new(4447, 2)));
+        assert_close(wma.value().unwrap().to_f64().unwrap(), 44.37866666666666);
+    }
+}
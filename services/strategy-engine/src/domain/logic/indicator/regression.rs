@@ -0,0 +1,268 @@
+//! # 线性回归 (Linear Regression)
+//!
+//! 两个序列之间的滚动线性关系 y = intercept + slope · x：
+//! - `RollingOls`：最近 N 个样本的普通最小二乘，维护滚动和，O(1)
+//! - `KalmanRegression`：斜率与截距作为随机游走状态的卡尔曼滤波，逐样本自适应
+//!
+//! 用于配对交易的对冲比率估计、价差 AR(1) 回归求半衰期等。
+
+use serde::{Deserialize, Serialize};
+
+use super::window::RollingWindow;
+use super::{Indicator, Numeric};
+
+/// 回归输出
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegressionOutput<T> {
+    /// 斜率
+    pub slope: T,
+    /// 截距
+    pub intercept: T,
+}
+
+impl<T: Numeric> RegressionOutput<T> {
+    /// 残差 y − (intercept + slope · x)
+    pub fn residual(&self, x: T, y: T) -> T {
+        y - (self.intercept + self.slope * x)
+    }
+}
+
+/// 滚动最小二乘
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollingOls<T> {
+    /// 自变量窗口（提供 Σx、Σx²）
+    x: RollingWindow<T>,
+    /// 因变量窗口（提供 Σy）
+    y: RollingWindow<T>,
+    /// 乘积窗口（提供 Σxy）
+    xy: RollingWindow<T>,
+}
+
+impl<T: Numeric> RollingOls<T> {
+    /// 创建滚动最小二乘（周期至少为 2）
+    pub fn new(period: usize) -> Self {
+        let period = period.max(2);
+        Self {
+            x: RollingWindow::new(period),
+            y: RollingWindow::new(period),
+            xy: RollingWindow::new(period),
+        }
+    }
+
+    /// 周期
+    pub fn period(&self) -> usize {
+        self.x.capacity()
+    }
+
+    /// 回放另一个回归窗口内的样本（按本实例的周期截取最新部分）
+    pub fn replay(&mut self, other: &RollingOls<T>) {
+        self.x.replay(&other.x);
+        self.y.replay(&other.y);
+        self.xy.replay(&other.xy);
+    }
+}
+
+impl<T: Numeric> Indicator for RollingOls<T> {
+    type Input = (T, T);
+    type Output = RegressionOutput<T>;
+
+    /// 输入 (x, y)
+    fn update(&mut self, (x, y): (T, T)) -> Option<RegressionOutput<T>> {
+        self.x.push(x);
+        self.y.push(y);
+        self.xy.push(x * y);
+        self.value()
+    }
+
+    /// 窗口填满且 x 不全相同时输出
+    fn value(&self) -> Option<RegressionOutput<T>> {
+        if !self.x.is_full() {
+            return None;
+        }
+
+        let n = T::from_count(self.x.len());
+        let sum_x = self.x.sum();
+        let sum_y = self.y.sum();
+        let denominator = n * self.x.variance()? * n;
+        if denominator.is_zero_value() {
+            return None;
+        }
+
+        let slope = (n * self.xy.sum() - sum_x * sum_y) / denominator;
+        let intercept = (sum_y - slope * sum_x) / n;
+        Some(RegressionOutput { slope, intercept })
+    }
+
+    fn reset(&mut self) {
+        self.x.clear();
+        self.y.clear();
+        self.xy.clear();
+    }
+}
+
+/// 卡尔曼滤波输出
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KalmanOutput<T> {
+    /// 更新后的斜率与截距
+    pub regression: RegressionOutput<T>,
+    /// 预测误差 y − ŷ（更新前的状态预测）
+    pub error: T,
+    /// 预测误差方差
+    pub error_variance: T,
+}
+
+/// 卡尔曼滤波回归
+///
+/// 状态 θ = [slope, intercept] 按随机游走演化，过程噪声协方差为 δ/(1−δ)·I，
+/// 观测 y = [x, 1]·θ + ε，ε 的方差为 `observation_variance`。
+/// δ 越大，对冲比率调整越快。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KalmanRegression<T> {
+    /// 过程噪声比例 δ ∈ (0, 1)
+    delta: T,
+    /// 观测噪声方差
+    observation_variance: T,
+    /// 状态 [slope, intercept]
+    state: [T; 2],
+    /// 状态协方差
+    covariance: [[T; 2]; 2],
+    /// 最近一次的预测误差与误差方差
+    last: Option<(T, T)>,
+    /// 已处理的样本数
+    samples: usize,
+    /// 预热样本数
+    warmup: usize,
+}
+
+impl<T: Numeric> KalmanRegression<T> {
+    /// 创建卡尔曼滤波回归（前 `warmup` 个样本不输出）
+    pub fn new(delta: T, observation_variance: T, warmup: usize) -> Self {
+        let zero = T::zero_value();
+        Self {
+            delta,
+            observation_variance,
+            state: [zero, zero],
+            covariance: [[zero, zero], [zero, zero]],
+            last: None,
+            samples: 0,
+            warmup,
+        }
+    }
+
+    /// 过程噪声比例
+    pub fn delta(&self) -> T {
+        self.delta
+    }
+
+    /// 观测噪声方差
+    pub fn observation_variance(&self) -> T {
+        self.observation_variance
+    }
+
+    /// 替换预热样本数，已收敛的状态保持不变
+    pub fn with_warmup(mut self, warmup: usize) -> Self {
+        self.warmup = warmup;
+        self
+    }
+
+    /// 当前斜率与截距
+    pub fn regression(&self) -> RegressionOutput<T> {
+        RegressionOutput {
+            slope: self.state[0],
+            intercept: self.state[1],
+        }
+    }
+}
+
+impl<T: Numeric> Indicator for KalmanRegression<T> {
+    type Input = (T, T);
+    type Output = KalmanOutput<T>;
+
+    /// 输入 (x, y)
+    fn update(&mut self, (x, y): (T, T)) -> Option<KalmanOutput<T>> {
+        let one = T::one_value();
+        let process = self.delta / (one - self.delta);
+        let p = self.covariance;
+
+        // 预测：R = P + Vw
+        let r = [[p[0][0] + process, p[0][1]], [p[1][0], p[1][1] + process]];
+
+        // 观测向量 h = [x, 1]
+        let predicted = self.state[0] * x + self.state[1];
+        let rh = [r[0][0] * x + r[0][1], r[1][0] * x + r[1][1]];
+        let error_variance = x * rh[0] + rh[1] + self.observation_variance;
+        let error = y - predicted;
+        self.samples += 1;
+        if error_variance.is_zero_value() {
+            return None;
+        }
+
+        // 更新：K = R·hᵀ / Q，θ += K·e，P = R − K·h·R
+        let gain = [rh[0] / error_variance, rh[1] / error_variance];
+        self.state = [self.state[0] + gain[0] * error, self.state[1] + gain[1] * error];
+        // h·R = (R·hᵀ)ᵀ（R 对称）
+        self.covariance = [
+            [r[0][0] - gain[0] * rh[0], r[0][1] - gain[0] * rh[1]],
+            [r[1][0] - gain[1] * rh[0], r[1][1] - gain[1] * rh[1]],
+        ];
+
+        self.last = Some((error, error_variance));
+        self.value()
+    }
+
+    fn value(&self) -> Option<KalmanOutput<T>> {
+        if self.samples <= self.warmup {
+            return None;
+        }
+        self.last.map(|(error, error_variance)| KalmanOutput {
+            regression: self.regression(),
+            error,
+            error_variance,
+        })
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new(self.delta, self.observation_variance, self.warmup);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_rolling_ols_recovers_exact_line() {
+        let mut ols = RollingOls::new(5);
+        let mut last = None;
+        for i in 0..8 {
+            let x = i as f64 * 1.5 + (i % 3) as f64;
+            last = ols.update((x, 2.0 + 0.5 * x));
+        }
+        let fit = last.unwrap();
+        assert!((fit.slope - 0.5).abs() < 1e-9);
+        assert!((fit.intercept - 2.0).abs() < 1e-9);
+        assert!(fit.residual(10.0, 7.0).abs() < 1e-9);
+
+        // x 全相同时斜率无定义
+        let mut flat = RollingOls::new(3);
+        for y in [1, 2, 3] {
+            assert_eq!(flat.update((Decimal::ONE, Decimal::from(y))), None);
+        }
+    }
+
+    #[test]
+    fn test_kalman_converges_to_hedge_ratio() {
+        let mut kalman = KalmanRegression::new(1e-4, 1e-3, 10);
+        let mut last = None;
+        for i in 0..500 {
+            let x = 100.0 + (i as f64 * 0.37).sin() * 5.0 + i as f64 * 0.01;
+            let noise = ((i * 7919) % 13) as f64 / 13.0 - 0.5;
+            last = kalman.update((x, 1.8 * x + 3.0 + noise * 0.01)).or(last);
+        }
+        let output = last.unwrap();
+        assert!((output.regression.slope - 1.8).abs() < 0.05, "slope {}", output.regression.slope);
+        assert!(output.error.abs() < 0.5);
+        assert!(output.error_variance > 0.0);
+    }
+}
@@ -0,0 +1,143 @@
+//! # 相对强弱指标 (RSI)
+//!
+//! Wilder 平滑：前 n 个涨跌幅取简单平均作为初值，之后
+//! avg = (avg·(n-1) + x) / n。需要 n + 1 个价格完成预热。
+
+use serde::{Deserialize, Serialize};
+
+use super::{Indicator, Numeric};
+
+/// 相对强弱指标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rsi<T> {
+    /// 周期
+    period: usize,
+    /// 上一个价格
+    prev_price: Option<T>,
+    /// 平均涨幅（预热完成后）
+    avg_gain: Option<T>,
+    /// 平均跌幅（预热完成后）
+    avg_loss: Option<T>,
+    /// 预热期涨幅累计
+    seed_gain: T,
+    /// 预热期跌幅累计
+    seed_loss: T,
+    /// 预热期已累计的涨跌幅个数
+    seed_count: usize,
+}
+
+impl<T: Numeric> Rsi<T> {
+    /// 创建 RSI
+    pub fn new(period: usize) -> Self {
+        Self {
+            period: period.max(1),
+            prev_price: None,
+            avg_gain: None,
+            avg_loss: None,
+            seed_gain: T::zero_value(),
+            seed_loss: T::zero_value(),
+            seed_count: 0,
+        }
+    }
+
+    /// 周期
+    pub fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: Numeric> Indicator for Rsi<T> {
+    type Input = T;
+    type Output = T;
+
+    fn update(&mut self, input: T) -> Option<T> {
+        let prev = self.prev_price.replace(input)?;
+        let zero = T::zero_value();
+        let change = input - prev;
+        let gain = if change > zero { change } else { zero };
+        let loss = if change < zero { -change } else { zero };
+        let n = T::from_count(self.period);
+
+        match (self.avg_gain, self.avg_loss) {
+            (Some(avg_gain), Some(avg_loss)) => {
+                let m = T::from_count(self.period - 1);
+                self.avg_gain = Some((avg_gain * m + gain) / n);
+                self.avg_loss = Some((avg_loss * m + loss) / n);
+            }
+            _ => {
+                self.seed_gain = self.seed_gain + gain;
+                self.seed_loss = self.seed_loss + loss;
+                self.seed_count += 1;
+                if self.seed_count < self.period {
+                    return None;
+                }
+                self.avg_gain = Some(self.seed_gain / n);
+                self.avg_loss = Some(self.seed_loss / n);
+            }
+        }
+
+        self.value()
+    }
+
+    /// 无跌幅时为 100；价格完全不动时为 50
+    fn value(&self) -> Option<T> {
+        let avg_gain = self.avg_gain?;
+        let avg_loss = self.avg_loss?;
+        let hundred = T::hundred();
+
+        if avg_loss.is_zero_value() {
+            return Some(if avg_gain.is_zero_value() {
+                T::from_count(50)
+            } else {
+                hundred
+            });
+        }
+
+        let rs = avg_gain / avg_loss;
+        Some(hundred - hundred / (T::one_value() + rs))
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new(self.period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::logic::indicator::test_data::{assert_close, CLOSES};
+    use rust_decimal::prelude::ToPrimitive;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_rsi_reference() {
+        let mut rsi = Rsi::new(14);
+        let values: Vec<_> = CLOSES.iter().map(|&c| rsi.update(c)).collect();
+        assert!(values[13].is_none());
+        // StockCharts 教程中的首个 RSI(14)
+        assert_close(values[14].unwrap(), 70.46413502109705);
+        assert_close(values[29].unwrap(), 45.499497238680405);
+    }
+
+    #[test]
+    fn test_rsi_decimal() {
+        let mut rsi = Rsi::new(14);
+        for c in CLOSES {
+            rsi.update(c.to_string().parse::<Decimal>().unwrap());
+        }
+        let value = rsi.value().unwrap().to_f64().unwrap();
+        assert!((value - 45.499497238680405).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rsi_flat_and_rising() {
+        let mut flat = Rsi::new(3);
+        let mut rising = Rsi::new(3);
+        for i in 0..5 {
+            flat.update(10.0);
+            rising.update(10.0 + i as f64);
+        }
+        assert_eq!(flat.value(), Some(50.0));
+        assert_eq!(rising.value(), Some(100.0));
+    }
+}
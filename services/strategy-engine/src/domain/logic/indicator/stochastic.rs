@@ -0,0 +1,94 @@
+//! # 随机指标 (Stochastic Oscillator)
+//!
+//! %K = 100·(收 - n 周期最低) / (n 周期最高 - n 周期最低)，区间为 0 时取 50；
+//! %D = SMA(%K, d)。最高 / 最低价用单调队列维护。
+
+use serde::{Deserialize, Serialize};
+
+use super::moving_average::Sma;
+use super::window::RollingExtremum;
+use super::{Bar, Indicator, Numeric};
+
+/// 随机指标输出
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StochasticOutput<T> {
+    /// %K
+    pub k: T,
+    /// %D
+    pub d: T,
+}
+
+/// 随机指标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Stochastic<T> {
+    highest: RollingExtremum<T>,
+    lowest: RollingExtremum<T>,
+    d: Sma<T>,
+    /// 最新输出
+    last: Option<StochasticOutput<T>>,
+}
+
+impl<T: Numeric> Stochastic<T> {
+    /// 创建随机指标
+    pub fn new(k_period: usize, d_period: usize) -> Self {
+        Self {
+            highest: RollingExtremum::max(k_period),
+            lowest: RollingExtremum::min(k_period),
+            d: Sma::new(d_period),
+            last: None,
+        }
+    }
+}
+
+impl<T: Numeric> Indicator for Stochastic<T> {
+    type Input = Bar<T>;
+    type Output = StochasticOutput<T>;
+
+    fn update(&mut self, input: Bar<T>) -> Option<StochasticOutput<T>> {
+        let highest = self.highest.push(input.high);
+        let lowest = self.lowest.push(input.low);
+        if !self.highest.is_full() {
+            return None;
+        }
+
+        let range = highest - lowest;
+        let k = if range.is_zero_value() {
+            T::from_count(50)
+        } else {
+            T::hundred() * (input.close - lowest) / range
+        };
+        let d = self.d.update(k)?;
+
+        self.last = Some(StochasticOutput { k, d });
+        self.last
+    }
+
+    fn value(&self) -> Option<StochasticOutput<T>> {
+        self.last
+    }
+
+    fn reset(&mut self) {
+        self.highest.clear();
+        self.lowest.clear();
+        self.d.reset();
+        self.last = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::logic::indicator::test_data::{assert_close, bars};
+
+    #[test]
+    fn test_stochastic_reference() {
+        let mut stochastic = Stochastic::new(5, 3);
+        let values: Vec<_> = bars().into_iter().map(|bar| stochastic.update(bar)).collect();
+        assert!(values[5].is_none());
+        assert!(values[6].is_some());
+
+        let last = values[29].unwrap();
+        assert_close(last.k, 43.50000000000003);
+        assert_close(last.d, 26.862278244631124);
+    }
+}
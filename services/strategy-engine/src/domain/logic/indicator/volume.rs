@@ -0,0 +1,135 @@
+//! # 量价指标 (Volume Indicators)
+//!
+//! - `Vwap`：成交量加权平均价 Σ(典型价·量) / Σ量，累计到 `reset`（按交易时段重置）
+//! - `Obv`：能量潮，收涨加量、收跌减量、平盘不变
+
+use serde::{Deserialize, Serialize};
+
+use super::{Bar, Indicator, Numeric};
+
+/// 成交量加权平均价
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Vwap<T> {
+    /// Σ(典型价·量)
+    price_volume: T,
+    /// Σ量
+    volume: T,
+}
+
+impl<T: Numeric> Vwap<T> {
+    /// 创建 VWAP
+    pub fn new() -> Self {
+        Self {
+            price_volume: T::zero_value(),
+            volume: T::zero_value(),
+        }
+    }
+}
+
+impl<T: Numeric> Default for Vwap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Numeric> Indicator for Vwap<T> {
+    type Input = Bar<T>;
+    type Output = T;
+
+    fn update(&mut self, input: Bar<T>) -> Option<T> {
+        self.price_volume = self.price_volume + input.typical_price() * input.volume;
+        self.volume = self.volume + input.volume;
+        self.value()
+    }
+
+    /// 累计成交量为 0 时返回 None
+    fn value(&self) -> Option<T> {
+        if self.volume.is_zero_value() {
+            None
+        } else {
+            Some(self.price_volume / self.volume)
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// 能量潮
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Obv<T> {
+    /// 前收盘价
+    prev_close: Option<T>,
+    /// 累计值
+    current: T,
+}
+
+impl<T: Numeric> Obv<T> {
+    /// 创建 OBV（从 0 开始）
+    pub fn new() -> Self {
+        Self {
+            prev_close: None,
+            current: T::zero_value(),
+        }
+    }
+}
+
+impl<T: Numeric> Default for Obv<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Numeric> Indicator for Obv<T> {
+    type Input = Bar<T>;
+    type Output = T;
+
+    fn update(&mut self, input: Bar<T>) -> Option<T> {
+        if let Some(prev) = self.prev_close {
+            if input.close > prev {
+                self.current = self.current + input.volume;
+            } else if input.close < prev {
+                self.current = self.current - input.volume;
+            }
+        }
+        self.prev_close = Some(input.close);
+        self.value()
+    }
+
+    /// 首根 K 线之后始终有值
+    fn value(&self) -> Option<T> {
+        self.prev_close.map(|_| self.current)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::logic::indicator::test_data::{assert_close, bars};
+
+    #[test]
+    fn test_vwap_and_obv_reference() {
+        let mut vwap = Vwap::new();
+        let mut obv = Obv::new();
+        for bar in bars() {
+            vwap.update(bar);
+            obv.update(bar);
+        }
+        assert_close(vwap.value().unwrap(), 45.392115739520165);
+        assert_close(obv.value().unwrap(), 7336.0);
+    }
+
+    #[test]
+    fn test_vwap_reset_starts_new_session() {
+        let mut vwap = Vwap::new();
+        vwap.update(Bar::from_price(100.0, 1.0));
+        vwap.reset();
+        assert_eq!(vwap.value(), None);
+        assert_eq!(vwap.update(Bar::from_price(50.0, 2.0)), Some(50.0));
+    }
+}
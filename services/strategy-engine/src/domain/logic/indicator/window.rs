@@ -0,0 +1,294 @@
+//! # 滚动窗口 (Rolling Window)
+//!
+//! - `RollingWindow`：固定长度窗口，维护滚动和与平方和，均值 / 方差 O(1)
+//! - `RollingExtremum`：单调队列维护窗口最大 / 最小值，均摊 O(1)
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use super::Numeric;
+
+/// 固定长度滚动窗口
+///
+/// `f64` 的滚动和存在累计误差，量级远小于指标用途的精度要求；`Decimal` 无误差。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollingWindow<T> {
+    /// 窗口长度
+    capacity: usize,
+    /// 窗口内的值（旧 → 新）
+    values: VecDeque<T>,
+    /// 滚动和
+    sum: T,
+    /// 滚动平方和
+    sum_sq: T,
+}
+
+impl<T: Numeric> RollingWindow<T> {
+    /// 创建窗口（长度至少为 1）
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            values: VecDeque::with_capacity(capacity + 1),
+            sum: T::zero_value(),
+            sum_sq: T::zero_value(),
+        }
+    }
+
+    /// 压入新值，窗口已满时返回被挤出的最旧值
+    pub fn push(&mut self, value: T) -> Option<T> {
+        self.values.push_back(value);
+        self.sum = self.sum + value;
+        self.sum_sq = self.sum_sq + value * value;
+
+        if self.values.len() > self.capacity {
+            let evicted = self.values.pop_front()?;
+            self.sum = self.sum - evicted;
+            self.sum_sq = self.sum_sq - evicted * evicted;
+            Some(evicted)
+        } else {
+            None
+        }
+    }
+
+    /// 窗口长度
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 当前值个数
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// 是否已填满
+    pub fn is_full(&self) -> bool {
+        self.values.len() >= self.capacity
+    }
+
+    /// 滚动和
+    pub fn sum(&self) -> T {
+        self.sum
+    }
+
+    /// 最新值
+    pub fn latest(&self) -> Option<T> {
+        self.values.back().copied()
+    }
+
+    /// 最旧值
+    pub fn oldest(&self) -> Option<T> {
+        self.values.front().copied()
+    }
+
+    /// 按位置取值（0 为最旧）
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.values.get(index).copied()
+    }
+
+    /// 遍历窗口（旧 → 新）
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.values.iter()
+    }
+
+    /// 均值
+    pub fn mean(&self) -> Option<T> {
+        if self.values.is_empty() {
+            return None;
+        }
+        Some(self.sum / T::from_count(self.values.len()))
+    }
+
+    /// 总体方差（除以 n），浮点误差导致的负值截断为 0
+    pub fn variance(&self) -> Option<T> {
+        let mean = self.mean()?;
+        let variance = self.sum_sq / T::from_count(self.values.len()) - mean * mean;
+        if variance < T::zero_value() {
+            Some(T::zero_value())
+        } else {
+            Some(variance)
+        }
+    }
+
+    /// 总体标准差
+    pub fn std_dev(&self) -> Option<T> {
+        self.variance().map(Numeric::square_root)
+    }
+
+    /// 依次压入另一窗口的值（旧 → 新），超出本窗口长度的最旧值被挤出
+    ///
+    /// 用于按新长度重建窗口：参数热更新或配置变化后的热重启沿用已累积的数据。
+    pub fn replay(&mut self, other: &RollingWindow<T>) {
+        for &value in other.iter() {
+            self.push(value);
+        }
+    }
+
+    /// 清空窗口
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.sum = T::zero_value();
+        self.sum_sq = T::zero_value();
+    }
+}
+
+/// 极值类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Extremum {
+    /// 最大值
+    Max,
+    /// 最小值
+    Min,
+}
+
+/// 滚动极值（单调队列）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollingExtremum<T> {
+    /// 窗口长度
+    period: usize,
+    /// 极值类型
+    kind: Extremum,
+    /// 已输入的值个数（同时作为序号）
+    seen: u64,
+    /// 单调队列 (序号, 值)，队首为当前极值
+    deque: VecDeque<(u64, T)>,
+}
+
+impl<T: Numeric> RollingExtremum<T> {
+    /// 滚动最大值
+    pub fn max(period: usize) -> Self {
+        Self::new(period, Extremum::Max)
+    }
+
+    /// 滚动最小值
+    pub fn min(period: usize) -> Self {
+        Self::new(period, Extremum::Min)
+    }
+
+    fn new(period: usize, kind: Extremum) -> Self {
+        Self {
+            period: period.max(1),
+            kind,
+            seen: 0,
+            deque: VecDeque::new(),
+        }
+    }
+
+    /// 压入新值，返回当前窗口极值
+    pub fn push(&mut self, value: T) -> T {
+        let index = self.seen;
+        self.seen += 1;
+
+        // 队尾弱于新值的元素不可能再成为极值
+        while let Some(&(_, back)) = self.deque.back() {
+            let dominated = match self.kind {
+                Extremum::Max => back <= value,
+                Extremum::Min => back >= value,
+            };
+            if !dominated {
+                break;
+            }
+            self.deque.pop_back();
+        }
+        self.deque.push_back((index, value));
+
+        // 移除滑出窗口的队首
+        while let Some(&(front_index, _)) = self.deque.front() {
+            if front_index + self.period as u64 > index {
+                break;
+            }
+            self.deque.pop_front();
+        }
+
+        self.deque.front().map(|&(_, v)| v).unwrap_or(value)
+    }
+
+    /// 窗口长度
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// 当前窗口极值
+    pub fn value(&self) -> Option<T> {
+        self.deque.front().map(|&(_, v)| v)
+    }
+
+    /// 是否已填满窗口
+    pub fn is_full(&self) -> bool {
+        self.seen >= self.period as u64
+    }
+
+    /// 清空状态
+    pub fn clear(&mut self) {
+        self.seen = 0;
+        self.deque.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_rolling_window_evicts_and_tracks_stats() {
+        let mut window = RollingWindow::new(3);
+        for v in [1.0, 2.0, 3.0] {
+            assert_eq!(window.push(v), None);
+        }
+        assert_eq!(window.push(4.0), Some(1.0));
+        assert!(window.is_full());
+        assert_eq!(window.sum(), 9.0);
+        assert_eq!(window.mean(), Some(3.0));
+        assert!((window.variance().unwrap() - 2.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rolling_window_replay_keeps_newest_values() {
+        let mut stored = RollingWindow::new(4);
+        for v in [1.0, 2.0, 3.0, 4.0] {
+            stored.push(v);
+        }
+
+        let mut shrunk = RollingWindow::new(2);
+        shrunk.replay(&stored);
+        assert_eq!(shrunk.iter().copied().collect::<Vec<_>>(), vec![3.0, 4.0]);
+        assert_eq!(shrunk.sum(), 7.0);
+
+        let mut grown = RollingWindow::new(6);
+        grown.replay(&stored);
+        assert_eq!(grown.len(), 4);
+        assert!(!grown.is_full());
+        assert_eq!(grown.capacity(), 6);
+    }
+
+    #[test]
+    fn test_rolling_window_decimal_is_exact() {
+        let mut window = RollingWindow::new(2);
+        for v in ["0.1", "0.2", "0.3"] {
+            window.push(v.parse::<Decimal>().unwrap());
+        }
+        assert_eq!(window.sum(), Decimal::new(5, 1));
+        assert_eq!(window.mean(), Some(Decimal::new(25, 2)));
+    }
+
+    #[test]
+    fn test_rolling_extremum_matches_naive() {
+        let values = [5.0, 3.0, 8.0, 1.0, 4.0, 7.0, 2.0, 6.0, 9.0, 0.0];
+        let mut max = RollingExtremum::max(3);
+        let mut min = RollingExtremum::min(3);
+        for (i, &v) in values.iter().enumerate() {
+            let window = &values[i.saturating_sub(2)..=i];
+            let naive_max = window.iter().cloned().fold(f64::MIN, f64::max);
+            let naive_min = window.iter().cloned().fold(f64::MAX, f64::min);
+            assert_eq!(max.push(v), naive_max);
+            assert_eq!(min.push(v), naive_min);
+        }
+    }
+}
@@ -7,7 +7,7 @@ use rust_decimal::Decimal;
 use shared::event::market_event::{MarketEvent, MarketEventData};
 use uuid::Uuid;
 
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalType};
 
 /// 均值回归策略配置 (Mean Reversion Strategy Configuration)
 #[derive(Debug, Clone)]
@@ -96,5 +96,6 @@ pub fn calculate_mean_reversion_signal(
         quantity: config.quantity,
         confidence: 1.0,
         created_at: event.timestamp,
+        instruction: OrderInstruction::default(),
     })
 }
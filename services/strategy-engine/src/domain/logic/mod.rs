@@ -18,6 +18,9 @@ pub mod signal_generator;
 /// 统一策略 Trait (Strategy Trait)
 pub mod strategy_trait;
 
+/// 技术指标库 (Technical Indicators)
+pub mod indicator;
+
 /// 现货策略模块 (Spot Strategies)
 pub mod spot;
 
@@ -29,3 +32,9 @@ pub mod ai;
 
 /// 高频策略模块 (HFT Strategies)
 pub mod hft;
+
+/// 用户脚本策略 (User Script Strategies)
+pub mod script;
+
+/// 组合策略 (Ensemble Strategies)
+pub mod ensemble;
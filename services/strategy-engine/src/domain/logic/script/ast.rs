@@ -0,0 +1,88 @@
+//! # 语法树 (Abstract Syntax Tree)
+
+use super::lexer::Position;
+
+/// 二元运算符
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+}
+
+/// 一元运算符
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    Neg,
+    Not,
+}
+
+/// 表达式
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprKind {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Str(String),
+    Var(String),
+    List(Vec<Expr>),
+    Map(Vec<(String, Expr)>),
+    Field(Box<Expr>, String),
+    Index(Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+    Unary(UnaryOp, Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+}
+
+/// 语句
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stmt {
+    pub kind: StmtKind,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StmtKind {
+    Let(String, Expr),
+    /// 赋值：左侧为变量、字段或下标
+    Assign(Expr, Expr),
+    Expr(Expr),
+    If(Expr, Vec<Stmt>, Vec<Stmt>),
+    While(Expr, Vec<Stmt>),
+    For(String, Expr, Vec<Stmt>),
+    Return(Option<Expr>),
+    Break,
+    Continue,
+}
+
+/// 函数定义
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+    pub name: String,
+    pub params: Vec<String>,
+    pub body: Vec<Stmt>,
+    pub position: Position,
+}
+
+/// 脚本：顶层语句与函数
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Program {
+    pub statements: Vec<Stmt>,
+    pub functions: Vec<Function>,
+}
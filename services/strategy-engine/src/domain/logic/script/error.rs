@@ -0,0 +1,72 @@
+//! # 脚本错误 (Script Errors)
+
+use super::lexer::Position;
+
+/// 错误类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptErrorKind {
+    /// 语法错误（加载时）
+    Syntax,
+    /// 运行时错误（类型不匹配、未定义变量、返回值不符合契约等）
+    Runtime,
+    /// 超出沙箱资源限制（指令数 / 内存 / 时间 / 调用深度）
+    Limit,
+}
+
+impl std::fmt::Display for ScriptErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScriptErrorKind::Syntax => write!(f, "语法错误"),
+            ScriptErrorKind::Runtime => write!(f, "运行时错误"),
+            ScriptErrorKind::Limit => write!(f, "资源超限"),
+        }
+    }
+}
+
+/// 脚本错误
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptError {
+    /// 错误类别
+    pub kind: ScriptErrorKind,
+    /// 错误信息
+    pub message: String,
+    /// 源码位置
+    pub position: Option<Position>,
+}
+
+impl ScriptError {
+    pub fn new(kind: ScriptErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            position: None,
+        }
+    }
+
+    /// 运行时错误
+    pub fn runtime(message: impl Into<String>) -> Self {
+        Self::new(ScriptErrorKind::Runtime, message)
+    }
+
+    /// 资源超限
+    pub fn limit(message: impl Into<String>) -> Self {
+        Self::new(ScriptErrorKind::Limit, message)
+    }
+
+    /// 补充源码位置（已有位置时保留最内层的位置）
+    pub fn at(mut self, position: Position) -> Self {
+        self.position.get_or_insert(position);
+        self
+    }
+}
+
+impl std::fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.position {
+            Some(p) => write!(f, "{}（第 {} 行第 {} 列）: {}", self.kind, p.line, p.column, self.message),
+            None => write!(f, "{}: {}", self.kind, self.message),
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
@@ -0,0 +1,1111 @@
+//! # 解释器 (Interpreter)
+//!
+//! 树遍历解释执行。脚本只能访问：
+//! - 局部变量（`let` 声明，块级作用域）
+//! - 全局常量（顶层 `let`，加载时计算，之后只读）
+//! - `params`：创建策略时传入的参数（只读）
+//! - `state`：持久状态映射（仅在函数中可用，随检查点保存）
+//! - 内置函数（见 `BUILTINS`，均为纯计算，没有 IO）
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::ast::{BinaryOp, Expr, ExprKind, Function, Stmt, StmtKind, UnaryOp};
+use super::error::ScriptError;
+use super::parser::parse;
+use super::sandbox::{
+    Meter, SandboxLimits, MAX_COLLECTION_LEN, MAX_LOGS, MAX_STRING_LEN, MAX_VALUE_DEPTH,
+};
+use super::value::Value;
+
+/// 宿主提供的 ABI 版本
+pub const ABI_VERSION: u32 = 1;
+
+/// 宿主回调的钩子及其参数个数
+pub const HOOKS: &[(&str, usize)] = &[
+    ("on_market_event", 1),
+    ("on_fill", 1),
+    ("on_position", 1),
+    ("reset", 0),
+];
+
+/// 内置函数
+pub const BUILTINS: &[&str] = &[
+    // 数学
+    "abs", "min", "max", "sqrt", "ln", "exp", "pow", "floor", "ceil", "round", "clamp",
+    // 集合
+    "len", "push", "pop", "last", "slice", "contains", "keys", "range",
+    // 序列统计
+    "sum", "mean", "stddev", "highest", "lowest", "ema",
+    // 类型与参数
+    "number", "string", "type_of", "is_null", "param", "require_abi",
+    // 信号
+    "open_long", "open_short", "close_long", "close_short",
+    // 日志
+    "log",
+];
+
+/// 保留名称（不能用作变量或函数名）
+const RESERVED: &[&str] = &["state", "params"];
+
+/// 单条日志最大长度（字符）
+const MAX_LOG_CHARS: usize = 256;
+
+/// 已加载的脚本
+#[derive(Debug, Clone)]
+pub struct Script {
+    functions: HashMap<String, Function>,
+    globals: BTreeMap<String, Value>,
+    params: Value,
+}
+
+/// 一次调用的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallOutcome {
+    /// 返回值
+    pub value: Value,
+    /// 脚本日志
+    pub logs: Vec<String>,
+    /// 执行的指令数
+    pub operations: u64,
+}
+
+impl Script {
+    /// 解析并执行顶层语句（计算全局常量）
+    pub fn load(source: &str, params: Value, limits: &SandboxLimits) -> Result<Self, ScriptError> {
+        let program = parse(source)?;
+
+        let mut functions = HashMap::new();
+        for function in program.functions {
+            if BUILTINS.contains(&function.name.as_str()) || RESERVED.contains(&function.name.as_str()) {
+                return Err(ScriptError::runtime(format!("函数名 {} 与内置名称冲突", function.name))
+                    .at(function.position));
+            }
+            if let Some((_, arity)) = HOOKS.iter().find(|(name, _)| *name == function.name) {
+                if function.params.len() != *arity {
+                    return Err(ScriptError::runtime(format!(
+                        "钩子 {} 应有 {} 个参数，实际 {} 个",
+                        function.name,
+                        arity,
+                        function.params.len()
+                    ))
+                    .at(function.position));
+                }
+            }
+            functions.insert(function.name.clone(), function);
+        }
+        if !functions.contains_key("on_market_event") {
+            return Err(ScriptError::runtime("脚本必须定义 on_market_event(event)"));
+        }
+
+        let mut script = Self {
+            functions,
+            globals: BTreeMap::new(),
+            params,
+        };
+
+        // 顶层 let 在加载结束后成为只读的全局常量
+        let globals = {
+            let mut context = Context::new(&script, None, limits);
+            match context.exec_stmts(&program.statements)? {
+                Flow::Normal => {}
+                _ => return Err(ScriptError::runtime("return / break / continue 不能出现在顶层")),
+            }
+            context.locals
+        };
+        script.globals = globals.into_iter().collect();
+        Ok(script)
+    }
+
+    /// 是否定义了函数
+    pub fn has_function(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    /// 调用函数（未定义时返回 None）
+    ///
+    /// `state` 必须是映射；出错时可能已被部分修改，由调用方决定是否回滚。
+    pub fn call(
+        &self,
+        name: &str,
+        args: Vec<Value>,
+        state: &mut Value,
+        limits: &SandboxLimits,
+    ) -> Result<Option<CallOutcome>, ScriptError> {
+        let Some(function) = self.functions.get(name) else {
+            return Ok(None);
+        };
+
+        let mut context = Context::new(self, Some(state), limits);
+        for arg in &args {
+            context.meter.allocate(heap_size(arg))?;
+        }
+        let value = context.invoke(function, args)?;
+        let operations = context.meter.operations();
+        let logs = context.logs;
+
+        let state_bytes = state.memory_size();
+        if state_bytes > limits.max_state_bytes {
+            return Err(ScriptError::limit(format!(
+                "持久状态约 {} 字节，超过上限 {}",
+                state_bytes, limits.max_state_bytes
+            )));
+        }
+        Ok(Some(CallOutcome {
+            value,
+            logs,
+            operations,
+        }))
+    }
+}
+
+/// 控制流
+enum Flow {
+    Normal,
+    Break,
+    Continue,
+    Return(Value),
+}
+
+/// 路径段（字段或下标）
+enum Segment {
+    Key(String),
+    Index(Value),
+}
+
+/// 单次调用的执行上下文
+struct Context<'a> {
+    script: &'a Script,
+    state: Option<&'a mut Value>,
+    meter: Meter,
+    /// 当前函数的局部变量（后声明的在后）
+    locals: Vec<(String, Value)>,
+    /// 块作用域起点
+    scopes: Vec<usize>,
+    /// 调用深度
+    depth: usize,
+    logs: Vec<String>,
+}
+
+impl<'a> Context<'a> {
+    fn new(script: &'a Script, state: Option<&'a mut Value>, limits: &SandboxLimits) -> Self {
+        Self {
+            script,
+            state,
+            meter: Meter::start(limits),
+            locals: Vec::new(),
+            scopes: Vec::new(),
+            depth: 0,
+            logs: Vec::new(),
+        }
+    }
+
+    // ========================================================================
+    // 函数调用
+    // ========================================================================
+
+    fn invoke(&mut self, function: &Function, args: Vec<Value>) -> Result<Value, ScriptError> {
+        if args.len() != function.params.len() {
+            return Err(ScriptError::runtime(format!(
+                "函数 {} 需要 {} 个参数，实际 {} 个",
+                function.name,
+                function.params.len(),
+                args.len()
+            )));
+        }
+        if self.depth >= self.meter.max_call_depth() {
+            return Err(ScriptError::limit(format!(
+                "调用深度超过 {}",
+                self.meter.max_call_depth()
+            )));
+        }
+
+        let locals = std::mem::replace(
+            &mut self.locals,
+            function.params.iter().cloned().zip(args).collect(),
+        );
+        let scopes = std::mem::take(&mut self.scopes);
+        self.depth += 1;
+        let flow = self.exec_stmts(&function.body);
+        self.depth -= 1;
+        self.locals = locals;
+        self.scopes = scopes;
+
+        match flow? {
+            Flow::Return(value) => Ok(value),
+            Flow::Normal => Ok(Value::Null),
+            Flow::Break | Flow::Continue => Err(ScriptError::runtime("break / continue 只能在循环中使用")),
+        }
+    }
+
+    fn call(&mut self, name: &str, args: &[Expr]) -> Result<Value, ScriptError> {
+        if BUILTINS.contains(&name) {
+            return self.builtin(name, args);
+        }
+        let script = self.script;
+        let function = script
+            .functions
+            .get(name)
+            .ok_or_else(|| ScriptError::runtime(format!("未定义的函数 {}", name)))?;
+        let args = self.eval_all(args)?;
+        self.invoke(function, args)
+    }
+
+    // ========================================================================
+    // 语句
+    // ========================================================================
+
+    fn exec_stmts(&mut self, statements: &[Stmt]) -> Result<Flow, ScriptError> {
+        for statement in statements {
+            match self.exec(statement).map_err(|e| e.at(statement.position))? {
+                Flow::Normal => {}
+                flow => return Ok(flow),
+            }
+        }
+        Ok(Flow::Normal)
+    }
+
+    fn exec_block(&mut self, statements: &[Stmt]) -> Result<Flow, ScriptError> {
+        self.scopes.push(self.locals.len());
+        let flow = self.exec_stmts(statements);
+        if let Some(mark) = self.scopes.pop() {
+            self.locals.truncate(mark);
+        }
+        flow
+    }
+
+    fn exec(&mut self, statement: &Stmt) -> Result<Flow, ScriptError> {
+        self.meter.tick()?;
+        match &statement.kind {
+            StmtKind::Let(name, expr) => {
+                if RESERVED.contains(&name.as_str()) {
+                    return Err(ScriptError::runtime(format!("{} 是保留名称", name)));
+                }
+                let value = self.eval(expr)?;
+                self.locals.push((name.clone(), value));
+            }
+            StmtKind::Assign(target, expr) => {
+                let value = self.eval(expr)?;
+                self.assign(target, value)?;
+            }
+            StmtKind::Expr(expr) => {
+                self.eval(expr)?;
+            }
+            StmtKind::If(condition, then_branch, else_branch) => {
+                let branch = if self.condition(condition)? {
+                    then_branch
+                } else {
+                    else_branch
+                };
+                return self.exec_block(branch);
+            }
+            StmtKind::While(condition, body) => {
+                while self.condition(condition)? {
+                    match self.exec_block(body)? {
+                        Flow::Break => break,
+                        Flow::Return(value) => return Ok(Flow::Return(value)),
+                        Flow::Normal | Flow::Continue => {}
+                    }
+                }
+            }
+            StmtKind::For(name, iterable, body) => {
+                let items = match self.eval(iterable)? {
+                    Value::List(items) => items,
+                    Value::Map(entries) => entries.into_keys().map(Value::Str).collect(),
+                    other => {
+                        return Err(ScriptError::runtime(format!(
+                            "for 只能遍历 list 或 map，实际为 {}",
+                            other.type_name()
+                        )))
+                    }
+                };
+                for item in items {
+                    self.meter.tick()?;
+                    self.scopes.push(self.locals.len());
+                    self.locals.push((name.clone(), item));
+                    let flow = self.exec_stmts(body);
+                    if let Some(mark) = self.scopes.pop() {
+                        self.locals.truncate(mark);
+                    }
+                    match flow? {
+                        Flow::Break => break,
+                        Flow::Return(value) => return Ok(Flow::Return(value)),
+                        Flow::Normal | Flow::Continue => {}
+                    }
+                }
+            }
+            StmtKind::Return(expr) => {
+                let value = match expr {
+                    Some(expr) => self.eval(expr)?,
+                    None => Value::Null,
+                };
+                return Ok(Flow::Return(value));
+            }
+            StmtKind::Break => return Ok(Flow::Break),
+            StmtKind::Continue => return Ok(Flow::Continue),
+        }
+        Ok(Flow::Normal)
+    }
+
+    fn condition(&mut self, expr: &Expr) -> Result<bool, ScriptError> {
+        let value = self.eval(expr)?;
+        value.as_bool().ok_or_else(|| {
+            ScriptError::runtime(format!("条件必须是 bool，实际为 {}", value.type_name()))
+                .at(expr.position)
+        })
+    }
+
+    // ========================================================================
+    // 变量与路径
+    // ========================================================================
+
+    fn lookup(&self, name: &str) -> Result<&Value, ScriptError> {
+        if let Some((_, value)) = self.locals.iter().rev().find(|(n, _)| n == name) {
+            return Ok(value);
+        }
+        match name {
+            "state" => self
+                .state
+                .as_deref()
+                .ok_or_else(|| ScriptError::runtime("state 只能在函数中使用")),
+            "params" => Ok(&self.script.params),
+            _ => self
+                .script
+                .globals
+                .get(name)
+                .ok_or_else(|| ScriptError::runtime(format!("未定义的变量 {}", name))),
+        }
+    }
+
+    fn lookup_mut(&mut self, name: &str) -> Result<&mut Value, ScriptError> {
+        if let Some((_, value)) = self.locals.iter_mut().rev().find(|(n, _)| n == name) {
+            return Ok(value);
+        }
+        if name == "state" {
+            return self
+                .state
+                .as_deref_mut()
+                .ok_or_else(|| ScriptError::runtime("state 只能在函数中使用"));
+        }
+        if name == "params" || self.script.globals.contains_key(name) {
+            return Err(ScriptError::runtime(format!("{} 是只读的", name)));
+        }
+        Err(ScriptError::runtime(format!("未定义的变量 {}（先用 let 声明）", name)))
+    }
+
+    /// 拆分路径：返回根表达式与按顺序求值后的字段 / 下标
+    fn path<'e>(&mut self, expr: &'e Expr) -> Result<(&'e Expr, Vec<Segment>), ScriptError> {
+        let mut pending = Vec::new();
+        let mut current = expr;
+        while let ExprKind::Field(base, _) | ExprKind::Index(base, _) = &current.kind {
+            pending.push(current);
+            current = base;
+        }
+
+        let mut segments = Vec::with_capacity(pending.len());
+        for expr in pending.into_iter().rev() {
+            segments.push(match &expr.kind {
+                ExprKind::Field(_, name) => Segment::Key(name.clone()),
+                ExprKind::Index(_, index) => Segment::Index(self.eval(index)?),
+                _ => unreachable!("path 只收集字段与下标"),
+            });
+        }
+        Ok((current, segments))
+    }
+
+    /// 以引用方式查看表达式的值（变量路径不复制）
+    fn inspect<R>(
+        &mut self,
+        expr: &Expr,
+        f: impl FnOnce(&Value) -> Result<R, ScriptError>,
+    ) -> Result<R, ScriptError> {
+        let (root, segments) = self.path(expr)?;
+        match &root.kind {
+            ExprKind::Var(name) => {
+                let base = self.lookup(name).map_err(|e| e.at(root.position))?;
+                f(walk(base, &segments).map_err(|e| e.at(expr.position))?)
+            }
+            _ => {
+                let base = self.eval(root)?;
+                f(walk(&base, &segments).map_err(|e| e.at(expr.position))?)
+            }
+        }
+    }
+
+    fn read(&mut self, expr: &Expr) -> Result<Value, ScriptError> {
+        let value = self.inspect(expr, |value| Ok(value.clone()))?;
+        self.meter.allocate(heap_size(&value))?;
+        Ok(value)
+    }
+
+    fn assign(&mut self, target: &Expr, value: Value) -> Result<(), ScriptError> {
+        let (root, segments) = self.path(target)?;
+        let ExprKind::Var(name) = &root.kind else {
+            return Err(ScriptError::runtime("只能给变量、字段或下标赋值").at(target.position));
+        };
+        check_depth(segments.len() + value.depth())?;
+        if name == "state" && segments.is_empty() && !matches!(value, Value::Map(_)) {
+            return Err(ScriptError::runtime("state 必须是 map"));
+        }
+
+        let slot = self.lookup_mut(name)?;
+        *walk_mut(slot, &segments)? = value;
+        Ok(())
+    }
+
+    // ========================================================================
+    // 表达式
+    // ========================================================================
+
+    fn eval(&mut self, expr: &Expr) -> Result<Value, ScriptError> {
+        self.meter.tick()?;
+        self.eval_kind(expr).map_err(|e| e.at(expr.position))
+    }
+
+    fn eval_all(&mut self, exprs: &[Expr]) -> Result<Vec<Value>, ScriptError> {
+        exprs.iter().map(|expr| self.eval(expr)).collect()
+    }
+
+    fn eval_kind(&mut self, expr: &Expr) -> Result<Value, ScriptError> {
+        match &expr.kind {
+            ExprKind::Null => Ok(Value::Null),
+            ExprKind::Bool(b) => Ok(Value::Bool(*b)),
+            ExprKind::Number(n) => Ok(Value::Number(*n)),
+            ExprKind::Str(s) => {
+                self.meter.allocate(s.len())?;
+                Ok(Value::Str(s.clone()))
+            }
+            ExprKind::Var(_) | ExprKind::Field(..) | ExprKind::Index(..) => self.read(expr),
+            ExprKind::List(items) => {
+                let items = self.eval_all(items)?;
+                self.meter.allocate(items.len() * super::value::VALUE_BYTES)?;
+                let list = Value::List(items);
+                check_depth(list.depth())?;
+                Ok(list)
+            }
+            ExprKind::Map(entries) => {
+                let mut map = BTreeMap::new();
+                for (key, expr) in entries {
+                    let value = self.eval(expr)?;
+                    self.meter.allocate(key.len() + super::value::VALUE_BYTES)?;
+                    map.insert(key.clone(), value);
+                }
+                let map = Value::Map(map);
+                check_depth(map.depth())?;
+                Ok(map)
+            }
+            ExprKind::Call(name, args) => self.call(name, args),
+            ExprKind::Unary(op, operand) => {
+                let value = self.eval(operand)?;
+                match (op, value) {
+                    (UnaryOp::Neg, Value::Number(n)) => Ok(Value::Number(-n)),
+                    (UnaryOp::Not, Value::Bool(b)) => Ok(Value::Bool(!b)),
+                    (UnaryOp::Neg, other) => Err(type_error("-", &other)),
+                    (UnaryOp::Not, other) => Err(type_error("!", &other)),
+                }
+            }
+            ExprKind::Binary(BinaryOp::And, left, right) => {
+                Ok(Value::Bool(self.condition(left)? && self.condition(right)?))
+            }
+            ExprKind::Binary(BinaryOp::Or, left, right) => {
+                Ok(Value::Bool(self.condition(left)? || self.condition(right)?))
+            }
+            ExprKind::Binary(op, left, right) => {
+                let left = self.eval(left)?;
+                let right = self.eval(right)?;
+                self.binary(*op, left, right)
+            }
+        }
+    }
+
+    fn binary(&mut self, op: BinaryOp, left: Value, right: Value) -> Result<Value, ScriptError> {
+        use BinaryOp::*;
+        match (op, left, right) {
+            (Eq, l, r) => Ok(Value::Bool(l == r)),
+            (Ne, l, r) => Ok(Value::Bool(l != r)),
+            (Add, Value::Str(l), r) => self.concat(l, &r.display()),
+            (Add, l, Value::Str(r)) => self.concat(l.display(), &r),
+            (Add, Value::List(mut l), Value::List(r)) => {
+                if l.len() + r.len() > MAX_COLLECTION_LEN {
+                    return Err(ScriptError::limit(format!("列表长度超过 {}", MAX_COLLECTION_LEN)));
+                }
+                self.meter.charge((l.len() + r.len()) as u64)?;
+                self.meter.allocate(l.len() * super::value::VALUE_BYTES)?;
+                l.extend(r);
+                Ok(Value::List(l))
+            }
+            (op, Value::Number(l), Value::Number(r)) => {
+                let result = match op {
+                    Add => l + r,
+                    Sub => l - r,
+                    Mul => l * r,
+                    Div => l / r,
+                    Rem => l % r,
+                    Lt => return Ok(Value::Bool(l < r)),
+                    Le => return Ok(Value::Bool(l <= r)),
+                    Gt => return Ok(Value::Bool(l > r)),
+                    Ge => return Ok(Value::Bool(l >= r)),
+                    Eq | Ne | And | Or => unreachable!("已单独处理"),
+                };
+                finite(operator_name(op), result)
+            }
+            (op @ (Lt | Le | Gt | Ge), Value::Str(l), Value::Str(r)) => Ok(Value::Bool(match op {
+                Lt => l < r,
+                Le => l <= r,
+                Gt => l > r,
+                _ => l >= r,
+            })),
+            (op, l, r) => Err(ScriptError::runtime(format!(
+                "{} 不支持 {} 与 {}",
+                operator_name(op),
+                l.type_name(),
+                r.type_name()
+            ))),
+        }
+    }
+
+    fn concat(&mut self, mut left: String, right: &str) -> Result<Value, ScriptError> {
+        if left.len() + right.len() > MAX_STRING_LEN {
+            return Err(ScriptError::limit(format!("字符串长度超过 {}", MAX_STRING_LEN)));
+        }
+        self.meter.allocate(left.len() + right.len())?;
+        left.push_str(right);
+        Ok(Value::Str(left))
+    }
+
+    // ========================================================================
+    // 内置函数
+    // ========================================================================
+
+    fn builtin(&mut self, name: &str, args: &[Expr]) -> Result<Value, ScriptError> {
+        match name {
+            "abs" | "sqrt" | "ln" | "exp" | "floor" | "ceil" => {
+                arity(name, args, 1, 1)?;
+                let x = self.number(&args[0], name)?;
+                let result = match name {
+                    "abs" => x.abs(),
+                    "sqrt" => x.sqrt(),
+                    "ln" => x.ln(),
+                    "exp" => x.exp(),
+                    "floor" => x.floor(),
+                    _ => x.ceil(),
+                };
+                finite(name, result)
+            }
+            "pow" => {
+                arity(name, args, 2, 2)?;
+                let base = self.number(&args[0], name)?;
+                let exponent = self.number(&args[1], name)?;
+                finite(name, base.powf(exponent))
+            }
+            "round" => {
+                arity(name, args, 1, 2)?;
+                let x = self.number(&args[0], name)?;
+                let digits = match args.get(1) {
+                    Some(arg) => self.count(arg, name)?.min(12) as i32,
+                    None => 0,
+                };
+                let scale = 10f64.powi(digits);
+                finite(name, (x * scale).round() / scale)
+            }
+            "min" | "max" => {
+                arity(name, args, 1, 64)?;
+                let mut result: Option<f64> = None;
+                for arg in args {
+                    let x = self.number(arg, name)?;
+                    result = Some(match result {
+                        None => x,
+                        Some(r) if name == "min" => r.min(x),
+                        Some(r) => r.max(x),
+                    });
+                }
+                Ok(Value::Number(result.unwrap_or_default()))
+            }
+            "clamp" => {
+                arity(name, args, 3, 3)?;
+                let x = self.number(&args[0], name)?;
+                let low = self.number(&args[1], name)?;
+                let high = self.number(&args[2], name)?;
+                if low > high {
+                    return Err(ScriptError::runtime(format!("clamp 下限 {} 大于上限 {}", low, high)));
+                }
+                Ok(Value::Number(x.clamp(low, high)))
+            }
+            "len" => {
+                arity(name, args, 1, 1)?;
+                let len = self.inspect(&args[0], |value| match value {
+                    Value::List(items) => Ok(items.len()),
+                    Value::Map(entries) => Ok(entries.len()),
+                    Value::Str(s) => Ok(s.chars().count()),
+                    other => Err(type_error("len", other)),
+                })?;
+                Ok(Value::Number(len as f64))
+            }
+            "push" => self.push(args),
+            "pop" => {
+                arity(name, args, 1, 1)?;
+                let (root, segments) = self.path(&args[0])?;
+                let ExprKind::Var(root) = &root.kind else {
+                    return Err(ScriptError::runtime("pop 的参数必须是变量或字段"));
+                };
+                match walk_mut(self.lookup_mut(root)?, &segments)? {
+                    Value::List(items) => Ok(items.pop().unwrap_or_default()),
+                    other => Err(type_error("pop", other)),
+                }
+            }
+            "last" => {
+                arity(name, args, 1, 1)?;
+                let value = self.inspect(&args[0], |value| match value {
+                    Value::List(items) => Ok(items.last().cloned().unwrap_or_default()),
+                    other => Err(type_error("last", other)),
+                })?;
+                self.meter.allocate(heap_size(&value))?;
+                Ok(value)
+            }
+            "slice" => {
+                arity(name, args, 2, 3)?;
+                let start = self.number(&args[1], name)?;
+                let end = match args.get(2) {
+                    Some(arg) => Some(self.number(arg, name)?),
+                    None => None,
+                };
+                let items = self.inspect(&args[0], |value| match value {
+                    Value::List(items) => {
+                        let start = clamp_index(start, items.len());
+                        let end = end.map_or(items.len(), |end| clamp_index(end, items.len()));
+                        Ok(items[start..end.max(start)].to_vec())
+                    }
+                    other => Err(type_error("slice", other)),
+                })?;
+                self.meter.charge(items.len() as u64)?;
+                let value = Value::List(items);
+                self.meter.allocate(heap_size(&value))?;
+                Ok(value)
+            }
+            "contains" => {
+                arity(name, args, 2, 2)?;
+                let needle = self.eval(&args[1])?;
+                let (found, cost) = self.inspect(&args[0], |value| match (value, &needle) {
+                    (Value::List(items), needle) => {
+                        Ok((items.iter().any(|item| item == needle), items.len()))
+                    }
+                    (Value::Map(entries), Value::Str(key)) => Ok((entries.contains_key(key), 1)),
+                    (Value::Str(s), Value::Str(part)) => Ok((s.contains(part.as_str()), s.len())),
+                    (other, _) => Err(type_error("contains", other)),
+                })?;
+                self.meter.charge(cost as u64)?;
+                Ok(Value::Bool(found))
+            }
+            "keys" => {
+                arity(name, args, 1, 1)?;
+                let keys = self.inspect(&args[0], |value| match value {
+                    Value::Map(entries) => Ok(entries.keys().cloned().map(Value::Str).collect::<Vec<_>>()),
+                    other => Err(type_error("keys", other)),
+                })?;
+                self.meter.charge(keys.len() as u64)?;
+                let value = Value::List(keys);
+                self.meter.allocate(heap_size(&value))?;
+                Ok(value)
+            }
+            "range" => {
+                arity(name, args, 1, 2)?;
+                let (start, end) = if args.len() == 1 {
+                    (0.0, self.number(&args[0], name)?)
+                } else {
+                    (self.number(&args[0], name)?, self.number(&args[1], name)?)
+                };
+                let (start, end) = (start.ceil(), end.ceil());
+                let len = (end - start).max(0.0);
+                if len > MAX_COLLECTION_LEN as f64 {
+                    return Err(ScriptError::limit(format!("range 长度超过 {}", MAX_COLLECTION_LEN)));
+                }
+                let len = len as usize;
+                self.meter.charge(len as u64)?;
+                self.meter.allocate(len * super::value::VALUE_BYTES)?;
+                Ok(Value::List((0..len).map(|i| Value::Number(start + i as f64)).collect()))
+            }
+            "sum" | "mean" | "stddev" | "highest" | "lowest" => self.series(name, args),
+            "ema" => {
+                arity(name, args, 3, 3)?;
+                let previous = self.eval(&args[0])?;
+                let value = self.number(&args[1], name)?;
+                let period = self.count(&args[2], name)?.max(1) as f64;
+                match previous {
+                    Value::Null => Ok(Value::Number(value)),
+                    Value::Number(previous) => {
+                        finite(name, previous + (value - previous) * 2.0 / (period + 1.0))
+                    }
+                    other => Err(type_error("ema", &other)),
+                }
+            }
+            "number" => {
+                arity(name, args, 1, 1)?;
+                Ok(match self.eval(&args[0])? {
+                    Value::Number(n) => Value::Number(n),
+                    Value::Bool(b) => Value::Number(if b { 1.0 } else { 0.0 }),
+                    Value::Str(s) => s
+                        .trim()
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|n| n.is_finite())
+                        .map(Value::Number)
+                        .unwrap_or_default(),
+                    _ => Value::Null,
+                })
+            }
+            "string" => {
+                arity(name, args, 1, 1)?;
+                let text = self.eval(&args[0])?.display();
+                self.concat(String::new(), &text)
+            }
+            "type_of" => {
+                arity(name, args, 1, 1)?;
+                let type_name = self.inspect(&args[0], |value| Ok(value.type_name()))?;
+                Ok(Value::from(type_name))
+            }
+            "is_null" => {
+                arity(name, args, 1, 1)?;
+                Ok(Value::Bool(self.inspect(&args[0], |value| Ok(value.is_null()))?))
+            }
+            "param" => {
+                arity(name, args, 1, 2)?;
+                let key = match self.eval(&args[0])? {
+                    Value::Str(key) => key,
+                    other => return Err(type_error("param", &other)),
+                };
+                let default = match args.get(1) {
+                    Some(arg) => self.eval(arg)?,
+                    None => Value::Null,
+                };
+                let value = match &self.script.params {
+                    Value::Map(params) => params.get(&key).cloned(),
+                    _ => None,
+                };
+                Ok(value.unwrap_or(default))
+            }
+            "require_abi" => {
+                arity(name, args, 1, 1)?;
+                let required = self.number(&args[0], name)?;
+                if required > ABI_VERSION as f64 {
+                    return Err(ScriptError::runtime(format!(
+                        "脚本需要 ABI v{}，宿主提供 v{}",
+                        required, ABI_VERSION
+                    )));
+                }
+                Ok(Value::Null)
+            }
+            "open_long" | "open_short" | "close_long" | "close_short" => {
+                arity(name, args, 0, 2)?;
+                let mut signal = BTreeMap::new();
+                signal.insert("action".to_string(), Value::from(name));
+                if let Some(arg) = args.first() {
+                    let quantity = self.eval(arg)?;
+                    if !quantity.is_null() {
+                        signal.insert("quantity".to_string(), quantity);
+                    }
+                }
+                if let Some(arg) = args.get(1) {
+                    signal.insert("confidence".to_string(), Value::Number(self.number(arg, name)?));
+                }
+                Ok(Value::Map(signal))
+            }
+            "log" => {
+                let parts = self.eval_all(args)?;
+                let mut message: String = parts
+                    .iter()
+                    .map(Value::display)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .chars()
+                    .take(MAX_LOG_CHARS)
+                    .collect();
+                message.shrink_to_fit();
+                if self.logs.len() < MAX_LOGS {
+                    self.meter.allocate(message.len())?;
+                    self.logs.push(message);
+                }
+                Ok(Value::Null)
+            }
+            _ => Err(ScriptError::runtime(format!("未实现的内置函数 {}", name))),
+        }
+    }
+
+    /// push(列表, 值, 最大长度?)：追加元素，超过最大长度时丢弃最早的元素
+    fn push(&mut self, args: &[Expr]) -> Result<Value, ScriptError> {
+        arity("push", args, 2, 3)?;
+        let value = self.eval(&args[1])?;
+        let max_len = match args.get(2) {
+            Some(arg) => Some(self.count(arg, "push")?),
+            None => None,
+        };
+        let (root, segments) = self.path(&args[0])?;
+        let ExprKind::Var(root) = &root.kind else {
+            return Err(ScriptError::runtime("push 的第一个参数必须是变量或字段"));
+        };
+        check_depth(segments.len() + 1 + value.depth())?;
+
+        let slot = walk_mut(self.lookup_mut(root)?, &segments)?;
+        if slot.is_null() {
+            *slot = Value::List(Vec::new());
+        }
+        let Value::List(items) = slot else {
+            return Err(type_error("push", slot));
+        };
+        if items.len() >= MAX_COLLECTION_LEN {
+            return Err(ScriptError::limit(format!("列表长度超过 {}", MAX_COLLECTION_LEN)));
+        }
+        items.push(value);
+        let removed = match max_len {
+            Some(max_len) if items.len() > max_len => {
+                let excess = items.len() - max_len;
+                items.drain(..excess);
+                items.len()
+            }
+            _ => 0,
+        };
+        let len = items.len();
+        self.meter.charge(removed as u64)?;
+        Ok(Value::Number(len as f64))
+    }
+
+    /// 序列统计：最近 n 个元素（默认全部），元素不足 n 个时返回 null
+    fn series(&mut self, name: &str, args: &[Expr]) -> Result<Value, ScriptError> {
+        arity(name, args, 1, 2)?;
+        let n = match args.get(1) {
+            Some(arg) => Some(self.count(arg, name)?),
+            None => None,
+        };
+        let (result, cost) = self.inspect(&args[0], |value| {
+            let Value::List(items) = value else {
+                return Err(type_error(name, value));
+            };
+            let n = n.unwrap_or(items.len());
+            if n == 0 || n > items.len() {
+                return Ok((None, 1));
+            }
+            let mut window = Vec::with_capacity(n);
+            for item in &items[items.len() - n..] {
+                window.push(
+                    item.as_number()
+                        .ok_or_else(|| ScriptError::runtime(format!("{} 的元素必须是 number", name)))?,
+                );
+            }
+            let count = n as f64;
+            let sum: f64 = window.iter().sum();
+            let result = match name {
+                "sum" => sum,
+                "mean" => sum / count,
+                "stddev" => {
+                    let mean = sum / count;
+                    (window.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count).sqrt()
+                }
+                "highest" => window.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                _ => window.iter().copied().fold(f64::INFINITY, f64::min),
+            };
+            Ok((Some(result), n))
+        })?;
+        self.meter.charge(cost as u64)?;
+        match result {
+            Some(result) => finite(name, result),
+            None => Ok(Value::Null),
+        }
+    }
+
+    fn number(&mut self, expr: &Expr, name: &str) -> Result<f64, ScriptError> {
+        let value = self.eval(expr)?;
+        value.as_number().ok_or_else(|| type_error(name, &value))
+    }
+
+    /// 非负整数参数（周期、长度）
+    fn count(&mut self, expr: &Expr, name: &str) -> Result<usize, ScriptError> {
+        let n = self.number(expr, name)?;
+        if n < 0.0 || n.fract() != 0.0 {
+            return Err(ScriptError::runtime(format!("{} 需要非负整数，实际为 {}", name, n)));
+        }
+        Ok(n.min(MAX_COLLECTION_LEN as f64) as usize)
+    }
+}
+
+/// 沿路径查看（缺失的键读作 null）
+fn walk<'v>(mut value: &'v Value, segments: &[Segment]) -> Result<&'v Value, ScriptError> {
+    const NULL: &Value = &Value::Null;
+    for segment in segments {
+        value = match (value, segment) {
+            (Value::Map(entries), Segment::Key(key))
+            | (Value::Map(entries), Segment::Index(Value::Str(key))) => entries.get(key).unwrap_or(NULL),
+            (Value::List(items), Segment::Index(index)) => &items[list_index(index, items.len())?],
+            (Value::Null, _) => return Err(ScriptError::runtime("不能读取 null 的字段或下标")),
+            (other, Segment::Key(key)) => {
+                return Err(ScriptError::runtime(format!("{} 没有字段 {}", other.type_name(), key)))
+            }
+            (other, Segment::Index(index)) => {
+                return Err(ScriptError::runtime(format!(
+                    "{} 不能用 {} 作下标",
+                    other.type_name(),
+                    index.type_name()
+                )))
+            }
+        };
+    }
+    Ok(value)
+}
+
+/// 沿路径取可变引用（映射中缺失的键自动创建，null 视为空映射）
+fn walk_mut<'v>(mut value: &'v mut Value, segments: &[Segment]) -> Result<&'v mut Value, ScriptError> {
+    for segment in segments {
+        if value.is_null() && !matches!(segment, Segment::Index(Value::Number(_))) {
+            *value = Value::Map(BTreeMap::new());
+        }
+        value = match (value, segment) {
+            (Value::Map(entries), Segment::Key(key))
+            | (Value::Map(entries), Segment::Index(Value::Str(key))) => {
+                if !entries.contains_key(key) && entries.len() >= MAX_COLLECTION_LEN {
+                    return Err(ScriptError::limit(format!("映射元素数超过 {}", MAX_COLLECTION_LEN)));
+                }
+                entries.entry(key.clone()).or_default()
+            }
+            (Value::List(items), Segment::Index(index)) => {
+                let index = list_index(index, items.len())?;
+                &mut items[index]
+            }
+            (other, _) => {
+                return Err(ScriptError::runtime(format!(
+                    "不能给 {} 的字段或下标赋值",
+                    other.type_name()
+                )))
+            }
+        };
+    }
+    Ok(value)
+}
+
+/// 列表下标（负数从末尾计）
+fn list_index(index: &Value, len: usize) -> Result<usize, ScriptError> {
+    let Value::Number(n) = index else {
+        return Err(ScriptError::runtime(format!("列表下标必须是 number，实际为 {}", index.type_name())));
+    };
+    if n.fract() != 0.0 {
+        return Err(ScriptError::runtime(format!("列表下标必须是整数，实际为 {}", n)));
+    }
+    let resolved = if *n < 0.0 { len as f64 + n } else { *n };
+    if resolved < 0.0 || resolved >= len as f64 {
+        return Err(ScriptError::runtime(format!("下标 {} 越界（长度 {}）", n, len)));
+    }
+    Ok(resolved as usize)
+}
+
+/// slice 的边界（负数从末尾计，超出范围截断）
+fn clamp_index(index: f64, len: usize) -> usize {
+    let resolved = if index < 0.0 { len as f64 + index } else { index };
+    resolved.clamp(0.0, len as f64) as usize
+}
+
+fn check_depth(depth: usize) -> Result<(), ScriptError> {
+    if depth > MAX_VALUE_DEPTH {
+        return Err(ScriptError::limit(format!("值嵌套超过 {} 层", MAX_VALUE_DEPTH)));
+    }
+    Ok(())
+}
+
+fn arity(name: &str, args: &[Expr], min: usize, max: usize) -> Result<(), ScriptError> {
+    if args.len() < min || args.len() > max {
+        let expected = if min == max {
+            min.to_string()
+        } else {
+            format!("{}~{}", min, max)
+        };
+        return Err(ScriptError::runtime(format!(
+            "{} 需要 {} 个参数，实际 {} 个",
+            name,
+            expected,
+            args.len()
+        )));
+    }
+    Ok(())
+}
+
+fn finite(name: &str, result: f64) -> Result<Value, ScriptError> {
+    if result.is_finite() {
+        Ok(Value::Number(result))
+    } else {
+        Err(ScriptError::runtime(format!("{} 的结果不是有限数（除以 0 或越界）", name)))
+    }
+}
+
+fn type_error(name: &str, value: &Value) -> ScriptError {
+    ScriptError::runtime(format!("{} 不支持 {}", name, value.type_name()))
+}
+
+fn operator_name(op: BinaryOp) -> &'static str {
+    match op {
+        BinaryOp::Add => "+",
+        BinaryOp::Sub => "-",
+        BinaryOp::Mul => "*",
+        BinaryOp::Div => "/",
+        BinaryOp::Rem => "%",
+        BinaryOp::Eq => "==",
+        BinaryOp::Ne => "!=",
+        BinaryOp::Lt => "<",
+        BinaryOp::Le => "<=",
+        BinaryOp::Gt => ">",
+        BinaryOp::Ge => ">=",
+        BinaryOp::And => "&&",
+        BinaryOp::Or => "||",
+    }
+}
+
+/// 值复制产生的堆分配（标量不计）
+fn heap_size(value: &Value) -> usize {
+    match value {
+        Value::Null | Value::Bool(_) | Value::Number(_) => 0,
+        other => other.memory_size(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(source: &str) -> Result<Value, ScriptError> {
+        let limits = SandboxLimits::default();
+        let script = Script::load(source, Value::Map(BTreeMap::new()), &limits)?;
+        let mut state = Value::Map(BTreeMap::new());
+        let outcome = script.call("on_market_event", vec![Value::Null], &mut state, &limits)?;
+        Ok(outcome.map(|o| o.value).unwrap_or_default())
+    }
+
+    #[test]
+    fn test_semantics_and_builtins() {
+        let value = run(r#"
+            fn on_market_event(e) {
+                let xs = [1, 2, 3, 4];
+                let copy = xs;
+                copy[0] = 10;
+                let m = {a: 1};
+                m.b.c = 2;
+                let total = 0;
+                for k in m { total = total + len(k); }
+                push(state.window, 5, 2); push(state.window, 6, 2); push(state.window, 7, 2);
+                return [xs[0], xs[-1], m.missing, total, sum(state.window), mean(xs, 2), slice(xs, -2), "n=" + 1.5];
+            }
+        "#)
+        .unwrap();
+        assert_eq!(
+            value.to_json(),
+            serde_json::json!([1.0, 4.0, null, 2.0, 13.0, 3.5, [3.0, 4.0], "n=1.5"])
+        );
+
+        let err = |source: &str| run(source).unwrap_err().to_string();
+        assert!(err("fn on_market_event(e) { return [1][1]; }").contains("越界"));
+        assert!(err("fn on_market_event(e) { return 1 / 0; }").contains("有限数"));
+        assert!(err("fn on_market_event(e) { if 1 { } }").contains("bool"));
+        assert!(err("fn on_market_event(e) { state = 1; }").contains("map"));
+        assert!(err("fn on_market_event(e) { x = 1; }").contains("未定义"));
+        assert!(err("fn on_market_event(e) { params.x = 1; }").contains("只读"));
+        assert!(err("fn on_market_event(e) { require_abi(2); }").contains("ABI"));
+        // 值嵌套深度受限
+        let nested = err("fn on_market_event(e) { let v = 1; while true { v = [v]; } }");
+        assert!(nested.contains("嵌套"), "{}", nested);
+        // 错误带行列位置
+        assert!(err("fn on_market_event(e) {\n  return nope;\n}").contains("第 2 行"));
+    }
+}
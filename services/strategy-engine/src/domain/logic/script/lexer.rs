@@ -0,0 +1,260 @@
+//! # 词法分析 (Lexer)
+//!
+//! 把脚本源码切分为记号，记录行列号供错误定位。
+
+use super::error::{ScriptError, ScriptErrorKind};
+
+/// 记号
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Number(f64),
+    Str(String),
+    Ident(String),
+    // 关键字
+    Fn,
+    Let,
+    If,
+    Else,
+    While,
+    For,
+    In,
+    Return,
+    Break,
+    Continue,
+    True,
+    False,
+    Null,
+    // 符号
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Comma,
+    Colon,
+    Semicolon,
+    Dot,
+    Assign,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Bang,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+    Eof,
+}
+
+/// 源码位置（从 1 开始）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Position {
+    pub line: u32,
+    pub column: u32,
+}
+
+/// 带位置的记号
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spanned {
+    pub token: Token,
+    pub position: Position,
+}
+
+/// 切分记号（末尾追加 `Eof`）
+pub fn tokenize(source: &str) -> Result<Vec<Spanned>, ScriptError> {
+    let mut lexer = Lexer {
+        chars: source.chars().collect(),
+        index: 0,
+        position: Position { line: 1, column: 1 },
+    };
+    let mut tokens = Vec::new();
+    loop {
+        lexer.skip_trivia();
+        let position = lexer.position;
+        let Some(c) = lexer.peek() else {
+            tokens.push(Spanned {
+                token: Token::Eof,
+                position,
+            });
+            return Ok(tokens);
+        };
+        let token = if c.is_ascii_digit() {
+            lexer.number()?
+        } else if c == '"' {
+            lexer.string()?
+        } else if c.is_alphabetic() || c == '_' {
+            lexer.word()
+        } else {
+            lexer.symbol()?
+        };
+        tokens.push(Spanned { token, position });
+    }
+}
+
+struct Lexer {
+    chars: Vec<char>,
+    index: usize,
+    position: Position,
+}
+
+impl Lexer {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.index).copied()
+    }
+
+    fn peek_next(&self) -> Option<char> {
+        self.chars.get(self.index + 1).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.index += 1;
+        if c == '\n' {
+            self.position.line += 1;
+            self.position.column = 1;
+        } else {
+            self.position.column += 1;
+        }
+        Some(c)
+    }
+
+    fn error(&self, message: impl Into<String>) -> ScriptError {
+        ScriptError::new(ScriptErrorKind::Syntax, message).at(self.position)
+    }
+
+    /// 跳过空白与 `//` 注释
+    fn skip_trivia(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.bump();
+            } else if c == '/' && self.peek_next() == Some('/') {
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.bump();
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Token, ScriptError> {
+        let start = self.index;
+        while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '_') {
+            self.bump();
+        }
+        if self.peek() == Some('.') && self.peek_next().is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+            while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '_') {
+                self.bump();
+            }
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.bump();
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.bump();
+            }
+            while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                self.bump();
+            }
+        }
+        let text: String = self.chars[start..self.index]
+            .iter()
+            .filter(|c| **c != '_')
+            .collect();
+        text.parse()
+            .map(Token::Number)
+            .map_err(|_| self.error(format!("无效的数字 {}", text)))
+    }
+
+    fn string(&mut self) -> Result<Token, ScriptError> {
+        self.bump();
+        let mut value = String::new();
+        loop {
+            match self.bump() {
+                None | Some('\n') => return Err(self.error("字符串缺少结束引号")),
+                Some('"') => return Ok(Token::Str(value)),
+                Some('\\') => match self.bump() {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some('"') => value.push('"'),
+                    Some('\\') => value.push('\\'),
+                    other => {
+                        return Err(self.error(format!("无效的转义字符 {:?}", other)));
+                    }
+                },
+                Some(c) => value.push(c),
+            }
+        }
+    }
+
+    fn word(&mut self) -> Token {
+        let start = self.index;
+        while self.peek().is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            self.bump();
+        }
+        let word: String = self.chars[start..self.index].iter().collect();
+        match word.as_str() {
+            "fn" => Token::Fn,
+            "let" => Token::Let,
+            "if" => Token::If,
+            "else" => Token::Else,
+            "while" => Token::While,
+            "for" => Token::For,
+            "in" => Token::In,
+            "return" => Token::Return,
+            "break" => Token::Break,
+            "continue" => Token::Continue,
+            "true" => Token::True,
+            "false" => Token::False,
+            "null" => Token::Null,
+            _ => Token::Ident(word),
+        }
+    }
+
+    fn symbol(&mut self) -> Result<Token, ScriptError> {
+        let c = self.bump().unwrap_or_default();
+        let followed_by = |lexer: &mut Lexer, expected: char| {
+            let matched = lexer.peek() == Some(expected);
+            if matched {
+                lexer.bump();
+            }
+            matched
+        };
+        let token = match c {
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '{' => Token::LBrace,
+            '}' => Token::RBrace,
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            ',' => Token::Comma,
+            ':' => Token::Colon,
+            ';' => Token::Semicolon,
+            '.' => Token::Dot,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '%' => Token::Percent,
+            '=' if followed_by(self, '=') => Token::Eq,
+            '=' => Token::Assign,
+            '!' if followed_by(self, '=') => Token::Ne,
+            '!' => Token::Bang,
+            '<' if followed_by(self, '=') => Token::Le,
+            '<' => Token::Lt,
+            '>' if followed_by(self, '=') => Token::Ge,
+            '>' => Token::Gt,
+            '&' if followed_by(self, '&') => Token::And,
+            '|' if followed_by(self, '|') => Token::Or,
+            other => return Err(self.error(format!("无法识别的字符 {:?}", other))),
+        };
+        Ok(token)
+    }
+}
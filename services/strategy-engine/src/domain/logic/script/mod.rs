@@ -0,0 +1,544 @@
+//! # 用户脚本策略 (User Script Strategies)
+//!
+//! 量化研究员上传脚本即可新增策略，无需重新编译服务。
+//! 脚本运行在内嵌的沙箱解释器中，通过稳定的 ABI 实现策略契约。
+//!
+//! ## 脚本语言
+//! - 语句：`let`、赋值、`if / else`、`while`、`for x in 列表或映射`、`return`、`break`、`continue`
+//! - 值：null、bool、number（f64）、string、list（`[1, 2]`）、map（`{a: 1, "b": 2}`）
+//! - 值语义：赋值与传参都是复制；映射中缺失的键读作 null
+//! - 条件必须是 bool，不做隐式真值转换
+//! - 顶层 `let` 为只读的全局常量；`params` 为创建参数（只读）；`state` 为持久状态
+//!
+//! ## ABI v1
+//! - 钩子：`on_market_event(event)`（必需）、`on_fill(fill)`、`on_position(position)`、`reset()`
+//! - `event`：`abi`、`kind`（trade / depth / tick / kline）、`symbol`、`timestamp`（毫秒）、
+//!   `price`（成交价 / 盘口中间价 / 收盘价），以及各类型的字段
+//! - `on_market_event` 返回 null 或信号：`open_long / open_short / close_long / close_short(数量?, 置信度?)`
+//! - 内置函数见 `interpreter::BUILTINS`，都是纯计算，没有网络、文件与时钟访问
+//!
+//! ## 隔离
+//! - 每个实例独立加载脚本、独立持有状态，实例之间（包括不同所有者之间）不共享任何数据
+//! - 每次回调都有指令数、内存、时间与调用深度预算（`SandboxLimits`），持久状态另有上限
+//! - 回调在状态副本上执行，成功才提交；失败时状态不变并上报故障（`Strategy::take_fault`），
+//!   计入实例失败次数，由监督器按重启策略处理
+
+pub mod ast;
+pub mod error;
+pub mod interpreter;
+pub mod lexer;
+pub mod parser;
+pub mod sandbox;
+pub mod value;
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::{PositionSnapshot, StrategyFill};
+use shared::event::market_event::{MarketEvent, MarketEventData};
+use shared::types::order::OrderSide;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
+use crate::domain::model::market_type::MarketType;
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalType};
+
+pub use error::{ScriptError, ScriptErrorKind};
+pub use interpreter::{Script, ABI_VERSION};
+pub use sandbox::SandboxLimits;
+pub use value::Value;
+
+/// 盘口传给脚本的档数
+const DEPTH_LEVELS: usize = 10;
+
+/// 用户脚本策略配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScriptConfig {
+    /// 脚本源码
+    pub source: String,
+    /// 脚本参数（脚本中通过 `params` / `param()` 读取）
+    pub params: serde_json::Map<String, serde_json::Value>,
+    /// 默认交易数量（信号未指定数量时使用）
+    pub quantity: Decimal,
+    /// 沙箱资源限制
+    pub limits: SandboxLimits,
+}
+
+impl Default for ScriptConfig {
+    fn default() -> Self {
+        Self {
+            source: String::new(),
+            params: serde_json::Map::new(),
+            quantity: Decimal::new(1, 3), // 0.001
+            limits: SandboxLimits::default(),
+        }
+    }
+}
+
+impl ScriptConfig {
+    /// 校验限制并加载脚本
+    pub fn compile(&self) -> Result<Script> {
+        if let Some((name, value, max)) = self.limits.exceeded().into_iter().next() {
+            bail!("沙箱限制 {} = {} 超出范围 1..={}", name, value, max);
+        }
+        let params = Value::from_json(&serde_json::Value::Object(self.params.clone()));
+        Script::load(&self.source, params, &self.limits).map_err(|e| anyhow!("脚本加载失败: {}", e))
+    }
+}
+
+/// 用户脚本策略
+pub struct ScriptStrategy {
+    meta: StrategyMeta,
+    config: ScriptConfig,
+    script: Script,
+    /// 持久状态（始终是映射）
+    state: Value,
+    /// 最近一次回调的故障
+    fault: Option<String>,
+}
+
+impl ScriptStrategy {
+    /// 创建用户脚本策略实例
+    ///
+    /// 加载失败或 `reset()` 执行失败时返回错误。
+    pub fn new(
+        instance_id: Uuid,
+        symbol: String,
+        config: ScriptConfig,
+        market_type: MarketType,
+    ) -> Result<Self> {
+        let script = config.compile()?;
+        let mut strategy = Self {
+            meta: StrategyMeta {
+                instance_id,
+                strategy_type: "script".to_string(),
+                market_type,
+                symbol,
+                is_active: false,
+            },
+            config,
+            script,
+            state: Value::Map(BTreeMap::new()),
+            fault: None,
+        };
+        strategy
+            .run("reset", Vec::new())
+            .map_err(|e| anyhow!("脚本 reset() 执行失败: {}", e))?;
+        Ok(strategy)
+    }
+
+    /// 在状态副本上执行钩子，成功才提交
+    fn run(&mut self, hook: &str, args: Vec<Value>) -> Result<Value, ScriptError> {
+        let mut state = self.state.clone();
+        let Some(outcome) = self.script.call(hook, args, &mut state, &self.config.limits)? else {
+            return Ok(Value::Null);
+        };
+        for line in &outcome.logs {
+            debug!(instance_id = %self.meta.instance_id, hook, "脚本日志: {}", line);
+        }
+        self.state = state;
+        Ok(outcome.value)
+    }
+
+    /// 执行钩子，失败时记录故障
+    fn guarded(&mut self, hook: &str, args: Vec<Value>) -> Value {
+        match self.run(hook, args) {
+            Ok(value) => value,
+            Err(e) => {
+                warn!(
+                    instance_id = %self.meta.instance_id,
+                    hook,
+                    kind = %e.kind,
+                    error = %e,
+                    "脚本执行失败"
+                );
+                self.fault = Some(format!("{}: {}", hook, e));
+                Value::Null
+            }
+        }
+    }
+
+    /// 把脚本返回值转为信号
+    fn to_signal(&self, event: &MarketEvent, price: Decimal, value: Value) -> Result<Option<Signal>, String> {
+        let entries = match value {
+            Value::Null => return Ok(None),
+            Value::Map(entries) => entries,
+            other => {
+                return Err(format!(
+                    "on_market_event 应返回 null 或信号，实际为 {}",
+                    other.type_name()
+                ))
+            }
+        };
+
+        let action = match entries.get("action") {
+            Some(Value::Str(action)) => action.as_str(),
+            _ => return Err("信号缺少 action".to_string()),
+        };
+        let (signal_type, instruction) = match action {
+            "open_long" => (SignalType::Buy, OrderInstruction::open()),
+            "open_short" if self.meta.market_type.is_futures() => {
+                (SignalType::Sell, OrderInstruction::open())
+            }
+            "open_short" => return Err("现货不能开空".to_string()),
+            "close_long" => (SignalType::Sell, OrderInstruction::close()),
+            "close_short" => (SignalType::Buy, OrderInstruction::close()),
+            other => return Err(format!("未知的信号动作 {}", other)),
+        };
+
+        let quantity = match entries.get("quantity") {
+            None => self.config.quantity,
+            Some(Value::Number(n)) if *n > 0.0 => {
+                Decimal::from_f64(*n).ok_or_else(|| format!("信号数量 {} 无效", n))?
+            }
+            Some(other) => return Err(format!("信号数量必须是正数，实际为 {}", other.display())),
+        };
+        let confidence = match entries.get("confidence") {
+            None => 1.0,
+            Some(Value::Number(c)) if (0.0..=1.0).contains(c) => *c,
+            Some(other) => return Err(format!("信号置信度必须在 0~1 之间，实际为 {}", other.display())),
+        };
+
+        Ok(Some(Signal {
+            id: Uuid::new_v4(),
+            strategy_id: self.meta.instance_id,
+            symbol: event.symbol.clone(),
+            signal_type,
+            price,
+            quantity,
+            confidence,
+            created_at: event.timestamp,
+            instruction,
+        }))
+    }
+}
+
+/// 行情事件转为脚本对象，同时给出信号参考价
+fn event_value(event: &MarketEvent) -> Option<(Value, Decimal)> {
+    let mut fields = BTreeMap::new();
+    fields.insert("abi".to_string(), Value::Number(ABI_VERSION as f64));
+    fields.insert("symbol".to_string(), Value::from(event.symbol.as_str()));
+    fields.insert("timestamp".to_string(), timestamp(event.timestamp));
+
+    let (kind, price) = match &event.data {
+        MarketEventData::Trade(trade) => {
+            fields.insert("quantity".to_string(), number(trade.quantity));
+            fields.insert("is_buyer_maker".to_string(), Value::Bool(trade.is_buyer_maker));
+            ("trade", trade.price)
+        }
+        MarketEventData::Depth(depth) => {
+            let (bid, ask) = (depth.bids.first()?.0, depth.asks.first()?.0);
+            fields.insert("best_bid".to_string(), number(bid));
+            fields.insert("best_ask".to_string(), number(ask));
+            fields.insert("bids".to_string(), levels(&depth.bids));
+            fields.insert("asks".to_string(), levels(&depth.asks));
+            ("depth", (bid + ask) / Decimal::TWO)
+        }
+        MarketEventData::Tick(tick) => {
+            fields.insert("bid".to_string(), number(tick.bid));
+            fields.insert("ask".to_string(), number(tick.ask));
+            ("tick", tick.price)
+        }
+        MarketEventData::Kline(kline) => {
+            fields.insert("interval".to_string(), Value::from(kline.interval.as_str()));
+            fields.insert("open".to_string(), number(kline.open));
+            fields.insert("high".to_string(), number(kline.high));
+            fields.insert("low".to_string(), number(kline.low));
+            fields.insert("close".to_string(), number(kline.close));
+            fields.insert("volume".to_string(), number(kline.volume));
+            ("kline", kline.close)
+        }
+    };
+    fields.insert("kind".to_string(), Value::from(kind));
+    fields.insert("price".to_string(), number(price));
+    Some((Value::Map(fields), price))
+}
+
+/// 成交回报转为脚本对象
+fn fill_value(symbol: &str, fill: &StrategyFill) -> Value {
+    let side = match fill.side {
+        OrderSide::Buy => "buy",
+        OrderSide::Sell => "sell",
+    };
+    Value::Map(BTreeMap::from([
+        ("symbol".to_string(), Value::from(symbol)),
+        ("side".to_string(), Value::from(side)),
+        ("price".to_string(), number(fill.price)),
+        ("quantity".to_string(), number(fill.quantity)),
+        ("cumulative_quantity".to_string(), number(fill.cumulative_quantity)),
+        ("original_quantity".to_string(), number(fill.original_quantity)),
+        ("is_final".to_string(), Value::Bool(fill.is_final)),
+        ("commission".to_string(), number(fill.commission)),
+        ("timestamp".to_string(), timestamp(fill.fill_time)),
+    ]))
+}
+
+/// 持仓快照转为脚本对象
+fn position_value(symbol: &str, snapshot: &PositionSnapshot) -> Value {
+    Value::Map(BTreeMap::from([
+        ("symbol".to_string(), Value::from(symbol)),
+        ("quantity".to_string(), number(snapshot.quantity)),
+        ("average_price".to_string(), number(snapshot.average_price)),
+    ]))
+}
+
+fn number(value: Decimal) -> Value {
+    Value::Number(value.to_f64().unwrap_or_default())
+}
+
+fn timestamp(time: DateTime<Utc>) -> Value {
+    Value::Number(time.timestamp_millis() as f64)
+}
+
+fn levels(levels: &[(Decimal, Decimal)]) -> Value {
+    Value::List(
+        levels
+            .iter()
+            .take(DEPTH_LEVELS)
+            .map(|(price, quantity)| Value::List(vec![number(*price), number(*quantity)]))
+            .collect(),
+    )
+}
+
+impl Strategy for ScriptStrategy {
+    fn meta(&self) -> &StrategyMeta {
+        &self.meta
+    }
+
+    fn meta_mut(&mut self) -> &mut StrategyMeta {
+        &mut self.meta
+    }
+
+    fn on_market_event(&mut self, event: &MarketEvent) -> Option<Signal> {
+        if !self.is_active() || event.symbol != self.meta.symbol {
+            return None;
+        }
+        let (value, price) = event_value(event)?;
+        let result = self.guarded("on_market_event", vec![value]);
+        match self.to_signal(event, price, result) {
+            Ok(signal) => signal,
+            Err(e) => {
+                warn!(instance_id = %self.meta.instance_id, error = %e, "脚本返回的信号无效");
+                self.fault = Some(e);
+                None
+            }
+        }
+    }
+
+    fn on_fill(&mut self, symbol: &str, fill: &StrategyFill) {
+        if self.script.has_function("on_fill") {
+            self.guarded("on_fill", vec![fill_value(symbol, fill)]);
+        }
+    }
+
+    fn on_position_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {
+        if self.script.has_function("on_position") {
+            self.guarded("on_position", vec![position_value(symbol, snapshot)]);
+        }
+    }
+
+    fn take_fault(&mut self) -> Option<String> {
+        self.fault.take()
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        Some(self.state.to_json())
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let state = Value::from_json(&state);
+        if !matches!(state, Value::Map(_)) {
+            bail!("脚本状态必须是 map");
+        }
+        if state.depth() > sandbox::MAX_VALUE_DEPTH {
+            bail!("脚本状态嵌套超过 {} 层", sandbox::MAX_VALUE_DEPTH);
+        }
+        if state.memory_size() > self.config.limits.max_state_bytes {
+            bail!("脚本状态超过上限 {} 字节", self.config.limits.max_state_bytes);
+        }
+        self.state = state;
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.state = Value::Map(BTreeMap::new());
+        self.guarded("reset", Vec::new());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::event::market_event::{MarketEventType, TradeData};
+
+    const MA_CROSS: &str = r#"
+        require_abi(1);
+        let fast = param("fast", 3);
+        let slow = param("slow", 5);
+
+        fn reset() {
+            state.prices = [];
+            state.long = false;
+        }
+
+        fn on_market_event(event) {
+            push(state.prices, event.price, slow);
+            if len(state.prices) < slow { return null; }
+            let spread = mean(state.prices, fast) - mean(state.prices, slow);
+            if spread > 0 && !state.long {
+                state.long = true;
+                return open_long(null, 0.8);
+            }
+            if spread < 0 && state.long {
+                state.long = false;
+                return close_long();
+            }
+            return null;
+        }
+    "#;
+
+    fn trade(price: i64) -> MarketEvent {
+        MarketEvent {
+            event_type: MarketEventType::Trade,
+            exchange: "binance".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            timestamp: Utc::now(),
+            data: MarketEventData::Trade(TradeData {
+                trade_id: "1".to_string(),
+                price: Decimal::from(price),
+                quantity: Decimal::ONE,
+                is_buyer_maker: false,
+            }),
+        }
+    }
+
+    fn build(source: &str, limits: SandboxLimits) -> Result<ScriptStrategy> {
+        let config = ScriptConfig {
+            source: source.to_string(),
+            limits,
+            ..Default::default()
+        };
+        let mut strategy = ScriptStrategy::new(Uuid::new_v4(), "BTCUSDT".to_string(), config, MarketType::Spot)?;
+        strategy.activate();
+        Ok(strategy)
+    }
+
+    #[test]
+    fn test_script_ma_cross_signals_and_state() {
+        let mut strategy = build(MA_CROSS, SandboxLimits::default()).unwrap();
+
+        let mut signals = Vec::new();
+        for price in [100, 100, 100, 100, 100, 101, 103, 106, 104, 100, 96, 93] {
+            if let Some(signal) = strategy.on_market_event(&trade(price)) {
+                signals.push(signal);
+            }
+            assert!(strategy.take_fault().is_none());
+        }
+        assert_eq!(signals.len(), 2);
+        assert_eq!(signals[0].signal_type, SignalType::Buy);
+        assert_eq!(signals[0].quantity, Decimal::new(1, 3));
+        assert_eq!(signals[0].confidence, 0.8);
+        assert_eq!(signals[1].signal_type, SignalType::Sell);
+        assert!(signals[1].is_reduce_only());
+
+        // 状态可导出恢复，窗口按 slow 截断
+        let exported = strategy.export_state().unwrap();
+        assert_eq!(exported["prices"].as_array().unwrap().len(), 5);
+        let mut restored = build(MA_CROSS, SandboxLimits::default()).unwrap();
+        restored.import_state(exported.clone()).unwrap();
+        assert_eq!(restored.export_state().unwrap(), exported);
+        assert!(restored.import_state(serde_json::json!([1, 2])).is_err());
+    }
+
+    #[test]
+    fn test_script_limits_fault_and_rollback() {
+        let runaway = r#"
+            fn reset() { state.count = 0; }
+            fn on_market_event(event) {
+                state.count = state.count + 1;
+                if event.price > 100 { while true {} }
+                if event.price < 100 { let s = "x"; while true { s = s + s; } }
+                return null;
+            }
+        "#;
+        let mut strategy = build(runaway, SandboxLimits::default()).unwrap();
+
+        strategy.on_market_event(&trade(100));
+        assert!(strategy.take_fault().is_none());
+
+        // 指令数超限：故障上报，状态回滚
+        strategy.on_market_event(&trade(101));
+        assert!(strategy.take_fault().unwrap().contains("指令数"));
+        // 字符串或内存超限
+        strategy.on_market_event(&trade(99));
+        assert!(strategy.take_fault().is_some());
+        assert_eq!(strategy.export_state().unwrap()["count"], 1.0);
+
+        // 时间超限
+        let limits = SandboxLimits {
+            max_operations: SandboxLimits::CEILING.max_operations,
+            max_time_ms: 1,
+            ..Default::default()
+        };
+        let mut slow = ScriptStrategy::new(
+            Uuid::new_v4(),
+            "BTCUSDT".to_string(),
+            ScriptConfig {
+                source: "fn on_market_event(e) { let i = 0; while true { i = i + 1; } }".to_string(),
+                limits,
+                ..Default::default()
+            },
+            MarketType::Spot,
+        )
+        .unwrap();
+        slow.activate();
+        slow.on_market_event(&trade(100));
+        let fault = slow.take_fault().unwrap();
+        assert!(fault.contains("执行时间") || fault.contains("指令数"), "{}", fault);
+
+        // 递归深度
+        let recursive = "fn f(n) { return f(n + 1); } fn on_market_event(e) { return f(0); }";
+        let mut strategy = build(recursive, SandboxLimits::default()).unwrap();
+        strategy.on_market_event(&trade(100));
+        assert!(strategy.take_fault().unwrap().contains("调用深度"));
+
+        // 现货开空与无效信号
+        let short = "fn on_market_event(e) { return open_short(); }";
+        let mut strategy = build(short, SandboxLimits::default()).unwrap();
+        assert!(strategy.on_market_event(&trade(100)).is_none());
+        assert!(strategy.take_fault().unwrap().contains("现货"));
+    }
+
+    #[test]
+    fn test_script_load_errors() {
+        let load = |source: &str| build(source, SandboxLimits::default()).err().map(|e| e.to_string());
+
+        assert!(load("fn reset() {}").unwrap().contains("on_market_event"));
+        assert!(load("fn on_market_event() {}").unwrap().contains("参数"));
+        assert!(load("fn len(x) {} fn on_market_event(e) {}").unwrap().contains("内置"));
+        assert!(load("let x = 1 fn on_market_event(e) {}").is_some());
+        assert!(load("fn reset() { state.x = 1 / 0; } fn on_market_event(e) {}").unwrap().contains("reset"));
+        // 顶层不能访问 state，全局常量只读
+        assert!(load("let s = state; fn on_market_event(e) {}").is_some());
+        let readonly = "let k = 1; fn on_market_event(e) { k = 2; }";
+        let mut strategy = build(readonly, SandboxLimits::default()).unwrap();
+        strategy.on_market_event(&trade(100));
+        assert!(strategy.take_fault().unwrap().contains("只读"));
+
+        let limits = SandboxLimits {
+            max_time_ms: 10_000,
+            ..Default::default()
+        };
+        assert!(strategy_with_limits_error(limits).contains("max_time_ms"));
+    }
+
+    fn strategy_with_limits_error(limits: SandboxLimits) -> String {
+        build("fn on_market_event(e) {}", limits).err().unwrap().to_string()
+    }
+}
@@ -0,0 +1,433 @@
+//! # 语法分析 (Parser)
+//!
+//! 递归下降解析。语法树深度有上限（括号、代码块与运算符 / 字段链的每一环都计一层），
+//! 过深的脚本在加载时拒绝，执行期间的递归深度因此有界。
+
+use super::ast::{BinaryOp, Expr, ExprKind, Function, Program, Stmt, StmtKind, UnaryOp};
+use super::error::{ScriptError, ScriptErrorKind};
+use super::lexer::{tokenize, Position, Spanned, Token};
+
+/// 最大语法树深度
+pub const MAX_NESTING: usize = 64;
+
+/// 解析脚本
+pub fn parse(source: &str) -> Result<Program, ScriptError> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        index: 0,
+        depth: 0,
+    };
+    let mut program = Program::default();
+    while !parser.check(&Token::Eof) {
+        if parser.check(&Token::Fn) {
+            let function = parser.function()?;
+            if program.functions.iter().any(|f| f.name == function.name) {
+                return Err(syntax(format!("函数 {} 重复定义", function.name)).at(function.position));
+            }
+            program.functions.push(function);
+        } else {
+            program.statements.push(parser.statement()?);
+        }
+    }
+    Ok(program)
+}
+
+fn syntax(message: impl Into<String>) -> ScriptError {
+    ScriptError::new(ScriptErrorKind::Syntax, message)
+}
+
+struct Parser {
+    tokens: Vec<Spanned>,
+    index: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.index].token
+    }
+
+    fn position(&self) -> Position {
+        self.tokens[self.index].position
+    }
+
+    fn check(&self, token: &Token) -> bool {
+        self.peek() == token
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.index].token.clone();
+        if token != Token::Eof {
+            self.index += 1;
+        }
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        let matched = self.check(token);
+        if matched {
+            self.advance();
+        }
+        matched
+    }
+
+    fn expect(&mut self, token: Token, what: &str) -> Result<(), ScriptError> {
+        if self.eat(&token) {
+            Ok(())
+        } else {
+            Err(self.unexpected(what))
+        }
+    }
+
+    fn unexpected(&self, what: &str) -> ScriptError {
+        syntax(format!("期望 {}，实际为 {:?}", what, self.peek())).at(self.position())
+    }
+
+    fn ident(&mut self, what: &str) -> Result<String, ScriptError> {
+        match self.peek().clone() {
+            Token::Ident(name) => {
+                self.advance();
+                Ok(name)
+            }
+            _ => Err(self.unexpected(what)),
+        }
+    }
+
+    /// 进入一层嵌套
+    fn enter(&mut self) -> Result<(), ScriptError> {
+        self.depth += 1;
+        if self.depth > MAX_NESTING {
+            return Err(syntax(format!("嵌套超过 {} 层", MAX_NESTING)).at(self.position()));
+        }
+        Ok(())
+    }
+
+    fn leave(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn function(&mut self) -> Result<Function, ScriptError> {
+        let position = self.position();
+        self.expect(Token::Fn, "fn")?;
+        let name = self.ident("函数名")?;
+        self.expect(Token::LParen, "(")?;
+        let mut params = Vec::new();
+        if !self.check(&Token::RParen) {
+            loop {
+                let param = self.ident("参数名")?;
+                if params.contains(&param) {
+                    return Err(syntax(format!("参数 {} 重复", param)).at(position));
+                }
+                params.push(param);
+                if !self.eat(&Token::Comma) {
+                    break;
+                }
+            }
+        }
+        self.expect(Token::RParen, ")")?;
+        let body = self.block()?;
+        Ok(Function {
+            name,
+            params,
+            body,
+            position,
+        })
+    }
+
+    fn block(&mut self) -> Result<Vec<Stmt>, ScriptError> {
+        self.enter()?;
+        self.expect(Token::LBrace, "{")?;
+        let mut statements = Vec::new();
+        while !self.check(&Token::RBrace) {
+            if self.check(&Token::Eof) {
+                return Err(self.unexpected("}"));
+            }
+            if self.check(&Token::Fn) {
+                return Err(syntax("函数只能在顶层定义").at(self.position()));
+            }
+            statements.push(self.statement()?);
+        }
+        self.advance();
+        self.leave();
+        Ok(statements)
+    }
+
+    fn statement(&mut self) -> Result<Stmt, ScriptError> {
+        let position = self.position();
+        let kind = match self.peek() {
+            Token::Let => {
+                self.advance();
+                let name = self.ident("变量名")?;
+                self.expect(Token::Assign, "=")?;
+                let value = self.expression()?;
+                self.expect(Token::Semicolon, ";")?;
+                StmtKind::Let(name, value)
+            }
+            Token::If => return self.if_statement(),
+            Token::While => {
+                self.advance();
+                let condition = self.expression()?;
+                StmtKind::While(condition, self.block()?)
+            }
+            Token::For => {
+                self.advance();
+                let name = self.ident("循环变量")?;
+                self.expect(Token::In, "in")?;
+                let iterable = self.expression()?;
+                StmtKind::For(name, iterable, self.block()?)
+            }
+            Token::Return => {
+                self.advance();
+                let value = if self.check(&Token::Semicolon) {
+                    None
+                } else {
+                    Some(self.expression()?)
+                };
+                self.expect(Token::Semicolon, ";")?;
+                StmtKind::Return(value)
+            }
+            Token::Break => {
+                self.advance();
+                self.expect(Token::Semicolon, ";")?;
+                StmtKind::Break
+            }
+            Token::Continue => {
+                self.advance();
+                self.expect(Token::Semicolon, ";")?;
+                StmtKind::Continue
+            }
+            _ => {
+                let target = self.expression()?;
+                let kind = if self.eat(&Token::Assign) {
+                    if !matches!(
+                        target.kind,
+                        ExprKind::Var(_) | ExprKind::Field(..) | ExprKind::Index(..)
+                    ) {
+                        return Err(syntax("只能给变量、字段或下标赋值").at(target.position));
+                    }
+                    StmtKind::Assign(target, self.expression()?)
+                } else {
+                    StmtKind::Expr(target)
+                };
+                self.expect(Token::Semicolon, ";")?;
+                kind
+            }
+        };
+        Ok(Stmt { kind, position })
+    }
+
+    fn if_statement(&mut self) -> Result<Stmt, ScriptError> {
+        let position = self.position();
+        self.expect(Token::If, "if")?;
+        let condition = self.expression()?;
+        let then_branch = self.block()?;
+        let else_branch = if self.eat(&Token::Else) {
+            if self.check(&Token::If) {
+                self.enter()?;
+                let nested = self.if_statement()?;
+                self.leave();
+                vec![nested]
+            } else {
+                self.block()?
+            }
+        } else {
+            Vec::new()
+        };
+        Ok(Stmt {
+            kind: StmtKind::If(condition, then_branch, else_branch),
+            position,
+        })
+    }
+
+    fn expression(&mut self) -> Result<Expr, ScriptError> {
+        self.enter()?;
+        let expr = self.binary(0)?;
+        self.leave();
+        Ok(expr)
+    }
+
+    /// 按优先级解析二元运算（左结合）
+    fn binary(&mut self, level: usize) -> Result<Expr, ScriptError> {
+        const LEVELS: &[&[(Token, BinaryOp)]] = &[
+            &[(Token::Or, BinaryOp::Or)],
+            &[(Token::And, BinaryOp::And)],
+            &[(Token::Eq, BinaryOp::Eq), (Token::Ne, BinaryOp::Ne)],
+            &[
+                (Token::Lt, BinaryOp::Lt),
+                (Token::Le, BinaryOp::Le),
+                (Token::Gt, BinaryOp::Gt),
+                (Token::Ge, BinaryOp::Ge),
+            ],
+            &[(Token::Plus, BinaryOp::Add), (Token::Minus, BinaryOp::Sub)],
+            &[
+                (Token::Star, BinaryOp::Mul),
+                (Token::Slash, BinaryOp::Div),
+                (Token::Percent, BinaryOp::Rem),
+            ],
+        ];
+        if level == LEVELS.len() {
+            return self.unary();
+        }
+
+        let mut left = self.binary(level + 1)?;
+        let mut chained = 0;
+        while let Some((_, op)) = LEVELS[level].iter().find(|(token, _)| self.check(token)) {
+            let op = *op;
+            let position = self.position();
+            self.advance();
+            self.enter()?;
+            chained += 1;
+            let right = self.binary(level + 1)?;
+            left = Expr {
+                kind: ExprKind::Binary(op, Box::new(left), Box::new(right)),
+                position,
+            };
+        }
+        self.depth -= chained;
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expr, ScriptError> {
+        let position = self.position();
+        let op = match self.peek() {
+            Token::Minus => UnaryOp::Neg,
+            Token::Bang => UnaryOp::Not,
+            _ => return self.postfix(),
+        };
+        self.advance();
+        self.enter()?;
+        let operand = self.unary()?;
+        self.leave();
+        Ok(Expr {
+            kind: ExprKind::Unary(op, Box::new(operand)),
+            position,
+        })
+    }
+
+    fn postfix(&mut self) -> Result<Expr, ScriptError> {
+        let mut expr = self.primary()?;
+        let mut chained = 0;
+        loop {
+            let position = self.position();
+            if matches!(self.peek(), Token::Dot | Token::LBracket | Token::LParen) {
+                self.enter()?;
+                chained += 1;
+            }
+            if self.eat(&Token::Dot) {
+                let field = self.ident("字段名")?;
+                expr = Expr {
+                    kind: ExprKind::Field(Box::new(expr), field),
+                    position,
+                };
+            } else if self.eat(&Token::LBracket) {
+                let index = self.expression()?;
+                self.expect(Token::RBracket, "]")?;
+                expr = Expr {
+                    kind: ExprKind::Index(Box::new(expr), Box::new(index)),
+                    position,
+                };
+            } else if self.check(&Token::LParen) {
+                let ExprKind::Var(name) = expr.kind else {
+                    return Err(syntax("只能调用函数名").at(position));
+                };
+                self.advance();
+                let args = self.list(Token::RParen)?;
+                expr = Expr {
+                    kind: ExprKind::Call(name, args),
+                    position: expr.position,
+                };
+            } else {
+                self.depth -= chained;
+                return Ok(expr);
+            }
+        }
+    }
+
+    /// 逗号分隔的表达式列表（允许末尾逗号）
+    fn list(&mut self, close: Token) -> Result<Vec<Expr>, ScriptError> {
+        let mut items = Vec::new();
+        while !self.check(&close) {
+            items.push(self.expression()?);
+            if !self.eat(&Token::Comma) {
+                break;
+            }
+        }
+        self.expect(close, "列表结束符")?;
+        Ok(items)
+    }
+
+    fn primary(&mut self) -> Result<Expr, ScriptError> {
+        let position = self.position();
+        let kind = match self.advance() {
+            Token::Null => ExprKind::Null,
+            Token::True => ExprKind::Bool(true),
+            Token::False => ExprKind::Bool(false),
+            Token::Number(n) => ExprKind::Number(n),
+            Token::Str(s) => ExprKind::Str(s),
+            Token::Ident(name) => ExprKind::Var(name),
+            Token::LParen => {
+                let expr = self.expression()?;
+                self.expect(Token::RParen, ")")?;
+                return Ok(expr);
+            }
+            Token::LBracket => {
+                self.enter()?;
+                let items = self.list(Token::RBracket)?;
+                self.leave();
+                ExprKind::List(items)
+            }
+            Token::LBrace => {
+                self.enter()?;
+                let mut entries = Vec::new();
+                while !self.check(&Token::RBrace) {
+                    let key = match self.advance() {
+                        Token::Ident(key) | Token::Str(key) => key,
+                        _ => return Err(syntax("映射的键必须是名称或字符串").at(position)),
+                    };
+                    self.expect(Token::Colon, ":")?;
+                    entries.push((key, self.expression()?));
+                    if !self.eat(&Token::Comma) {
+                        break;
+                    }
+                }
+                self.expect(Token::RBrace, "}")?;
+                self.leave();
+                ExprKind::Map(entries)
+            }
+            token => {
+                return Err(syntax(format!("意外的记号 {:?}", token)).at(position));
+            }
+        };
+        Ok(Expr { kind, position })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_precedence_and_errors() {
+        let program = parse("let x = 1 + 2 * 3 == 7 && !false;\nfn f(a, b) { return a[0].b; }").unwrap();
+        assert_eq!(program.functions.len(), 1);
+        let StmtKind::Let(_, expr) = &program.statements[0].kind else {
+            panic!("expected let");
+        };
+        let ExprKind::Binary(BinaryOp::And, left, _) = &expr.kind else {
+            panic!("expected &&");
+        };
+        assert!(matches!(left.kind, ExprKind::Binary(BinaryOp::Eq, _, _)));
+
+        let err = parse("let x = ;").unwrap_err();
+        assert_eq!(err.kind, ScriptErrorKind::Syntax);
+        assert_eq!(err.position.map(|p| (p.line, p.column)), Some((1, 9)));
+
+        assert!(parse("fn f() {} fn f() {}").is_err());
+        assert!(parse("1 + 2 = 3;").is_err());
+        let nested = format!("let x = {}1{};", "(".repeat(100), ")".repeat(100));
+        assert!(parse(&nested).unwrap_err().message.contains("嵌套"));
+        let chained = format!("let x = 1{};", " + 1".repeat(100));
+        assert!(parse(&chained).unwrap_err().message.contains("嵌套"));
+        assert!(parse(&format!("let x = 1{};", " + 1".repeat(30))).is_ok());
+    }
+}
@@ -0,0 +1,150 @@
+//! # 沙箱限制 (Sandbox Limits)
+//!
+//! 每次调用脚本（处理一个事件）都有独立的资源预算：
+//! - 指令数：每个表达式 / 语句计 1，内置函数按处理的元素数计
+//! - 内存：复制出的值按估算字节数累计（值语义下每次读取集合都是一次分配）
+//! - 时间：墙钟时间，每 1024 条指令检查一次
+//! - 调用深度：用户函数的嵌套调用层数
+//!
+//! 持久状态另有上限，每次调用结束后检查。
+//! 用户可以收紧限制，但不能超过宿主设定的上限（`SandboxLimits::CEILING`）。
+
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use super::error::ScriptError;
+
+/// 单个集合（列表 / 映射）的最大元素数
+pub const MAX_COLLECTION_LEN: usize = 100_000;
+
+/// 单个字符串的最大长度（字节）
+pub const MAX_STRING_LEN: usize = 64 * 1024;
+
+/// 值的最大嵌套层数
+pub const MAX_VALUE_DEPTH: usize = 32;
+
+/// 每次调用最多保留的日志条数
+pub const MAX_LOGS: usize = 16;
+
+/// 沙箱资源限制
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SandboxLimits {
+    /// 每次调用的最大指令数
+    pub max_operations: u64,
+    /// 每次调用的最大内存分配（字节，估算）
+    pub max_memory_bytes: usize,
+    /// 持久状态上限（字节，估算）
+    pub max_state_bytes: usize,
+    /// 每次调用的最长执行时间（毫秒）
+    pub max_time_ms: u64,
+    /// 最大函数调用深度
+    pub max_call_depth: usize,
+}
+
+impl SandboxLimits {
+    /// 宿主允许的上限
+    pub const CEILING: SandboxLimits = SandboxLimits {
+        max_operations: 2_000_000,
+        max_memory_bytes: 64 * 1024 * 1024,
+        max_state_bytes: 8 * 1024 * 1024,
+        max_time_ms: 200,
+        max_call_depth: 16,
+    };
+
+    /// 超过上限的字段（字段名, 当前值, 上限）
+    pub fn exceeded(&self) -> Vec<(&'static str, u64, u64)> {
+        let ceiling = Self::CEILING;
+        [
+            ("max_operations", self.max_operations, ceiling.max_operations),
+            ("max_memory_bytes", self.max_memory_bytes as u64, ceiling.max_memory_bytes as u64),
+            ("max_state_bytes", self.max_state_bytes as u64, ceiling.max_state_bytes as u64),
+            ("max_time_ms", self.max_time_ms, ceiling.max_time_ms),
+            ("max_call_depth", self.max_call_depth as u64, ceiling.max_call_depth as u64),
+        ]
+        .into_iter()
+        .filter(|(_, value, max)| value > max || *value == 0)
+        .collect()
+    }
+}
+
+impl Default for SandboxLimits {
+    fn default() -> Self {
+        Self {
+            max_operations: 200_000,
+            max_memory_bytes: 8 * 1024 * 1024,
+            max_state_bytes: 1024 * 1024,
+            max_time_ms: 20,
+            max_call_depth: 8,
+        }
+    }
+}
+
+/// 单次调用的资源计量
+#[derive(Debug)]
+pub struct Meter {
+    limits: SandboxLimits,
+    operations: u64,
+    memory: usize,
+    deadline: Instant,
+}
+
+impl Meter {
+    /// 开始计量
+    pub fn start(limits: &SandboxLimits) -> Self {
+        Self {
+            limits: limits.clone(),
+            operations: 0,
+            memory: 0,
+            deadline: Instant::now() + Duration::from_millis(limits.max_time_ms),
+        }
+    }
+
+    /// 计 n 条指令
+    pub fn charge(&mut self, operations: u64) -> Result<(), ScriptError> {
+        let before = self.operations;
+        self.operations = self.operations.saturating_add(operations);
+        if self.operations > self.limits.max_operations {
+            return Err(ScriptError::limit(format!(
+                "指令数超过 {}",
+                self.limits.max_operations
+            )));
+        }
+        // 每跨过 1024 条指令检查一次时间
+        if before >> 10 != self.operations >> 10 && Instant::now() > self.deadline {
+            return Err(ScriptError::limit(format!(
+                "执行时间超过 {}ms",
+                self.limits.max_time_ms
+            )));
+        }
+        Ok(())
+    }
+
+    /// 计 1 条指令
+    pub fn tick(&mut self) -> Result<(), ScriptError> {
+        self.charge(1)
+    }
+
+    /// 计内存分配
+    pub fn allocate(&mut self, bytes: usize) -> Result<(), ScriptError> {
+        self.memory = self.memory.saturating_add(bytes);
+        if self.memory > self.limits.max_memory_bytes {
+            return Err(ScriptError::limit(format!(
+                "内存分配超过 {} 字节",
+                self.limits.max_memory_bytes
+            )));
+        }
+        Ok(())
+    }
+
+    /// 调用深度上限
+    pub fn max_call_depth(&self) -> usize {
+        self.limits.max_call_depth
+    }
+
+    /// 已执行的指令数
+    pub fn operations(&self) -> u64 {
+        self.operations
+    }
+}
@@ -0,0 +1,368 @@
+//! # 现货定投策略 (Spot DCA Strategy)
+//!
+//! 按定时计划以固定金额（计价资产）市价买入，不择时、只累积。
+//!
+//! - 定投金额：`amount`，数量 = 金额 / 最新成交价
+//! - 逢跌加码：价格相对近期高点（最近 `dip_lookback` 次价格更新的最高价）的回撤
+//!   达到某一档 `drawdown` 时，本次金额乘以该档 `multiplier`（取满足条件的最大倍数）
+//! - 总额上限：`max_total_amount` > 0 时累计投入不超过该金额，最后一次按剩余额度买入
+//!
+//! ## 规则
+//! - ✅ 行情只用于更新最新价与近期高点，买入只在定时器到期时发生
+//! - ✅ 尚未收到任何成交价时跳过本次定投
+//! - ✅ 累计投入 / 数量 / 次数按成交回报记账；发出未成交的金额先占用额度，拒单或撤单时释放
+//! - ❌ 不卖出
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::{OrderRejection, StrategyFill};
+use shared::event::market_event::{MarketEvent, MarketEventData};
+use shared::types::order::OrderSide;
+use uuid::Uuid;
+
+use crate::domain::logic::indicator::RollingExtremum;
+use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
+use crate::domain::model::market_type::MarketType;
+use crate::domain::model::signal::{OrderInstruction, OrderType, Signal, SignalGroup, SignalType};
+use crate::domain::model::timer::{TimerEvent, TimerSchedule, TimerSpec};
+
+/// 定投定时器名称
+const DCA_TIMER: &str = "dca";
+
+/// 买入数量精度（小数位）
+const QUANTITY_SCALE: u32 = 8;
+
+/// 逢跌加码档位
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DipLevel {
+    /// 相对近期高点的回撤（0.1 表示 10%）
+    pub drawdown: Decimal,
+    /// 金额倍数
+    pub multiplier: Decimal,
+}
+
+/// 定投策略配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SpotDcaConfig {
+    /// 每次定投金额（计价资产，如 USDT）
+    pub amount: Decimal,
+    /// 定投计划
+    pub schedule: TimerSchedule,
+    /// 近期高点的回看周期（价格更新次数）
+    pub dip_lookback: usize,
+    /// 逢跌加码档位（为空不加码）
+    pub dip_levels: Vec<DipLevel>,
+    /// 累计投入上限（0 不限）
+    pub max_total_amount: Decimal,
+}
+
+impl Default for SpotDcaConfig {
+    fn default() -> Self {
+        Self {
+            amount: Decimal::from(100),
+            schedule: TimerSchedule::every(86_400), // 每天
+            dip_lookback: 1_000,
+            dip_levels: Vec::new(),
+            max_total_amount: Decimal::ZERO,
+        }
+    }
+}
+
+/// 定投策略状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpotDcaState {
+    /// 最新成交价
+    pub last_price: Option<Decimal>,
+    /// 近期高点
+    pub high: RollingExtremum<Decimal>,
+    /// 累计投入金额（按成交价）
+    pub invested: Decimal,
+    /// 累计买入数量
+    pub accumulated: Decimal,
+    /// 定投次数（全部成交的买单数）
+    pub buys: u32,
+    /// 已发出未成交的金额（占用总额度）
+    pub pending_amount: Decimal,
+    /// 已发出未成交的数量
+    pub pending_quantity: Decimal,
+}
+
+impl SpotDcaState {
+    pub fn new(config: &SpotDcaConfig) -> Self {
+        Self {
+            last_price: None,
+            high: RollingExtremum::max(config.dip_lookback),
+            invested: Decimal::ZERO,
+            accumulated: Decimal::ZERO,
+            buys: 0,
+            pending_amount: Decimal::ZERO,
+            pending_quantity: Decimal::ZERO,
+        }
+    }
+}
+
+/// 现货定投策略
+pub struct SpotDcaStrategy {
+    meta: StrategyMeta,
+    config: SpotDcaConfig,
+    state: SpotDcaState,
+}
+
+impl SpotDcaStrategy {
+    pub fn new(instance_id: Uuid, symbol: String, config: SpotDcaConfig) -> Self {
+        Self {
+            meta: StrategyMeta {
+                instance_id,
+                strategy_type: "spot_dca".to_string(),
+                market_type: MarketType::Spot,
+                symbol,
+                is_active: false,
+            },
+            state: SpotDcaState::new(&config),
+            config,
+        }
+    }
+
+    /// 当前价格对应的加码倍数（未达到任何档位时为 1）
+    fn dip_multiplier(&self, price: Decimal) -> Decimal {
+        let high = match self.state.high.value() {
+            Some(high) if high > Decimal::ZERO => high,
+            _ => return Decimal::ONE,
+        };
+        let drawdown = (high - price) / high;
+
+        self.config
+            .dip_levels
+            .iter()
+            .filter(|level| drawdown >= level.drawdown)
+            .map(|level| level.multiplier)
+            .fold(Decimal::ONE, Decimal::max)
+    }
+
+    /// 生成一次定投买入信号
+    fn buy_signal(&mut self, timer: &TimerEvent) -> Option<Signal> {
+        let price = self.state.last_price.filter(|p| *p > Decimal::ZERO)?;
+
+        let mut amount = self.config.amount * self.dip_multiplier(price);
+        if self.config.max_total_amount > Decimal::ZERO {
+            let committed = self.state.invested + self.state.pending_amount;
+            amount = amount.min(self.config.max_total_amount - committed);
+        }
+        if amount <= Decimal::ZERO {
+            return None;
+        }
+
+        let quantity =
+            (amount / price).round_dp_with_strategy(QUANTITY_SCALE, RoundingStrategy::ToZero);
+        if quantity.is_zero() {
+            return None;
+        }
+
+        self.state.pending_amount += amount;
+        self.state.pending_quantity += quantity;
+
+        Some(Signal {
+            id: Uuid::new_v4(),
+            strategy_id: self.meta.instance_id,
+            symbol: self.meta.symbol.clone(),
+            signal_type: SignalType::Buy,
+            price,
+            quantity,
+            confidence: 1.0,
+            created_at: timer.fired_at,
+            instruction: OrderInstruction::open().with_order_type(OrderType::Market),
+        })
+    }
+
+    /// 释放 `quantity` 对应的占用额度（按未成交数量的比例）
+    fn release_pending(&mut self, quantity: Decimal) {
+        let state = &mut self.state;
+        if state.pending_quantity <= Decimal::ZERO {
+            return;
+        }
+        let released = quantity.min(state.pending_quantity);
+        state.pending_amount -= state.pending_amount * released / state.pending_quantity;
+        state.pending_quantity -= released;
+        if state.pending_quantity.is_zero() {
+            state.pending_amount = Decimal::ZERO;
+        }
+    }
+}
+
+impl Strategy for SpotDcaStrategy {
+    fn meta(&self) -> &StrategyMeta {
+        &self.meta
+    }
+
+    fn meta_mut(&mut self) -> &mut StrategyMeta {
+        &mut self.meta
+    }
+
+    fn on_market_event(&mut self, event: &MarketEvent) -> Option<Signal> {
+        if let MarketEventData::Trade(trade) = &event.data {
+            self.state.last_price = Some(trade.price);
+            self.state.high.push(trade.price);
+        }
+        None
+    }
+
+    fn timers(&self) -> Vec<TimerSpec> {
+        vec![TimerSpec::new(DCA_TIMER, self.config.schedule.clone())]
+    }
+
+    fn on_timer(&mut self, timer: &TimerEvent) -> Option<SignalGroup> {
+        if !self.is_active() || timer.name != DCA_TIMER {
+            return None;
+        }
+        self.buy_signal(timer).map(SignalGroup::single)
+    }
+
+    fn on_fill(&mut self, symbol: &str, fill: &StrategyFill) {
+        if symbol != self.meta.symbol || fill.side != OrderSide::Buy {
+            return;
+        }
+        self.release_pending(fill.quantity);
+        self.state.invested += fill.quantity * fill.price;
+        self.state.accumulated += fill.quantity;
+        if fill.is_final {
+            self.state.buys += 1;
+        }
+    }
+
+    fn on_order_rejected(&mut self, symbol: &str, rejection: &OrderRejection) {
+        if symbol != self.meta.symbol || rejection.side != OrderSide::Buy {
+            return;
+        }
+        self.release_pending(rejection.quantity);
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let mut state: SpotDcaState = serde_json::from_value(state)?;
+        // 回看周期变化后近期高点无法沿用，重新累积
+        if state.high.period() != self.config.dip_lookback.max(1) {
+            state.high = RollingExtremum::max(self.config.dip_lookback);
+        }
+        self.state = state;
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.state = SpotDcaState::new(&self.config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use shared::event::execution_feedback_event::RejectionSource;
+    use shared::event::market_event::{MarketEventType, TradeData};
+
+    fn strategy(max_total_amount: i64) -> SpotDcaStrategy {
+        let config = SpotDcaConfig {
+            max_total_amount: Decimal::from(max_total_amount),
+            ..SpotDcaConfig::default()
+        };
+        let mut strategy = SpotDcaStrategy::new(Uuid::new_v4(), "BTCUSDT".to_string(), config);
+        strategy.activate();
+        strategy.on_market_event(&MarketEvent {
+            event_type: MarketEventType::Trade,
+            exchange: "binance".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            timestamp: Utc::now(),
+            data: MarketEventData::Trade(TradeData {
+                trade_id: "1".to_string(),
+                price: Decimal::from(10),
+                quantity: Decimal::ONE,
+                is_buyer_maker: false,
+            }),
+        });
+        strategy
+    }
+
+    /// 定投一次，返回买入数量
+    fn buy(strategy: &mut SpotDcaStrategy) -> Option<Decimal> {
+        let now = Utc::now();
+        let timer = TimerEvent { name: DCA_TIMER.to_string(), scheduled_at: now, fired_at: now };
+        strategy
+            .on_timer(&timer)
+            .and_then(|group| group.legs.first().map(|signal| signal.quantity))
+    }
+
+    fn fill(quantity: i64, is_final: bool) -> StrategyFill {
+        StrategyFill {
+            order_id: "1".to_string(),
+            trade_id: "t1".to_string(),
+            side: OrderSide::Buy,
+            quantity: Decimal::from(quantity),
+            price: Decimal::from(10),
+            cumulative_quantity: Decimal::from(quantity),
+            original_quantity: Decimal::from(10),
+            is_final,
+            commission: Decimal::ZERO,
+            commission_asset: "BNB".to_string(),
+            fill_time: Utc::now(),
+        }
+    }
+
+    fn rejection(quantity: i64) -> OrderRejection {
+        OrderRejection {
+            order_id: None,
+            side: OrderSide::Buy,
+            quantity: Decimal::from(quantity),
+            price: None,
+            source: RejectionSource::Risk,
+            code: "MAX_POSITION".to_string(),
+            reason: "position limit".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_budget_cap_counts_pending_buys() {
+        // 每次 100，上限 250，价格 10
+        let mut strategy = strategy(250);
+        assert_eq!(buy(&mut strategy), Some(Decimal::from(10)));
+        assert_eq!(buy(&mut strategy), Some(Decimal::from(10)));
+
+        // 未成交的买单同样占用额度：最后一次按剩余 50 买入，之后不再买
+        assert_eq!(buy(&mut strategy), Some(Decimal::from(5)));
+        assert_eq!(buy(&mut strategy), None);
+        assert_eq!(strategy.state.invested, Decimal::ZERO);
+        assert_eq!(strategy.state.buys, 0);
+
+        // 拒单释放额度
+        strategy.on_order_rejected("BTCUSDT", &rejection(5));
+        assert_eq!(strategy.state.pending_amount, Decimal::from(200));
+        assert_eq!(buy(&mut strategy), Some(Decimal::from(5)));
+    }
+
+    #[test]
+    fn test_accounting_follows_fills() {
+        let mut strategy = strategy(0);
+        assert_eq!(buy(&mut strategy), Some(Decimal::from(10)));
+
+        strategy.on_fill("BTCUSDT", &fill(4, false));
+        assert_eq!(strategy.state.invested, Decimal::from(40));
+        assert_eq!(strategy.state.accumulated, Decimal::from(4));
+        assert_eq!(strategy.state.pending_amount, Decimal::from(60));
+        assert_eq!(strategy.state.buys, 0);
+
+        // 剩余部分被撤销：不计入投入，额度全部释放
+        strategy.on_order_rejected("BTCUSDT", &rejection(6));
+        assert_eq!(strategy.state.invested, Decimal::from(40));
+        assert_eq!(strategy.state.pending_amount, Decimal::ZERO);
+        assert_eq!(strategy.state.pending_quantity, Decimal::ZERO);
+
+        assert_eq!(buy(&mut strategy), Some(Decimal::from(10)));
+        strategy.on_fill("BTCUSDT", &fill(10, true));
+        assert_eq!(strategy.state.invested, Decimal::from(140));
+        assert_eq!(strategy.state.accumulated, Decimal::from(14));
+        assert_eq!(strategy.state.buys, 1);
+    }
+}
@@ -4,15 +4,17 @@
 
 use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use shared::event::market_event::{MarketEvent, MarketEventData};
 use uuid::Uuid;
 
 use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
 use crate::domain::model::market_type::MarketType;
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalType};
 
 /// 现货网格策略配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct SpotGridConfig {
     /// 价格上界
     pub upper_price: Decimal,
@@ -25,7 +27,7 @@ pub struct SpotGridConfig {
 }
 
 /// 现货网格策略状态
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpotGridState {
     /// 上一次价格所在网格索引
     pub last_grid_index: Option<i32>,
@@ -130,6 +132,7 @@ impl SpotGridStrategy {
             quantity: self.config.quantity_per_grid,
             confidence: 1.0,
             created_at: event.timestamp,
+            instruction: OrderInstruction::default(),
         })
     }
 }
@@ -150,6 +153,15 @@ impl Strategy for SpotGridStrategy {
         self.calculate_signal(event)
     }
 
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        self.state = serde_json::from_value(state)?;
+        Ok(())
+    }
+
     fn reset(&mut self) {
         self.state = SpotGridState::new();
     }
@@ -2,17 +2,19 @@
 //!
 //! 基于 MACD 指标的趋势跟踪策略实现。(MACD Trend Following Strategy)
 
-use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use shared::event::market_event::{MarketEvent, MarketEventData};
 use uuid::Uuid;
 
+use crate::domain::logic::indicator::{Indicator, Macd};
 use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
 use crate::domain::model::market_type::MarketType;
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalType};
 
 /// 现货 MACD 配置 (Spot MACD Configuration)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct SpotMacdConfig {
     /// 快线周期 (Fast Period)
     pub fast_period: usize,
@@ -24,43 +26,36 @@ pub struct SpotMacdConfig {
     pub quantity: Decimal,
 }
 
+impl Default for SpotMacdConfig {
+    fn default() -> Self {
+        Self {
+            fast_period: 12,
+            slow_period: 26,
+            signal_period: 9,
+            quantity: Decimal::new(1, 3), // 0.001
+        }
+    }
+}
+
 /// 现货 MACD 状态 (Spot MACD State)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpotMacdState {
-    /// 价格历史 (Price History)
-    pub price_history: Vec<Decimal>,
-    /// MACD 历史 (MACD History)
-    pub macd_history: Vec<Decimal>,
-    /// 快线 EMA (Fast EMA)
-    pub fast_ema: Option<Decimal>,
-    /// 慢线 EMA (Slow EMA)
-    pub slow_ema: Option<Decimal>,
-    /// 信号线 (Signal Line)
-    pub signal_line: Option<Decimal>,
-    /// 上一次柱状图值 (Last Histogram)
+    /// MACD 指标
+    pub macd: Macd<Decimal>,
+    /// 上一次柱状图
     pub last_histogram: Option<Decimal>,
 }
 
 impl SpotMacdState {
     /// 创建初始状态 (Create Initial State)
-    pub fn new() -> Self {
+    pub fn new(config: &SpotMacdConfig) -> Self {
         Self {
-            price_history: Vec::new(),
-            macd_history: Vec::new(),
-            fast_ema: None,
-            slow_ema: None,
-            signal_line: None,
+            macd: Macd::new(config.fast_period, config.slow_period, config.signal_period),
             last_histogram: None,
         }
     }
 }
 
-impl Default for SpotMacdState {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 /// 现货 MACD 策略 (Spot MACD Strategy)
 pub struct SpotMacdStrategy {
     meta: StrategyMeta,
@@ -79,8 +74,8 @@ impl SpotMacdStrategy {
                 symbol,
                 is_active: false,
             },
+            state: SpotMacdState::new(&config),
             config,
-            state: SpotMacdState::new(),
         }
     }
 
@@ -102,57 +97,9 @@ impl SpotMacdStrategy {
         }
 
         let price = trade.price;
-        let max_period = self.config.fast_period.max(self.config.slow_period);
-        self.state.price_history.push(price);
-        if self.state.price_history.len() > max_period {
-            let overflow = self.state.price_history.len() - max_period;
-            self.state.price_history.drain(0..overflow);
-        }
-
-        let fast_ema = match self.state.fast_ema {
-            Some(prev) => update_ema(price, prev, self.config.fast_period)?,
-            None => {
-                if self.state.price_history.len() < self.config.fast_period {
-                    return None;
-                }
-                let start = self.state.price_history.len() - self.config.fast_period;
-                average(&self.state.price_history[start..])?
-            }
-        };
 
-        let slow_ema = match self.state.slow_ema {
-            Some(prev) => update_ema(price, prev, self.config.slow_period)?,
-            None => {
-                if self.state.price_history.len() < self.config.slow_period {
-                    return None;
-                }
-                let start = self.state.price_history.len() - self.config.slow_period;
-                average(&self.state.price_history[start..])?
-            }
-        };
-
-        self.state.fast_ema = Some(fast_ema);
-        self.state.slow_ema = Some(slow_ema);
-
-        let macd_line = fast_ema - slow_ema;
-        self.state.macd_history.push(macd_line);
-        if self.state.macd_history.len() > self.config.signal_period {
-            let overflow = self.state.macd_history.len() - self.config.signal_period;
-            self.state.macd_history.drain(0..overflow);
-        }
-
-        let signal_line = match self.state.signal_line {
-            Some(prev) => update_ema(macd_line, prev, self.config.signal_period)?,
-            None => {
-                if self.state.macd_history.len() < self.config.signal_period {
-                    return None;
-                }
-                average(&self.state.macd_history)?
-            }
-        };
-        self.state.signal_line = Some(signal_line);
-
-        let histogram = macd_line - signal_line;
+        // 更新 MACD（预热期无信号）
+        let histogram = self.state.macd.update(price)?.histogram;
         let last_histogram = self.state.last_histogram;
         self.state.last_histogram = Some(histogram);
 
@@ -171,6 +118,7 @@ impl SpotMacdStrategy {
             quantity: self.config.quantity,
             confidence: 1.0,
             created_at: event.timestamp,
+            instruction: OrderInstruction::default(),
         })
     }
 }
@@ -191,28 +139,27 @@ impl Strategy for SpotMacdStrategy {
         self.calculate_signal(event)
     }
 
-    fn reset(&mut self) {
-        self.state = SpotMacdState::new();
+    fn state_version(&self) -> u32 {
+        2
     }
-}
 
-fn average(values: &[Decimal]) -> Option<Decimal> {
-    if values.is_empty() {
-        return None;
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
     }
-    let sum = values.iter().fold(Decimal::ZERO, |acc, value| acc + *value);
-    let count = Decimal::from_usize(values.len())?;
-    if count == Decimal::ZERO {
-        return None;
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let mut state: SpotMacdState = serde_json::from_value(state)?;
+        // 参数热更新后周期变化的指标窗口无法沿用，重新预热
+        let fresh = SpotMacdState::new(&self.config);
+        if state.macd.periods() != fresh.macd.periods() {
+            state.macd = fresh.macd;
+            state.last_histogram = None;
+        }
+        self.state = state;
+        Ok(())
     }
-    Some(sum / count)
-}
 
-fn update_ema(current: Decimal, previous: Decimal, period: usize) -> Option<Decimal> {
-    if period == 0 {
-        return None;
+    fn reset(&mut self) {
+        self.state = SpotMacdState::new(&self.config);
     }
-    let period_decimal = Decimal::from_usize(period)?;
-    let multiplier = Decimal::from_u32(2)? / (period_decimal + Decimal::ONE);
-    Some((current - previous) * multiplier + previous)
 }
@@ -2,48 +2,56 @@
 //!
 //! 现货市场的均值回归策略实现。
 
-use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use shared::event::market_event::{MarketEvent, MarketEventData};
 use uuid::Uuid;
 
+use crate::domain::logic::indicator::{Indicator, Sma};
 use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
 use crate::domain::model::market_type::MarketType;
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalType};
 
 /// 现货均值回归策略配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct SpotMeanReversionConfig {
     /// 移动平均窗口大小
+    #[serde(alias = "period")]
     pub window_size: usize,
     /// 偏离阈值百分比（如 0.02 表示 2%）
+    #[serde(alias = "std_dev_multiplier")]
     pub threshold_percent: Decimal,
     /// 交易数量
     pub quantity: Decimal,
 }
 
+impl Default for SpotMeanReversionConfig {
+    fn default() -> Self {
+        Self {
+            window_size: 20,
+            threshold_percent: Decimal::new(2, 2), // 2%
+            quantity: Decimal::new(1, 3),          // 0.001
+        }
+    }
+}
+
 /// 现货均值回归策略状态
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpotMeanReversionState {
-    /// 历史价格队列
-    pub price_history: Vec<Decimal>,
+    /// 移动平均指标
+    pub sma: Sma<Decimal>,
 }
 
 impl SpotMeanReversionState {
     /// 创建初始状态
-    pub fn new() -> Self {
+    pub fn new(config: &SpotMeanReversionConfig) -> Self {
         Self {
-            price_history: Vec::new(),
+            sma: Sma::new(config.window_size),
         }
     }
 }
 
-impl Default for SpotMeanReversionState {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 /// 现货均值回归策略
 pub struct SpotMeanReversionStrategy {
     meta: StrategyMeta,
@@ -62,8 +70,8 @@ impl SpotMeanReversionStrategy {
                 symbol,
                 is_active: false,
             },
+            state: SpotMeanReversionState::new(&config),
             config,
-            state: SpotMeanReversionState::new(),
         }
     }
 
@@ -82,30 +90,8 @@ impl SpotMeanReversionStrategy {
             return None;
         }
 
-        // 更新价格历史
-        self.state.price_history.push(trade.price);
-        if self.state.price_history.len() > self.config.window_size {
-            let overflow = self.state.price_history.len() - self.config.window_size;
-            self.state.price_history.drain(0..overflow);
-        }
-
-        // 窗口未满，不产生信号
-        if self.state.price_history.len() < self.config.window_size {
-            return None;
-        }
-
-        // 计算移动平均
-        let sum = self
-            .state
-            .price_history
-            .iter()
-            .fold(Decimal::ZERO, |acc, price| acc + *price);
-        let window_size = Decimal::from_usize(self.config.window_size)?;
-        if window_size == Decimal::ZERO {
-            return None;
-        }
-
-        let moving_average = sum / window_size;
+        // 更新移动平均（窗口未满不产生信号）
+        let moving_average = self.state.sma.update(trade.price)?;
         if moving_average == Decimal::ZERO {
             return None;
         }
@@ -132,6 +118,7 @@ impl SpotMeanReversionStrategy {
             quantity: self.config.quantity,
             confidence: 1.0,
             created_at: event.timestamp,
+            instruction: OrderInstruction::default(),
         })
     }
 }
@@ -152,7 +139,25 @@ impl Strategy for SpotMeanReversionStrategy {
         self.calculate_signal(event)
     }
 
+    fn state_version(&self) -> u32 {
+        2
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let mut state: SpotMeanReversionState = serde_json::from_value(state)?;
+        // 指标按当前配置重建，只回放检查点中的价格（周期变化后截取最新部分）
+        let mut fresh = SpotMeanReversionState::new(&self.config);
+        fresh.sma.replay(&state.sma);
+        state.sma = fresh.sma;
+        self.state = state;
+        Ok(())
+    }
+
     fn reset(&mut self) {
-        self.state = SpotMeanReversionState::new();
+        self.state = SpotMeanReversionState::new(&self.config);
     }
 }
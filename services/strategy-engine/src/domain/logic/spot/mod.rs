@@ -7,9 +7,13 @@ pub mod mean;
 pub mod macd;
 pub mod bollinger;
 pub mod rsi;
+pub mod dca;
+pub mod rebalance;
 
 pub use grid::SpotGridStrategy;
 pub use mean::SpotMeanReversionStrategy;
 pub use macd::SpotMacdStrategy;
 pub use bollinger::SpotBollingerStrategy;
 pub use rsi::SpotRsiStrategy;
+pub use dca::SpotDcaStrategy;
+pub use rebalance::SpotRebalanceStrategy;
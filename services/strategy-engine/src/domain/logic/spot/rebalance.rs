@@ -0,0 +1,392 @@
+//! # 现货组合再平衡策略 (Spot Portfolio Rebalancing Strategy)
+//!
+//! 按目标权重持有一篮子现货资产，未分配的权重留作计价资产现金。
+//!
+//! - 组合价值 = 现金 + Σ 持仓数量 × 最新价
+//! - 阈值模式：任一资产的实际权重偏离目标超过 `drift_threshold` 时再平衡
+//! - 定期模式：按 `schedule` 定时再平衡（与偏离程度无关）
+//! - 再平衡把每个资产调回目标权重，金额小于 `min_trade_value` 的调整忽略
+//!
+//! 同时订阅全部目标交易对，调整以信号组输出（先卖后买，卖出释放的现金用于买入），
+//! 由 trading-engine 作为关联组整体执行。
+//!
+//! ## 规则
+//! - ✅ 所有目标交易对都有成交价后才开始再平衡
+//! - ✅ 信号发出即按信号价更新持仓与现金，持仓快照到达后以快照为准
+//! - ❌ 不做空，目标权重之和不超过 1
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::PositionSnapshot;
+use shared::event::market_event::{MarketEvent, MarketEventData};
+use uuid::Uuid;
+
+use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
+use crate::domain::model::market_type::MarketType;
+use crate::domain::model::signal::{OrderInstruction, OrderType, Signal, SignalGroup, SignalType};
+use crate::domain::model::timer::{TimerEvent, TimerSchedule, TimerSpec};
+
+/// 定期再平衡定时器名称
+const REBALANCE_TIMER: &str = "rebalance";
+
+/// 调整数量精度（小数位）
+const QUANTITY_SCALE: u32 = 8;
+
+/// 再平衡触发方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RebalanceMode {
+    /// 权重偏离超过阈值
+    Threshold,
+    /// 定期
+    Calendar,
+}
+
+/// 组合再平衡策略配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SpotRebalanceConfig {
+    /// 目标权重（交易对 -> 权重，之和不超过 1，剩余为现金）
+    pub targets: BTreeMap<String, Decimal>,
+    /// 初始投入资金（计价资产）
+    pub capital: Decimal,
+    /// 触发方式
+    pub mode: RebalanceMode,
+    /// 权重偏离阈值（阈值模式，绝对值，0.05 表示 5 个百分点）
+    pub drift_threshold: Decimal,
+    /// 再平衡计划（定期模式）
+    pub schedule: TimerSchedule,
+    /// 最小调整金额（计价资产）
+    pub min_trade_value: Decimal,
+}
+
+impl Default for SpotRebalanceConfig {
+    fn default() -> Self {
+        Self {
+            targets: BTreeMap::from([
+                ("BTCUSDT".to_string(), Decimal::new(5, 1)),
+                ("ETHUSDT".to_string(), Decimal::new(3, 1)),
+            ]),
+            capital: Decimal::from(10_000),
+            mode: RebalanceMode::Threshold,
+            drift_threshold: Decimal::new(5, 2),        // 0.05
+            schedule: TimerSchedule::cron("0 0 * * 1"), // 每周一 00:00
+            min_trade_value: Decimal::from(10),
+        }
+    }
+}
+
+/// 组合再平衡策略状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpotRebalanceState {
+    /// 最新成交价
+    pub prices: BTreeMap<String, Decimal>,
+    /// 持仓数量
+    pub holdings: BTreeMap<String, Decimal>,
+    /// 现金（计价资产）
+    pub cash: Decimal,
+    /// 再平衡次数
+    pub rebalances: u32,
+}
+
+impl SpotRebalanceState {
+    pub fn new(config: &SpotRebalanceConfig) -> Self {
+        Self {
+            prices: BTreeMap::new(),
+            holdings: BTreeMap::new(),
+            cash: config.capital,
+            rebalances: 0,
+        }
+    }
+}
+
+/// 现货组合再平衡策略
+pub struct SpotRebalanceStrategy {
+    meta: StrategyMeta,
+    config: SpotRebalanceConfig,
+    state: SpotRebalanceState,
+}
+
+impl SpotRebalanceStrategy {
+    pub fn new(instance_id: Uuid, symbol: String, config: SpotRebalanceConfig) -> Self {
+        Self {
+            meta: StrategyMeta {
+                instance_id,
+                strategy_type: "spot_rebalance".to_string(),
+                market_type: MarketType::Spot,
+                symbol,
+                is_active: false,
+            },
+            state: SpotRebalanceState::new(&config),
+            config,
+        }
+    }
+
+    /// 组合总价值（有目标交易对缺少价格时为 None）
+    fn portfolio_value(&self) -> Option<Decimal> {
+        let mut total = self.state.cash;
+        for symbol in self.config.targets.keys() {
+            let price = *self.state.prices.get(symbol)?;
+            total += self.holding(symbol) * price;
+        }
+        Some(total)
+    }
+
+    fn holding(&self, symbol: &str) -> Decimal {
+        self.state.holdings.get(symbol).copied().unwrap_or_default()
+    }
+
+    /// 实际权重与目标权重的最大偏离
+    fn max_drift(&self, total: Decimal) -> Decimal {
+        self.config
+            .targets
+            .iter()
+            .map(|(symbol, target)| {
+                let value = self.holding(symbol) * self.state.prices[symbol];
+                (value / total - target).abs()
+            })
+            .fold(Decimal::ZERO, Decimal::max)
+    }
+
+    /// 生成再平衡信号组（先卖后买）
+    fn rebalance(&mut self, timestamp: DateTime<Utc>, force: bool) -> Option<SignalGroup> {
+        let total = self.portfolio_value().filter(|v| *v > Decimal::ZERO)?;
+        if !force && self.max_drift(total) <= self.config.drift_threshold {
+            return None;
+        }
+
+        let mut sells = Vec::new();
+        let mut buys = Vec::new();
+        for (symbol, target) in &self.config.targets {
+            let price = self.state.prices[symbol];
+            let diff = total * target - self.holding(symbol) * price;
+            if diff.abs() < self.config.min_trade_value || price <= Decimal::ZERO {
+                continue;
+            }
+
+            let quantity = (diff.abs() / price)
+                .round_dp_with_strategy(QUANTITY_SCALE, RoundingStrategy::ToZero);
+            if quantity.is_zero() {
+                continue;
+            }
+            let signal_type = if diff > Decimal::ZERO {
+                SignalType::Buy
+            } else {
+                SignalType::Sell
+            };
+            let signal = Signal {
+                id: Uuid::new_v4(),
+                strategy_id: self.meta.instance_id,
+                symbol: symbol.clone(),
+                signal_type,
+                price,
+                quantity,
+                confidence: 1.0,
+                created_at: timestamp,
+                instruction: OrderInstruction::open().with_order_type(OrderType::Market),
+            };
+            match signal_type {
+                SignalType::Sell => sells.push(signal),
+                _ => buys.push(signal),
+            }
+        }
+        if sells.is_empty() && buys.is_empty() {
+            return None;
+        }
+
+        for signal in sells.iter().chain(buys.iter()) {
+            let value = signal.quantity * signal.price;
+            let holding = self
+                .state
+                .holdings
+                .entry(signal.symbol.clone())
+                .or_default();
+            if signal.signal_type == SignalType::Sell {
+                *holding -= signal.quantity;
+                self.state.cash += value;
+            } else {
+                *holding += signal.quantity;
+                self.state.cash -= value;
+            }
+        }
+        self.state.rebalances += 1;
+
+        sells.extend(buys);
+        Some(SignalGroup::new(self.meta.instance_id, sells, timestamp))
+    }
+}
+
+impl Strategy for SpotRebalanceStrategy {
+    fn meta(&self) -> &StrategyMeta {
+        &self.meta
+    }
+
+    fn meta_mut(&mut self) -> &mut StrategyMeta {
+        &mut self.meta
+    }
+
+    /// 再平衡总是以信号组输出，单信号接口不使用
+    fn on_market_event(&mut self, _event: &MarketEvent) -> Option<Signal> {
+        None
+    }
+
+    fn on_market_event_legs(&mut self, event: &MarketEvent) -> Option<SignalGroup> {
+        let MarketEventData::Trade(trade) = &event.data else {
+            return None;
+        };
+        if !self.config.targets.contains_key(&event.symbol) {
+            return None;
+        }
+        self.state.prices.insert(event.symbol.clone(), trade.price);
+
+        if !self.is_active() || self.config.mode != RebalanceMode::Threshold {
+            return None;
+        }
+        self.rebalance(event.timestamp, false)
+    }
+
+    fn subscriptions(&self) -> Vec<String> {
+        self.config.targets.keys().cloned().collect()
+    }
+
+    fn timers(&self) -> Vec<TimerSpec> {
+        match self.config.mode {
+            RebalanceMode::Calendar => {
+                vec![TimerSpec::new(
+                    REBALANCE_TIMER,
+                    self.config.schedule.clone(),
+                )]
+            }
+            RebalanceMode::Threshold => Vec::new(),
+        }
+    }
+
+    fn on_timer(&mut self, timer: &TimerEvent) -> Option<SignalGroup> {
+        if !self.is_active() || timer.name != REBALANCE_TIMER {
+            return None;
+        }
+        self.rebalance(timer.fired_at, true)
+    }
+
+    fn on_position_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {
+        if self.config.targets.contains_key(symbol) {
+            self.state
+                .holdings
+                .insert(symbol.to_string(), snapshot.quantity.max(Decimal::ZERO));
+        }
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        self.state = serde_json::from_value(state)?;
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.state = SpotRebalanceState::new(&self.config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::event::market_event::{MarketEventType, TradeData};
+
+    fn trade(symbol: &str, price: i64) -> MarketEvent {
+        MarketEvent {
+            event_type: MarketEventType::Trade,
+            exchange: "binance".to_string(),
+            symbol: symbol.to_string(),
+            timestamp: Utc::now(),
+            data: MarketEventData::Trade(TradeData {
+                trade_id: "1".to_string(),
+                price: Decimal::from(price),
+                quantity: Decimal::ONE,
+                is_buyer_maker: false,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_threshold_rebalance_sells_before_buys() {
+        let config = SpotRebalanceConfig {
+            targets: BTreeMap::from([
+                ("BTCUSDT".to_string(), Decimal::new(5, 1)),
+                ("ETHUSDT".to_string(), Decimal::new(5, 1)),
+            ]),
+            capital: Decimal::from(1_000),
+            ..SpotRebalanceConfig::default()
+        };
+        let mut strategy =
+            SpotRebalanceStrategy::new(Uuid::new_v4(), "BTCUSDT".to_string(), config);
+        strategy.activate();
+
+        // 所有目标都有价格后才建仓：各买 500
+        assert!(strategy
+            .on_market_event_legs(&trade("BTCUSDT", 100))
+            .is_none());
+        let group = strategy
+            .on_market_event_legs(&trade("ETHUSDT", 10))
+            .unwrap();
+        assert_eq!(group.legs.len(), 2);
+        assert!(group.legs.iter().all(|s| s.signal_type == SignalType::Buy));
+        assert_eq!(strategy.holding("BTCUSDT"), Decimal::from(5));
+        assert_eq!(strategy.holding("ETHUSDT"), Decimal::from(50));
+        assert_eq!(strategy.state.cash, Decimal::ZERO);
+
+        // 小幅偏离不触发：BTC 102 → 权重约 50.5%
+        assert!(strategy
+            .on_market_event_legs(&trade("BTCUSDT", 102))
+            .is_none());
+
+        // BTC 涨到 200：1000 / 1500 ≈ 67%，卖 BTC 250、买 ETH 250
+        let group = strategy
+            .on_market_event_legs(&trade("BTCUSDT", 200))
+            .unwrap();
+        assert_eq!(group.legs[0].signal_type, SignalType::Sell);
+        assert_eq!(group.legs[0].symbol, "BTCUSDT");
+        assert_eq!(group.legs[0].quantity, Decimal::new(125, 2));
+        assert_eq!(group.legs[1].signal_type, SignalType::Buy);
+        assert_eq!(group.legs[1].quantity, Decimal::from(25));
+        assert_eq!(strategy.state.rebalances, 2);
+    }
+
+    #[test]
+    fn test_drift_threshold_is_exclusive() {
+        let config = SpotRebalanceConfig {
+            targets: BTreeMap::from([
+                ("BTCUSDT".to_string(), Decimal::new(5, 1)),
+                ("ETHUSDT".to_string(), Decimal::new(5, 1)),
+            ]),
+            capital: Decimal::from(1_000),
+            drift_threshold: Decimal::new(1, 1), // 0.1
+            ..SpotRebalanceConfig::default()
+        };
+        let mut strategy =
+            SpotRebalanceStrategy::new(Uuid::new_v4(), "BTCUSDT".to_string(), config);
+        strategy.activate();
+
+        // 建仓：5 BTC @ 100，50 ETH @ 10
+        strategy.on_market_event_legs(&trade("BTCUSDT", 100));
+        assert!(strategy.on_market_event_legs(&trade("ETHUSDT", 10)).is_some());
+
+        // BTC 150：750 / 1250 = 60%，偏离恰好等于阈值，不触发
+        assert!(strategy
+            .on_market_event_legs(&trade("BTCUSDT", 150))
+            .is_none());
+        assert_eq!(strategy.state.rebalances, 1);
+
+        // BTC 151：755 / 1255 ≈ 60.2%，超过阈值
+        let group = strategy
+            .on_market_event_legs(&trade("BTCUSDT", 151))
+            .unwrap();
+        assert_eq!(group.legs[0].signal_type, SignalType::Sell);
+        assert_eq!(strategy.state.rebalances, 2);
+    }
+}
@@ -2,18 +2,19 @@
 //!
 //! 基于RSI指标的超买超卖策略。
 
-use std::collections::VecDeque;
-
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use shared::event::market_event::{MarketEvent, MarketEventData};
 use uuid::Uuid;
 
+use crate::domain::logic::indicator::{Indicator, Rsi};
 use crate::domain::logic::strategy_trait::{Strategy, StrategyMeta};
 use crate::domain::model::market_type::MarketType;
-use crate::domain::model::signal::{Signal, SignalType};
+use crate::domain::model::signal::{OrderInstruction, Signal, SignalType};
 
 /// RSI策略配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct SpotRsiConfig {
     /// RSI周期
     pub period: usize,
@@ -37,35 +38,23 @@ impl Default for SpotRsiConfig {
 }
 
 /// RSI策略状态
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpotRsiState {
-    /// 价格历史
-    pub price_history: VecDeque<Decimal>,
-    /// 平均涨幅
-    pub avg_gain: Option<Decimal>,
-    /// 平均跌幅
-    pub avg_loss: Option<Decimal>,
+    /// RSI 指标
+    pub rsi: Rsi<Decimal>,
     /// 上次信号
     pub last_signal: Option<SignalType>,
 }
 
 impl SpotRsiState {
-    pub fn new() -> Self {
+    pub fn new(config: &SpotRsiConfig) -> Self {
         Self {
-            price_history: VecDeque::new(),
-            avg_gain: None,
-            avg_loss: None,
+            rsi: Rsi::new(config.period),
             last_signal: None,
         }
     }
 }
 
-impl Default for SpotRsiState {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 /// 现货RSI策略
 pub struct SpotRsiStrategy {
     meta: StrategyMeta,
@@ -83,8 +72,8 @@ impl SpotRsiStrategy {
                 symbol,
                 is_active: false,
             },
+            state: SpotRsiState::new(&config),
             config,
-            state: SpotRsiState::new(),
         }
     }
 
@@ -97,45 +86,8 @@ impl SpotRsiStrategy {
 
         let price = trade.price;
 
-        // 更新价格历史
-        self.state.price_history.push_back(price);
-        if self.state.price_history.len() > self.config.period + 1 {
-            self.state.price_history.pop_front();
-        }
-
-        // 需要足够的数据
-        if self.state.price_history.len() < 2 {
-            return None;
-        }
-
-        // 计算价格变化
-        let prev_price = self.state.price_history[self.state.price_history.len() - 2];
-        let change = price - prev_price;
-
-        let gain = if change > Decimal::ZERO { change } else { Decimal::ZERO };
-        let loss = if change < Decimal::ZERO { -change } else { Decimal::ZERO };
-
-        // 更新平均涨跌幅
-        let period_decimal = Decimal::from(self.config.period);
-        self.state.avg_gain = Some(match self.state.avg_gain {
-            Some(avg) => (avg * (period_decimal - Decimal::ONE) + gain) / period_decimal,
-            None => gain,
-        });
-
-        self.state.avg_loss = Some(match self.state.avg_loss {
-            Some(avg) => (avg * (period_decimal - Decimal::ONE) + loss) / period_decimal,
-            None => loss,
-        });
-
-        // 计算RSI
-        let rsi = match (self.state.avg_gain, self.state.avg_loss) {
-            (Some(avg_gain), Some(avg_loss)) if avg_loss != Decimal::ZERO => {
-                let rs = avg_gain / avg_loss;
-                Decimal::from(100) - (Decimal::from(100) / (Decimal::ONE + rs))
-            }
-            (Some(_), Some(avg_loss)) if avg_loss == Decimal::ZERO => Decimal::from(100),
-            _ => return None,
-        };
+        // 更新RSI（预热期无信号）
+        let rsi = self.state.rsi.update(price)?;
 
         // 判断信号
         let signal_type = if rsi < self.config.oversold_threshold
@@ -162,6 +114,7 @@ impl SpotRsiStrategy {
                 quantity: self.config.quantity,
                 confidence: 0.7,
                 created_at: event.timestamp,
+                instruction: OrderInstruction::default(),
             });
         }
 
@@ -185,7 +138,26 @@ impl Strategy for SpotRsiStrategy {
         self.calculate_signal(event)
     }
 
+    fn state_version(&self) -> u32 {
+        2
+    }
+
+    fn export_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state).ok()
+    }
+
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        let mut state: SpotRsiState = serde_json::from_value(state)?;
+        // 参数热更新后周期变化的指标窗口无法沿用，重新预热
+        let fresh = SpotRsiState::new(&self.config);
+        if state.rsi.period() != fresh.rsi.period() {
+            state.rsi = fresh.rsi;
+        }
+        self.state = state;
+        Ok(())
+    }
+
     fn reset(&mut self) {
-        self.state = SpotRsiState::new();
+        self.state = SpotRsiState::new(&self.config);
     }
 }
@@ -2,12 +2,42 @@
 //!
 //! 定义所有策略必须实现的统一接口。
 //! 为高频交易预留 tick 级别处理能力。
+//!
+//! ## 执行回报
+//! trading-engine 按策略实例回送成交、拒单与持仓快照：
+//! - 每条成交 / 拒单之后都紧跟一条持仓快照
+//! - 持仓以快照为准，策略不应只凭自身信号推断是否持仓
+//!
+//! ## 多交易对与多腿
+//! - `subscriptions` 声明订阅的交易对，调度器按交易对把行情分发给订阅的策略
+//! - `on_market_event_legs` 返回多腿信号组，trading-engine 作为关联组整体执行
+//!
+//! ## 双边报价
+//! - 做市类策略消费深度行情（`MarketEventData::Depth`），通过 `on_market_event_quotes`
+//!   返回报价指令（撤旧挂新 / 全部撤单），单个信号无法表达双边挂单与撤单
+//!
+//! ## 组合策略
+//! - `ensemble_members` 声明成员实例，调度器把成员的单腿意图转为 `MemberSignal`
+//!   交给 `on_member_signals`，成员意图不再单独发布
+//!
+//! ## 定时触发
+//! - `timers` 声明定时计划（固定间隔 / Cron），到期时回调 `on_timer`
+//! - 定时器由行情时间驱动，实盘与回测在处理行情前以同一方式检查到期
+//!
+//! ## 状态持久化
+//! - `export_state` / `import_state` 导出与恢复指标窗口、持仓标记等运行状态
+//! - `state_version` 标识状态结构版本，字段变化时递增
+//! - `migrate_state` 把旧版本检查点逐级升级到当前版本（每次升一级）
 
+use shared::event::execution_feedback_event::{OrderRejection, PositionSnapshot, StrategyFill};
 use shared::event::market_event::MarketEvent;
 use uuid::Uuid;
 
+use crate::domain::model::ensemble::{EnsembleDecision, MemberSignal};
 use crate::domain::model::market_type::MarketType;
-use crate::domain::model::signal::Signal;
+use crate::domain::model::quote::QuoteInstruction;
+use crate::domain::model::signal::{Signal, SignalGroup};
+use crate::domain::model::timer::{TimerEvent, TimerSpec};
 
 /// 策略元信息
 #[derive(Debug, Clone)]
@@ -29,7 +59,14 @@ pub struct StrategyMeta {
 /// 所有策略（现货/合约）都必须实现此 trait。
 /// 设计考虑：
 /// - `on_market_event`: 标准行情事件处理
+/// - `on_market_event_legs` / `subscriptions`: 多腿信号与多交易对订阅
+/// - `on_market_event_quotes`: 双边报价（做市）
+/// - `ensemble_members` / `on_member_signals`: 组合成员信号
+/// - `timers` / `on_timer`: 定时触发
 /// - `on_tick`: 高频 tick 处理（预留）
+/// - `on_fill` / `on_order_rejected` / `on_position_snapshot`: 执行回报
+/// - `export_state` / `import_state` / `migrate_state`: 版本化状态持久化
+/// - `take_fault`: 回调执行故障（用户脚本）
 /// - `reset`: 重置策略状态
 pub trait Strategy: Send + Sync {
     /// 获取策略元信息
@@ -48,6 +85,68 @@ pub trait Strategy: Send + Sync {
     /// - `None`: 无信号
     fn on_market_event(&mut self, event: &MarketEvent) -> Option<Signal>;
 
+    /// 处理行情事件，返回多腿信号组
+    ///
+    /// 默认实现：将 `on_market_event` 的单个信号包装为单腿组
+    /// 多腿策略（如跨期套利）覆盖此方法，一次返回需要整体执行的全部腿
+    fn on_market_event_legs(&mut self, event: &MarketEvent) -> Option<SignalGroup> {
+        self.on_market_event(event).map(SignalGroup::single)
+    }
+
+    /// 处理行情事件，返回双边报价指令
+    ///
+    /// 默认实现：不报价
+    /// 做市类策略覆盖此方法，返回 `None` 表示维持现有报价不变
+    #[allow(unused_variables)]
+    fn on_market_event_quotes(&mut self, event: &MarketEvent) -> Option<QuoteInstruction> {
+        None
+    }
+
+    /// 订阅的交易对
+    ///
+    /// 默认实现：只订阅元信息中的交易对
+    /// 多交易对策略覆盖此方法，调度器会把这些交易对的行情都分发给本实例
+    fn subscriptions(&self) -> Vec<String> {
+        vec![self.meta().symbol.clone()]
+    }
+
+    /// 组合成员实例
+    ///
+    /// 默认实现：不是组合策略（返回空）
+    fn ensemble_members(&self) -> Vec<Uuid> {
+        Vec::new()
+    }
+
+    /// 处理成员信号，返回综合信号与成员归因
+    ///
+    /// 默认实现：不处理
+    /// `event` 为触发成员信号的行情事件
+    #[allow(unused_variables)]
+    fn on_member_signals(
+        &mut self,
+        event: &MarketEvent,
+        signals: &[MemberSignal],
+    ) -> Option<EnsembleDecision> {
+        None
+    }
+
+    /// 定时计划
+    ///
+    /// 默认实现：无定时器
+    /// 在实例首次处理行情时读取，参数热更新后按新参数重新读取
+    fn timers(&self) -> Vec<TimerSpec> {
+        Vec::new()
+    }
+
+    /// 定时器到期，返回信号组
+    ///
+    /// 默认实现：不处理
+    /// 同时到期的多个定时器按计划时刻逐个回调
+    #[allow(unused_variables)]
+    fn on_timer(&mut self, timer: &TimerEvent) -> Option<SignalGroup> {
+        None
+    }
+
     /// 高频 tick 处理（预留接口）
     ///
     /// 默认实现：不处理，返回 None
@@ -57,6 +156,80 @@ pub trait Strategy: Send + Sync {
         None
     }
 
+    /// 订单成交回报（部分或全部）
+    ///
+    /// 默认实现：不处理
+    #[allow(unused_variables)]
+    fn on_fill(&mut self, symbol: &str, fill: &StrategyFill) {}
+
+    /// 订单被拒绝或未成交部分被撤销
+    ///
+    /// 默认实现：不处理
+    #[allow(unused_variables)]
+    fn on_order_rejected(&mut self, symbol: &str, rejection: &OrderRejection) {}
+
+    /// 持仓快照（以 trading-engine 的成交记录为准）
+    ///
+    /// 默认实现：不处理
+    /// 自行跟踪持仓的策略应覆盖此方法，用快照修正内部状态
+    #[allow(unused_variables)]
+    fn on_position_snapshot(&mut self, symbol: &str, snapshot: &PositionSnapshot) {}
+
+    /// 模型版本（机器学习策略）
+    ///
+    /// 默认实现：无模型（返回 None）
+    fn model_version(&self) -> Option<String> {
+        None
+    }
+
+    /// 取出上一次回调中的故障（用户脚本等可能执行失败的策略）
+    ///
+    /// 默认实现：不会出错（返回 None）
+    /// 适配器在每次回调后检查，有故障时返回错误，计入实例失败次数并交给监督器处理
+    fn take_fault(&mut self) -> Option<String> {
+        None
+    }
+
+    /// 状态结构版本
+    ///
+    /// 默认实现：1。状态字段变化时递增，并在 `migrate_state` 中处理旧版本
+    fn state_version(&self) -> u32 {
+        1
+    }
+
+    /// 导出运行状态
+    ///
+    /// 默认实现：无状态需要持久化（返回 None，不写检查点）
+    fn export_state(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// 恢复运行状态
+    ///
+    /// 传入的状态已迁移到 `state_version` 版本。
+    /// 默认实现：忽略
+    #[allow(unused_variables)]
+    fn import_state(&mut self, state: serde_json::Value) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// 状态迁移钩子：把 `from_version` 版本的状态升级到 `from_version + 1`
+    ///
+    /// 默认实现：不支持迁移，旧检查点被丢弃，策略冷启动
+    #[allow(unused_variables)]
+    fn migrate_state(
+        &self,
+        from_version: u32,
+        state: serde_json::Value,
+    ) -> anyhow::Result<serde_json::Value> {
+        anyhow::bail!(
+            "策略 {} 不支持状态迁移: v{} -> v{}",
+            self.meta().strategy_type,
+            from_version,
+            from_version + 1
+        )
+    }
+
     /// 重置策略状态
     ///
     /// 用于策略重启或参数变更后的状态清理
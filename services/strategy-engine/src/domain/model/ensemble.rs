@@ -0,0 +1,89 @@
+//! # 组合信号模型 (Ensemble Model)
+//!
+//! 组合策略订阅成员实例的信号，综合后只产出一个意图：
+//! - `MemberSignal`：成员实例的一次信号（由成员的交易意图转换）
+//! - `EnsembleAttribution`：综合意图的成员归因，随意图一起发布，供事后分析
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::signal::Signal;
+use super::strategy_runtime::{OrderSide, TradeIntent};
+
+/// 成员信号
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemberSignal {
+    /// 成员实例 ID
+    pub instance_id: Uuid,
+    /// 交易对
+    pub symbol: String,
+    /// 方向（None 表示成员离场：平仓 / 减仓信号）
+    pub side: Option<OrderSide>,
+    /// 置信度 (0.0 - 1.0)
+    pub confidence: f64,
+    /// 信号时间
+    pub created_at: DateTime<Utc>,
+}
+
+impl MemberSignal {
+    /// 由成员的交易意图转换
+    pub fn from_intent(intent: &TradeIntent) -> Self {
+        Self {
+            instance_id: intent.strategy_id,
+            symbol: intent.symbol.clone(),
+            side: (!intent.instruction.action.is_reduce_only()).then_some(intent.side),
+            confidence: intent.confidence.clamp(0.0, 1.0),
+            created_at: intent.created_at,
+        }
+    }
+
+    /// 方向分值：买 +1，卖 -1，离场 0
+    pub fn direction(&self) -> f64 {
+        match self.side {
+            Some(OrderSide::Buy) => 1.0,
+            Some(OrderSide::Sell) => -1.0,
+            None => 0.0,
+        }
+    }
+}
+
+/// 单个成员的投票
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemberVote {
+    /// 成员实例 ID
+    pub instance_id: Uuid,
+    /// 方向（None 为离场或未投票）
+    pub side: Option<OrderSide>,
+    /// 置信度（未投票为 0）
+    pub confidence: f64,
+    /// 权重
+    pub weight: f64,
+    /// 对综合分值的贡献（元模型不可加，为 None）
+    pub contribution: Option<f64>,
+    /// 信号时间（未投票或已过期为 None）
+    pub signal_at: Option<DateTime<Utc>>,
+}
+
+/// 综合意图的成员归因
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnsembleAttribution {
+    /// 组合方法
+    pub method: String,
+    /// 综合分值（-1 到 1，正数看多）
+    pub score: f64,
+    /// 元模型版本
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_version: Option<String>,
+    /// 各成员投票（按成员配置顺序）
+    pub members: Vec<MemberVote>,
+}
+
+/// 组合决策：综合信号与归因
+#[derive(Debug, Clone)]
+pub struct EnsembleDecision {
+    /// 综合信号
+    pub signal: Signal,
+    /// 成员归因
+    pub attribution: EnsembleAttribution,
+}
@@ -70,6 +70,18 @@ impl LifecycleState {
     pub fn is_faulted(&self) -> bool {
         matches!(self, LifecycleState::Faulted)
     }
+
+    /// 从名称解析（大小写不敏感）
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "created" => Some(LifecycleState::Created),
+            "running" => Some(LifecycleState::Running),
+            "paused" => Some(LifecycleState::Paused),
+            "stopped" => Some(LifecycleState::Stopped),
+            "faulted" => Some(LifecycleState::Faulted),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for LifecycleState {
@@ -90,6 +102,36 @@ impl Default for LifecycleState {
     }
 }
 
+/// 生命周期操作
+///
+/// 外部可触发的显式状态转换，故障状态只能由执行结果触发。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LifecycleAction {
+    /// 启动
+    Start,
+    /// 暂停
+    Pause,
+    /// 恢复
+    Resume,
+    /// 停止
+    Stop,
+    /// 重启
+    Restart,
+}
+
+impl std::fmt::Display for LifecycleAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LifecycleAction::Start => write!(f, "start"),
+            LifecycleAction::Pause => write!(f, "pause"),
+            LifecycleAction::Resume => write!(f, "resume"),
+            LifecycleAction::Stop => write!(f, "stop"),
+            LifecycleAction::Restart => write!(f, "restart"),
+        }
+    }
+}
+
 /// 生命周期转换事件
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LifecycleTransition {
@@ -165,4 +207,19 @@ mod tests {
         assert_eq!(LifecycleState::Running.to_string(), "running");
         assert_eq!(LifecycleState::Faulted.to_string(), "faulted");
     }
+
+    #[test]
+    fn test_state_parse_roundtrip() {
+        for state in [
+            LifecycleState::Created,
+            LifecycleState::Running,
+            LifecycleState::Paused,
+            LifecycleState::Stopped,
+            LifecycleState::Faulted,
+        ] {
+            assert_eq!(LifecycleState::parse(&state.to_string()), Some(state));
+        }
+        assert_eq!(LifecycleState::parse(" Running "), Some(LifecycleState::Running));
+        assert_eq!(LifecycleState::parse("unknown"), None);
+    }
 }
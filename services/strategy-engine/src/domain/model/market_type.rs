@@ -36,6 +36,16 @@ impl MarketType {
             MarketType::CoinFutures => "coin_futures",
         }
     }
+
+    /// 从名称解析（大小写不敏感）
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "spot" => Some(MarketType::Spot),
+            "usdt_futures" => Some(MarketType::UsdtFutures),
+            "coin_futures" => Some(MarketType::CoinFutures),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for MarketType {
@@ -45,30 +55,49 @@ impl std::fmt::Display for MarketType {
 }
 
 /// 合约方向（仅合约市场使用）
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum PositionSide {
     /// 多头
+    #[serde(alias = "long")]
     Long,
     /// 空头
+    #[serde(alias = "short")]
     Short,
     /// 双向持仓模式下的净仓位
+    #[serde(alias = "both")]
+    #[default]
     Both,
 }
 
 /// 杠杆配置（仅合约市场使用）
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct LeverageConfig {
     /// 杠杆倍数
     pub leverage: u32,
     /// 保证金模式
+    #[serde(default)]
     pub margin_type: MarginType,
 }
 
+impl Default for LeverageConfig {
+    /// 默认 10 倍全仓
+    fn default() -> Self {
+        Self {
+            leverage: 10,
+            margin_type: MarginType::default(),
+        }
+    }
+}
+
 /// 保证金模式
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum MarginType {
     /// 逐仓
+    #[serde(alias = "isolated")]
     Isolated,
     /// 全仓
+    #[serde(alias = "cross")]
+    #[default]
     Cross,
 }
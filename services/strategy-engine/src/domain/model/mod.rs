@@ -17,15 +17,30 @@ pub mod lifecycle_state;
 /// 故障记录
 pub mod failure_record;
 
+/// 重启策略（故障自动重启）
+pub mod restart_policy;
+
 /// 市场类型
 pub mod market_type;
 
 /// 信号模型
 pub mod signal;
 
+/// 报价模型（做市双边报价）
+pub mod quote;
+
+/// 组合信号模型（成员信号与归因）
+pub mod ensemble;
+
+/// 定时触发（间隔 / Cron）
+pub mod timer;
+
 /// 策略配置
 pub mod strategy_config;
 
+/// 策略状态检查点（热恢复）
+pub mod strategy_checkpoint;
+
 /// 策略实体（兼容旧代码）
 pub mod strategy;
 
@@ -38,3 +53,4 @@ pub use strategy_runtime::{
 pub use strategy_handle::StrategyHandle;
 pub use strategy_metadata::{StrategyMetadata, StrategyKind, MarketType};
 pub use lifecycle_state::LifecycleState;
+pub use strategy_checkpoint::StrategyCheckpoint;
@@ -0,0 +1,118 @@
+//! # 报价模型 (Quote Model)
+//!
+//! 做市类策略的双边报价指令。
+//!
+//! 单个 `Signal` 只能表达一笔订单，报价指令同时描述买卖两侧，并带有撤单语义：
+//! - `Replace`：撤掉该实例在该交易对上的全部挂单，再挂出新的报价（任一侧可为空）
+//! - `CancelAll`：撤掉全部挂单，暂停报价
+//!
+//! ## 规则
+//! - ✅ 报价一律为只做 Maker 的限价单（PostOnly），会立即成交时由交易所拒单
+//! - ✅ 每条指令都是完整的目标状态，下游无需与上一条指令合并
+//! - ❌ 不描述开平仓动作，库存由策略自行通过数量上限控制
+//! - ❌ 执行不在范围内：trading-engine 尚未消费报价主题（见 `StrategyScheduler`）
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 单侧报价
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuoteLevel {
+    /// 挂单价格
+    pub price: Decimal,
+    /// 挂单数量
+    pub quantity: Decimal,
+}
+
+/// 报价动作
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum QuoteAction {
+    /// 撤掉旧报价并挂出新报价
+    Replace {
+        /// 买单（None 表示不挂买单）
+        bid: Option<QuoteLevel>,
+        /// 卖单（None 表示不挂卖单）
+        ask: Option<QuoteLevel>,
+    },
+    /// 撤掉全部报价
+    CancelAll,
+}
+
+/// 报价指令
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteInstruction {
+    /// 指令 ID
+    pub id: Uuid,
+    /// 策略实例 ID
+    pub strategy_id: Uuid,
+    /// 交易对
+    pub symbol: String,
+    /// 报价动作
+    pub action: QuoteAction,
+    /// 报价时的公允价格
+    pub fair_value: Decimal,
+    /// 生成时间
+    pub created_at: DateTime<Utc>,
+}
+
+impl QuoteInstruction {
+    /// 撤旧挂新
+    pub fn replace(
+        strategy_id: Uuid,
+        symbol: impl Into<String>,
+        bid: Option<QuoteLevel>,
+        ask: Option<QuoteLevel>,
+        fair_value: Decimal,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            strategy_id,
+            symbol: symbol.into(),
+            action: QuoteAction::Replace { bid, ask },
+            fair_value,
+            created_at,
+        }
+    }
+
+    /// 撤掉全部报价
+    pub fn cancel_all(
+        strategy_id: Uuid,
+        symbol: impl Into<String>,
+        fair_value: Decimal,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            strategy_id,
+            symbol: symbol.into(),
+            action: QuoteAction::CancelAll,
+            fair_value,
+            created_at,
+        }
+    }
+
+    /// 买单
+    pub fn bid(&self) -> Option<QuoteLevel> {
+        match self.action {
+            QuoteAction::Replace { bid, .. } => bid,
+            QuoteAction::CancelAll => None,
+        }
+    }
+
+    /// 卖单
+    pub fn ask(&self) -> Option<QuoteLevel> {
+        match self.action {
+            QuoteAction::Replace { ask, .. } => ask,
+            QuoteAction::CancelAll => None,
+        }
+    }
+
+    /// 是否为撤单指令
+    pub fn is_cancel(&self) -> bool {
+        self.action == QuoteAction::CancelAll
+    }
+}
@@ -0,0 +1,155 @@
+//! # 重启策略 (Restart Policy)
+//!
+//! 定义故障实例的自动重启规则，由监督器（Supervisor）执行。
+//!
+//! ## 规则
+//! - ✅ `never`（默认）：故障后保持 Faulted，直接视为永久故障
+//! - ✅ `on_failure`：按指数退避重启，退避指数为窗口内已重启次数
+//! - ✅ 窗口内重启次数达到 `max_restarts` 后放弃，视为永久故障
+//! - ❌ 不负责执行重启，只描述规则
+
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+fn default_initial_backoff_ms() -> u64 {
+    1_000
+}
+
+fn default_max_backoff_ms() -> u64 {
+    60_000
+}
+
+fn default_multiplier() -> f64 {
+    2.0
+}
+
+fn default_max_restarts() -> Option<u32> {
+    Some(5)
+}
+
+fn default_window_secs() -> u64 {
+    600
+}
+
+/// 重启策略
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "policy", rename_all = "snake_case", deny_unknown_fields)]
+pub enum RestartPolicy {
+    /// 从不自动重启
+    Never,
+    /// 故障后按指数退避自动重启
+    OnFailure {
+        /// 首次重启前的等待时间（毫秒）
+        #[serde(default = "default_initial_backoff_ms")]
+        initial_backoff_ms: u64,
+        /// 退避上限（毫秒）
+        #[serde(default = "default_max_backoff_ms")]
+        max_backoff_ms: u64,
+        /// 退避倍数
+        #[serde(default = "default_multiplier")]
+        multiplier: f64,
+        /// 窗口内最大重启次数（null 表示不限）
+        #[serde(default = "default_max_restarts")]
+        max_restarts: Option<u32>,
+        /// 统计窗口（秒）
+        #[serde(default = "default_window_secs")]
+        window_secs: u64,
+    },
+}
+
+/// 默认不自动重启：故障原因未排查前重启可能反复下错单，需显式开启
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        RestartPolicy::Never
+    }
+}
+
+impl RestartPolicy {
+    /// 按默认参数指数退避重启
+    pub fn on_failure() -> Self {
+        RestartPolicy::OnFailure {
+            initial_backoff_ms: default_initial_backoff_ms(),
+            max_backoff_ms: default_max_backoff_ms(),
+            multiplier: default_multiplier(),
+            max_restarts: default_max_restarts(),
+            window_secs: default_window_secs(),
+        }
+    }
+
+    /// 第 `restarts` 次（窗口内已重启次数）重启前的退避时间
+    ///
+    /// `Never` 返回 None。
+    pub fn backoff(&self, restarts: u32) -> Option<Duration> {
+        match self {
+            RestartPolicy::Never => None,
+            RestartPolicy::OnFailure {
+                initial_backoff_ms,
+                max_backoff_ms,
+                multiplier,
+                ..
+            } => {
+                let exponent = restarts.min(i32::MAX as u32) as i32;
+                let backoff = (*initial_backoff_ms as f64) * multiplier.max(1.0).powi(exponent);
+                let capped = backoff.min(*max_backoff_ms as f64).max(0.0);
+                Some(Duration::milliseconds(capped as i64))
+            }
+        }
+    }
+
+    /// 窗口内已重启 `restarts` 次后是否还允许重启
+    pub fn allows_restart(&self, restarts: u32) -> bool {
+        match self {
+            RestartPolicy::Never => false,
+            RestartPolicy::OnFailure { max_restarts, .. } => {
+                max_restarts.is_none_or(|max| restarts < max)
+            }
+        }
+    }
+
+    /// 重启次数统计窗口
+    pub fn window(&self) -> Duration {
+        match self {
+            RestartPolicy::Never => Duration::zero(),
+            RestartPolicy::OnFailure { window_secs, .. } => Duration::seconds(*window_secs as i64),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_backoff_is_capped() {
+        let policy = RestartPolicy::OnFailure {
+            initial_backoff_ms: 100,
+            max_backoff_ms: 1_000,
+            multiplier: 2.0,
+            max_restarts: Some(3),
+            window_secs: 60,
+        };
+
+        assert_eq!(policy.backoff(0), Some(Duration::milliseconds(100)));
+        assert_eq!(policy.backoff(2), Some(Duration::milliseconds(400)));
+        assert_eq!(policy.backoff(10), Some(Duration::milliseconds(1_000)));
+        assert!(policy.allows_restart(2));
+        assert!(!policy.allows_restart(3));
+        assert_eq!(RestartPolicy::Never.backoff(0), None);
+        assert!(!RestartPolicy::Never.allows_restart(0));
+    }
+
+    #[test]
+    fn test_deserialize_with_defaults() {
+        let policy: RestartPolicy =
+            serde_json::from_value(serde_json::json!({ "policy": "on_failure", "max_restarts": null }))
+                .unwrap();
+        assert!(policy.allows_restart(1_000));
+        assert_eq!(policy.backoff(0), Some(Duration::seconds(1)));
+
+        let never: RestartPolicy =
+            serde_json::from_value(serde_json::json!({ "policy": "never" })).unwrap();
+        assert_eq!(never, RestartPolicy::Never);
+        assert_eq!(RestartPolicy::default(), RestartPolicy::Never);
+        assert_eq!(RestartPolicy::on_failure().backoff(0), Some(Duration::seconds(1)));
+    }
+}
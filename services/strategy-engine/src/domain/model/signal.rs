@@ -1,12 +1,20 @@
 //! # 信号模型 (Signal Model)
 //! 
 //! 定义交易信号的领域实体。
+//!
+//! `instruction` 描述下单方式（开/平/减/反手、持仓方向、订单类型、
+//! 有效期、附带止损止盈、过期时间），缺省时即旧信号语义。
+//!
+//! 多腿策略（如跨期套利）一次产出 `SignalGroup`，由 trading-engine
+//! 作为关联组整体执行（任一腿失败则回滚已下单的腿）。
 
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+pub use shared::types::order::{OrderInstruction, OrderType, PositionAction, TimeInForce};
+
 /// 交易信号实体
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Signal {
@@ -26,6 +34,60 @@ pub struct Signal {
     pub confidence: f64,
     /// 生成时间
     pub created_at: DateTime<Utc>,
+    /// 下单指令（旧信号缺省为开仓 + 默认订单类型）
+    #[serde(default)]
+    pub instruction: OrderInstruction,
+}
+
+impl Signal {
+    /// 附加下单指令
+    pub fn with_instruction(mut self, instruction: OrderInstruction) -> Self {
+        self.instruction = instruction;
+        self
+    }
+
+    /// 是否只减仓（平仓 / 减仓）
+    pub fn is_reduce_only(&self) -> bool {
+        self.instruction.action.is_reduce_only()
+    }
+}
+
+/// 多腿信号组
+///
+/// 组内各腿按顺序下单，整体成功或整体回滚。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignalGroup {
+    /// 组 ID
+    pub id: Uuid,
+    /// 关联的策略ID
+    pub strategy_id: Uuid,
+    /// 各腿信号（按下单顺序）
+    pub legs: Vec<Signal>,
+    /// 生成时间
+    pub created_at: DateTime<Utc>,
+}
+
+impl SignalGroup {
+    /// 创建多腿信号组
+    pub fn new(strategy_id: Uuid, legs: Vec<Signal>, created_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            strategy_id,
+            legs,
+            created_at,
+        }
+    }
+
+    /// 单腿信号组（普通信号）
+    pub fn single(signal: Signal) -> Self {
+        let (strategy_id, created_at) = (signal.strategy_id, signal.created_at);
+        Self::new(strategy_id, vec![signal], created_at)
+    }
+
+    /// 是否只有一条腿
+    pub fn is_single(&self) -> bool {
+        self.legs.len() == 1
+    }
 }
 
 /// 信号类型枚举
@@ -0,0 +1,46 @@
+//! # 策略状态检查点 (Strategy Checkpoint)
+//!
+//! 策略实例运行状态的版本化快照，用于进程重启后的热恢复。
+//!
+//! ## 规则
+//! - ✅ `state` 为策略自行导出的 JSON，引擎不解析其结构
+//! - ✅ `schema_version` 标识状态结构版本，恢复时旧版本先经策略的迁移钩子升级
+//! - ❌ 不恢复比当前策略代码更新的版本（回滚部署时丢弃）
+//! - ❌ 不恢复策略类型不一致的检查点
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 策略状态检查点
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyCheckpoint {
+    /// 策略实例 ID
+    pub instance_id: Uuid,
+    /// 策略类型名称
+    pub strategy_type: String,
+    /// 状态结构版本
+    pub schema_version: u32,
+    /// 策略导出的状态
+    pub state: serde_json::Value,
+    /// 保存时间
+    pub saved_at: DateTime<Utc>,
+}
+
+impl StrategyCheckpoint {
+    /// 创建检查点（保存时间为当前时间）
+    pub fn new(
+        instance_id: Uuid,
+        strategy_type: impl Into<String>,
+        schema_version: u32,
+        state: serde_json::Value,
+    ) -> Self {
+        Self {
+            instance_id,
+            strategy_type: strategy_type.into(),
+            schema_version,
+            state,
+            saved_at: Utc::now(),
+        }
+    }
+}
@@ -6,33 +6,68 @@
 //! - ✅ 持有策略执行器
 //! - ✅ 管理生命周期状态
 //! - ✅ 记录故障历史
+//! - ✅ 参数热更新：在两次事件之间替换执行器，观察期内失败自动回滚
+//! - ✅ 隔离执行器 panic：转换为错误并立即标记故障，不影响调度器
+//! - ✅ panic 后重置执行器并恢复最近的检查点，丢弃执行到一半的状态
 //! - ❌ 不持有策略逻辑
 //! - ❌ 不做调度决策
 
+use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use chrono::{DateTime, Utc};
 use parking_lot::RwLock;
+use shared::event::execution_feedback_event::ExecutionFeedback;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 use super::failure_record::{FailureHistory, FailureRecord, FailureType};
 use super::lifecycle_state::{LifecycleState, LifecycleTransition, LifecycleTransitionError};
+use super::restart_policy::RestartPolicy;
+use super::strategy_checkpoint::StrategyCheckpoint;
 use super::strategy_metadata::StrategyMetadata;
-use super::strategy_runtime::{ExecutionRequest, ExecutionResult};
+use super::strategy_runtime::{
+    extract_panic_message, ExecutionRequest, ExecutionResult, TradeIntent,
+};
 use crate::domain::port::strategy_executor_port::StrategyExecutorPort;
 
+/// 回调结果中的逻辑错误（计入失败次数，观察期内触发回滚）
+trait CallOutcome {
+    fn logic_error(&self) -> Option<&str>;
+}
+
+impl CallOutcome for ExecutionResult {
+    fn logic_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+impl CallOutcome for Vec<ExecutionResult> {
+    fn logic_error(&self) -> Option<&str> {
+        self.iter().find_map(|result| result.error.as_deref())
+    }
+}
+
 /// 策略句柄
 ///
 /// Registry 持有此句柄，通过它管理策略实例的生命周期。
 /// 使用内部可变性（RwLock）支持并发访问。
 pub struct StrategyHandle {
-    /// 策略元数据
-    metadata: StrategyMetadata,
+    /// 策略实例 ID
+    instance_id: Uuid,
+    /// 策略元数据（仅版本号与更新时间可变）
+    metadata: RwLock<StrategyMetadata>,
     /// 内部可变状态
     inner: RwLock<StrategyHandleInner>,
     /// 策略执行器
-    executor: Arc<dyn StrategyExecutorPort>,
+    ///
+    /// 执行期间持有读锁，参数热更新持有写锁，保证替换发生在两次事件之间。
+    executor: RwLock<Arc<dyn StrategyExecutorPort>>,
+    /// 订阅的交易对（元数据交易对 + 执行器声明，创建时确定）
+    subscriptions: Vec<String>,
+    /// 故障后的自动重启策略
+    restart_policy: RestartPolicy,
 }
 
 /// 内部可变状态
@@ -49,14 +84,44 @@ struct StrategyHandleInner {
     execution_count: u64,
     /// 成功计数
     success_count: u64,
+    /// 当前策略参数
+    params: serde_json::Value,
+    /// 参数更新观察期（观察期内执行失败则回滚）
+    probation: Option<ParamProbation>,
+    /// 最近一次导出或恢复的检查点（panic 后的恢复点）
+    recovery_point: Option<StrategyCheckpoint>,
+}
+
+/// 参数更新观察期
+///
+/// 保存最近一次通过观察期的执行器与参数，观察期内连续更新时不覆盖。
+struct ParamProbation {
+    /// 回滚目标执行器
+    executor: Arc<dyn StrategyExecutorPort>,
+    /// 回滚目标参数
+    params: serde_json::Value,
+    /// 回滚目标对应的版本号
+    version: u32,
+    /// 剩余观察事件数
+    remaining: u32,
 }
 
 impl StrategyHandle {
     /// 创建策略句柄（Created 状态）
     pub fn new(metadata: StrategyMetadata, executor: Arc<dyn StrategyExecutorPort>) -> Self {
+        let mut subscriptions = vec![metadata.symbol.clone()];
+        for symbol in executor.subscriptions() {
+            if !subscriptions.contains(&symbol) {
+                subscriptions.push(symbol);
+            }
+        }
+
         Self {
-            metadata,
-            executor,
+            instance_id: metadata.instance_id,
+            metadata: RwLock::new(metadata),
+            executor: RwLock::new(executor),
+            subscriptions,
+            restart_policy: RestartPolicy::default(),
             inner: RwLock::new(StrategyHandleInner {
                 state: LifecycleState::Created,
                 failure_history: FailureHistory::new(),
@@ -64,22 +129,76 @@ impl StrategyHandle {
                 last_execution_at: None,
                 execution_count: 0,
                 success_count: 0,
+                params: serde_json::Value::Null,
+                probation: None,
+                recovery_point: None,
             }),
         }
     }
 
+    /// 设置故障后的自动重启策略
+    pub fn with_restart_policy(mut self, policy: RestartPolicy) -> Self {
+        self.restart_policy = policy;
+        self
+    }
+
+    /// 设置创建时的策略参数
+    pub fn with_params(mut self, params: serde_json::Value) -> Self {
+        self.inner.get_mut().params = params;
+        self
+    }
+
     // ========================================================================
     // 基本信息
     // ========================================================================
 
     /// 获取实例 ID
     pub fn instance_id(&self) -> Uuid {
-        self.metadata.instance_id
+        self.instance_id
+    }
+
+    /// 获取元数据快照
+    pub fn metadata(&self) -> StrategyMetadata {
+        self.metadata.read().clone()
     }
 
-    /// 获取元数据
-    pub fn metadata(&self) -> &StrategyMetadata {
-        &self.metadata
+    /// 自动重启策略
+    pub fn restart_policy(&self) -> &RestartPolicy {
+        &self.restart_policy
+    }
+
+    /// 最近一次进入故障状态的时间
+    pub fn faulted_at(&self) -> Option<DateTime<Utc>> {
+        let inner = self.inner.read();
+        if inner.state != LifecycleState::Faulted {
+            return None;
+        }
+        inner
+            .transitions
+            .iter()
+            .rev()
+            .find(|t| t.to == LifecycleState::Faulted)
+            .map(|t| t.timestamp)
+    }
+
+    /// 当前策略参数
+    pub fn params(&self) -> serde_json::Value {
+        self.inner.read().params.clone()
+    }
+
+    /// 参数更新观察期剩余事件数（不在观察期时为 None）
+    pub fn probation_remaining(&self) -> Option<u32> {
+        self.inner.read().probation.as_ref().map(|p| p.remaining)
+    }
+
+    /// 订阅的交易对
+    pub fn subscriptions(&self) -> &[String] {
+        &self.subscriptions
+    }
+
+    /// 是否订阅了该交易对
+    pub fn subscribes(&self, symbol: &str) -> bool {
+        self.subscriptions.iter().any(|s| s == symbol)
     }
 
     /// 获取当前生命周期状态
@@ -116,6 +235,40 @@ impl StrategyHandle {
 
     /// 执行策略计算
     pub fn execute(&self, request: &ExecutionRequest) -> Result<ExecutionResult> {
+        self.run(|executor| executor.execute(request))
+    }
+
+    /// 组合策略：综合成员意图
+    ///
+    /// 与 `execute` 共用状态检查、统计、观察期与故障处理。
+    pub fn execute_ensemble(
+        &self,
+        request: &ExecutionRequest,
+        member_intents: &[TradeIntent],
+    ) -> Result<ExecutionResult> {
+        self.run(|executor| executor.execute_ensemble(request, member_intents))
+    }
+
+    /// 组合成员实例（非组合策略为空）
+    pub fn ensemble_members(&self) -> Vec<Uuid> {
+        self.executor.read().ensemble_members()
+    }
+
+    /// 触发到期的定时器（`now` 为行情时间）
+    ///
+    /// 没有到期的定时器时直接返回，不计入执行次数与观察期。
+    pub fn fire_timers(&self, now: DateTime<Utc>) -> Result<Vec<ExecutionResult>> {
+        if !self.executor.read().timers_due(now) {
+            return Ok(Vec::new());
+        }
+        self.run(|executor| executor.fire_timers(now))
+    }
+
+    /// 执行一次策略回调
+    fn run<T: CallOutcome>(
+        &self,
+        call: impl Fn(&dyn StrategyExecutorPort) -> Result<T>,
+    ) -> Result<T> {
         {
             let inner = self.inner.read();
             if !inner.state.can_execute() {
@@ -134,43 +287,262 @@ impl StrategyHandle {
             inner.last_execution_at = Some(Utc::now());
         }
 
-        // 执行策略
-        let result = self.executor.execute(request);
+        // 执行策略（panic 转换为错误）
+        let (result, panicked) =
+            match std::panic::catch_unwind(AssertUnwindSafe(|| call(self.executor.read().as_ref()))) {
+                Ok(result) => (result, false),
+                Err(panic) => (
+                    Err(anyhow!("策略执行 panic: {}", extract_panic_message(&panic))),
+                    true,
+                ),
+            };
+
+        if panicked {
+            self.recover_after_panic();
+        }
 
         // 更新统计
+        let mut rollback_reason = None;
         {
             let mut inner = self.inner.write();
+            if let Some(probation) = inner.probation.as_mut() {
+                match &result {
+                    Ok(r) if r.logic_error().is_none() => {
+                        probation.remaining = probation.remaining.saturating_sub(1);
+                        if probation.remaining == 0 {
+                            inner.probation = None;
+                            info!(instance_id = %self.instance_id, "参数更新已通过观察期");
+                        }
+                    }
+                    Ok(r) => rollback_reason = r.logic_error().map(str::to_string),
+                    Err(e) => rollback_reason = Some(e.to_string()),
+                }
+            }
+
             match &result {
-                Ok(r) if r.error.is_none() => {
+                Ok(r) if r.logic_error().is_none() => {
                     inner.success_count += 1;
                     inner.failure_history.record_success();
                 }
                 Ok(r) => {
                     inner.failure_history.record(FailureRecord::new(
                         FailureType::LogicError,
-                        r.error.as_deref().unwrap_or("未知错误"),
+                        r.logic_error().unwrap_or("未知错误"),
                     ));
                     self.check_fault_threshold(&mut inner);
                 }
+                Err(e) if panicked => {
+                    inner.failure_history.record(FailureRecord::new(
+                        FailureType::Panic,
+                        e.to_string(),
+                    ));
+                    Self::fault(&mut inner, e.to_string());
+                }
                 Err(e) => {
                     inner.failure_history.record(FailureRecord::new(
                         FailureType::Unknown,
-                        &e.to_string(),
+                        e.to_string(),
                     ));
                     self.check_fault_threshold(&mut inner);
                 }
             }
         }
 
+        if let Some(reason) = rollback_reason {
+            self.rollback_params(&reason);
+        }
+
         result
     }
 
+    /// 投递执行回报
+    ///
+    /// 不检查生命周期状态：暂停或停止的策略仍需同步持仓。
+    pub fn on_feedback(&self, symbol: &str, feedback: &ExecutionFeedback) -> Result<()> {
+        match std::panic::catch_unwind(AssertUnwindSafe(|| {
+            self.executor.read().on_feedback(symbol, feedback)
+        })) {
+            Ok(result) => result,
+            Err(panic) => {
+                let message = format!("执行回报处理 panic: {}", extract_panic_message(&panic));
+                self.recover_after_panic();
+                let mut inner = self.inner.write();
+                inner
+                    .failure_history
+                    .record(FailureRecord::new(FailureType::Panic, &message));
+                Self::fault(&mut inner, message.clone());
+                Err(anyhow!(message))
+            }
+        }
+    }
+
+    /// 生成状态检查点
+    ///
+    /// 不检查生命周期状态：暂停的策略同样保留指标窗口。
+    /// 导出的检查点同时作为 panic 后的恢复点。
+    pub fn checkpoint(&self) -> Result<Option<StrategyCheckpoint>> {
+        let checkpoint = self.executor.read().checkpoint()?;
+        if let Some(checkpoint) = &checkpoint {
+            self.inner.write().recovery_point = Some(checkpoint.clone());
+        }
+        Ok(checkpoint)
+    }
+
+    /// 从检查点恢复状态
+    pub fn restore(&self, checkpoint: StrategyCheckpoint) -> Result<()> {
+        self.executor.read().restore(checkpoint.clone())?;
+        self.inner.write().recovery_point = Some(checkpoint);
+        Ok(())
+    }
+
+    /// panic 后恢复执行器状态
+    ///
+    /// panic 可能发生在状态更新到一半时，且 parking_lot 锁不会中毒，
+    /// 重启后会继续使用被破坏的状态。先重置执行器，再恢复最近的检查点；
+    /// 没有检查点时从空状态重新开始。
+    fn recover_after_panic(&self) {
+        let recovery_point = self.inner.read().recovery_point.clone();
+        let executor = self.executor.read();
+
+        if let Err(e) = executor.reset() {
+            warn!(instance_id = %self.instance_id, error = %e, "panic 后重置执行器失败");
+        }
+        if let Some(checkpoint) = recovery_point {
+            match executor.restore(checkpoint) {
+                Ok(()) => info!(instance_id = %self.instance_id, "panic 后已恢复最近的检查点"),
+                Err(e) => {
+                    warn!(instance_id = %self.instance_id, error = %e, "panic 后恢复检查点失败，从空状态重新开始")
+                }
+            }
+        }
+    }
+
+    // ========================================================================
+    // 参数热更新
+    // ========================================================================
+
+    /// 以新参数创建的执行器替换当前执行器
+    ///
+    /// 当前状态通过检查点迁移到新执行器，指标由 `import_state` 按新参数重建并回放窗口；
+    /// 替换在两次事件之间完成，随后 `probation_events` 个事件内任一执行失败即自动回滚。
+    /// 返回新版本号。
+    pub fn update_params(
+        &self,
+        params: serde_json::Value,
+        executor: Arc<dyn StrategyExecutorPort>,
+        probation_events: u32,
+    ) -> Result<u32> {
+        let mut subscriptions = executor.subscriptions();
+        subscriptions.retain(|s| !self.subscribes(s));
+        if !subscriptions.is_empty() {
+            bail!("参数更新不能改变订阅的交易对: {}", subscriptions.join(", "));
+        }
+
+        let mut current = self.executor.write();
+        if let Some(checkpoint) = current.checkpoint().context("导出当前状态失败")? {
+            executor
+                .restore(checkpoint)
+                .context("新参数无法沿用当前状态")?;
+        }
+
+        let mut metadata = self.metadata.write();
+        let mut inner = self.inner.write();
+        let previous_version = metadata.version;
+        metadata.increment_version();
+        metadata.model_version = executor.model_version();
+        let version = metadata.version;
+
+        let previous_executor = std::mem::replace(&mut *current, executor);
+        let previous_params = std::mem::replace(&mut inner.params, params);
+        inner.probation = match inner.probation.take() {
+            // 观察期内再次更新：回滚目标仍是最近一次通过观察期的版本
+            Some(probation) => Some(ParamProbation {
+                remaining: probation_events,
+                ..probation
+            }),
+            None => Some(ParamProbation {
+                executor: previous_executor,
+                params: previous_params,
+                version: previous_version,
+                remaining: probation_events,
+            }),
+        }
+        .filter(|p| p.remaining > 0);
+
+        let state = inner.state;
+        inner
+            .transitions
+            .push(LifecycleTransition::new(state, state, format!("参数更新 v{}", version)));
+
+        info!(
+            instance_id = %self.instance_id,
+            version,
+            probation_events,
+            "策略参数已更新"
+        );
+        Ok(version)
+    }
+
+    /// 回滚到观察期前的参数与执行器
+    ///
+    /// 观察期内的状态尽量迁回旧执行器，不兼容时旧执行器沿用更新前的状态。
+    /// 不在观察期时返回 false。
+    pub fn rollback_params(&self, reason: &str) -> bool {
+        let mut current = self.executor.write();
+        let mut metadata = self.metadata.write();
+        let mut inner = self.inner.write();
+        let Some(probation) = inner.probation.take() else {
+            return false;
+        };
+
+        match current.checkpoint() {
+            Ok(Some(checkpoint)) => {
+                if let Err(e) = probation.executor.restore(checkpoint) {
+                    warn!(instance_id = %self.instance_id, error = %e, "回滚时状态迁移失败，沿用更新前状态");
+                }
+            }
+            Ok(None) => {}
+            Err(e) => {
+                warn!(instance_id = %self.instance_id, error = %e, "回滚时导出状态失败，沿用更新前状态");
+            }
+        }
+
+        *current = probation.executor;
+        inner.params = probation.params;
+        metadata.increment_version();
+        metadata.model_version = current.model_version();
+        let version = metadata.version;
+
+        let state = inner.state;
+        inner.transitions.push(LifecycleTransition::new(
+            state,
+            state,
+            format!("参数回滚 v{}（恢复 v{} 参数）: {}", version, probation.version, reason),
+        ));
+
+        warn!(
+            instance_id = %self.instance_id,
+            version,
+            restored_version = probation.version,
+            reason,
+            "策略参数已回滚"
+        );
+        true
+    }
+
     /// 检查是否超过故障阈值
     fn check_fault_threshold(&self, inner: &mut StrategyHandleInner) {
         if inner.failure_history.exceeds_threshold(3) && inner.state == LifecycleState::Running {
+            Self::fault(inner, "连续故障超过阈值");
+        }
+    }
+
+    /// 运行中或暂停的实例转入故障状态
+    fn fault(inner: &mut StrategyHandleInner, reason: impl Into<String>) {
+        if matches!(inner.state, LifecycleState::Running | LifecycleState::Paused) {
             let from = inner.state;
             inner.state = LifecycleState::Faulted;
-            inner.transitions.push(LifecycleTransition::new(from, LifecycleState::Faulted, "连续故障超过阈值"));
+            inner.transitions.push(LifecycleTransition::new(from, LifecycleState::Faulted, reason));
         }
     }
 
@@ -344,6 +716,8 @@ mod tests {
                 request_id: request.request_id,
                 has_intent: false,
                 intent: None,
+                group: None,
+                quotes: None,
                 execution_time_us: 0,
                 error: None,
             })
@@ -423,6 +797,256 @@ mod tests {
         assert_eq!(stats.success_rate(), 0.0);
     }
 
+    /// 测试用的计数执行器：可配置为执行失败，计数通过检查点迁移
+    struct CountingExecutor {
+        seen: std::sync::atomic::AtomicU64,
+        fail: bool,
+    }
+
+    impl CountingExecutor {
+        fn new(fail: bool) -> Arc<Self> {
+            Arc::new(Self {
+                seen: std::sync::atomic::AtomicU64::new(0),
+                fail,
+            })
+        }
+
+        fn seen(&self) -> u64 {
+            self.seen.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    impl StrategyExecutorPort for CountingExecutor {
+        fn execute(&self, request: &ExecutionRequest) -> Result<ExecutionResult> {
+            self.seen.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(ExecutionResult {
+                request_id: request.request_id,
+                has_intent: false,
+                intent: None,
+                group: None,
+                quotes: None,
+                execution_time_us: 0,
+                error: self.fail.then(|| "参数导致计算失败".to_string()),
+            })
+        }
+
+        fn reset(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn state_snapshot(&self) -> Result<serde_json::Value> {
+            Ok(serde_json::json!({}))
+        }
+
+        fn checkpoint(&self) -> Result<Option<StrategyCheckpoint>> {
+            Ok(Some(StrategyCheckpoint::new(
+                Uuid::nil(),
+                "counting",
+                1,
+                serde_json::json!({ "seen": self.seen() }),
+            )))
+        }
+
+        fn restore(&self, checkpoint: StrategyCheckpoint) -> Result<()> {
+            let seen = checkpoint.state["seen"].as_u64().unwrap_or_default();
+            self.seen.store(seen, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn request() -> ExecutionRequest {
+        ExecutionRequest {
+            request_id: Uuid::new_v4(),
+            symbol: "BTCUSDT".to_string(),
+            price: rust_decimal::Decimal::new(50000, 0),
+            quantity: rust_decimal::Decimal::new(1, 3),
+            timestamp: Utc::now(),
+            is_buyer_maker: false,
+            depth: None,
+        }
+    }
+
+    fn create_counting_handle() -> (StrategyHandle, Arc<CountingExecutor>) {
+        let executor = CountingExecutor::new(false);
+        let handle = StrategyHandle::new(create_test_metadata(), executor.clone())
+            .with_params(serde_json::json!({ "period": 20 }));
+        handle.start().unwrap();
+        (handle, executor)
+    }
+
+    #[test]
+    fn test_update_params_carries_state_and_passes_probation() {
+        let (handle, _) = create_counting_handle();
+        handle.execute(&request()).unwrap();
+        handle.execute(&request()).unwrap();
+
+        let updated = CountingExecutor::new(false);
+        let version = handle
+            .update_params(serde_json::json!({ "period": 30 }), updated.clone(), 2)
+            .unwrap();
+
+        assert_eq!(version, 2);
+        assert_eq!(handle.metadata().version, 2);
+        assert_eq!(handle.params()["period"], 30);
+        assert_eq!(updated.seen(), 2);
+        assert_eq!(handle.lifecycle_state(), LifecycleState::Running);
+        assert_eq!(handle.transitions().last().unwrap().reason, "参数更新 v2");
+
+        handle.execute(&request()).unwrap();
+        assert_eq!(handle.probation_remaining(), Some(1));
+        handle.execute(&request()).unwrap();
+        assert_eq!(handle.probation_remaining(), None);
+        assert_eq!(updated.seen(), 4);
+        assert!(!handle.rollback_params("手动"));
+    }
+
+    #[test]
+    fn test_failed_probation_rolls_back() {
+        let (handle, original) = create_counting_handle();
+        handle.execute(&request()).unwrap();
+
+        let broken = CountingExecutor::new(true);
+        handle
+            .update_params(serde_json::json!({ "period": 0 }), broken.clone(), 5)
+            .unwrap();
+        handle.execute(&request()).unwrap();
+
+        // 回滚：恢复旧执行器与参数，版本号继续递增，状态迁回旧执行器
+        assert_eq!(handle.probation_remaining(), None);
+        assert_eq!(handle.params()["period"], 20);
+        assert_eq!(handle.metadata().version, 3);
+        assert_eq!(original.seen(), 2);
+        assert!(handle.transitions().last().unwrap().reason.starts_with("参数回滚 v3"));
+
+        handle.execute(&request()).unwrap();
+        assert_eq!(original.seen(), 3);
+        assert_eq!(broken.seen(), 2);
+        assert_eq!(handle.lifecycle_state(), LifecycleState::Running);
+    }
+
+    #[test]
+    fn test_update_params_rejects_new_subscriptions() {
+        struct FarLegExecutor;
+
+        impl StrategyExecutorPort for FarLegExecutor {
+            fn execute(&self, _request: &ExecutionRequest) -> Result<ExecutionResult> {
+                unreachable!()
+            }
+
+            fn reset(&self) -> Result<()> {
+                Ok(())
+            }
+
+            fn state_snapshot(&self) -> Result<serde_json::Value> {
+                Ok(serde_json::json!({}))
+            }
+
+            fn subscriptions(&self) -> Vec<String> {
+                vec!["BTCUSDT_250328".to_string()]
+            }
+        }
+
+        let (handle, _) = create_counting_handle();
+        assert!(handle
+            .update_params(serde_json::json!({}), Arc::new(FarLegExecutor), 5)
+            .is_err());
+        assert_eq!(handle.metadata().version, 1);
+    }
+
+    #[test]
+    fn test_panic_is_isolated_and_faults_instance() {
+        struct PanickingExecutor;
+
+        impl StrategyExecutorPort for PanickingExecutor {
+            fn execute(&self, _request: &ExecutionRequest) -> Result<ExecutionResult> {
+                panic!("索引越界");
+            }
+
+            fn reset(&self) -> Result<()> {
+                Ok(())
+            }
+
+            fn state_snapshot(&self) -> Result<serde_json::Value> {
+                Ok(serde_json::json!({}))
+            }
+        }
+
+        let handle = StrategyHandle::new(create_test_metadata(), Arc::new(PanickingExecutor));
+        handle.start().unwrap();
+
+        let err = handle.execute(&request()).unwrap_err();
+        assert!(err.to_string().contains("索引越界"));
+        assert_eq!(handle.lifecycle_state(), LifecycleState::Faulted);
+        assert!(handle.faulted_at().is_some());
+        assert_eq!(
+            handle.failure_history().last().unwrap().failure_type,
+            FailureType::Panic
+        );
+
+        // 重启后执行器仍可被调用（锁未被破坏）
+        handle.restart().unwrap();
+        assert!(handle.faulted_at().is_none());
+        assert!(handle.execute(&request()).is_err());
+    }
+
+    #[test]
+    fn test_panic_restores_last_checkpoint() {
+        /// 第 3 次执行时先改状态再 panic
+        struct HalfUpdatedExecutor {
+            counter: Arc<CountingExecutor>,
+            resets: std::sync::atomic::AtomicU32,
+        }
+
+        impl StrategyExecutorPort for HalfUpdatedExecutor {
+            fn execute(&self, request: &ExecutionRequest) -> Result<ExecutionResult> {
+                let result = self.counter.execute(request)?;
+                if self.counter.seen() == 3 {
+                    panic!("状态更新到一半");
+                }
+                Ok(result)
+            }
+
+            fn reset(&self) -> Result<()> {
+                self.resets.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                self.counter.seen.store(0, std::sync::atomic::Ordering::SeqCst);
+                Ok(())
+            }
+
+            fn state_snapshot(&self) -> Result<serde_json::Value> {
+                Ok(serde_json::json!({}))
+            }
+
+            fn checkpoint(&self) -> Result<Option<StrategyCheckpoint>> {
+                self.counter.checkpoint()
+            }
+
+            fn restore(&self, checkpoint: StrategyCheckpoint) -> Result<()> {
+                self.counter.restore(checkpoint)
+            }
+        }
+
+        let executor = Arc::new(HalfUpdatedExecutor {
+            counter: CountingExecutor::new(false),
+            resets: std::sync::atomic::AtomicU32::new(0),
+        });
+        let handle = StrategyHandle::new(create_test_metadata(), executor.clone());
+        handle.start().unwrap();
+
+        handle.execute(&request()).unwrap();
+        handle.checkpoint().unwrap();
+        handle.execute(&request()).unwrap();
+        assert!(handle.execute(&request()).is_err());
+        assert_eq!(handle.lifecycle_state(), LifecycleState::Faulted);
+
+        // 丢弃 panic 前的半更新状态，回到检查点
+        assert_eq!(executor.resets.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(executor.counter.seen(), 1);
+
+        handle.restart().unwrap();
+        handle.execute(&request()).unwrap();
+        assert_eq!(executor.counter.seen(), 2);
+    }
+
     #[test]
     fn test_restart() {
         let handle = create_test_handle();
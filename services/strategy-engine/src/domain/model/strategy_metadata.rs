@@ -94,6 +94,9 @@ pub struct StrategyMetadata {
     pub tags: Vec<String>,
     /// 初始资金（用于风控和统计）
     pub initial_capital: Option<Decimal>,
+    /// 模型版本（机器学习策略，随参数热更新切换）
+    #[serde(default)]
+    pub model_version: Option<String>,
 }
 
 impl StrategyMetadata {
@@ -119,6 +122,7 @@ impl StrategyMetadata {
             updated_at: now,
             tags: Vec::new(),
             initial_capital: None,
+            model_version: None,
         }
     }
 
@@ -146,6 +150,12 @@ impl StrategyMetadata {
         self
     }
 
+    /// 设置模型版本
+    pub fn with_model_version(mut self, model_version: Option<String>) -> Self {
+        self.model_version = model_version;
+        self
+    }
+
     /// 增加版本号
     pub fn increment_version(&mut self) {
         self.version += 1;
@@ -16,13 +16,22 @@ use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use shared::event::execution_feedback_event::StrategyFill;
+use shared::event::market_event::DepthData;
+use shared::event::signal_event::{
+    SignalEvent, SignalLeg, SignalType as EventSignalType, SIGNAL_EVENT_SCHEMA_VERSION,
+};
 use tokio::sync::{mpsc, oneshot};
 use tokio::task::JoinHandle;
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
 // 复用 shared 的类型
-pub use shared::types::order::{OrderSide, OrderType};
+pub use shared::types::order::{OrderInstruction, OrderSide, OrderType};
+
+use super::ensemble::EnsembleAttribution;
+use super::quote::QuoteInstruction;
+use super::signal::{Signal, SignalGroup, SignalType};
 
 // ============================================================================
 // 执行请求/响应（Strategy Engine 内部使用）
@@ -43,6 +52,9 @@ pub struct ExecutionRequest {
     pub timestamp: DateTime<Utc>,
     /// 是否买方主动
     pub is_buyer_maker: bool,
+    /// 盘口深度（深度行情时存在，此时 `price` 为中间价、`quantity` 为 0）
+    #[serde(default)]
+    pub depth: Option<DepthData>,
 }
 
 impl ExecutionRequest {
@@ -55,9 +67,30 @@ impl ExecutionRequest {
             quantity,
             timestamp: Utc::now(),
             is_buyer_maker: false,
+            depth: None,
         }
     }
 
+    /// 从深度行情创建（价格取买一卖一中间价，盘口不完整时返回 None）
+    pub fn from_depth(
+        symbol: impl Into<String>,
+        depth: DepthData,
+        timestamp: DateTime<Utc>,
+    ) -> Option<Self> {
+        let (best_bid, _) = *depth.bids.first()?;
+        let (best_ask, _) = *depth.asks.first()?;
+
+        Some(Self {
+            request_id: Uuid::new_v4(),
+            symbol: symbol.into(),
+            price: (best_bid + best_ask) / Decimal::TWO,
+            quantity: Decimal::ZERO,
+            timestamp,
+            is_buyer_maker: false,
+            depth: Some(depth),
+        })
+    }
+
     /// 从行情事件创建
     pub fn from_market_data(
         symbol: impl Into<String>,
@@ -74,6 +107,7 @@ impl ExecutionRequest {
             timestamp: DateTime::from_timestamp_millis(timestamp_ms)
                 .unwrap_or_else(Utc::now),
             is_buyer_maker,
+            depth: None,
         }
     }
 }
@@ -87,6 +121,12 @@ pub struct ExecutionResult {
     pub has_intent: bool,
     /// 交易意图
     pub intent: Option<TradeIntent>,
+    /// 多腿意图组（多腿策略产出时 intent 为 None）
+    #[serde(default)]
+    pub group: Option<TradeIntentGroup>,
+    /// 双边报价指令（做市策略产出）
+    #[serde(default)]
+    pub quotes: Option<QuoteInstruction>,
     /// 执行耗时（微秒）
     pub execution_time_us: u64,
     /// 错误信息
@@ -114,6 +154,29 @@ pub struct TradeIntent {
     pub confidence: f64,
     /// 创建时间
     pub created_at: DateTime<Utc>,
+    /// 下单指令（开平仓动作、有效期、止损止盈、过期时间）
+    #[serde(default)]
+    pub instruction: OrderInstruction,
+    /// 组合层内部对冲（与其他策略的反向意图对敲的部分，不发往交易所）
+    #[serde(default)]
+    pub netting: Option<IntentNetting>,
+    /// 组合策略的成员归因（组合策略产出时填写）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attribution: Option<EnsembleAttribution>,
+}
+
+/// 内部对冲
+///
+/// 调度器按 `price` 把 `quantity` 记为该策略的内部成交（见 `TradeIntent::internal_fill`），
+/// 只把剩余部分（`exchange_quantity`）发往交易所。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntentNetting {
+    /// 对冲数量
+    pub quantity: Decimal,
+    /// 对冲成交均价
+    pub price: Decimal,
+    /// 对手策略实例
+    pub counterparties: Vec<Uuid>,
 }
 
 impl TradeIntent {
@@ -129,6 +192,9 @@ impl TradeIntent {
             order_type: OrderType::Market,
             confidence,
             created_at: Utc::now(),
+            instruction: OrderInstruction::default(),
+            netting: None,
+            attribution: None,
         }
     }
 
@@ -144,6 +210,9 @@ impl TradeIntent {
             order_type: OrderType::Market,
             confidence,
             created_at: Utc::now(),
+            instruction: OrderInstruction::default(),
+            netting: None,
+            attribution: None,
         }
     }
 
@@ -159,6 +228,9 @@ impl TradeIntent {
             order_type: OrderType::Limit,
             confidence,
             created_at: Utc::now(),
+            instruction: OrderInstruction::default(),
+            netting: None,
+            attribution: None,
         }
     }
 
@@ -174,8 +246,163 @@ impl TradeIntent {
             order_type: OrderType::Limit,
             confidence,
             created_at: Utc::now(),
+            instruction: OrderInstruction::default(),
+            netting: None,
+            attribution: None,
+        }
+    }
+
+    /// 需要发往交易所的数量（扣除内部对冲）
+    pub fn exchange_quantity(&self) -> Decimal {
+        let netted = self.netting.as_ref().map_or(Decimal::ZERO, |n| n.quantity);
+        (self.quantity - netted).max(Decimal::ZERO)
+    }
+
+    /// 内部对冲成交（未对冲时返回 None）
+    ///
+    /// 对冲部分不经过交易所，不会有 trading-engine 回报，由调度器作为成交回报直接交给策略与组合账簿。
+    pub fn internal_fill(&self) -> Option<StrategyFill> {
+        let netting = self.netting.as_ref().filter(|n| n.quantity > Decimal::ZERO)?;
+        let id = format!("internal-{}", self.id);
+        Some(StrategyFill {
+            order_id: id.clone(),
+            trade_id: id,
+            side: self.side,
+            quantity: netting.quantity,
+            price: netting.price,
+            cumulative_quantity: netting.quantity,
+            original_quantity: self.quantity,
+            is_final: self.exchange_quantity().is_zero(),
+            commission: Decimal::ZERO,
+            commission_asset: String::new(),
+            fill_time: Utc::now(),
+        })
+    }
+
+    /// 附加下单指令
+    pub fn with_instruction(mut self, instruction: OrderInstruction) -> Self {
+        self.instruction = instruction;
+        self
+    }
+
+    /// 附加成员归因
+    pub fn with_attribution(mut self, attribution: EnsembleAttribution) -> Self {
+        self.attribution = Some(attribution);
+        self
+    }
+
+    /// 由交易信号转换（Hold 信号不生成交易意图）
+    ///
+    /// 未指定订单类型时保持旧行为：按信号价格下限价单。
+    pub fn from_signal(signal: Signal) -> Option<Self> {
+        let side = match signal.signal_type {
+            SignalType::Buy => OrderSide::Buy,
+            SignalType::Sell => OrderSide::Sell,
+            SignalType::Hold => return None,
+        };
+
+        let order_type = signal.instruction.order_type.unwrap_or(OrderType::Limit);
+        let price = match order_type {
+            OrderType::Market => None,
+            _ => Some(signal.price),
+        };
+
+        Some(Self {
+            id: signal.id,
+            strategy_id: signal.strategy_id,
+            symbol: signal.symbol,
+            side,
+            quantity: signal.quantity,
+            price,
+            order_type,
+            confidence: signal.confidence,
+            created_at: signal.created_at,
+            instruction: signal.instruction,
+            netting: None,
+            attribution: None,
+        })
+    }
+
+    /// 转换为跨服务信号事件（当前版本）
+    ///
+    /// 市价意图以 `reference_price` 作为参考价；数量只包含需要发往交易所的部分。
+    pub fn to_signal_event(&self, reference_price: Decimal) -> SignalEvent {
+        SignalEvent {
+            schema_version: SIGNAL_EVENT_SCHEMA_VERSION,
+            id: self.id,
+            strategy_id: self.strategy_id,
+            symbol: self.symbol.clone(),
+            signal_type: match self.side {
+                OrderSide::Buy => EventSignalType::Buy,
+                OrderSide::Sell => EventSignalType::Sell,
+            },
+            price: self.price.unwrap_or(reference_price),
+            quantity: self.exchange_quantity(),
+            confidence: self.confidence,
+            created_at: self.created_at,
+            instruction: self.instruction.clone().with_order_type(self.order_type),
+            legs: Vec::new(),
         }
     }
+
+    /// 转换为信号事件中的一腿
+    fn to_signal_leg(&self) -> SignalLeg {
+        SignalLeg {
+            symbol: self.symbol.clone(),
+            side: self.side,
+            quantity: self.exchange_quantity(),
+            price: self.price,
+            instruction: self.instruction.clone().with_order_type(self.order_type),
+        }
+    }
+}
+
+/// 多腿交易意图组
+///
+/// 由 trading-engine 作为关联组整体执行，任一腿失败则回滚已下单的腿。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeIntentGroup {
+    /// 组 ID
+    pub id: Uuid,
+    /// 策略实例 ID
+    pub strategy_id: Uuid,
+    /// 各腿意图（按下单顺序）
+    pub legs: Vec<TradeIntent>,
+    /// 创建时间
+    pub created_at: DateTime<Utc>,
+}
+
+impl TradeIntentGroup {
+    /// 由多腿信号组转换，任一腿为 Hold 时整组无效
+    pub fn from_signal_group(group: SignalGroup) -> Option<Self> {
+        if group.legs.is_empty() {
+            return None;
+        }
+
+        let legs = group
+            .legs
+            .into_iter()
+            .map(TradeIntent::from_signal)
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            id: group.id,
+            strategy_id: group.strategy_id,
+            legs,
+            created_at: group.created_at,
+        })
+    }
+
+    /// 转换为跨服务信号事件（整组一条，顶层字段为第一腿的摘要）
+    pub fn to_signal_event(&self, reference_price: Decimal) -> Option<SignalEvent> {
+        let first = self.legs.first()?;
+        Some(SignalEvent {
+            id: self.id,
+            created_at: self.created_at,
+            legs: self.legs.iter().map(TradeIntent::to_signal_leg).collect(),
+            ..first.to_signal_event(reference_price)
+        })
+    }
 }
 
 // ============================================================================
@@ -375,7 +602,7 @@ async fn runtime_task_loop<E: StrategyExecutor>(
     }
 }
 
-fn extract_panic_message(panic_info: &Box<dyn std::any::Any + Send>) -> String {
+pub(crate) fn extract_panic_message(panic_info: &Box<dyn std::any::Any + Send>) -> String {
     if let Some(s) = panic_info.downcast_ref::<&str>() {
         s.to_string()
     } else if let Some(s) = panic_info.downcast_ref::<String>() {
@@ -430,6 +657,8 @@ mod tests {
                 request_id: request.request_id,
                 has_intent: false,
                 intent: None,
+                group: None,
+                quotes: None,
                 execution_time_us: 0,
                 error: None,
             })
@@ -0,0 +1,341 @@
+//! # 定时触发 (Strategy Timers)
+//!
+//! 策略通过 `Strategy::timers` 声明定时计划，到期时回调 `on_timer`：
+//! - 固定间隔：按 UTC 纪元对齐到 `every_secs` 的整数倍再加 `offset_secs`，
+//!   例如每 8 小时结算的资金费、结算前 5 分钟：`every_secs = 28800, offset_secs = -300`
+//! - Cron：5 段 UTC 表达式 `分 时 日 月 周`，例如每周一 00:00：`0 0 * * 1`
+//!
+//! 定时器由行情时间驱动：实盘与回测都在处理每条行情前，以行情时间检查到期的定时器，
+//! 两者的触发时刻完全一致（行情稀疏时触发相应延后）。
+//!
+//! ## 规则
+//! - ✅ 首次检查时从当时开始计时，之前的计划时刻不补发
+//! - ✅ 错过的多次触发合并为一次（`scheduled_at` 为最早错过的计划时刻）
+//! - ✅ 同时到期的多个定时器按计划时刻先后回调
+//! - ❌ 不支持秒级 Cron；日与周同时限定时满足其一即可（与标准 cron 一致）
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// 定时计划
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum TimerSchedule {
+    /// 固定间隔
+    Interval {
+        /// 间隔（秒）
+        every_secs: u64,
+        /// 相对对齐时刻的偏移（秒，负数表示提前）
+        #[serde(default)]
+        offset_secs: i64,
+    },
+    /// Cron 表达式（UTC）
+    Cron {
+        /// `分 时 日 月 周`
+        expression: String,
+    },
+}
+
+impl TimerSchedule {
+    /// 固定间隔
+    pub fn every(every_secs: u64) -> Self {
+        Self::Interval {
+            every_secs,
+            offset_secs: 0,
+        }
+    }
+
+    /// 固定间隔并偏移
+    pub fn every_with_offset(every_secs: u64, offset_secs: i64) -> Self {
+        Self::Interval {
+            every_secs,
+            offset_secs,
+        }
+    }
+
+    /// Cron 表达式
+    pub fn cron(expression: impl Into<String>) -> Self {
+        Self::Cron {
+            expression: expression.into(),
+        }
+    }
+
+    /// 校验计划
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::Interval { every_secs, .. } if *every_secs == 0 => bail!("定时间隔必须大于 0"),
+            Self::Interval { .. } => Ok(()),
+            Self::Cron { expression } => CronExpr::parse(expression).map(|_| ()),
+        }
+    }
+
+    /// `after` 之后（不含）的下一个计划时刻
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Interval {
+                every_secs,
+                offset_secs,
+            } => {
+                let every = i64::try_from(*every_secs).ok().filter(|e| *e > 0)?;
+                let slot = (after.timestamp() - offset_secs).div_euclid(every) + 1;
+                DateTime::from_timestamp(slot.checked_mul(every)?.checked_add(*offset_secs)?, 0)
+            }
+            Self::Cron { expression } => CronExpr::parse(expression).ok()?.next_after(after),
+        }
+    }
+}
+
+/// 定时器声明
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimerSpec {
+    /// 定时器名称（回调时用于区分）
+    pub name: String,
+    /// 定时计划
+    pub schedule: TimerSchedule,
+}
+
+impl TimerSpec {
+    pub fn new(name: impl Into<String>, schedule: TimerSchedule) -> Self {
+        Self {
+            name: name.into(),
+            schedule,
+        }
+    }
+}
+
+/// 定时器到期事件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimerEvent {
+    /// 定时器名称
+    pub name: String,
+    /// 计划时刻
+    pub scheduled_at: DateTime<Utc>,
+    /// 实际触发时刻（行情时间）
+    pub fired_at: DateTime<Utc>,
+}
+
+/// 一组定时器及其下次触发时刻
+#[derive(Debug, Clone)]
+pub struct TimerSet {
+    timers: Vec<(TimerSpec, DateTime<Utc>)>,
+}
+
+impl TimerSet {
+    /// 从 `now` 开始计时（无效的计划被忽略）
+    pub fn new(specs: Vec<TimerSpec>, now: DateTime<Utc>) -> Self {
+        let timers = specs
+            .into_iter()
+            .filter_map(|spec| match spec.schedule.validate() {
+                Ok(()) => spec.schedule.next_after(now).map(|next| (spec, next)),
+                Err(e) => {
+                    warn!(timer = %spec.name, error = %e, "定时计划无效，已忽略");
+                    None
+                }
+            })
+            .collect();
+        Self { timers }
+    }
+
+    /// 是否没有定时器
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+
+    /// 是否有定时器到期
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.timers.iter().any(|(_, next)| *next <= now)
+    }
+
+    /// 取出到期的定时器并推进到 `now` 之后的下一个计划时刻
+    pub fn due(&mut self, now: DateTime<Utc>) -> Vec<TimerEvent> {
+        let mut events = Vec::new();
+        self.timers.retain_mut(|(spec, next)| {
+            if *next > now {
+                return true;
+            }
+            events.push(TimerEvent {
+                name: spec.name.clone(),
+                scheduled_at: *next,
+                fired_at: now,
+            });
+            match spec.schedule.next_after(now) {
+                Some(following) => {
+                    *next = following;
+                    true
+                }
+                None => false,
+            }
+        });
+        events.sort_by_key(|event| event.scheduled_at);
+        events
+    }
+}
+
+/// 解析后的 Cron 表达式（各字段为允许取值的位集）
+#[derive(Debug, Clone, Copy)]
+struct CronExpr {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// 日字段为 `*`
+    any_day: bool,
+    /// 周字段为 `*`
+    any_weekday: bool,
+}
+
+impl CronExpr {
+    fn parse(expression: &str) -> Result<Self> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        if fields.len() != 5 {
+            bail!("Cron 表达式需要 5 段（分 时 日 月 周）: {}", expression);
+        }
+
+        let mut weekdays = parse_field(fields[4], 0, 7).context("周字段无效")?;
+        // 7 与 0 都表示周日
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+
+        Ok(Self {
+            minutes: parse_field(fields[0], 0, 59).context("分字段无效")?,
+            hours: parse_field(fields[1], 0, 23).context("时字段无效")?,
+            days: parse_field(fields[2], 1, 31).context("日字段无效")?,
+            months: parse_field(fields[3], 1, 12).context("月字段无效")?,
+            weekdays,
+            any_day: fields[2] == "*",
+            any_weekday: fields[4] == "*",
+        })
+    }
+
+    fn day_matches(&self, date: NaiveDate) -> bool {
+        let day = self.days & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().num_days_from_sunday()) != 0;
+        match (self.any_day, self.any_weekday) {
+            (true, true) => true,
+            (false, true) => day,
+            (true, false) => weekday,
+            (false, false) => day || weekday,
+        }
+    }
+
+    /// 逐级跳过不匹配的月 / 日 / 时 / 分（最多向后查找约 5 年）
+    fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let limit = start + Duration::days(366 * 5);
+        let mut t = start;
+
+        while t < limit {
+            let date = t.date_naive();
+            if self.months & (1 << date.month()) == 0 {
+                let (year, month) = if date.month() == 12 {
+                    (date.year() + 1, 1)
+                } else {
+                    (date.year(), date.month() + 1)
+                };
+                t = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?.and_utc();
+            } else if !self.day_matches(date) {
+                t = date.succ_opt()?.and_hms_opt(0, 0, 0)?.and_utc();
+            } else if self.hours & (1 << t.hour()) == 0 {
+                t = t.with_minute(0)? + Duration::hours(1);
+            } else if self.minutes & (1 << t.minute()) == 0 {
+                t += Duration::minutes(1);
+            } else {
+                return Some(t);
+            }
+        }
+        None
+    }
+}
+
+/// 解析单个字段：`*`、`a`、`a-b`、`*/n`、`a-b/n`、`a/n`，可用逗号组合
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64> {
+    let mut bits = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().context("步长无效")?),
+            None => (part, 1),
+        };
+        if step == 0 {
+            bail!("步长必须大于 0: {}", part);
+        }
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            (a.parse::<u32>()?, b.parse::<u32>()?)
+        } else {
+            let value = range.parse::<u32>()?;
+            (value, if part.contains('/') { max } else { value })
+        };
+        if start < min || end > max || start > end {
+            bail!("取值超出范围 {}-{}: {}", min, max, part);
+        }
+
+        for value in (start..=end).step_by(step as usize) {
+            bits |= 1 << value;
+        }
+    }
+    Ok(bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_next_after() {
+        // 资金费结算（00/08/16 点）前 5 分钟
+        let funding = TimerSchedule::every_with_offset(8 * 3600, -300);
+        assert_eq!(funding.next_after(at(2024, 1, 1, 3, 0)), Some(at(2024, 1, 1, 7, 55)));
+        assert_eq!(funding.next_after(at(2024, 1, 1, 7, 55)), Some(at(2024, 1, 1, 15, 55)));
+
+        // 每周一 00:00（2024-01-03 为周三）
+        let weekly = TimerSchedule::cron("0 0 * * 1");
+        assert_eq!(weekly.next_after(at(2024, 1, 3, 12, 0)), Some(at(2024, 1, 8, 0, 0)));
+
+        // 每月 1 日与 15 日 09:30，以及跨年
+        let monthly = TimerSchedule::cron("30 9 1,15 * *");
+        assert_eq!(monthly.next_after(at(2024, 12, 20, 0, 0)), Some(at(2025, 1, 1, 9, 30)));
+
+        // 工作日每 15 分钟
+        let quarter = TimerSchedule::cron("*/15 * * * 1-5");
+        assert_eq!(quarter.next_after(at(2024, 1, 5, 23, 50)), Some(at(2024, 1, 8, 0, 0)));
+
+        assert!(TimerSchedule::cron("0 0 * *").validate().is_err());
+        assert!(TimerSchedule::cron("61 0 * * *").validate().is_err());
+        assert!(TimerSchedule::every(0).validate().is_err());
+    }
+
+    #[test]
+    fn test_timer_set_coalesces_missed_fires() {
+        let mut timers = TimerSet::new(
+            vec![
+                TimerSpec::new("hourly", TimerSchedule::every(3600)),
+                TimerSpec::new("daily", TimerSchedule::cron("0 0 * * *")),
+                TimerSpec::new("invalid", TimerSchedule::cron("bad")),
+            ],
+            at(2024, 1, 1, 22, 30),
+        );
+        assert!(!timers.is_due(at(2024, 1, 1, 22, 59)));
+
+        // 停机 3 小时：hourly 错过 3 次只触发一次，daily 同时到期，按计划时刻排序
+        let now = at(2024, 1, 2, 1, 10);
+        let events = timers.due(now);
+        let names: Vec<&str> = events.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["hourly", "daily"]);
+        assert_eq!(events[0].scheduled_at, at(2024, 1, 1, 23, 0));
+        assert_eq!(events[0].fired_at, now);
+
+        assert!(timers.due(now).is_empty());
+        assert_eq!(timers.due(at(2024, 1, 2, 2, 0)).len(), 1);
+    }
+}
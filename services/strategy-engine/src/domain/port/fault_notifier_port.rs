@@ -0,0 +1,47 @@
+//! # 故障通知端口 (Fault Notifier Port)
+//!
+//! 定义实例永久故障时发出通知的抽象接口。
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::model::failure_record::FailureRecord;
+
+/// 永久故障通知
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FaultNotification {
+    /// 策略实例 ID
+    pub instance_id: Uuid,
+    /// 所有者用户 ID
+    pub owner_id: Uuid,
+    /// 策略名称
+    pub name: String,
+    /// 策略类型
+    pub strategy_type: String,
+    /// 放弃重启的原因
+    pub reason: String,
+    /// 统计窗口内已重启次数
+    pub restarts_in_window: u32,
+    /// 最近一次故障
+    pub last_failure: Option<FailureRecord>,
+    /// 进入故障状态的时间
+    pub faulted_at: Option<DateTime<Utc>>,
+    /// 通知时间
+    pub notified_at: DateTime<Utc>,
+}
+
+/// 故障通知端口 - Domain 层定义的抽象接口
+pub trait FaultNotifierPort: Send + Sync {
+    /// 发出永久故障通知
+    fn notify(&self, notification: &FaultNotification) -> bool;
+}
+
+// Arc<T> 自动实现 FaultNotifierPort
+impl<T: FaultNotifierPort> FaultNotifierPort for Arc<T> {
+    fn notify(&self, notification: &FaultNotification) -> bool {
+        (**self).notify(notification)
+    }
+}
@@ -23,5 +23,9 @@ pub mod strategy_state_port;
 /// 策略执行器端口（句柄与逻辑分离）
 pub mod strategy_executor_port;
 
+/// 故障通知端口（永久故障告警）
+pub mod fault_notifier_port;
+
 pub use strategy_state_port::{GridStateData, MeanReversionStateData, StrategyStatePort};
 pub use strategy_executor_port::StrategyExecutorPort;
+pub use fault_notifier_port::{FaultNotification, FaultNotifierPort};
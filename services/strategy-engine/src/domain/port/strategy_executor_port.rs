@@ -6,9 +6,13 @@
 //! - Port（端口）：定义契约
 //! - 由 Infrastructure 层实现
 
-use anyhow::Result;
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use shared::event::execution_feedback_event::ExecutionFeedback;
+use uuid::Uuid;
 
-use crate::domain::model::strategy_runtime::{ExecutionRequest, ExecutionResult};
+use crate::domain::model::strategy_checkpoint::StrategyCheckpoint;
+use crate::domain::model::strategy_runtime::{ExecutionRequest, ExecutionResult, TradeIntent};
 
 /// 策略执行器端口
 ///
@@ -22,4 +26,76 @@ pub trait StrategyExecutorPort: Send + Sync {
 
     /// 获取状态快照
     fn state_snapshot(&self) -> Result<serde_json::Value>;
+
+    /// 生成状态检查点
+    ///
+    /// 默认实现：无状态（返回 None）
+    fn checkpoint(&self) -> Result<Option<StrategyCheckpoint>> {
+        Ok(None)
+    }
+
+    /// 从检查点恢复状态（必要时先做版本迁移）
+    ///
+    /// 默认实现：忽略
+    #[allow(unused_variables)]
+    fn restore(&self, checkpoint: StrategyCheckpoint) -> Result<()> {
+        Ok(())
+    }
+
+    /// 额外订阅的交易对（元数据中的交易对之外）
+    ///
+    /// 默认实现：无
+    fn subscriptions(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// 组合成员实例（组合策略）
+    ///
+    /// 默认实现：不是组合策略
+    fn ensemble_members(&self) -> Vec<Uuid> {
+        Vec::new()
+    }
+
+    /// 综合成员意图，产出一个综合意图
+    ///
+    /// 默认实现：不是组合策略，返回错误
+    #[allow(unused_variables)]
+    fn execute_ensemble(
+        &self,
+        request: &ExecutionRequest,
+        member_intents: &[TradeIntent],
+    ) -> Result<ExecutionResult> {
+        bail!("策略不是组合策略")
+    }
+
+    /// 是否有定时器到期（`now` 为行情时间）
+    ///
+    /// 默认实现：无定时器
+    #[allow(unused_variables)]
+    fn timers_due(&self, now: DateTime<Utc>) -> bool {
+        false
+    }
+
+    /// 触发到期的定时器，返回产生意图的结果（每个定时器至多一个）
+    ///
+    /// 默认实现：无定时器
+    #[allow(unused_variables)]
+    fn fire_timers(&self, now: DateTime<Utc>) -> Result<Vec<ExecutionResult>> {
+        Ok(Vec::new())
+    }
+
+    /// 策略使用的模型版本（写入策略元数据）
+    ///
+    /// 默认实现：无模型
+    fn model_version(&self) -> Option<String> {
+        None
+    }
+
+    /// 投递执行回报（成交 / 拒单 / 持仓快照）
+    ///
+    /// 默认实现：忽略
+    #[allow(unused_variables)]
+    fn on_feedback(&self, symbol: &str, feedback: &ExecutionFeedback) -> Result<()> {
+        Ok(())
+    }
 }
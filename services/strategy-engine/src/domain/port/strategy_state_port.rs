@@ -5,6 +5,7 @@
 //! ## 职责
 //! - 读取策略状态
 //! - 保存策略状态
+//! - 读写通用的版本化检查点（任意策略的热恢复）
 //!
 //! ## 存储位置
 //! 按架构规范，短期状态存储在 Redis
@@ -14,6 +15,8 @@ use async_trait::async_trait;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::domain::model::strategy_checkpoint::StrategyCheckpoint;
+
 /// 网格策略状态（可序列化）
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct GridStateData {
@@ -79,7 +82,24 @@ pub trait StrategyStatePort: Send + Sync {
         state: &MeanReversionStateData,
     ) -> Result<()>;
 
-    /// 删除策略状态
+    /// 获取策略检查点
+    ///
+    /// # 参数
+    /// - `strategy_id`: 策略实例 ID
+    ///
+    /// # 返回
+    /// - `Ok(Some(checkpoint))`: 找到检查点
+    /// - `Ok(None)`: 检查点不存在（首次运行或已过期）
+    /// - `Err`: 存储错误
+    async fn load_checkpoint(&self, strategy_id: &str) -> Result<Option<StrategyCheckpoint>>;
+
+    /// 保存策略检查点（覆盖同一实例的旧检查点）
+    ///
+    /// # 参数
+    /// - `checkpoint`: 策略检查点
+    async fn save_checkpoint(&self, checkpoint: &StrategyCheckpoint) -> Result<()>;
+
+    /// 删除策略状态（含检查点）
     ///
     /// # 参数
     /// - `strategy_id`: 策略实例 ID
@@ -16,17 +16,20 @@
 use std::sync::Arc;
 
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
 use dashmap::DashMap;
+use serde::Serialize;
+use shared::event::execution_feedback_event::ExecutionFeedback;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
-use crate::domain::model::lifecycle_state::LifecycleState;
+use crate::domain::model::lifecycle_state::{LifecycleAction, LifecycleState};
 use crate::domain::model::strategy_handle::StrategyHandle;
 use crate::domain::model::strategy_metadata::{MarketType, StrategyKind};
-use crate::domain::model::{ExecutionRequest, ExecutionResult};
+use crate::domain::model::{ExecutionRequest, ExecutionResult, TradeIntent};
 
 /// 注册表统计信息
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct RegistryStats {
     pub total: usize,
     pub running: usize,
@@ -62,7 +65,7 @@ impl StrategyQuery {
         let metadata = handle.metadata();
         if let Some(owner_id) = self.owner_id { if metadata.owner_id != owner_id { return false; } }
         if let Some(market_type) = self.market_type { if metadata.market_type != market_type { return false; } }
-        if let Some(ref symbol) = self.symbol { if metadata.symbol != *symbol { return false; } }
+        if let Some(ref symbol) = self.symbol { if !handle.subscribes(symbol) { return false; } }
         if let Some(ref kind) = self.kind { if metadata.kind != *kind { return false; } }
         if let Some(state) = self.lifecycle_state { if handle.lifecycle_state() != state { return false; } }
         if let Some(ref tags) = self.tags {
@@ -152,6 +155,29 @@ impl StrategyRegistry {
         handle.execute(request)
     }
 
+    /// 投递执行回报（委托给 Handle）
+    pub fn dispatch_feedback(&self, instance_id: Uuid, symbol: &str, feedback: &ExecutionFeedback) -> Result<()> {
+        let handle = self.handles.get(&instance_id)
+            .ok_or_else(|| anyhow!("策略实例 {} 不存在", instance_id))?;
+        debug!(instance_id = %instance_id, symbol = %symbol, "路由执行回报");
+        handle.on_feedback(symbol, feedback)
+    }
+
+    /// 组合策略综合成员意图（委托给 Handle）
+    pub fn execute_ensemble(&self, instance_id: Uuid, request: &ExecutionRequest, member_intents: &[TradeIntent]) -> Result<ExecutionResult> {
+        let handle = self.handles.get(&instance_id)
+            .ok_or_else(|| anyhow!("策略实例 {} 不存在", instance_id))?;
+        debug!(instance_id = %instance_id, members = member_intents.len(), "路由成员意图");
+        handle.execute_ensemble(request, member_intents)
+    }
+
+    /// 触发实例到期的定时器（委托给 Handle）
+    pub fn fire_timers(&self, instance_id: Uuid, now: DateTime<Utc>) -> Result<Vec<ExecutionResult>> {
+        let handle = self.handles.get(&instance_id)
+            .ok_or_else(|| anyhow!("策略实例 {} 不存在", instance_id))?;
+        handle.fire_timers(now)
+    }
+
     /// 批量执行
     pub fn execute_batch(&self, query: &StrategyQuery, request: &ExecutionRequest) -> Vec<(Uuid, Result<ExecutionResult>)> {
         let handles = self.query(query);
@@ -205,11 +231,36 @@ impl StrategyRegistry {
     }
 
     pub fn stop_batch(&self, query: &StrategyQuery) -> Vec<(Uuid, Result<()>)> {
-        self.query(query).into_iter().map(|handle| {
+        self.apply_batch(query, LifecycleAction::Stop)
+    }
+
+    /// 执行生命周期操作
+    pub fn apply(&self, instance_id: Uuid, action: LifecycleAction) -> Result<()> {
+        match action {
+            LifecycleAction::Start => self.start(instance_id),
+            LifecycleAction::Pause => self.pause(instance_id),
+            LifecycleAction::Resume => self.resume(instance_id),
+            LifecycleAction::Stop => self.stop(instance_id),
+            LifecycleAction::Restart => self.restart(instance_id),
+        }
+    }
+
+    /// 对查询命中的实例批量执行生命周期操作，单个失败不影响其余实例
+    pub fn apply_batch(&self, query: &StrategyQuery, action: LifecycleAction) -> Vec<(Uuid, Result<()>)> {
+        let results: Vec<_> = self.query(query).into_iter().map(|handle| {
             let id = handle.instance_id();
-            let result = handle.stop().map_err(|e| anyhow!("{}", e));
-            (id, result)
-        }).collect()
+            let result = match action {
+                LifecycleAction::Start => handle.start(),
+                LifecycleAction::Pause => handle.pause(),
+                LifecycleAction::Resume => handle.resume(),
+                LifecycleAction::Stop => handle.stop(),
+                LifecycleAction::Restart => handle.restart(),
+            };
+            (id, result.map_err(|e| anyhow!("{}", e)))
+        }).collect();
+        let failed = results.iter().filter(|(_, r)| r.is_err()).count();
+        info!(action = %action, total = results.len(), failed, "批量生命周期操作完成");
+        results
     }
 
     // =========================================================================
@@ -217,8 +268,13 @@ impl StrategyRegistry {
     // =========================================================================
 
     pub fn stats(&self) -> RegistryStats {
+        self.stats_for(&StrategyQuery::all())
+    }
+
+    /// 查询命中实例的统计信息
+    pub fn stats_for(&self, query: &StrategyQuery) -> RegistryStats {
         let mut stats = RegistryStats::default();
-        for entry in self.handles.iter() {
+        for entry in self.handles.iter().filter(|entry| query.matches(entry.value())) {
             stats.total += 1;
             match entry.value().lifecycle_state() {
                 LifecycleState::Created => stats.created += 1,
@@ -239,6 +295,22 @@ impl StrategyRegistry {
         self.query(&StrategyQuery::by_state(LifecycleState::Running))
     }
 
+    /// 订阅了该交易对的运行中实例
+    pub fn running_for_symbol(&self, symbol: &str) -> Vec<Arc<StrategyHandle>> {
+        self.query(&StrategyQuery::by_state(LifecycleState::Running).with_symbol(symbol))
+    }
+
+    /// 订阅了该交易对的组合策略（任意状态）及其成员
+    pub fn ensembles_for_symbol(&self, symbol: &str) -> Vec<(Arc<StrategyHandle>, Vec<Uuid>)> {
+        self.query(&StrategyQuery::by_symbol(symbol))
+            .into_iter()
+            .filter_map(|handle| {
+                let members = handle.ensemble_members();
+                (!members.is_empty()).then_some((handle, members))
+            })
+            .collect()
+    }
+
     // =========================================================================
     // 清理
     // =========================================================================
@@ -283,6 +355,8 @@ mod tests {
                 request_id: request.request_id,
                 has_intent: false,
                 intent: None,
+                group: None,
+                quotes: None,
                 execution_time_us: 0,
                 error: None,
             })
@@ -355,6 +429,24 @@ mod tests {
         assert!(registry.unregister(instance_id).is_err());
     }
 
+    #[test]
+    fn test_dispatch_feedback() {
+        use shared::event::execution_feedback_event::PositionSnapshot;
+
+        let registry = StrategyRegistry::new();
+        let handle = create_test_handle(Uuid::new_v4(), "BTCUSDT");
+        let instance_id = handle.instance_id();
+        registry.register(handle).unwrap();
+
+        let feedback = ExecutionFeedback::PositionSnapshot(PositionSnapshot {
+            quantity: rust_decimal::Decimal::ZERO,
+            average_price: rust_decimal::Decimal::ZERO,
+        });
+        // 未启动的实例也接收回报
+        assert!(registry.dispatch_feedback(instance_id, "BTCUSDT", &feedback).is_ok());
+        assert!(registry.dispatch_feedback(Uuid::new_v4(), "BTCUSDT", &feedback).is_err());
+    }
+
     #[test]
     fn test_query_by_owner() {
         let registry = StrategyRegistry::new();
@@ -378,6 +470,50 @@ mod tests {
         assert_eq!(results.len(), 2);
     }
 
+    /// 测试用的多交易对执行器
+    struct SpreadExecutor;
+
+    impl StrategyExecutorPort for SpreadExecutor {
+        fn execute(&self, request: &ExecutionRequest) -> Result<ExecutionResult> {
+            NoopExecutor.execute(request)
+        }
+
+        fn reset(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn state_snapshot(&self) -> Result<serde_json::Value> {
+            Ok(serde_json::json!({}))
+        }
+
+        fn subscriptions(&self) -> Vec<String> {
+            vec!["BTCUSDT_PERP".to_string(), "BTCUSDT_240329".to_string()]
+        }
+    }
+
+    #[test]
+    fn test_running_for_symbol_uses_subscriptions() {
+        let registry = StrategyRegistry::new();
+        let metadata = StrategyMetadata::new(
+            StrategyKind::Custom("calendar_spread".to_string()),
+            MarketType::UsdtFutures,
+            "BTCUSDT_PERP",
+            Uuid::new_v4(),
+            "跨期套利",
+        );
+        let spread = Arc::new(StrategyHandle::new(metadata, Arc::new(SpreadExecutor)));
+        let spread_id = registry.register(spread).unwrap();
+        let single_id = registry.register(create_test_handle(Uuid::new_v4(), "BTCUSDT_PERP")).unwrap();
+        registry.start(spread_id).unwrap();
+        registry.start(single_id).unwrap();
+
+        assert_eq!(registry.running_for_symbol("BTCUSDT_PERP").len(), 2);
+        let far = registry.running_for_symbol("BTCUSDT_240329");
+        assert_eq!(far.len(), 1);
+        assert_eq!(far[0].instance_id(), spread_id);
+        assert!(registry.running_for_symbol("ETHUSDT").is_empty());
+    }
+
     #[test]
     fn test_lifecycle_management() {
         let registry = StrategyRegistry::new();
@@ -430,6 +566,34 @@ mod tests {
         assert_eq!(registry.len(), 1);
     }
 
+    #[test]
+    fn test_apply_batch_scoped_by_owner() {
+        let registry = StrategyRegistry::new();
+        let owner = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        let mine = create_test_handle(owner, "BTCUSDT");
+        let theirs = create_test_handle(other, "BTCUSDT");
+        let mine_id = mine.instance_id();
+        let theirs_id = theirs.instance_id();
+        registry.register(mine).unwrap();
+        registry.register(theirs).unwrap();
+        registry.apply(theirs_id, LifecycleAction::Start).unwrap();
+
+        let results = registry.apply_batch(&StrategyQuery::by_owner(owner), LifecycleAction::Start);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, mine_id);
+        assert!(results[0].1.is_ok());
+
+        // 非法转换逐个报告
+        let results = registry.apply_batch(&StrategyQuery::by_owner(owner), LifecycleAction::Resume);
+        assert!(results[0].1.is_err());
+
+        let stats = registry.stats_for(&StrategyQuery::by_owner(owner).with_state(LifecycleState::Running));
+        assert_eq!(stats.total, 1);
+        assert_eq!(stats.running, 1);
+        assert_eq!(registry.stats().running, 2);
+    }
+
     #[test]
     fn test_shutdown() {
         let registry = StrategyRegistry::new();
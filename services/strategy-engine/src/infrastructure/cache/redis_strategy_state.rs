@@ -5,6 +5,7 @@
 //! ## Key 格式
 //! - 网格策略: `strategy:grid:{strategy_id}`
 //! - 均值回归: `strategy:mean:{strategy_id}`
+//! - 通用检查点: `strategy:checkpoint:{strategy_id}`
 //!
 //! ## TTL
 //! 默认 24 小时过期，可配置
@@ -13,6 +14,7 @@ use anyhow::{Context, Result};
 use async_trait::async_trait;
 use redis::AsyncCommands;
 
+use crate::domain::model::strategy_checkpoint::StrategyCheckpoint;
 use crate::domain::port::strategy_state_port::{
     GridStateData, MeanReversionStateData, StrategyStatePort,
 };
@@ -67,6 +69,11 @@ impl RedisStrategyStateAdapter {
         format!("{}:mean:{}", self.key_prefix, strategy_id)
     }
 
+    /// 构建检查点 Key
+    fn checkpoint_key(&self, strategy_id: &str) -> String {
+        format!("{}:checkpoint:{}", self.key_prefix, strategy_id)
+    }
+
     /// 获取异步连接
     async fn get_connection(&self) -> Result<redis::aio::MultiplexedConnection> {
         self.client
@@ -151,14 +158,48 @@ impl StrategyStatePort for RedisStrategyStateAdapter {
         Ok(())
     }
 
+    async fn load_checkpoint(&self, strategy_id: &str) -> Result<Option<StrategyCheckpoint>> {
+        let mut conn = self.get_connection().await?;
+        let key = self.checkpoint_key(strategy_id);
+
+        let value: Option<String> = conn
+            .get(&key)
+            .await
+            .context("Redis GET 失败")?;
+
+        match value {
+            Some(json) => {
+                let checkpoint: StrategyCheckpoint = serde_json::from_str(&json)
+                    .context("反序列化 StrategyCheckpoint 失败")?;
+                Ok(Some(checkpoint))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn save_checkpoint(&self, checkpoint: &StrategyCheckpoint) -> Result<()> {
+        let mut conn = self.get_connection().await?;
+        let key = self.checkpoint_key(&checkpoint.instance_id.to_string());
+
+        let json = serde_json::to_string(checkpoint)
+            .context("序列化 StrategyCheckpoint 失败")?;
+
+        let _: () = conn.set_ex(&key, &json, self.ttl_seconds)
+            .await
+            .context("Redis SETEX 失败")?;
+
+        Ok(())
+    }
+
     async fn delete_state(&self, strategy_id: &str) -> Result<()> {
         let mut conn = self.get_connection().await?;
         let grid_key = self.grid_key(strategy_id);
         let mean_key = self.mean_key(strategy_id);
+        let checkpoint_key = self.checkpoint_key(strategy_id);
 
-        // 删除两种类型的 Key
+        // 删除全部类型的 Key
         let _: () = conn
-            .del(&[&grid_key, &mean_key])
+            .del(&[&grid_key, &mean_key, &checkpoint_key])
             .await
             .context("Redis DEL 失败")?;
 
@@ -0,0 +1,65 @@
+//! 永久故障通知的 Kafka 适配器。
+
+use std::time::Duration;
+
+use anyhow::Context;
+use rdkafka::config::ClientConfig;
+use rdkafka::producer::{BaseProducer, BaseRecord, Producer};
+use tracing::{error, warn};
+
+use crate::domain::port::fault_notifier_port::{FaultNotification, FaultNotifierPort};
+
+pub struct KafkaFaultNotifier {
+    topic: String,
+    producer: BaseProducer,
+}
+
+impl KafkaFaultNotifier {
+    pub fn new(brokers: &str, topic: impl Into<String>) -> anyhow::Result<Self> {
+        let producer: BaseProducer = ClientConfig::new()
+            .set("bootstrap.servers", brokers)
+            .set("message.timeout.ms", "5000")
+            .create()
+            .context("failed to create kafka producer")?;
+
+        Ok(Self {
+            topic: topic.into(),
+            producer,
+        })
+    }
+}
+
+impl FaultNotifierPort for KafkaFaultNotifier {
+    fn notify(&self, notification: &FaultNotification) -> bool {
+        // 告警同时落日志，Kafka 不可用时也能追溯
+        warn!(
+            instance_id = %notification.instance_id,
+            owner_id = %notification.owner_id,
+            reason = %notification.reason,
+            restarts_in_window = notification.restarts_in_window,
+            "Strategy permanently faulted"
+        );
+
+        let payload = match serde_json::to_string(notification) {
+            Ok(value) => value,
+            Err(err) => {
+                error!(error = %err, "serialize fault notification failed");
+                return false;
+            }
+        };
+        let key = notification.instance_id.to_string();
+
+        let record = BaseRecord::to(&self.topic).payload(&payload).key(&key);
+
+        match self.producer.send(record) {
+            Ok(_) => {
+                let _ = self.producer.flush(Duration::from_secs(5));
+                true
+            }
+            Err((err, _)) => {
+                error!(error = %err, "kafka send failed");
+                false
+            }
+        }
+    }
+}
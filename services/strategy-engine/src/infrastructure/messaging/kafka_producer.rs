@@ -9,7 +9,9 @@ use tracing::error;
 
 use crate::domain::model::signal::{Signal, SignalType};
 use crate::domain::port::message_port::SignalMessagePort;
-use shared::event::signal_event::{SignalEvent, SignalType as EventSignalType};
+use shared::event::signal_event::{
+    SignalEvent, SignalType as EventSignalType, SIGNAL_EVENT_SCHEMA_VERSION,
+};
 
 pub struct KafkaProducer {
     #[allow(dead_code)]
@@ -38,6 +40,7 @@ impl KafkaProducer {
 impl SignalMessagePort for KafkaProducer {
     fn publish_signal(&self, signal: &Signal) -> bool {
         let event = SignalEvent {
+            schema_version: SIGNAL_EVENT_SCHEMA_VERSION,
             id: signal.id,
             strategy_id: signal.strategy_id,
             symbol: signal.symbol.clone(),
@@ -50,6 +53,8 @@ impl SignalMessagePort for KafkaProducer {
             quantity: signal.quantity,
             confidence: signal.confidence,
             created_at: signal.created_at,
+            instruction: signal.instruction.clone(),
+            legs: Vec::new(),
         };
 
         let payload = match serde_json::to_string(&event) {
@@ -9,6 +9,10 @@ pub mod kafka_consumer;
 /// Mock 消费者 - 测试用
 pub mod mock_consumer;
 
+/// Kafka 故障通知 - 发布永久故障告警
+pub mod kafka_fault_notifier;
+
 pub use kafka_producer::KafkaProducer;
 pub use kafka_consumer::KafkaConsumer;
 pub use mock_consumer::MockConsumer;
+pub use kafka_fault_notifier::KafkaFaultNotifier;
@@ -12,5 +12,8 @@ pub mod strategy;
 /// 风控实现
 pub mod risk;
 
+/// 推理模型 - JSON / ONNX
+pub mod model;
+
 /// 缓存 - Redis
 pub mod cache;
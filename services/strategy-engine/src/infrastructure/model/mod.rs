@@ -0,0 +1,10 @@
+//! # 推理模型基础设施 (Model Infrastructure)
+
+/// 模型加载 - JSON / ONNX 文件
+pub mod model_loader;
+
+/// ONNX 推理 - tract
+#[cfg(feature = "onnx")]
+pub mod onnx_model;
+
+pub use model_loader::load_model;
@@ -0,0 +1,108 @@
+//! # 模型加载 (Model Loader)
+//!
+//! 从文件加载离线训练的推理模型，按扩展名选择格式：
+//! - `.json`：线性 / 树模型（见 `JsonModelFile`）
+//! - `.onnx`：需启用 `onnx` feature
+//!
+//! 模型版本优先取文件声明的版本，否则为 `文件名@内容指纹`，
+//! 同一路径下替换模型文件后版本随之变化。
+
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{bail, Context, Result};
+use tracing::info;
+
+use crate::domain::logic::ai::ml_signal::{InferenceModel, JsonInferenceModel, JsonModelFile};
+
+/// 加载推理模型
+///
+/// `input_size` 为特征维度，加载时校验与模型输入一致。
+pub fn load_model(path: &str, input_size: usize) -> Result<Arc<dyn InferenceModel>> {
+    if path.trim().is_empty() {
+        bail!("模型路径不能为空");
+    }
+    let path = Path::new(path);
+    let bytes = std::fs::read(path).with_context(|| format!("读取模型文件 {} 失败", path.display()))?;
+    let fingerprint = fingerprint(&bytes);
+    let stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("model");
+    let default_version = format!("{}@{:016x}", stem, fingerprint);
+
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+    let model: Arc<dyn InferenceModel> = match extension.as_deref() {
+        Some("json") => {
+            let file: JsonModelFile = serde_json::from_slice(&bytes)
+                .with_context(|| format!("解析模型文件 {} 失败", path.display()))?;
+            let version = file.version.unwrap_or(default_version);
+            Arc::new(JsonInferenceModel::new(file.model, version, input_size)?)
+        }
+        Some("onnx") => load_onnx(&bytes, input_size, default_version)?,
+        _ => bail!("不支持的模型格式: {}（支持 .json / .onnx）", path.display()),
+    };
+
+    info!(
+        path = %path.display(),
+        version = model.version(),
+        input_size,
+        "Model loaded"
+    );
+    Ok(model)
+}
+
+#[cfg(feature = "onnx")]
+fn load_onnx(bytes: &[u8], input_size: usize, version: String) -> Result<Arc<dyn InferenceModel>> {
+    Ok(Arc::new(super::onnx_model::OnnxModel::load(bytes, input_size, version)?))
+}
+
+#[cfg(not(feature = "onnx"))]
+fn load_onnx(_bytes: &[u8], _input_size: usize, version: String) -> Result<Arc<dyn InferenceModel>> {
+    bail!("ONNX 模型 {} 需要以 onnx feature 编译", version)
+}
+
+/// 内容指纹（FNV-1a 64 位，跨进程稳定）
+fn fingerprint(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_json_model_with_version() {
+        let dir = std::env::temp_dir().join(format!("model-loader-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let versioned = dir.join("momentum.json");
+        std::fs::write(
+            &versioned,
+            r#"{ "type": "logistic", "version": "2024-06-01", "weights": [1.0, -1.0] }"#,
+        )
+        .unwrap();
+        let model = load_model(versioned.to_str().unwrap(), 2).unwrap();
+        assert_eq!(model.version(), "2024-06-01");
+        assert!((model.predict(&[1.0, 1.0]).unwrap()[0] - 0.5).abs() < 1e-12);
+        // 特征维度与模型不一致
+        assert!(load_model(versioned.to_str().unwrap(), 3).is_err());
+
+        // 未声明版本时按文件名与内容生成
+        let unversioned = dir.join("trend.json");
+        std::fs::write(&unversioned, r#"{ "type": "logistic", "weights": [0.5] }"#).unwrap();
+        let first = load_model(unversioned.to_str().unwrap(), 1).unwrap();
+        assert!(first.version().starts_with("trend@"));
+        std::fs::write(&unversioned, r#"{ "type": "logistic", "weights": [0.7] }"#).unwrap();
+        let second = load_model(unversioned.to_str().unwrap(), 1).unwrap();
+        assert_ne!(first.version(), second.version());
+
+        assert!(load_model(dir.join("model.bin").to_str().unwrap(), 1).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,56 @@
+//! # ONNX 模型 (ONNX Model)
+//!
+//! 用 tract（纯 Rust）在进程内 CPU 推理 ONNX 模型。
+//!
+//! ## 约定
+//! - 输入：单个 float32 张量，形状 [1, 特征数]
+//! - 输出：取最后一个 float32 输出作为类别概率
+//!   （sklearn 分类器导出时需关闭 ZipMap，概率输出才是张量）
+
+use anyhow::{anyhow, Result};
+use tract_onnx::prelude::*;
+
+use crate::domain::logic::ai::ml_signal::InferenceModel;
+
+/// ONNX 推理模型
+pub struct OnnxModel {
+    plan: TypedRunnableModel<TypedModel>,
+    input_size: usize,
+    version: String,
+}
+
+impl OnnxModel {
+    /// 加载并优化计算图（固定输入形状）
+    pub fn load(bytes: &[u8], input_size: usize, version: String) -> Result<Self> {
+        let plan = tract_onnx::onnx()
+            .model_for_read(&mut std::io::Cursor::new(bytes))?
+            .with_input_fact(0, f32::fact([1, input_size]).into())?
+            .into_optimized()?
+            .into_runnable()?;
+
+        Ok(Self {
+            plan,
+            input_size,
+            version,
+        })
+    }
+}
+
+impl InferenceModel for OnnxModel {
+    fn version(&self) -> &str {
+        &self.version
+    }
+
+    fn predict(&self, features: &[f64]) -> Result<Vec<f64>> {
+        let values: Vec<f32> = features.iter().map(|v| *v as f32).collect();
+        let input = Tensor::from_shape(&[1, self.input_size], &values)?;
+        let outputs = self.plan.run(tvec!(input.into()))?;
+
+        outputs
+            .iter()
+            .rev()
+            .find_map(|output| output.as_slice::<f32>().ok())
+            .map(|probabilities| probabilities.iter().map(|p| f64::from(*p)).collect())
+            .ok_or_else(|| anyhow!("ONNX 模型 {} 没有 float32 输出", self.version))
+    }
+}
@@ -6,16 +6,25 @@
 //! - 保留现有策略代码
 //! - 适配到新的架构
 //! - 提供统一的执行接口
+//! - 持有策略的定时器（首次检查时按策略声明的计划开始计时）
 
 use std::sync::Arc;
 
-use anyhow::Result;
-use parking_lot::RwLock;
-use shared::event::market_event::{MarketEvent, MarketEventData, TradeData};
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use parking_lot::{Mutex, RwLock};
+use shared::event::execution_feedback_event::ExecutionFeedback;
+use shared::event::market_event::{MarketEvent, MarketEventData, MarketEventType, TradeData};
+use uuid::Uuid;
 
 use crate::domain::logic::strategy_trait::Strategy;
-use crate::domain::model::signal::SignalType;
-use crate::domain::model::strategy_runtime::{ExecutionRequest, ExecutionResult, TradeIntent};
+use crate::domain::model::ensemble::MemberSignal;
+use crate::domain::model::signal::SignalGroup;
+use crate::domain::model::strategy_checkpoint::StrategyCheckpoint;
+use crate::domain::model::strategy_runtime::{
+    ExecutionRequest, ExecutionResult, TradeIntent, TradeIntentGroup,
+};
+use crate::domain::model::timer::TimerSet;
 use crate::domain::port::strategy_executor_port::StrategyExecutorPort;
 
 /// 策略执行器适配器
@@ -24,6 +33,8 @@ use crate::domain::port::strategy_executor_port::StrategyExecutorPort;
 pub struct StrategyExecutorAdapter<S: Strategy> {
     /// 策略实例（使用 RwLock 实现内部可变性）
     strategy: Arc<RwLock<S>>,
+    /// 定时器（首次检查前为 None）
+    timers: Mutex<Option<TimerSet>>,
 }
 
 impl<S: Strategy> StrategyExecutorAdapter<S> {
@@ -31,22 +42,31 @@ impl<S: Strategy> StrategyExecutorAdapter<S> {
     pub fn new(strategy: S) -> Self {
         Self {
             strategy: Arc::new(RwLock::new(strategy)),
+            timers: Mutex::new(None),
         }
     }
 
-    /// 将 ExecutionRequest 转换为 MarketEvent
+    /// 将 ExecutionRequest 转换为 MarketEvent（带盘口时为深度事件）
     fn request_to_market_event(&self, request: &ExecutionRequest) -> MarketEvent {
+        let (event_type, data) = match &request.depth {
+            Some(depth) => (MarketEventType::Depth, MarketEventData::Depth(depth.clone())),
+            None => (
+                MarketEventType::Trade,
+                MarketEventData::Trade(TradeData {
+                    trade_id: request.request_id.to_string(),
+                    price: request.price,
+                    quantity: request.quantity,
+                    is_buyer_maker: request.is_buyer_maker,
+                }),
+            ),
+        };
+
         MarketEvent {
-            event_type: shared::event::market_event::MarketEventType::Trade,
+            event_type,
             exchange: "binance".to_string(),
             symbol: request.symbol.clone(),
             timestamp: request.timestamp,
-            data: MarketEventData::Trade(TradeData {
-                trade_id: request.request_id.to_string(),
-                price: request.price,
-                quantity: request.quantity,
-                is_buyer_maker: request.is_buyer_maker,
-            }),
+            data,
         }
     }
 }
@@ -58,47 +78,127 @@ impl<S: Strategy + Send + Sync + 'static> StrategyExecutorPort for StrategyExecu
         // 转换请求为行情事件
         let market_event = self.request_to_market_event(request);
 
-        // 执行策略
-        let signal = {
+        // 执行策略（多腿策略返回多条腿，做市策略返回报价指令）
+        let (group, quotes) = {
             let mut strategy = self.strategy.write();
-            strategy.on_market_event(&market_event)
+            let group = strategy.on_market_event_legs(&market_event);
+            let quotes = strategy.on_market_event_quotes(&market_event);
+            if let Some(fault) = strategy.take_fault() {
+                bail!("策略执行故障: {}", fault);
+            }
+            (group, quotes)
         };
 
-        // 转换信号为交易意图
-        let intent = signal.and_then(|sig| {
-            let side = match sig.signal_type {
-                SignalType::Buy => shared::types::order::OrderSide::Buy,
-                SignalType::Sell => shared::types::order::OrderSide::Sell,
-                SignalType::Hold => return None, // Hold信号不生成交易意图
-            };
-
-            Some(TradeIntent {
-                id: sig.id,
-                strategy_id: sig.strategy_id,
-                symbol: sig.symbol,
-                side,
-                quantity: sig.quantity,
-                price: Some(sig.price),
-                order_type: shared::types::order::OrderType::Limit,
-                confidence: sig.confidence,
-                created_at: sig.created_at,
-            })
-        });
+        let (intent, group) = split_signal_group(group);
 
         let execution_time_us = start.elapsed().as_micros() as u64;
 
         Ok(ExecutionResult {
             request_id: request.request_id,
-            has_intent: intent.is_some(),
+            has_intent: intent.is_some() || group.is_some() || quotes.is_some(),
             intent,
+            group,
+            quotes,
             execution_time_us,
             error: None,
         })
     }
 
+    fn execute_ensemble(
+        &self,
+        request: &ExecutionRequest,
+        member_intents: &[TradeIntent],
+    ) -> Result<ExecutionResult> {
+        let start = std::time::Instant::now();
+
+        let market_event = self.request_to_market_event(request);
+        let signals: Vec<MemberSignal> = member_intents.iter().map(MemberSignal::from_intent).collect();
+
+        let decision = {
+            let mut strategy = self.strategy.write();
+            let decision = strategy.on_member_signals(&market_event, &signals);
+            if let Some(fault) = strategy.take_fault() {
+                bail!("组合策略执行故障: {}", fault);
+            }
+            decision
+        };
+
+        // 综合意图附带成员归因
+        let intent = decision.and_then(|decision| {
+            TradeIntent::from_signal(decision.signal)
+                .map(|intent| intent.with_attribution(decision.attribution))
+        });
+
+        Ok(ExecutionResult {
+            request_id: request.request_id,
+            has_intent: intent.is_some(),
+            intent,
+            group: None,
+            quotes: None,
+            execution_time_us: start.elapsed().as_micros() as u64,
+            error: None,
+        })
+    }
+
+    fn timers_due(&self, now: DateTime<Utc>) -> bool {
+        self.timers
+            .lock()
+            .get_or_insert_with(|| TimerSet::new(self.strategy.read().timers(), now))
+            .is_due(now)
+    }
+
+    fn fire_timers(&self, now: DateTime<Utc>) -> Result<Vec<ExecutionResult>> {
+        let events = self
+            .timers
+            .lock()
+            .get_or_insert_with(|| TimerSet::new(self.strategy.read().timers(), now))
+            .due(now);
+
+        let mut results = Vec::new();
+        for event in events {
+            let start = std::time::Instant::now();
+            let group = {
+                let mut strategy = self.strategy.write();
+                let group = strategy.on_timer(&event);
+                if let Some(fault) = strategy.take_fault() {
+                    bail!("定时器 {} 回调故障: {}", event.name, fault);
+                }
+                group
+            };
+
+            let (intent, group) = split_signal_group(group);
+            if intent.is_none() && group.is_none() {
+                continue;
+            }
+            results.push(ExecutionResult {
+                request_id: Uuid::new_v4(),
+                has_intent: true,
+                intent,
+                group,
+                quotes: None,
+                execution_time_us: start.elapsed().as_micros() as u64,
+                error: None,
+            });
+        }
+        Ok(results)
+    }
+
+    fn subscriptions(&self) -> Vec<String> {
+        self.strategy.read().subscriptions()
+    }
+
+    fn ensemble_members(&self) -> Vec<Uuid> {
+        self.strategy.read().ensemble_members()
+    }
+
+    fn model_version(&self) -> Option<String> {
+        self.strategy.read().model_version()
+    }
+
     fn reset(&self) -> Result<()> {
         let mut strategy = self.strategy.write();
         strategy.reset();
+        *self.timers.lock() = None;
         Ok(())
     }
 
@@ -114,6 +214,79 @@ impl<S: Strategy + Send + Sync + 'static> StrategyExecutorPort for StrategyExecu
             "is_active": meta.is_active,
         }))
     }
+
+    fn checkpoint(&self) -> Result<Option<StrategyCheckpoint>> {
+        let strategy = self.strategy.read();
+        let meta = strategy.meta();
+
+        Ok(strategy.export_state().map(|state| {
+            StrategyCheckpoint::new(
+                meta.instance_id,
+                meta.strategy_type.clone(),
+                strategy.state_version(),
+                state,
+            )
+        }))
+    }
+
+    fn restore(&self, checkpoint: StrategyCheckpoint) -> Result<()> {
+        let mut strategy = self.strategy.write();
+        let current_version = strategy.state_version();
+
+        if checkpoint.strategy_type != strategy.meta().strategy_type {
+            bail!(
+                "检查点策略类型 {} 与实例类型 {} 不一致",
+                checkpoint.strategy_type,
+                strategy.meta().strategy_type
+            );
+        }
+        if checkpoint.schema_version > current_version {
+            bail!(
+                "检查点版本 v{} 高于当前版本 v{}",
+                checkpoint.schema_version,
+                current_version
+            );
+        }
+
+        // 逐级迁移到当前版本
+        let mut state = checkpoint.state;
+        for version in checkpoint.schema_version..current_version {
+            state = strategy
+                .migrate_state(version, state)
+                .with_context(|| format!("状态迁移失败: v{} -> v{}", version, version + 1))?;
+        }
+
+        strategy.import_state(state).context("恢复策略状态失败")
+    }
+
+    fn on_feedback(&self, symbol: &str, feedback: &ExecutionFeedback) -> Result<()> {
+        let mut strategy = self.strategy.write();
+        match feedback {
+            ExecutionFeedback::Fill(fill) => strategy.on_fill(symbol, fill),
+            ExecutionFeedback::OrderRejected(rejection) => {
+                strategy.on_order_rejected(symbol, rejection)
+            }
+            ExecutionFeedback::PositionSnapshot(snapshot) => {
+                strategy.on_position_snapshot(symbol, snapshot)
+            }
+        }
+        if let Some(fault) = strategy.take_fault() {
+            bail!("策略处理执行回报故障: {}", fault);
+        }
+        Ok(())
+    }
+}
+
+/// 转换信号为交易意图：单腿为普通意图，多腿为意图组
+fn split_signal_group(group: Option<SignalGroup>) -> (Option<TradeIntent>, Option<TradeIntentGroup>) {
+    match group {
+        Some(group) if group.is_single() => {
+            let intent = group.legs.into_iter().next().and_then(TradeIntent::from_signal);
+            (intent, None)
+        }
+        Some(group) => (None, TradeIntentGroup::from_signal_group(group)),
+        None => (None, None),
+    }
 }
 
 #[cfg(test)]
@@ -127,6 +300,7 @@ mod tests {
     struct TestStrategy {
         instance_id: Uuid,
         call_count: u32,
+        position: Option<Decimal>,
     }
 
     impl Strategy for TestStrategy {
@@ -143,6 +317,14 @@ mod tests {
             None
         }
 
+        fn on_position_snapshot(
+            &mut self,
+            _symbol: &str,
+            snapshot: &shared::event::execution_feedback_event::PositionSnapshot,
+        ) {
+            self.position = Some(snapshot.quantity);
+        }
+
         fn reset(&mut self) {
             self.call_count = 0;
         }
@@ -153,6 +335,7 @@ mod tests {
         let strategy = TestStrategy {
             instance_id: Uuid::new_v4(),
             call_count: 0,
+            position: None,
         };
         let adapter = StrategyExecutorAdapter::new(strategy);
 
@@ -163,6 +346,7 @@ mod tests {
             quantity: Decimal::new(1, 3),
             timestamp: Utc::now(),
             is_buyer_maker: false,
+            depth: None,
         };
 
         let result = adapter.execute(&request);
@@ -174,10 +358,129 @@ mod tests {
         let strategy = TestStrategy {
             instance_id: Uuid::new_v4(),
             call_count: 0,
+            position: None,
         };
         let adapter = StrategyExecutorAdapter::new(strategy);
 
         let result = adapter.reset();
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_adapter_routes_position_snapshot() {
+        use shared::event::execution_feedback_event::PositionSnapshot;
+
+        let strategy = TestStrategy {
+            instance_id: Uuid::new_v4(),
+            call_count: 0,
+            position: None,
+        };
+        let adapter = StrategyExecutorAdapter::new(strategy);
+
+        let feedback = ExecutionFeedback::PositionSnapshot(PositionSnapshot {
+            quantity: Decimal::new(-5, 1),
+            average_price: Decimal::new(50000, 0),
+        });
+        assert!(adapter.on_feedback("BTCUSDT", &feedback).is_ok());
+        assert_eq!(adapter.strategy.read().position, Some(Decimal::new(-5, 1)));
+    }
+
+    // 测试用的有状态策略：v1 状态字段为 `count`，v2 改名为 `seen`
+    struct CountingStrategy {
+        meta: crate::domain::logic::strategy_trait::StrategyMeta,
+        seen: u64,
+    }
+
+    impl CountingStrategy {
+        fn new() -> Self {
+            Self {
+                meta: crate::domain::logic::strategy_trait::StrategyMeta {
+                    instance_id: Uuid::new_v4(),
+                    strategy_type: "counting".to_string(),
+                    market_type: crate::domain::model::market_type::MarketType::Spot,
+                    symbol: "BTCUSDT".to_string(),
+                    is_active: true,
+                },
+                seen: 0,
+            }
+        }
+    }
+
+    impl Strategy for CountingStrategy {
+        fn meta(&self) -> &crate::domain::logic::strategy_trait::StrategyMeta {
+            &self.meta
+        }
+
+        fn meta_mut(&mut self) -> &mut crate::domain::logic::strategy_trait::StrategyMeta {
+            &mut self.meta
+        }
+
+        fn on_market_event(&mut self, _event: &MarketEvent) -> Option<crate::domain::model::signal::Signal> {
+            self.seen += 1;
+            None
+        }
+
+        fn state_version(&self) -> u32 {
+            2
+        }
+
+        fn export_state(&self) -> Option<serde_json::Value> {
+            Some(serde_json::json!({ "seen": self.seen }))
+        }
+
+        fn import_state(&mut self, state: serde_json::Value) -> Result<()> {
+            self.seen = state["seen"].as_u64().context("missing seen")?;
+            Ok(())
+        }
+
+        fn migrate_state(&self, from_version: u32, state: serde_json::Value) -> Result<serde_json::Value> {
+            match from_version {
+                1 => Ok(serde_json::json!({ "seen": state["count"] })),
+                _ => bail!("unknown version {}", from_version),
+            }
+        }
+
+        fn reset(&mut self) {
+            self.seen = 0;
+        }
+    }
+
+    #[test]
+    fn test_adapter_checkpoint_roundtrip() {
+        let adapter = StrategyExecutorAdapter::new(CountingStrategy::new());
+        adapter.strategy.write().seen = 7;
+
+        let checkpoint = adapter.checkpoint().unwrap().expect("stateful strategy");
+        assert_eq!(checkpoint.schema_version, 2);
+
+        adapter.reset().unwrap();
+        adapter.restore(checkpoint).unwrap();
+        assert_eq!(adapter.strategy.read().seen, 7);
+    }
+
+    #[test]
+    fn test_adapter_restore_migrates_old_version() {
+        let adapter = StrategyExecutorAdapter::new(CountingStrategy::new());
+        let instance_id = adapter.strategy.read().meta.instance_id;
+
+        let checkpoint =
+            StrategyCheckpoint::new(instance_id, "counting", 1, serde_json::json!({ "count": 3 }));
+        adapter.restore(checkpoint).unwrap();
+        assert_eq!(adapter.strategy.read().seen, 3);
+    }
+
+    #[test]
+    fn test_adapter_restore_rejects_newer_version_and_other_type() {
+        let adapter = StrategyExecutorAdapter::new(CountingStrategy::new());
+        let instance_id = adapter.strategy.read().meta.instance_id;
+
+        let newer =
+            StrategyCheckpoint::new(instance_id, "counting", 3, serde_json::json!({ "seen": 1 }));
+        assert!(adapter.restore(newer).is_err());
+
+        let other =
+            StrategyCheckpoint::new(instance_id, "spot_grid", 2, serde_json::json!({ "seen": 1 }));
+        assert!(adapter.restore(other).is_err());
+        assert_eq!(adapter.strategy.read().seen, 0);
+    }
 }
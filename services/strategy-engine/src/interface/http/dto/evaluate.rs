@@ -34,6 +34,9 @@ pub struct EvaluateResponse {
     /// 交易意图（可选）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub intent: Option<OrderIntentDto>,
+    /// 多腿意图（多腿策略产出时 intent 为空，各腿作为关联组整体执行）
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub legs: Vec<OrderIntentDto>,
 }
 
 /// 交易意图 DTO
@@ -52,10 +55,27 @@ pub struct OrderIntentDto {
     /// 价格（限价单）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub price: Option<Decimal>,
-    /// 订单类型: "market" / "limit"
+    /// 订单类型: "market" / "limit" / "stop_loss" / "take_profit"
     pub order_type: String,
     /// 置信度 (0.0 - 1.0)
     pub confidence: f64,
     /// 创建时间（毫秒）
     pub created_at: i64,
+    /// 仓位动作: "open" / "close" / "reduce" / "flip"
+    pub action: String,
+    /// 持仓方向: "long" / "short"（双向持仓模式）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_side: Option<String>,
+    /// 有效期: "gtc" / "ioc" / "fok" / "post_only"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<String>,
+    /// 附带止损触发价
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss: Option<Decimal>,
+    /// 附带止盈触发价
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit: Option<Decimal>,
+    /// 过期时间（毫秒）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
 }
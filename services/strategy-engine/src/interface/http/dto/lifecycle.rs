@@ -0,0 +1,317 @@
+//! # 策略生命周期 DTO
+//!
+//! 定义单实例控制、批量控制、统计与历史查询 API 的请求/响应结构。
+//!
+//! ## 规则
+//! - ✅ 所有接口按调用方身份限定范围，他人的实例视为不存在
+//! - ✅ 调用方身份只取网关认证后注入的 `x-user-id` 请求头，不信任客户端自报的 `owner_id`
+//! - ✅ 批量操作逐个返回结果，单个失败不影响其余实例
+
+use std::convert::Infallible;
+
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::http::HeaderMap;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::model::failure_record::FailureRecord;
+use crate::domain::model::lifecycle_state::{LifecycleAction, LifecycleTransition};
+use crate::domain::model::restart_policy::RestartPolicy;
+use crate::domain::model::strategy_handle::StrategyHandle;
+use crate::domain::model::ExecutionResult;
+use crate::domain::service::strategy_registry::RegistryStats;
+
+/// 网关认证后注入的用户ID请求头
+pub const USER_ID_HEADER: &str = "x-user-id";
+
+/// 所有者范围（网关认证后注入的 `x-user-id` 请求头）
+///
+/// 请求头缺失或不是合法 UUID 时 `owner_id` 为 None，由处理函数返回错误。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OwnerScope {
+    /// 已认证的用户ID
+    pub owner_id: Option<Uuid>,
+}
+
+impl OwnerScope {
+    /// 从请求头解析调用方身份
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let owner_id = headers
+            .get(USER_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| Uuid::parse_str(v.trim()).ok());
+        Self { owner_id }
+    }
+
+    /// 已认证的用户ID，缺失时返回错误信息
+    pub fn require(&self) -> Result<Uuid, String> {
+        self.owner_id
+            .ok_or_else(|| format!("missing or invalid {} header", USER_ID_HEADER))
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for OwnerScope {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::from_headers(&parts.headers))
+    }
+}
+
+/// 策略筛选条件（范围始终限定为调用方自己的实例）
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StrategyFilter {
+    /// 交易对
+    pub symbol: Option<String>,
+    /// 生命周期状态: "created", "running", "paused", "stopped", "faulted"
+    pub state: Option<String>,
+}
+
+/// 生命周期操作响应
+#[derive(Debug, Clone, Serialize)]
+pub struct LifecycleResponse {
+    /// 策略实例 ID
+    pub instance_id: Uuid,
+    /// 执行的操作
+    pub action: LifecycleAction,
+    /// 操作后的状态
+    pub state: String,
+}
+
+/// 批量生命周期操作请求
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchLifecycleRequest {
+    /// 操作: "start", "pause", "resume", "stop", "restart"
+    pub action: LifecycleAction,
+    /// 筛选条件
+    #[serde(flatten)]
+    pub filter: StrategyFilter,
+}
+
+/// 批量操作的单个实例结果
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchItemResult {
+    /// 策略实例 ID
+    pub instance_id: Uuid,
+    /// 是否成功
+    pub success: bool,
+    /// 错误信息
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 批量生命周期操作响应
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchLifecycleResponse {
+    /// 执行的操作
+    pub action: LifecycleAction,
+    /// 命中实例数
+    pub total: usize,
+    /// 成功数
+    pub succeeded: usize,
+    /// 失败数
+    pub failed: usize,
+    /// 各实例结果
+    pub results: Vec<BatchItemResult>,
+}
+
+impl BatchLifecycleResponse {
+    /// 由注册表批量操作结果构造
+    pub fn from_results(action: LifecycleAction, results: Vec<(Uuid, anyhow::Result<()>)>) -> Self {
+        let results = results
+            .into_iter()
+            .map(|(instance_id, result)| BatchItemResult {
+                instance_id,
+                success: result.is_ok(),
+                error: result.err().map(|e| e.to_string()),
+            })
+            .collect::<Vec<_>>();
+        let succeeded = results.iter().filter(|r| r.success).count();
+        Self {
+            action,
+            total: results.len(),
+            succeeded,
+            failed: results.len() - succeeded,
+            results,
+        }
+    }
+}
+
+/// 批量执行请求
+///
+/// 把一笔行情投递给调用方订阅了该交易对的全部运行中实例。
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchExecuteRequest {
+    /// 交易对
+    pub symbol: String,
+    /// 成交价格
+    pub price: Decimal,
+    /// 成交数量
+    pub quantity: Decimal,
+    /// 是否为卖方主动成交
+    #[serde(default)]
+    pub is_buyer_maker: bool,
+}
+
+/// 批量执行的单个实例结果
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchExecuteItem {
+    /// 策略实例 ID
+    pub instance_id: Uuid,
+    /// 执行结果
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<ExecutionResult>,
+    /// 错误信息
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 批量执行响应
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchExecuteResponse {
+    /// 命中实例数
+    pub total: usize,
+    /// 产生交易意图的实例数
+    pub with_intent: usize,
+    /// 各实例结果
+    pub results: Vec<BatchExecuteItem>,
+}
+
+/// 策略健康状况
+#[derive(Debug, Clone, Serialize)]
+pub struct StrategyHealthDto {
+    /// 策略实例 ID
+    pub instance_id: Uuid,
+    /// 策略名称
+    pub name: String,
+    /// 生命周期状态
+    pub state: String,
+    /// 执行次数
+    pub execution_count: u64,
+    /// 成功次数
+    pub success_count: u64,
+    /// 故障次数
+    pub failure_count: u64,
+    /// 连续故障次数
+    pub consecutive_failures: u32,
+    /// 成功率
+    pub success_rate: f64,
+    /// 最后执行时间
+    pub last_execution_at: Option<DateTime<Utc>>,
+    /// 最近一次故障
+    pub last_failure: Option<FailureRecord>,
+    /// 参数更新观察期剩余事件数
+    pub probation_remaining: Option<u32>,
+}
+
+impl StrategyHealthDto {
+    /// 由策略句柄构造
+    pub fn from_handle(handle: &StrategyHandle) -> Self {
+        let stats = handle.stats();
+        Self {
+            instance_id: handle.instance_id(),
+            name: handle.metadata().name,
+            state: handle.lifecycle_state().to_string(),
+            execution_count: stats.execution_count,
+            success_count: stats.success_count,
+            failure_count: stats.failure_count,
+            consecutive_failures: stats.consecutive_failures,
+            success_rate: stats.success_rate(),
+            last_execution_at: stats.last_execution_at,
+            last_failure: handle.failure_history().last().cloned(),
+            probation_remaining: handle.probation_remaining(),
+        }
+    }
+}
+
+/// 策略详情响应
+#[derive(Debug, Clone, Serialize)]
+pub struct StrategyDetailResponse {
+    /// 策略实例 ID
+    pub instance_id: Uuid,
+    /// 策略名称
+    pub name: String,
+    /// 策略类型
+    pub strategy_type: String,
+    /// 市场类型
+    pub market_type: String,
+    /// 交易对
+    pub symbol: String,
+    /// 订阅的交易对
+    pub subscriptions: Vec<String>,
+    /// 用户ID
+    pub owner_id: Uuid,
+    /// 版本号（参数每次热更新递增）
+    pub version: u32,
+    /// 当前参数
+    pub params: serde_json::Value,
+    /// 模型版本（机器学习策略）
+    pub model_version: Option<String>,
+    /// 故障后的自动重启策略
+    pub restart_policy: RestartPolicy,
+    /// 创建时间
+    pub created_at: DateTime<Utc>,
+    /// 最后更新时间
+    pub updated_at: DateTime<Utc>,
+    /// 健康状况
+    pub health: StrategyHealthDto,
+}
+
+impl StrategyDetailResponse {
+    /// 由策略句柄构造
+    pub fn from_handle(handle: &StrategyHandle) -> Self {
+        let metadata = handle.metadata();
+        Self {
+            instance_id: metadata.instance_id,
+            name: metadata.name,
+            strategy_type: metadata.kind.to_string(),
+            market_type: metadata.market_type.to_string(),
+            symbol: metadata.symbol,
+            subscriptions: handle.subscriptions().to_vec(),
+            owner_id: metadata.owner_id,
+            version: metadata.version,
+            params: handle.params(),
+            model_version: metadata.model_version,
+            restart_policy: handle.restart_policy().clone(),
+            created_at: metadata.created_at,
+            updated_at: metadata.updated_at,
+            health: StrategyHealthDto::from_handle(handle),
+        }
+    }
+}
+
+/// 策略统计响应
+#[derive(Debug, Clone, Serialize)]
+pub struct StrategyStatsResponse {
+    /// 各状态实例数
+    pub stats: RegistryStats,
+    /// 故障实例的健康状况
+    pub faulted: Vec<StrategyHealthDto>,
+}
+
+/// 故障历史响应
+#[derive(Debug, Clone, Serialize)]
+pub struct FailureHistoryResponse {
+    /// 策略实例 ID
+    pub instance_id: Uuid,
+    /// 总故障次数（含已淘汰的记录）
+    pub total_failures: u64,
+    /// 连续故障次数
+    pub consecutive_failures: u32,
+    /// 最近的故障记录（新的在前）
+    pub records: Vec<FailureRecord>,
+}
+
+/// 生命周期转换历史响应
+#[derive(Debug, Clone, Serialize)]
+pub struct TransitionHistoryResponse {
+    /// 策略实例 ID
+    pub instance_id: Uuid,
+    /// 转换记录（按时间顺序）
+    pub transitions: Vec<LifecycleTransition>,
+}
@@ -5,7 +5,11 @@
 pub mod evaluate;
 pub mod strategy;
 pub mod common;
+pub mod optimize;
+pub mod lifecycle;
 
 pub use evaluate::*;
 pub use strategy::*;
 pub use common::*;
+pub use optimize::*;
+pub use lifecycle::*;
@@ -0,0 +1,100 @@
+//! # 参数优化 DTO
+//!
+//! 定义参数优化与滚动前推分析 API 的请求/响应结构。
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::application::optimizer::{
+    Objective, OptimizationReport, ParamSpace, SearchMethod, TrialResult, WalkForwardConfig,
+};
+use crate::application::service::BacktestSettings;
+
+fn default_market_type() -> String {
+    "spot".to_string()
+}
+
+fn default_top_n() -> usize {
+    10
+}
+
+/// 参数优化请求
+#[derive(Debug, Clone, Deserialize)]
+pub struct OptimizeRequest {
+    /// 策略类型，见 `GET /api/v1/strategy-types`
+    pub strategy_type: String,
+    /// 市场类型: "spot", "usdt_futures", "coin_futures"，默认 spot
+    #[serde(default = "default_market_type")]
+    pub market_type: String,
+    /// 交易对
+    pub symbol: String,
+    /// 历史价格序列
+    pub prices: Vec<Decimal>,
+    /// 基础参数（不参与优化）
+    #[serde(default)]
+    pub base_params: serde_json::Value,
+    /// 参数空间，如 `{"period": {"type": "int", "min": 5, "max": 30, "step": 5}}`
+    pub space: ParamSpace,
+    /// 搜索方式，如 `{"method": "bayesian", "trials": 100, "seed": 42}`，默认网格搜索
+    #[serde(default)]
+    pub search: SearchMethod,
+    /// 优化目标: "sharpe", "return_over_drawdown", "total_return"，默认 sharpe
+    #[serde(default)]
+    pub objective: Objective,
+    /// 回测设置（初始资金 / 每笔成交量 / 手续费率）
+    #[serde(default)]
+    pub settings: BacktestSettings,
+    /// 每次回测的预热行情笔数
+    #[serde(default)]
+    pub warmup: usize,
+    /// 返回排名前 N 的试验，默认 10
+    #[serde(default = "default_top_n")]
+    pub top_n: usize,
+}
+
+/// 参数优化响应
+#[derive(Debug, Clone, Serialize)]
+pub struct OptimizeResponse {
+    /// 搜索方式
+    pub method: &'static str,
+    /// 优化目标
+    pub objective: Objective,
+    /// 试验次数
+    pub evaluated: usize,
+    /// 失败次数
+    pub failed: usize,
+    /// 耗时（毫秒）
+    pub elapsed_ms: u64,
+    /// 最优试验
+    pub best: Option<TrialResult>,
+    /// 排名前 N 的试验
+    pub top: Vec<TrialResult>,
+}
+
+impl OptimizeResponse {
+    /// 由优化结果构造（只保留前 `top_n` 个试验）
+    pub fn from_report(report: OptimizationReport, top_n: usize) -> Self {
+        let best = report.best().cloned();
+        let mut top = report.trials;
+        top.truncate(top_n);
+        Self {
+            method: report.method,
+            objective: report.objective,
+            evaluated: report.evaluated,
+            failed: report.failed,
+            elapsed_ms: report.elapsed_ms,
+            best,
+            top,
+        }
+    }
+}
+
+/// 滚动前推分析请求
+#[derive(Debug, Clone, Deserialize)]
+pub struct WalkForwardRequest {
+    /// 每折样本内使用的优化参数
+    #[serde(flatten)]
+    pub optimize: OptimizeRequest,
+    /// 折切分配置
+    pub walk_forward: WalkForwardConfig,
+}
@@ -5,6 +5,9 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::application::factory::StrategyTypeInfo;
+use crate::domain::model::restart_policy::RestartPolicy;
+
 /// 策略信息 DTO
 #[derive(Debug, Clone, Serialize)]
 pub struct StrategyInfoDto {
@@ -32,21 +35,21 @@ pub struct StrategyListResponse {
 /// 创建策略请求
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateStrategyRequest {
-    /// 策略类型: "spot_grid", "spot_mean", "futures_grid", etc.
+    /// 策略类型，见 `GET /api/v1/strategy-types`
     pub strategy_type: String,
     /// 市场类型: "spot", "usdt_futures", "coin_futures"
     pub market_type: String,
     /// 交易对
     pub symbol: String,
-    /// 策略配置（JSON）
+    /// 策略配置（JSON），须符合该策略类型的参数描述
     #[serde(default)]
     pub config: serde_json::Value,
-    /// 用户ID（必填）
-    pub owner_id: Option<Uuid>,
     /// 策略名称（可选）
     pub name: Option<String>,
     /// 是否自动启动，默认 true
     pub auto_start: Option<bool>,
+    /// 故障后的自动重启策略，如 `{"policy": "on_failure", "max_restarts": 3}`，默认不自动重启
+    pub restart_policy: Option<RestartPolicy>,
 }
 
 /// 创建策略响应
@@ -57,3 +60,35 @@ pub struct CreateStrategyResponse {
     /// 消息
     pub message: String,
 }
+
+/// 策略类型列表响应
+#[derive(Debug, Clone, Serialize)]
+pub struct StrategyTypeListResponse {
+    /// 策略类型（含参数 JSON Schema）
+    pub strategy_types: Vec<StrategyTypeInfo>,
+    /// 总数
+    pub total: usize,
+}
+
+/// 参数热更新请求
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateParamsRequest {
+    /// 新参数（JSON），默认按键合并到当前参数
+    pub params: serde_json::Value,
+    /// 是否整体替换当前参数，默认 false
+    #[serde(default)]
+    pub replace: bool,
+}
+
+/// 参数热更新响应
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateParamsResponse {
+    /// 策略实例 ID
+    pub instance_id: Uuid,
+    /// 更新后的版本号
+    pub version: u32,
+    /// 生效的完整参数
+    pub params: serde_json::Value,
+    /// 观察期事件数，期间执行失败将自动回滚
+    pub probation_events: u32,
+}
@@ -11,7 +11,7 @@ use tracing::{debug, warn};
 
 use crate::domain::logic::grid::{calculate_grid_signal, GridState};
 use crate::domain::logic::mean::{calculate_mean_reversion_signal, MeanReversionState};
-use crate::domain::model::signal::SignalType;
+use crate::domain::model::signal::{OrderType, Signal, SignalType};
 use crate::domain::port::{GridStateData, MeanReversionStateData};
 use crate::interface::http::dto::{
     ApiResponse, EvaluateRequest, EvaluateResponse, OrderIntentDto,
@@ -52,47 +52,52 @@ pub async fn evaluate_strategy(
     // 根据配置的策略类型评估（从 Redis 读取/写入状态）
     let signal = evaluate_with_redis_state(&state, &market_event, &strategy_id).await;
 
-    // 转换为响应
-    let response = match signal {
-        Some(sig) => {
-            let side = match sig.signal_type {
-                SignalType::Buy => "buy",
-                SignalType::Sell => "sell",
-                SignalType::Hold => "hold",
-            };
-
-            // Hold 信号不生成交易意图
-            if matches!(sig.signal_type, SignalType::Hold) {
-                EvaluateResponse {
-                    has_intent: false,
-                    intent: None,
-                }
-            } else {
-                EvaluateResponse {
-                    has_intent: true,
-                    intent: Some(OrderIntentDto {
-                        id: sig.id,
-                        strategy_id: sig.strategy_id,
-                        symbol: sig.symbol,
-                        side: side.to_string(),
-                        quantity: sig.quantity,
-                        price: Some(sig.price),
-                        order_type: "limit".to_string(),
-                        confidence: sig.confidence,
-                        created_at: Utc::now().timestamp_millis(),
-                    }),
-                }
-            }
-        }
-        None => EvaluateResponse {
-            has_intent: false,
-            intent: None,
-        },
+    // 转换为响应（Hold 信号不生成交易意图）
+    let intent = signal.and_then(to_intent_dto);
+    let response = EvaluateResponse {
+        has_intent: intent.is_some(),
+        intent,
+        legs: Vec::new(),
     };
 
     Json(ApiResponse::ok(response))
 }
 
+/// 信号转换为意图 DTO（Hold 信号返回 None）
+fn to_intent_dto(sig: Signal) -> Option<OrderIntentDto> {
+    let side = match sig.signal_type {
+        SignalType::Buy => "buy",
+        SignalType::Sell => "sell",
+        SignalType::Hold => return None,
+    };
+
+    let instruction = &sig.instruction;
+    // 未指定订单类型时保持旧行为：限价单
+    let order_type = instruction.order_type.unwrap_or(OrderType::Limit);
+    let price = match order_type {
+        OrderType::Market => None,
+        _ => Some(sig.price),
+    };
+
+    Some(OrderIntentDto {
+        id: sig.id,
+        strategy_id: sig.strategy_id,
+        symbol: sig.symbol,
+        side: side.to_string(),
+        quantity: sig.quantity,
+        price,
+        order_type: order_type.as_str().to_string(),
+        confidence: sig.confidence,
+        created_at: Utc::now().timestamp_millis(),
+        action: instruction.action.as_str().to_string(),
+        position_side: instruction.position_side.map(|s| s.as_str().to_string()),
+        time_in_force: instruction.time_in_force.map(|t| t.as_str().to_string()),
+        stop_loss: instruction.stop_loss,
+        take_profit: instruction.take_profit,
+        expires_at: instruction.expires_at.map(|t| t.timestamp_millis()),
+    })
+}
+
 /// 使用 Redis 存储的状态进行策略评估
 async fn evaluate_with_redis_state(
     state: &AppState,
@@ -0,0 +1,300 @@
+//! # 策略生命周期处理器 (Strategy Lifecycle Handlers)
+//!
+//! ## 规则
+//! - ✅ 调用方身份取自网关认证后注入的 `x-user-id` 请求头（见 [`OwnerScope`]）
+//! - ✅ 所有接口按调用方身份限定范围，他人的实例视为不存在
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::model::lifecycle_state::{LifecycleAction, LifecycleState};
+use crate::domain::model::strategy_handle::StrategyHandle;
+use crate::domain::model::ExecutionRequest;
+use crate::domain::service::strategy_registry::{StrategyQuery, StrategyRegistry};
+use crate::interface::http::dto::{
+    ApiResponse, BatchExecuteItem, BatchExecuteRequest, BatchExecuteResponse,
+    BatchLifecycleRequest, BatchLifecycleResponse, FailureHistoryResponse, LifecycleResponse,
+    OwnerScope, StrategyDetailResponse, StrategyFilter, StrategyHealthDto, StrategyStatsResponse,
+    TransitionHistoryResponse,
+};
+use crate::state::AppState;
+
+/// GET /api/v1/strategies/:id
+pub async fn get_strategy(
+    State(state): State<AppState>,
+    Path(instance_id): Path<Uuid>,
+    scope: OwnerScope,
+) -> Json<ApiResponse<StrategyDetailResponse>> {
+    match owned_handle(&state, instance_id, &scope) {
+        Ok((_, handle)) => Json(ApiResponse::ok(StrategyDetailResponse::from_handle(&handle))),
+        Err(msg) => Json(ApiResponse::err(msg)),
+    }
+}
+
+/// DELETE /api/v1/strategies/:id
+pub async fn delete_strategy(
+    State(state): State<AppState>,
+    Path(instance_id): Path<Uuid>,
+    scope: OwnerScope,
+) -> Json<ApiResponse<Uuid>> {
+    let registry = match owned_handle(&state, instance_id, &scope) {
+        Ok((registry, _)) => registry,
+        Err(msg) => return Json(ApiResponse::err(msg)),
+    };
+
+    match registry.unregister(instance_id) {
+        Ok(()) => Json(ApiResponse::ok(instance_id)),
+        Err(err) => Json(ApiResponse::err(err.to_string())),
+    }
+}
+
+/// POST /api/v1/strategies/:id/start
+pub async fn start_strategy(
+    state: State<AppState>,
+    path: Path<Uuid>,
+    scope: OwnerScope,
+) -> Json<ApiResponse<LifecycleResponse>> {
+    control(state, path, scope, LifecycleAction::Start)
+}
+
+/// POST /api/v1/strategies/:id/pause
+pub async fn pause_strategy(
+    state: State<AppState>,
+    path: Path<Uuid>,
+    scope: OwnerScope,
+) -> Json<ApiResponse<LifecycleResponse>> {
+    control(state, path, scope, LifecycleAction::Pause)
+}
+
+/// POST /api/v1/strategies/:id/resume
+pub async fn resume_strategy(
+    state: State<AppState>,
+    path: Path<Uuid>,
+    scope: OwnerScope,
+) -> Json<ApiResponse<LifecycleResponse>> {
+    control(state, path, scope, LifecycleAction::Resume)
+}
+
+/// POST /api/v1/strategies/:id/stop
+pub async fn stop_strategy(
+    state: State<AppState>,
+    path: Path<Uuid>,
+    scope: OwnerScope,
+) -> Json<ApiResponse<LifecycleResponse>> {
+    control(state, path, scope, LifecycleAction::Stop)
+}
+
+/// POST /api/v1/strategies/:id/restart
+pub async fn restart_strategy(
+    state: State<AppState>,
+    path: Path<Uuid>,
+    scope: OwnerScope,
+) -> Json<ApiResponse<LifecycleResponse>> {
+    control(state, path, scope, LifecycleAction::Restart)
+}
+
+/// GET /api/v1/strategies/:id/failures
+pub async fn get_failure_history(
+    State(state): State<AppState>,
+    Path(instance_id): Path<Uuid>,
+    scope: OwnerScope,
+) -> Json<ApiResponse<FailureHistoryResponse>> {
+    let handle = match owned_handle(&state, instance_id, &scope) {
+        Ok((_, handle)) => handle,
+        Err(msg) => return Json(ApiResponse::err(msg)),
+    };
+
+    let history = handle.failure_history();
+    Json(ApiResponse::ok(FailureHistoryResponse {
+        instance_id,
+        total_failures: history.total_failures(),
+        consecutive_failures: history.consecutive_failures(),
+        records: history.recent().cloned().collect(),
+    }))
+}
+
+/// GET /api/v1/strategies/:id/transitions
+pub async fn get_transitions(
+    State(state): State<AppState>,
+    Path(instance_id): Path<Uuid>,
+    scope: OwnerScope,
+) -> Json<ApiResponse<TransitionHistoryResponse>> {
+    match owned_handle(&state, instance_id, &scope) {
+        Ok((_, handle)) => Json(ApiResponse::ok(TransitionHistoryResponse {
+            instance_id,
+            transitions: handle.transitions(),
+        })),
+        Err(msg) => Json(ApiResponse::err(msg)),
+    }
+}
+
+/// GET /api/v1/strategies/stats
+pub async fn get_stats(
+    State(state): State<AppState>,
+    scope: OwnerScope,
+) -> Json<ApiResponse<StrategyStatsResponse>> {
+    let Some(registry) = state.strategy_registry.as_ref() else {
+        return Json(ApiResponse::err("strategy registry is not initialized"));
+    };
+    let owner_id = match scope.require() {
+        Ok(owner_id) => owner_id,
+        Err(msg) => return Json(ApiResponse::err(msg)),
+    };
+
+    let query = StrategyQuery::by_owner(owner_id);
+    let faulted = registry
+        .query(&query.clone().with_state(LifecycleState::Faulted))
+        .iter()
+        .map(|handle| StrategyHealthDto::from_handle(handle))
+        .collect();
+
+    Json(ApiResponse::ok(StrategyStatsResponse {
+        stats: registry.stats_for(&query),
+        faulted,
+    }))
+}
+
+/// POST /api/v1/strategies/batch
+pub async fn batch_lifecycle(
+    State(state): State<AppState>,
+    scope: OwnerScope,
+    Json(req): Json<BatchLifecycleRequest>,
+) -> Json<ApiResponse<BatchLifecycleResponse>> {
+    let Some(registry) = state.strategy_registry.as_ref() else {
+        return Json(ApiResponse::err("strategy registry is not initialized"));
+    };
+
+    let query = match build_query(&scope, &req.filter) {
+        Ok(query) => query,
+        Err(msg) => return Json(ApiResponse::err(msg)),
+    };
+
+    let results = registry.apply_batch(&query, req.action);
+    Json(ApiResponse::ok(BatchLifecycleResponse::from_results(req.action, results)))
+}
+
+/// POST /api/v1/strategies/batch/execute
+pub async fn batch_execute(
+    State(state): State<AppState>,
+    scope: OwnerScope,
+    Json(req): Json<BatchExecuteRequest>,
+) -> Json<ApiResponse<BatchExecuteResponse>> {
+    let Some(registry) = state.strategy_registry.as_ref() else {
+        return Json(ApiResponse::err("strategy registry is not initialized"));
+    };
+    let owner_id = match scope.require() {
+        Ok(owner_id) => owner_id,
+        Err(msg) => return Json(ApiResponse::err(msg)),
+    };
+
+    let symbol = req.symbol.trim().to_uppercase();
+    if symbol.is_empty() {
+        return Json(ApiResponse::err("symbol cannot be empty"));
+    }
+
+    let request = ExecutionRequest {
+        request_id: Uuid::new_v4(),
+        symbol: symbol.clone(),
+        price: req.price,
+        quantity: req.quantity,
+        timestamp: Utc::now(),
+        is_buyer_maker: req.is_buyer_maker,
+        depth: None,
+    };
+    let query = StrategyQuery::by_owner(owner_id)
+        .with_symbol(symbol)
+        .with_state(LifecycleState::Running);
+
+    let results = registry
+        .execute_batch(&query, &request)
+        .into_iter()
+        .map(|(instance_id, result)| match result {
+            Ok(result) => BatchExecuteItem {
+                instance_id,
+                result: Some(result),
+                error: None,
+            },
+            Err(err) => BatchExecuteItem {
+                instance_id,
+                result: None,
+                error: Some(err.to_string()),
+            },
+        })
+        .collect::<Vec<_>>();
+
+    Json(ApiResponse::ok(BatchExecuteResponse {
+        total: results.len(),
+        with_intent: results
+            .iter()
+            .filter(|r| r.result.as_ref().is_some_and(|r| r.has_intent))
+            .count(),
+        results,
+    }))
+}
+
+/// 执行单实例生命周期操作
+fn control(
+    State(state): State<AppState>,
+    Path(instance_id): Path<Uuid>,
+    scope: OwnerScope,
+    action: LifecycleAction,
+) -> Json<ApiResponse<LifecycleResponse>> {
+    let (registry, handle) = match owned_handle(&state, instance_id, &scope) {
+        Ok(found) => found,
+        Err(msg) => return Json(ApiResponse::err(msg)),
+    };
+
+    match registry.apply(instance_id, action) {
+        Ok(()) => Json(ApiResponse::ok(LifecycleResponse {
+            instance_id,
+            action,
+            state: handle.lifecycle_state().to_string(),
+        })),
+        Err(err) => Json(ApiResponse::err(format!("failed to {} strategy: {}", action, err))),
+    }
+}
+
+/// 查找属于调用方的策略实例
+pub(crate) fn owned_handle(
+    state: &AppState,
+    instance_id: Uuid,
+    scope: &OwnerScope,
+) -> Result<(Arc<StrategyRegistry>, Arc<StrategyHandle>), String> {
+    let Some(registry) = state.strategy_registry.as_ref() else {
+        return Err("strategy registry is not initialized".to_string());
+    };
+    let owner_id = scope.require()?;
+
+    match registry.get(instance_id) {
+        Some(handle) if handle.metadata().owner_id == owner_id => {
+            Ok((Arc::clone(registry), handle))
+        }
+        _ => Err(format!("strategy {} not found", instance_id)),
+    }
+}
+
+/// 调用方身份 + 筛选条件 → 注册表查询
+pub(crate) fn build_query(
+    scope: &OwnerScope,
+    filter: &StrategyFilter,
+) -> Result<StrategyQuery, String> {
+    let mut query = StrategyQuery::by_owner(scope.require()?);
+    if let Some(symbol) = filter.symbol.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        query = query.with_symbol(symbol.to_uppercase());
+    }
+    if let Some(raw) = filter.state.as_deref() {
+        let Some(state) = LifecycleState::parse(raw) else {
+            return Err(
+                "invalid state, allowed: created, running, paused, stopped, faulted".to_string(),
+            );
+        };
+        query = query.with_state(state);
+    }
+    Ok(query)
+}
@@ -6,8 +6,14 @@ pub mod health;
 /// 策略管理处理器
 pub mod strategies;
 
+/// 策略生命周期处理器
+pub mod lifecycle;
+
 /// 回测处理器
 pub mod backtest;
 
+/// 参数优化处理器
+pub mod optimize;
+
 /// 策略评估处理器
 pub mod evaluate;
@@ -0,0 +1,112 @@
+//! # 参数优化处理器 (Parameter Optimization Handlers)
+//!
+//! ## 职责
+//! - `POST /api/v1/optimize`: 在历史价格上搜索最优参数（网格 / 随机 / 贝叶斯）
+//! - `POST /api/v1/optimize/walk-forward`: 滚动前推分析
+//!
+//! 优化计算量大，在阻塞线程池中运行。
+
+use std::sync::Arc;
+
+use axum::{extract::State, Json};
+use rust_decimal::Decimal;
+
+use crate::application::optimizer::{OptimizationSpec, Optimizer, WalkForwardReport};
+use crate::application::service::BacktestService;
+use crate::domain::model::market_type::MarketType;
+use crate::interface::http::dto::{
+    ApiResponse, OptimizeRequest, OptimizeResponse, WalkForwardRequest,
+};
+use crate::state::AppState;
+
+/// POST /api/v1/optimize
+pub async fn optimize(
+    State(state): State<AppState>,
+    Json(req): Json<OptimizeRequest>,
+) -> Json<ApiResponse<OptimizeResponse>> {
+    let top_n = req.top_n;
+    let (optimizer, spec, prices) = match prepare(&state, req) {
+        Ok(prepared) => prepared,
+        Err(msg) => return Json(ApiResponse::err(msg)),
+    };
+
+    let result = tokio::task::spawn_blocking(move || optimizer.optimize(&spec, &prices)).await;
+    match result {
+        Ok(Ok(report)) if report.best().is_none() => {
+            let reason = report
+                .trials
+                .iter()
+                .find_map(|t| t.error.clone())
+                .unwrap_or_default();
+            Json(ApiResponse::err(format!("every trial failed: {}", reason)))
+        }
+        Ok(Ok(report)) => Json(ApiResponse::ok(OptimizeResponse::from_report(report, top_n))),
+        Ok(Err(err)) => Json(ApiResponse::err(format!("optimization failed: {:#}", err))),
+        Err(err) => Json(ApiResponse::err(format!("optimization task failed: {}", err))),
+    }
+}
+
+/// POST /api/v1/optimize/walk-forward
+pub async fn walk_forward(
+    State(state): State<AppState>,
+    Json(req): Json<WalkForwardRequest>,
+) -> Json<ApiResponse<WalkForwardReport>> {
+    let config = req.walk_forward;
+    let (optimizer, spec, prices) = match prepare(&state, req.optimize) {
+        Ok(prepared) => prepared,
+        Err(msg) => return Json(ApiResponse::err(msg)),
+    };
+
+    let result =
+        tokio::task::spawn_blocking(move || optimizer.walk_forward(&spec, &prices, &config)).await;
+    match result {
+        Ok(Ok(report)) => Json(ApiResponse::ok(report)),
+        Ok(Err(err)) => Json(ApiResponse::err(format!("walk-forward failed: {:#}", err))),
+        Err(err) => Json(ApiResponse::err(format!("walk-forward task failed: {}", err))),
+    }
+}
+
+/// 校验请求并构造优化器与任务描述
+fn prepare(
+    state: &AppState,
+    req: OptimizeRequest,
+) -> Result<(Optimizer, OptimizationSpec, Vec<Decimal>), String> {
+    let Some(loader) = state.strategy_loader.as_ref() else {
+        return Err("strategy loader is not initialized".to_string());
+    };
+
+    let strategy_type = req.strategy_type.trim().to_ascii_lowercase();
+    if loader.factory().descriptor(&strategy_type).is_none() {
+        return Err(format!(
+            "unsupported strategy_type, allowed: {}",
+            loader.factory().strategy_types().join(", ")
+        ));
+    }
+
+    let symbol = req.symbol.trim().to_uppercase();
+    if symbol.is_empty() {
+        return Err("symbol cannot be empty".to_string());
+    }
+
+    let Some(market_type) = MarketType::parse(&req.market_type) else {
+        return Err("invalid market_type, allowed: spot, usdt_futures, coin_futures".to_string());
+    };
+
+    if req.prices.len() < 2 {
+        return Err("prices must contain at least 2 points".to_string());
+    }
+
+    let spec = OptimizationSpec {
+        strategy_type,
+        market_type,
+        symbol,
+        base_params: req.base_params,
+        space: req.space,
+        search: req.search,
+        objective: req.objective,
+        settings: req.settings,
+        warmup: req.warmup,
+    };
+    let backtest = BacktestService::new(Arc::clone(loader.factory()));
+    Ok((Optimizer::new(Arc::new(backtest)), spec, req.prices))
+}
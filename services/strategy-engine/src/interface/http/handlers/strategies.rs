@@ -1,26 +1,40 @@
 //! Strategy management handlers.
 
-use axum::{extract::State, Json};
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
 use uuid::Uuid;
 
+use crate::application::factory::StrategyTypeInfo;
 use crate::application::scheduler::StrategyConfig;
 use crate::domain::model::lifecycle_state::LifecycleState;
-use crate::domain::service::strategy_registry::StrategyQuery;
+use crate::domain::model::market_type::MarketType;
 use crate::interface::http::dto::{
-    ApiResponse, CreateStrategyRequest, CreateStrategyResponse, StrategyInfoDto,
-    StrategyListResponse,
+    ApiResponse, CreateStrategyRequest, CreateStrategyResponse, OwnerScope, StrategyFilter,
+    StrategyInfoDto, StrategyListResponse, StrategyTypeListResponse, UpdateParamsRequest,
+    UpdateParamsResponse,
 };
+use crate::interface::http::handlers::lifecycle::{build_query, owned_handle};
 use crate::state::AppState;
 
 /// GET /api/v1/strategies
+///
+/// 只列出调用方自己的实例，支持 `?symbol=&state=` 筛选。
 pub async fn list_strategies(
     State(state): State<AppState>,
+    scope: OwnerScope,
+    Query(filter): Query<StrategyFilter>,
 ) -> Json<ApiResponse<StrategyListResponse>> {
     let Some(registry) = state.strategy_registry.as_ref() else {
         return Json(ApiResponse::err("strategy registry is not initialized"));
     };
 
-    let mut handles = registry.query(&StrategyQuery::all());
+    let query = match build_query(&scope, &filter) {
+        Ok(query) => query,
+        Err(msg) => return Json(ApiResponse::err(msg)),
+    };
+    let mut handles = registry.query(&query);
     handles.sort_by_key(|h| h.metadata().created_at);
 
     let strategies = handles
@@ -46,22 +60,24 @@ pub async fn list_strategies(
 /// POST /api/v1/strategies
 pub async fn create_strategy(
     State(state): State<AppState>,
+    scope: OwnerScope,
     Json(req): Json<CreateStrategyRequest>,
 ) -> Json<ApiResponse<CreateStrategyResponse>> {
     let Some(loader) = state.strategy_loader.as_ref() else {
         return Json(ApiResponse::err("strategy loader is not initialized"));
     };
 
-    let owner_id = match req.owner_id {
-        Some(v) => v,
-        None => return Json(ApiResponse::err("owner_id is required")),
+    let owner_id = match scope.require() {
+        Ok(owner_id) => owner_id,
+        Err(msg) => return Json(ApiResponse::err(msg)),
     };
 
     let strategy_type = req.strategy_type.trim().to_ascii_lowercase();
-    if strategy_type != "spot_grid" && strategy_type != "spot_mean_reversion" {
-        return Json(ApiResponse::err(
-            "unsupported strategy_type, allowed: spot_grid, spot_mean_reversion",
-        ));
+    if loader.factory().descriptor(&strategy_type).is_none() {
+        return Json(ApiResponse::err(format!(
+            "unsupported strategy_type, allowed: {}",
+            loader.factory().strategy_types().join(", ")
+        )));
     }
 
     let symbol = req.symbol.trim().to_uppercase();
@@ -69,10 +85,11 @@ pub async fn create_strategy(
         return Json(ApiResponse::err("symbol cannot be empty"));
     }
 
-    let market_type = req.market_type.trim().to_ascii_lowercase();
-    if market_type != "spot" {
-        return Json(ApiResponse::err("only spot market_type is currently supported"));
-    }
+    let Some(market_type) = MarketType::parse(&req.market_type) else {
+        return Json(ApiResponse::err(
+            "invalid market_type, allowed: spot, usdt_futures, coin_futures",
+        ));
+    };
 
     let instance_id = Uuid::new_v4();
     let strategy_name = req
@@ -83,19 +100,87 @@ pub async fn create_strategy(
     let config = StrategyConfig {
         instance_id,
         strategy_type,
+        market_type,
         symbol,
         owner_id,
         name: strategy_name,
         params: req.config,
         auto_start: req.auto_start.unwrap_or(true),
+        restart_policy: req.restart_policy.unwrap_or_default(),
     };
 
-    match loader.load_strategies(vec![config]).await {
-        Ok(ids) if !ids.is_empty() => Json(ApiResponse::ok(CreateStrategyResponse {
-            instance_id: ids[0],
+    match loader.load_strategy(&config).await {
+        Ok(instance_id) => Json(ApiResponse::ok(CreateStrategyResponse {
+            instance_id,
             message: "strategy created".to_string(),
         })),
-        Ok(_) => Json(ApiResponse::err("strategy was rejected by config validation")),
         Err(err) => Json(ApiResponse::err(format!("failed to create strategy: {}", err))),
     }
 }
+
+/// PATCH /api/v1/strategies/:id/params
+pub async fn update_strategy_params(
+    State(state): State<AppState>,
+    Path(instance_id): Path<Uuid>,
+    scope: OwnerScope,
+    Json(req): Json<UpdateParamsRequest>,
+) -> Json<ApiResponse<UpdateParamsResponse>> {
+    let Some(loader) = state.strategy_loader.as_ref() else {
+        return Json(ApiResponse::err("strategy loader is not initialized"));
+    };
+    if let Err(msg) = owned_handle(&state, instance_id, &scope) {
+        return Json(ApiResponse::err(msg));
+    }
+
+    if !req.params.is_object() {
+        return Json(ApiResponse::err("params must be a JSON object"));
+    }
+
+    match loader.update_params(instance_id, req.params, req.replace) {
+        Ok(update) => Json(ApiResponse::ok(UpdateParamsResponse {
+            instance_id,
+            version: update.version,
+            params: update.params,
+            probation_events: update.probation_events,
+        })),
+        Err(err) => Json(ApiResponse::err(format!("failed to update params: {:#}", err))),
+    }
+}
+
+/// GET /api/v1/strategy-types
+pub async fn list_strategy_types(
+    State(state): State<AppState>,
+) -> Json<ApiResponse<StrategyTypeListResponse>> {
+    let Some(loader) = state.strategy_loader.as_ref() else {
+        return Json(ApiResponse::err("strategy loader is not initialized"));
+    };
+
+    let strategy_types = loader
+        .factory()
+        .descriptors()
+        .map(|d| d.info())
+        .collect::<Vec<_>>();
+
+    Json(ApiResponse::ok(StrategyTypeListResponse {
+        total: strategy_types.len(),
+        strategy_types,
+    }))
+}
+
+/// GET /api/v1/strategy-types/:strategy_type
+pub async fn get_strategy_type(
+    State(state): State<AppState>,
+    Path(strategy_type): Path<String>,
+) -> Json<ApiResponse<StrategyTypeInfo>> {
+    let Some(loader) = state.strategy_loader.as_ref() else {
+        return Json(ApiResponse::err("strategy loader is not initialized"));
+    };
+
+    match loader.factory().descriptor(&strategy_type) {
+        Some(descriptor) => Json(ApiResponse::ok(descriptor.info())),
+        None => Json(ApiResponse::err(format!(
+            "unknown strategy_type: {}",
+            strategy_type
+        ))),
+    }
+}
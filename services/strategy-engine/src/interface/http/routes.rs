@@ -7,13 +7,28 @@
 //! - `POST /api/v1/strategy/evaluate`: ⭐ 策略评估（核心）
 //! - `GET /api/v1/strategies`: 获取策略列表
 //! - `POST /api/v1/strategies`: 创建新策略
+//! - `GET /api/v1/strategies/:id`: 策略详情（元数据、参数、健康状况）
+//! - `DELETE /api/v1/strategies/:id`: 注销策略（仅 created / stopped）
+//! - `POST /api/v1/strategies/:id/{start,pause,resume,stop,restart}`: 单实例生命周期控制
+//! - `GET /api/v1/strategies/:id/failures`: 故障历史
+//! - `GET /api/v1/strategies/:id/transitions`: 生命周期转换历史
+//! - `PATCH /api/v1/strategies/:id/params`: 参数热更新（观察期内失败自动回滚）
+//! - `GET /api/v1/strategies/stats`: 各状态实例数与故障实例
+//! - `POST /api/v1/strategies/batch`: 按条件批量生命周期控制
+//! - `POST /api/v1/strategies/batch/execute`: 按条件批量执行
+//!
+//! 策略管理、单实例与批量接口均按网关注入的 `x-user-id` 请求头限定范围。
+//! - `GET /api/v1/strategy-types`: 可用策略类型及参数描述（JSON Schema）
+//! - `GET /api/v1/strategy-types/:strategy_type`: 单个策略类型的参数描述
 //! - `POST /api/v1/backtest`: 运行回测
+//! - `POST /api/v1/optimize`: 参数优化（网格 / 随机 / 贝叶斯搜索）
+//! - `POST /api/v1/optimize/walk-forward`: 滚动前推分析
 
 // ============================================================================
 // 外部依赖导入
 // ============================================================================
 
-use axum::{routing::{get, post}, Router};
+use axum::{routing::{delete, get, patch, post}, Router};
 use crate::state::AppState;
 use super::handlers;
 
@@ -31,7 +46,44 @@ pub fn create_router(state: AppState) -> Router {
         // 策略管理
         .route("/api/v1/strategies", get(handlers::strategies::list_strategies))
         .route("/api/v1/strategies", post(handlers::strategies::create_strategy))
+        .route(
+            "/api/v1/strategies/:id/params",
+            patch(handlers::strategies::update_strategy_params),
+        )
+        // 策略生命周期
+        .route("/api/v1/strategies/stats", get(handlers::lifecycle::get_stats))
+        .route("/api/v1/strategies/batch", post(handlers::lifecycle::batch_lifecycle))
+        .route(
+            "/api/v1/strategies/batch/execute",
+            post(handlers::lifecycle::batch_execute),
+        )
+        .route("/api/v1/strategies/:id", get(handlers::lifecycle::get_strategy))
+        .route("/api/v1/strategies/:id", delete(handlers::lifecycle::delete_strategy))
+        .route("/api/v1/strategies/:id/start", post(handlers::lifecycle::start_strategy))
+        .route("/api/v1/strategies/:id/pause", post(handlers::lifecycle::pause_strategy))
+        .route("/api/v1/strategies/:id/resume", post(handlers::lifecycle::resume_strategy))
+        .route("/api/v1/strategies/:id/stop", post(handlers::lifecycle::stop_strategy))
+        .route("/api/v1/strategies/:id/restart", post(handlers::lifecycle::restart_strategy))
+        .route(
+            "/api/v1/strategies/:id/failures",
+            get(handlers::lifecycle::get_failure_history),
+        )
+        .route(
+            "/api/v1/strategies/:id/transitions",
+            get(handlers::lifecycle::get_transitions),
+        )
+        .route("/api/v1/strategy-types", get(handlers::strategies::list_strategy_types))
+        .route(
+            "/api/v1/strategy-types/:strategy_type",
+            get(handlers::strategies::get_strategy_type),
+        )
         // 回测
         .route("/api/v1/backtest", post(handlers::backtest::run_backtest))
+        // 参数优化
+        .route("/api/v1/optimize", post(handlers::optimize::optimize))
+        .route(
+            "/api/v1/optimize/walk-forward",
+            post(handlers::optimize::walk_forward),
+        )
         .with_state(state)
 }
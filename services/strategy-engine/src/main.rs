@@ -6,6 +6,9 @@
 //! - 接收行情数据和用户策略配置
 //! - 运行策略算法计算交易信号
 //! - 输出信号事件到消息队列
+//! - 接收执行回报（成交 / 拒单 / 持仓快照）并回送给策略实例
+//! - 策略状态定时写入 Redis 检查点，重启时先恢复再消费行情
+//! - 隔离单个策略的 panic，按重启策略自动重启故障实例，放弃时发送告警
 //! 
 //! ## 支持的策略类型
 //! - 网格交易 (Grid Trading)
@@ -68,16 +71,31 @@ async fn main() -> Result<()> {
     let mut state = state::AppState::new().await?;
     let config = state.config.as_ref().clone();
 
+    // 组合资金分配（调度器与执行回报消费者共享）
+    let portfolio = bootstrap::create_portfolio_allocator(config.portfolio.clone());
+    if let Some(portfolio) = &portfolio {
+        info!(
+            equity = %portfolio.config().base_equity,
+            method = portfolio.config().method.name(),
+            netting = portfolio.config().netting,
+            "Portfolio allocation enabled"
+        );
+    }
+
     // 创建策略调度器（新版本）
     let (registry, scheduler, loader) = bootstrap::create_strategy_scheduler(
         config.kafka_brokers.clone(),
         config.kafka_market_topic.clone(),
         config.kafka_signal_topic.clone(),
+        config.kafka_signal_group_topic.clone(),
+        config.kafka_quote_topic.clone(),
         config.kafka_consumer_group.clone(),
+        portfolio.clone(),
+        config.signal_events.clone(),
     ).await?;
 
     // 注入运行时组件到 AppState，供 HTTP handler 直接使用
-    let loader = Arc::new(loader);
+    let loader = Arc::new(loader.with_probation_events(config.param_probation_events));
     state.strategy_registry = Some(Arc::clone(&registry));
     state.strategy_loader = Some(Arc::clone(&loader));
 
@@ -87,6 +105,14 @@ async fn main() -> Result<()> {
     let instance_ids = loader.load_strategies(example_configs).await?;
     info!("Loaded {} strategies", instance_ids.len());
 
+    // 恢复策略状态检查点（必须在消费行情之前完成）
+    let checkpointer = bootstrap::create_state_checkpointer(
+        Arc::clone(&registry),
+        Arc::clone(&state.strategy_state),
+        config.checkpoint_interval_secs,
+    );
+    let restored = checkpointer.restore_all().await;
+
     // 启动调度器（在后台任务中运行）
     let scheduler_clone = Arc::clone(&scheduler);
     let scheduler_handle = tokio::spawn(async move {
@@ -95,6 +121,38 @@ async fn main() -> Result<()> {
         }
     });
 
+    // 启动执行回报消费者（在后台任务中运行）
+    let feedback_consumer = bootstrap::create_feedback_consumer(
+        Arc::clone(&registry),
+        &config.kafka_brokers,
+        &config.kafka_feedback_topic,
+        &config.kafka_consumer_group,
+        portfolio,
+        restored,
+    )?;
+    let feedback_handle = tokio::spawn(async move {
+        if let Err(err) = feedback_consumer.run().await {
+            error!(error = %err, "Execution feedback consumer stopped");
+        }
+    });
+
+    // 定时写入检查点（在后台任务中运行）
+    let checkpointer_clone = Arc::clone(&checkpointer);
+    let checkpoint_handle = tokio::spawn(async move {
+        checkpointer_clone.run().await;
+    });
+
+    // 故障实例监督（在后台任务中运行）
+    let supervisor = bootstrap::create_strategy_supervisor(
+        Arc::clone(&registry),
+        &config.kafka_brokers,
+        &config.kafka_alert_topic,
+        config.supervisor_interval_secs,
+    )?;
+    let supervisor_handle = tokio::spawn(async move {
+        supervisor.run().await;
+    });
+
     // 创建路由
     let app = interface::http::routes::create_router(state);
 
@@ -115,6 +173,13 @@ async fn main() -> Result<()> {
         .await?;
 
     scheduler_handle.abort();
+    feedback_handle.abort();
+    checkpoint_handle.abort();
+    supervisor_handle.abort();
+
+    // 停止消费后写入最后一次检查点
+    let saved = checkpointer.checkpoint_all().await;
+    info!(saved, "Strategy checkpoints saved on shutdown");
     info!("Strategy Engine 已优雅关闭");
 
     Ok(())
@@ -6,7 +6,8 @@ use anyhow::{Context, Result};
 use rust_decimal::Decimal;
 use std::sync::Arc;
 
-use crate::application::scheduler::StrategyLoader;
+use crate::application::portfolio::{AllocationMethod, PortfolioConfig};
+use crate::application::scheduler::{SignalEventConfig, StrategyLoader};
 use crate::domain::logic::grid::GridConfig;
 use crate::domain::logic::mean::MeanReversionConfig;
 use crate::domain::model::strategy_config::StrategyType;
@@ -32,7 +33,25 @@ pub struct AppConfig {
     pub kafka_brokers: String,
     pub kafka_market_topic: String,
     pub kafka_signal_topic: String,
+    /// 多腿意图组主题
+    pub kafka_signal_group_topic: String,
+    /// 做市报价主题（trading-engine 尚未消费，报价不会被执行）
+    pub kafka_quote_topic: String,
+    /// 执行回报主题（trading-engine 发布）
+    pub kafka_feedback_topic: String,
     pub kafka_consumer_group: String,
+    /// 永久故障通知主题
+    pub kafka_alert_topic: String,
+    /// 策略状态检查点间隔（秒）
+    pub checkpoint_interval_secs: u64,
+    /// 参数热更新观察期（事件数，0 表示不观察）
+    pub param_probation_events: u32,
+    /// 故障实例巡检间隔（秒）
+    pub supervisor_interval_secs: u64,
+    /// 组合资金分配（None 表示按策略原始数量下单）
+    pub portfolio: Option<PortfolioConfig>,
+    /// 版本化信号事件发布（None 表示不发布）
+    pub signal_events: Option<SignalEventConfig>,
     pub strategy_type: StrategyType,
     pub grid_config: GridConfig,
     pub mean_reversion_config: MeanReversionConfig,
@@ -60,8 +79,21 @@ impl AppState {
                 .unwrap_or_else(|_| "market-events".to_string()),
             kafka_signal_topic: std::env::var("KAFKA_SIGNAL_TOPIC")
                 .unwrap_or_else(|_| "trading.signals".to_string()),
+            kafka_signal_group_topic: std::env::var("KAFKA_SIGNAL_GROUP_TOPIC")
+                .unwrap_or_else(|_| "trading.signal-groups".to_string()),
+            kafka_quote_topic: std::env::var("KAFKA_QUOTE_TOPIC")
+                .unwrap_or_else(|_| "trading.quotes".to_string()),
+            kafka_feedback_topic: std::env::var("KAFKA_EXECUTION_FEEDBACK_TOPIC")
+                .unwrap_or_else(|_| "execution-feedback".to_string()),
             kafka_consumer_group: std::env::var("KAFKA_CONSUMER_GROUP")
                 .unwrap_or_else(|_| "strategy-engine".to_string()),
+            kafka_alert_topic: std::env::var("KAFKA_STRATEGY_ALERT_TOPIC")
+                .unwrap_or_else(|_| "strategy.alerts".to_string()),
+            checkpoint_interval_secs: read_u64_env("STRATEGY_CHECKPOINT_INTERVAL_SECS", 30).max(1),
+            param_probation_events: read_u32_env("STRATEGY_PARAM_PROBATION_EVENTS", 20),
+            supervisor_interval_secs: read_u64_env("STRATEGY_SUPERVISOR_INTERVAL_SECS", 1).max(1),
+            portfolio: read_portfolio_config()?,
+            signal_events: read_signal_event_config(),
             strategy_type: read_strategy_type(),
             grid_config,
             mean_reversion_config,
@@ -96,6 +128,13 @@ fn read_u32_env(key: &str, default: u32) -> u32 {
         .unwrap_or(default)
 }
 
+fn read_u64_env(key: &str, default: u64) -> u64 {
+    std::env::var(key)
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(default)
+}
+
 fn read_usize_env(key: &str, default: usize) -> usize {
     std::env::var(key)
         .ok()
@@ -103,6 +142,50 @@ fn read_usize_env(key: &str, default: usize) -> usize {
         .unwrap_or(default)
 }
 
+fn read_bool_env(key: &str, default: bool) -> bool {
+    std::env::var(key)
+        .ok()
+        .and_then(|value| value.parse::<bool>().ok())
+        .unwrap_or(default)
+}
+
+fn read_portfolio_config() -> Result<Option<PortfolioConfig>> {
+    if !read_bool_env("PORTFOLIO_ENABLED", false) {
+        return Ok(None);
+    }
+
+    let defaults = PortfolioConfig::default();
+    let method = match std::env::var("PORTFOLIO_ALLOCATION") {
+        Ok(value) => AllocationMethod::parse(&value).context("PORTFOLIO_ALLOCATION 配置无效")?,
+        Err(_) => defaults.method.clone(),
+    };
+    let config = PortfolioConfig {
+        base_equity: read_decimal_env("PORTFOLIO_EQUITY", defaults.base_equity),
+        method,
+        order_fraction: read_decimal_env("PORTFOLIO_ORDER_FRACTION", defaults.order_fraction),
+        quantity_scale: read_u32_env("PORTFOLIO_QUANTITY_SCALE", defaults.quantity_scale),
+        return_window: read_usize_env("PORTFOLIO_RETURN_WINDOW", defaults.return_window),
+        min_trades: read_usize_env("PORTFOLIO_MIN_TRADES", defaults.min_trades),
+        netting: read_bool_env("PORTFOLIO_NETTING", defaults.netting),
+    };
+    config.validate().context("组合资金分配配置无效")?;
+    Ok(Some(config))
+}
+
+/// 版本化信号事件发布（STRATEGY_SIGNAL_EVENTS_ENABLED，默认关闭）
+fn read_signal_event_config() -> Option<SignalEventConfig> {
+    if !read_bool_env("STRATEGY_SIGNAL_EVENTS_ENABLED", false) {
+        return None;
+    }
+
+    let defaults = SignalEventConfig::default();
+    Some(SignalEventConfig {
+        topic: std::env::var("KAFKA_SIGNAL_EVENT_TOPIC").unwrap_or(defaults.topic),
+        max_age_ms: read_u64_env("STRATEGY_SIGNAL_MAX_AGE_MS", defaults.max_age_ms),
+        dedupe_window: read_usize_env("STRATEGY_SIGNAL_DEDUPE_WINDOW", defaults.dedupe_window),
+    })
+}
+
 fn read_strategy_type() -> StrategyType {
     match std::env::var("STRATEGY_TYPE").ok() {
         Some(value)
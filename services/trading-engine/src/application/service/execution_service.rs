@@ -5,10 +5,15 @@
 //!
 //! ## 职责
 //! 1. 接收 MarketEvent
-//! 2. 调用 StrategyPort → 获取 OrderIntent
-//! 3. 调用 OrderRiskPort → 校验 OrderIntent
-//! 4. 调用 OrderExecutionPort → 执行 OrderIntent
-//! 5. 下单成功后 → 更新风控状态 + 落库 + 审计记录
+//! 2. 调用 StrategyPort → 获取 OrderIntent（多腿策略为 OrderIntentGroup，整体执行、失败回滚）；
+//!    异步策略的信号由 SignalConsumerService 直接送入，不经过行情事件
+//! 3. 丢弃已过期的意图；检查 MarketQualityPort → 行情质量异常的交易对不下单
+//! 4. 调用 OrderRiskPort → 校验 OrderIntent
+//! 5. 调用 OrderExecutionPort → 执行 OrderIntent
+//! 6. 下单成功后 → 更新风控状态 + 落库 + 审计记录
+//! 7. 拒单 / 成交 / 撤单 → 按策略回送执行回报（StrategyFeedbackService）
+//! 8. 入场单成交 → 按成交数量挂出附带的止损止盈；保护单成交 → 撤销 OCO 对手单
+//! 9. 多腿回滚撤单失败 → 经 ExchangeQueryPort 查询腿的实际成交数量再平仓，无法确定时不平仓并告警
 //!
 //! ## 风控状态管理
 //! ExecutionService 是唯一允许修改 RiskStatePort 的地方：
@@ -27,6 +32,7 @@ use std::sync::Arc;
 
 use chrono::Utc;
 use rust_decimal::Decimal;
+use shared::event::execution_feedback_event::RejectionSource;
 use shared::event::market_event::MarketEvent;
 use tokio::sync::RwLock;
 use tracing::{info, warn, error, debug};
@@ -35,7 +41,13 @@ use uuid::Uuid;
 use crate::domain::model::order::{Order, OrderSide, OrderStatus, OrderType};
 use crate::domain::model::trade::Trade;
 use crate::domain::model::audit_event::{ExecutionResultEvent, RiskRejectedEvent};
+use crate::domain::model::order_intent::{self, OrderIntent, PositionAction};
+use crate::domain::model::order_intent_group::OrderIntentGroup;
 use crate::domain::model::execution_fill::{ExecutionFill, ExecutionStreamEvent, FillSide, FillType};
+use crate::application::service::protective_order_book::ProtectiveOrderBook;
+use crate::application::service::strategy_feedback_service::StrategyFeedbackService;
+use crate::domain::port::exchange_query_port::{ExchangeOrderStatus, ExchangeQueryPort};
+use crate::domain::port::market_quality_port::MarketQualityPort;
 use crate::domain::port::order_execution_port::OrderExecutionPort;
 use crate::domain::port::order_repository_port::OrderRepositoryPort;
 use crate::domain::port::order_risk_port::OrderRiskPort;
@@ -65,6 +77,14 @@ pub struct ExecutionService {
     audit: Option<Arc<dyn TradeAuditPort>>,
     /// 已应用的 trade_id 缓存（用于成交幂等判断）
     applied_trade_ids: RwLock<HashSet<String>>,
+    /// 行情质量守卫（可选，拦截数据异常交易对的下单）
+    quality_guard: Option<Arc<dyn MarketQualityPort>>,
+    /// 策略执行回报（可选，向策略回送成交 / 拒单 / 持仓快照）
+    feedback: Option<Arc<StrategyFeedbackService>>,
+    /// 附带止损止盈簿记（入场成交后挂出，止损止盈互为 OCO）
+    protection: ProtectiveOrderBook,
+    /// 交易所查询（可选，多腿回滚撤单失败时查询实际成交数量）
+    exchange_query: Option<Arc<dyn ExchangeQueryPort>>,
 }
 
 impl ExecutionService {
@@ -87,6 +107,10 @@ impl ExecutionService {
             order_repo: None,
             audit: None,
             applied_trade_ids: RwLock::new(HashSet::new()),
+            quality_guard: None,
+            feedback: None,
+            protection: ProtectiveOrderBook::new(),
+            exchange_query: None,
         }
     }
 
@@ -111,6 +135,10 @@ impl ExecutionService {
             order_repo: Some(order_repo),
             audit: None,
             applied_trade_ids: RwLock::new(HashSet::new()),
+            quality_guard: None,
+            feedback: None,
+            protection: ProtectiveOrderBook::new(),
+            exchange_query: None,
         }
     }
 
@@ -139,9 +167,37 @@ impl ExecutionService {
             order_repo,
             audit,
             applied_trade_ids: RwLock::new(HashSet::new()),
+            quality_guard: None,
+            feedback: None,
+            protection: ProtectiveOrderBook::new(),
+            exchange_query: None,
         }
     }
 
+    /// 接入行情质量守卫
+    ///
+    /// 接入后，质量为 Suspect 的交易对产生的交易意图会在风控前被拦截。
+    pub fn with_quality_guard(mut self, guard: Arc<dyn MarketQualityPort>) -> Self {
+        self.quality_guard = Some(guard);
+        self
+    }
+
+    /// 接入策略执行回报
+    ///
+    /// 接入后，拒单、成交与撤单会按 strategy_id 回送给策略引擎。
+    pub fn with_feedback(mut self, feedback: Arc<StrategyFeedbackService>) -> Self {
+        self.feedback = Some(feedback);
+        self
+    }
+
+    /// 接入交易所查询
+    ///
+    /// 接入后，多腿回滚撤单失败时按交易所查询到的成交数量平仓；未接入时不平仓并告警。
+    pub fn with_exchange_query(mut self, query: Arc<dyn ExchangeQueryPort>) -> Self {
+        self.exchange_query = Some(query);
+        self
+    }
+
     /// 处理行情事件
     ///
     /// 这是交易主链路的唯一入口。
@@ -154,17 +210,9 @@ impl ExecutionService {
     /// - `Ok(())`: 处理完成（不代表一定有交易）
     /// - `Err`: 处理失败
     pub async fn on_market_event(&self, event: &MarketEvent) -> anyhow::Result<()> {
-        // Step 1: 调用策略，获取交易意图
-        let intent = match self.strategy.evaluate(event).await {
-            Ok(Some(intent)) => {
-                info!(
-                    symbol = %intent.symbol,
-                    side = ?intent.side,
-                    quantity = %intent.quantity,
-                    "Strategy generated order intent"
-                );
-                intent
-            }
+        // Step 1: 调用策略，获取交易意图（多腿策略返回意图组）
+        let group = match self.strategy.evaluate_group(event).await {
+            Ok(Some(group)) => group,
             Ok(None) => {
                 // 策略无交易意图，正常情况
                 return Ok(());
@@ -175,24 +223,81 @@ impl ExecutionService {
             }
         };
 
+        self.on_strategy_group(group).await
+    }
+
+    /// 处理策略产出的意图（单意图或多腿组）
+    ///
+    /// 行情驱动的策略经 `on_market_event` 进入；异步信号由 SignalConsumerService 直接调用。
+    pub async fn on_strategy_group(&self, group: OrderIntentGroup) -> anyhow::Result<()> {
+        if !group.is_single() {
+            return self.on_intent_group(group).await;
+        }
+
+        let Some(intent) = group.into_single() else {
+            return Ok(());
+        };
+
+        info!(
+            symbol = %intent.symbol,
+            side = ?intent.side,
+            quantity = %intent.quantity,
+            "Strategy generated order intent"
+        );
+
+        // Step 1.4 - 2: 前置检查（过期 / 行情质量 / 风控）
+        if !self.screen_intent(&intent).await {
+            return Ok(());
+        }
+
+        // Step 3 - 4: 执行 + 后处理
+        self.submit_intent(&intent).await.map(|_| ())
+    }
+
+    /// 前置检查：过期 → 行情质量 → 风控
+    ///
+    /// 未通过时记录审计并回送拒单，返回 false。
+    async fn screen_intent(&self, intent: &OrderIntent) -> bool {
+        if !self.precheck_intent(intent).await {
+            return false;
+        }
+
         // Step 2: 调用风控，校验交易意图
         // 明确区分：Strategy None vs Risk Rejected
-        if let Err(risk_err) = self.risk.check(&intent).await {
-            // 风控拒绝 - 明确记录拒绝原因，不触发 Execution
-            let reject_reason = risk_err.to_string();
-            let reject_code = Self::extract_reject_code(&reject_reason);
-            
-            info!(
+        if let Err(risk_err) = self.risk.check(intent).await {
+            // 风控拒绝不是系统错误，只是不执行
+            self.reject_by_risk(intent, &risk_err).await;
+            return false;
+        }
+
+        info!(
+            symbol = %intent.symbol,
+            side = ?intent.side,
+            outcome = "RISK_PASSED",
+            "Order intent passed risk check, proceeding to execution"
+        );
+
+        true
+    }
+
+    /// 风控前的检查：过期 → 行情质量
+    ///
+    /// 未通过时记录审计并回送拒单，返回 false。
+    async fn precheck_intent(&self, intent: &OrderIntent) -> bool {
+        // Step 1.4: 过期拦截（策略指定了过期时间且已过期时不下单）
+        if intent.is_expired(Utc::now()) {
+            let reject_reason = format!(
+                "intent expired at {}",
+                intent.instruction.expires_at.map(|t| t.to_rfc3339()).unwrap_or_default()
+            );
+
+            warn!(
+                intent_id = %intent.id,
                 symbol = %intent.symbol,
-                side = ?intent.side,
-                quantity = %intent.quantity,
-                reject_reason = %reject_reason,
-                reject_code = %reject_code,
-                outcome = "RISK_REJECTED",
-                "Order intent rejected by risk check - execution skipped"
+                outcome = "EXPIRED",
+                "Order intent expired - execution skipped"
             );
 
-            // 记录风控拒绝事件（审计）
             if let Some(ref audit) = self.audit {
                 let reject_event = RiskRejectedEvent::new(
                     intent.strategy_id,
@@ -200,29 +305,130 @@ impl ExecutionService {
                     intent.side,
                     intent.quantity,
                     intent.price,
-                    reject_reason,
-                    reject_code,
+                    reject_reason.clone(),
+                    "EXPIRED".to_string(),
                 );
                 if let Err(e) = audit.record_risk_rejected(&reject_event).await {
-                    error!(error = %e, "Failed to record risk rejected event");
+                    error!(error = %e, "Failed to record expired intent event");
                 }
             }
 
-            // 风控拒绝不是系统错误，只是不执行，返回 Ok
-            return Ok(());
+            if let Some(ref feedback) = self.feedback {
+                feedback
+                    .on_intent_rejected(intent, None, RejectionSource::Expired, "EXPIRED", &reject_reason)
+                    .await;
+            }
+
+            return false;
         }
 
+        // Step 1.5: 行情质量拦截（数据异常时不下单）
+        if let Some(ref guard) = self.quality_guard {
+            if !guard.is_tradable(&intent.symbol) {
+                let quality = guard.quality(&intent.symbol);
+                let reject_reason = format!("data quality: symbol {} is {}", intent.symbol, quality);
+
+                warn!(
+                    symbol = %intent.symbol,
+                    quality = %quality,
+                    outcome = "QUALITY_BLOCKED",
+                    "Order intent blocked by market data quality - execution skipped"
+                );
+
+                if let Some(ref audit) = self.audit {
+                    let reject_event = RiskRejectedEvent::new(
+                        intent.strategy_id,
+                        intent.symbol.clone(),
+                        intent.side,
+                        intent.quantity,
+                        intent.price,
+                        reject_reason.clone(),
+                        "DATA_QUALITY".to_string(),
+                    );
+                    if let Err(e) = audit.record_risk_rejected(&reject_event).await {
+                        error!(error = %e, "Failed to record quality rejected event");
+                    }
+                }
+
+                if let Some(ref feedback) = self.feedback {
+                    feedback
+                        .on_intent_rejected(
+                            intent,
+                            None,
+                            RejectionSource::DataQuality,
+                            "DATA_QUALITY",
+                            &reject_reason,
+                        )
+                        .await;
+                }
+
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// 风控拒绝：明确记录拒绝原因（审计）并回送拒单，不触发 Execution
+    async fn reject_by_risk(&self, intent: &OrderIntent, risk_err: &anyhow::Error) {
+        let reject_reason = risk_err.to_string();
+        let reject_code = Self::extract_reject_code(&reject_reason);
+
         info!(
             symbol = %intent.symbol,
             side = ?intent.side,
-            outcome = "RISK_PASSED",
-            "Order intent passed risk check, proceeding to execution"
+            quantity = %intent.quantity,
+            reject_reason = %reject_reason,
+            reject_code = %reject_code,
+            outcome = "RISK_REJECTED",
+            "Order intent rejected by risk check - execution skipped"
         );
 
+        // 记录风控拒绝事件（审计）
+        if let Some(ref audit) = self.audit {
+            let reject_event = RiskRejectedEvent::new(
+                intent.strategy_id,
+                intent.symbol.clone(),
+                intent.side,
+                intent.quantity,
+                intent.price,
+                reject_reason.clone(),
+                reject_code.clone(),
+            );
+            if let Err(e) = audit.record_risk_rejected(&reject_event).await {
+                error!(error = %e, "Failed to record risk rejected event");
+            }
+        }
+
+        if let Some(ref feedback) = self.feedback {
+            feedback
+                .on_intent_rejected(
+                    intent,
+                    None,
+                    RejectionSource::Risk,
+                    &reject_code,
+                    &reject_reason,
+                )
+                .await;
+        }
+    }
+
+    /// 执行意图并完成后处理（更新风控状态 + 落库 + 审计）
+    ///
+    /// # 返回
+    /// - `Ok(Some(order_id))`: 交易所已接收订单
+    /// - `Ok(None)`: 交易所拒单（已审计、已回送）
+    /// - `Err`: 执行调用失败
+    async fn submit_intent(&self, intent: &OrderIntent) -> anyhow::Result<Option<String>> {
+        // Step 2.9: 登记附带的止损止盈（成交回报可能先于下单响应到达，须在下单前登记）
+        let closing = self.flip_closing_quantity(intent).await;
+        self.protection.register(intent, closing);
+
         // Step 3: 调用执行，执行交易意图
-        let result = match self.execution.execute(&intent).await {
+        let result = match self.execution.execute(intent).await {
             Ok(result) => result,
             Err(err) => {
+                self.protection.discard(intent);
                 // 记录执行失败事件
                 if let Some(ref audit) = self.audit {
                     let fail_event = ExecutionResultEvent::failure(
@@ -236,6 +442,17 @@ impl ExecutionService {
                         error!(error = %e, "Failed to record execution failure event");
                     }
                 }
+                if let Some(ref feedback) = self.feedback {
+                    feedback
+                        .on_intent_rejected(
+                            intent,
+                            None,
+                            RejectionSource::Exchange,
+                            "EXECUTION_ERROR",
+                            &err.to_string(),
+                        )
+                        .await;
+                }
                 warn!(
                     symbol = %intent.symbol,
                     error = %err,
@@ -276,6 +493,12 @@ impl ExecutionService {
                 );
             }
 
+            // 4.1.1 记录订单归属，成交回报据此路由给策略
+            if let Some(ref feedback) = self.feedback {
+                feedback.on_order_submitted(&result.order_id, intent).await;
+            }
+            self.protection.bind(intent, &result.order_id);
+
             // 4.2 等待真实成交回报（User Data Stream）驱动持仓和余额更新
             // 这里只记录下单时间，不做本地模拟成交。
 
@@ -343,6 +566,8 @@ impl ExecutionService {
                 }
             }
         } else {
+            self.protection.discard(intent);
+
             // 执行失败 - 如果之前添加了 open_order，需要移除
             // 注意：实际上执行失败时不会有 order_id，这里是防御性编程
             if let Some(ref risk_state) = self.risk_state {
@@ -368,6 +593,20 @@ impl ExecutionService {
                     error!(error = %e, "Failed to record execution failure event");
                 }
             }
+
+            if let Some(ref feedback) = self.feedback {
+                let order_id = (!result.order_id.is_empty()).then(|| result.order_id.clone());
+                let reason = result.error.clone().unwrap_or_else(|| "Unknown error".to_string());
+                feedback
+                    .on_intent_rejected(
+                        intent,
+                        order_id,
+                        RejectionSource::Exchange,
+                        "EXECUTION_FAILED",
+                        &reason,
+                    )
+                    .await;
+            }
             warn!(
                 symbol = %result.symbol,
                 error = ?result.error,
@@ -375,9 +614,238 @@ impl ExecutionService {
             );
         }
 
+        Ok(result.success.then_some(result.order_id))
+    }
+
+    /// 反手意图会先平掉的原有持仓数量
+    ///
+    /// 按策略持仓计算；持仓未知时视为 0（保护单覆盖全部成交，只减仓由交易所兜底）。
+    async fn flip_closing_quantity(&self, intent: &OrderIntent) -> Decimal {
+        if intent.instruction.action != PositionAction::Flip {
+            return Decimal::ZERO;
+        }
+        let position = match self.feedback {
+            Some(ref feedback) => feedback.position(intent.strategy_id, &intent.symbol).await,
+            None => None,
+        };
+        let Some(position) = position else {
+            warn!(intent_id = %intent.id, symbol = %intent.symbol, "Strategy position unknown, flip protection sized to full fill");
+            return Decimal::ZERO;
+        };
+        match intent.side {
+            order_intent::OrderSide::Buy if position.is_short() => position.quantity.abs(),
+            order_intent::OrderSide::Sell if position.is_long() => position.quantity,
+            _ => Decimal::ZERO,
+        }
+    }
+
+    /// 保护单处理
+    ///
+    /// - 保护单成交 → 撤销 OCO 对手单
+    /// - 入场单成交 → 按本笔新开仓数量挂出止损止盈（只减仓，跳过风控）
+    async fn apply_protection(&self, fill: &ExecutionFill) {
+        if let Some(sibling) = self.protection.take_sibling(&fill.order_id) {
+            match self.execution.cancel(&sibling.symbol, &sibling.order_id).await {
+                Ok(_) => {
+                    info!(order_id = %fill.order_id, sibling_id = %sibling.order_id, symbol = %sibling.symbol, "Protective order triggered, OCO sibling canceled");
+                }
+                Err(err) => {
+                    error!(order_id = %fill.order_id, sibling_id = %sibling.order_id, symbol = %sibling.symbol, error = %err, "Failed to cancel OCO sibling - manual intervention required");
+                }
+            }
+            return;
+        }
+
+        let protective = self.protection.on_entry_fill(fill);
+        let mut placed = Vec::with_capacity(protective.len());
+        for intent in &protective {
+            match self.submit_intent(intent).await {
+                Ok(Some(order_id)) => placed.push(order_id),
+                Ok(None) => {
+                    error!(entry_order_id = %fill.order_id, symbol = %intent.symbol, "Protective order rejected by exchange - position unprotected");
+                }
+                Err(err) => {
+                    error!(entry_order_id = %fill.order_id, symbol = %intent.symbol, error = %err, "Protective order failed - position unprotected");
+                }
+            }
+        }
+
+        if let [stop, target] = placed.as_slice() {
+            self.protection.link(&fill.symbol, stop, target);
+        }
+        if !placed.is_empty() {
+            info!(entry_order_id = %fill.order_id, symbol = %fill.symbol, quantity = %protective[0].quantity, placed = placed.len(), "Protective orders placed for fill");
+        }
+    }
+
+    /// 处理多腿意图组
+    ///
+    /// 流程：
+    /// 1. 所有腿先做前置检查，风控按整组合计影响校验，任一腿未通过则整组不下单
+    /// 2. 按顺序逐腿下单
+    /// 3. 任一腿失败：已下单的腿逆序撤单并平掉已成交部分，未下单的腿回送拒单
+    pub async fn on_intent_group(&self, group: OrderIntentGroup) -> anyhow::Result<()> {
+        if group.legs.is_empty() {
+            return Ok(());
+        }
+
+        info!(
+            group_id = %group.id,
+            strategy_id = %group.strategy_id,
+            leg_count = group.legs.len(),
+            "Strategy generated order intent group"
+        );
+
+        // Step G1: 前置检查全部腿
+        if let Some(failed) = self.find_screen_failure(&group).await {
+            let reason = format!("leg {} of group {} rejected before execution", failed, group.id);
+            warn!(group_id = %group.id, failed_leg = failed, outcome = "GROUP_REJECTED", "Order intent group rejected - no leg executed");
+            for (index, leg) in group.legs.iter().enumerate() {
+                if index != failed {
+                    self.reject_group_leg(leg, "GROUP_REJECTED", &reason).await;
+                }
+            }
+            return Ok(());
+        }
+
+        // Step G2: 逐腿下单
+        let mut executed: Vec<(&OrderIntent, String)> = Vec::with_capacity(group.legs.len());
+        for (index, leg) in group.legs.iter().enumerate() {
+            let failure = match self.submit_intent(leg).await {
+                Ok(Some(order_id)) => {
+                    executed.push((leg, order_id));
+                    continue;
+                }
+                Ok(None) => "exchange rejected".to_string(),
+                Err(err) => err.to_string(),
+            };
+
+            // Step G3: 腿失败 → 回滚已下单的腿，拒绝剩余的腿
+            let reason = format!("leg {} of group {} failed: {}", index, group.id, failure);
+            warn!(
+                group_id = %group.id,
+                failed_leg = index,
+                executed_legs = executed.len(),
+                error = %failure,
+                outcome = "GROUP_UNWIND",
+                "Order intent group leg failed - unwinding executed legs"
+            );
+
+            for (leg, order_id) in executed.iter().rev() {
+                self.unwind_group_leg(&group, leg, order_id).await;
+            }
+            for leg in group.legs.iter().skip(index + 1) {
+                self.reject_group_leg(leg, "GROUP_ABORTED", &reason).await;
+            }
+            return Ok(());
+        }
+
+        info!(group_id = %group.id, leg_count = executed.len(), outcome = "GROUP_EXECUTED", "Order intent group executed");
         Ok(())
     }
 
+    /// 整组前置检查，返回第一条未通过的腿序号
+    ///
+    /// 过期与行情质量逐腿检查；风控按整组校验，后面的腿计入前面腿的影响。
+    async fn find_screen_failure(&self, group: &OrderIntentGroup) -> Option<usize> {
+        for (index, leg) in group.legs.iter().enumerate() {
+            if !self.precheck_intent(leg).await {
+                return Some(index);
+            }
+        }
+
+        let (index, risk_err) = self.risk.check_group(&group.legs).await.err()?;
+        if let Some(leg) = group.legs.get(index) {
+            self.reject_by_risk(leg, &risk_err).await;
+        }
+        Some(index)
+    }
+
+    /// 回滚一条已被交易所接受的腿
+    ///
+    /// 先撤单，再按撤单时已成交的数量反向市价只减仓（跳过风控）；未成交则无需平仓。
+    /// 撤单失败时向交易所查询订单的实际成交数量；查询不到或订单仍未结束时不平仓
+    /// （现货不支持只减仓，按猜测的数量平仓可能反向开仓），告警人工处理。
+    async fn unwind_group_leg(&self, group: &OrderIntentGroup, leg: &OrderIntent, order_id: &str) {
+        let filled = match self.execution.cancel(&leg.symbol, order_id).await {
+            Ok(canceled) => canceled.filled_quantity.min(leg.quantity),
+            Err(err) => {
+                warn!(group_id = %group.id, leg_id = %leg.id, order_id, symbol = %leg.symbol, error = %err, "Group leg cancel failed - querying filled quantity");
+                match self.query_filled_quantity(leg, order_id).await {
+                    Some(filled) => filled,
+                    None => {
+                        error!(group_id = %group.id, leg_id = %leg.id, order_id, symbol = %leg.symbol, "Group leg filled quantity unknown - not unwound, manual intervention required");
+                        return;
+                    }
+                }
+            }
+        };
+
+        if filled <= Decimal::ZERO {
+            info!(group_id = %group.id, leg_id = %leg.id, order_id, symbol = %leg.symbol, "Group leg canceled before any fill - nothing to unwind");
+            return;
+        }
+
+        let unwind = leg.unwind(filled);
+        match self.submit_intent(&unwind).await {
+            Ok(Some(_)) => {
+                info!(group_id = %group.id, leg_id = %leg.id, unwind_id = %unwind.id, symbol = %leg.symbol, quantity = %filled, "Group leg unwound");
+            }
+            Ok(None) => {
+                error!(group_id = %group.id, leg_id = %leg.id, symbol = %leg.symbol, "Group leg unwind rejected by exchange - manual intervention required");
+            }
+            Err(err) => {
+                error!(group_id = %group.id, leg_id = %leg.id, symbol = %leg.symbol, error = %err, "Group leg unwind failed - manual intervention required");
+            }
+        }
+    }
+
+    /// 查询腿的实际成交数量（订单已结束时返回，仍未结束或无法查询时返回 None）
+    async fn query_filled_quantity(&self, leg: &OrderIntent, order_id: &str) -> Option<Decimal> {
+        let query = self.exchange_query.as_ref()?;
+        match query.get_order(&leg.symbol, order_id).await {
+            Ok(Some(order)) => match order.status {
+                ExchangeOrderStatus::New | ExchangeOrderStatus::PartiallyFilled => {
+                    warn!(leg_id = %leg.id, order_id, status = ?order.status, executed = %order.executed_qty, "Group leg order still open after cancel failure");
+                    None
+                }
+                _ => Some(order.executed_qty.min(leg.quantity)),
+            },
+            Ok(None) => {
+                warn!(leg_id = %leg.id, order_id, symbol = %leg.symbol, "Group leg order not found on exchange");
+                None
+            }
+            Err(err) => {
+                warn!(leg_id = %leg.id, order_id, symbol = %leg.symbol, error = %err, "Failed to query group leg order");
+                None
+            }
+        }
+    }
+
+    /// 回送同组连带拒绝
+    async fn reject_group_leg(&self, leg: &OrderIntent, code: &str, reason: &str) {
+        if let Some(ref audit) = self.audit {
+            let reject_event = RiskRejectedEvent::new(
+                leg.strategy_id,
+                leg.symbol.clone(),
+                leg.side,
+                leg.quantity,
+                leg.price,
+                reason.to_string(),
+                code.to_string(),
+            );
+            if let Err(e) = audit.record_risk_rejected(&reject_event).await {
+                error!(error = %e, "Failed to record group rejected event");
+            }
+        }
+
+        if let Some(ref feedback) = self.feedback {
+            feedback
+                .on_intent_rejected(leg, None, RejectionSource::LegGroup, code, reason)
+                .await;
+        }
+    }
+
     /// 从拒绝原因中提取拒绝代码
     fn extract_reject_code(reason: &str) -> String {
         // 简单实现：取第一个冒号前的部分作为代码
@@ -438,6 +906,8 @@ impl ExecutionService {
     /// # 参数
     /// - `order_id`: 订单 ID
     pub async fn on_order_canceled(&self, order_id: &str) {
+        self.protection.forget(order_id);
+
         if let Some(ref risk_state) = self.risk_state {
             risk_state.remove_open_order(order_id).await;
             info!(
@@ -539,6 +1009,19 @@ impl ExecutionService {
             applied.insert(fill.trade_id.clone());
         }
 
+        // 按策略回送成交与持仓快照（不依赖 RiskStatePort）
+        if let Some(ref feedback) = self.feedback {
+            let fallback = if feedback.is_tracked(&fill.order_id).await {
+                None
+            } else {
+                self.find_order_strategy_id(&fill.order_id).await
+            };
+            feedback.on_fill(fill, fallback).await;
+        }
+
+        // 止损止盈：入场成交后挂出，保护单成交后撤销对手单
+        self.apply_protection(fill).await;
+
         let Some(ref risk_state) = self.risk_state else {
             warn!("RiskStatePort not configured, skipping fill application");
             return;
@@ -720,6 +1203,24 @@ impl ExecutionService {
         );
     }
 
+    /// 通过订单仓储查找订单所属策略
+    ///
+    /// 用于重启后内存中没有订单归属的成交回报。
+    async fn find_order_strategy_id(&self, exchange_order_id: &str) -> Option<Uuid> {
+        let repo = self.order_repo.as_ref()?;
+        match repo.find_order_by_exchange_order_id(exchange_order_id).await {
+            Ok(order) => order.and_then(|o| o.strategy_id),
+            Err(e) => {
+                warn!(
+                    order_id = %exchange_order_id,
+                    error = %e,
+                    "Failed to look up order owner"
+                );
+                None
+            }
+        }
+    }
+
     /// 处理来自 WebSocket 的成交事件
     ///
     /// 这是 BinanceFillStream 的入口方法。
@@ -786,6 +1287,9 @@ impl ExecutionService {
                 ExecutionStreamEvent::Fill(fill) => self.on_execution_fill(fill).await,
                 ExecutionStreamEvent::Canceled(canceled) => {
                     self.on_order_canceled(&canceled.order_id).await;
+                    if let Some(ref feedback) = self.feedback {
+                        feedback.on_order_canceled(&canceled).await;
+                    }
                 }
             }
         }
@@ -9,6 +9,9 @@ pub mod execution_service;
 /// 行情事件消费服务 - 消费行情并转发给 ExecutionService
 pub mod market_event_consumer_service;
 
+/// 信号事件消费服务 - 消费异步策略信号并立即转发给 ExecutionService
+pub mod signal_consumer_service;
+
 /// 风控状态初始化服务 - 启动时从交易所同步状态到 RiskStatePort
 pub mod risk_state_initializer;
 
@@ -20,3 +23,9 @@ pub mod fill_processor;
 
 /// 订单生命周期服务 - 订单超时检测与处理 (v1.1 安全修补)
 pub mod order_lifecycle_service;
+
+/// 策略执行回报服务 - 按策略回送成交 / 拒单 / 持仓快照
+pub mod strategy_feedback_service;
+
+/// 附带止损止盈簿记 - 入场成交后挂出保护单，止损止盈互为 OCO
+pub mod protective_order_book;
@@ -0,0 +1,292 @@
+//! # 附带止损止盈簿记 (Protective Order Book)
+//!
+//! 路径: services/trading-engine/src/application/service/protective_order_book.rs
+//!
+//! ## 职责
+//! 记录开仓 / 反手意图附带的止损止盈，由 ExecutionService 在成交后挂出：
+//! - 下单前按客户端订单 ID 登记（成交回报可能先于下单响应到达）
+//! - 入场单每笔成交 → 按本笔新开仓数量生成一对保护单意图
+//! - 反手先抵扣平掉的原有持仓，只保护反向新开的部分
+//! - 一对保护单互为 OCO：任一成交即撤销另一张
+//!
+//! ## 架构约束
+//! - 只做簿记，不下单、不撤单
+//! - 入场单撤单 / 全部成交后移除登记
+
+use std::collections::HashMap;
+
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+
+use crate::domain::model::execution_fill::ExecutionFill;
+use crate::domain::model::order_intent::{OrderInstruction, OrderIntent, OrderSide, OrderType};
+
+/// 等待入场成交的保护单
+#[derive(Debug, Clone)]
+struct PendingProtection {
+    /// 入场意图
+    intent: OrderIntent,
+    /// 交易所订单 ID（下单响应后绑定）
+    order_id: Option<String>,
+    /// 尚未抵扣的平仓数量（仅反手）
+    closing: Decimal,
+}
+
+/// OCO 对手单
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OcoSibling {
+    /// 交易对
+    pub symbol: String,
+    /// 对手单的交易所订单 ID
+    pub order_id: String,
+}
+
+/// 附带止损止盈簿记
+#[derive(Debug, Default)]
+pub struct ProtectiveOrderBook {
+    /// 客户端订单 ID → 等待成交的保护单
+    pending: Mutex<HashMap<String, PendingProtection>>,
+    /// 保护单订单 ID → OCO 对手单
+    siblings: Mutex<HashMap<String, OcoSibling>>,
+}
+
+impl ProtectiveOrderBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 登记入场意图的止损止盈
+    ///
+    /// 非入场意图或未附带止损止盈时不登记，返回 false。
+    ///
+    /// # 参数
+    /// - `closing`: 反手时被平掉的原有持仓数量（开仓为 0）
+    pub fn register(&self, intent: &OrderIntent, closing: Decimal) -> bool {
+        let instruction = &intent.instruction;
+        if !instruction.action.is_entry()
+            || (instruction.stop_loss.is_none() && instruction.take_profit.is_none())
+        {
+            return false;
+        }
+
+        self.pending.lock().insert(
+            intent.id.to_string(),
+            PendingProtection {
+                intent: intent.clone(),
+                order_id: None,
+                closing: closing.max(Decimal::ZERO),
+            },
+        );
+        true
+    }
+
+    /// 绑定交易所订单 ID（用于按订单 ID 处理撤单）
+    pub fn bind(&self, intent: &OrderIntent, order_id: &str) {
+        if let Some(pending) = self.pending.lock().get_mut(&intent.id.to_string()) {
+            pending.order_id = Some(order_id.to_string());
+        }
+    }
+
+    /// 入场单成交，返回需要挂出的止损 / 止盈意图
+    ///
+    /// 数量为本笔成交中新开仓的部分；反手先抵扣平仓数量。
+    pub fn on_entry_fill(&self, fill: &ExecutionFill) -> Vec<OrderIntent> {
+        let mut pending = self.pending.lock();
+        let key = match fill.client_order_id.as_deref() {
+            Some(client_id) if pending.contains_key(client_id) => client_id.to_string(),
+            _ => match pending
+                .iter()
+                .find(|(_, p)| p.order_id.as_deref() == Some(fill.order_id.as_str()))
+            {
+                Some((key, _)) => key.clone(),
+                None => return Vec::new(),
+            },
+        };
+
+        let Some(entry) = pending.get_mut(&key) else {
+            return Vec::new();
+        };
+        let absorbed = entry.closing.min(fill.filled_quantity);
+        entry.closing -= absorbed;
+        let quantity = fill.filled_quantity - absorbed;
+        let intent = entry.intent.clone();
+        if fill.is_full() {
+            pending.remove(&key);
+        }
+        drop(pending);
+
+        if quantity <= Decimal::ZERO {
+            return Vec::new();
+        }
+        Self::protective_intents(&intent, quantity)
+    }
+
+    /// 关联一对止损止盈为 OCO
+    pub fn link(&self, symbol: &str, stop_order_id: &str, target_order_id: &str) {
+        let mut siblings = self.siblings.lock();
+        siblings.insert(
+            stop_order_id.to_string(),
+            OcoSibling { symbol: symbol.to_string(), order_id: target_order_id.to_string() },
+        );
+        siblings.insert(
+            target_order_id.to_string(),
+            OcoSibling { symbol: symbol.to_string(), order_id: stop_order_id.to_string() },
+        );
+    }
+
+    /// 保护单成交：取出需要撤销的对手单（同时解除关联）
+    pub fn take_sibling(&self, order_id: &str) -> Option<OcoSibling> {
+        let mut siblings = self.siblings.lock();
+        let sibling = siblings.remove(order_id)?;
+        siblings.remove(&sibling.order_id);
+        Some(sibling)
+    }
+
+    /// 订单已撤销：移除其入场登记和 OCO 关联
+    pub fn forget(&self, order_id: &str) {
+        self.pending
+            .lock()
+            .retain(|_, p| p.order_id.as_deref() != Some(order_id));
+        let mut siblings = self.siblings.lock();
+        if let Some(sibling) = siblings.remove(order_id) {
+            siblings.remove(&sibling.order_id);
+        }
+    }
+
+    /// 放弃登记（入场单未被交易所接受）
+    pub fn discard(&self, intent: &OrderIntent) {
+        self.pending.lock().remove(&intent.id.to_string());
+    }
+
+    /// 构建保护单意图：反向、只减仓、条件市价，止损在前
+    fn protective_intents(entry: &OrderIntent, quantity: Decimal) -> Vec<OrderIntent> {
+        let exit_side = match entry.side {
+            OrderSide::Buy => OrderSide::Sell,
+            OrderSide::Sell => OrderSide::Buy,
+        };
+        let instruction = &entry.instruction;
+        let triggers = [
+            (OrderType::StopLoss, instruction.stop_loss),
+            (OrderType::TakeProfit, instruction.take_profit),
+        ];
+
+        triggers
+            .into_iter()
+            .filter_map(|(order_type, trigger)| {
+                let trigger = trigger?;
+                let mut protective = OrderInstruction::reduce().with_order_type(order_type);
+                protective.position_side = instruction.position_side;
+                Some(
+                    OrderIntent::new(
+                        entry.strategy_id,
+                        entry.symbol.clone(),
+                        exit_side,
+                        quantity,
+                        Some(trigger),
+                        entry.confidence,
+                    )
+                    .with_instruction(protective),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    use uuid::Uuid;
+
+    use crate::domain::model::execution_fill::{FillSide, FillType};
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn entry(instruction: OrderInstruction, quantity: &str) -> OrderIntent {
+        let instruction = instruction.with_stop_loss(dec("49000")).with_take_profit(dec("52000"));
+        let (symbol, price) = ("BTCUSDT".to_string(), Some(dec("50000")));
+        OrderIntent::new(Uuid::new_v4(), symbol, OrderSide::Buy, dec(quantity), price, 0.8)
+            .with_instruction(instruction)
+    }
+
+    fn fill(intent: &OrderIntent, trade: &str, qty: &str, cumulative: &str) -> ExecutionFill {
+        let mut fill = ExecutionFill::partial(
+            "1".to_string(),
+            trade.to_string(),
+            intent.symbol.clone(),
+            FillSide::Buy,
+            dec(qty),
+            dec("50000"),
+            dec(cumulative),
+            intent.quantity,
+        );
+        if fill.cumulative_quantity >= intent.quantity {
+            fill.fill_type = FillType::Full;
+        }
+        fill.client_order_id = Some(intent.id.to_string());
+        fill
+    }
+
+    #[test]
+    fn test_protection_follows_each_fill() {
+        let book = ProtectiveOrderBook::new();
+        let intent = entry(OrderInstruction::open(), "0.3");
+        assert!(book.register(&intent, Decimal::ZERO));
+
+        let first = book.on_entry_fill(&fill(&intent, "t1", "0.1", "0.1"));
+        assert_eq!(first.len(), 2);
+        assert_eq!(first[0].instruction.order_type, Some(OrderType::StopLoss));
+        assert_eq!(first[0].price, Some(dec("49000")));
+        assert_eq!(first[1].instruction.order_type, Some(OrderType::TakeProfit));
+        assert!(first.iter().all(|p| p.quantity == dec("0.1") && p.side == OrderSide::Sell));
+        assert!(first.iter().all(|p| p.instruction.action.is_reduce_only()));
+
+        let last = book.on_entry_fill(&fill(&intent, "t2", "0.2", "0.3"));
+        assert!(last.iter().all(|p| p.quantity == dec("0.2")));
+
+        // 全部成交后登记移除
+        assert!(book.on_entry_fill(&fill(&intent, "t3", "0.2", "0.3")).is_empty());
+    }
+
+    #[test]
+    fn test_flip_protects_only_new_leg() {
+        let book = ProtectiveOrderBook::new();
+        let intent = entry(OrderInstruction::flip(), "3");
+        book.register(&intent, dec("1"));
+
+        // 第一笔全部用于平掉原有空头
+        assert!(book.on_entry_fill(&fill(&intent, "t1", "0.6", "0.6")).is_empty());
+
+        let protective = book.on_entry_fill(&fill(&intent, "t2", "2.4", "3"));
+        assert_eq!(protective.len(), 2);
+        assert!(protective.iter().all(|p| p.quantity == dec("2")));
+    }
+
+    #[test]
+    fn test_unprotected_or_canceled_entry_is_ignored() {
+        let book = ProtectiveOrderBook::new();
+        let symbol = "BTCUSDT".to_string();
+        let plain = OrderIntent::new(Uuid::new_v4(), symbol, OrderSide::Buy, dec("1"), None, 0.8);
+        assert!(!book.register(&plain, Decimal::ZERO));
+
+        let intent = entry(OrderInstruction::open(), "1");
+        book.register(&intent, Decimal::ZERO);
+        book.bind(&intent, "1");
+        book.forget("1");
+        assert!(book.on_entry_fill(&fill(&intent, "t1", "1", "1")).is_empty());
+    }
+
+    #[test]
+    fn test_oco_sibling_taken_once() {
+        let book = ProtectiveOrderBook::new();
+        book.link("BTCUSDT", "sl-1", "tp-1");
+
+        let sibling = book.take_sibling("sl-1").unwrap();
+        assert_eq!(sibling.symbol, "BTCUSDT");
+        assert_eq!(sibling.order_id, "tp-1");
+        assert!(book.take_sibling("tp-1").is_none());
+    }
+}
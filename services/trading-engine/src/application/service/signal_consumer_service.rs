@@ -0,0 +1,62 @@
+//! # 信号事件消费服务 (Signal Consumer Service)
+//!
+//! 异步策略模式：消费策略信号，立即转发给 ExecutionService 执行，不等待行情事件。
+//!
+//! ## 职责
+//! - 消费 SignalEventPort 交出的意图组
+//! - 调用 ExecutionService.on_strategy_group()
+//! - 处理完成后提交位点（先执行后提交，崩溃时信号重新投递而不是丢失）
+//!
+//! ## 禁止
+//! - 禁止直接调用 RiskPort / ExecutionPort
+//! - 禁止包含任何业务逻辑
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tracing::{error, info};
+
+use crate::application::service::execution_service::ExecutionService;
+use crate::domain::port::signal_event_port::SignalEventPort;
+
+/// 信号事件消费服务
+pub struct SignalConsumerService {
+    source: Arc<dyn SignalEventPort>,
+    execution: Arc<ExecutionService>,
+}
+
+impl SignalConsumerService {
+    /// 创建信号事件消费服务
+    ///
+    /// # 参数
+    /// - `source`: 信号事件源
+    /// - `execution`: 交易主链路调度服务
+    pub fn new(source: Arc<dyn SignalEventPort>, execution: Arc<ExecutionService>) -> Self {
+        Self { source, execution }
+    }
+
+    /// 运行消费循环
+    pub async fn run(&self) {
+        info!("SignalConsumerService started, waiting for signal events...");
+
+        loop {
+            if let Err(err) = self.process_next().await {
+                error!(error = %err, "Signal event source error");
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        }
+    }
+
+    /// 处理一条信号：执行后提交位点
+    ///
+    /// 执行失败（拒单、下单错误）已由 ExecutionService 记录与回送，同样提交位点，不重复执行。
+    pub async fn process_next(&self) -> anyhow::Result<()> {
+        if let Some(group) = self.source.next_signal().await? {
+            let group_id = group.id;
+            if let Err(err) = self.execution.on_strategy_group(group).await {
+                error!(group_id = %group_id, error = %err, "ExecutionService error");
+            }
+        }
+        self.source.commit().await
+    }
+}
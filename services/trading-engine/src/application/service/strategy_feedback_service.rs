@@ -0,0 +1,613 @@
+//! # 策略执行回报服务 (Strategy Feedback Service)
+//!
+//! 路径: services/trading-engine/src/application/service/strategy_feedback_service.rs
+//!
+//! ## 职责
+//! 将订单执行结果按策略归属回送给策略引擎：
+//! - 下单成功 → 记录 order_id → (strategy_id, symbol) 归属
+//! - 成交 → Fill + PositionSnapshot
+//! - 质量拦截 / 风控拒绝 / 下单失败 / 撤单（未成交部分）→ OrderRejected + PositionSnapshot
+//!
+//! ## 持仓口径
+//! 按 (strategy_id, symbol) 维护净持仓与均价，只由真实成交驱动。
+//! 与 RiskStatePort 的账户级持仓相互独立。
+//!
+//! 内存持仓在重启后丢失，启动时由 `restore_positions` 按已落库的策略订单成交重建。
+//! 重建完成前持仓未知：只回送成交 / 拒单，不发布持仓快照，避免用错误的快照覆盖
+//! 策略引擎从检查点恢复的状态。
+//!
+//! ## 订单归属
+//! 全部成交或撤单后移除；超过 `OWNER_TTL` 仍未结束的归属被淘汰，
+//! 之后的回报由调用方按订单仓储提供归属（`fallback_strategy_id`）。
+//!
+//! ## 架构约束
+//! - 只依赖 ExecutionFeedbackPort，不感知 Kafka
+//! - 发布失败只记录告警，不影响交易主链路
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rust_decimal::Decimal;
+use shared::event::execution_feedback_event::{
+    ExecutionFeedback, ExecutionFeedbackEvent, OrderRejection, PositionSnapshot, RejectionSource,
+    StrategyFill,
+};
+use shared::types::order::OrderSide;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::domain::model::execution_fill::{
+    CancelReason, ExecutionFill, FillSide, FillType, OrderCanceled,
+};
+use crate::domain::model::order::{self, Order};
+use crate::domain::model::order_intent::{self, OrderIntent};
+use crate::domain::port::execution_feedback_port::ExecutionFeedbackPort;
+
+/// 订单归属保留时长（超过后淘汰）
+const OWNER_TTL: Duration = Duration::from_secs(24 * 3600);
+
+/// 归属表超过该条数时清理过期归属
+const OWNER_SWEEP_THRESHOLD: usize = 10_000;
+
+/// 订单归属
+#[derive(Debug, Clone)]
+struct OrderOwner {
+    strategy_id: Uuid,
+    symbol: String,
+    submitted_at: Instant,
+}
+
+/// 策略在单个交易对上的持仓
+#[derive(Debug, Clone, Copy, Default)]
+struct StrategyPosition {
+    /// 净持仓（正数为多头，负数为空头）
+    quantity: Decimal,
+    /// 持仓均价
+    average_price: Decimal,
+}
+
+impl StrategyPosition {
+    /// 应用一笔成交
+    ///
+    /// - 同向加仓：按数量加权更新均价
+    /// - 反向减仓：均价不变，归零时清空均价
+    /// - 反向穿越：剩余部分以成交价为均价
+    fn apply(&mut self, delta: Decimal, price: Decimal) {
+        let new_quantity = self.quantity + delta;
+
+        if self.quantity.is_zero() || self.quantity.is_sign_positive() == delta.is_sign_positive() {
+            let total = self.quantity.abs() + delta.abs();
+            if !total.is_zero() {
+                self.average_price =
+                    (self.average_price * self.quantity.abs() + price * delta.abs()) / total;
+            }
+        } else if new_quantity.is_zero() {
+            self.average_price = Decimal::ZERO;
+        } else if new_quantity.is_sign_positive() != self.quantity.is_sign_positive() {
+            self.average_price = price;
+        }
+
+        self.quantity = new_quantity;
+    }
+
+    fn snapshot(&self) -> PositionSnapshot {
+        PositionSnapshot {
+            quantity: self.quantity,
+            average_price: self.average_price,
+        }
+    }
+}
+
+/// 策略执行回报服务
+pub struct StrategyFeedbackService {
+    port: Arc<dyn ExecutionFeedbackPort>,
+    /// 交易所订单 ID → 归属
+    owners: RwLock<HashMap<String, OrderOwner>>,
+    /// (strategy_id, symbol) → 持仓
+    positions: RwLock<HashMap<(Uuid, String), StrategyPosition>>,
+    /// 持仓是否已按落库成交重建（重建前不发布持仓快照）
+    positions_restored: AtomicBool,
+}
+
+impl StrategyFeedbackService {
+    /// 创建策略执行回报服务
+    pub fn new(port: Arc<dyn ExecutionFeedbackPort>) -> Self {
+        Self {
+            port,
+            owners: RwLock::new(HashMap::new()),
+            positions: RwLock::new(HashMap::new()),
+            positions_restored: AtomicBool::new(false),
+        }
+    }
+
+    /// 按已落库的策略订单成交重建持仓，完成后开始发布持仓快照
+    ///
+    /// # 参数
+    /// - `orders`: 有成交的策略订单（按创建时间升序），每笔按累计成交量与成交均价计入
+    ///
+    /// 应在开始处理成交回报之前调用。
+    pub async fn restore_positions(&self, orders: &[Order]) {
+        let mut positions = self.positions.write().await;
+        positions.clear();
+        for order in orders {
+            let Some(strategy_id) = order.strategy_id else {
+                continue;
+            };
+            let Some(price) = order.average_price.or(order.price) else {
+                continue;
+            };
+            let delta = match order.side {
+                order::OrderSide::Buy => order.filled_quantity,
+                order::OrderSide::Sell => -order.filled_quantity,
+            };
+            if delta.is_zero() {
+                continue;
+            }
+            positions
+                .entry((strategy_id, order.symbol.clone()))
+                .or_default()
+                .apply(delta, price);
+        }
+        self.positions_restored.store(true, Ordering::Release);
+
+        info!(
+            orders = orders.len(),
+            positions = positions.len(),
+            "Strategy positions restored from persisted fills"
+        );
+    }
+
+    /// 记录下单成功的订单归属
+    pub async fn on_order_submitted(&self, order_id: &str, intent: &OrderIntent) {
+        if order_id.is_empty() {
+            return;
+        }
+        let mut owners = self.owners.write().await;
+        if owners.len() >= OWNER_SWEEP_THRESHOLD {
+            let before = owners.len();
+            owners.retain(|_, owner| owner.submitted_at.elapsed() < OWNER_TTL);
+            debug!(evicted = before - owners.len(), "Expired order owners evicted");
+        }
+        owners.insert(
+            order_id.to_string(),
+            OrderOwner {
+                strategy_id: intent.strategy_id,
+                symbol: intent.symbol.clone(),
+                submitted_at: Instant::now(),
+            },
+        );
+    }
+
+    /// 订单是否已记录归属
+    pub async fn is_tracked(&self, order_id: &str) -> bool {
+        self.owners.read().await.contains_key(order_id)
+    }
+
+    /// 交易意图在下单前或下单时被拒绝
+    ///
+    /// # 参数
+    /// - `intent`: 被拒绝的交易意图
+    /// - `order_id`: 交易所订单 ID（下单前被拦截时为空）
+    /// - `source`: 拒绝来源
+    /// - `code`: 拒绝代码
+    /// - `reason`: 拒绝原因
+    pub async fn on_intent_rejected(
+        &self,
+        intent: &OrderIntent,
+        order_id: Option<String>,
+        source: RejectionSource,
+        code: &str,
+        reason: &str,
+    ) {
+        let rejection = OrderRejection {
+            order_id,
+            side: match intent.side {
+                order_intent::OrderSide::Buy => OrderSide::Buy,
+                order_intent::OrderSide::Sell => OrderSide::Sell,
+            },
+            quantity: intent.quantity,
+            price: intent.price,
+            source,
+            code: code.to_string(),
+            reason: reason.to_string(),
+        };
+
+        self.publish_with_snapshot(
+            intent.strategy_id,
+            &intent.symbol,
+            ExecutionFeedback::OrderRejected(rejection),
+        )
+        .await;
+    }
+
+    /// 成交回报
+    ///
+    /// # 参数
+    /// - `fill`: 成交回报（调用方已完成 trade_id 幂等判断）
+    /// - `fallback_strategy_id`: 内存中无归属时的策略 ID（如重启后由订单仓储查得）
+    pub async fn on_fill(&self, fill: &ExecutionFill, fallback_strategy_id: Option<Uuid>) {
+        let owner = self.owners.read().await.get(&fill.order_id).cloned();
+        let Some(strategy_id) = owner.map(|o| o.strategy_id).or(fallback_strategy_id) else {
+            debug!(
+                order_id = %fill.order_id,
+                "Fill has no owning strategy, feedback skipped"
+            );
+            return;
+        };
+
+        {
+            let mut positions = self.positions.write().await;
+            positions
+                .entry((strategy_id, fill.symbol.clone()))
+                .or_default()
+                .apply(fill.position_delta(), fill.fill_price);
+        }
+
+        if fill.fill_type == FillType::Full {
+            self.owners.write().await.remove(&fill.order_id);
+        }
+
+        let strategy_fill = StrategyFill {
+            order_id: fill.order_id.clone(),
+            trade_id: fill.trade_id.clone(),
+            side: Self::order_side(fill.side),
+            quantity: fill.filled_quantity,
+            price: fill.fill_price,
+            cumulative_quantity: fill.cumulative_quantity,
+            original_quantity: fill.original_quantity,
+            is_final: fill.is_full(),
+            commission: fill.commission,
+            commission_asset: fill.commission_asset.clone(),
+            fill_time: fill.fill_time,
+        };
+
+        self.publish_with_snapshot(strategy_id, &fill.symbol, ExecutionFeedback::Fill(strategy_fill))
+            .await;
+    }
+
+    /// 撤单回报
+    ///
+    /// 未成交部分作为 `RejectionSource::Canceled` 回送，已全部成交的订单不回送。
+    pub async fn on_order_canceled(&self, canceled: &OrderCanceled) {
+        let Some(owner) = self.owners.write().await.remove(&canceled.order_id) else {
+            return;
+        };
+        if !canceled.has_unfilled() {
+            return;
+        }
+
+        let code = match canceled.reason {
+            CancelReason::UserRequested => "USER_REQUESTED",
+            CancelReason::SystemCanceled => "SYSTEM_CANCELED",
+            CancelReason::Expired => "EXPIRED",
+            CancelReason::ExchangeRejected => "EXCHANGE_REJECTED",
+        };
+
+        let rejection = OrderRejection {
+            order_id: Some(canceled.order_id.clone()),
+            side: Self::order_side(canceled.side),
+            quantity: canceled.unfilled_quantity(),
+            price: None,
+            source: RejectionSource::Canceled,
+            code: code.to_string(),
+            reason: format!(
+                "order canceled with {} unfilled",
+                canceled.unfilled_quantity()
+            ),
+        };
+
+        self.publish_with_snapshot(
+            owner.strategy_id,
+            &owner.symbol,
+            ExecutionFeedback::OrderRejected(rejection),
+        )
+        .await;
+    }
+
+    /// 查询策略在交易对上的持仓快照
+    ///
+    /// 持仓尚未重建时返回 `None`（未知）。
+    pub async fn position(&self, strategy_id: Uuid, symbol: &str) -> Option<PositionSnapshot> {
+        if !self.positions_restored.load(Ordering::Acquire) {
+            return None;
+        }
+        let snapshot = self
+            .positions
+            .read()
+            .await
+            .get(&(strategy_id, symbol.to_string()))
+            .copied()
+            .unwrap_or_default()
+            .snapshot();
+        Some(snapshot)
+    }
+
+    /// 发布回报，持仓已知时紧跟一条持仓快照
+    async fn publish_with_snapshot(&self, strategy_id: Uuid, symbol: &str, feedback: ExecutionFeedback) {
+        self.publish(ExecutionFeedbackEvent::new(strategy_id, symbol, feedback))
+            .await;
+
+        let Some(snapshot) = self.position(strategy_id, symbol).await else {
+            debug!(%strategy_id, symbol, "Position unknown until restored, snapshot skipped");
+            return;
+        };
+        self.publish(ExecutionFeedbackEvent::new(
+            strategy_id,
+            symbol,
+            ExecutionFeedback::PositionSnapshot(snapshot),
+        ))
+        .await;
+    }
+
+    async fn publish(&self, event: ExecutionFeedbackEvent) {
+        if let Err(e) = self.port.publish(&event).await {
+            warn!(
+                strategy_id = %event.strategy_id,
+                symbol = %event.symbol,
+                error = %e,
+                "Failed to publish execution feedback"
+            );
+        }
+    }
+
+    fn order_side(side: FillSide) -> OrderSide {
+        match side {
+            FillSide::Buy => OrderSide::Buy,
+            FillSide::Sell => OrderSide::Sell,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use parking_lot::Mutex;
+
+    #[derive(Default)]
+    struct RecordingPort {
+        events: Mutex<Vec<ExecutionFeedbackEvent>>,
+    }
+
+    #[async_trait]
+    impl ExecutionFeedbackPort for RecordingPort {
+        async fn publish(&self, event: &ExecutionFeedbackEvent) -> anyhow::Result<()> {
+            self.events.lock().push(event.clone());
+            Ok(())
+        }
+    }
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap_or_default()
+    }
+
+    async fn setup() -> (StrategyFeedbackService, Arc<RecordingPort>) {
+        let port = Arc::new(RecordingPort::default());
+        let service = StrategyFeedbackService::new(port.clone());
+        service.restore_positions(&[]).await;
+        (service, port)
+    }
+
+    fn intent(strategy_id: Uuid, side: order_intent::OrderSide, quantity: &str) -> OrderIntent {
+        OrderIntent::new(
+            strategy_id,
+            "BTCUSDT".to_string(),
+            side,
+            dec(quantity),
+            Some(dec("50000")),
+            1.0,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_partial_fill_then_cancel() {
+        let (service, port) = setup().await;
+        let strategy_id = Uuid::new_v4();
+
+        service
+            .on_order_submitted("o1", &intent(strategy_id, order_intent::OrderSide::Buy, "1"))
+            .await;
+
+        let fill = ExecutionFill::partial(
+            "o1".to_string(),
+            "t1".to_string(),
+            "BTCUSDT".to_string(),
+            FillSide::Buy,
+            dec("0.4"),
+            dec("50000"),
+            dec("0.4"),
+            dec("1"),
+        );
+        service.on_fill(&fill, None).await;
+
+        let canceled = OrderCanceled::new(
+            "o1".to_string(),
+            "BTCUSDT".to_string(),
+            FillSide::Buy,
+            dec("1"),
+            dec("0.4"),
+            CancelReason::Expired,
+        );
+        service.on_order_canceled(&canceled).await;
+
+        let events = port.events.lock();
+        assert_eq!(events.len(), 4);
+        assert!(events.iter().all(|e| e.strategy_id == strategy_id));
+        assert!(matches!(events[0].feedback, ExecutionFeedback::Fill(ref f) if !f.is_final));
+        match &events[2].feedback {
+            ExecutionFeedback::OrderRejected(r) => {
+                assert_eq!(r.source, RejectionSource::Canceled);
+                assert_eq!(r.quantity, dec("0.6"));
+            }
+            other => panic!("unexpected feedback: {:?}", other),
+        }
+        match &events[3].feedback {
+            ExecutionFeedback::PositionSnapshot(s) => {
+                assert_eq!(s.quantity, dec("0.4"));
+                assert_eq!(s.average_price, dec("50000"));
+            }
+            other => panic!("unexpected feedback: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rejection_publishes_flat_snapshot() {
+        let (service, port) = setup().await;
+        let strategy_id = Uuid::new_v4();
+
+        service
+            .on_intent_rejected(
+                &intent(strategy_id, order_intent::OrderSide::Sell, "1"),
+                None,
+                RejectionSource::Risk,
+                "MAX_POSITION",
+                "max position exceeded",
+            )
+            .await;
+
+        let events = port.events.lock();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0].feedback, ExecutionFeedback::OrderRejected(_)));
+        assert!(matches!(events[1].feedback, ExecutionFeedback::PositionSnapshot(ref s) if s.is_flat()));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_order_uses_fallback_owner() {
+        let (service, port) = setup().await;
+        let fill = ExecutionFill::full(
+            "o9".to_string(),
+            "t9".to_string(),
+            "BTCUSDT".to_string(),
+            FillSide::Sell,
+            dec("2"),
+            dec("100"),
+        );
+
+        service.on_fill(&fill, None).await;
+        assert!(port.events.lock().is_empty());
+
+        let strategy_id = Uuid::new_v4();
+        service.on_fill(&fill, Some(strategy_id)).await;
+        let snapshot = service.position(strategy_id, "BTCUSDT").await.unwrap();
+        assert!(snapshot.is_short());
+        assert_eq!(snapshot.average_price, dec("100"));
+    }
+
+    fn persisted_order(strategy_id: Uuid, side: order::OrderSide, filled: &str, price: &str) -> Order {
+        Order {
+            id: Uuid::new_v4(),
+            user_id: Uuid::nil(),
+            strategy_id: Some(strategy_id),
+            symbol: "BTCUSDT".to_string(),
+            order_type: order::OrderType::Limit,
+            side,
+            quantity: dec(filled),
+            price: Some(dec(price)),
+            status: order::OrderStatus::Filled,
+            filled_quantity: dec(filled),
+            average_price: Some(dec(price)),
+            exchange_order_id: Some(Uuid::new_v4().to_string()),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_no_snapshot_until_positions_restored() {
+        let port = Arc::new(RecordingPort::default());
+        let service = StrategyFeedbackService::new(port.clone());
+        let strategy_id = Uuid::new_v4();
+
+        let fill = ExecutionFill::full(
+            "o1".to_string(),
+            "t1".to_string(),
+            "BTCUSDT".to_string(),
+            FillSide::Buy,
+            dec("1"),
+            dec("100"),
+        );
+        service.on_fill(&fill, Some(strategy_id)).await;
+        assert!(service.position(strategy_id, "BTCUSDT").await.is_none());
+        {
+            let events = port.events.lock();
+            assert_eq!(events.len(), 1);
+            assert!(matches!(events[0].feedback, ExecutionFeedback::Fill(_)));
+        }
+
+        // 重建：已落库成交 +2 @100、-0.5 @120
+        service
+            .restore_positions(&[
+                persisted_order(strategy_id, order::OrderSide::Buy, "2", "100"),
+                persisted_order(strategy_id, order::OrderSide::Sell, "0.5", "120"),
+            ])
+            .await;
+        let snapshot = service.position(strategy_id, "BTCUSDT").await.unwrap();
+        assert_eq!(snapshot.quantity, dec("1.5"));
+        assert_eq!(snapshot.average_price, dec("100"));
+
+        let fill = ExecutionFill::full(
+            "o2".to_string(),
+            "t2".to_string(),
+            "BTCUSDT".to_string(),
+            FillSide::Sell,
+            dec("1.5"),
+            dec("130"),
+        );
+        service.on_fill(&fill, Some(strategy_id)).await;
+        let events = port.events.lock();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[2].feedback, ExecutionFeedback::PositionSnapshot(ref s) if s.is_flat()));
+    }
+
+    #[tokio::test]
+    async fn test_expired_owners_are_evicted() {
+        let (service, _port) = setup().await;
+        let strategy_id = Uuid::new_v4();
+        let stale = Instant::now().checked_sub(OWNER_TTL + Duration::from_secs(1));
+        let Some(stale) = stale else {
+            return;
+        };
+        {
+            let mut owners = service.owners.write().await;
+            for i in 0..OWNER_SWEEP_THRESHOLD {
+                owners.insert(
+                    format!("old-{}", i),
+                    OrderOwner {
+                        strategy_id,
+                        symbol: "BTCUSDT".to_string(),
+                        submitted_at: stale,
+                    },
+                );
+            }
+        }
+
+        service
+            .on_order_submitted("new", &intent(strategy_id, order_intent::OrderSide::Buy, "1"))
+            .await;
+        assert_eq!(service.owners.read().await.len(), 1);
+        assert!(service.is_tracked("new").await);
+    }
+
+    #[test]
+    fn test_position_averaging_and_flip() {
+        let mut position = StrategyPosition::default();
+        position.apply(dec("1"), dec("100"));
+        position.apply(dec("1"), dec("200"));
+        assert_eq!(position.average_price, dec("150"));
+
+        position.apply(dec("-1"), dec("300"));
+        assert_eq!(position.quantity, dec("1"));
+        assert_eq!(position.average_price, dec("150"));
+
+        position.apply(dec("-3"), dec("120"));
+        assert_eq!(position.quantity, dec("-2"));
+        assert_eq!(position.average_price, dec("120"));
+
+        position.apply(dec("2"), dec("110"));
+        assert!(position.quantity.is_zero());
+        assert!(position.average_price.is_zero());
+    }
+}
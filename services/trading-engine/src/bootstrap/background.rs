@@ -5,6 +5,7 @@
 //! ## 职责
 //! 统一启动所有后台服务，包括：
 //! - OrderLifecycleService（订单超时检测）
+//! - 行情质量状态消费（可选）
 //! - 其他后台任务
 //!
 //! ## 架构约束
@@ -18,6 +19,7 @@ use tokio::task::JoinHandle;
 use tracing::{info, error};
 
 use crate::domain::port::risk_state_port::RiskStatePort;
+use super::quality::QualityStatusWorker;
 use crate::application::service::order_lifecycle_service::{
     OrderLifecycleConfig,
     OrderLifecycleService,
@@ -30,6 +32,8 @@ use crate::application::service::order_lifecycle_service::{
 pub struct BackgroundHandles {
     /// 订单生命周期服务句柄
     pub order_lifecycle: JoinHandle<()>,
+    /// 行情质量状态消费句柄（未启用时为 None）
+    pub quality_status: Option<JoinHandle<()>>,
     // 未来可添加更多后台服务句柄
 }
 
@@ -37,6 +41,9 @@ impl BackgroundHandles {
     /// 中止所有后台服务
     pub fn abort_all(&self) {
         self.order_lifecycle.abort();
+        if let Some(ref handle) = self.quality_status {
+            handle.abort();
+        }
         info!("所有后台服务已中止");
     }
 }
@@ -45,24 +52,37 @@ impl BackgroundHandles {
 ///
 /// # 参数
 /// - `risk_state`: 风控状态端口（共享实例）
+/// - `quality_status`: 行情质量状态消费任务（可选）
 ///
 /// # 返回
 /// - `BackgroundHandles`: 后台服务句柄集合
 ///
 /// # 启动的服务
 /// - OrderLifecycleService: 订单超时检测（v1.1 安全修补）
+/// - QualityStatusWorker: 行情质量状态消费
 pub fn start_background_services(
     risk_state: Arc<dyn RiskStatePort>,
+    quality_status: Option<QualityStatusWorker>,
 ) -> BackgroundHandles {
     info!("启动后台服务...");
 
     // 1. 启动订单生命周期服务
     let order_lifecycle_handle = start_order_lifecycle_service(risk_state);
 
+    // 2. 启动行情质量状态消费
+    let quality_status_handle = quality_status.map(|worker| {
+        tokio::spawn(async move {
+            info!("行情质量状态消费已启动");
+            worker.run().await;
+            error!("行情质量状态消费意外退出");
+        })
+    });
+
     info!("所有后台服务已启动");
 
     BackgroundHandles {
         order_lifecycle: order_lifecycle_handle,
+        quality_status: quality_status_handle,
     }
 }
 
@@ -16,6 +16,8 @@
 
 use std::sync::Arc;
 
+use crate::domain::port::exchange_query_port::ExchangeQueryPort;
+use crate::domain::port::market_quality_port::MarketQualityPort;
 use crate::domain::port::order_repository_port::OrderRepositoryPort;
 use crate::domain::port::risk_state_port::RiskStatePort;
 use crate::domain::port::trade_audit_port::TradeAuditPort;
@@ -24,9 +26,11 @@ use crate::infrastructure::repository::PostgresOrderRepository;
 use crate::infrastructure::audit::PostgresTradeAuditAdapter;
 use crate::application::service::execution_service::ExecutionService;
 use crate::application::service::market_event_consumer_service::MarketEventConsumerService;
+use crate::application::service::signal_consumer_service::SignalConsumerService;
+use crate::application::service::strategy_feedback_service::StrategyFeedbackService;
 
 use super::database::create_postgres_pool;
-use super::strategy::create_strategy_port;
+use super::strategy::{create_strategy_port, SignalStreamConfig, StrategyComponents};
 use super::risk::create_risk_port;
 use super::execution::{create_execution_port, create_order_execution_port};
 
@@ -40,6 +44,8 @@ pub struct ConsumerConfig {
     // 策略配置
     pub strategy_mode: String,
     pub strategy_url: Option<String>,
+    /// 信号事件消费配置（kafka 策略模式）
+    pub signal_stream: SignalStreamConfig,
     // 执行配置
     pub execution_mode: String,
     pub binance_api_key: Option<String>,
@@ -58,6 +64,12 @@ pub struct ConsumerConfigWithState {
     pub config: ConsumerConfig,
     /// 风控状态端口（共享实例，必须从外部传入）
     pub risk_state: Arc<dyn RiskStatePort>,
+    /// 行情质量守卫（可选，拦截数据异常交易对的下单）
+    pub quality_guard: Option<Arc<dyn MarketQualityPort>>,
+    /// 策略执行回报（可选，向策略回送成交 / 拒单 / 持仓快照）
+    pub feedback: Option<Arc<StrategyFeedbackService>>,
+    /// 交易所查询（可选，多腿回滚撤单失败时查询实际成交数量）
+    pub exchange_query: Option<Arc<dyn ExchangeQueryPort>>,
 }
 
 /// 创建行情事件消费服务（交易主链路）
@@ -86,6 +98,9 @@ pub async fn create_market_event_consumer(
     let config_with_state = ConsumerConfigWithState {
         config,
         risk_state,
+        quality_guard: None,
+        feedback: None,
+        exchange_query: None,
     };
     
     create_market_event_consumer_with_state(config_with_state).await
@@ -105,17 +120,29 @@ pub async fn create_market_event_consumer(
 pub async fn create_market_event_consumer_with_state(
     config: ConsumerConfigWithState,
 ) -> anyhow::Result<MarketEventConsumerService> {
-    let ConsumerConfigWithState { config, risk_state } = config;
-    // 1. 创建行情事件源
+    let ConsumerConfigWithState {
+        config,
+        risk_state,
+        quality_guard,
+        feedback,
+        exchange_query,
+    } = config;
+    // 1. 创建策略端口（kafka 模式同时创建信号事件源）
+    let StrategyComponents { port: strategy, signals } = create_strategy_port(
+        &config.strategy_mode,
+        config.strategy_url,
+        &config.kafka_brokers,
+        &config.kafka_consumer_group,
+        config.signal_stream,
+    )?;
+
+    // 2. 创建行情事件源
     let source = Arc::new(MarketEventKafkaConsumer::new(
         config.kafka_brokers,
         config.kafka_market_topic,
         config.kafka_consumer_group,
     )?);
 
-    // 2. 创建策略端口
-    let strategy = create_strategy_port(&config.strategy_mode, config.strategy_url)?;
-
     // 3. 创建风控端口（远程模式）
     let risk = create_risk_port(config.risk_url);
 
@@ -132,6 +159,7 @@ pub async fn create_market_event_consumer_with_state(
     // 不再内部创建，确保整个系统使用同一个实例
 
     // 6. 创建 ExecutionService（核心调度）
+    let mut order_store: Option<Arc<dyn OrderRepositoryPort>> = None;
     let execution_service = if config.storage_enabled {
         // 尝试创建数据库连接池
         match create_postgres_pool().await {
@@ -140,40 +168,84 @@ pub async fn create_market_event_consumer_with_state(
                     Arc::new(PostgresOrderRepository::new(pool.clone()));
                 let audit: Arc<dyn TradeAuditPort> = Arc::new(PostgresTradeAuditAdapter::new(pool));
                 tracing::info!("订单存储与交易审计已启用 (postgres)");
-                Arc::new(ExecutionService::with_full_config(
+                order_store = Some(Arc::clone(&order_repo));
+                ExecutionService::with_full_config(
                     strategy,
                     risk,
                     execution,
                     Some(risk_state),
                     Some(order_repo),
                     Some(audit),
-                ))
+                )
             }
             Err(e) => {
                 tracing::warn!("无法连接数据库，订单存储与交易审计已禁用: {}", e);
-                Arc::new(ExecutionService::with_full_config(
+                ExecutionService::with_full_config(
                     strategy,
                     risk,
                     execution,
                     Some(risk_state),
                     None,
                     None,
-                ))
+                )
             }
         }
     } else {
         tracing::info!("订单存储与交易审计已禁用");
-        Arc::new(ExecutionService::with_full_config(
+        ExecutionService::with_full_config(
             strategy,
             risk,
             execution,
             Some(risk_state),
             None,
             None,
-        ))
+        )
+    };
+
+    // 7. 接入行情质量守卫（可选）
+    let execution_service = match quality_guard {
+        Some(guard) => {
+            tracing::info!("行情质量守卫已接入交易主链路");
+            execution_service.with_quality_guard(guard)
+        }
+        None => execution_service,
     };
 
-    // 8. 创建 MarketEventConsumerService
+    // 8. 接入交易所查询（可选）
+    let execution_service = match exchange_query {
+        Some(query) => execution_service.with_exchange_query(query),
+        None => {
+            tracing::warn!("交易所查询未接入，多腿回滚撤单失败时不平仓");
+            execution_service
+        }
+    };
+
+    // 9. 接入策略执行回报（可选），先按落库成交重建策略持仓
+    let execution_service = match feedback {
+        Some(feedback) => {
+            match order_store {
+                Some(ref repo) => match repo.find_filled_strategy_orders().await {
+                    Ok(orders) => feedback.restore_positions(&orders).await,
+                    Err(e) => tracing::warn!("重建策略持仓失败，持仓快照停用: {}", e),
+                },
+                None => tracing::warn!("订单存储未启用，无法重建策略持仓，持仓快照停用"),
+            }
+            tracing::info!("策略执行回报已接入交易主链路");
+            Arc::new(execution_service.with_feedback(feedback))
+        }
+        None => Arc::new(execution_service),
+    };
+
+    // 10. kafka 策略模式：启动信号消费，信号到达即执行
+    if let Some(signals) = signals {
+        let signal_consumer = SignalConsumerService::new(signals, Arc::clone(&execution_service));
+        tokio::spawn(async move {
+            signal_consumer.run().await;
+        });
+        tracing::info!("信号事件消费已启动");
+    }
+
+    // 11. 创建 MarketEventConsumerService
     Ok(MarketEventConsumerService::new(source, execution_service))
 }
 
@@ -223,6 +295,7 @@ pub async fn create_market_event_consumer_compat(
             kafka_consumer_group: group_id,
             strategy_mode,
             strategy_url,
+            signal_stream: SignalStreamConfig::default(),
             execution_mode,
             binance_api_key,
             binance_secret_key,
@@ -231,6 +304,9 @@ pub async fn create_market_event_consumer_compat(
             storage_enabled,
         },
         risk_state,
+        quality_guard: None,
+        feedback: None,
+        exchange_query: None,
     };
     create_market_event_consumer_with_state(config).await
 }
@@ -0,0 +1,35 @@
+//! # 策略执行回报工厂
+//!
+//! 路径: services/trading-engine/src/bootstrap/feedback.rs
+//!
+//! ## 职责
+//! 创建 StrategyFeedbackService 及其 Kafka 发布端口
+//!
+//! ## 数据流
+//! ```text
+//! ExecutionService（拒单 / 成交 / 撤单）→ StrategyFeedbackService
+//!        → ExecutionFeedbackKafkaProducer → Kafka(execution-feedback) → strategy-engine
+//! ```
+
+use std::sync::Arc;
+
+use crate::application::service::strategy_feedback_service::StrategyFeedbackService;
+use crate::domain::port::execution_feedback_port::ExecutionFeedbackPort;
+use crate::infrastructure::messaging::ExecutionFeedbackKafkaProducer;
+
+/// 创建策略执行回报服务
+///
+/// # 参数
+/// - `kafka_brokers`: Kafka 地址
+/// - `topic`: 执行回报 Topic
+pub fn create_feedback_service(
+    kafka_brokers: String,
+    topic: String,
+) -> anyhow::Result<Arc<StrategyFeedbackService>> {
+    let producer: Arc<dyn ExecutionFeedbackPort> =
+        Arc::new(ExecutionFeedbackKafkaProducer::new(kafka_brokers, topic.clone())?);
+
+    tracing::info!(topic = %topic, "策略执行回报已创建");
+
+    Ok(Arc::new(StrategyFeedbackService::new(producer)))
+}
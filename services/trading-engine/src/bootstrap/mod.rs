@@ -15,6 +15,8 @@
 //! - risk: 风控端口工厂
 //! - execution: 执行端口工厂
 //! - lifecycle: 订单生命周期服务工厂 (v1.1)
+//! - quality: 行情质量守卫工厂
+//! - feedback: 策略执行回报工厂
 //! - consumer: 行情消费服务组装
 //! - background: 后台服务启动 (v1.1 集成重构)
 
@@ -23,6 +25,8 @@ pub mod strategy;
 pub mod risk;
 pub mod execution;
 pub mod lifecycle;
+pub mod quality;
+pub mod feedback;
 pub mod consumer;
 pub mod background;
 
@@ -31,4 +35,6 @@ pub use consumer::create_market_event_consumer_compat as create_market_event_con
 pub use consumer::{ConsumerConfig, ConsumerConfigWithState, create_market_event_consumer_with_state};
 pub use lifecycle::create_order_lifecycle_service;
 pub use risk::create_risk_state;
+pub use quality::{create_quality_guard, QualityGuardConfig};
+pub use feedback::create_feedback_service;
 pub use background::{start_background_services, BackgroundHandles};
@@ -0,0 +1,97 @@
+//! # 行情质量守卫工厂
+//!
+//! 路径: services/trading-engine/src/bootstrap/quality.rs
+//!
+//! ## 职责
+//! 创建 MarketQualityPort 实现及其 Kafka 状态消费者
+//!
+//! ## 数据流
+//! ```text
+//! market-data → Kafka(market-quality) → QualityEventKafkaConsumer → InMemoryQualityGuard
+//!                                                                         ↓
+//!                                                   ExecutionService（下单前拦截）
+//! ```
+
+use std::sync::Arc;
+
+use crate::domain::port::market_quality_port::MarketQualityPort;
+use crate::infrastructure::messaging::QualityEventKafkaConsumer;
+use crate::infrastructure::quality::InMemoryQualityGuard;
+
+/// 行情质量守卫配置
+#[derive(Debug, Clone)]
+pub struct QualityGuardConfig {
+    pub kafka_brokers: String,
+    pub kafka_quality_topic: String,
+    pub kafka_consumer_group: String,
+    /// 从未收到质量状态的交易对是否拦截
+    pub block_unknown: bool,
+    /// market-data 质量心跳间隔（秒），连续错过 `MISSED_HEARTBEATS` 次后状态过期
+    pub heartbeat_secs: u64,
+    /// 下单交易所（其他交易所断流不拦截该交易所的下单）
+    pub venue: String,
+}
+
+/// 状态过期前允许错过的心跳次数
+const MISSED_HEARTBEATS: u32 = 3;
+
+/// 行情质量守卫组件
+///
+/// - `guard`: 注入 ExecutionService
+/// - `consumer`: 交给 start_background_services 启动
+pub struct QualityGuardComponents {
+    pub guard: Arc<dyn MarketQualityPort>,
+    pub consumer: QualityStatusWorker,
+}
+
+/// 质量状态消费任务（消费者 + 写入目标）
+pub struct QualityStatusWorker {
+    pub(crate) consumer: QualityEventKafkaConsumer,
+    pub(crate) guard: Arc<InMemoryQualityGuard>,
+}
+
+impl QualityStatusWorker {
+    /// 运行消费循环
+    pub async fn run(self) {
+        self.consumer.run(self.guard).await;
+    }
+}
+
+/// 创建行情质量守卫
+///
+/// # 参数
+/// - `config`: 守卫配置
+///
+/// # 返回
+/// - `QualityGuardComponents`: 守卫端口与后台消费任务
+pub fn create_quality_guard(config: QualityGuardConfig) -> anyhow::Result<QualityGuardComponents> {
+    let heartbeat = chrono::Duration::seconds(config.heartbeat_secs.max(1) as i64);
+    let max_age = heartbeat * MISSED_HEARTBEATS as i32;
+    let guard = Arc::new(InMemoryQualityGuard::new(
+        config.block_unknown,
+        max_age,
+        config.venue.clone(),
+    ));
+    // 每个实例使用独立消费组，保证都能收到全部交易对状态；
+    // 消费者从 latest 开始且不提交 offset，启动时的状态由 market-data 的全量心跳补齐，
+    // 心跳到达前交易对状态未知，按 block_unknown 处理
+    let group_id = format!("{}-quality-{}", config.kafka_consumer_group, uuid::Uuid::new_v4());
+    let consumer = QualityEventKafkaConsumer::new(
+        config.kafka_brokers,
+        config.kafka_quality_topic.clone(),
+        group_id,
+    )?;
+
+    tracing::info!(
+        topic = %config.kafka_quality_topic,
+        block_unknown = config.block_unknown,
+        max_age_secs = max_age.num_seconds(),
+        venue = %config.venue,
+        "行情质量守卫已创建"
+    );
+
+    Ok(QualityGuardComponents {
+        guard: Arc::clone(&guard) as Arc<dyn MarketQualityPort>,
+        consumer: QualityStatusWorker { consumer, guard },
+    })
+}
@@ -1,19 +1,86 @@
 //! Strategy port factory
+//!
+//! - `remote`: 每条行情同步调用 strategy-engine 的评估接口
+//! - `kafka`: 异步消费 strategy-engine 发布的信号事件，收到即执行（不等待行情）
 
 use std::sync::Arc;
 
 use anyhow::anyhow;
 
+use crate::domain::port::signal_event_port::SignalEventPort;
 use crate::domain::port::strategy_port::StrategyPort;
-use crate::infrastructure::strategy::RemoteStrategy;
+use crate::infrastructure::messaging::SignalEventKafkaConsumer;
+use crate::infrastructure::strategy::{KafkaSignalStrategy, RemoteStrategy};
 
-pub fn create_strategy_port(mode: &str, url: Option<String>) -> anyhow::Result<Arc<dyn StrategyPort>> {
+/// 信号事件消费配置（kafka 模式）
+#[derive(Debug, Clone)]
+pub struct SignalStreamConfig {
+    /// 信号事件主题
+    pub topic: String,
+    /// 信号创建到执行的最大时延（毫秒，0 不限）
+    pub max_age_ms: u64,
+    /// 去重窗口（记住的最近信号 ID 数）
+    pub dedupe_window: usize,
+}
+
+impl Default for SignalStreamConfig {
+    fn default() -> Self {
+        Self {
+            topic: "trading.signal-events".to_string(),
+            max_age_ms: 5_000,
+            dedupe_window: 10_000,
+        }
+    }
+}
+
+/// 策略组件
+///
+/// - `port`: 注入 ExecutionService
+/// - `signals`: kafka 模式的信号事件源，ExecutionService 创建后交给 SignalConsumerService 消费
+pub struct StrategyComponents {
+    pub port: Arc<dyn StrategyPort>,
+    pub signals: Option<Arc<dyn SignalEventPort>>,
+}
+
+pub fn create_strategy_port(
+    mode: &str,
+    url: Option<String>,
+    kafka_brokers: &str,
+    kafka_consumer_group: &str,
+    signal_stream: SignalStreamConfig,
+) -> anyhow::Result<StrategyComponents> {
     match mode.trim().to_lowercase().as_str() {
         "remote" => {
             let url = url.ok_or_else(|| anyhow!("STRATEGY_ENGINE_URL is required for remote strategy"))?;
             tracing::info!(url = %url, "using remote strategy service");
-            Ok(Arc::new(RemoteStrategy::new(url)))
+            Ok(StrategyComponents {
+                port: Arc::new(RemoteStrategy::new(url)),
+                signals: None,
+            })
+        }
+        "kafka" => {
+            let strategy = Arc::new(KafkaSignalStrategy::new(
+                chrono::Duration::milliseconds(signal_stream.max_age_ms as i64),
+                signal_stream.dedupe_window,
+            ));
+            let consumer = SignalEventKafkaConsumer::new(
+                kafka_brokers.to_string(),
+                signal_stream.topic.clone(),
+                format!("{}-signals", kafka_consumer_group),
+                Arc::clone(&strategy),
+            )?;
+
+            tracing::info!(
+                topic = %signal_stream.topic,
+                max_age_ms = signal_stream.max_age_ms,
+                dedupe_window = signal_stream.dedupe_window,
+                "using kafka signal strategy"
+            );
+            Ok(StrategyComponents {
+                port: strategy,
+                signals: Some(Arc::new(consumer)),
+            })
         }
-        other => Err(anyhow!("unsupported TRADING_STRATEGY_MODE={} (allowed: remote)", other)),
+        other => Err(anyhow!("unsupported TRADING_STRATEGY_MODE={} (allowed: remote, kafka)", other)),
     }
 }
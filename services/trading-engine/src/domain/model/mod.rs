@@ -13,6 +13,9 @@ pub mod audit_event;
 /// 交易意图模型 - 策略产生的交易意图
 pub mod order_intent;
 
+/// 多腿意图组 - 多腿策略的关联意图（整体执行、失败回滚）
+pub mod order_intent_group;
+
 /// 订单模型
 pub mod order;
 
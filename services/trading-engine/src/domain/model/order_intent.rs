@@ -2,12 +2,18 @@
 //!
 //! 策略产生的交易意图，不是执行指令。
 //! 需要经过风控检查后才能转换为执行指令。
+//!
+//! `instruction` 携带策略的下单提示（开/平/减/反手、持仓方向、订单类型、
+//! 有效期、附带止损止盈、过期时间），旧意图缺省为开仓。
 
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+pub use shared::types::order::{OrderInstruction, OrderType, PositionAction, TimeInForce};
+pub use shared::types::position::PositionSide;
+
 /// 交易意图
 ///
 /// 策略计算产生的交易意图，表示"想要"执行的交易。
@@ -30,6 +36,9 @@ pub struct OrderIntent {
     pub confidence: f64,
     /// 创建时间
     pub created_at: DateTime<Utc>,
+    /// 下单指令
+    #[serde(default)]
+    pub instruction: OrderInstruction,
 }
 
 /// 订单方向
@@ -60,6 +69,41 @@ impl OrderIntent {
             price,
             confidence,
             created_at: Utc::now(),
+            instruction: OrderInstruction::default(),
         }
     }
+
+    /// 附加下单指令
+    pub fn with_instruction(mut self, instruction: OrderInstruction) -> Self {
+        self.instruction = instruction;
+        self
+    }
+
+    /// 是否已过期
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.instruction.is_expired(now)
+    }
+
+    /// 生成反向平仓意图（多腿组回滚使用）
+    ///
+    /// 反方向、市价、只减仓，沿用原意图的持仓方向。
+    /// 数量为原订单撤单时已成交的部分，未成交部分已随撤单取消。
+    pub fn unwind(&self, filled_quantity: Decimal) -> Self {
+        let side = match self.side {
+            OrderSide::Buy => OrderSide::Sell,
+            OrderSide::Sell => OrderSide::Buy,
+        };
+        let mut instruction = OrderInstruction::close().with_order_type(OrderType::Market);
+        instruction.position_side = self.instruction.position_side;
+
+        Self::new(
+            self.strategy_id,
+            self.symbol.clone(),
+            side,
+            filled_quantity,
+            None,
+            self.confidence,
+        )
+        .with_instruction(instruction)
+    }
 }
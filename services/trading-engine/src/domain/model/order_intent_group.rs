@@ -0,0 +1,58 @@
+//! # 多腿意图组 (Order Intent Group)
+//!
+//! 多腿策略（如跨期套利：做多近月 / 做空远月）一次产生的一组关联意图。
+//!
+//! ## 规则
+//! - ✅ 任一腿未通过前置检查（过期 / 行情质量 / 风控），整组不下单
+//! - ✅ 各腿按顺序下单，任一腿失败时已下单的腿反向只减仓平掉（unwind）
+//! - ❌ 不允许跨策略组合（组内所有腿属于同一策略实例）
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::order_intent::OrderIntent;
+
+/// 多腿意图组
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderIntentGroup {
+    /// 组 ID
+    pub id: Uuid,
+    /// 策略 ID
+    pub strategy_id: Uuid,
+    /// 各腿意图（按下单顺序）
+    pub legs: Vec<OrderIntent>,
+    /// 创建时间
+    pub created_at: DateTime<Utc>,
+}
+
+impl OrderIntentGroup {
+    /// 创建多腿意图组
+    pub fn new(strategy_id: Uuid, legs: Vec<OrderIntent>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            strategy_id,
+            legs,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// 单腿意图组（普通意图）
+    pub fn single(intent: OrderIntent) -> Self {
+        Self::new(intent.strategy_id, vec![intent])
+    }
+
+    /// 是否只有一条腿
+    pub fn is_single(&self) -> bool {
+        self.legs.len() == 1
+    }
+
+    /// 单腿时取出该意图
+    pub fn into_single(mut self) -> Option<OrderIntent> {
+        if self.is_single() {
+            self.legs.pop()
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! # 执行回报端口 (Execution Feedback Port)
+//!
+//! 路径: services/trading-engine/src/domain/port/execution_feedback_port.rs
+//!
+//! ## 职责
+//! 将按策略归属的成交、拒单与持仓快照回送给策略引擎。
+//!
+//! ## 架构位置
+//! - 所属层级: Domain Layer (Port)
+//! - 实现位置: Infrastructure Layer (Kafka)
+//! - 调用者: StrategyFeedbackService
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use shared::event::execution_feedback_event::ExecutionFeedbackEvent;
+
+/// 执行回报端口
+#[async_trait]
+pub trait ExecutionFeedbackPort: Send + Sync {
+    /// 发布执行回报事件
+    ///
+    /// # 参数
+    /// - `event`: 执行回报事件
+    async fn publish(&self, event: &ExecutionFeedbackEvent) -> Result<()>;
+}
+
+#[async_trait]
+impl<T: ExecutionFeedbackPort> ExecutionFeedbackPort for Arc<T> {
+    async fn publish(&self, event: &ExecutionFeedbackEvent) -> Result<()> {
+        (**self).publish(event).await
+    }
+}
@@ -7,6 +7,7 @@
 //!
 //! ## 版本说明
 //! v2 支持多种订单类型：市价单、限价单、止损单、止盈单。
+//! 指令可携带持仓方向与只减仓标志（合约平仓 / 减仓）。
 //!
 //! ## 职责
 //! - 接收执行指令
@@ -89,6 +90,8 @@ pub enum TimeInForce {
     IOC,
     /// Fill Or Kill - 全部成交或全部取消
     FOK,
+    /// Good Till Crossing - 只做 Maker（Post Only），会立即成交时拒单
+    GTX,
 }
 
 impl TimeInForce {
@@ -98,6 +101,29 @@ impl TimeInForce {
             TimeInForce::GTC => "GTC",
             TimeInForce::IOC => "IOC",
             TimeInForce::FOK => "FOK",
+            TimeInForce::GTX => "GTX",
+        }
+    }
+}
+
+/// 持仓方向（合约专用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionSide {
+    /// 多头（做多）
+    Long,
+    /// 空头（做空）
+    Short,
+    /// 双向持仓模式（默认）
+    Both,
+}
+
+impl PositionSide {
+    /// 转换为币安 API 字符串
+    pub fn to_binance_str(&self) -> &'static str {
+        match self {
+            PositionSide::Long => "LONG",
+            PositionSide::Short => "SHORT",
+            PositionSide::Both => "BOTH",
         }
     }
 }
@@ -124,6 +150,10 @@ pub struct ExecutionCommand {
     pub time_in_force: Option<TimeInForce>,
     /// 客户端订单 ID（可选，用于幂等）
     pub client_order_id: Option<String>,
+    /// 持仓方向（仅合约双向持仓模式需要）
+    pub position_side: Option<PositionSide>,
+    /// 是否只减仓（平仓 / 减仓 / 附带止损止盈）
+    pub reduce_only: bool,
 }
 
 impl ExecutionCommand {
@@ -138,6 +168,8 @@ impl ExecutionCommand {
             stop_price: None,
             time_in_force: None,
             client_order_id: None,
+            position_side: None,
+            reduce_only: false,
         }
     }
 
@@ -157,6 +189,8 @@ impl ExecutionCommand {
             stop_price: None,
             time_in_force: Some(TimeInForce::GTC),
             client_order_id: None,
+            position_side: None,
+            reduce_only: false,
         }
     }
 
@@ -177,6 +211,8 @@ impl ExecutionCommand {
             stop_price: Some(stop_price),
             time_in_force: Some(TimeInForce::GTC),
             client_order_id: None,
+            position_side: None,
+            reduce_only: false,
         }
     }
 
@@ -197,6 +233,8 @@ impl ExecutionCommand {
             stop_price: Some(stop_price),
             time_in_force: Some(TimeInForce::GTC),
             client_order_id: None,
+            position_side: None,
+            reduce_only: false,
         }
     }
 
@@ -216,6 +254,8 @@ impl ExecutionCommand {
             stop_price: Some(stop_price),
             time_in_force: None,
             client_order_id: None,
+            position_side: None,
+            reduce_only: false,
         }
     }
 
@@ -235,6 +275,8 @@ impl ExecutionCommand {
             stop_price: Some(stop_price),
             time_in_force: None,
             client_order_id: None,
+            position_side: None,
+            reduce_only: false,
         }
     }
 
@@ -249,6 +291,18 @@ impl ExecutionCommand {
         self.time_in_force = Some(time_in_force);
         self
     }
+
+    /// 设置持仓方向
+    pub fn with_position_side(mut self, position_side: PositionSide) -> Self {
+        self.position_side = Some(position_side);
+        self
+    }
+
+    /// 设置只减仓
+    pub fn with_reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = reduce_only;
+        self
+    }
 }
 
 /// 执行结果
@@ -286,6 +340,13 @@ pub trait ExecutionPort: Send + Sync {
     /// - `Ok(ExecutionResult)`: 执行成功，返回订单信息
     /// - `Err`: 执行失败
     async fn execute(&self, command: &ExecutionCommand) -> anyhow::Result<ExecutionResult>;
+
+    /// 撤销订单
+    ///
+    /// # 返回
+    /// - `Ok(ExecutionResult)`: 撤单成功，`executed_qty` 为撤单时的累计成交数量
+    /// - `Err`: 撤单失败（订单已完结或不存在时交易所同样返回错误）
+    async fn cancel(&self, symbol: &str, order_id: &str) -> anyhow::Result<ExecutionResult>;
 }
 
 // Arc<T> 自动实现 ExecutionPort
@@ -294,4 +355,8 @@ impl<T: ?Sized + ExecutionPort> ExecutionPort for Arc<T> {
     async fn execute(&self, command: &ExecutionCommand) -> anyhow::Result<ExecutionResult> {
         (**self).execute(command).await
     }
+
+    async fn cancel(&self, symbol: &str, order_id: &str) -> anyhow::Result<ExecutionResult> {
+        (**self).cancel(symbol, order_id).await
+    }
 }
@@ -0,0 +1,33 @@
+//! # 行情质量端口 (Market Quality Port)
+//!
+//! 查询交易对的行情数据质量，用于下单前拦截。
+//!
+//! ## 架构位置
+//! Domain Layer > Port
+//!
+//! ## 规则
+//! - 只读查询，同步且廉价（热路径调用）
+//! - 质量状态由 market-data 发布，本服务不做判定
+
+use std::sync::Arc;
+
+use shared::types::market::DataQuality;
+
+/// 行情质量端口
+pub trait MarketQualityPort: Send + Sync {
+    /// 查询交易对当前的数据质量
+    fn quality(&self, symbol: &str) -> DataQuality;
+
+    /// 交易对是否允许下单
+    fn is_tradable(&self, symbol: &str) -> bool;
+}
+
+impl<T: MarketQualityPort> MarketQualityPort for Arc<T> {
+    fn quality(&self, symbol: &str) -> DataQuality {
+        (**self).quality(symbol)
+    }
+
+    fn is_tradable(&self, symbol: &str) -> bool {
+        (**self).is_tradable(symbol)
+    }
+}
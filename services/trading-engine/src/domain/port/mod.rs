@@ -17,6 +17,9 @@ pub mod execution_port;
 /// 行情事件端口 - 消费行情事件
 pub mod market_event_port;
 
+/// 信号事件端口 - 消费异步策略信号
+pub mod signal_event_port;
+
 /// 策略端口 - 调用策略计算
 pub mod strategy_port;
 
@@ -37,3 +40,9 @@ pub mod exchange_query_port;
 
 /// 风控状态端口 - 为风控适配器提供账户状态
 pub mod risk_state_port;
+
+/// 行情质量端口 - 按交易对数据质量拦截下单
+pub mod market_quality_port;
+
+/// 执行回报端口 - 向策略回送成交 / 拒单 / 持仓快照
+pub mod execution_feedback_port;
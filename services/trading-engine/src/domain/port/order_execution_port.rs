@@ -6,6 +6,7 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use rust_decimal::Decimal;
 
 use crate::domain::model::order_intent::OrderIntent;
 
@@ -22,6 +23,15 @@ pub struct ExecutionResult {
     pub error: Option<String>,
 }
 
+/// 撤单结果
+#[derive(Debug, Clone)]
+pub struct CancelResult {
+    /// 订单 ID（交易所返回）
+    pub order_id: String,
+    /// 撤单时的累计成交数量
+    pub filled_quantity: Decimal,
+}
+
 /// 订单执行端口
 ///
 /// 定义订单执行的抽象接口。
@@ -36,6 +46,13 @@ pub trait OrderExecutionPort: Send + Sync {
     /// - `Ok(ExecutionResult)`: 执行结果
     /// - `Err`: 执行失败
     async fn execute(&self, intent: &OrderIntent) -> anyhow::Result<ExecutionResult>;
+
+    /// 撤销已被交易所接受的订单
+    ///
+    /// # 返回
+    /// - `Ok(CancelResult)`: 撤单成功，附撤单时已成交数量
+    /// - `Err`: 撤单失败（包括订单已全部成交）
+    async fn cancel(&self, symbol: &str, order_id: &str) -> anyhow::Result<CancelResult>;
 }
 
 #[async_trait]
@@ -43,4 +60,8 @@ impl<T: OrderExecutionPort> OrderExecutionPort for Arc<T> {
     async fn execute(&self, intent: &OrderIntent) -> anyhow::Result<ExecutionResult> {
         (**self).execute(intent).await
     }
+
+    async fn cancel(&self, symbol: &str, order_id: &str) -> anyhow::Result<CancelResult> {
+        (**self).cancel(symbol, order_id).await
+    }
 }
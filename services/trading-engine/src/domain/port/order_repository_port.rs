@@ -48,6 +48,9 @@ pub trait OrderRepositoryPort: Send + Sync {
 
     /// 查询用户订单列表
     async fn find_orders_by_user(&self, user_id: Uuid, limit: i64) -> Result<Vec<Order>>;
+
+    /// 查询有成交的策略订单（按创建时间升序，用于重启后重建策略持仓）
+    async fn find_filled_strategy_orders(&self) -> Result<Vec<Order>>;
 }
 
 /// 为 Arc<T> 实现 OrderRepositoryPort
@@ -105,4 +108,8 @@ impl<T: OrderRepositoryPort> OrderRepositoryPort for Arc<T> {
     async fn find_orders_by_user(&self, user_id: Uuid, limit: i64) -> Result<Vec<Order>> {
         (**self).find_orders_by_user(user_id, limit).await
     }
+
+    async fn find_filled_strategy_orders(&self) -> Result<Vec<Order>> {
+        (**self).find_filled_strategy_orders().await
+    }
 }
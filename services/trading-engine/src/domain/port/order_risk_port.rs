@@ -25,6 +25,21 @@ pub trait OrderRiskPort: Send + Sync {
     /// - `Err`: 风控拒绝，包含拒绝原因
     async fn check(&self, intent: &OrderIntent) -> anyhow::Result<()>;
 
+    /// 整组检查多腿意图
+    ///
+    /// 有状态的实现应按全部腿的合计影响校验（后面的腿计入前面腿的挂单）；
+    /// 默认逐腿调用 `check`，适用于只做单笔校验的无状态实现。
+    ///
+    /// # 返回
+    /// - `Ok(())`: 全部腿通过
+    /// - `Err((index, reason))`: 第 index 条腿未通过
+    async fn check_group(&self, legs: &[OrderIntent]) -> Result<(), (usize, anyhow::Error)> {
+        for (index, leg) in legs.iter().enumerate() {
+            self.check(leg).await.map_err(|err| (index, err))?;
+        }
+        Ok(())
+    }
+
     /// 更新持仓（下单成功后调用）
     ///
     /// # 参数
@@ -45,6 +60,10 @@ impl<T: OrderRiskPort> OrderRiskPort for Arc<T> {
         (**self).check(intent).await
     }
 
+    async fn check_group(&self, legs: &[OrderIntent]) -> Result<(), (usize, anyhow::Error)> {
+        (**self).check_group(legs).await
+    }
+
     async fn update_position(&self, symbol: &str, delta: Decimal) {
         (**self).update_position(symbol, delta).await
     }
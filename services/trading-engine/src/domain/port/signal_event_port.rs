@@ -0,0 +1,36 @@
+//! # 信号事件端口 (Signal Event Port)
+//!
+//! 定义异步策略信号消费的抽象接口。
+//!
+//! ## 规则
+//! - ✅ 信号源负责去重、时效与版本检查，只交出可执行的意图组
+//! - ✅ 位点在信号处理完成后由调用方显式提交，进程崩溃时未执行的信号会被重新投递
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::model::order_intent_group::OrderIntentGroup;
+
+/// 信号事件端口
+#[async_trait]
+pub trait SignalEventPort: Send + Sync {
+    /// 获取下一条信号
+    ///
+    /// 信号被丢弃（重复、过期、无法解析）时返回 `Ok(None)`，同样需要提交位点。
+    async fn next_signal(&self) -> anyhow::Result<Option<OrderIntentGroup>>;
+
+    /// 提交最近一次取出的信号的位点
+    async fn commit(&self) -> anyhow::Result<()>;
+}
+
+#[async_trait]
+impl<T: SignalEventPort> SignalEventPort for Arc<T> {
+    async fn next_signal(&self) -> anyhow::Result<Option<OrderIntentGroup>> {
+        (**self).next_signal().await
+    }
+
+    async fn commit(&self) -> anyhow::Result<()> {
+        (**self).commit().await
+    }
+}
@@ -9,6 +9,7 @@
 //! ## 职责
 //! - 接收 MarketEvent
 //! - 返回 OrderIntent（交易意图，非执行指令）
+//! - 多腿策略返回 OrderIntentGroup（整体执行的关联意图）
 //!
 //! ## 规则
 //! - Strategy 只能被 ExecutionFlowService 调用
@@ -21,6 +22,7 @@ use async_trait::async_trait;
 use shared::event::market_event::MarketEvent;
 
 use crate::domain::model::order_intent::OrderIntent;
+use crate::domain::model::order_intent_group::OrderIntentGroup;
 
 /// 策略端口
 ///
@@ -38,6 +40,14 @@ pub trait StrategyPort: Send + Sync {
     /// - `Ok(None)`: 无交易意图
     /// - `Err`: 评估失败
     async fn evaluate(&self, event: &MarketEvent) -> anyhow::Result<Option<OrderIntent>>;
+
+    /// 评估行情事件，返回意图组（支持多腿）
+    ///
+    /// 默认实现：将 `evaluate` 的单个意图包装为单腿组。
+    /// 能产生多腿意图的策略实现应覆盖此方法。
+    async fn evaluate_group(&self, event: &MarketEvent) -> anyhow::Result<Option<OrderIntentGroup>> {
+        Ok(self.evaluate(event).await?.map(OrderIntentGroup::single))
+    }
 }
 
 #[async_trait]
@@ -45,4 +55,8 @@ impl<T: StrategyPort> StrategyPort for Arc<T> {
     async fn evaluate(&self, event: &MarketEvent) -> anyhow::Result<Option<OrderIntent>> {
         (**self).evaluate(event).await
     }
+
+    async fn evaluate_group(&self, event: &MarketEvent) -> anyhow::Result<Option<OrderIntentGroup>> {
+        (**self).evaluate_group(event).await
+    }
 }
@@ -121,6 +121,7 @@ mod tests {
             price: Some(Decimal::from(50000)),
             confidence: 0.9,
             created_at: Utc::now(),
+            instruction: Default::default(),
         }
     }
 
@@ -123,6 +123,7 @@ mod tests {
             price: Some(Decimal::from(50000)),
             confidence: 0.9,
             created_at: Utc::now(),
+            instruction: Default::default(),
         };
         let state = RiskStateSnapshot::default();
         let ctx = RiskContext::new(&intent, &state);
@@ -143,6 +144,7 @@ mod tests {
             price: Some(Decimal::from(50000)),
             confidence: 0.9,
             created_at: Utc::now(),
+            instruction: Default::default(),
         };
         let state = RiskStateSnapshot::default();
         let ctx = RiskContext::new(&intent, &state);
@@ -95,6 +95,54 @@ impl ExecutionPort for BinanceExecution {
             )
             .await
     }
+    async fn cancel(&self, symbol: &str, order_id: &str) -> Result<ExecutionResult> {
+        self.rate_limiter.acquire(1).await;
+
+        let symbol = symbol.trim().to_uppercase();
+        let query = format!(
+            "symbol={}&orderId={}&timestamp={}",
+            symbol,
+            order_id,
+            Self::now_millis()?
+        );
+        let signature = self.sign(&query)?;
+        let url = format!("{}/api/v3/order?{}&signature={}", self.base_url, query, signature);
+
+        let response = self
+            .client
+            .delete(url)
+            .header("X-MBX-APIKEY", &self.api_key)
+            .send()
+            .await
+            .context("binance cancel request failed")?;
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        if !status.is_success() {
+            warn!(symbol = %symbol, order_id, status = %status, body = %body, "Order cancel failed");
+            return Err(anyhow!("binance cancel failed: status={} body={}", status, body));
+        }
+
+        // 撤单响应带撤单时的累计成交数量
+        let json: serde_json::Value = serde_json::from_str(&body)
+            .context("failed to parse binance cancel response")?;
+        let executed_qty = json["executedQty"]
+            .as_str()
+            .and_then(|s| s.parse::<Decimal>().ok())
+            .unwrap_or(Decimal::ZERO);
+        let order_status = json["status"].as_str().unwrap_or("CANCELED").to_string();
+
+        info!(symbol = %symbol, order_id, status = %order_status, executed_qty = %executed_qty, "Order canceled");
+
+        Ok(ExecutionResult {
+            order_id: order_id.to_string(),
+            client_order_id: json["clientOrderId"].as_str().map(|s| s.to_string()),
+            symbol,
+            status: order_status,
+            executed_qty,
+            avg_price: None,
+        })
+    }
 }
 
 impl BinanceExecution {
@@ -113,7 +161,14 @@ impl BinanceExecution {
 
         let symbol = command.symbol.trim().to_uppercase();
         let side = command.side.to_binance_str();
-        let order_type = command.order_type.to_binance_str();
+        // 现货 Post Only 使用 LIMIT_MAKER 类型（不带 timeInForce）
+        let post_only = command.order_type == OrderType::Limit
+            && command.time_in_force == Some(crate::domain::port::execution_port::TimeInForce::GTX);
+        let order_type = if post_only {
+            "LIMIT_MAKER"
+        } else {
+            command.order_type.to_binance_str()
+        };
         let quantity = command.quantity.to_string();
 
         // 构建查询参数
@@ -136,8 +191,10 @@ impl BinanceExecution {
                 let price = command.price.context("Limit order requires price")?;
                 params.push(format!("price={}", price));
 
-                let tif = command.time_in_force.unwrap_or(crate::domain::port::execution_port::TimeInForce::GTC);
-                params.push(format!("timeInForce={}", tif.to_binance_str()));
+                if !post_only {
+                    let tif = command.time_in_force.unwrap_or(crate::domain::port::execution_port::TimeInForce::GTC);
+                    params.push(format!("timeInForce={}", tif.to_binance_str()));
+                }
             }
             OrderType::StopLossLimit | OrderType::TakeProfitLimit => {
                 // 止损/止盈限价单需要价格、止损价和时间有效性
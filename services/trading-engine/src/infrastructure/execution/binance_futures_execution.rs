@@ -19,6 +19,8 @@ use rust_decimal::Decimal;
 use sha2::Sha256;
 use tracing::{info, warn};
 
+pub use crate::domain::port::execution_port::PositionSide;
+
 use crate::domain::port::execution_port::{
     ExecutionCommand, ExecutionPort, ExecutionResult, OrderSide, OrderType,
 };
@@ -26,28 +28,6 @@ use crate::infrastructure::execution::{RateLimiter, RetryPolicy};
 
 type HmacSha256 = Hmac<Sha256>;
 
-/// 持仓方向（合约专用）
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum PositionSide {
-    /// 多头（做多）
-    Long,
-    /// 空头（做空）
-    Short,
-    /// 双向持仓模式（默认）
-    Both,
-}
-
-impl PositionSide {
-    /// 转换为币安 API 字符串
-    pub fn to_binance_str(&self) -> &'static str {
-        match self {
-            PositionSide::Long => "LONG",
-            PositionSide::Short => "SHORT",
-            PositionSide::Both => "BOTH",
-        }
-    }
-}
-
 /// 保证金模式
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MarginType {
@@ -195,6 +175,18 @@ impl BinanceFuturesExecution {
         Ok(duration.as_millis())
     }
 
+    /// 合约订单类型字符串（与现货命名不同）
+    fn futures_order_type(order_type: OrderType) -> &'static str {
+        match order_type {
+            OrderType::Market => "MARKET",
+            OrderType::Limit => "LIMIT",
+            OrderType::StopLossLimit => "STOP",
+            OrderType::TakeProfitLimit => "TAKE_PROFIT",
+            OrderType::StopLossMarket => "STOP_MARKET",
+            OrderType::TakeProfitMarket => "TAKE_PROFIT_MARKET",
+        }
+    }
+
     /// 执行合约订单
     pub async fn execute_futures(&self, command: &FuturesCommand) -> Result<ExecutionResult> {
         self.retry_policy
@@ -222,7 +214,7 @@ impl BinanceFuturesExecution {
 
         let symbol = command.base.symbol.trim().to_uppercase();
         let side = command.base.side.to_binance_str();
-        let order_type = command.base.order_type.to_binance_str();
+        let order_type = Self::futures_order_type(command.base.order_type);
         let quantity = command.base.quantity.to_string();
 
         // 构建查询参数
@@ -240,8 +232,12 @@ impl BinanceFuturesExecution {
         // 添加数量参数
         params.push(format!("quantity={}", quantity));
 
-        // 添加只减仓标志
-        if command.reduce_only {
+        // 添加只减仓标志（双向持仓模式下由 positionSide 决定平仓方向，币安不接受 reduceOnly）
+        let hedge_mode = matches!(
+            command.position_side,
+            Some(PositionSide::Long) | Some(PositionSide::Short)
+        );
+        if command.reduce_only && !hedge_mode {
             params.push("reduceOnly=true".to_string());
         }
 
@@ -465,12 +461,60 @@ impl BinanceFuturesExecution {
 #[async_trait]
 impl ExecutionPort for BinanceFuturesExecution {
     async fn execute(&self, command: &ExecutionCommand) -> Result<ExecutionResult> {
-        // 将基础指令转换为合约指令（默认单向持仓）
+        // 将基础指令转换为合约指令（持仓方向与只减仓标志随指令传递）
         let futures_command = FuturesCommand {
             base: command.clone(),
-            position_side: None,
-            reduce_only: false,
+            position_side: command.position_side,
+            reduce_only: command.reduce_only,
         };
         self.execute_futures(&futures_command).await
     }
+    async fn cancel(&self, symbol: &str, order_id: &str) -> Result<ExecutionResult> {
+        self.rate_limiter.acquire(1).await;
+
+        let symbol = symbol.trim().to_uppercase();
+        let query = format!(
+            "symbol={}&orderId={}&timestamp={}",
+            symbol,
+            order_id,
+            Self::now_millis()?
+        );
+        let signature = self.sign(&query)?;
+        let url = format!("{}/fapi/v1/order?{}&signature={}", self.base_url, query, signature);
+
+        let response = self
+            .client
+            .delete(url)
+            .header("X-MBX-APIKEY", &self.api_key)
+            .send()
+            .await
+            .context("binance futures cancel request failed")?;
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        if !status.is_success() {
+            warn!(symbol = %symbol, order_id, status = %status, body = %body, "Futures cancel failed");
+            return Err(anyhow!("binance futures cancel failed: status={} body={}", status, body));
+        }
+
+        // 撤单响应带撤单时的累计成交数量
+        let json: serde_json::Value = serde_json::from_str(&body)
+            .context("failed to parse binance cancel response")?;
+        let executed_qty = json["executedQty"]
+            .as_str()
+            .and_then(|s| s.parse::<Decimal>().ok())
+            .unwrap_or(Decimal::ZERO);
+        let order_status = json["status"].as_str().unwrap_or("CANCELED").to_string();
+
+        info!(symbol = %symbol, order_id, status = %order_status, executed_qty = %executed_qty, "Futures order canceled");
+
+        Ok(ExecutionResult {
+            order_id: order_id.to_string(),
+            client_order_id: json["clientOrderId"].as_str().map(|s| s.to_string()),
+            symbol,
+            status: order_status,
+            executed_qty,
+            avg_price: None,
+        })
+    }
 }
@@ -28,4 +28,12 @@ impl ExecutionPort for NoopExecution {
             command.side
         ))
     }
+
+    async fn cancel(&self, symbol: &str, order_id: &str) -> anyhow::Result<ExecutionResult> {
+        Err(anyhow::anyhow!(
+            "execution is disabled, cannot cancel order {} on {}",
+            order_id,
+            symbol
+        ))
+    }
 }
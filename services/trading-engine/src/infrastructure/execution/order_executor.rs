@@ -1,11 +1,23 @@
 //! Order executor adapter
+//!
+//! 将 OrderIntent 的下单指令映射为 ExecutionCommand：
+//! - 订单类型 / 有效期 / 持仓方向 / 只减仓（平仓、减仓）
+//! - 止损 / 止盈类型映射为条件市价单
+//!
+//! 附带的止损止盈不在这里挂出：入场单成交后由 ExecutionService 按成交数量
+//! 以只减仓意图下单（见 `ProtectiveOrderBook`）。
 
 use async_trait::async_trait;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
-use crate::domain::model::order_intent::{OrderIntent, OrderSide};
-use crate::domain::port::execution_port::{ExecutionCommand, ExecutionPort, OrderSide as ExchangeOrderSide};
-use crate::domain::port::order_execution_port::{ExecutionResult, OrderExecutionPort};
+use crate::domain::model::order_intent::{
+    OrderIntent, OrderSide, OrderType, PositionSide, TimeInForce,
+};
+use crate::domain::port::execution_port::{
+    ExecutionCommand, ExecutionPort, OrderSide as ExchangeOrderSide,
+    PositionSide as ExchangePositionSide, TimeInForce as ExchangeTimeInForce,
+};
+use crate::domain::port::order_execution_port::{CancelResult, ExecutionResult, OrderExecutionPort};
 
 pub struct OrderExecutor {
     inner: std::sync::Arc<dyn ExecutionPort>,
@@ -15,6 +27,58 @@ impl OrderExecutor {
     pub fn new(inner: std::sync::Arc<dyn ExecutionPort>) -> Self {
         Self { inner }
     }
+
+    /// 根据意图构建主订单指令
+    fn build_command(intent: &OrderIntent, side: ExchangeOrderSide) -> ExecutionCommand {
+        let instruction = &intent.instruction;
+        let symbol = intent.symbol.clone();
+
+        let command = match (instruction.resolve_order_type(intent.price), intent.price) {
+            (OrderType::Limit, Some(price)) => {
+                let command = ExecutionCommand::limit(symbol, side, intent.quantity, price);
+                match instruction.time_in_force {
+                    Some(tif) => command.with_time_in_force(Self::map_time_in_force(tif)),
+                    None => command,
+                }
+            }
+            (OrderType::StopLoss, Some(stop_price)) => {
+                ExecutionCommand::stop_loss_market(symbol, side, intent.quantity, stop_price)
+            }
+            (OrderType::TakeProfit, Some(stop_price)) => {
+                ExecutionCommand::take_profit_market(symbol, side, intent.quantity, stop_price)
+            }
+            (OrderType::Market, _) => ExecutionCommand::market(symbol, side, intent.quantity),
+            (order_type, None) => {
+                warn!(intent_id = %intent.id, order_type = ?order_type, "Order type requires price, fallback to market");
+                ExecutionCommand::market(symbol, side, intent.quantity)
+            }
+        };
+
+        let command = command
+            .with_client_order_id(intent.id.to_string())
+            .with_reduce_only(instruction.action.is_reduce_only());
+
+        match instruction.position_side {
+            Some(position_side) => command.with_position_side(Self::map_position_side(position_side)),
+            None => command,
+        }
+    }
+
+    fn map_time_in_force(tif: TimeInForce) -> ExchangeTimeInForce {
+        match tif {
+            TimeInForce::Gtc => ExchangeTimeInForce::GTC,
+            TimeInForce::Ioc => ExchangeTimeInForce::IOC,
+            TimeInForce::Fok => ExchangeTimeInForce::FOK,
+            TimeInForce::PostOnly => ExchangeTimeInForce::GTX,
+        }
+    }
+
+    fn map_position_side(position_side: PositionSide) -> ExchangePositionSide {
+        match position_side {
+            PositionSide::Long => ExchangePositionSide::Long,
+            PositionSide::Short => ExchangePositionSide::Short,
+        }
+    }
 }
 
 #[async_trait]
@@ -25,14 +89,9 @@ impl OrderExecutionPort for OrderExecutor {
             OrderSide::Sell => ExchangeOrderSide::Sell,
         };
 
-        let command = match intent.price {
-            Some(price) => ExecutionCommand::limit(intent.symbol.clone(), side, intent.quantity, price)
-                .with_client_order_id(intent.id.to_string()),
-            None => ExecutionCommand::market(intent.symbol.clone(), side, intent.quantity)
-                .with_client_order_id(intent.id.to_string()),
-        };
+        let command = Self::build_command(intent, side);
 
-        debug!(symbol = %command.symbol, side = ?command.side, quantity = %command.quantity, "Executing order");
+        debug!(symbol = %command.symbol, side = ?command.side, order_type = ?command.order_type, quantity = %command.quantity, reduce_only = command.reduce_only, "Executing order");
 
         match self.inner.execute(&command).await {
             Ok(exchange_result) => {
@@ -60,4 +119,124 @@ impl OrderExecutionPort for OrderExecutor {
             }),
         }
     }
+
+    async fn cancel(&self, symbol: &str, order_id: &str) -> anyhow::Result<CancelResult> {
+        let result = self.inner.cancel(symbol, order_id).await?;
+        Ok(CancelResult {
+            order_id: result.order_id,
+            filled_quantity: result.executed_qty,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    use parking_lot::Mutex;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    use crate::domain::model::order_intent::OrderInstruction;
+    use crate::domain::port::execution_port::{ExecutionResult as ExchangeResult, OrderType as ExchangeOrderType};
+
+    /// 记录所有下单指令的执行端口
+    #[derive(Default)]
+    struct RecordingExecution {
+        commands: Mutex<Vec<ExecutionCommand>>,
+    }
+
+    #[async_trait]
+    impl ExecutionPort for RecordingExecution {
+        async fn execute(&self, command: &ExecutionCommand) -> anyhow::Result<ExchangeResult> {
+            self.commands.lock().push(command.clone());
+            Ok(ExchangeResult {
+                order_id: "1".to_string(),
+                client_order_id: command.client_order_id.clone(),
+                symbol: command.symbol.clone(),
+                status: "NEW".to_string(),
+                executed_qty: Decimal::ZERO,
+                avg_price: None,
+            })
+        }
+
+        async fn cancel(&self, symbol: &str, order_id: &str) -> anyhow::Result<ExchangeResult> {
+            Ok(ExchangeResult {
+                order_id: order_id.to_string(),
+                client_order_id: None,
+                symbol: symbol.to_string(),
+                status: "CANCELED".to_string(),
+                executed_qty: Decimal::ZERO,
+                avg_price: None,
+            })
+        }
+    }
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_close_maps_to_reduce_only_market() {
+        let inner = Arc::new(RecordingExecution::default());
+        let executor = OrderExecutor::new(inner.clone());
+
+        let intent = OrderIntent::new(Uuid::new_v4(), "BTCUSDT".to_string(), OrderSide::Sell, dec("0.1"), Some(dec("50000")), 0.8)
+            .with_instruction(
+                OrderInstruction::close()
+                    .with_order_type(OrderType::Market)
+                    .with_position_side(PositionSide::Long),
+            );
+
+        let result = executor.execute(&intent).await.unwrap();
+        assert!(result.success);
+
+        let commands = inner.commands.lock();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].order_type, ExchangeOrderType::Market);
+        assert!(commands[0].reduce_only);
+        assert_eq!(commands[0].position_side, Some(ExchangePositionSide::Long));
+    }
+
+    #[tokio::test]
+    async fn test_entry_sends_no_protection_before_fill() {
+        let inner = Arc::new(RecordingExecution::default());
+        let executor = OrderExecutor::new(inner.clone());
+
+        let intent = OrderIntent::new(Uuid::new_v4(), "BTCUSDT".to_string(), OrderSide::Buy, dec("0.1"), Some(dec("50000")), 0.8)
+            .with_instruction(
+                OrderInstruction::open()
+                    .post_only()
+                    .with_stop_loss(dec("49000"))
+                    .with_take_profit(dec("52000")),
+            );
+
+        // 入场单仅被接受（NEW），止损止盈要等成交后才挂出
+        executor.execute(&intent).await.unwrap();
+
+        let commands = inner.commands.lock();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].time_in_force, Some(ExchangeTimeInForce::GTX));
+        assert!(!commands[0].reduce_only);
+    }
+
+    #[tokio::test]
+    async fn test_protective_intent_maps_to_reduce_only_stop() {
+        let inner = Arc::new(RecordingExecution::default());
+        let executor = OrderExecutor::new(inner.clone());
+
+        let intent = OrderIntent::new(Uuid::new_v4(), "BTCUSDT".to_string(), OrderSide::Sell, dec("0.1"), Some(dec("49000")), 0.8)
+            .with_instruction(OrderInstruction::reduce().with_order_type(OrderType::StopLoss));
+
+        executor.execute(&intent).await.unwrap();
+
+        let commands = inner.commands.lock();
+        assert_eq!(commands[0].order_type, ExchangeOrderType::StopLossMarket);
+        assert_eq!(commands[0].side, ExchangeOrderSide::Sell);
+        assert_eq!(commands[0].stop_price, Some(dec("49000")));
+        assert!(commands[0].reduce_only);
+        assert_eq!(commands[0].client_order_id, Some(intent.id.to_string()));
+    }
 }
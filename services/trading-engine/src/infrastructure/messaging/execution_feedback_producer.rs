@@ -0,0 +1,52 @@
+//! # 执行回报 Kafka 生产者 (Execution Feedback Kafka Producer)
+//!
+//! 实现 ExecutionFeedbackPort，将 ExecutionFeedbackEvent 写入 Kafka。
+//!
+//! ## 消息 Key
+//! `{strategy_id}`：同一策略实例的回报保序，快照总在对应成交 / 拒单之后。
+
+use std::time::Duration;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use rdkafka::config::ClientConfig;
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use shared::event::execution_feedback_event::ExecutionFeedbackEvent;
+
+use crate::domain::port::execution_feedback_port::ExecutionFeedbackPort;
+
+/// 执行回报 Kafka 生产者
+pub struct ExecutionFeedbackKafkaProducer {
+    topic: String,
+    producer: FutureProducer,
+}
+
+impl ExecutionFeedbackKafkaProducer {
+    /// 创建生产者
+    pub fn new(brokers: String, topic: String) -> anyhow::Result<Self> {
+        let producer: FutureProducer = ClientConfig::new()
+            .set("bootstrap.servers", &brokers)
+            .set("message.timeout.ms", "5000")
+            .create()
+            .context("failed to create kafka feedback producer")?;
+
+        Ok(Self { topic, producer })
+    }
+}
+
+#[async_trait]
+impl ExecutionFeedbackPort for ExecutionFeedbackKafkaProducer {
+    async fn publish(&self, event: &ExecutionFeedbackEvent) -> anyhow::Result<()> {
+        let payload = serde_json::to_string(event)
+            .context("serialize execution feedback event")?;
+        let key = event.strategy_id.to_string();
+        let record = FutureRecord::to(&self.topic)
+            .payload(&payload)
+            .key(&key);
+
+        match self.producer.send(record, Duration::from_secs(5)).await {
+            Ok(_) => Ok(()),
+            Err((err, _)) => Err(err.into()),
+        }
+    }
+}
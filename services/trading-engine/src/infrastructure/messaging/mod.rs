@@ -1,8 +1,20 @@
 //! # 消息基础设施模块
 //!
-//! 提供 Kafka 消费者实现。
+//! 提供 Kafka 消费者与生产者实现。
 
 /// 行情事件 Kafka 消费者
 pub mod market_event_consumer;
 
+/// 行情质量事件 Kafka 消费者
+pub mod quality_event_consumer;
+
+/// 执行回报 Kafka 生产者
+pub mod execution_feedback_producer;
+
+/// 信号事件 Kafka 消费者（异步策略模式）
+pub mod signal_event_consumer;
+
 pub use market_event_consumer::MarketEventKafkaConsumer;
+pub use quality_event_consumer::QualityEventKafkaConsumer;
+pub use execution_feedback_producer::ExecutionFeedbackKafkaProducer;
+pub use signal_event_consumer::SignalEventKafkaConsumer;
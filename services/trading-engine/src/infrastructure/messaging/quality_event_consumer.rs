@@ -0,0 +1,68 @@
+//! # 行情质量事件 Kafka 消费者 (Quality Event Kafka Consumer)
+//!
+//! 从 Kafka 消费 market-data 发布的 DataQualityEvent，写入 InMemoryQualityGuard。
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{Consumer, StreamConsumer};
+use rdkafka::Message;
+use shared::event::quality_event::DataQualityEvent;
+use tracing::{error, info, warn};
+
+use crate::infrastructure::quality::InMemoryQualityGuard;
+
+/// 行情质量事件 Kafka 消费者
+pub struct QualityEventKafkaConsumer {
+    consumer: StreamConsumer,
+}
+
+impl QualityEventKafkaConsumer {
+    /// 创建消费者
+    ///
+    /// 只消费启动之后的新状态：market-data 按心跳周期发布全量快照，
+    /// 启动后一个心跳周期内即可拿到每个交易对的当前状态，不回放历史。
+    /// 不提交 offset，消费组不在 broker 上留下状态。
+    pub fn new(brokers: String, topic: String, group_id: String) -> anyhow::Result<Self> {
+        let consumer: StreamConsumer = ClientConfig::new()
+            .set("bootstrap.servers", &brokers)
+            .set("group.id", &group_id)
+            .set("enable.auto.commit", "false")
+            .set("auto.offset.reset", "latest")
+            .create()
+            .context("failed to create kafka quality consumer")?;
+
+        consumer
+            .subscribe(&[&topic])
+            .context("failed to subscribe kafka quality topic")?;
+
+        Ok(Self { consumer })
+    }
+
+    /// 运行消费循环
+    pub async fn run(&self, guard: Arc<InMemoryQualityGuard>) {
+        info!("QualityEventKafkaConsumer started");
+
+        loop {
+            let message = match self.consumer.recv().await {
+                Ok(message) => message,
+                Err(e) => {
+                    error!(error = %e, "failed to receive quality event");
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    continue;
+                }
+            };
+
+            let Some(payload) = message.payload() else {
+                continue;
+            };
+
+            match serde_json::from_slice::<DataQualityEvent>(payload) {
+                Ok(event) => guard.apply(&event),
+                Err(e) => warn!(error = %e, "failed to deserialize quality event"),
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! # 信号事件 Kafka 消费者 (Signal Event Kafka Consumer)
+//!
+//! 异步策略模式：从 Kafka 消费 strategy-engine 发布的 SignalEvent，经 KafkaSignalStrategy
+//! 去重与时效检查后交出意图组。
+//!
+//! ## 规则
+//! - ✅ 关闭自动提交，位点只在 SignalConsumerService 执行完信号后提交
+//! - ✅ 无法解析的消息丢弃，同样提交位点，不阻塞后续信号
+
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use chrono::Utc;
+use parking_lot::Mutex;
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+use rdkafka::{Message, Offset, TopicPartitionList};
+use shared::event::signal_event::SignalEvent;
+use tracing::warn;
+
+use crate::domain::model::order_intent_group::OrderIntentGroup;
+use crate::domain::port::signal_event_port::SignalEventPort;
+use crate::infrastructure::strategy::KafkaSignalStrategy;
+
+/// 已取出、待提交的消息位置
+#[derive(Debug, Clone)]
+struct ConsumedOffset {
+    topic: String,
+    partition: i32,
+    offset: i64,
+}
+
+/// 信号事件 Kafka 消费者
+pub struct SignalEventKafkaConsumer {
+    consumer: StreamConsumer,
+    /// 去重、时效检查与意图转换
+    strategy: Arc<KafkaSignalStrategy>,
+    /// 最近一次取出的消息（提交后清空）
+    uncommitted: Mutex<Option<ConsumedOffset>>,
+}
+
+impl SignalEventKafkaConsumer {
+    /// 创建消费者
+    ///
+    /// 首次启动从最新的 offset 开始消费，积压的旧信号不再执行；
+    /// 之后从已提交的位点继续，未执行完的信号会重新投递。
+    pub fn new(
+        brokers: String,
+        topic: String,
+        group_id: String,
+        strategy: Arc<KafkaSignalStrategy>,
+    ) -> anyhow::Result<Self> {
+        let consumer: StreamConsumer = ClientConfig::new()
+            .set("bootstrap.servers", &brokers)
+            .set("group.id", &group_id)
+            .set("enable.auto.commit", "false")
+            .set("auto.offset.reset", "latest")
+            .create()
+            .context("failed to create kafka signal consumer")?;
+
+        consumer
+            .subscribe(&[&topic])
+            .context("failed to subscribe kafka signal topic")?;
+
+        Ok(Self {
+            consumer,
+            strategy,
+            uncommitted: Mutex::new(None),
+        })
+    }
+}
+
+#[async_trait]
+impl SignalEventPort for SignalEventKafkaConsumer {
+    async fn next_signal(&self) -> anyhow::Result<Option<OrderIntentGroup>> {
+        let message = self
+            .consumer
+            .recv()
+            .await
+            .context("failed to receive signal event")?;
+
+        *self.uncommitted.lock() = Some(ConsumedOffset {
+            topic: message.topic().to_string(),
+            partition: message.partition(),
+            offset: message.offset(),
+        });
+
+        let Some(payload) = message.payload() else {
+            return Ok(None);
+        };
+
+        match serde_json::from_slice::<SignalEvent>(payload) {
+            Ok(event) => Ok(self.strategy.accept(event, Utc::now())),
+            Err(e) => {
+                warn!(error = %e, "failed to deserialize signal event");
+                Ok(None)
+            }
+        }
+    }
+
+    async fn commit(&self) -> anyhow::Result<()> {
+        let Some(consumed) = self.uncommitted.lock().take() else {
+            return Ok(());
+        };
+
+        // 提交的是下一条要消费的位置
+        let next = Offset::Offset(consumed.offset + 1);
+        let mut offsets = TopicPartitionList::new();
+        offsets
+            .add_partition_offset(&consumed.topic, consumed.partition, next)
+            .map_err(|e| anyhow!("invalid signal offset: {}", e))?;
+
+        self.consumer
+            .commit(&offsets, CommitMode::Async)
+            .context("commit signal offset")
+    }
+}
@@ -36,3 +36,6 @@ pub mod strategy;
 
 /// 审计模块 - 实现 TradeAuditPort
 pub mod audit;
+
+/// 行情质量模块 - 实现 MarketQualityPort
+pub mod quality;
@@ -0,0 +1,216 @@
+//! # 内存行情质量守卫 (InMemory Quality Guard)
+//!
+//! 实现 MarketQualityPort，在内存中缓存各交易对最新的 DataQuality 及其接收时间。
+//!
+//! ## 拦截规则
+//! - `Suspect`: 拦截；唯一的异常是其他交易所断流时放行（下单交易所的行情仍然有效）
+//! - 超过 `max_age` 未收到状态（market-data 崩溃或心跳中断）: 视为 `Suspect`，拦截
+//! - `Unknown`（从未收到状态）: 由 `block_unknown` 决定（默认拦截）
+//! - `Normal` / `Recovered`: 放行
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::RwLock;
+use shared::event::quality_event::{DataQualityEvent, QualityIssue};
+use shared::types::market::DataQuality;
+use tracing::{info, warn};
+
+use crate::domain::port::market_quality_port::MarketQualityPort;
+
+/// 单个交易对最近一次收到的状态
+#[derive(Debug, Clone)]
+struct QualityStatus {
+    quality: DataQuality,
+    issues: Vec<QualityIssue>,
+    received_at: DateTime<Utc>,
+}
+
+/// 内存行情质量守卫
+pub struct InMemoryQualityGuard {
+    /// symbol(大写) -> 最新状态
+    statuses: RwLock<HashMap<String, QualityStatus>>,
+    /// 未知质量是否拦截
+    block_unknown: bool,
+    /// 状态最长有效期（超过视为 Suspect）
+    max_age: Duration,
+    /// 下单交易所（与行情事件的 exchange 字段一致）
+    venue: String,
+}
+
+impl InMemoryQualityGuard {
+    /// 创建守卫
+    ///
+    /// # 参数
+    /// - `block_unknown`: 从未收到状态的交易对是否拦截
+    /// - `max_age`: 状态最长有效期，通常为 market-data 心跳间隔的数倍
+    /// - `venue`: 下单交易所，其他交易所断流不影响该交易所的下单
+    pub fn new(block_unknown: bool, max_age: Duration, venue: impl Into<String>) -> Self {
+        Self {
+            statuses: RwLock::new(HashMap::new()),
+            block_unknown,
+            max_age,
+            venue: venue.into().to_lowercase(),
+        }
+    }
+
+    /// 应用一条质量事件
+    pub fn apply(&self, event: &DataQualityEvent) {
+        self.apply_at(event, Utc::now());
+    }
+
+    fn apply_at(&self, event: &DataQualityEvent, now: DateTime<Utc>) {
+        let symbol = event.symbol.to_uppercase();
+        let status = QualityStatus {
+            quality: event.quality,
+            issues: event.issues.clone(),
+            received_at: now,
+        };
+        let previous = self.statuses.write().insert(symbol.clone(), status);
+
+        if previous.map(|s| s.quality) != Some(event.quality) {
+            if event.quality == DataQuality::Suspect {
+                warn!(symbol = %symbol, issues = ?event.issues, "行情质量异常，暂停该交易对下单");
+            } else {
+                info!(symbol = %symbol, quality = %event.quality, "行情质量状态更新");
+            }
+        }
+    }
+
+    /// 按接收时间计算的有效状态（过期的状态视为 Suspect）
+    fn status_at(&self, symbol: &str, now: DateTime<Utc>) -> Option<QualityStatus> {
+        let mut status = self.statuses.read().get(&symbol.to_uppercase()).cloned()?;
+        if now - status.received_at > self.max_age {
+            status.quality = DataQuality::Suspect;
+        }
+        Some(status)
+    }
+
+    fn is_tradable_at(&self, symbol: &str, now: DateTime<Utc>) -> bool {
+        let Some(status) = self.status_at(symbol, now) else {
+            return !self.block_unknown;
+        };
+        if now - status.received_at > self.max_age {
+            warn!(symbol = %symbol, received_at = %status.received_at, "行情质量状态已过期，暂停该交易对下单");
+            return false;
+        }
+
+        match status.quality {
+            DataQuality::Normal | DataQuality::Recovered => true,
+            DataQuality::Suspect => self.only_other_venues_stale(&status.issues),
+            DataQuality::Unknown => !self.block_unknown,
+        }
+    }
+
+    /// 异常是否全部是其他交易所断流
+    fn only_other_venues_stale(&self, issues: &[QualityIssue]) -> bool {
+        !issues.is_empty()
+            && issues.iter().all(|issue| match issue {
+                QualityIssue::Stale { exchange, .. } => !exchange.eq_ignore_ascii_case(&self.venue),
+                _ => false,
+            })
+    }
+}
+
+impl MarketQualityPort for InMemoryQualityGuard {
+    fn quality(&self, symbol: &str) -> DataQuality {
+        self.status_at(symbol, Utc::now())
+            .map(|s| s.quality)
+            .unwrap_or_default()
+    }
+
+    fn is_tradable(&self, symbol: &str) -> bool {
+        self.is_tradable_at(symbol, Utc::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(symbol: &str, quality: DataQuality) -> DataQualityEvent {
+        DataQualityEvent {
+            symbol: symbol.to_string(),
+            quality,
+            issues: Vec::new(),
+            last_update: None,
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn stale(exchange: &str) -> QualityIssue {
+        QualityIssue::Stale {
+            exchange: exchange.to_string(),
+            silent_secs: 45,
+        }
+    }
+
+    fn guard(block_unknown: bool) -> InMemoryQualityGuard {
+        InMemoryQualityGuard::new(block_unknown, Duration::seconds(90), "binance")
+    }
+
+    #[test]
+    fn test_suspect_blocks_symbol() {
+        let guard = guard(false);
+        assert!(guard.is_tradable("BTCUSDT"));
+
+        guard.apply(&event("BTCUSDT", DataQuality::Suspect));
+        assert!(!guard.is_tradable("btcusdt"));
+        assert!(guard.is_tradable("ETHUSDT"));
+
+        guard.apply(&event("BTCUSDT", DataQuality::Recovered));
+        assert!(guard.is_tradable("BTCUSDT"));
+    }
+
+    #[test]
+    fn test_block_unknown() {
+        let guard = guard(true);
+        assert!(!guard.is_tradable("BTCUSDT"));
+
+        guard.apply(&event("BTCUSDT", DataQuality::Normal));
+        assert!(guard.is_tradable("BTCUSDT"));
+    }
+
+    #[test]
+    fn test_status_expires_without_heartbeat() {
+        let guard = guard(false);
+        let t0 = Utc::now();
+        guard.apply_at(&event("BTCUSDT", DataQuality::Normal), t0);
+
+        assert!(guard.is_tradable_at("BTCUSDT", t0 + Duration::seconds(90)));
+        // market-data 停止心跳：最后一次 Normal 不能一直有效
+        let later = t0 + Duration::seconds(91);
+        assert!(!guard.is_tradable_at("BTCUSDT", later));
+        assert_eq!(guard.status_at("BTCUSDT", later).unwrap().quality, DataQuality::Suspect);
+
+        guard.apply_at(&event("BTCUSDT", DataQuality::Normal), later);
+        assert!(guard.is_tradable_at("BTCUSDT", later));
+    }
+
+    #[test]
+    fn test_stale_feed_only_blocks_its_own_venue() {
+        let guard = guard(true);
+        let mut other_stale = event("BTCUSDT", DataQuality::Suspect);
+        other_stale.issues = vec![stale("okx")];
+        guard.apply(&other_stale);
+        assert!(guard.is_tradable("BTCUSDT"));
+
+        let mut venue_stale = event("BTCUSDT", DataQuality::Suspect);
+        venue_stale.issues = vec![stale("okx"), stale("binance")];
+        guard.apply(&venue_stale);
+        assert!(!guard.is_tradable("BTCUSDT"));
+
+        // 其他类型的异常仍然拦截
+        let mut spike = event("BTCUSDT", DataQuality::Suspect);
+        spike.issues = vec![
+            stale("okx"),
+            QualityIssue::CrossedBook {
+                exchange: "okx".to_string(),
+                best_bid: 2.into(),
+                best_ask: 1.into(),
+            },
+        ];
+        guard.apply(&spike);
+        assert!(!guard.is_tradable("BTCUSDT"));
+    }
+}
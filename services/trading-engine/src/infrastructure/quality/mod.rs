@@ -0,0 +1,8 @@
+//! # 行情质量适配器模块 (Market Quality Adapters)
+//!
+//! 实现 MarketQualityPort 的适配器。
+//! 质量状态来自 market-data 发布的 DataQualityEvent（Kafka）。
+
+pub mod in_memory_quality_guard;
+
+pub use in_memory_quality_guard::InMemoryQualityGuard;
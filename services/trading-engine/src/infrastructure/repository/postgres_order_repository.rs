@@ -293,6 +293,28 @@ impl OrderRepositoryPort for PostgresOrderRepository {
 
         Ok(orders)
     }
+
+    /// 查询有成交的策略订单
+    async fn find_filled_strategy_orders(&self) -> Result<Vec<Order>> {
+        let client = self.pool.get().await.context("获取数据库连接失败")?;
+
+        let sql = r#"
+            SELECT id, user_id, symbol, order_type, side, quantity, price,
+                   status, filled_quantity, average_price, created_at, updated_at,
+                   metadata
+            FROM orders
+            WHERE metadata->>'strategy_id' IS NOT NULL
+              AND filled_quantity::numeric > 0
+            ORDER BY created_at ASC
+        "#;
+
+        let rows = client
+            .query(sql, &[])
+            .await
+            .context("查询策略成交订单失败")?;
+
+        rows.iter().map(row_to_order).collect()
+    }
 }
 
 /// 将数据库行转换为 Order
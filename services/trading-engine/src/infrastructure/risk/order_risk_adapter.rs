@@ -57,7 +57,7 @@ use tracing::{debug, info};
 
 use crate::domain::model::order_intent::{OrderIntent, OrderSide};
 use crate::domain::port::order_risk_port::OrderRiskPort;
-use crate::domain::port::risk_state_port::{RiskStatePort, RiskStateSnapshot};
+use crate::domain::port::risk_state_port::{RiskOpenOrder, RiskStatePort, RiskStateSnapshot};
 use crate::domain::risk::result::{RiskCheckResult, RiskRejectReason};
 
 // 从 risk_limits 模块导入配置结构体
@@ -94,36 +94,46 @@ impl OrderRiskAdapter {
         Self::new(OrderRiskConfig::default(), risk_state)
     }
 
+    /// 获取风控状态快照
+    async fn snapshot(&self) -> Result<RiskStateSnapshot, RiskRejectReason> {
+        self.risk_state.get_snapshot().await.map_err(|e| RiskRejectReason::Custom {
+            rule_name: "snapshot".to_string(),
+            message: format!("获取风控状态失败: {}", e),
+        })
+    }
+
+    /// 拒绝原因转换为错误（格式与 `check` 一致）
+    fn rejection_error(reason: &RiskRejectReason) -> anyhow::Error {
+        anyhow::anyhow!("{}: {}", reason.code(), reason.message())
+    }
+
     /// 执行所有风控检查
     async fn check_all_rules(&self, intent: &OrderIntent) -> RiskCheckResult {
-        // 获取风控状态快照
-        let snapshot = match self.risk_state.get_snapshot().await {
-            Ok(s) => s,
-            Err(e) => {
-                return RiskCheckResult::rejected(RiskRejectReason::Custom {
-                    rule_name: "snapshot".to_string(),
-                    message: format!("获取风控状态失败: {}", e),
-                });
-            }
-        };
+        match self.snapshot().await {
+            Ok(snapshot) => self.check_rules(intent, &snapshot),
+            Err(reason) => RiskCheckResult::rejected(reason),
+        }
+    }
 
+    /// 按给定快照执行所有风控检查
+    fn check_rules(&self, intent: &OrderIntent, snapshot: &RiskStateSnapshot) -> RiskCheckResult {
         // 基础检查
         if let result @ RiskCheckResult::Rejected(_) = self.check_basic(intent) {
             return result;
         }
 
         // 规则 A: 单笔下单金额上限
-        if let result @ RiskCheckResult::Rejected(_) = self.check_order_amount(intent, &snapshot) {
+        if let result @ RiskCheckResult::Rejected(_) = self.check_order_amount(intent, snapshot) {
             return result;
         }
 
         // 规则 B: Symbol 维度最大仓位
-        if let result @ RiskCheckResult::Rejected(_) = self.check_symbol_position(intent, &snapshot) {
+        if let result @ RiskCheckResult::Rejected(_) = self.check_symbol_position(intent, snapshot) {
             return result;
         }
 
         // 规则 C: 账户总风险敞口
-        if let result @ RiskCheckResult::Rejected(_) = self.check_total_exposure(intent, &snapshot) {
+        if let result @ RiskCheckResult::Rejected(_) = self.check_total_exposure(intent, snapshot) {
             return result;
         }
 
@@ -133,7 +143,7 @@ impl OrderRiskAdapter {
         }
 
         // 规则 E: 强平前安全风控 (v1.1 安全修补)
-        if let result @ RiskCheckResult::Rejected(_) = self.check_margin_safety(intent, &snapshot) {
+        if let result @ RiskCheckResult::Rejected(_) = self.check_margin_safety(intent, snapshot) {
             return result;
         }
 
@@ -465,6 +475,45 @@ impl OrderRiskPort for OrderRiskAdapter {
         }
     }
 
+    /// 多腿组按合计影响校验：后面的腿计入前面腿的挂单，全部通过后才记录下单时间
+    async fn check_group(&self, legs: &[OrderIntent]) -> Result<(), (usize, anyhow::Error)> {
+        let mut snapshot = match self.snapshot().await {
+            Ok(snapshot) => snapshot,
+            Err(reason) => return Err((0, Self::rejection_error(&reason))),
+        };
+
+        for (index, leg) in legs.iter().enumerate() {
+            if let RiskCheckResult::Rejected(reason) = self.check_rules(leg, &snapshot) {
+                info!(
+                    symbol = %leg.symbol,
+                    leg = index,
+                    code = %reason.code(),
+                    message = %reason.message(),
+                    "风控检查拒绝（多腿组）"
+                );
+                return Err((index, Self::rejection_error(&reason)));
+            }
+
+            snapshot.open_orders.push(RiskOpenOrder {
+                order_id: leg.id.to_string(),
+                symbol: leg.symbol.clone(),
+                side: match leg.side {
+                    OrderSide::Buy => "BUY",
+                    OrderSide::Sell => "SELL",
+                }
+                .to_string(),
+                quantity: leg.quantity,
+                price: leg.price.unwrap_or_default(),
+                created_at: chrono::Utc::now().timestamp_millis(),
+            });
+        }
+
+        for leg in legs {
+            self.record_order_time(&leg.symbol).await;
+        }
+        Ok(())
+    }
+
     async fn update_position(&self, symbol: &str, delta: Decimal) {
         self.risk_state
             .update_position(symbol, delta, Decimal::ZERO)
@@ -673,6 +722,32 @@ mod tests {
         assert!(result.is_ok(), "应该通过");
     }
 
+    #[tokio::test]
+    async fn test_group_checked_against_combined_position() {
+        let config = OrderRiskConfig {
+            limits: RiskLimits {
+                max_position_per_symbol: dec("1"),
+                max_order_notional: dec("100000"),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let (adapter, _) = create_adapter(config);
+        let legs = vec![
+            create_intent("BTCUSDT", OrderSide::Buy, "0.6", Some("50000")),
+            create_intent("BTCUSDT", OrderSide::Buy, "0.6", Some("50000")),
+        ];
+
+        // 单独检查每条腿都在限额内
+        for leg in &legs {
+            assert!(adapter.check_rules(leg, &RiskStateSnapshot::default()).is_passed());
+        }
+
+        let (index, err) = adapter.check_group(&legs).await.unwrap_err();
+        assert_eq!(index, 1);
+        assert!(err.to_string().contains("POSITION_LIMIT_EXCEEDED"));
+    }
+
     #[tokio::test]
     async fn test_reject_open_orders_per_symbol_exceeded() {
         let config = OrderRiskConfig {
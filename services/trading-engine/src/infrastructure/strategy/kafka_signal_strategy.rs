@@ -0,0 +1,248 @@
+//! # Kafka 信号策略适配器 (Kafka Signal Strategy)
+//!
+//! 异步策略模式：strategy-engine 把版本化 `SignalEvent` 发布到信号事件主题，
+//! `SignalEventKafkaConsumer` 消费后经本适配器检查、转换为意图组，
+//! 由 SignalConsumerService 立即执行，不等待该交易对的下一条行情。
+//!
+//! ## 规则
+//! - ✅ 按信号 ID 去重（重复投递只执行一次）
+//! - ✅ 超过最大时延或指令过期的信号直接丢弃
+//! - ✅ 作为 StrategyPort 不从行情事件产生意图
+//! - ❌ 不接受高于当前版本的信号（`SIGNAL_EVENT_SCHEMA_VERSION`）
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::Mutex;
+use shared::event::market_event::MarketEvent;
+use shared::event::signal_event::{SignalEvent, SignalGate, SignalLeg, SignalType};
+use shared::types::order::OrderSide as SharedOrderSide;
+use tracing::{debug, warn};
+
+use crate::domain::model::order_intent::{OrderIntent, OrderSide, OrderType};
+use crate::domain::model::order_intent_group::OrderIntentGroup;
+use crate::domain::port::strategy_port::StrategyPort;
+
+/// Kafka 信号策略
+pub struct KafkaSignalStrategy {
+    /// 去重与过期闸门
+    gate: Mutex<SignalGate>,
+}
+
+impl KafkaSignalStrategy {
+    /// 创建适配器
+    ///
+    /// - `max_age`: 信号创建到执行的最大时延（0 不限）
+    /// - `dedupe_window`: 记住的最近信号 ID 数
+    pub fn new(max_age: Duration, dedupe_window: usize) -> Self {
+        Self {
+            gate: Mutex::new(SignalGate::new(max_age, dedupe_window)),
+        }
+    }
+
+    /// 接收一条信号事件，返回待执行的意图组
+    ///
+    /// 重复、过期、版本不支持或无法转换时返回 None。
+    pub fn accept(&self, event: SignalEvent, now: DateTime<Utc>) -> Option<OrderIntentGroup> {
+        if let Err(reason) = self.gate.lock().admit(&event, now) {
+            warn!(
+                signal_id = %event.id,
+                strategy_id = %event.strategy_id,
+                symbol = %event.symbol,
+                reason = %reason,
+                "signal event rejected"
+            );
+            return None;
+        }
+
+        let group = to_intent_group(&event);
+        if group.is_none() {
+            debug!(signal_id = %event.id, symbol = %event.symbol, "signal event has no executable intent");
+        }
+        group
+    }
+}
+
+/// 信号事件转换为意图组（Hold 或方向无效时返回 None）
+///
+/// 意图沿用信号 ID 与创建时间，便于下游按信号追踪。
+fn to_intent_group(event: &SignalEvent) -> Option<OrderIntentGroup> {
+    if event.is_group() {
+        let legs = event
+            .legs
+            .iter()
+            .map(|leg| leg_intent(event, leg))
+            .collect::<Vec<_>>();
+        let mut group = OrderIntentGroup::new(event.strategy_id, legs);
+        group.id = event.id;
+        group.created_at = event.created_at;
+        return Some(group);
+    }
+
+    let side = match event.signal_type {
+        SignalType::Buy => OrderSide::Buy,
+        SignalType::Sell => OrderSide::Sell,
+        SignalType::Hold => return None,
+    };
+    // v1 信号没有订单类型提示，按信号价格下限价单
+    let price = match event.instruction.resolve_order_type(Some(event.price)) {
+        OrderType::Market => None,
+        _ => Some(event.price),
+    };
+
+    let mut intent = OrderIntent::new(
+        event.strategy_id,
+        event.symbol.clone(),
+        side,
+        event.quantity,
+        price,
+        event.confidence,
+    )
+    .with_instruction(event.instruction.clone());
+    intent.id = event.id;
+    intent.created_at = event.created_at;
+    Some(OrderIntentGroup::single(intent))
+}
+
+/// 多腿组中的一腿
+fn leg_intent(event: &SignalEvent, leg: &SignalLeg) -> OrderIntent {
+    let side = match leg.side {
+        SharedOrderSide::Buy => OrderSide::Buy,
+        SharedOrderSide::Sell => OrderSide::Sell,
+    };
+
+    let mut intent = OrderIntent::new(
+        event.strategy_id,
+        leg.symbol.clone(),
+        side,
+        leg.quantity,
+        leg.price,
+        event.confidence,
+    )
+    .with_instruction(leg.instruction.clone());
+    intent.created_at = event.created_at;
+    intent
+}
+
+#[async_trait]
+impl StrategyPort for KafkaSignalStrategy {
+    /// 信号由 SignalConsumerService 直接执行，行情事件不产生意图
+    async fn evaluate(&self, _event: &MarketEvent) -> anyhow::Result<Option<OrderIntent>> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+    use shared::event::signal_event::SIGNAL_EVENT_SCHEMA_VERSION;
+    use shared::types::order::OrderInstruction;
+    use uuid::Uuid;
+
+    fn signal(symbol: &str, created_at: DateTime<Utc>) -> SignalEvent {
+        SignalEvent {
+            schema_version: SIGNAL_EVENT_SCHEMA_VERSION,
+            id: Uuid::new_v4(),
+            strategy_id: Uuid::new_v4(),
+            symbol: symbol.to_string(),
+            signal_type: SignalType::Buy,
+            price: Decimal::new(50_000, 0),
+            quantity: Decimal::new(1, 3),
+            confidence: 0.8,
+            created_at,
+            instruction: OrderInstruction::open().with_order_type(OrderType::Market),
+            legs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_dedupe_and_expiry() {
+        let strategy = KafkaSignalStrategy::new(Duration::seconds(5), 100);
+        let now = Utc::now();
+
+        // 重复投递只执行一次；市价信号不带委托价
+        let event = signal("BTCUSDT", now);
+        let intent = strategy.accept(event.clone(), now).unwrap().into_single().unwrap();
+        assert_eq!(intent.id, event.id);
+        assert_eq!(intent.price, None);
+        assert!(strategy.accept(event, now).is_none());
+
+        // 到达时已超过最大时延
+        assert!(strategy.accept(signal("BTCUSDT", now - Duration::seconds(10)), now).is_none());
+
+        // 不支持的新版本
+        let mut future = signal("BTCUSDT", now);
+        future.schema_version = SIGNAL_EVENT_SCHEMA_VERSION + 1;
+        assert!(strategy.accept(future, now).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_market_events_produce_no_intent() {
+        let strategy = KafkaSignalStrategy::new(Duration::zero(), 100);
+        let now = Utc::now();
+        assert!(strategy.accept(signal("BTCUSDT", now), now).is_some());
+
+        let event = MarketEvent {
+            event_type: shared::event::market_event::MarketEventType::Trade,
+            exchange: "binance".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            timestamp: now,
+            data: shared::event::market_event::MarketEventData::Trade(
+                shared::event::market_event::TradeData {
+                    trade_id: "1".to_string(),
+                    price: Decimal::new(50_000, 0),
+                    quantity: Decimal::ONE,
+                    is_buyer_maker: false,
+                },
+            ),
+        };
+        assert!(strategy.evaluate_group(&event).await.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_legacy_and_group_signals() {
+        let strategy = KafkaSignalStrategy::new(Duration::zero(), 100);
+        let now = Utc::now();
+
+        // v1 信号：缺省版本与指令，按信号价格下限价单
+        let legacy: SignalEvent = serde_json::from_value(serde_json::json!({
+            "id": Uuid::new_v4(),
+            "strategy_id": Uuid::new_v4(),
+            "symbol": "ETHUSDT",
+            "signal_type": "Sell",
+            "price": "3000",
+            "quantity": "0.1",
+            "confidence": 0.5,
+            "created_at": now,
+        }))
+        .unwrap();
+        assert_eq!(legacy.schema_version, 1);
+        let intent = strategy.accept(legacy, now).unwrap().into_single().unwrap();
+        assert_eq!(intent.side, OrderSide::Sell);
+        assert_eq!(intent.price, Some(Decimal::new(3000, 0)));
+
+        // 多腿组：整组一条信号
+        let mut group = signal("BTCUSDT", now);
+        group.legs = vec![
+            SignalLeg {
+                symbol: "BTCUSDT".to_string(),
+                side: SharedOrderSide::Buy,
+                quantity: Decimal::ONE,
+                price: None,
+                instruction: OrderInstruction::open(),
+            },
+            SignalLeg {
+                symbol: "BTCUSDT_PERP".to_string(),
+                side: SharedOrderSide::Sell,
+                quantity: Decimal::ONE,
+                price: Some(Decimal::new(50_100, 0)),
+                instruction: OrderInstruction::open(),
+            },
+        ];
+        let intents = strategy.accept(group.clone(), now).unwrap();
+        assert_eq!(intents.id, group.id);
+        assert_eq!(intents.legs.len(), 2);
+        assert_eq!(intents.legs[1].side, OrderSide::Sell);
+        assert_eq!(intents.legs[1].symbol, "BTCUSDT_PERP");
+    }
+}
@@ -2,8 +2,10 @@
 //!
 //! 实现 StrategyPort 的适配器。
 
+pub mod kafka_signal_strategy;
 pub mod noop_strategy;
 pub mod remote_strategy;
 
+pub use kafka_signal_strategy::KafkaSignalStrategy;
 pub use noop_strategy::NoopStrategy;
 pub use remote_strategy::RemoteStrategy;
@@ -2,6 +2,7 @@
 
 use anyhow::Context;
 use async_trait::async_trait;
+use chrono::DateTime;
 use reqwest::Client;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -9,7 +10,10 @@ use shared::event::market_event::{MarketEvent, MarketEventData};
 use tracing::{debug, warn};
 use uuid::Uuid;
 
-use crate::domain::model::order_intent::{OrderIntent, OrderSide};
+use crate::domain::model::order_intent::{
+    OrderInstruction, OrderIntent, OrderSide, OrderType, PositionAction, PositionSide, TimeInForce,
+};
+use crate::domain::model::order_intent_group::OrderIntentGroup;
 use crate::domain::port::strategy_port::StrategyPort;
 
 pub struct RemoteStrategy {
@@ -47,6 +51,9 @@ struct ApiResponse<T> {
 struct EvaluateResponse {
     has_intent: bool,
     intent: Option<IntentDto>,
+    /// 多腿意图（旧版本 strategy-engine 不返回）
+    #[serde(default)]
+    legs: Vec<IntentDto>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -57,11 +64,80 @@ struct IntentDto {
     quantity: Decimal,
     price: Option<Decimal>,
     confidence: f64,
+    // 以下字段为扩展指令，旧版本 strategy-engine 不返回
+    #[serde(default)]
+    order_type: Option<String>,
+    #[serde(default)]
+    action: Option<String>,
+    #[serde(default)]
+    position_side: Option<String>,
+    #[serde(default)]
+    time_in_force: Option<String>,
+    #[serde(default)]
+    stop_loss: Option<Decimal>,
+    #[serde(default)]
+    take_profit: Option<Decimal>,
+    #[serde(default)]
+    expires_at: Option<i64>,
+}
+
+impl IntentDto {
+    /// 转换为交易意图，方向无法识别时返回 None
+    fn into_intent(self) -> Option<OrderIntent> {
+        let side = match self.side.to_ascii_lowercase().as_str() {
+            "buy" => OrderSide::Buy,
+            "sell" => OrderSide::Sell,
+            _ => return None,
+        };
+
+        let instruction = self.instruction();
+
+        Some(
+            OrderIntent::new(
+                self.strategy_id,
+                self.symbol,
+                side,
+                self.quantity,
+                self.price,
+                self.confidence,
+            )
+            .with_instruction(instruction),
+        )
+    }
+
+    /// 解析下单指令，无法识别的取值按缺省处理
+    fn instruction(&self) -> OrderInstruction {
+        let action = match self.action.as_deref() {
+            Some(raw) => PositionAction::parse(raw).unwrap_or_else(|| {
+                warn!(action = %raw, "unknown position action, fallback to open");
+                PositionAction::Open
+            }),
+            None => PositionAction::Open,
+        };
+
+        OrderInstruction {
+            action,
+            position_side: self.position_side.as_deref().and_then(PositionSide::parse),
+            order_type: self.order_type.as_deref().and_then(OrderType::parse),
+            time_in_force: self.time_in_force.as_deref().and_then(TimeInForce::parse),
+            stop_loss: self.stop_loss,
+            take_profit: self.take_profit,
+            expires_at: self.expires_at.and_then(DateTime::from_timestamp_millis),
+        }
+    }
 }
 
 #[async_trait]
 impl StrategyPort for RemoteStrategy {
+    /// 单意图评估（多腿组不通过此方法返回，请使用 `evaluate_group`）
     async fn evaluate(&self, event: &MarketEvent) -> anyhow::Result<Option<OrderIntent>> {
+        Ok(self
+            .evaluate_group(event)
+            .await?
+            .and_then(OrderIntentGroup::into_single))
+    }
+
+    async fn evaluate_group(&self, event: &MarketEvent) -> anyhow::Result<Option<OrderIntentGroup>> {
         let trade = match &event.data {
             MarketEventData::Trade(trade) => trade,
             _ => return Ok(None),
@@ -112,24 +188,26 @@ impl StrategyPort for RemoteStrategy {
             return Ok(None);
         }
 
-        let dto = match result.intent {
-            Some(v) => v,
-            None => return Ok(None),
-        };
-
-        let side = match dto.side.to_ascii_lowercase().as_str() {
-            "buy" => OrderSide::Buy,
-            "sell" => OrderSide::Sell,
-            _ => return Ok(None),
-        };
+        // 多腿组：任一腿无法解析则整组丢弃
+        if !result.legs.is_empty() {
+            let leg_count = result.legs.len();
+            let legs: Option<Vec<OrderIntent>> =
+                result.legs.into_iter().map(IntentDto::into_intent).collect();
+            return match legs {
+                Some(legs) => {
+                    let strategy_id = legs[0].strategy_id;
+                    Ok(Some(OrderIntentGroup::new(strategy_id, legs)))
+                }
+                None => {
+                    warn!(symbol = %event.symbol, leg_count, "invalid leg in intent group, group dropped");
+                    Ok(None)
+                }
+            };
+        }
 
-        Ok(Some(OrderIntent::new(
-            dto.strategy_id,
-            dto.symbol,
-            side,
-            dto.quantity,
-            dto.price,
-            dto.confidence,
-        )))
+        Ok(result
+            .intent
+            .and_then(IntentDto::into_intent)
+            .map(OrderIntentGroup::single))
     }
 }
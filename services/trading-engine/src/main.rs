@@ -20,9 +20,12 @@
 //!   ├── bootstrap::create_risk_state() → 创建唯一 RiskState
 //!   ├── RiskStateCoordinator::new() → 创建协调器
 //!   ├── coordinator.rebuild(Startup) → 初始化状态
+//!   ├── bootstrap::create_quality_guard() → 创建行情质量守卫（可选）
+//!   ├── bootstrap::create_feedback_service() → 创建策略执行回报（可选）
 //!   ├── bootstrap::create_market_event_consumer_with_state() → 创建消费者
 //!   ├── bootstrap::start_background_services() → 启动后台服务
-//!   │     └── OrderLifecycleService (tokio::spawn)
+//!   │     ├── OrderLifecycleService (tokio::spawn)
+//!   │     └── QualityStatusWorker (tokio::spawn，可选)
 //!   └── axum::serve() → 启动 HTTP 服务
 //! ```
 
@@ -50,10 +53,13 @@ use crate::infrastructure::exchange::{create_fill_stream, BinanceQueryAdapter};
 use crate::application::service::risk_state_coordinator::{RiskStateCoordinator, RebuildReason};
 use crate::bootstrap::{
     create_risk_state,
+    create_quality_guard,
+    create_feedback_service,
     create_market_event_consumer_with_state,
     start_background_services,
     ConsumerConfig,
     ConsumerConfigWithState,
+    QualityGuardConfig,
 };
 
 /// # 主函数 - 服务启动入口
@@ -63,11 +69,12 @@ use crate::bootstrap::{
 /// 2. 创建应用状态（配置）
 /// 3. 创建唯一的 RiskState 实例
 /// 4. 创建 RiskStateCoordinator 并初始化状态
-/// 5. 创建交易主链路消费者（共享 RiskState）
-/// 6. 通过 bootstrap 启动所有后台服务
-/// 7. 创建交易所查询适配器
-/// 8. 创建 HTTP 路由
-/// 9. 绑定端口并启动服务
+/// 5. 创建行情质量守卫与策略执行回报（可选）
+/// 6. 创建交易主链路消费者（共享 RiskState）
+/// 7. 通过 bootstrap 启动所有后台服务
+/// 8. 创建交易所查询适配器
+/// 9. 创建 HTTP 路由
+/// 10. 绑定端口并启动服务
 #[tokio::main]
 async fn main() -> Result<()> {
     // 加载 .env 文件
@@ -130,7 +137,37 @@ async fn main() -> Result<()> {
     info!("RiskStateCoordinator 已初始化");
 
     // ========================================
-    // Step 4: 创建交易主链路消费者（共享 RiskState）
+    // Step 4: 创建行情质量守卫与策略执行回报（可选）
+    // ========================================
+    let quality = match config.kafka_quality_topic.clone() {
+        Some(topic) => Some(create_quality_guard(QualityGuardConfig {
+            kafka_brokers: config.kafka_brokers.clone(),
+            kafka_quality_topic: topic,
+            kafka_consumer_group: config.kafka_consumer_group.clone(),
+            block_unknown: config.quality_block_unknown,
+            heartbeat_secs: config.quality_heartbeat_secs,
+            venue: config.quality_venue.clone(),
+        })?),
+        None => {
+            info!("行情质量守卫未启用");
+            None
+        }
+    };
+    let (quality_guard, quality_worker) = match quality {
+        Some(components) => (Some(components.guard), Some(components.consumer)),
+        None => (None, None),
+    };
+
+    let feedback = match config.kafka_feedback_topic.clone() {
+        Some(topic) => Some(create_feedback_service(config.kafka_brokers.clone(), topic)?),
+        None => {
+            info!("策略执行回报未启用");
+            None
+        }
+    };
+
+    // ========================================
+    // Step 5: 创建交易主链路消费者（共享 RiskState）
     // ========================================
     let consumer_config = ConsumerConfigWithState {
         config: ConsumerConfig {
@@ -139,6 +176,7 @@ async fn main() -> Result<()> {
             kafka_consumer_group: config.kafka_consumer_group.clone(),
             strategy_mode: config.strategy_mode.clone(),
             strategy_url: config.strategy_engine_url.clone(),
+            signal_stream: config.signal_stream.clone(),
             execution_mode: config.execution_mode.clone(),
             binance_api_key: config.binance_api_key.clone(),
             binance_secret_key: config.binance_secret_key.clone(),
@@ -147,6 +185,9 @@ async fn main() -> Result<()> {
             storage_enabled: config.storage_enabled,
         },
         risk_state: Arc::clone(&risk_state),
+        quality_guard,
+        feedback,
+        exchange_query: Some(Arc::clone(&exchange_query)),
     };
 
     let market_consumer = create_market_event_consumer_with_state(consumer_config).await?;
@@ -183,24 +224,24 @@ async fn main() -> Result<()> {
     info!("真实成交回报链路已启动");
 
     // ========================================
-    // Step 5: 通过 bootstrap 启动所有后台服务
+    // Step 6: 通过 bootstrap 启动所有后台服务
     // ========================================
-    let background_handles = start_background_services(Arc::clone(&risk_state));
+    let background_handles = start_background_services(Arc::clone(&risk_state), quality_worker);
     info!("所有后台服务已通过 bootstrap 启动");
 
     // ========================================
-    // Step 6: 创建交易所查询适配器（用于 HTTP API）
+    // Step 7: 创建交易所查询适配器（用于 HTTP API）
     // ========================================
     info!("交易所查询适配器已启用 (Binance)");
     let http_exchange_query = exchange_query;
 
     // ========================================
-    // Step 7: 创建 HTTP 路由
+    // Step 8: 创建 HTTP 路由
     // ========================================
     let app = interface::http::routes::create_router(http_exchange_query);
 
     // ========================================
-    // Step 8: 启动 HTTP 服务
+    // Step 9: 启动 HTTP 服务
     // ========================================
     let port: u16 = std::env::var("TRADING_ENGINE_PORT")
         .ok()
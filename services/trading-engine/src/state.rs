@@ -5,6 +5,8 @@ use rust_decimal::Decimal;
 use std::str::FromStr;
 use std::sync::Arc;
 
+use crate::bootstrap::strategy::SignalStreamConfig;
+
 /// 应用状态
 #[derive(Clone)]
 pub struct AppState {
@@ -22,10 +24,12 @@ pub struct AppConfig {
     pub kafka_consumer_group: String,
     /// 执行模式: "binance" 或 "binance_futures"
     pub execution_mode: String,
-    /// 策略模式: "remote"
+    /// 策略模式: "remote" 或 "kafka"
     pub strategy_mode: String,
     /// Strategy Engine 服务地址
     pub strategy_engine_url: Option<String>,
+    /// 信号事件消费配置（kafka 策略模式）
+    pub signal_stream: SignalStreamConfig,
     /// 风控模式: "remote"
     pub risk_mode: String,
     /// Risk Management 服务地址（remote 模式需要）
@@ -46,6 +50,16 @@ pub struct AppConfig {
     pub risk_allow_symbols: Option<Vec<String>>,
     /// 是否启用订单存储
     pub storage_enabled: bool,
+    /// 行情质量 Topic（None 表示不启用质量守卫）
+    pub kafka_quality_topic: Option<String>,
+    /// 是否拦截尚未收到质量状态的交易对（默认拦截）
+    pub quality_block_unknown: bool,
+    /// market-data 质量心跳间隔（秒）
+    pub quality_heartbeat_secs: u64,
+    /// 下单交易所（对应行情事件的 exchange）
+    pub quality_venue: String,
+    /// 策略执行回报 Topic（None 表示不回送）
+    pub kafka_feedback_topic: Option<String>,
 }
 
 impl AppState {
@@ -62,6 +76,7 @@ impl AppState {
             strategy_mode: std::env::var("TRADING_STRATEGY_MODE")
                 .unwrap_or_else(|_| "remote".to_string()),
             strategy_engine_url: std::env::var("STRATEGY_ENGINE_URL").ok(),
+            signal_stream: parse_signal_stream_env()?,
             risk_mode: std::env::var("TRADING_RISK_MODE")
                 .unwrap_or_else(|_| "remote".to_string()),
             risk_management_url: std::env::var("RISK_MANAGEMENT_URL").ok(),
@@ -76,6 +91,17 @@ impl AppState {
             storage_enabled: std::env::var("TRADING_STORAGE_ENABLED")
                 .map(|v| v.to_lowercase() == "true")
                 .unwrap_or(false),
+            kafka_quality_topic: parse_quality_topic_env(),
+            quality_block_unknown: std::env::var("TRADING_QUALITY_BLOCK_UNKNOWN")
+                .map(|v| v.to_lowercase() != "false")
+                .unwrap_or(true),
+            quality_heartbeat_secs: std::env::var("TRADING_QUALITY_HEARTBEAT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(30),
+            quality_venue: std::env::var("TRADING_QUALITY_VENUE")
+                .unwrap_or_else(|_| "binance".to_string()),
+            kafka_feedback_topic: parse_feedback_topic_env(),
         };
 
         Ok(Self {
@@ -104,6 +130,54 @@ fn parse_decimal_env(key: &str) -> Result<Option<Decimal>> {
     Ok(value)
 }
 
+/// 行情质量守卫开关（TRADING_QUALITY_GUARD_ENABLED，默认启用）
+fn parse_quality_topic_env() -> Option<String> {
+    let enabled = std::env::var("TRADING_QUALITY_GUARD_ENABLED")
+        .map(|v| v.to_lowercase() != "false")
+        .unwrap_or(true);
+    if !enabled {
+        return None;
+    }
+    Some(
+        std::env::var("KAFKA_MARKET_QUALITY_TOPIC")
+            .unwrap_or_else(|_| "market-quality".to_string()),
+    )
+}
+
+/// 策略执行回报开关（TRADING_FEEDBACK_ENABLED，默认启用）
+fn parse_feedback_topic_env() -> Option<String> {
+    let enabled = std::env::var("TRADING_FEEDBACK_ENABLED")
+        .map(|v| v.to_lowercase() != "false")
+        .unwrap_or(true);
+    if !enabled {
+        return None;
+    }
+    Some(
+        std::env::var("KAFKA_EXECUTION_FEEDBACK_TOPIC")
+            .unwrap_or_else(|_| "execution-feedback".to_string()),
+    )
+}
+
+/// 信号事件消费配置（kafka 策略模式）
+fn parse_signal_stream_env() -> Result<SignalStreamConfig> {
+    let defaults = SignalStreamConfig::default();
+    Ok(SignalStreamConfig {
+        topic: std::env::var("KAFKA_SIGNAL_EVENT_TOPIC").unwrap_or(defaults.topic),
+        max_age_ms: parse_number_env("TRADING_SIGNAL_MAX_AGE_MS", defaults.max_age_ms)?,
+        dedupe_window: parse_number_env("TRADING_SIGNAL_DEDUPE_WINDOW", defaults.dedupe_window)?,
+    })
+}
+
+fn parse_number_env<T: FromStr>(key: &str, default: T) -> Result<T> {
+    match std::env::var(key) {
+        Ok(value) if !value.trim().is_empty() => value
+            .trim()
+            .parse()
+            .map_err(|_| anyhow::anyhow!("invalid number for {}", key)),
+        _ => Ok(default),
+    }
+}
+
 fn parse_symbols_env(key: &str) -> Option<Vec<String>> {
     std::env::var(key)
         .ok()
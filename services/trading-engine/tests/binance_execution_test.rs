@@ -14,10 +14,10 @@
 use rust_decimal::Decimal;
 use std::str::FromStr;
 
-use crate::domain::port::execution_port::{
+use trading_engine::domain::port::execution_port::{
     ExecutionCommand, ExecutionPort, OrderSide, OrderType, TimeInForce,
 };
-use crate::infrastructure::execution::{
+use trading_engine::infrastructure::execution::{
     BinanceExecution, BinanceFuturesExecution, FuturesCommand, MarginType, PositionSide,
     RateLimiter, RateLimiterConfig, RetryConfig, RetryPolicy,
 };
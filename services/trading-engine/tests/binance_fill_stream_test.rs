@@ -11,9 +11,13 @@ use tokio::sync::mpsc;
 use uuid::Uuid;
 
 use trading_engine::application::service::execution_service::ExecutionService;
-use trading_engine::domain::model::execution_fill::{ExecutionFill, FillSide, FillType};
+use trading_engine::domain::model::execution_fill::{
+    ExecutionFill, ExecutionStreamEvent, FillSide, FillType,
+};
 use trading_engine::domain::model::order_intent::OrderIntent;
-use trading_engine::domain::port::order_execution_port::{ExecutionResult, OrderExecutionPort};
+use trading_engine::domain::port::order_execution_port::{
+    CancelResult, ExecutionResult, OrderExecutionPort,
+};
 use trading_engine::domain::port::risk_state_port::RiskStatePort;
 use trading_engine::domain::port::strategy_port::StrategyPort;
 use trading_engine::infrastructure::risk::inmemory_risk_state::InMemoryRiskStateAdapter;
@@ -51,6 +55,10 @@ impl OrderExecutionPort for MockExecution {
             error: None,
         })
     }
+
+    async fn cancel(&self, _symbol: &str, order_id: &str) -> anyhow::Result<CancelResult> {
+        Ok(CancelResult { order_id: order_id.to_string(), filled_quantity: Decimal::ZERO })
+    }
 }
 
 // ========== 测试 1: Binance 成交事件 → ExecutionFill 映射 ==========
@@ -255,7 +263,7 @@ async fn test_fill_consumer_via_channel() {
     ));
 
     // 3. 创建 channel
-    let (fill_tx, fill_rx) = mpsc::channel::<ExecutionFill>(100);
+    let (fill_tx, fill_rx) = mpsc::channel::<ExecutionStreamEvent>(100);
 
     // 4. 先添加 open_orders
     risk_state
@@ -305,7 +313,7 @@ async fn test_fill_consumer_via_channel() {
         created_at: chrono::Utc::now(),
     };
 
-    fill_tx.send(fill1).await.unwrap();
+    fill_tx.send(ExecutionStreamEvent::Fill(fill1)).await.unwrap();
 
     // 等待处理
     tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
@@ -339,7 +347,7 @@ async fn test_fill_consumer_via_channel() {
         created_at: chrono::Utc::now(),
     };
 
-    fill_tx.send(fill2).await.unwrap();
+    fill_tx.send(ExecutionStreamEvent::Fill(fill2)).await.unwrap();
 
     // 等待处理
     tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
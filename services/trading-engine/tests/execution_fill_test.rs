@@ -11,7 +11,9 @@ use uuid::Uuid;
 use trading_engine::application::service::execution_service::ExecutionService;
 use trading_engine::domain::model::execution_fill::{ExecutionFill, FillSide, FillType};
 use trading_engine::domain::model::order_intent::{OrderIntent, OrderSide as IntentSide};
-use trading_engine::domain::port::order_execution_port::{ExecutionResult, OrderExecutionPort};
+use trading_engine::domain::port::order_execution_port::{
+    CancelResult, ExecutionResult, OrderExecutionPort,
+};
 use trading_engine::domain::port::risk_state_port::RiskStatePort;
 use trading_engine::domain::port::strategy_port::StrategyPort;
 use trading_engine::infrastructure::risk::inmemory_risk_state::InMemoryRiskStateAdapter;
@@ -78,6 +80,10 @@ impl OrderExecutionPort for MockExecution {
             })
         }
     }
+
+    async fn cancel(&self, _symbol: &str, order_id: &str) -> anyhow::Result<CancelResult> {
+        Ok(CancelResult { order_id: order_id.to_string(), filled_quantity: Decimal::ZERO })
+    }
 }
 
 // ========== 测试辅助函数 ==========
@@ -107,21 +113,48 @@ fn create_buy_intent(symbol: &str, quantity: Decimal, price: Decimal) -> OrderIn
         price: Some(price),
         confidence: 0.9,
         created_at: chrono::Utc::now(),
+        instruction: Default::default(),
+    }
+}
+
+/// 模拟交易所回报：按委托价全部成交当前所有未完成订单（手续费 0.1%）
+async fn fill_open_orders(service: &ExecutionService, risk_state: &InMemoryRiskStateAdapter) {
+    let snapshot = risk_state.get_snapshot().await.unwrap();
+    for order in snapshot.open_orders {
+        let fill = ExecutionFill {
+            id: Uuid::new_v4(),
+            order_id: order.order_id.clone(),
+            trade_id: format!("trade_{}", order.order_id),
+            client_order_id: None,
+            symbol: order.symbol.clone(),
+            side: if order.side == "BUY" { FillSide::Buy } else { FillSide::Sell },
+            fill_type: FillType::Full,
+            filled_quantity: order.quantity,
+            fill_price: order.price,
+            cumulative_quantity: order.quantity,
+            original_quantity: order.quantity,
+            commission: order.quantity * order.price * dec("0.001"),
+            commission_asset: "USDT".to_string(),
+            fill_time: chrono::Utc::now(),
+            created_at: chrono::Utc::now(),
+        };
+        service.apply_execution_fill(&fill).await;
     }
 }
 
 fn create_risk_config(allowed_symbols: Vec<String>, max_position: Decimal) -> OrderRiskConfig {
-    let mut config = OrderRiskConfig::default();
-    config.allowed_symbols = allowed_symbols.into_iter().collect();
-    config.trading_enabled = true;
-    config.limits = RiskLimits {
-        max_order_notional: dec("100000"),
-        max_position_per_symbol: max_position,
-        max_total_exposure: dec("500000"),
-        max_market_order_notional: dec("50000"),
+    OrderRiskConfig {
+        allowed_symbols: allowed_symbols.into_iter().collect(),
+        trading_enabled: true,
+        limits: RiskLimits {
+            max_order_notional: dec("100000"),
+            max_position_per_symbol: max_position,
+            max_total_exposure: dec("500000"),
+            max_market_order_notional: dec("50000"),
+            ..Default::default()
+        },
         ..Default::default()
-    };
-    config
+    }
 }
 
 // ========== 测试 1: 成交后仓位与余额一致性 ==========
@@ -155,6 +188,9 @@ async fn test_fill_updates_position_and_balance_correctly() {
     let event = create_market_event("BTCUSDT", dec("50000"));
     service.on_market_event(&event).await.unwrap();
 
+    // 仓位只在成交回报时记账，模拟交易所成交
+    fill_open_orders(&service, &risk_state).await;
+
     // 5. 验证 RiskState
     let snapshot = risk_state.get_snapshot().await.unwrap();
 
@@ -202,6 +238,7 @@ async fn test_risk_rejects_after_position_limit_reached() {
 
     let event = create_market_event("BTCUSDT", dec("50000"));
     service1.on_market_event(&event).await.unwrap();
+    fill_open_orders(&service1, &risk_state).await;
 
     // 验证第一笔成交
     let snapshot = risk_state.get_snapshot().await.unwrap();
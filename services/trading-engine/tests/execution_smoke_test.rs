@@ -4,7 +4,7 @@
 //!
 //! ## 测试范围
 //! - ExecutionPort trait 可被实现
-//! - NoopExecution 能正常执行
+//! - NoopExecution 拒绝下单（安全兜底）
 //! - ExecutionService 能调度完整链路
 //! - 风控拦截验证（Symbol 白名单、仓位超限）
 
@@ -12,7 +12,9 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 // 导入 trading-engine 模块
-use trading_engine::domain::port::execution_port::{ExecutionCommand, ExecutionPort};
+use trading_engine::domain::port::execution_port::{
+    ExecutionCommand, ExecutionPort, OrderSide as CommandSide,
+};
 use trading_engine::domain::port::strategy_port::StrategyPort;
 use trading_engine::domain::port::order_risk_port::OrderRiskPort;
 use trading_engine::domain::port::order_execution_port::OrderExecutionPort;
@@ -39,16 +41,16 @@ fn dec(s: &str) -> Decimal {
 async fn test_noop_execution_port() {
     let executor = NoopExecution::new();
     
-    let command = ExecutionCommand {
-        symbol: "BTCUSDT".to_string(),
-        side: "buy".to_string(),
-        quantity: "0.001".to_string(),
-    };
+    let command = ExecutionCommand::market(
+        "BTCUSDT".to_string(),
+        CommandSide::Buy,
+        dec("0.001"),
+    );
     
     let result = executor.execute(&command).await;
     
-    // NoopExecution 应该始终成功
-    assert!(result.is_ok(), "NoopExecution should always succeed");
+    // NoopExecution 是安全兜底：未配置真实执行时拒绝一切下单
+    assert!(result.is_err(), "NoopExecution should refuse to place orders");
 }
 
 /// Test 2: OrderExecutor 适配器验证
@@ -68,10 +70,11 @@ async fn test_order_executor_adapter() {
     
     let result = executor.execute(&intent).await;
     
-    assert!(result.is_ok(), "OrderExecutor should succeed with NoopExecution");
-    let exec_result = result.unwrap();
-    assert!(exec_result.success, "Execution result should be success");
+    // 底层拒单转换为失败的执行结果，而不是 Err
+    let exec_result = result.expect("OrderExecutor should map the refusal into a result");
+    assert!(!exec_result.success, "Execution result should report failure");
     assert_eq!(exec_result.symbol, "ETHUSDT");
+    assert!(exec_result.error.is_some(), "Refusal reason should be kept");
 }
 
 /// Test 3: ExecutionService 完整链路验证
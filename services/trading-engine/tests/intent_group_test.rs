@@ -0,0 +1,336 @@
+//! # 多腿意图组集成测试
+//!
+//! 测试 OrderIntentGroup 的整体执行：前置检查全部通过才下单，腿失败时撤销已下单的腿并平掉已成交部分
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use trading_engine::application::service::execution_service::ExecutionService;
+use trading_engine::domain::model::order_intent::{OrderIntent, OrderSide, PositionAction};
+use trading_engine::domain::model::order_intent_group::OrderIntentGroup;
+use trading_engine::domain::port::exchange_query_port::{
+    AccountBalance, CancelOrderResult, ExchangeOrder, ExchangeOrderStatus, ExchangeQueryPort,
+    Position,
+};
+use trading_engine::domain::port::order_execution_port::{
+    CancelResult, ExecutionResult, OrderExecutionPort,
+};
+use trading_engine::domain::port::order_risk_port::OrderRiskPort;
+use trading_engine::domain::port::strategy_port::StrategyPort;
+
+use shared::event::market_event::{MarketEvent, MarketEventData, MarketEventType, TradeData};
+
+fn dec(s: &str) -> Decimal {
+    s.parse().unwrap_or_default()
+}
+
+// ========== Mock Strategy（多腿） ==========
+
+struct GroupStrategy {
+    group: OrderIntentGroup,
+}
+
+#[async_trait]
+impl StrategyPort for GroupStrategy {
+    async fn evaluate(&self, _event: &MarketEvent) -> anyhow::Result<Option<OrderIntent>> {
+        Ok(None)
+    }
+
+    async fn evaluate_group(&self, _event: &MarketEvent) -> anyhow::Result<Option<OrderIntentGroup>> {
+        Ok(Some(self.group.clone()))
+    }
+}
+
+// ========== Mock Risk（按交易对拒绝） ==========
+
+struct SymbolRisk {
+    rejected: HashSet<String>,
+}
+
+#[async_trait]
+impl OrderRiskPort for SymbolRisk {
+    async fn check(&self, intent: &OrderIntent) -> anyhow::Result<()> {
+        if self.rejected.contains(&intent.symbol) {
+            anyhow::bail!("symbol not allowed: {}", intent.symbol);
+        }
+        Ok(())
+    }
+
+    async fn update_position(&self, _symbol: &str, _delta: Decimal) {}
+
+    async fn record_order_time(&self, _symbol: &str) {}
+}
+
+// ========== Mock Execution（记录下单与撤单，按交易对失败） ==========
+
+struct RecordingExecution {
+    failing: HashSet<String>,
+    /// 撤单时报告的已成交数量
+    filled_on_cancel: Decimal,
+    /// 撤单是否失败
+    cancel_fails: bool,
+    submitted: Mutex<Vec<OrderIntent>>,
+    canceled: Mutex<Vec<String>>,
+}
+
+impl RecordingExecution {
+    fn new(failing: &[&str]) -> Self {
+        Self::with_filled(failing, Decimal::ZERO)
+    }
+
+    fn with_filled(failing: &[&str], filled_on_cancel: Decimal) -> Self {
+        Self {
+            failing: failing.iter().map(|s| s.to_string()).collect(),
+            filled_on_cancel,
+            cancel_fails: false,
+            submitted: Mutex::new(Vec::new()),
+            canceled: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl OrderExecutionPort for RecordingExecution {
+    async fn execute(&self, intent: &OrderIntent) -> anyhow::Result<ExecutionResult> {
+        self.submitted.lock().push(intent.clone());
+        let success = !self.failing.contains(&intent.symbol);
+        Ok(ExecutionResult {
+            success,
+            order_id: if success { format!("mock_order_{}", Uuid::new_v4()) } else { String::new() },
+            symbol: intent.symbol.clone(),
+            error: (!success).then(|| "Mock execution failed".to_string()),
+        })
+    }
+
+    async fn cancel(&self, _symbol: &str, order_id: &str) -> anyhow::Result<CancelResult> {
+        self.canceled.lock().push(order_id.to_string());
+        if self.cancel_fails {
+            anyhow::bail!("Unknown order sent");
+        }
+        Ok(CancelResult { order_id: order_id.to_string(), filled_quantity: self.filled_on_cancel })
+    }
+}
+
+// ========== Mock Exchange Query（按固定状态与成交数量回答订单查询） ==========
+
+struct FixedOrderQuery {
+    status: ExchangeOrderStatus,
+    executed_qty: Decimal,
+}
+
+#[async_trait]
+impl ExchangeQueryPort for FixedOrderQuery {
+    async fn get_spot_balances(&self) -> anyhow::Result<Vec<AccountBalance>> {
+        Ok(Vec::new())
+    }
+
+    async fn get_futures_positions(&self) -> anyhow::Result<Vec<Position>> {
+        Ok(Vec::new())
+    }
+
+    async fn get_order(&self, symbol: &str, order_id: &str) -> anyhow::Result<Option<ExchangeOrder>> {
+        Ok(Some(ExchangeOrder {
+            order_id: order_id.to_string(),
+            client_order_id: None,
+            symbol: symbol.to_string(),
+            side: "BUY".to_string(),
+            order_type: "LIMIT".to_string(),
+            status: self.status,
+            price: dec("50000"),
+            quantity: dec("0.1"),
+            executed_qty: self.executed_qty,
+            avg_price: dec("50000"),
+            created_at: 0,
+            updated_at: 0,
+        }))
+    }
+
+    async fn get_open_orders(&self, _symbol: Option<&str>) -> anyhow::Result<Vec<ExchangeOrder>> {
+        Ok(Vec::new())
+    }
+
+    async fn cancel_order(&self, symbol: &str, order_id: &str) -> anyhow::Result<CancelOrderResult> {
+        Ok(CancelOrderResult {
+            order_id: order_id.to_string(),
+            symbol: symbol.to_string(),
+            success: false,
+            error: Some("Unknown order sent".to_string()),
+        })
+    }
+
+    async fn cancel_all_orders(&self, _symbol: &str) -> anyhow::Result<Vec<CancelOrderResult>> {
+        Ok(Vec::new())
+    }
+}
+
+// ========== 测试辅助函数 ==========
+
+fn create_market_event(symbol: &str) -> MarketEvent {
+    MarketEvent {
+        event_type: MarketEventType::Trade,
+        exchange: "binance".to_string(),
+        symbol: symbol.to_string(),
+        timestamp: chrono::Utc::now(),
+        data: MarketEventData::Trade(TradeData {
+            trade_id: "1".to_string(),
+            price: dec("50000"),
+            quantity: dec("1.0"),
+            is_buyer_maker: false,
+        }),
+    }
+}
+
+/// 跨期套利组：买近月 + 卖远月
+fn create_spread_group() -> OrderIntentGroup {
+    let strategy_id = Uuid::new_v4();
+    OrderIntentGroup::new(
+        strategy_id,
+        vec![
+            OrderIntent::new(strategy_id, "BTCUSDT".to_string(), OrderSide::Buy, dec("0.1"), Some(dec("50000")), 0.8),
+            OrderIntent::new(strategy_id, "BTCUSDT_250328".to_string(), OrderSide::Sell, dec("0.1"), Some(dec("50500")), 0.8),
+        ],
+    )
+}
+
+fn create_service(
+    group: OrderIntentGroup,
+    rejected: &[&str],
+    execution: Arc<RecordingExecution>,
+) -> ExecutionService {
+    let risk = Arc::new(SymbolRisk {
+        rejected: rejected.iter().map(|s| s.to_string()).collect(),
+    });
+    ExecutionService::new(Arc::new(GroupStrategy { group }), risk, execution)
+}
+
+// ========== 测试 1: 全部腿成功 ==========
+
+#[tokio::test]
+async fn test_group_executes_all_legs_in_order() {
+    let execution = Arc::new(RecordingExecution::new(&[]));
+    let group = create_spread_group();
+    let service = create_service(group.clone(), &[], execution.clone());
+
+    service.on_market_event(&create_market_event("BTCUSDT")).await.unwrap();
+
+    let submitted = execution.submitted.lock();
+    assert_eq!(submitted.len(), 2);
+    assert_eq!(submitted[0].id, group.legs[0].id);
+    assert_eq!(submitted[1].id, group.legs[1].id);
+}
+
+// ========== 测试 2: 风控拒绝任一腿，整组不下单 ==========
+
+#[tokio::test]
+async fn test_group_rejected_when_any_leg_fails_risk() {
+    let execution = Arc::new(RecordingExecution::new(&[]));
+    let service = create_service(create_spread_group(), &["BTCUSDT_250328"], execution.clone());
+
+    service.on_market_event(&create_market_event("BTCUSDT")).await.unwrap();
+
+    assert!(execution.submitted.lock().is_empty(), "No leg should be executed");
+}
+
+// ========== 测试 3: 腿下单失败，撤销已成交的腿并平仓 ==========
+
+#[tokio::test]
+async fn test_group_unwinds_executed_legs_on_failure() {
+    let execution = Arc::new(RecordingExecution::with_filled(&["BTCUSDT_250328"], dec("0.1")));
+    let group = create_spread_group();
+    let service = create_service(group.clone(), &[], execution.clone());
+
+    service.on_market_event(&create_market_event("BTCUSDT")).await.unwrap();
+
+    assert_eq!(execution.canceled.lock().len(), 1, "leg 0 canceled before unwinding");
+
+    let submitted = execution.submitted.lock();
+    assert_eq!(submitted.len(), 3, "leg 0, failed leg 1, unwind of leg 0");
+
+    let unwind = &submitted[2];
+    assert_eq!(unwind.symbol, "BTCUSDT");
+    assert_eq!(unwind.side, OrderSide::Sell);
+    assert_eq!(unwind.quantity, group.legs[0].quantity);
+    assert_eq!(unwind.price, None);
+    assert_eq!(unwind.instruction.action, PositionAction::Close);
+}
+
+// ========== 测试 4: 腿已被接受但未成交，只撤单不平仓 ==========
+
+#[tokio::test]
+async fn test_group_cancels_unfilled_leg_without_unwind() {
+    let execution = Arc::new(RecordingExecution::new(&["BTCUSDT_250328"]));
+    let service = create_service(create_spread_group(), &[], execution.clone());
+
+    service.on_market_event(&create_market_event("BTCUSDT")).await.unwrap();
+
+    assert_eq!(execution.canceled.lock().len(), 1);
+    assert_eq!(execution.submitted.lock().len(), 2, "leg 0 and failed leg 1 only, no unwind order");
+}
+
+// ========== 测试 5: 腿部分成交，只平掉已成交部分 ==========
+
+#[tokio::test]
+async fn test_group_unwinds_only_filled_quantity() {
+    let execution = Arc::new(RecordingExecution::with_filled(&["BTCUSDT_250328"], dec("0.04")));
+    let service = create_service(create_spread_group(), &[], execution.clone());
+
+    service.on_market_event(&create_market_event("BTCUSDT")).await.unwrap();
+
+    let submitted = execution.submitted.lock();
+    assert_eq!(submitted.len(), 3);
+    assert_eq!(submitted[2].quantity, dec("0.04"));
+    assert_eq!(submitted[2].instruction.action, PositionAction::Close);
+}
+
+// ========== 测试 6: 撤单失败时按交易所查询到的成交数量平仓 ==========
+
+#[tokio::test]
+async fn test_group_unwind_queries_filled_quantity_when_cancel_fails() {
+    let mut execution = RecordingExecution::with_filled(&["BTCUSDT_250328"], Decimal::ZERO);
+    execution.cancel_fails = true;
+    let execution = Arc::new(execution);
+    let query = Arc::new(FixedOrderQuery {
+        status: ExchangeOrderStatus::Filled,
+        executed_qty: dec("0.07"),
+    });
+    let service = create_service(create_spread_group(), &[], execution.clone())
+        .with_exchange_query(query);
+
+    service.on_market_event(&create_market_event("BTCUSDT")).await.unwrap();
+
+    let submitted = execution.submitted.lock();
+    assert_eq!(submitted.len(), 3);
+    assert_eq!(submitted[2].quantity, dec("0.07"), "unwind the queried quantity, not the leg");
+    assert_eq!(submitted[2].instruction.action, PositionAction::Close);
+}
+
+// ========== 测试 7: 撤单失败且成交数量无法确定时不平仓 ==========
+
+#[tokio::test]
+async fn test_group_unwind_skipped_when_filled_quantity_unknown() {
+    // 未接入交易所查询
+    let mut execution = RecordingExecution::new(&["BTCUSDT_250328"]);
+    execution.cancel_fails = true;
+    let execution = Arc::new(execution);
+    let service = create_service(create_spread_group(), &[], execution.clone());
+    service.on_market_event(&create_market_event("BTCUSDT")).await.unwrap();
+    assert_eq!(execution.submitted.lock().len(), 2, "no unwind without a known fill");
+
+    // 订单仍未结束
+    let mut execution = RecordingExecution::new(&["BTCUSDT_250328"]);
+    execution.cancel_fails = true;
+    let execution = Arc::new(execution);
+    let query = Arc::new(FixedOrderQuery {
+        status: ExchangeOrderStatus::PartiallyFilled,
+        executed_qty: dec("0.03"),
+    });
+    let service = create_service(create_spread_group(), &[], execution.clone())
+        .with_exchange_query(query);
+    service.on_market_event(&create_market_event("BTCUSDT")).await.unwrap();
+    assert_eq!(execution.submitted.lock().len(), 2, "no unwind while the order is still open");
+}
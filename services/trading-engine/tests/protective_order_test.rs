@@ -0,0 +1,211 @@
+//! # 附带止损止盈集成测试
+//!
+//! 测试入场单成交后才挂出止损止盈、数量跟随成交，以及止损止盈互为 OCO
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use trading_engine::application::service::execution_service::ExecutionService;
+use trading_engine::domain::model::execution_fill::{ExecutionFill, FillSide, FillType};
+use trading_engine::domain::model::order_intent::{
+    OrderInstruction, OrderIntent, OrderSide, OrderType, PositionAction,
+};
+use trading_engine::domain::port::order_execution_port::{
+    CancelResult, ExecutionResult, OrderExecutionPort,
+};
+use trading_engine::domain::port::order_risk_port::OrderRiskPort;
+use trading_engine::domain::port::strategy_port::StrategyPort;
+
+use shared::event::market_event::{MarketEvent, MarketEventData, MarketEventType, TradeData};
+
+fn dec(s: &str) -> Decimal {
+    s.parse().unwrap_or_default()
+}
+
+// ========== Mock Strategy ==========
+
+struct EntryStrategy {
+    intent: OrderIntent,
+}
+
+#[async_trait]
+impl StrategyPort for EntryStrategy {
+    async fn evaluate(&self, _event: &MarketEvent) -> anyhow::Result<Option<OrderIntent>> {
+        Ok(Some(self.intent.clone()))
+    }
+}
+
+// ========== Mock Risk ==========
+
+struct PassRisk;
+
+#[async_trait]
+impl OrderRiskPort for PassRisk {
+    async fn check(&self, _intent: &OrderIntent) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn update_position(&self, _symbol: &str, _delta: Decimal) {}
+
+    async fn record_order_time(&self, _symbol: &str) {}
+}
+
+// ========== Mock Execution（顺序编号订单，记录撤单） ==========
+
+#[derive(Default)]
+struct RecordingExecution {
+    submitted: Mutex<Vec<OrderIntent>>,
+    canceled: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl OrderExecutionPort for RecordingExecution {
+    async fn execute(&self, intent: &OrderIntent) -> anyhow::Result<ExecutionResult> {
+        let mut submitted = self.submitted.lock();
+        submitted.push(intent.clone());
+        Ok(ExecutionResult {
+            success: true,
+            order_id: format!("order-{}", submitted.len()),
+            symbol: intent.symbol.clone(),
+            error: None,
+        })
+    }
+
+    async fn cancel(&self, _symbol: &str, order_id: &str) -> anyhow::Result<CancelResult> {
+        self.canceled.lock().push(order_id.to_string());
+        Ok(CancelResult { order_id: order_id.to_string(), filled_quantity: Decimal::ZERO })
+    }
+}
+
+// ========== 测试辅助函数 ==========
+
+fn create_market_event() -> MarketEvent {
+    MarketEvent {
+        event_type: MarketEventType::Trade,
+        exchange: "binance".to_string(),
+        symbol: "BTCUSDT".to_string(),
+        timestamp: chrono::Utc::now(),
+        data: MarketEventData::Trade(TradeData {
+            trade_id: "1".to_string(),
+            price: dec("50000"),
+            quantity: dec("1.0"),
+            is_buyer_maker: false,
+        }),
+    }
+}
+
+/// 挂单开多 0.3，附带止损 49000 / 止盈 52000
+fn create_entry() -> OrderIntent {
+    let instruction = OrderInstruction::open()
+        .post_only()
+        .with_stop_loss(dec("49000"))
+        .with_take_profit(dec("52000"));
+    let (symbol, price) = ("BTCUSDT".to_string(), Some(dec("50000")));
+    OrderIntent::new(Uuid::new_v4(), symbol, OrderSide::Buy, dec("0.3"), price, 0.8)
+        .with_instruction(instruction)
+}
+
+/// 订单数量均为 0.3，累计成交达到时为全部成交
+fn create_fill(
+    order_id: &str,
+    trade_id: &str,
+    side: FillSide,
+    qty: &str,
+    cumulative: &str,
+) -> ExecutionFill {
+    let mut fill = ExecutionFill::partial(
+        order_id.to_string(),
+        trade_id.to_string(),
+        "BTCUSDT".to_string(),
+        side,
+        dec(qty),
+        dec("50000"),
+        dec(cumulative),
+        dec("0.3"),
+    );
+    if fill.cumulative_quantity >= fill.original_quantity {
+        fill.fill_type = FillType::Full;
+    }
+    fill
+}
+
+// ========== 测试 1: 入场单未成交不挂保护单 ==========
+
+#[tokio::test]
+async fn test_no_protection_until_entry_fills() {
+    let execution = Arc::new(RecordingExecution::default());
+    let service = ExecutionService::new(
+        Arc::new(EntryStrategy { intent: create_entry() }),
+        Arc::new(PassRisk),
+        execution.clone(),
+    );
+
+    service.on_market_event(&create_market_event()).await.unwrap();
+
+    assert_eq!(execution.submitted.lock().len(), 1, "only the entry order");
+}
+
+// ========== 测试 2: 按成交数量挂出止损止盈 ==========
+
+#[tokio::test]
+async fn test_protection_sized_to_filled_quantity() {
+    let execution = Arc::new(RecordingExecution::default());
+    let entry = create_entry();
+    let service = ExecutionService::new(
+        Arc::new(EntryStrategy { intent: entry.clone() }),
+        Arc::new(PassRisk),
+        execution.clone(),
+    );
+    service.on_market_event(&create_market_event()).await.unwrap();
+
+    let mut fill = create_fill("order-1", "t1", FillSide::Buy, "0.1", "0.1");
+    fill.client_order_id = Some(entry.id.to_string());
+    service.apply_execution_fill(&fill).await;
+
+    let submitted = execution.submitted.lock();
+    assert_eq!(submitted.len(), 3);
+
+    let (stop, target) = (&submitted[1], &submitted[2]);
+    assert_eq!(stop.instruction.order_type, Some(OrderType::StopLoss));
+    assert_eq!(stop.price, Some(dec("49000")));
+    assert_eq!(target.instruction.order_type, Some(OrderType::TakeProfit));
+    assert_eq!(target.price, Some(dec("52000")));
+    for protective in [stop, target] {
+        assert_eq!(protective.side, OrderSide::Sell);
+        assert_eq!(protective.quantity, dec("0.1"));
+        assert_eq!(protective.instruction.action, PositionAction::Reduce);
+    }
+}
+
+// ========== 测试 3: 止损触发撤销止盈 ==========
+
+#[tokio::test]
+async fn test_stop_fill_cancels_target() {
+    let execution = Arc::new(RecordingExecution::default());
+    let entry = create_entry();
+    let service = ExecutionService::new(
+        Arc::new(EntryStrategy { intent: entry.clone() }),
+        Arc::new(PassRisk),
+        execution.clone(),
+    );
+    service.on_market_event(&create_market_event()).await.unwrap();
+
+    let mut fill = create_fill("order-1", "t1", FillSide::Buy, "0.3", "0.3");
+    fill.client_order_id = Some(entry.id.to_string());
+    service.apply_execution_fill(&fill).await;
+
+    // order-2 为止损，order-3 为止盈
+    let stop_fill = create_fill("order-2", "t2", FillSide::Sell, "0.3", "0.3");
+    service.apply_execution_fill(&stop_fill).await;
+
+    assert_eq!(*execution.canceled.lock(), vec!["order-3".to_string()]);
+
+    // 止盈不再可撤（已解除关联）
+    let target_fill = create_fill("order-3", "t3", FillSide::Sell, "0.3", "0.3");
+    service.apply_execution_fill(&target_fill).await;
+    assert_eq!(execution.canceled.lock().len(), 1);
+}
@@ -0,0 +1,155 @@
+//! # 信号事件消费集成测试
+//!
+//! 测试异步策略信号到达即执行（不等待行情事件），且执行完成后才提交位点
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use trading_engine::application::service::execution_service::ExecutionService;
+use trading_engine::application::service::signal_consumer_service::SignalConsumerService;
+use trading_engine::domain::model::order_intent::{OrderIntent, OrderSide};
+use trading_engine::domain::model::order_intent_group::OrderIntentGroup;
+use trading_engine::domain::port::order_execution_port::{
+    CancelResult, ExecutionResult, OrderExecutionPort,
+};
+use trading_engine::domain::port::order_risk_port::OrderRiskPort;
+use trading_engine::domain::port::signal_event_port::SignalEventPort;
+use trading_engine::domain::port::strategy_port::StrategyPort;
+
+use shared::event::market_event::MarketEvent;
+
+fn dec(s: &str) -> Decimal {
+    s.parse().unwrap_or_default()
+}
+
+/// 各组件按发生顺序写入的事件
+type EventLog = Arc<Mutex<Vec<String>>>;
+
+// ========== Mock Strategy（行情不产生意图） ==========
+
+struct SilentStrategy;
+
+#[async_trait]
+impl StrategyPort for SilentStrategy {
+    async fn evaluate(&self, _event: &MarketEvent) -> anyhow::Result<Option<OrderIntent>> {
+        Ok(None)
+    }
+}
+
+// ========== Mock Risk ==========
+
+struct PassRisk;
+
+#[async_trait]
+impl OrderRiskPort for PassRisk {
+    async fn check(&self, _intent: &OrderIntent) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn update_position(&self, _symbol: &str, _delta: Decimal) {}
+
+    async fn record_order_time(&self, _symbol: &str) {}
+}
+
+// ========== Mock Execution（记录下单） ==========
+
+struct RecordingExecution {
+    log: EventLog,
+}
+
+#[async_trait]
+impl OrderExecutionPort for RecordingExecution {
+    async fn execute(&self, intent: &OrderIntent) -> anyhow::Result<ExecutionResult> {
+        self.log.lock().push(format!("execute {}", intent.symbol));
+        Ok(ExecutionResult {
+            success: true,
+            order_id: format!("mock_order_{}", Uuid::new_v4()),
+            symbol: intent.symbol.clone(),
+            error: None,
+        })
+    }
+
+    async fn cancel(&self, _symbol: &str, order_id: &str) -> anyhow::Result<CancelResult> {
+        Ok(CancelResult { order_id: order_id.to_string(), filled_quantity: Decimal::ZERO })
+    }
+}
+
+// ========== Mock Signal Source（按顺序交出信号，记录提交） ==========
+
+struct QueuedSignals {
+    signals: Mutex<VecDeque<Option<OrderIntentGroup>>>,
+    log: EventLog,
+}
+
+#[async_trait]
+impl SignalEventPort for QueuedSignals {
+    async fn next_signal(&self) -> anyhow::Result<Option<OrderIntentGroup>> {
+        self.signals
+            .lock()
+            .pop_front()
+            .ok_or_else(|| anyhow::anyhow!("no more signals"))
+    }
+
+    async fn commit(&self) -> anyhow::Result<()> {
+        self.log.lock().push("commit".to_string());
+        Ok(())
+    }
+}
+
+// ========== 测试辅助函数 ==========
+
+fn create_signal(symbol: &str) -> OrderIntentGroup {
+    let symbol = symbol.to_string();
+    let intent = OrderIntent::new(Uuid::new_v4(), symbol, OrderSide::Buy, dec("0.1"), None, 0.8);
+    OrderIntentGroup::single(intent)
+}
+
+fn create_consumer(signals: Vec<Option<OrderIntentGroup>>) -> (SignalConsumerService, EventLog) {
+    let log = EventLog::default();
+    let service = ExecutionService::new(
+        Arc::new(SilentStrategy),
+        Arc::new(PassRisk),
+        Arc::new(RecordingExecution { log: log.clone() }),
+    );
+    let source = QueuedSignals { signals: Mutex::new(signals.into()), log: log.clone() };
+    (SignalConsumerService::new(Arc::new(source), Arc::new(service)), log)
+}
+
+// ========== 测试 1: 信号到达即执行，执行后提交 ==========
+
+#[tokio::test]
+async fn test_signal_executes_without_market_event() {
+    let (consumer, log) = create_consumer(vec![Some(create_signal("BTCUSDT"))]);
+
+    consumer.process_next().await.unwrap();
+
+    assert_eq!(*log.lock(), vec!["execute BTCUSDT".to_string(), "commit".to_string()]);
+}
+
+// ========== 测试 2: 丢弃的信号同样提交位点 ==========
+
+#[tokio::test]
+async fn test_dropped_signal_is_committed() {
+    let (consumer, log) = create_consumer(vec![None, Some(create_signal("ETHUSDT"))]);
+
+    consumer.process_next().await.unwrap();
+    consumer.process_next().await.unwrap();
+
+    let expected = ["commit", "execute ETHUSDT", "commit"].map(str::to_string);
+    assert_eq!(*log.lock(), expected.to_vec());
+}
+
+// ========== 测试 3: 信号源错误不提交 ==========
+
+#[tokio::test]
+async fn test_source_error_skips_commit() {
+    let (consumer, log) = create_consumer(Vec::new());
+
+    assert!(consumer.process_next().await.is_err());
+    assert!(log.lock().is_empty());
+}
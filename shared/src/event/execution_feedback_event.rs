@@ -0,0 +1,144 @@
+//! Execution Feedback Events - 执行回报事件
+//!
+//! 由 trading-engine 按策略实例发布（成交、拒单、持仓快照），
+//! strategy-engine 按 `strategy_id` 路由给对应的策略实例。
+//!
+//! 每条成交 / 拒单之后都会紧跟一条该策略在该交易对上的持仓快照，
+//! 策略以快照为准修正自身持仓状态。
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::types::order::OrderSide;
+
+/// 执行回报事件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionFeedbackEvent {
+    /// 事件 ID
+    pub id: Uuid,
+    /// 所属策略实例 ID
+    pub strategy_id: Uuid,
+    /// 交易对
+    pub symbol: String,
+    /// 回报内容
+    pub feedback: ExecutionFeedback,
+    /// 事件时间
+    pub timestamp: DateTime<Utc>,
+}
+
+impl ExecutionFeedbackEvent {
+    /// 创建执行回报事件
+    pub fn new(strategy_id: Uuid, symbol: impl Into<String>, feedback: ExecutionFeedback) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            strategy_id,
+            symbol: symbol.into(),
+            feedback,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// 回报内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExecutionFeedback {
+    /// 成交（部分或全部）
+    Fill(StrategyFill),
+    /// 订单被拒绝或未成交部分被撤销
+    OrderRejected(OrderRejection),
+    /// 策略在该交易对上的持仓快照
+    PositionSnapshot(PositionSnapshot),
+}
+
+/// 成交回报
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyFill {
+    /// 交易所订单 ID
+    pub order_id: String,
+    /// 成交 ID
+    pub trade_id: String,
+    /// 方向
+    pub side: OrderSide,
+    /// 本次成交数量
+    pub quantity: Decimal,
+    /// 本次成交价格
+    pub price: Decimal,
+    /// 累计成交数量
+    pub cumulative_quantity: Decimal,
+    /// 订单原始数量
+    pub original_quantity: Decimal,
+    /// 订单是否已全部成交
+    pub is_final: bool,
+    /// 手续费
+    pub commission: Decimal,
+    /// 手续费资产
+    pub commission_asset: String,
+    /// 成交时间
+    pub fill_time: DateTime<Utc>,
+}
+
+/// 拒单回报
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderRejection {
+    /// 交易所订单 ID（下单前被拦截时为空）
+    pub order_id: Option<String>,
+    /// 方向
+    pub side: OrderSide,
+    /// 被拒绝（或被撤销未成交）的数量
+    pub quantity: Decimal,
+    /// 委托价格（市价单为空）
+    pub price: Option<Decimal>,
+    /// 拒绝来源
+    pub source: RejectionSource,
+    /// 拒绝代码
+    pub code: String,
+    /// 拒绝原因
+    pub reason: String,
+}
+
+/// 拒绝来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectionSource {
+    /// 风控拒绝
+    Risk,
+    /// 行情数据质量拦截
+    DataQuality,
+    /// 交易所拒单 / 下单失败
+    Exchange,
+    /// 订单撤销（未成交部分）
+    Canceled,
+    /// 意图已过期（未下单）
+    Expired,
+    /// 同组其他腿失败，本腿未下单或已回滚
+    LegGroup,
+}
+
+/// 持仓快照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionSnapshot {
+    /// 净持仓（正数为多头，负数为空头）
+    pub quantity: Decimal,
+    /// 持仓均价（无持仓时为 0）
+    pub average_price: Decimal,
+}
+
+impl PositionSnapshot {
+    /// 是否无持仓
+    pub fn is_flat(&self) -> bool {
+        self.quantity.is_zero()
+    }
+
+    /// 是否多头
+    pub fn is_long(&self) -> bool {
+        self.quantity > Decimal::ZERO
+    }
+
+    /// 是否空头
+    pub fn is_short(&self) -> bool {
+        self.quantity < Decimal::ZERO
+    }
+}
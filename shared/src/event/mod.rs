@@ -7,3 +7,5 @@ pub mod risk_event;
 pub mod signal_event;
 pub mod copytrading_event;
 pub mod commission_event;
+pub mod quality_event;
+pub mod execution_feedback_event;
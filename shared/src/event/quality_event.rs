@@ -0,0 +1,61 @@
+//! Market Data Quality Events - 行情数据质量事件
+//!
+//! 由 market-data 按交易对发布，trading-engine 据此拦截下单。
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::types::market::DataQuality;
+
+/// 交易对数据质量事件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataQualityEvent {
+    /// 交易对
+    pub symbol: String,
+    /// 当前质量状态
+    pub quality: DataQuality,
+    /// 当前生效的异常（Normal 时为空）
+    pub issues: Vec<QualityIssue>,
+    /// 最近一次收到行情的时间
+    pub last_update: Option<DateTime<Utc>>,
+    /// 事件时间
+    pub timestamp: DateTime<Utc>,
+}
+
+/// 数据质量异常
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum QualityIssue {
+    /// 断流：超过阈值时间未更新
+    Stale {
+        exchange: String,
+        silent_secs: i64,
+    },
+    /// 异常价格：收益率超过 k·σ
+    PriceSpike {
+        exchange: String,
+        price: Decimal,
+        previous: Decimal,
+        sigma_multiple: f64,
+    },
+    /// 盘口交叉：买一 >= 卖一
+    CrossedBook {
+        exchange: String,
+        best_bid: Decimal,
+        best_ask: Decimal,
+    },
+    /// 序号缺口：成交 ID 不连续
+    SequenceGap {
+        exchange: String,
+        expected: u64,
+        received: u64,
+    },
+    /// 跨源偏离：与其他交易所价格偏离过大
+    CrossSourceDeviation {
+        exchange: String,
+        price: Decimal,
+        reference: Decimal,
+        deviation_pct: f64,
+    },
+}
@@ -1,20 +1,55 @@
 //! 跨服务的交易信号事件。
+//!
+//! strategy-engine 发布、trading-engine 消费（异步策略模式）。
+//!
+//! ## 版本 (schema_version)
+//! - v1：只有方向、价格、数量、置信度（缺少 `schema_version` 的消息按 v1 处理）
+//! - v2：增加下单指令 `instruction` 与多腿 `legs`
+//!
+//! 消费方只接受不高于自身 `SIGNAL_EVENT_SCHEMA_VERSION` 的消息。
+//!
+//! ## 幂等与过期
+//! 同一信号可能因重试被重复投递，也可能在积压后才到达。
+//! 发布方与消费方都通过 `SignalGate` 按信号 ID 去重，并丢弃超过最大时延的信号。
 
-use chrono::{DateTime, Utc};
+use std::collections::{HashSet, VecDeque};
+
+use chrono::{DateTime, Duration, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use uuid::Uuid;
 
+use crate::types::order::{OrderInstruction, OrderSide};
+
+/// 当前信号事件版本
+pub const SIGNAL_EVENT_SCHEMA_VERSION: u32 = 2;
+
+fn legacy_schema_version() -> u32 {
+    1
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SignalEvent {
+    /// 消息版本（旧消息缺省为 1）
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u32,
+    /// 信号 ID（去重键）
     pub id: Uuid,
     pub strategy_id: Uuid,
     pub symbol: String,
     pub signal_type: SignalType,
+    /// 价格（限价单为委托价，市价单为参考价）
     pub price: Decimal,
     pub quantity: Decimal,
     pub confidence: f64,
     pub created_at: DateTime<Utc>,
+    /// 下单指令（v2，缺省为开仓）
+    #[serde(default)]
+    pub instruction: OrderInstruction,
+    /// 多腿组的全部腿（v2，非空时以此为准，顶层字段为第一腿的摘要）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub legs: Vec<SignalLeg>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,3 +58,97 @@ pub enum SignalType {
     Sell,
     Hold,
 }
+
+/// 多腿组中的一腿
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignalLeg {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub quantity: Decimal,
+    /// 委托价（None 为市价）
+    pub price: Option<Decimal>,
+    #[serde(default)]
+    pub instruction: OrderInstruction,
+}
+
+impl SignalEvent {
+    /// 是否多腿组
+    pub fn is_group(&self) -> bool {
+        !self.legs.is_empty()
+    }
+
+    /// 是否已过期：超过最大时延（`max_age` 为 0 不限），或超过指令的过期时间
+    pub fn is_expired(&self, now: DateTime<Utc>, max_age: Duration) -> bool {
+        let too_old = max_age > Duration::zero() && self.created_at + max_age <= now;
+        too_old
+            || self.instruction.is_expired(now)
+            || self.legs.iter().any(|leg| leg.instruction.is_expired(now))
+    }
+}
+
+/// 信号拒收原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum SignalRejection {
+    #[error("unsupported signal schema version: {0}")]
+    UnsupportedVersion(u32),
+    #[error("duplicate signal")]
+    Duplicate,
+    #[error("signal expired")]
+    Expired,
+}
+
+/// 信号准入闸门（按 ID 去重 + 过期丢弃）
+///
+/// 只记住最近 `capacity` 个信号 ID，更早的重复投递由过期检查兜底。
+#[derive(Debug)]
+pub struct SignalGate {
+    max_age: Duration,
+    capacity: usize,
+    seen: HashSet<Uuid>,
+    order: VecDeque<Uuid>,
+}
+
+impl SignalGate {
+    /// 创建闸门（`max_age` 为 0 不限时延）
+    pub fn new(max_age: Duration, capacity: usize) -> Self {
+        Self {
+            max_age,
+            capacity: capacity.max(1),
+            seen: HashSet::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// 最大时延
+    pub fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    /// 检查版本与时效（不记录 ID）
+    pub fn check(&self, event: &SignalEvent, now: DateTime<Utc>) -> Result<(), SignalRejection> {
+        if event.schema_version > SIGNAL_EVENT_SCHEMA_VERSION {
+            return Err(SignalRejection::UnsupportedVersion(event.schema_version));
+        }
+        if event.is_expired(now, self.max_age) {
+            return Err(SignalRejection::Expired);
+        }
+        Ok(())
+    }
+
+    /// 准入：通过检查且未见过的信号记录其 ID
+    pub fn admit(&mut self, event: &SignalEvent, now: DateTime<Utc>) -> Result<(), SignalRejection> {
+        if self.seen.contains(&event.id) {
+            return Err(SignalRejection::Duplicate);
+        }
+        self.check(event, now)?;
+
+        if self.order.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        self.seen.insert(event.id);
+        self.order.push_back(event.id);
+        Ok(())
+    }
+}
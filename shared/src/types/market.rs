@@ -25,3 +25,41 @@ pub struct Kline {
     pub open_time: DateTime<Utc>,
     pub close_time: DateTime<Utc>,
 }
+
+/// 数据质量
+///
+/// 与 shared-models `common::DataQuality` 保持同一序列化格式，
+/// 在共享内核中提供，供 market-data 发布、trading-engine 消费。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum DataQuality {
+    /// 正常数据 - 连续性良好
+    #[serde(rename = "normal")]
+    Normal,
+    /// 可疑数据 - 断流 / 异常价格 / 盘口交叉 / 序号缺口
+    #[serde(rename = "suspect")]
+    Suspect,
+    /// 恢复数据 - 异常消失后的观察期
+    #[serde(rename = "recovered")]
+    Recovered,
+    /// 未知 - 尚未收到任何数据
+    #[default]
+    Unknown,
+}
+
+impl DataQuality {
+    /// 转换为字符串表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataQuality::Normal => "normal",
+            DataQuality::Suspect => "suspect",
+            DataQuality::Recovered => "recovered",
+            DataQuality::Unknown => "Unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for DataQuality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
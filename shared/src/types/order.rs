@@ -1,9 +1,12 @@
 //! Order Types
 
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::position::PositionSide;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderId(pub Uuid);
 
@@ -21,6 +24,213 @@ pub enum OrderType {
     TakeProfit,
 }
 
+impl OrderType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Market => "market",
+            Self::Limit => "limit",
+            Self::StopLoss => "stop_loss",
+            Self::TakeProfit => "take_profit",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "market" => Some(Self::Market),
+            "limit" => Some(Self::Limit),
+            "stop_loss" => Some(Self::StopLoss),
+            "take_profit" => Some(Self::TakeProfit),
+            _ => None,
+        }
+    }
+}
+
+/// 仓位动作
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PositionAction {
+    /// 开仓 / 加仓
+    #[default]
+    Open,
+    /// 平掉指定方向的持仓（只减仓）
+    Close,
+    /// 减仓指定数量（只减仓）
+    Reduce,
+    /// 反手：平掉当前持仓并反向开仓，数量包含两部分
+    Flip,
+}
+
+impl PositionAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Close => "close",
+            Self::Reduce => "reduce",
+            Self::Flip => "flip",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "open" => Some(Self::Open),
+            "close" => Some(Self::Close),
+            "reduce" => Some(Self::Reduce),
+            "flip" => Some(Self::Flip),
+            _ => None,
+        }
+    }
+
+    /// 是否只减仓
+    pub fn is_reduce_only(&self) -> bool {
+        matches!(self, Self::Close | Self::Reduce)
+    }
+
+    /// 是否建立新仓位（可附带止损止盈）
+    pub fn is_entry(&self) -> bool {
+        matches!(self, Self::Open | Self::Flip)
+    }
+}
+
+/// 订单有效期
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeInForce {
+    /// 一直有效直到成交或撤单
+    #[default]
+    Gtc,
+    /// 立即成交，剩余撤销
+    Ioc,
+    /// 全部成交或全部撤销
+    Fok,
+    /// 只做 Maker，会立即成交时拒单
+    PostOnly,
+}
+
+impl TimeInForce {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Gtc => "gtc",
+            Self::Ioc => "ioc",
+            Self::Fok => "fok",
+            Self::PostOnly => "post_only",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "gtc" => Some(Self::Gtc),
+            "ioc" => Some(Self::Ioc),
+            "fok" => Some(Self::Fok),
+            "post_only" | "gtx" => Some(Self::PostOnly),
+            _ => None,
+        }
+    }
+}
+
+/// 下单指令（交易信号的扩展部分）
+///
+/// 所有字段都有默认值：默认即旧信号语义（开仓、单向持仓、
+/// 有价格为限价 GTC、无价格为市价、无止损止盈、不过期）。
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct OrderInstruction {
+    /// 仓位动作
+    pub action: PositionAction,
+    /// 持仓方向（双向持仓模式使用，None 为单向持仓）
+    pub position_side: Option<PositionSide>,
+    /// 订单类型提示（None 时按是否有价格决定）
+    pub order_type: Option<OrderType>,
+    /// 有效期（限价单）
+    pub time_in_force: Option<TimeInForce>,
+    /// 附带止损触发价
+    pub stop_loss: Option<Decimal>,
+    /// 附带止盈触发价
+    pub take_profit: Option<Decimal>,
+    /// 过期时间，过期未下单的意图直接丢弃
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl OrderInstruction {
+    /// 开仓
+    pub fn open() -> Self {
+        Self::default()
+    }
+
+    /// 平仓（双向持仓模式需再指定 `with_position_side`）
+    pub fn close() -> Self {
+        Self {
+            action: PositionAction::Close,
+            ..Self::default()
+        }
+    }
+
+    /// 减仓（双向持仓模式需再指定 `with_position_side`）
+    pub fn reduce() -> Self {
+        Self {
+            action: PositionAction::Reduce,
+            ..Self::default()
+        }
+    }
+
+    /// 反手
+    pub fn flip() -> Self {
+        Self {
+            action: PositionAction::Flip,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_position_side(mut self, position_side: PositionSide) -> Self {
+        self.position_side = Some(position_side);
+        self
+    }
+
+    pub fn with_order_type(mut self, order_type: OrderType) -> Self {
+        self.order_type = Some(order_type);
+        self
+    }
+
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// 只做 Maker 的限价单
+    pub fn post_only(self) -> Self {
+        self.with_order_type(OrderType::Limit)
+            .with_time_in_force(TimeInForce::PostOnly)
+    }
+
+    pub fn with_stop_loss(mut self, price: Decimal) -> Self {
+        self.stop_loss = Some(price);
+        self
+    }
+
+    pub fn with_take_profit(mut self, price: Decimal) -> Self {
+        self.take_profit = Some(price);
+        self
+    }
+
+    pub fn with_expiry(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// 最终订单类型：显式提示优先，否则有价格为限价、无价格为市价
+    pub fn resolve_order_type(&self, price: Option<Decimal>) -> OrderType {
+        match (self.order_type, price) {
+            (Some(order_type), _) => order_type,
+            (None, Some(_)) => OrderType::Limit,
+            (None, None) => OrderType::Market,
+        }
+    }
+
+    /// 是否已过期
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum OrderStatus {
     Pending,
@@ -13,6 +13,23 @@ pub enum PositionSide {
     Short,
 }
 
+impl PositionSide {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Long => "long",
+            Self::Short => "short",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "long" => Some(Self::Long),
+            "short" => Some(Self::Short),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PositionInfo {
     pub id: PositionId,